@@ -0,0 +1,163 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The paragraph level and the explicit levels and directions: rules P2–P3 and X1–X8 of
+//! [UAX #9](https://www.unicode.org/reports/tr9/#Explicit_Levels_and_Directions).
+
+use crate::level::Level;
+use alloc::vec;
+use icu_uniset::enum_props::BidiClass::{self, *};
+
+/// Returns the direction of the first strong character, skipping isolates (P2 and P3).
+///
+/// With `stop_at_pdi`, the scan ends at a PDI that closes the isolate the text starts in,
+/// which is how the direction of an FSI is determined.
+pub(crate) fn first_strong_level(classes: &[BidiClass], stop_at_pdi: bool) -> Option<Level> {
+    let mut isolate_depth = 0usize;
+    for &class in classes {
+        match class {
+            LeftToRight if isolate_depth == 0 => return Some(Level::ltr()),
+            RightToLeft | ArabicLetter if isolate_depth == 0 => return Some(Level::rtl()),
+            FirstStrongIsolate | LeftToRightIsolate | RightToLeftIsolate => isolate_depth += 1,
+            PopDirectionalIsolate if isolate_depth > 0 => isolate_depth -= 1,
+            PopDirectionalIsolate if stop_at_pdi => return None,
+            ParagraphSeparator => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// An entry of the directional status stack.
+#[derive(Copy, Clone)]
+struct Status {
+    level: Level,
+    override_class: Option<BidiClass>,
+    isolate: bool,
+}
+
+/// Computes the explicit embedding levels of a paragraph (X1–X8).
+///
+/// `processing_classes` starts out as a copy of the original classes, and the directional
+/// overrides are applied to it. Characters removed by X9 keep the level they are given here
+/// until [`crate::prepare`] assigns them their final one.
+pub(crate) fn compute(
+    classes: &[BidiClass],
+    para_level: Level,
+    levels: &mut [Level],
+    processing_classes: &mut [BidiClass],
+) {
+    // X1
+    let mut stack = vec![Status {
+        level: para_level,
+        override_class: None,
+        isolate: false,
+    }];
+    let mut overflow_isolates = 0usize;
+    let mut overflow_embeddings = 0usize;
+    let mut valid_isolates = 0usize;
+
+    for (i, &class) in classes.iter().enumerate() {
+        let last = *stack.last().expect("The stack is never empty");
+        match class {
+            // X2–X5
+            RightToLeftEmbedding | LeftToRightEmbedding | RightToLeftOverride
+            | LeftToRightOverride => {
+                levels[i] = last.level;
+                let new_level = match class {
+                    RightToLeftEmbedding | RightToLeftOverride => last.level.next_rtl(),
+                    _ => last.level.next_ltr(),
+                };
+                match new_level {
+                    Some(level) if overflow_isolates == 0 && overflow_embeddings == 0 => {
+                        stack.push(Status {
+                            level,
+                            override_class: match class {
+                                RightToLeftOverride => Some(RightToLeft),
+                                LeftToRightOverride => Some(LeftToRight),
+                                _ => None,
+                            },
+                            isolate: false,
+                        });
+                    }
+                    _ => {
+                        if overflow_isolates == 0 {
+                            overflow_embeddings += 1;
+                        }
+                    }
+                }
+            }
+            // X5a–X5c
+            RightToLeftIsolate | LeftToRightIsolate | FirstStrongIsolate => {
+                levels[i] = last.level;
+                if let Some(override_class) = last.override_class {
+                    processing_classes[i] = override_class;
+                }
+                let is_rtl = match class {
+                    RightToLeftIsolate => true,
+                    LeftToRightIsolate => false,
+                    _ => first_strong_level(&classes[i + 1..], true) == Some(Level::rtl()),
+                };
+                let new_level = if is_rtl {
+                    last.level.next_rtl()
+                } else {
+                    last.level.next_ltr()
+                };
+                match new_level {
+                    Some(level) if overflow_isolates == 0 && overflow_embeddings == 0 => {
+                        valid_isolates += 1;
+                        stack.push(Status {
+                            level,
+                            override_class: None,
+                            isolate: true,
+                        });
+                    }
+                    _ => overflow_isolates += 1,
+                }
+            }
+            // X6a
+            PopDirectionalIsolate => {
+                if overflow_isolates > 0 {
+                    overflow_isolates -= 1;
+                } else if valid_isolates > 0 {
+                    overflow_embeddings = 0;
+                    while matches!(stack.last(), Some(status) if !status.isolate) {
+                        stack.pop();
+                    }
+                    stack.pop();
+                    valid_isolates -= 1;
+                }
+                let last = *stack
+                    .last()
+                    .expect("The isolate entry is above the first entry");
+                levels[i] = last.level;
+                if let Some(override_class) = last.override_class {
+                    processing_classes[i] = override_class;
+                }
+            }
+            // X7
+            PopDirectionalFormat => {
+                levels[i] = last.level;
+                if overflow_isolates == 0 {
+                    if overflow_embeddings > 0 {
+                        overflow_embeddings -= 1;
+                    } else if !last.isolate && stack.len() >= 2 {
+                        stack.pop();
+                    }
+                }
+            }
+            // X8
+            ParagraphSeparator => levels[i] = para_level,
+            // Removed by X9
+            BoundaryNeutral => levels[i] = last.level,
+            // X6
+            _ => {
+                levels[i] = last.level;
+                if let Some(override_class) = last.override_class {
+                    processing_classes[i] = override_class;
+                }
+            }
+        }
+    }
+}
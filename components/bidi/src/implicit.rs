@@ -0,0 +1,274 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The weak types, neutral types and implicit levels: rules W1–W7, N0–N2 and I1–I2 of
+//! [UAX #9](https://www.unicode.org/reports/tr9/#Resolving_Weak_Types).
+
+use crate::level::Level;
+use crate::prepare::{is_isolate_initiator, IsolatingRunSequence};
+use alloc::vec::Vec;
+use icu_uniset::enum_props::BidiClass::{self, *};
+
+/// The maximum nesting of bracket pairs that are resolved, see BD16.
+const MAX_BRACKET_DEPTH: usize = 63;
+
+/// A paired bracket, identified by the code point of its opening bracket.
+///
+/// U+2329 and U+232A are identified with their canonical equivalents U+3008 and U+3009, so
+/// that the brackets pair up either way.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum Bracket {
+    Open(char),
+    Close(char),
+}
+
+impl Bracket {
+    pub(crate) fn canonical(c: char) -> char {
+        match c {
+            '\u{2329}' => '\u{3008}',
+            '\u{232A}' => '\u{3009}',
+            _ => c,
+        }
+    }
+}
+
+/// W1–W7.
+pub(crate) fn resolve_weak(sequence: &IsolatingRunSequence, classes: &mut [BidiClass]) {
+    let indices = &sequence.indices;
+
+    // W1
+    let mut previous = sequence.sos;
+    for &i in indices {
+        if classes[i] == NonspacingMark {
+            classes[i] = if is_isolate_initiator(previous) || previous == PopDirectionalIsolate {
+                OtherNeutral
+            } else {
+                previous
+            };
+        }
+        previous = classes[i];
+    }
+
+    // W2 and W3
+    let mut last_strong = sequence.sos;
+    for &i in indices {
+        match classes[i] {
+            LeftToRight | RightToLeft => last_strong = classes[i],
+            ArabicLetter => {
+                last_strong = ArabicLetter;
+                classes[i] = RightToLeft;
+            }
+            EuropeanNumber if last_strong == ArabicLetter => classes[i] = ArabicNumber,
+            _ => {}
+        }
+    }
+
+    // W4
+    for k in 1..indices.len().saturating_sub(1) {
+        let (before, after) = (classes[indices[k - 1]], classes[indices[k + 1]]);
+        match (before, classes[indices[k]], after) {
+            (EuropeanNumber, EuropeanSeparator, EuropeanNumber)
+            | (EuropeanNumber, CommonSeparator, EuropeanNumber) => {
+                classes[indices[k]] = EuropeanNumber
+            }
+            (ArabicNumber, CommonSeparator, ArabicNumber) => classes[indices[k]] = ArabicNumber,
+            _ => {}
+        }
+    }
+
+    // W5
+    let mut k = 0;
+    while k < indices.len() {
+        if classes[indices[k]] != EuropeanTerminator {
+            k += 1;
+            continue;
+        }
+        let start = k;
+        while k < indices.len() && classes[indices[k]] == EuropeanTerminator {
+            k += 1;
+        }
+        let before = start > 0 && classes[indices[start - 1]] == EuropeanNumber;
+        let after = k < indices.len() && classes[indices[k]] == EuropeanNumber;
+        if before || after {
+            for &i in &indices[start..k] {
+                classes[i] = EuropeanNumber;
+            }
+        }
+    }
+
+    // W6
+    for &i in indices {
+        if matches!(
+            classes[i],
+            EuropeanSeparator | EuropeanTerminator | CommonSeparator
+        ) {
+            classes[i] = OtherNeutral;
+        }
+    }
+
+    // W7
+    let mut last_strong = sequence.sos;
+    for &i in indices {
+        match classes[i] {
+            LeftToRight | RightToLeft => last_strong = classes[i],
+            EuropeanNumber if last_strong == LeftToRight => classes[i] = LeftToRight,
+            _ => {}
+        }
+    }
+}
+
+/// The strong direction of a class for the neutral rules, with numbers counting as
+/// right-to-left.
+fn strong_direction(class: BidiClass) -> Option<BidiClass> {
+    match class {
+        LeftToRight => Some(LeftToRight),
+        RightToLeft | EuropeanNumber | ArabicNumber => Some(RightToLeft),
+        _ => None,
+    }
+}
+
+fn is_neutral_or_isolate(class: BidiClass) -> bool {
+    matches!(
+        class,
+        ParagraphSeparator
+            | SegmentSeparator
+            | WhiteSpace
+            | OtherNeutral
+            | FirstStrongIsolate
+            | LeftToRightIsolate
+            | RightToLeftIsolate
+            | PopDirectionalIsolate
+    )
+}
+
+/// Identifies the bracket pairs of a sequence (BD16), as pairs of positions in the sequence
+/// sorted by their opening bracket.
+fn bracket_pairs(
+    sequence: &IsolatingRunSequence,
+    classes: &[BidiClass],
+    brackets: &[Option<Bracket>],
+) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    let mut openers: Vec<(char, usize)> = Vec::new();
+    for (k, &i) in sequence.indices.iter().enumerate() {
+        if classes[i] != OtherNeutral {
+            continue;
+        }
+        match brackets[i] {
+            Some(Bracket::Open(id)) => {
+                if openers.len() == MAX_BRACKET_DEPTH {
+                    break;
+                }
+                openers.push((id, k));
+            }
+            Some(Bracket::Close(id)) => {
+                if let Some(depth) = openers.iter().rposition(|&(open, _)| open == id) {
+                    pairs.push((openers[depth].1, k));
+                    openers.truncate(depth);
+                }
+            }
+            None => {}
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+/// N0–N2. `original_classes` are the classes before any rule was applied, which N0 needs to
+/// find the nonspacing marks following a bracket.
+pub(crate) fn resolve_neutral(
+    sequence: &IsolatingRunSequence,
+    original_classes: &[BidiClass],
+    brackets: &[Option<Bracket>],
+    classes: &mut [BidiClass],
+) {
+    let indices = &sequence.indices;
+    let embedding = if sequence.level.is_rtl() {
+        RightToLeft
+    } else {
+        LeftToRight
+    };
+
+    // N0
+    for (open, close) in bracket_pairs(sequence, classes, brackets) {
+        let mut found_embedding = false;
+        let mut found_opposite = false;
+        for &i in &indices[open + 1..close] {
+            match strong_direction(classes[i]) {
+                Some(direction) if direction == embedding => found_embedding = true,
+                Some(_) => found_opposite = true,
+                None => {}
+            }
+        }
+        let resolved = if found_embedding {
+            embedding
+        } else if found_opposite {
+            let context = indices[..open]
+                .iter()
+                .rev()
+                .find_map(|&i| strong_direction(classes[i]))
+                .unwrap_or(sequence.sos);
+            if context != embedding {
+                context
+            } else {
+                embedding
+            }
+        } else {
+            continue;
+        };
+        for &bracket in &[open, close] {
+            classes[indices[bracket]] = resolved;
+            for &i in indices[bracket + 1..]
+                .iter()
+                .take_while(|&&i| original_classes[i] == NonspacingMark)
+            {
+                classes[i] = resolved;
+            }
+        }
+    }
+
+    // N1 and N2
+    let mut k = 0;
+    while k < indices.len() {
+        if !is_neutral_or_isolate(classes[indices[k]]) {
+            k += 1;
+            continue;
+        }
+        let start = k;
+        while k < indices.len() && is_neutral_or_isolate(classes[indices[k]]) {
+            k += 1;
+        }
+        let before = if start == 0 {
+            sequence.sos
+        } else {
+            strong_direction(classes[indices[start - 1]]).unwrap_or(embedding)
+        };
+        let after = if k == indices.len() {
+            sequence.eos
+        } else {
+            strong_direction(classes[indices[k]]).unwrap_or(embedding)
+        };
+        let resolved = if before == after { before } else { embedding };
+        for &i in &indices[start..k] {
+            classes[i] = resolved;
+        }
+    }
+}
+
+/// I1 and I2.
+pub(crate) fn resolve_levels(
+    sequence: &IsolatingRunSequence,
+    classes: &[BidiClass],
+    levels: &mut [Level],
+) {
+    for &i in &sequence.indices {
+        let level = &mut levels[i];
+        match (level.is_rtl(), classes[i]) {
+            (false, RightToLeft) => level.raise(1),
+            (false, ArabicNumber) | (false, EuropeanNumber) => level.raise(2),
+            (true, LeftToRight) | (true, ArabicNumber) | (true, EuropeanNumber) => level.raise(1),
+            _ => {}
+        }
+    }
+}
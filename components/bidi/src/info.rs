@@ -0,0 +1,314 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Running the algorithm over text, see [`BidiInfo`].
+
+use crate::explicit;
+use crate::implicit::{self, Bracket};
+use crate::level::Level;
+use crate::prepare;
+use crate::properties::BidiProperties;
+use crate::reorder::{reorder_visual, reset_whitespace_levels};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+use icu_uniset::enum_props::{BidiClass, BidiPairedBracketType};
+
+/// A paragraph of a [`BidiInfo`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParagraphInfo {
+    /// The byte range of the paragraph in the text, including its paragraph separator.
+    pub range: Range<usize>,
+    /// The paragraph embedding level.
+    pub level: Level,
+}
+
+/// The embedding levels of a text, resolved by the Unicode Bidirectional Algorithm, from which
+/// lines of the text can be reordered for display.
+///
+/// The text is split into paragraphs after each paragraph separator. Levels are resolved for
+/// each paragraph up to the rules that depend on line breaks; the methods taking a line apply
+/// them to a line of a paragraph.
+///
+/// # Examples
+///
+/// ```
+/// use icu_bidi::{BidiInfo, BidiProperties, Level};
+///
+/// let provider = icu_provider_uprops::BidiDataProvider::new(
+///     icu_testdata::paths::data_root().join("uprops"),
+/// );
+/// let properties = BidiProperties::try_new(&provider).expect("Data should load successfully");
+///
+/// let text = "abc אבג (דה)";
+/// let info = BidiInfo::new(&properties, text, None);
+///
+/// let paragraph = &info.paragraphs()[0];
+/// assert_eq!(paragraph.level, Level::ltr());
+/// assert_eq!(
+///     info.reordered_line(paragraph, paragraph.range.clone()),
+///     "abc (הד) גבא"
+/// );
+/// ```
+pub struct BidiInfo<'a, 'data> {
+    properties: &'a BidiProperties<'data>,
+    text: &'a str,
+    /// The Bidi_Class of the character at each byte.
+    original_classes: Vec<BidiClass>,
+    /// The level of the character at each byte.
+    levels: Vec<Level>,
+    paragraphs: Vec<ParagraphInfo>,
+}
+
+impl<'a, 'data> BidiInfo<'a, 'data> {
+    /// Resolves the levels of `text`.
+    ///
+    /// The level of each paragraph is `default_para_level` if given, and otherwise determined by
+    /// its first strong character, left-to-right if there is none.
+    pub fn new(
+        properties: &'a BidiProperties<'data>,
+        text: &'a str,
+        default_para_level: Option<Level>,
+    ) -> Self {
+        let mut classes = Vec::new();
+        let mut brackets = Vec::new();
+        for c in text.chars() {
+            classes.push(properties.bidi_class(c));
+            brackets.push(match properties.paired_bracket_type(c) {
+                BidiPairedBracketType::Open => Some(Bracket::Open(Bracket::canonical(c))),
+                BidiPairedBracketType::Close => properties
+                    .paired_bracket(c)
+                    .map(|open| Bracket::Close(Bracket::canonical(open))),
+                BidiPairedBracketType::None => None,
+            });
+        }
+
+        let mut chars = text.chars();
+        let mut original_classes = Vec::with_capacity(text.len());
+        let mut levels = Vec::with_capacity(text.len());
+        let mut paragraphs = Vec::new();
+        for range in paragraph_ranges(&classes) {
+            let para_classes = &classes[range.clone()];
+            let level = paragraph_level(para_classes, default_para_level);
+            let para_levels = resolve_paragraph(para_classes, &brackets[range], level);
+            let start = levels.len();
+            for (&class, &char_level) in para_classes.iter().zip(para_levels.iter()) {
+                let len = chars.next().map_or(0, char::len_utf8);
+                original_classes.resize(original_classes.len() + len, class);
+                levels.resize(levels.len() + len, char_level);
+            }
+            paragraphs.push(ParagraphInfo {
+                range: start..levels.len(),
+                level,
+            });
+        }
+
+        Self {
+            properties,
+            text,
+            original_classes,
+            levels,
+            paragraphs,
+        }
+    }
+
+    /// The text the levels were resolved for.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The paragraphs of the text.
+    pub fn paragraphs(&self) -> &[ParagraphInfo] {
+        &self.paragraphs
+    }
+
+    /// The level of the character at each byte of the text, before the line-based rules.
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
+    /// Returns `true` if any of the text is at a right-to-left level.
+    pub fn has_rtl(&self) -> bool {
+        self.levels.iter().any(|level| level.is_rtl())
+    }
+
+    /// Returns the levels of the character at each byte of `line`, with the line-based rules
+    /// applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line` is not a range of character boundaries within `paragraph`.
+    pub fn reordered_levels(&self, paragraph: &ParagraphInfo, line: Range<usize>) -> Vec<Level> {
+        let (starts, char_levels) = self.line_levels(paragraph, line.clone());
+        let mut levels = Vec::with_capacity(line.len());
+        for (k, &level) in char_levels.iter().enumerate() {
+            let end = starts.get(k + 1).copied().unwrap_or(line.end);
+            levels.resize(levels.len() + end - starts[k], level);
+        }
+        levels
+    }
+
+    /// Returns the byte ranges of the runs of `line` in visual order, each with its level.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line` is not a range of character boundaries within `paragraph`.
+    pub fn visual_runs(
+        &self,
+        paragraph: &ParagraphInfo,
+        line: Range<usize>,
+    ) -> Vec<(Range<usize>, Level)> {
+        let (starts, levels) = self.line_levels(paragraph, line.clone());
+        let order = reorder_visual(&levels);
+        let byte_range = |first: usize, last: usize| {
+            starts[first]..starts.get(last + 1).copied().unwrap_or(line.end)
+        };
+
+        let mut runs = Vec::new();
+        let mut k = 0;
+        while k < order.len() {
+            let (start, level) = (order[k], levels[order[k]]);
+            let mut end = start;
+            while let Some(&next) = order.get(k + 1) {
+                let adjacent = if level.is_rtl() {
+                    next + 1 == end
+                } else {
+                    next == end + 1
+                };
+                if !adjacent || levels[next] != level {
+                    break;
+                }
+                end = next;
+                k += 1;
+            }
+            k += 1;
+            runs.push(if level.is_rtl() {
+                (byte_range(end, start), level)
+            } else {
+                (byte_range(start, end), level)
+            });
+        }
+        runs
+    }
+
+    /// Returns `line` in visual order, with the characters of right-to-left runs replaced by
+    /// their mirroring glyph.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line` is not a range of character boundaries within `paragraph`.
+    pub fn reordered_line(&self, paragraph: &ParagraphInfo, line: Range<usize>) -> String {
+        let mut reordered = String::with_capacity(line.len());
+        for (range, level) in self.visual_runs(paragraph, line) {
+            if level.is_rtl() {
+                reordered.extend(
+                    self.text[range]
+                        .chars()
+                        .rev()
+                        .map(|c| self.properties.mirroring_glyph(c).unwrap_or(c)),
+                );
+            } else {
+                reordered.push_str(&self.text[range]);
+            }
+        }
+        reordered
+    }
+
+    /// Returns the byte offset and the level of each character of `line`, with L1 applied.
+    fn line_levels(
+        &self,
+        paragraph: &ParagraphInfo,
+        line: Range<usize>,
+    ) -> (Vec<usize>, Vec<Level>) {
+        assert!(paragraph.range.start <= line.start && line.end <= paragraph.range.end);
+        let mut starts = Vec::new();
+        let mut classes = Vec::new();
+        let mut levels = Vec::new();
+        for (i, _) in self.text[line.clone()].char_indices() {
+            let i = line.start + i;
+            starts.push(i);
+            classes.push(self.original_classes[i]);
+            levels.push(self.levels[i]);
+        }
+        reset_whitespace_levels(&classes, &mut levels, paragraph.level);
+        (starts, levels)
+    }
+}
+
+/// Resolves the levels of text given by the Bidi_Class of each of its characters.
+///
+/// The text is split into paragraphs after each paragraph separator, and each paragraph is
+/// treated as a single line. As the characters themselves are not known, no brackets are
+/// paired by rule N0.
+///
+/// # Examples
+///
+/// ```
+/// use icu_bidi::{resolve_levels, Level};
+/// use icu_uniset::enum_props::BidiClass;
+///
+/// let classes = [
+///     BidiClass::LeftToRight,
+///     BidiClass::WhiteSpace,
+///     BidiClass::RightToLeft,
+///     BidiClass::EuropeanNumber,
+/// ];
+/// let levels: Vec<u8> = resolve_levels(&classes, None)
+///     .into_iter()
+///     .map(Level::number)
+///     .collect();
+/// assert_eq!(levels, [0, 0, 1, 2]);
+/// ```
+pub fn resolve_levels(classes: &[BidiClass], default_para_level: Option<Level>) -> Vec<Level> {
+    let mut levels = Vec::with_capacity(classes.len());
+    for range in paragraph_ranges(classes) {
+        let classes = &classes[range];
+        let level = paragraph_level(classes, default_para_level);
+        let mut para_levels = resolve_paragraph(classes, &vec![None; classes.len()], level);
+        reset_whitespace_levels(classes, &mut para_levels, level);
+        levels.extend(para_levels);
+    }
+    levels
+}
+
+/// Splits text into paragraphs after each paragraph separator (P1).
+fn paragraph_ranges(classes: &[BidiClass]) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    for (i, &class) in classes.iter().enumerate() {
+        if class == BidiClass::ParagraphSeparator {
+            ranges.push(start..i + 1);
+            start = i + 1;
+        }
+    }
+    if start < classes.len() {
+        ranges.push(start..classes.len());
+    }
+    ranges
+}
+
+/// P2 and P3.
+fn paragraph_level(classes: &[BidiClass], default_para_level: Option<Level>) -> Level {
+    default_para_level
+        .unwrap_or_else(|| explicit::first_strong_level(classes, false).unwrap_or_else(Level::ltr))
+}
+
+/// Resolves the levels of a paragraph up to the line-based rules.
+fn resolve_paragraph(
+    classes: &[BidiClass],
+    brackets: &[Option<Bracket>],
+    para_level: Level,
+) -> Vec<Level> {
+    let mut levels = vec![para_level; classes.len()];
+    let mut processing_classes = classes.to_vec();
+    explicit::compute(classes, para_level, &mut levels, &mut processing_classes);
+    for sequence in prepare::isolating_run_sequences(classes, &levels, para_level) {
+        implicit::resolve_weak(&sequence, &mut processing_classes);
+        implicit::resolve_neutral(&sequence, classes, brackets, &mut processing_classes);
+        implicit::resolve_levels(&sequence, &processing_classes, &mut levels);
+    }
+    prepare::assign_removed_levels(classes, &mut levels, para_level);
+    levels
+}
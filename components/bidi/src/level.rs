@@ -0,0 +1,87 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Embedding levels, see [`Level`].
+
+/// An embedding level of the Unicode Bidirectional Algorithm.
+///
+/// Text at even levels is displayed left-to-right, and text at odd levels right-to-left. Explicit
+/// embeddings can nest up to [`Level::MAX_DEPTH`], and the implicit rules can raise a level one
+/// or two above that.
+///
+/// # Examples
+///
+/// ```
+/// use icu_bidi::Level;
+///
+/// assert!(Level::ltr().is_ltr());
+/// assert!(Level::rtl().is_rtl());
+/// assert_eq!(Level::new(4).map(Level::is_ltr), Some(true));
+/// assert_eq!(Level::new(200), None);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Level(u8);
+
+impl Level {
+    /// The maximum explicit embedding level.
+    pub const MAX_DEPTH: u8 = 125;
+
+    /// The maximum level after the implicit rules.
+    const MAX_IMPLICIT_DEPTH: u8 = Self::MAX_DEPTH + 1;
+
+    /// Creates a level from its number, if it is at most one above [`Level::MAX_DEPTH`].
+    pub fn new(number: u8) -> Option<Self> {
+        if number <= Self::MAX_IMPLICIT_DEPTH {
+            Some(Self(number))
+        } else {
+            None
+        }
+    }
+
+    /// The left-to-right paragraph level, 0.
+    pub const fn ltr() -> Self {
+        Self(0)
+    }
+
+    /// The right-to-left paragraph level, 1.
+    pub const fn rtl() -> Self {
+        Self(1)
+    }
+
+    /// Returns the number of this level.
+    pub fn number(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if text at this level is displayed left-to-right.
+    pub fn is_ltr(self) -> bool {
+        self.0 & 1 == 0
+    }
+
+    /// Returns `true` if text at this level is displayed right-to-left.
+    pub fn is_rtl(self) -> bool {
+        self.0 & 1 == 1
+    }
+
+    /// The least odd level greater than this one, or `None` above [`Level::MAX_DEPTH`].
+    pub(crate) fn next_rtl(self) -> Option<Self> {
+        Some(Self((self.0 + 1) | 1)).filter(|level| level.0 <= Self::MAX_DEPTH)
+    }
+
+    /// The least even level greater than this one, or `None` above [`Level::MAX_DEPTH`].
+    pub(crate) fn next_ltr(self) -> Option<Self> {
+        Some(Self((self.0 + 2) & !1)).filter(|level| level.0 <= Self::MAX_DEPTH)
+    }
+
+    /// Raises this level by `amount` for the implicit rules.
+    pub(crate) fn raise(&mut self, amount: u8) {
+        self.0 = (self.0 + amount).min(Self::MAX_IMPLICIT_DEPTH);
+    }
+}
+
+impl From<Level> for u8 {
+    fn from(level: Level) -> u8 {
+        level.0
+    }
+}
@@ -0,0 +1,69 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_bidi`](crate) is an implementation of the Unicode Bidirectional Algorithm, as
+//! specified in [Unicode Standard Annex #9][UAX9].
+//!
+//! It determines the direction of paragraphs, resolves the embedding level of each character,
+//! and reorders lines of text for display. The Bidi_Class, Bidi_Paired_Bracket_Type,
+//! Bidi_Paired_Bracket and Bidi_Mirroring_Glyph properties it needs are loaded from a data
+//! provider into [`BidiProperties`].
+//!
+//! [UAX9]: https://www.unicode.org/reports/tr9/
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_bidi::{BidiInfo, BidiProperties, Level};
+//!
+//! let provider = icu_provider_uprops::BidiDataProvider::new(
+//!     icu_testdata::paths::data_root().join("uprops"),
+//! );
+//! let properties = BidiProperties::try_new(&provider).expect("Data should load successfully");
+//!
+//! // A left-to-right paragraph, followed by a right-to-left one
+//! let text = "abc אבג\nאבג abc";
+//! let info = BidiInfo::new(&properties, text, None);
+//!
+//! let paragraphs = info.paragraphs();
+//! assert_eq!(paragraphs.len(), 2);
+//! assert_eq!(paragraphs[0].level, Level::ltr());
+//! assert_eq!(paragraphs[1].level, Level::rtl());
+//!
+//! // Each paragraph is displayed as a single line
+//! let lines: Vec<String> = paragraphs
+//!     .iter()
+//!     .map(|paragraph| {
+//!         let line = paragraph.range.clone();
+//!         info.reordered_line(paragraph, line)
+//!     })
+//!     .collect();
+//! assert_eq!(lines, ["abc גבא\n", "abc גבא"]);
+//! ```
+//!
+//! # Conformance tests
+//!
+//! The conformance tests run every case of `tests/testdata/BidiTest.txt` and
+//! `tests/testdata/BidiCharacterTest.txt`, which are copies of the files of the same name in the
+//! Unicode Character Database.
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+pub mod error;
+mod explicit;
+mod implicit;
+mod info;
+mod level;
+mod prepare;
+mod properties;
+pub mod provider;
+mod reorder;
+
+pub use error::Error as BidiError;
+pub use info::{resolve_levels, BidiInfo, ParagraphInfo};
+pub use level::Level;
+pub use properties::BidiProperties;
+pub use reorder::reorder_visual;
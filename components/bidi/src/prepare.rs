@@ -0,0 +1,143 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Preparing for the implicit rules: rules X9 and X10 of
+//! [UAX #9](https://www.unicode.org/reports/tr9/#Preparations_for_Implicit_Processing).
+
+use crate::level::Level;
+use alloc::vec;
+use alloc::vec::Vec;
+use icu_uniset::enum_props::BidiClass::{self, *};
+
+/// Whether characters of this class are removed by X9.
+pub(crate) fn removed_by_x9(class: BidiClass) -> bool {
+    matches!(
+        class,
+        RightToLeftEmbedding
+            | LeftToRightEmbedding
+            | RightToLeftOverride
+            | LeftToRightOverride
+            | PopDirectionalFormat
+            | BoundaryNeutral
+    )
+}
+
+pub(crate) fn is_isolate_initiator(class: BidiClass) -> bool {
+    matches!(
+        class,
+        RightToLeftIsolate | LeftToRightIsolate | FirstStrongIsolate
+    )
+}
+
+/// An isolating run sequence (BD13), the unit the implicit rules are applied to.
+pub(crate) struct IsolatingRunSequence {
+    /// The characters of the sequence in order, without those removed by X9.
+    pub indices: Vec<usize>,
+    pub level: Level,
+    /// The start-of-sequence type, `LeftToRight` or `RightToLeft`.
+    pub sos: BidiClass,
+    /// The end-of-sequence type, `LeftToRight` or `RightToLeft`.
+    pub eos: BidiClass,
+}
+
+/// Gives the characters removed by X9 the level of the preceding character, so that they don't
+/// split the text displayed around them into separate runs.
+pub(crate) fn assign_removed_levels(
+    classes: &[BidiClass],
+    levels: &mut [Level],
+    para_level: Level,
+) {
+    let mut previous = para_level;
+    for (&class, level) in classes.iter().zip(levels.iter_mut()) {
+        if removed_by_x9(class) {
+            *level = previous;
+        } else {
+            previous = *level;
+        }
+    }
+}
+
+/// Computes the isolating run sequences of a paragraph (X10), given its original classes and
+/// explicit levels.
+pub(crate) fn isolating_run_sequences(
+    classes: &[BidiClass],
+    levels: &[Level],
+    para_level: Level,
+) -> Vec<IsolatingRunSequence> {
+    // BD9: the PDI matching each isolate initiator
+    let mut matching_pdi = vec![None; classes.len()];
+    let mut open_isolates = Vec::new();
+    for (i, &class) in classes.iter().enumerate() {
+        if is_isolate_initiator(class) {
+            open_isolates.push(i);
+        } else if class == PopDirectionalIsolate {
+            if let Some(initiator) = open_isolates.pop() {
+                matching_pdi[initiator] = Some(i);
+            }
+        }
+    }
+
+    // BD7: level runs, ignoring the characters removed by X9
+    let mut runs: Vec<Vec<usize>> = Vec::new();
+    let mut run_of = vec![0; classes.len()];
+    let mut current_level = None;
+    for (i, &class) in classes.iter().enumerate() {
+        if removed_by_x9(class) {
+            continue;
+        }
+        if current_level != Some(levels[i]) {
+            runs.push(Vec::new());
+            current_level = Some(levels[i]);
+        }
+        runs.last_mut().expect("A run was just pushed").push(i);
+        run_of[i] = runs.len() - 1;
+    }
+
+    let mut appended = vec![false; runs.len()];
+    let mut sequences = Vec::new();
+    for run in 0..runs.len() {
+        if appended[run] {
+            continue;
+        }
+        let mut indices = runs[run].clone();
+        while let Some(pdi) = indices.last().and_then(|&last| matching_pdi[last]) {
+            let pdi_run = run_of[pdi];
+            if pdi_run == run || appended[pdi_run] || runs[pdi_run][0] != pdi {
+                break;
+            }
+            appended[pdi_run] = true;
+            indices.extend_from_slice(&runs[pdi_run]);
+        }
+
+        let first = indices[0];
+        let last = *indices.last().expect("Runs are not empty");
+        let level = levels[first];
+        let preceding = classes[..first]
+            .iter()
+            .rposition(|&class| !removed_by_x9(class))
+            .map_or(para_level, |i| levels[i]);
+        let following = if is_isolate_initiator(classes[last]) {
+            para_level
+        } else {
+            classes[last + 1..]
+                .iter()
+                .position(|&class| !removed_by_x9(class))
+                .map_or(para_level, |i| levels[last + 1 + i])
+        };
+        let direction = |other: Level| {
+            if level.max(other).is_rtl() {
+                RightToLeft
+            } else {
+                LeftToRight
+            }
+        };
+        sequences.push(IsolatingRunSequence {
+            sos: direction(preceding),
+            eos: direction(following),
+            indices,
+            level,
+        });
+    }
+    sequences
+}
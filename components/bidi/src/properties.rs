@@ -0,0 +1,161 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The character properties used by the algorithm, see [`BidiProperties`].
+
+use crate::error::Error;
+use crate::provider::{self, BidiMirroringV1Marker};
+use alloc::vec::Vec;
+use icu_provider::prelude::*;
+use icu_uniset::enum_props::{BidiClass, BidiPairedBracketType};
+use icu_uniset::props;
+use icu_uniset::provider::UnicodePropertyV1Marker;
+
+const ALL_BIDI_CLASSES: [BidiClass; 23] = [
+    BidiClass::LeftToRight,
+    BidiClass::RightToLeft,
+    BidiClass::EuropeanNumber,
+    BidiClass::EuropeanSeparator,
+    BidiClass::EuropeanTerminator,
+    BidiClass::ArabicNumber,
+    BidiClass::CommonSeparator,
+    BidiClass::ParagraphSeparator,
+    BidiClass::SegmentSeparator,
+    BidiClass::WhiteSpace,
+    BidiClass::OtherNeutral,
+    BidiClass::LeftToRightEmbedding,
+    BidiClass::LeftToRightOverride,
+    BidiClass::ArabicLetter,
+    BidiClass::RightToLeftEmbedding,
+    BidiClass::RightToLeftOverride,
+    BidiClass::PopDirectionalFormat,
+    BidiClass::NonspacingMark,
+    BidiClass::BoundaryNeutral,
+    BidiClass::FirstStrongIsolate,
+    BidiClass::LeftToRightIsolate,
+    BidiClass::RightToLeftIsolate,
+    BidiClass::PopDirectionalIsolate,
+];
+
+/// The Unicode properties that the Bidirectional Algorithm depends on: Bidi_Class,
+/// Bidi_Paired_Bracket_Type, Bidi_Paired_Bracket and Bidi_Mirroring_Glyph.
+///
+/// # Examples
+///
+/// ```
+/// use icu_bidi::BidiProperties;
+/// use icu_uniset::enum_props::{BidiClass, BidiPairedBracketType};
+///
+/// let provider = icu_provider_uprops::BidiDataProvider::new(
+///     icu_testdata::paths::data_root().join("uprops"),
+/// );
+/// let properties = BidiProperties::try_new(&provider).expect("Data should load successfully");
+///
+/// assert_eq!(properties.bidi_class('a'), BidiClass::LeftToRight);
+/// assert_eq!(properties.bidi_class('א'), BidiClass::RightToLeft);
+/// assert_eq!(
+///     properties.paired_bracket_type('('),
+///     BidiPairedBracketType::Open
+/// );
+/// assert_eq!(properties.paired_bracket('('), Some(')'));
+/// assert_eq!(properties.mirroring_glyph('<'), Some('>'));
+/// ```
+pub struct BidiProperties<'data> {
+    /// Disjoint code point ranges with their Bidi_Class, in code point order.
+    classes: Vec<(u32, u32, BidiClass)>,
+    open_brackets: DataPayload<'data, UnicodePropertyV1Marker>,
+    close_brackets: DataPayload<'data, UnicodePropertyV1Marker>,
+    mirroring: DataPayload<'data, BidiMirroringV1Marker>,
+}
+
+impl<'data> BidiProperties<'data> {
+    /// Loads the properties from a data provider.
+    pub fn try_new<D>(provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, UnicodePropertyV1Marker>
+            + DataProvider<'data, BidiMirroringV1Marker>
+            + ?Sized,
+    {
+        let mut classes = Vec::new();
+        for &class in ALL_BIDI_CLASSES.iter() {
+            let set = props::get_bidi_class_val_set(provider, class)?;
+            classes.extend(
+                set.get()
+                    .inv_list
+                    .iter_ranges()
+                    .map(|range| (*range.start(), *range.end(), class)),
+            );
+        }
+        classes.sort_unstable_by_key(|&(start, _, _)| start);
+
+        let open_brackets =
+            props::get_bidi_paired_bracket_type_val_set(provider, BidiPairedBracketType::Open)?;
+        let close_brackets =
+            props::get_bidi_paired_bracket_type_val_set(provider, BidiPairedBracketType::Close)?;
+
+        let mirroring: DataPayload<BidiMirroringV1Marker> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: provider::key::MIRRORING_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: None,
+                    },
+                },
+            })?
+            .take_payload()?;
+
+        Ok(Self {
+            classes,
+            open_brackets,
+            close_brackets,
+            mirroring,
+        })
+    }
+
+    /// Returns the Bidi_Class of `c`.
+    ///
+    /// Code points missing from the data are treated as [`BidiClass::LeftToRight`].
+    pub fn bidi_class(&self, c: char) -> BidiClass {
+        let c = c as u32;
+        match self
+            .classes
+            .binary_search_by_key(&c, |&(start, _, _)| start)
+        {
+            Ok(i) => self.classes[i].2,
+            Err(0) => BidiClass::LeftToRight,
+            Err(i) => {
+                let (_, end, class) = self.classes[i - 1];
+                if c <= end {
+                    class
+                } else {
+                    BidiClass::LeftToRight
+                }
+            }
+        }
+    }
+
+    /// Returns the Bidi_Paired_Bracket_Type of `c`.
+    pub fn paired_bracket_type(&self, c: char) -> BidiPairedBracketType {
+        if self.open_brackets.get().inv_list.contains(c) {
+            BidiPairedBracketType::Open
+        } else if self.close_brackets.get().inv_list.contains(c) {
+            BidiPairedBracketType::Close
+        } else {
+            BidiPairedBracketType::None
+        }
+    }
+
+    /// Returns the Bidi_Paired_Bracket of `c`: the closing bracket of an opening bracket, and
+    /// the opening bracket of a closing one.
+    pub fn paired_bracket(&self, c: char) -> Option<char> {
+        self.mirroring.get().paired_bracket(c)
+    }
+
+    /// Returns the Bidi_Mirroring_Glyph of `c`: the character whose glyph is the mirror image
+    /// of that of `c`, used to display it in right-to-left text.
+    pub fn mirroring_glyph(&self, c: char) -> Option<char> {
+        self.mirroring.get().mirroring_glyph(c)
+    }
+}
@@ -0,0 +1,67 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+//!
+//! The Bidi_Class and Bidi_Paired_Bracket_Type properties are loaded as
+//! [`UnicodePropertyV1`](icu_uniset::provider::UnicodePropertyV1) sets, one per value; see
+//! [`BidiProperties`](crate::BidiProperties).
+
+use icu_provider::yoke::{self, *};
+use zerovec::ZeroVec;
+
+pub mod key {
+    //! Resource keys for [`icu_bidi`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: the Bidi_Mirroring_Glyph and Bidi_Paired_Bracket properties.
+    pub const MIRRORING_V1: ResourceKey = resource_key!(Bidi, "mirroring", 1);
+}
+
+/// The code point mappings of the Bidi_Mirroring_Glyph and Bidi_Paired_Bracket properties.
+///
+/// Each mapping is stored as two parallel vectors: the characters that have a value for the
+/// property, sorted by code point, and the value for each of them.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct BidiMirroringV1<'data> {
+    /// Characters with a Bidi_Mirroring_Glyph, in code point order.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub mirrored: ZeroVec<'data, char>,
+
+    /// The Bidi_Mirroring_Glyph of each character in `mirrored`.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub mirroring_glyphs: ZeroVec<'data, char>,
+
+    /// Characters with a Bidi_Paired_Bracket, in code point order.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub brackets: ZeroVec<'data, char>,
+
+    /// The Bidi_Paired_Bracket of each character in `brackets`.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub paired_brackets: ZeroVec<'data, char>,
+}
+
+impl BidiMirroringV1<'_> {
+    /// Returns the Bidi_Mirroring_Glyph of `c`, if it has one.
+    pub fn mirroring_glyph(&self, c: char) -> Option<char> {
+        lookup(&self.mirrored, &self.mirroring_glyphs, c)
+    }
+
+    /// Returns the Bidi_Paired_Bracket of `c`, if it is a paired bracket.
+    pub fn paired_bracket(&self, c: char) -> Option<char> {
+        lookup(&self.brackets, &self.paired_brackets, c)
+    }
+}
+
+fn lookup(keys: &ZeroVec<char>, values: &ZeroVec<char>, c: char) -> Option<char> {
+    keys.binary_search(&c).ok().and_then(|i| values.get(i))
+}
@@ -0,0 +1,84 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Reordering resolved levels by line: rules L1 and L2 of
+//! [UAX #9](https://www.unicode.org/reports/tr9/#Reordering_Resolved_Levels).
+
+use crate::level::Level;
+use crate::prepare::{is_isolate_initiator, removed_by_x9};
+use alloc::vec::Vec;
+use icu_uniset::enum_props::BidiClass::{self, *};
+
+/// Resets segment separators, paragraph separators and trailing whitespace of a line to the
+/// paragraph level (L1). `classes` are the original classes of the characters of the line.
+pub(crate) fn reset_whitespace_levels(
+    classes: &[BidiClass],
+    levels: &mut [Level],
+    para_level: Level,
+) {
+    let mut reset = true;
+    for (&class, level) in classes.iter().zip(levels.iter_mut()).rev() {
+        match class {
+            SegmentSeparator | ParagraphSeparator => {
+                *level = para_level;
+                reset = true;
+            }
+            WhiteSpace | PopDirectionalIsolate => {
+                if reset {
+                    *level = para_level;
+                }
+            }
+            _ if is_isolate_initiator(class) || removed_by_x9(class) => {
+                if reset {
+                    *level = para_level;
+                }
+            }
+            _ => reset = false,
+        }
+    }
+}
+
+/// Returns the visual order of the characters of a line from their levels (L2): the element at
+/// each visual position is the logical index of the character displayed there.
+///
+/// The levels should be the ones of a single line, after trailing whitespace has been reset as
+/// done by [`BidiInfo::reordered_levels`](crate::BidiInfo::reordered_levels).
+///
+/// # Examples
+///
+/// ```
+/// use icu_bidi::{reorder_visual, Level};
+///
+/// let levels: Vec<Level> = [0, 0, 1, 1, 2, 1, 0]
+///     .iter()
+///     .map(|&n| Level::new(n).expect("Valid level"))
+///     .collect();
+/// assert_eq!(reorder_visual(&levels), [0, 1, 5, 4, 3, 2, 6]);
+/// ```
+pub fn reorder_visual(levels: &[Level]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..levels.len()).collect();
+    let max_level = match levels.iter().max() {
+        Some(&level) => level.number(),
+        None => return order,
+    };
+    let min_odd_level = match levels.iter().filter(|level| level.is_rtl()).min() {
+        Some(&level) => level.number(),
+        None => return order,
+    };
+    for level in (min_odd_level..=max_level).rev() {
+        let mut k = 0;
+        while k < order.len() {
+            if levels[order[k]].number() < level {
+                k += 1;
+                continue;
+            }
+            let start = k;
+            while k < order.len() && levels[order[k]].number() >= level {
+                k += 1;
+            }
+            order[start..k].reverse();
+        }
+    }
+    order
+}
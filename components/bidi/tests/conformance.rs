@@ -0,0 +1,193 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_bidi::{reorder_visual, resolve_levels, BidiInfo, BidiProperties, Level};
+use icu_provider_uprops::BidiDataProvider;
+use icu_uniset::enum_props::BidiClass;
+use std::char;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+
+fn bidi_class(name: &str) -> BidiClass {
+    match name {
+        "L" => BidiClass::LeftToRight,
+        "R" => BidiClass::RightToLeft,
+        "EN" => BidiClass::EuropeanNumber,
+        "ES" => BidiClass::EuropeanSeparator,
+        "ET" => BidiClass::EuropeanTerminator,
+        "AN" => BidiClass::ArabicNumber,
+        "CS" => BidiClass::CommonSeparator,
+        "B" => BidiClass::ParagraphSeparator,
+        "S" => BidiClass::SegmentSeparator,
+        "WS" => BidiClass::WhiteSpace,
+        "ON" => BidiClass::OtherNeutral,
+        "LRE" => BidiClass::LeftToRightEmbedding,
+        "LRO" => BidiClass::LeftToRightOverride,
+        "AL" => BidiClass::ArabicLetter,
+        "RLE" => BidiClass::RightToLeftEmbedding,
+        "RLO" => BidiClass::RightToLeftOverride,
+        "PDF" => BidiClass::PopDirectionalFormat,
+        "NSM" => BidiClass::NonspacingMark,
+        "BN" => BidiClass::BoundaryNeutral,
+        "FSI" => BidiClass::FirstStrongIsolate,
+        "LRI" => BidiClass::LeftToRightIsolate,
+        "RLI" => BidiClass::RightToLeftIsolate,
+        "PDI" => BidiClass::PopDirectionalIsolate,
+        _ => panic!("Unknown Bidi_Class {}", name),
+    }
+}
+
+/// Parses a list of levels, where `x` marks a character removed by rule X9.
+fn parse_levels(levels: &str) -> Vec<Option<u8>> {
+    levels
+        .split_ascii_whitespace()
+        .map(|level| level.parse().ok())
+        .collect()
+}
+
+fn parse_order(order: &str) -> Vec<usize> {
+    order
+        .split_ascii_whitespace()
+        .map(|i| i.parse().expect("Valid index"))
+        .collect()
+}
+
+/// Returns the levels where the expected ones are not `x`, and the visual order of those
+/// characters when each paragraph, given by the lengths in `paragraphs`, is a line.
+fn actual_levels_and_order(
+    levels: &[Level],
+    paragraphs: impl Iterator<Item = usize>,
+    expected: &[Option<u8>],
+) -> (Vec<Option<u8>>, Vec<usize>) {
+    let actual = levels
+        .iter()
+        .zip(expected)
+        .map(|(level, expected)| expected.and(Some(level.number())))
+        .collect();
+    let mut order = Vec::new();
+    let mut start = 0;
+    for len in paragraphs {
+        let line = reorder_visual(&levels[start..start + len]);
+        order.extend(line.into_iter().map(|i| start + i));
+        start += len;
+    }
+    order.retain(|&i| expected[i].is_some());
+    (actual, order)
+}
+
+/// Returns the lengths of the paragraphs of `classes`, which end after each paragraph separator.
+fn paragraph_lengths(classes: &[BidiClass]) -> impl Iterator<Item = usize> + '_ {
+    classes
+        .split_inclusive(|&class| class == BidiClass::ParagraphSeparator)
+        .map(<[BidiClass]>::len)
+}
+
+#[test]
+fn run_bidi_test() {
+    let f = File::open("./tests/testdata/BidiTest.txt");
+    let f = BufReader::new(f.unwrap());
+    let mut expected_levels = Vec::new();
+    let mut expected_order = Vec::new();
+    let mut failures = Vec::new();
+    for line in f.lines() {
+        let line = line.unwrap();
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(levels) = line.strip_prefix("@Levels:") {
+            expected_levels = parse_levels(levels);
+            continue;
+        }
+        if let Some(order) = line.strip_prefix("@Reorder:") {
+            expected_order = parse_order(order);
+            continue;
+        }
+        if line.starts_with('@') {
+            continue;
+        }
+
+        let mut fields = line.split(';');
+        let class_names = fields.next().unwrap().trim();
+        let classes: Vec<BidiClass> = class_names
+            .split_ascii_whitespace()
+            .map(bidi_class)
+            .collect();
+        let bitset = u8::from_str_radix(fields.next().unwrap().trim(), 16).unwrap();
+        let para_levels = [(1, None), (2, Some(Level::ltr())), (4, Some(Level::rtl()))];
+        for &(bit, para_level) in para_levels.iter() {
+            if bitset & bit == 0 {
+                continue;
+            }
+            let levels = resolve_levels(&classes, para_level);
+            let (actual_levels, actual_order) =
+                actual_levels_and_order(&levels, paragraph_lengths(&classes), &expected_levels);
+            if actual_levels != expected_levels || actual_order != expected_order {
+                failures.push(format!(
+                    "{} ({:?}): levels {:?}, expected {:?}; order {:?}, expected {:?}",
+                    line, para_level, actual_levels, expected_levels, actual_order, expected_order
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{:#?}", failures);
+}
+
+#[test]
+fn run_bidi_character_test() {
+    let provider = BidiDataProvider::new(icu_testdata::paths::data_root().join("uprops"));
+    let properties = BidiProperties::try_new(&provider).expect("Data should load successfully");
+
+    let f = File::open("./tests/testdata/BidiCharacterTest.txt");
+    let f = BufReader::new(f.unwrap());
+    let mut failures = Vec::new();
+    for line in f.lines() {
+        let line = line.unwrap();
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(';').collect();
+        let text: String = fields[0]
+            .split_ascii_whitespace()
+            .map(|c| char::from_u32(u32::from_str_radix(c, 16).unwrap()).unwrap())
+            .collect();
+        let default_para_level = match fields[1] {
+            "0" => Some(Level::ltr()),
+            "1" => Some(Level::rtl()),
+            _ => None,
+        };
+        let expected_para_level: u8 = fields[2].parse().unwrap();
+        let expected_levels = parse_levels(fields[3]);
+        let expected_order = parse_order(fields[4]);
+
+        let info = BidiInfo::new(&properties, &text, default_para_level);
+        let mut levels = Vec::new();
+        let mut paragraphs = Vec::new();
+        for paragraph in info.paragraphs() {
+            let range = paragraph.range.clone();
+            let byte_levels = info.reordered_levels(paragraph, range.clone());
+            let paragraph_levels = text[range.clone()]
+                .char_indices()
+                .map(|(i, _)| byte_levels[i]);
+            levels.extend(paragraph_levels);
+            paragraphs.push(text[range].chars().count());
+        }
+        let (actual_levels, actual_order) =
+            actual_levels_and_order(&levels, paragraphs.into_iter(), &expected_levels);
+        let paragraph_level = info.paragraphs()[0].level.number();
+        if paragraph_level != expected_para_level
+            || actual_levels != expected_levels
+            || actual_order != expected_order
+        {
+            failures.push(format!(
+                "{}: paragraph level {}, levels {:?}, expected {:?}; order {:?}, expected {:?}",
+                line, paragraph_level, actual_levels, expected_levels, actual_order, expected_order
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "{:#?}", failures);
+}
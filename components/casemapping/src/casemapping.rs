@@ -0,0 +1,302 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Full case mapping and case folding of strings, see [`CaseMapping`].
+
+use crate::error::Error;
+use crate::provider::*;
+use alloc::string::String;
+use icu_locid::LanguageIdentifier;
+use icu_provider::prelude::*;
+
+/// The languages whose case mappings differ from those of the root locale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Tailoring {
+    Root,
+    /// Turkish and Azeri, which distinguish dotted and dotless "i".
+    Turkic,
+    /// Lithuanian, which keeps the dot of "i" under accents above.
+    Lithuanian,
+}
+
+/// Full case mappings and case folding of strings, as specified in chapter 3.13 of the
+/// [Unicode Standard], with the conditional mappings of `SpecialCasing.txt`.
+///
+/// The mappings of the root locale apply to all languages but Turkish, Azeri and Lithuanian,
+/// which are tailored when the mapper is created with [`CaseMapping::try_new_with_locale()`].
+///
+/// [Unicode Standard]: https://www.unicode.org/versions/latest/
+///
+/// # Examples
+///
+/// ```
+/// use icu_casemapping::CaseMapping;
+/// use icu_locid_macros::langid;
+///
+/// let provider = icu_provider_uprops::CaseMappingDataProvider::new(
+///     icu_testdata::paths::data_root().join("uprops"),
+/// );
+/// let root = CaseMapping::try_new(&provider).expect("Data should load successfully");
+/// let tr = CaseMapping::try_new_with_locale(&langid!("tr"), &provider)
+///     .expect("Data should load successfully");
+///
+/// assert_eq!(root.to_uppercase("straße"), "STRASSE");
+/// assert_eq!(root.to_lowercase("ΟΔΟΣ"), "οδος");
+/// assert_eq!(root.to_lowercase("DIŞ"), "diş");
+/// assert_eq!(tr.to_lowercase("DIŞ"), "dış");
+/// assert_eq!(tr.to_uppercase("istanbul"), "İSTANBUL");
+/// ```
+pub struct CaseMapping<'data> {
+    data: DataPayload<'data, CaseMappingV1Marker>,
+    tailoring: Tailoring,
+}
+
+impl<'data> CaseMapping<'data> {
+    /// Creates a case mapper with the mappings of the root locale.
+    pub fn try_new<D>(provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CaseMappingV1Marker> + ?Sized,
+    {
+        Self::try_new_with_tailoring(provider, Tailoring::Root)
+    }
+
+    /// Creates a case mapper with the mappings of the language of `langid`.
+    pub fn try_new_with_locale<D>(langid: &LanguageIdentifier, provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CaseMappingV1Marker> + ?Sized,
+    {
+        let tailoring = match langid.language.as_str() {
+            "tr" | "az" => Tailoring::Turkic,
+            "lt" => Tailoring::Lithuanian,
+            _ => Tailoring::Root,
+        };
+        Self::try_new_with_tailoring(provider, tailoring)
+    }
+
+    fn try_new_with_tailoring<D>(provider: &D, tailoring: Tailoring) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CaseMappingV1Marker> + ?Sized,
+    {
+        let data_req = DataRequest {
+            resource_path: ResourcePath {
+                key: key::CASE_MAPPING_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: None,
+                },
+            },
+        };
+        let resp: DataResponse<CaseMappingV1Marker> = provider.load_payload(&data_req)?;
+        Ok(CaseMapping {
+            data: resp.take_payload()?,
+            tailoring,
+        })
+    }
+
+    /// Returns the full lowercase mapping of `text`.
+    pub fn to_lowercase(&self, text: &str) -> String {
+        self.map(text, 0, text.len(), MappingKind::Lower)
+    }
+
+    /// Returns the full uppercase mapping of `text`.
+    pub fn to_uppercase(&self, text: &str) -> String {
+        self.map(text, 0, text.len(), MappingKind::Upper)
+    }
+
+    /// Returns the full case folding of `text`, for caseless matching.
+    pub fn fold(&self, text: &str) -> String {
+        self.map(text, 0, text.len(), MappingKind::Fold)
+    }
+
+    /// Returns the full titlecase mapping of `text`, with words starting at the byte indices
+    /// of `word_boundaries`, as found by a segmenter.
+    ///
+    /// In each word, the first letter, number, symbol or private use character is
+    /// titlecased, the characters before it are unchanged, and the characters after it are
+    /// lowercased. The end of `text` is always a word boundary, and indices that are not
+    /// in ascending order or not at a character boundary are ignored, so that an empty
+    /// iterator titlecases `text` as a single word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_casemapping::CaseMapping;
+    /// use icu_segmenter::LineBreakIterator;
+    ///
+    /// let provider = icu_provider_uprops::CaseMappingDataProvider::new(
+    ///     icu_testdata::paths::data_root().join("uprops"),
+    /// );
+    /// let cm = CaseMapping::try_new(&provider).expect("Data should load successfully");
+    ///
+    /// let text = "'tHE ǆUNGLE' book";
+    /// assert_eq!(cm.to_titlecase(text, LineBreakIterator::new(text)), "'The ǅungle' Book");
+    /// assert_eq!(cm.to_titlecase(text, std::iter::empty()), "'The ǆungle' book");
+    /// ```
+    pub fn to_titlecase<I>(&self, text: &str, word_boundaries: I) -> String
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let data = self.data.get();
+        let mut result = String::with_capacity(text.len());
+        let mut start = 0;
+        let boundaries = word_boundaries
+            .into_iter()
+            .filter(|&i| i < text.len() && text.is_char_boundary(i))
+            .chain(core::iter::once(text.len()));
+        for end in boundaries {
+            if end <= start {
+                continue;
+            }
+            let word = &text[start..end];
+            match word
+                .char_indices()
+                .find(|&(_, c)| data.get(c) & LETTER_NUMBER_SYMBOL != 0)
+            {
+                Some((i, c)) => {
+                    let title_start = start + i;
+                    let title_end = title_start + c.len_utf8();
+                    result.push_str(&text[start..title_start]);
+                    self.push_mapping(text, title_start, c, MappingKind::Title, &mut result);
+                    result.push_str(&self.map(text, title_end, end, MappingKind::Lower));
+                }
+                None => result.push_str(word),
+            }
+            start = end;
+        }
+        result
+    }
+
+    /// Returns the mapping of `text[start..end]`, with the rest of `text` as its context.
+    fn map(&self, text: &str, start: usize, end: usize, kind: MappingKind) -> String {
+        let mut result = String::with_capacity(end - start);
+        for (i, c) in text[start..end].char_indices() {
+            self.push_mapping(text, start + i, c, kind, &mut result);
+        }
+        result
+    }
+
+    /// Appends the full mapping of the character `c` at the byte index `i` of `text`.
+    fn push_mapping(&self, text: &str, i: usize, c: char, kind: MappingKind, out: &mut String) {
+        let context = Context {
+            data: self.data.get(),
+            before: &text[..i],
+            after: &text[i + c.len_utf8()..],
+        };
+        match (self.tailoring, kind, c) {
+            // Final_Sigma
+            (_, MappingKind::Lower, '\u{3A3}') if context.is_final_sigma() => {
+                return out.push('\u{3C2}');
+            }
+
+            (Tailoring::Turkic, MappingKind::Lower, '\u{130}') => return out.push('i'),
+            (Tailoring::Turkic, MappingKind::Lower, '\u{307}') if context.is_after_i() => {
+                return;
+            }
+            (Tailoring::Turkic, MappingKind::Lower, 'I') if !context.is_before_dot() => {
+                return out.push('\u{131}');
+            }
+            (Tailoring::Turkic, MappingKind::Upper | MappingKind::Title, 'i') => {
+                return out.push('\u{130}');
+            }
+            (Tailoring::Turkic, MappingKind::Fold, 'I') => return out.push('\u{131}'),
+            (Tailoring::Turkic, MappingKind::Fold, '\u{130}') => return out.push('i'),
+
+            (Tailoring::Lithuanian, MappingKind::Lower, 'I' | 'J' | '\u{12E}')
+                if context.is_more_above() =>
+            {
+                out.push_str(match c {
+                    'I' => "i\u{307}",
+                    'J' => "j\u{307}",
+                    _ => "\u{12F}\u{307}",
+                });
+                return;
+            }
+            (Tailoring::Lithuanian, MappingKind::Lower, '\u{CC}') => {
+                return out.push_str("i\u{307}\u{300}");
+            }
+            (Tailoring::Lithuanian, MappingKind::Lower, '\u{CD}') => {
+                return out.push_str("i\u{307}\u{301}");
+            }
+            (Tailoring::Lithuanian, MappingKind::Lower, '\u{128}') => {
+                return out.push_str("i\u{307}\u{303}");
+            }
+            (Tailoring::Lithuanian, MappingKind::Upper | MappingKind::Title, '\u{307}')
+                if context.is_after_soft_dotted() =>
+            {
+                return;
+            }
+            _ => {}
+        }
+        match context.data.mapping(c, kind) {
+            FullMapping::Char(c) => out.push(c),
+            FullMapping::String(s) => out.push_str(s),
+        }
+    }
+}
+
+/// The text around a character, for the conditions of the conditional mappings.
+struct Context<'a> {
+    data: &'a CaseMappingV1<'a>,
+    before: &'a str,
+    after: &'a str,
+}
+
+impl Context<'_> {
+    fn dot_type(&self, c: char) -> u32 {
+        (self.data.get(c) >> DOT_TYPE_SHIFT) & DOT_TYPE_MASK
+    }
+
+    /// Returns whether `c` is a cased letter, and not ignored as case-ignorable.
+    fn is_cased(&self, c: char) -> Option<bool> {
+        let value = self.data.get(c);
+        if value & CASE_IGNORABLE != 0 {
+            None
+        } else {
+            Some(value & CASE_TYPE_MASK != CASE_TYPE_NONE)
+        }
+    }
+
+    /// The character is preceded by a cased letter and not followed by one, ignoring
+    /// case-ignorable characters in between.
+    fn is_final_sigma(&self) -> bool {
+        let mut before = self.before.chars().rev().filter_map(|c| self.is_cased(c));
+        let mut after = self.after.chars().filter_map(|c| self.is_cased(c));
+        before.next() == Some(true) && after.next() != Some(true)
+    }
+
+    /// Returns the first character of `chars` whose combining class is 0 or 230 (Above).
+    fn next_starter_or_above(&self, mut chars: impl Iterator<Item = char>) -> Option<char> {
+        chars.find(|&c| self.dot_type(c) != DOT_TYPE_OTHER_ACCENT)
+    }
+
+    /// The character is preceded by "I", with no characters of combining class 0 or 230
+    /// in between.
+    fn is_after_i(&self) -> bool {
+        self.next_starter_or_above(self.before.chars().rev()) == Some('I')
+    }
+
+    /// The character is followed by U+0307 COMBINING DOT ABOVE, with no characters of
+    /// combining class 0 or 230 in between.
+    fn is_before_dot(&self) -> bool {
+        self.next_starter_or_above(self.after.chars()) == Some('\u{307}')
+    }
+
+    /// The character is followed by a character of combining class 230, with no characters
+    /// of combining class 0 in between.
+    fn is_more_above(&self) -> bool {
+        matches!(
+            self.next_starter_or_above(self.after.chars()),
+            Some(c) if self.dot_type(c) == DOT_TYPE_ABOVE
+        )
+    }
+
+    /// The character is preceded by a Soft_Dotted character, with no characters of
+    /// combining class 0 or 230 in between.
+    fn is_after_soft_dotted(&self) -> bool {
+        matches!(
+            self.next_starter_or_above(self.before.chars().rev()),
+            Some(c) if self.dot_type(c) == DOT_TYPE_SOFT_DOTTED
+        )
+    }
+}
@@ -0,0 +1,55 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_casemapping`](crate) is an implementation of the full case mappings and case folding
+//! of Unicode, as specified in chapter 3.13 of the [Unicode Standard].
+//!
+//! A [`CaseMapping`] maps strings to lowercase, uppercase and titlecase, and folds them for
+//! caseless matching. It applies the full mappings of `SpecialCasing.txt`, which may change
+//! the length of the text, like "ß" to "SS", and their conditional mappings, like the final
+//! form of the Greek sigma. The mappings are tailored for Turkish, Azeri and Lithuanian when
+//! a [`LanguageIdentifier`](icu_locid::LanguageIdentifier) is given. Titlecasing takes the word
+//! boundaries from a segmenter.
+//!
+//! [Unicode Standard]: https://www.unicode.org/versions/latest/
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_casemapping::CaseMapping;
+//! use icu_locid_macros::langid;
+//!
+//! let provider = icu_provider_uprops::CaseMappingDataProvider::new(
+//!     icu_testdata::paths::data_root().join("uprops"),
+//! );
+//! let cm = CaseMapping::try_new(&provider).expect("Data should load successfully");
+//!
+//! assert_eq!(cm.to_uppercase("Maße"), "MASSE");
+//! assert_eq!(cm.fold("Maße"), cm.fold("MASSE"));
+//! assert_eq!(cm.to_lowercase("ὈΔΥΣΣΕΎΣ"), "ὀδυσσεύς");
+//! assert_eq!(cm.to_titlecase("ǆemal", std::iter::empty()), "ǅemal");
+//!
+//! let lt = CaseMapping::try_new_with_locale(&langid!("lt"), &provider)
+//!     .expect("Data should load successfully");
+//! assert_eq!(cm.to_lowercase("Ì"), "ì");
+//! assert_eq!(lt.to_lowercase("Ì"), "i\u{307}\u{300}");
+//! ```
+//!
+//! # Conformance tests
+//!
+//! The conformance tests check the mappings of `tests/testdata/SpecialCasing.txt`, with the text
+//! around each character that its condition needs, and the full case folding of every code point
+//! in `tests/testdata/CaseFolding.txt`, with and without the Turkic mappings. Both are copies of
+//! the files of the same name in the Unicode Character Database.
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod casemapping;
+pub mod error;
+pub mod provider;
+
+pub use casemapping::CaseMapping;
+pub use error::Error as CaseMappingError;
@@ -0,0 +1,139 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::string::String;
+use icu_codepointtrie::codepointtrie::{CodePointTrie, Small};
+use icu_provider::yoke::{self, *};
+use zerovec::VarZeroVec;
+
+pub mod key {
+    //! Resource keys for [`icu_casemapping`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: the case mappings and case properties of all code points.
+    pub const CASE_MAPPING_V1: ResourceKey = resource_key!(CaseMapping, "mappings", 1);
+}
+
+/// Mask for the case type in a [`CaseMappingV1`] trie value.
+pub const CASE_TYPE_MASK: u32 = 0x3;
+/// The case type of characters that are not cased.
+pub const CASE_TYPE_NONE: u32 = 0;
+/// The case type of lowercase characters.
+pub const CASE_TYPE_LOWER: u32 = 1;
+/// The case type of uppercase characters.
+pub const CASE_TYPE_UPPER: u32 = 2;
+/// The case type of titlecase characters.
+pub const CASE_TYPE_TITLE: u32 = 3;
+/// Set in a [`CaseMappingV1`] trie value if the character is Case_Ignorable.
+pub const CASE_IGNORABLE: u32 = 1 << 2;
+/// Shift of the dot type in a [`CaseMappingV1`] trie value.
+pub const DOT_TYPE_SHIFT: u32 = 3;
+/// Mask for the dot type, after shifting it by [`DOT_TYPE_SHIFT`].
+pub const DOT_TYPE_MASK: u32 = 0x3;
+/// The dot type of characters with Canonical_Combining_Class 0 that are not Soft_Dotted.
+pub const DOT_TYPE_NONE: u32 = 0;
+/// The dot type of Soft_Dotted characters, like "i" and "j".
+pub const DOT_TYPE_SOFT_DOTTED: u32 = 1;
+/// The dot type of characters with Canonical_Combining_Class 230 (Above).
+pub const DOT_TYPE_ABOVE: u32 = 2;
+/// The dot type of characters with another nonzero Canonical_Combining_Class.
+pub const DOT_TYPE_OTHER_ACCENT: u32 = 3;
+/// Set in a [`CaseMappingV1`] trie value if the character is a letter, number, symbol or
+/// private use character, where titlecasing starts in a word.
+pub const LETTER_NUMBER_SYMBOL: u32 = 1 << 5;
+/// Set in a [`CaseMappingV1`] trie value if the mappings of the character are exceptions.
+pub const EXCEPTION: u32 = 1 << 6;
+/// Shift of the delta or the exception index in a [`CaseMappingV1`] trie value.
+pub const DELTA_SHIFT: u32 = 7;
+
+/// The kinds of full case mappings, in the order of their strings in
+/// [`CaseMappingV1::exceptions`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MappingKind {
+    Lower = 0,
+    Upper = 1,
+    Title = 2,
+    Fold = 3,
+}
+
+/// The full case mappings of the root locale, with the case properties needed by the
+/// conditional mappings of SpecialCasing.txt.
+///
+/// For each code point, `code_point_trie` stores a value with
+/// - the case type in the bits of [`CASE_TYPE_MASK`],
+/// - the [`CASE_IGNORABLE`] and [`LETTER_NUMBER_SYMBOL`] flags,
+/// - the dot type at [`DOT_TYPE_SHIFT`], and
+/// - in the bits starting at [`DELTA_SHIFT`], either a signed delta or, if the
+///   [`EXCEPTION`] flag is set, an exception index.
+///
+/// Without the exception flag, lowercase and uncased characters map to themselves in
+/// lowercase and case folding, and to the code point plus the delta in uppercase and
+/// titlecase. Uppercase and titlecase characters map to the code point plus the delta in
+/// lowercase and case folding, and to themselves otherwise.
+///
+/// The mappings of a character with the exception flag are the four strings of `exceptions`
+/// starting at four times its exception index, in the order of [`MappingKind`]. An empty
+/// string means that the character maps to itself.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct CaseMappingV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub code_point_trie: CodePointTrie<'data, u32, Small>,
+
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub exceptions: VarZeroVec<'data, String>,
+}
+
+impl CaseMappingV1<'_> {
+    /// Returns the trie value of `c`.
+    pub fn get(&self, c: char) -> u32 {
+        self.code_point_trie.get(c as u32)
+    }
+
+    /// Returns the full mapping of `c` of the given kind, as a character or a string.
+    pub fn mapping(&self, c: char, kind: MappingKind) -> FullMapping<'_> {
+        let value = self.get(c);
+        if value & EXCEPTION != 0 {
+            let index = (value >> DELTA_SHIFT) as usize * 4 + kind as usize;
+            let mapping = self.exceptions.get(index).unwrap_or_default();
+            let mut chars = mapping.chars();
+            return match (chars.next(), chars.next()) {
+                (None, _) => FullMapping::Char(c),
+                (Some(mapped), None) => FullMapping::Char(mapped),
+                _ => FullMapping::String(mapping),
+            };
+        }
+        let lower = value & CASE_TYPE_MASK <= CASE_TYPE_LOWER;
+        let changes = match kind {
+            MappingKind::Lower | MappingKind::Fold => !lower,
+            MappingKind::Upper | MappingKind::Title => lower,
+        };
+        if !changes {
+            return FullMapping::Char(c);
+        }
+        let delta = (value as i32) >> DELTA_SHIFT;
+        let mapped = (c as u32 as i32).wrapping_add(delta) as u32;
+        FullMapping::Char(char::from_u32(mapped).unwrap_or(c))
+    }
+}
+
+/// A full case mapping of a character, returned by [`CaseMappingV1::mapping()`].
+///
+/// Mappings to a single character are always returned as [`FullMapping::Char`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FullMapping<'a> {
+    /// The character maps to a single character.
+    Char(char),
+    /// The character maps to a string of several characters.
+    String(&'a str),
+}
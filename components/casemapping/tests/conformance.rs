@@ -0,0 +1,230 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_casemapping::CaseMapping;
+use icu_locid::LanguageIdentifier;
+use icu_provider_uprops::CaseMappingDataProvider;
+use icu_segmenter::LineBreakIterator;
+use std::char;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+
+fn provider() -> CaseMappingDataProvider {
+    CaseMappingDataProvider::new(icu_testdata::paths::data_root().join("uprops"))
+}
+
+fn parse_code_points(field: &str) -> String {
+    field
+        .split_ascii_whitespace()
+        .map(|c| u32::from_str_radix(c, 16).expect("Valid hex"))
+        .map(|c| char::from_u32(c).expect("Valid code point"))
+        .collect()
+}
+
+/// Returns the fields of the data lines of a file of the Unicode Character Database.
+fn data_lines(path: &str) -> Vec<Vec<String>> {
+    let f = BufReader::new(File::open(path).expect("Test file"));
+    f.lines()
+        .map(|line| line.expect("Valid line"))
+        .filter_map(|line| {
+            let line = line
+                .split('#')
+                .next()
+                .expect("Split has a first element")
+                .trim();
+            if line.is_empty() {
+                return None;
+            }
+            Some(
+                line.split(';')
+                    .map(|field| field.trim().to_string())
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// Returns the text before and after a character that satisfies a condition of
+/// SpecialCasing.txt.
+fn context(condition: &str) -> (&'static str, &'static str) {
+    match condition {
+        "" | "Not_Before_Dot" => ("", ""),
+        "Final_Sigma" => ("\u{391}", ""),
+        "After_Soft_Dotted" => ("i", ""),
+        "More_Above" => ("", "\u{301}"),
+        "After_I" => ("I", ""),
+        _ => panic!("Unknown condition {}", condition),
+    }
+}
+
+#[test]
+fn run_special_casing_test() {
+    let provider = provider();
+    let root = CaseMapping::try_new(&provider).expect("Valid data");
+    let mut mappers = HashMap::new();
+    let mut count = 0;
+
+    for fields in data_lines("./tests/testdata/SpecialCasing.txt") {
+        let c = parse_code_points(&fields[0]);
+        let (lower, title, upper) = (
+            parse_code_points(&fields[1]),
+            parse_code_points(&fields[2]),
+            parse_code_points(&fields[3]),
+        );
+        let mut conditions = fields[4].split_ascii_whitespace();
+        let (language, condition) = match conditions.next() {
+            Some(first) if first.chars().all(|c| c.is_ascii_lowercase()) => {
+                (first, conditions.next().unwrap_or(""))
+            }
+            first => ("und", first.unwrap_or("")),
+        };
+        let cm = mappers.entry(language.to_string()).or_insert_with(|| {
+            let langid: LanguageIdentifier = language.parse().expect("Valid language");
+            CaseMapping::try_new_with_locale(&langid, &provider).expect("Valid data")
+        });
+
+        // The text around the character is mapped as it is without a language.
+        let (before, after) = context(condition);
+        let source = format!("{}{}{}", before, c, after);
+        let line = fields.join("; ");
+        assert_eq!(
+            cm.to_lowercase(&source),
+            format!(
+                "{}{}{}",
+                root.to_lowercase(before),
+                lower,
+                root.to_lowercase(after)
+            ),
+            "Lowercase: {}",
+            line
+        );
+        assert_eq!(
+            cm.to_uppercase(&source),
+            format!(
+                "{}{}{}",
+                root.to_uppercase(before),
+                upper,
+                root.to_uppercase(after)
+            ),
+            "Uppercase: {}",
+            line
+        );
+        // Only the first character of a word is titlecased.
+        if before.is_empty() {
+            assert_eq!(
+                cm.to_titlecase(&source, std::iter::empty()),
+                format!("{}{}", title, root.to_lowercase(after)),
+                "Titlecase: {}",
+                line
+            );
+        }
+        count += 1;
+    }
+    assert!(count > 0);
+}
+
+#[test]
+fn run_case_folding_test() {
+    let provider = provider();
+    let root = CaseMapping::try_new(&provider).expect("Valid data");
+    let tr =
+        CaseMapping::try_new_with_locale(&"tr".parse().unwrap(), &provider).expect("Valid data");
+
+    // The full case folding, and its Turkic variant.
+    let mut full = HashMap::new();
+    let mut turkic = HashMap::new();
+    for fields in data_lines("./tests/testdata/CaseFolding.txt") {
+        let c = parse_code_points(&fields[0]);
+        let folded = parse_code_points(&fields[2]);
+        match fields[1].as_str() {
+            "C" | "F" => {
+                full.insert(c.clone(), folded.clone());
+                turkic.entry(c).or_insert(folded);
+            }
+            "T" => {
+                turkic.insert(c, folded);
+            }
+            "S" => (),
+            status => panic!("Unknown status {}", status),
+        }
+    }
+    assert!(!full.is_empty());
+
+    for c in '\0'..=char::MAX {
+        let c = c.to_string();
+        let line = format!("{:04X}", c.chars().next().unwrap() as u32);
+        assert_eq!(
+            &root.fold(&c),
+            full.get(&c).unwrap_or(&c),
+            "Case folding: {}",
+            line
+        );
+        assert_eq!(
+            &tr.fold(&c),
+            turkic.get(&c).unwrap_or(&c),
+            "Turkic case folding: {}",
+            line
+        );
+    }
+}
+
+#[test]
+fn test_final_sigma() {
+    let cm = CaseMapping::try_new(&provider()).expect("Valid data");
+
+    assert_eq!(cm.to_lowercase("ΣΑΣ"), "σας");
+    assert_eq!(cm.to_lowercase("ΣΑΣ ΣΑΣ."), "σας σας.");
+    // Case-ignorable characters are skipped on both sides.
+    assert_eq!(cm.to_lowercase("Α'Σ'"), "α'ς'");
+    assert_eq!(cm.to_lowercase("Α'Σ'Α"), "α'σ'α");
+    // A lone sigma is not final.
+    assert_eq!(cm.to_lowercase("Σ"), "σ");
+    assert_eq!(cm.to_lowercase("1Σ"), "1σ");
+    // The context extends beyond the word in titlecasing.
+    assert_eq!(cm.to_titlecase("ΣΑΣ", std::iter::empty()), "Σας");
+}
+
+#[test]
+fn test_tailorings() {
+    let provider = provider();
+    let root = CaseMapping::try_new(&provider).expect("Valid data");
+    let en =
+        CaseMapping::try_new_with_locale(&"en-US".parse().unwrap(), &provider).expect("Valid data");
+    let az = CaseMapping::try_new_with_locale(&"az-Latn-AZ".parse().unwrap(), &provider)
+        .expect("Valid data");
+    let lt =
+        CaseMapping::try_new_with_locale(&"lt".parse().unwrap(), &provider).expect("Valid data");
+
+    assert_eq!(en.to_lowercase("I\u{130}"), root.to_lowercase("I\u{130}"));
+    assert_eq!(root.to_lowercase("I\u{130}"), "ii\u{307}");
+    assert_eq!(az.to_lowercase("I\u{130}"), "\u{131}i");
+    // "I" followed by a combining dot above, with a dot below in between.
+    assert_eq!(az.to_lowercase("I\u{323}\u{307}"), "i\u{323}");
+    assert_eq!(az.to_uppercase("i\u{131}"), "\u{130}I");
+    assert_eq!(az.to_titlecase("ilk", std::iter::empty()), "\u{130}lk");
+    assert_eq!(az.fold("I\u{130}"), "\u{131}i");
+    assert_eq!(root.fold("I\u{130}"), "ii\u{307}");
+
+    // Lithuanian keeps the dot of "i" under accents above, and removes it in uppercase.
+    assert_eq!(lt.to_lowercase("I\u{301}"), "i\u{307}\u{301}");
+    assert_eq!(lt.to_lowercase("I\u{323}\u{301}"), "i\u{307}\u{323}\u{301}");
+    assert_eq!(lt.to_lowercase("\u{CD}"), "i\u{307}\u{301}");
+    assert_eq!(lt.to_uppercase("i\u{307}\u{301}"), "I\u{301}");
+    assert_eq!(root.to_uppercase("i\u{307}\u{301}"), "I\u{307}\u{301}");
+}
+
+#[test]
+fn test_titlecase_words() {
+    let cm = CaseMapping::try_new(&provider()).expect("Valid data");
+
+    let text = "hello WORLD, ﬁrst \"quoted\" 42nd";
+    assert_eq!(
+        cm.to_titlecase(text, LineBreakIterator::new(text)),
+        "Hello World, First \"Quoted\" 42nd"
+    );
+    // Boundaries that are out of order, out of range or inside characters are ignored.
+    assert_eq!(cm.to_titlecase("ßa ßa", vec![3, 2, 5, 100]), "Ssa Ssa");
+}
@@ -0,0 +1,352 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The collation algorithm, see [`Collator`].
+
+use crate::error::Error;
+use crate::options::*;
+use crate::provider::*;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use icu_locid::{LanguageIdentifier, Locale};
+use icu_normalizer::provider::DecompositionsV1Marker;
+use icu_normalizer::DecomposingNormalizer;
+use icu_provider::prelude::*;
+
+/// The primary weight of U+FFFE, which is lower than all others and never variable.
+const MERGE_SEPARATOR_PRIMARY: u32 = 0x0200_0000;
+/// The offset of the primary weights of the digits of numbers from the numeric primary, after
+/// those of the numbers of digits.
+const NUMERIC_DIGITS_OFFSET: u32 = 0x100;
+/// The maximum number of digits that are collated as one number.
+const MAX_NUMERIC_DIGITS: usize = 254;
+/// The separator between the levels of a sort key.
+const LEVEL_SEPARATOR: u8 = 0x01;
+
+/// A collator, which compares strings in the order of the Unicode Collation Algorithm with
+/// the CLDR root collation and its tailorings.
+///
+/// # Examples
+///
+/// ```
+/// use icu_collator::{Collator, CollatorOptions};
+/// use icu_locid_macros::langid;
+/// use core::cmp::Ordering;
+///
+/// let provider = icu_provider_uprops::CollationDataProvider::new(
+///     icu_testdata::paths::data_root().join("uprops"),
+/// );
+/// let sv = Collator::try_new(&langid!("sv").into(), &provider, CollatorOptions::default())
+///     .expect("Data should load successfully");
+///
+/// let mut words = vec!["öl", "Zürich", "ål", "ära", "zebra", "ar"];
+/// words.sort_by(|a, b| sv.compare(a, b));
+/// assert_eq!(words, ["ar", "zebra", "Zürich", "ål", "ära", "öl"]);
+/// assert_eq!(sv.compare("ål", "al"), Ordering::Greater);
+/// ```
+pub struct Collator<'data> {
+    root: DataPayload<'data, CollationDataV1Marker>,
+    tailoring: Option<DataPayload<'data, CollationDataV1Marker>>,
+    special_primaries: DataPayload<'data, CollationSpecialPrimariesV1Marker>,
+    nfd: DecomposingNormalizer<'data>,
+    options: ResolvedOptions,
+}
+
+impl<'data> Collator<'data> {
+    /// Creates a collator for a locale.
+    ///
+    /// The tailoring is chosen by the language of the locale. Options that are `None` are
+    /// taken from the Unicode extension keywords of the locale, see [`CollatorOptions`].
+    pub fn try_new<D>(
+        locale: &Locale,
+        provider: &D,
+        options: CollatorOptions,
+    ) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CollationDataV1Marker>
+            + DataProvider<'data, CollationMetadataV1Marker>
+            + DataProvider<'data, CollationSpecialPrimariesV1Marker>
+            + DataProvider<'data, DecompositionsV1Marker>
+            + ?Sized,
+    {
+        let language = LanguageIdentifier {
+            language: locale.id.language,
+            ..Default::default()
+        };
+        let metadata: DataPayload<CollationMetadataV1Marker> =
+            load(provider, key::COLLATION_METADATA_V1, Some(language.clone()))?;
+        let tailoring = if metadata.get().tailored() {
+            Some(load(provider, key::COLLATION_DATA_V1, Some(language))?)
+        } else {
+            None
+        };
+
+        Ok(Collator {
+            root: load(provider, key::COLLATION_DATA_V1, None)?,
+            tailoring,
+            special_primaries: load(provider, key::COLLATION_SPECIAL_PRIMARIES_V1, None)?,
+            nfd: DecomposingNormalizer::try_new_nfd(provider)?,
+            options: ResolvedOptions::new(options, locale, metadata.get()),
+        })
+    }
+
+    /// Compares two strings.
+    pub fn compare(&self, left: &str, right: &str) -> Ordering {
+        if left == right {
+            return Ordering::Equal;
+        }
+        self.sort_key(left).cmp(&self.sort_key(right))
+    }
+
+    /// Returns the sort key of a string. Comparing the sort keys of two strings as bytes gives
+    /// the same result as [`Collator::compare()`].
+    ///
+    /// The sort key has the weights of each level up to the strength, separated by the byte
+    /// `0x01`. The identical level is the NFD form of the string in UTF-8.
+    pub fn sort_key(&self, text: &str) -> Vec<u8> {
+        let mut chars = Vec::new();
+        let mut decomposition = self.nfd.normalize_iter(text.chars());
+        while let Some(item) = decomposition.next_with_ccc() {
+            chars.push(item);
+        }
+        let nfd: String = chars.iter().map(|&(c, _)| c).collect();
+        let ces = self.collation_elements(chars);
+
+        let options = &self.options;
+        let shifted = options.alternate_handling == AlternateHandling::Shifted;
+        let variable_top = self
+            .special_primaries
+            .get()
+            .variable_tops
+            .get(options.max_variable as usize)
+            .unwrap_or(0);
+        let mut primaries = Vec::new();
+        let mut secondaries = Vec::new();
+        let mut tertiaries = Vec::new();
+        let mut quaternaries = Vec::new();
+        let mut after_variable = false;
+        for ce in ces {
+            let p = (ce >> 32) as u32;
+            let s = (ce >> 16) as u16;
+            let t = ce as u16;
+            if shifted {
+                if p > MERGE_SEPARATOR_PRIMARY && p <= variable_top {
+                    // Variable collation elements are only weighted at the quaternary level,
+                    // and the primary ignorable ones after them are ignored.
+                    quaternaries.push(p);
+                    after_variable = true;
+                    continue;
+                } else if p == 0 && after_variable {
+                    continue;
+                }
+                after_variable = false;
+                quaternaries.push(u32::MAX);
+            }
+            if p != 0 {
+                primaries.push(p);
+            }
+            if s != 0 {
+                secondaries.push(s);
+            }
+            let t = match options.case_first {
+                CaseFirst::Off => t & 0x3f3f,
+                CaseFirst::LowerFirst => t & 0xff3f,
+                // Uppercase sorts before mixed case and lowercase. Tertiary collation elements
+                // keep sorting after the ones with secondary weights.
+                CaseFirst::UpperFirst => match t & 0xff3f {
+                    0 => 0,
+                    t if s != 0 => t ^ 0xc000,
+                    t => t + 0x4000,
+                },
+            };
+            if t != 0 {
+                tertiaries.push(t);
+            }
+        }
+
+        let mut key = Vec::new();
+        for p in primaries {
+            key.extend_from_slice(&p.to_be_bytes());
+        }
+        if options.strength >= Strength::Secondary {
+            key.push(LEVEL_SEPARATOR);
+            for s in secondaries {
+                key.extend_from_slice(&s.to_be_bytes());
+            }
+        }
+        if options.strength >= Strength::Tertiary {
+            key.push(LEVEL_SEPARATOR);
+            for t in tertiaries {
+                key.extend_from_slice(&t.to_be_bytes());
+            }
+        }
+        if options.strength >= Strength::Quaternary {
+            key.push(LEVEL_SEPARATOR);
+            for q in quaternaries {
+                key.extend_from_slice(&q.to_be_bytes());
+            }
+        }
+        if options.strength >= Strength::Identical {
+            key.push(LEVEL_SEPARATOR);
+            key.extend_from_slice(nfd.as_bytes());
+        }
+        key
+    }
+
+    /// Returns the table with the collation elements of `c`, and its trie value there.
+    fn lookup(&self, c: char) -> (&CollationDataV1<'_>, u32) {
+        if let Some(tailoring) = &self.tailoring {
+            let tailoring = tailoring.get();
+            let value = tailoring.get(c);
+            if value & TAG_MASK != TAG_NONE {
+                return (tailoring, value);
+            }
+        }
+        let root = self.root.get();
+        (root, root.get(c))
+    }
+
+    /// Returns the collation elements of the characters of a string in NFD, with their
+    /// Canonical_Combining_Class.
+    fn collation_elements(&self, mut chars: Vec<(char, u8)>) -> Vec<u64> {
+        let special_primaries = self.special_primaries.get();
+        let mut ces = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i].0;
+            i += 1;
+            let (data, mut value) = self.lookup(c);
+            match value & TAG_MASK {
+                TAG_NONE => {
+                    let p = special_primaries.unassigned_primary + c as u32;
+                    ces.push(u64::from(p) << 32 | COMMON_SEC_TER);
+                    continue;
+                }
+                TAG_IMPLICIT => {
+                    let p = value >> IMPLICIT_PRIMARY_SHIFT;
+                    ces.push(u64::from(p) << 32 | COMMON_SEC_TER);
+                    continue;
+                }
+                TAG_CONTRACTION => value = match_contraction(data, value, &mut chars, &mut i),
+                _ => {}
+            }
+            if self.options.numeric && value & DIGIT != 0 {
+                let mut digits = vec![digit_value(value)];
+                while let Some(&(c, _)) = chars.get(i) {
+                    let (_, value) = self.lookup(c);
+                    if value & TAG_MASK != TAG_EXPANSION || value & DIGIT == 0 {
+                        break;
+                    }
+                    digits.push(digit_value(value));
+                    i += 1;
+                }
+                append_numeric(special_primaries.numeric_primary, &digits, &mut ces);
+            } else {
+                ces.extend(data.expansion(value));
+            }
+        }
+        ces
+    }
+}
+
+fn load<'data, D, M>(
+    provider: &D,
+    key: ResourceKey,
+    langid: Option<LanguageIdentifier>,
+) -> Result<DataPayload<'data, M>, Error>
+where
+    D: DataProvider<'data, M> + ?Sized,
+    M: DataMarker<'data>,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key,
+            options: ResourceOptions {
+                variant: None,
+                langid,
+            },
+        },
+    };
+    let resp: DataResponse<M> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
+
+fn digit_value(value: u32) -> u8 {
+    (value >> DIGIT_VALUE_SHIFT & DIGIT_VALUE_MASK) as u8
+}
+
+/// Returns the expansion value of the longest contraction of a starter, given by its
+/// contraction value, that matches the characters starting at `i`, and moves `i` past them.
+///
+/// After the longest contiguous match, unblocked non-starters that extend the match to
+/// another contraction are removed from `chars`, as in step S2.1 of the Unicode Collation
+/// Algorithm.
+fn match_contraction(
+    data: &CollationDataV1,
+    value: u32,
+    chars: &mut Vec<(char, u8)>,
+    i: &mut usize,
+) -> u32 {
+    let mut matched = String::new();
+    let mut matched_len = 0;
+    let mut result = TAG_NONE;
+    for (suffix, value) in data.contractions(value) {
+        let len = suffix.chars().count();
+        if (result == TAG_NONE || len > matched_len)
+            && suffix
+                .chars()
+                .eq(chars[*i..].iter().take(len).map(|&(c, _)| c))
+        {
+            matched.clear();
+            matched.push_str(suffix);
+            matched_len = len;
+            result = value;
+        }
+    }
+    *i += matched_len;
+
+    let mut j = *i;
+    let mut last_ccc = 0;
+    while let Some(&(c, ccc)) = chars.get(j) {
+        if ccc == 0 {
+            break;
+        }
+        if last_ccc < ccc {
+            matched.push(c);
+            let extended = data
+                .contractions(value)
+                .find(|&(suffix, _)| suffix == matched);
+            if let Some((_, value)) = extended {
+                result = value;
+                chars.remove(j);
+                continue;
+            }
+            matched.pop();
+        }
+        last_ccc = ccc;
+        j += 1;
+    }
+    result
+}
+
+/// Appends the collation elements of a number with the given digits, which are ordered by the
+/// number of digits and then by their values, ignoring leading zeros.
+fn append_numeric(numeric_primary: u32, digits: &[u8], ces: &mut Vec<u64>) {
+    let ce = |p: u32| u64::from(p) << 32 | COMMON_SEC_TER;
+    let mut pos = 0;
+    while pos < digits.len() {
+        while pos < digits.len() - 1 && digits[pos] == 0 {
+            pos += 1;
+        }
+        let len = (digits.len() - pos).min(MAX_NUMERIC_DIGITS);
+        ces.push(ce(numeric_primary + len as u32));
+        for chunk in digits[pos..pos + len].chunks(4) {
+            let chunk = chunk.iter().fold(0, |n, &d| n * 10 + u32::from(d));
+            ces.push(ce(numeric_primary + NUMERIC_DIGITS_OFFSET + chunk));
+        }
+        pos += len;
+    }
+}
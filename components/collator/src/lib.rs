@@ -0,0 +1,67 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_collator`](crate) is an implementation of the [Unicode Collation Algorithm] with the
+//! root collation and the tailorings of [CLDR].
+//!
+//! A [`Collator`] compares strings and creates sort keys, which are compared byte by byte. It
+//! tailors the order for the language of a locale, and supports the options of the collation
+//! Unicode extension keywords: the strength, the handling of variable characters like spaces
+//! and punctuation, whether uppercase or lowercase sorts first, and numeric collation. Strings
+//! are normalized to NFD first, so canonically equivalent strings are always equal.
+//!
+//! [Unicode Collation Algorithm]: https://www.unicode.org/reports/tr10/
+//! [CLDR]: https://cldr.unicode.org/
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_collator::{Collator, CollatorOptions, Strength};
+//! use icu_locid::Locale;
+//! use core::cmp::Ordering;
+//!
+//! let provider = icu_provider_uprops::CollationDataProvider::new(
+//!     icu_testdata::paths::data_root().join("uprops"),
+//! );
+//! let root = Collator::try_new(&Locale::und(), &provider, CollatorOptions::default())
+//!     .expect("Data should load successfully");
+//! assert_eq!(root.compare("côte", "coté"), Ordering::Greater);
+//! assert_eq!(root.compare("a", "A"), Ordering::Less);
+//!
+//! let mut options = CollatorOptions::default();
+//! options.strength = Some(Strength::Secondary);
+//! let secondary = Collator::try_new(&Locale::und(), &provider, options)
+//!     .expect("Data should load successfully");
+//! assert_eq!(secondary.compare("a", "A"), Ordering::Equal);
+//!
+//! // Numeric collation from the "kn" Unicode extension keyword
+//! let numeric: Locale = "en-u-kn".parse().expect("Valid locale");
+//! let numeric = Collator::try_new(&numeric, &provider, CollatorOptions::default())
+//!     .expect("Data should load successfully");
+//! assert_eq!(root.compare("a10", "a9"), Ordering::Less);
+//! assert_eq!(numeric.compare("a10", "a9"), Ordering::Greater);
+//! assert!(numeric.sort_key("a10") > numeric.sort_key("a9"));
+//! ```
+//!
+//! # Generating test data
+//!
+//! The conformance tests read `tests/testdata/CollationTest_CLDR_NON_IGNORABLE_SHORT.txt` and
+//! `tests/testdata/CollationTest_CLDR_SHIFTED_SHORT.txt`, the files of the root collation from
+//! the `common/uca` directory of CLDR, unmodified. The lines with unpaired surrogates are
+//! skipped, since strings can't contain them. `tests/testdata/CollationTailoringTest.txt` has
+//! strings in the order of some tailorings and options, and is generated with ICU4C by
+//! `tools/generate_tests.c`, whose header has the commands to build and run it.
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod collator;
+pub mod error;
+mod options;
+pub mod provider;
+
+pub use collator::Collator;
+pub use error::Error as CollatorError;
+pub use options::{AlternateHandling, CaseFirst, CollatorOptions, MaxVariable, Strength};
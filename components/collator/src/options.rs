@@ -0,0 +1,186 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`Collator`](crate::Collator).
+
+use crate::provider::*;
+use alloc::string::ToString;
+use icu_locid::extensions::unicode::Key;
+use icu_locid::Locale;
+
+/// The number of levels at which strings are compared.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Strength {
+    /// Compare only base letters, as in "a" < "b".
+    Primary,
+    /// Also compare accents, as in "a" < "á".
+    Secondary,
+    /// Also compare case and variants, as in "a" < "A". This is the default.
+    Tertiary,
+    /// Also compare variable characters like spaces and punctuation when they are shifted, as
+    /// in "a-b" < "ab".
+    Quaternary,
+    /// Also compare the code points of the NFD forms of strings that are equal at all other
+    /// levels.
+    Identical,
+}
+
+/// How variable characters, like spaces and punctuation, are compared.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AlternateHandling {
+    /// Variable characters are compared like all others. This is the default.
+    NonIgnorable,
+    /// Variable characters are ignored at the first three levels and compared at the
+    /// quaternary level.
+    Shifted,
+}
+
+/// Whether uppercase or lowercase characters are compared first at the tertiary level.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CaseFirst {
+    /// Case is compared like the other tertiary differences, with lowercase first. This is
+    /// the default.
+    Off,
+    /// Lowercase characters are compared first, before all other tertiary differences.
+    LowerFirst,
+    /// Uppercase characters are compared first, before all other tertiary differences.
+    UpperFirst,
+}
+
+/// The group of characters with the highest primary weights that are variable.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MaxVariable {
+    /// Spaces are variable.
+    Space,
+    /// Spaces and punctuation are variable. This is the default.
+    Punctuation,
+    /// Spaces, punctuation and symbols except currency symbols are variable.
+    Symbol,
+    /// Spaces, punctuation and all symbols are variable.
+    Currency,
+}
+
+/// Options for a [`Collator`](crate::Collator).
+///
+/// Options that are `None` are taken from the Unicode extension keywords of the locale: `ks`
+/// for the strength, `ka` for the alternate handling, `kf` for case-first, `kv` for the
+/// maximum variable group and `kn` for numeric collation. Without keywords, the defaults of
+/// the tailoring of the language, or of the root collation, are used.
+///
+/// # Examples
+///
+/// ```
+/// use icu_collator::{CollatorOptions, Strength};
+///
+/// let mut options = CollatorOptions::default();
+/// options.strength = Some(Strength::Secondary);
+/// options.numeric = Some(true);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CollatorOptions {
+    /// The number of levels at which strings are compared.
+    pub strength: Option<Strength>,
+    /// How variable characters, like spaces and punctuation, are compared.
+    pub alternate_handling: Option<AlternateHandling>,
+    /// Whether uppercase or lowercase characters are compared first.
+    pub case_first: Option<CaseFirst>,
+    /// The group of characters with the highest primary weights that are variable.
+    pub max_variable: Option<MaxVariable>,
+    /// Whether sequences of decimal digits are compared by their numeric value, as in
+    /// "2" < "10".
+    pub numeric: Option<bool>,
+}
+
+/// The options of a collator after resolving them against the locale and the data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ResolvedOptions {
+    pub strength: Strength,
+    pub alternate_handling: AlternateHandling,
+    pub case_first: CaseFirst,
+    pub max_variable: MaxVariable,
+    pub numeric: bool,
+}
+
+impl ResolvedOptions {
+    pub fn new(options: CollatorOptions, locale: &Locale, metadata: &CollationMetadataV1) -> Self {
+        let bits = metadata.bits;
+        let mut resolved = ResolvedOptions {
+            strength: Strength::Tertiary,
+            alternate_handling: if bits & ALTERNATE_SHIFTED != 0 {
+                AlternateHandling::Shifted
+            } else {
+                AlternateHandling::NonIgnorable
+            },
+            case_first: match bits >> CASE_FIRST_SHIFT & CASE_FIRST_MASK {
+                1 => CaseFirst::LowerFirst,
+                2 => CaseFirst::UpperFirst,
+                _ => CaseFirst::Off,
+            },
+            max_variable: match bits >> MAX_VARIABLE_SHIFT & MAX_VARIABLE_MASK {
+                0 => MaxVariable::Space,
+                1 => MaxVariable::Punctuation,
+                2 => MaxVariable::Symbol,
+                _ => MaxVariable::Currency,
+            },
+            numeric: false,
+        };
+
+        let keywords = &locale.extensions.unicode.keywords;
+        if let Some(value) = keywords.get(key(b"ks")) {
+            resolved.strength = match value.to_string().as_str() {
+                "level1" => Strength::Primary,
+                "level2" => Strength::Secondary,
+                "level3" => Strength::Tertiary,
+                "level4" => Strength::Quaternary,
+                "identic" => Strength::Identical,
+                _ => resolved.strength,
+            };
+        }
+        if let Some(value) = keywords.get(key(b"ka")) {
+            resolved.alternate_handling = match value.to_string().as_str() {
+                "noignore" => AlternateHandling::NonIgnorable,
+                "shifted" => AlternateHandling::Shifted,
+                _ => resolved.alternate_handling,
+            };
+        }
+        if let Some(value) = keywords.get(key(b"kf")) {
+            resolved.case_first = match value.to_string().as_str() {
+                "false" => CaseFirst::Off,
+                "lower" => CaseFirst::LowerFirst,
+                "upper" => CaseFirst::UpperFirst,
+                _ => resolved.case_first,
+            };
+        }
+        if let Some(value) = keywords.get(key(b"kv")) {
+            resolved.max_variable = match value.to_string().as_str() {
+                "space" => MaxVariable::Space,
+                "punct" => MaxVariable::Punctuation,
+                "symbol" => MaxVariable::Symbol,
+                "currency" => MaxVariable::Currency,
+                _ => resolved.max_variable,
+            };
+        }
+        if let Some(value) = keywords.get(key(b"kn")) {
+            resolved.numeric = match value.to_string().as_str() {
+                "" | "true" => true,
+                "false" => false,
+                _ => resolved.numeric,
+            };
+        }
+
+        ResolvedOptions {
+            strength: options.strength.unwrap_or(resolved.strength),
+            alternate_handling: options
+                .alternate_handling
+                .unwrap_or(resolved.alternate_handling),
+            case_first: options.case_first.unwrap_or(resolved.case_first),
+            max_variable: options.max_variable.unwrap_or(resolved.max_variable),
+            numeric: options.numeric.unwrap_or(resolved.numeric),
+        }
+    }
+}
+
+fn key(key: &[u8]) -> Key {
+    Key::from_bytes(key).expect("Valid Unicode extension key")
+}
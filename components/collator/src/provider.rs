@@ -0,0 +1,189 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::string::String;
+use icu_codepointtrie::codepointtrie::{CodePointTrie, Small};
+use icu_provider::yoke::{self, *};
+use zerovec::{VarZeroVec, ZeroVec};
+
+pub mod key {
+    //! Resource keys for [`icu_collator`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: the collation elements of the root collation, or of a tailoring if the
+    /// request has a language.
+    pub const COLLATION_DATA_V1: ResourceKey = resource_key!(Collator, "data", 1);
+
+    /// Resource key: whether a language has a tailoring, and its default options.
+    pub const COLLATION_METADATA_V1: ResourceKey = resource_key!(Collator, "meta", 1);
+
+    /// Resource key: the primary weights with a special meaning in the root collation.
+    pub const COLLATION_SPECIAL_PRIMARIES_V1: ResourceKey = resource_key!(Collator, "prim", 1);
+}
+
+/// The common secondary and tertiary weights of a collation element, in its lower 32 bits.
+pub const COMMON_SEC_TER: u64 = 0x0500_0500;
+
+/// Mask for the tag of a [`CollationDataV1`] trie value.
+pub const TAG_MASK: u32 = 0x3;
+/// The tag of code points that have no collation elements in the table.
+pub const TAG_NONE: u32 = 0;
+/// The tag of code points with a list of collation elements in [`CollationDataV1::ces`].
+pub const TAG_EXPANSION: u32 = 1;
+/// The tag of code points that start contractions.
+pub const TAG_CONTRACTION: u32 = 2;
+/// The tag of Han ideographs, whose single collation element has the primary weight stored
+/// in the bits starting at [`IMPLICIT_PRIMARY_SHIFT`] and common secondary and tertiary
+/// weights.
+pub const TAG_IMPLICIT: u32 = 3;
+/// Shift of the number of collation elements of an expansion, or of the number of
+/// contractions of a starter.
+pub const LENGTH_SHIFT: u32 = 2;
+/// Mask for the number of collation elements of an expansion, after shifting it by
+/// [`LENGTH_SHIFT`].
+pub const EXPANSION_LENGTH_MASK: u32 = 0x1f;
+/// Mask for the number of contractions of a starter, after shifting it by [`LENGTH_SHIFT`].
+pub const CONTRACTION_COUNT_MASK: u32 = 0x3ff;
+/// Set in the value of an expansion if the code point is a decimal digit.
+pub const DIGIT: u32 = 1 << 7;
+/// Shift of the value of a decimal digit.
+pub const DIGIT_VALUE_SHIFT: u32 = 8;
+/// Mask for the value of a decimal digit, after shifting it by [`DIGIT_VALUE_SHIFT`].
+pub const DIGIT_VALUE_MASK: u32 = 0xf;
+/// Shift of the index into [`CollationDataV1::ces`] or into the contractions.
+pub const INDEX_SHIFT: u32 = 12;
+/// Shift of the primary weight of a Han ideograph.
+pub const IMPLICIT_PRIMARY_SHIFT: u32 = 2;
+
+/// The collation elements of the root collation or of a tailoring.
+///
+/// A collation element is a `u64` with the primary weight in its upper 32 bits, the
+/// secondary weight in the next 16 bits and the tertiary weight in the lowest 16 bits. The
+/// two highest bits of the tertiary weight are the case bits: `00` for lowercase and uncased
+/// characters, `01` for mixed case and `10` for uppercase.
+///
+/// For each code point in NFD, `trie` stores a value with a tag in the bits of [`TAG_MASK`]:
+/// - [`TAG_NONE`] if the table has no collation elements for it,
+/// - [`TAG_EXPANSION`] with the number of collation elements at [`LENGTH_SHIFT`], the
+///   [`DIGIT`] flag and the value at [`DIGIT_VALUE_SHIFT`] for decimal digits, and the index
+///   of the first collation element in `ces` at [`INDEX_SHIFT`],
+/// - [`TAG_CONTRACTION`] with the number of contractions at [`LENGTH_SHIFT`] and the index
+///   of the first one at [`INDEX_SHIFT`], or
+/// - [`TAG_IMPLICIT`] with a primary weight at [`IMPLICIT_PRIMARY_SHIFT`].
+///
+/// The contractions of a starter are sorted by the code points that follow the starter,
+/// stored in `contractions`, and `contraction_values` has their expansion values. The first
+/// contraction of each starter is the empty string, for the starter alone.
+///
+/// A tailoring has all code points and contractions that it changes, and all contractions of
+/// the root collation that start with the same code points.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct CollationDataV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub trie: CodePointTrie<'data, u32, Small>,
+
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub ces: ZeroVec<'data, u64>,
+
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub contractions: VarZeroVec<'data, String>,
+
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub contraction_values: ZeroVec<'data, u32>,
+}
+
+impl CollationDataV1<'_> {
+    /// Returns the trie value of `c`.
+    pub fn get(&self, c: char) -> u32 {
+        self.trie.get(c as u32)
+    }
+
+    /// Returns the collation elements of an expansion value.
+    pub fn expansion(&self, value: u32) -> impl Iterator<Item = u64> + '_ {
+        let index = (value >> INDEX_SHIFT) as usize;
+        let len = (value >> LENGTH_SHIFT & EXPANSION_LENGTH_MASK) as usize;
+        (index..index + len).filter_map(move |i| self.ces.get(i))
+    }
+
+    /// Returns the contractions of a contraction value, as the code points after the starter
+    /// and their expansion values.
+    pub fn contractions(&self, value: u32) -> impl Iterator<Item = (&str, u32)> + '_ {
+        let index = (value >> INDEX_SHIFT) as usize;
+        let count = (value >> LENGTH_SHIFT & CONTRACTION_COUNT_MASK) as usize;
+        (index..index + count)
+            .filter_map(move |i| Some((self.contractions.get(i)?, self.contraction_values.get(i)?)))
+    }
+}
+
+/// Set in [`CollationMetadataV1::bits`] if the language has a tailoring.
+pub const TAILORED: u32 = 1;
+/// Shift of the default case-first option in [`CollationMetadataV1::bits`].
+pub const CASE_FIRST_SHIFT: u32 = 1;
+/// Mask for the case-first option, after shifting it by [`CASE_FIRST_SHIFT`]. The values
+/// are 0 for off, 1 for lowercase first and 2 for uppercase first.
+pub const CASE_FIRST_MASK: u32 = 0x3;
+/// Set in [`CollationMetadataV1::bits`] if variable characters are shifted by default.
+pub const ALTERNATE_SHIFTED: u32 = 1 << 3;
+/// Shift of the default maximum variable group in [`CollationMetadataV1::bits`].
+pub const MAX_VARIABLE_SHIFT: u32 = 4;
+/// Mask for the maximum variable group, after shifting it by [`MAX_VARIABLE_SHIFT`]. The
+/// values are 0 for spaces, 1 for punctuation, 2 for symbols and 3 for currency symbols.
+pub const MAX_VARIABLE_MASK: u32 = 0x3;
+
+/// Whether a language has a tailoring, and the default options of its collation, in the
+/// bits described by [`TAILORED`], [`CASE_FIRST_SHIFT`], [`ALTERNATE_SHIFTED`] and
+/// [`MAX_VARIABLE_SHIFT`].
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct CollationMetadataV1 {
+    pub bits: u32,
+}
+
+impl CollationMetadataV1 {
+    /// Returns whether the language has a tailoring.
+    pub fn tailored(&self) -> bool {
+        self.bits & TAILORED != 0
+    }
+}
+
+/// The primary weights with a special meaning in the root collation, which apply to all
+/// tailorings.
+///
+/// `variable_tops` has the highest primary weight of the space, punctuation, symbol and
+/// currency groups, in that order. Collation elements with a primary weight up to the one of
+/// the maximum variable group are variable.
+///
+/// The primary weights from `numeric_primary` up to `numeric_primary + 0x3000` are reserved
+/// for numeric collation. Unassigned code points and private use characters get a single
+/// collation element with the primary weight `unassigned_primary` plus the code point.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct CollationSpecialPrimariesV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub variable_tops: ZeroVec<'data, u32>,
+
+    pub numeric_primary: u32,
+
+    pub unassigned_primary: u32,
+}
@@ -0,0 +1,394 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::cmp::Ordering;
+use icu_collator::provider::*;
+use icu_collator::{AlternateHandling, Collator, CollatorOptions, Strength};
+use icu_locid::Locale;
+use icu_locid_macros::langid;
+use icu_normalizer::provider::DecompositionsV1Marker;
+use icu_provider::prelude::*;
+use icu_provider_uprops::CollationDataProvider;
+use std::char;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+
+/// A provider that loads the data shared by all collators only once, since the tests create
+/// many collators and parsing the root collation is slow.
+struct TestProvider {
+    source: CollationDataProvider,
+    root: DataPayload<'static, CollationDataV1Marker>,
+    special_primaries: DataPayload<'static, CollationSpecialPrimariesV1Marker>,
+    decompositions: DataPayload<'static, DecompositionsV1Marker>,
+}
+
+fn load<M>(source: &CollationDataProvider, key: ResourceKey) -> DataPayload<'static, M>
+where
+    M: DataMarker<'static>,
+    CollationDataProvider: DataProvider<'static, M>,
+{
+    let req = DataRequest::from(key);
+    let resp: DataResponse<M> = source.load_payload(&req).expect("Valid data");
+    resp.take_payload().expect("Data present")
+}
+
+fn provider() -> TestProvider {
+    let source = CollationDataProvider::new(icu_testdata::paths::data_root().join("uprops"));
+    TestProvider {
+        root: load(&source, key::COLLATION_DATA_V1),
+        special_primaries: load(&source, key::COLLATION_SPECIAL_PRIMARIES_V1),
+        decompositions: load(&source, icu_normalizer::provider::key::DECOMPOSITIONS_V1),
+        source,
+    }
+}
+
+impl DataProvider<'static, CollationDataV1Marker> for TestProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'static, CollationDataV1Marker>, DataError> {
+        if req.resource_path.options.langid.is_none() {
+            Ok(DataResponse {
+                metadata: Default::default(),
+                payload: Some(self.root.clone()),
+            })
+        } else {
+            self.source.load_payload(req)
+        }
+    }
+}
+
+impl DataProvider<'static, CollationMetadataV1Marker> for TestProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'static, CollationMetadataV1Marker>, DataError> {
+        self.source.load_payload(req)
+    }
+}
+
+impl DataProvider<'static, CollationSpecialPrimariesV1Marker> for TestProvider {
+    fn load_payload(
+        &self,
+        _: &DataRequest,
+    ) -> Result<DataResponse<'static, CollationSpecialPrimariesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(self.special_primaries.clone()),
+        })
+    }
+}
+
+impl DataProvider<'static, DecompositionsV1Marker> for TestProvider {
+    fn load_payload(
+        &self,
+        _: &DataRequest,
+    ) -> Result<DataResponse<'static, DecompositionsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(self.decompositions.clone()),
+        })
+    }
+}
+
+fn parse_code_points(field: &str) -> String {
+    try_parse_code_points(field).expect("Valid code points")
+}
+
+/// Parses the code points of a field, or returns `None` if one of them is a surrogate.
+fn try_parse_code_points(field: &str) -> Option<String> {
+    field
+        .split_ascii_whitespace()
+        .map(|c| u32::from_str_radix(c, 16).expect("Valid hex"))
+        .map(char::from_u32)
+        .collect()
+}
+
+/// Checks that the strings of a file in the format of CLDR's CollationTest files, one per
+/// line, are in ascending order with identical strength, and in code point order where they
+/// are equal, like canonically equivalent strings.
+fn run_collation_test(path: &str, alternate_handling: AlternateHandling) {
+    let options = CollatorOptions {
+        strength: Some(Strength::Identical),
+        alternate_handling: Some(alternate_handling),
+        ..Default::default()
+    };
+    let collator = Collator::try_new(&Locale::und(), &provider(), options).expect("Valid data");
+    let f = BufReader::new(File::open(path).expect("Test file"));
+
+    let mut previous: Option<(String, Vec<u8>)> = None;
+    let mut count = 0;
+    let mut skipped = 0;
+    for line in f.lines() {
+        let line = line.expect("Valid line");
+        let line = line
+            .split(&['#', ';'][..])
+            .next()
+            .expect("Split has a first element")
+            .trim();
+        if line.is_empty() {
+            continue;
+        }
+        // Strings can't contain the unpaired surrogates of some lines, which the order of the
+        // other lines doesn't depend on.
+        let text = match try_parse_code_points(line) {
+            Some(text) => text,
+            None => {
+                skipped += 1;
+                continue;
+            }
+        };
+        let key = collator.sort_key(&text);
+        if let Some((previous, previous_key)) = &previous {
+            assert_eq!(
+                collator
+                    .compare(previous, &text)
+                    .then_with(|| previous.cmp(&text)),
+                Ordering::Less,
+                "{:?} < {:?} ({})",
+                previous,
+                text,
+                line
+            );
+            assert!(
+                (previous_key, previous) < (&key, &text),
+                "Sort keys: {:?} < {:?}",
+                previous,
+                text
+            );
+        }
+        previous = Some((text, key));
+        count += 1;
+    }
+    assert!(count > 0);
+    assert_eq!(skipped, 30);
+}
+
+#[test]
+fn run_collation_test_non_ignorable() {
+    run_collation_test(
+        "./tests/testdata/CollationTest_CLDR_NON_IGNORABLE_SHORT.txt",
+        AlternateHandling::NonIgnorable,
+    );
+}
+
+#[test]
+fn run_collation_test_shifted() {
+    run_collation_test(
+        "./tests/testdata/CollationTest_CLDR_SHIFTED_SHORT.txt",
+        AlternateHandling::Shifted,
+    );
+}
+
+/// Checks the order of the strings in the sections of CollationTailoringTest.txt, with the
+/// level of each difference.
+#[test]
+fn run_tailoring_test() {
+    let provider = provider();
+    let f = BufReader::new(File::open("./tests/testdata/CollationTailoringTest.txt").unwrap());
+    let strengths = [
+        Strength::Primary,
+        Strength::Secondary,
+        Strength::Tertiary,
+        Strength::Quaternary,
+    ];
+
+    let mut locale = Locale::und();
+    let mut collator = None;
+    // Collators for the locale with each strength below identical, created when a difference
+    // at their level is checked
+    let mut by_strength: [Option<Collator>; 4] = Default::default();
+    let mut previous = String::new();
+    let mut count = 0;
+    for line in f.lines() {
+        let line = line.expect("Valid line");
+        let line = line.split('#').next().expect("Split has a first element");
+        if let Some(tag) = line.strip_prefix("@ ") {
+            locale = tag.parse().expect("Valid locale");
+            collator = Some(
+                Collator::try_new(&locale, &provider, CollatorOptions::default())
+                    .expect("Valid data"),
+            );
+            by_strength = Default::default();
+            previous.clear();
+            continue;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (relation, text) = match line.find(' ') {
+            Some(i) if line.starts_with('<') || line.starts_with('=') => {
+                (&line[..i], parse_code_points(&line[i..]))
+            }
+            _ => ("", parse_code_points(line)),
+        };
+        let collator = collator.as_ref().expect("Section with a locale");
+        let expected = match relation {
+            "" => {
+                previous = text;
+                continue;
+            }
+            "=" => Ordering::Equal,
+            _ => Ordering::Less,
+        };
+        assert_eq!(
+            collator.compare(&previous, &text),
+            expected,
+            "{:?} {} {:?}",
+            previous,
+            relation,
+            text
+        );
+        assert_eq!(
+            collator.sort_key(&previous).cmp(&collator.sort_key(&text)),
+            expected,
+            "Sort keys: {:?} {} {:?}",
+            previous,
+            relation,
+            text
+        );
+        // The strings are equal up to the level before the one of the difference, and differ at
+        // that level. Equal strings may differ at levels above the strength of the locale.
+        let level: usize = match relation {
+            "<1" => 1,
+            "<2" => 2,
+            "<3" => 3,
+            "<4" => 4,
+            _ => {
+                previous = text;
+                count += 1;
+                continue;
+            }
+        };
+        for &strength in &strengths[level.saturating_sub(2)..level] {
+            let collator = by_strength[strength as usize].get_or_insert_with(|| {
+                let options = CollatorOptions {
+                    strength: Some(strength),
+                    ..Default::default()
+                };
+                Collator::try_new(&locale, &provider, options).expect("Valid data")
+            });
+            let expected = if (strength as usize) < level - 1 {
+                Ordering::Equal
+            } else {
+                expected
+            };
+            assert_eq!(
+                collator.compare(&previous, &text),
+                expected,
+                "{:?} {} {:?} with {:?} strength",
+                previous,
+                relation,
+                text,
+                strength
+            );
+        }
+        previous = text;
+        count += 1;
+    }
+    assert!(count > 0);
+}
+
+#[test]
+fn test_options() {
+    let provider = provider();
+    let collator = |locale: &str, options| {
+        let locale: Locale = locale.parse().expect("Valid locale");
+        Collator::try_new(&locale, &provider, options).expect("Valid data")
+    };
+    let root = collator("und", CollatorOptions::default());
+    assert_eq!(root.compare("a", "A"), Ordering::Less);
+    assert_eq!(root.compare("a-b", "ab"), Ordering::Less);
+    assert_eq!(root.compare("10", "9"), Ordering::Less);
+
+    let options = CollatorOptions {
+        case_first: Some(icu_collator::CaseFirst::UpperFirst),
+        ..Default::default()
+    };
+    assert_eq!(
+        collator("und", options).compare("a", "A"),
+        Ordering::Greater
+    );
+    // Danish sorts uppercase first by default, unless the options say otherwise.
+    assert_eq!(
+        collator("da", CollatorOptions::default()).compare("a", "A"),
+        Ordering::Greater
+    );
+    assert_eq!(
+        collator("da-u-kf-lower", CollatorOptions::default()).compare("a", "A"),
+        Ordering::Less
+    );
+    let options = CollatorOptions {
+        case_first: Some(icu_collator::CaseFirst::Off),
+        ..Default::default()
+    };
+    assert_eq!(
+        collator("da-u-kf-upper", options).compare("a", "A"),
+        Ordering::Less
+    );
+
+    let mut options = CollatorOptions {
+        alternate_handling: Some(AlternateHandling::Shifted),
+        ..Default::default()
+    };
+    let shifted = collator("und", options);
+    assert_eq!(shifted.compare("a-b", "ab"), Ordering::Equal);
+    options.strength = Some(Strength::Quaternary);
+    assert_eq!(
+        collator("und", options).compare("a-b", "ab"),
+        Ordering::Less
+    );
+    // Symbols are only variable with a higher maximum variable group.
+    assert_eq!(shifted.compare("a+b", "ab"), Ordering::Less);
+    let options = CollatorOptions {
+        alternate_handling: Some(AlternateHandling::Shifted),
+        ..Default::default()
+    };
+    let shifted_symbols = collator("und-u-kv-symbol", options);
+    assert_eq!(shifted_symbols.compare("a+b", "ab"), Ordering::Equal);
+
+    let options = CollatorOptions {
+        numeric: Some(true),
+        ..Default::default()
+    };
+    let numeric = collator("und", options);
+    assert_eq!(numeric.compare("10", "9"), Ordering::Greater);
+    assert_eq!(numeric.compare("a 0010", "a 10"), Ordering::Equal);
+    assert_eq!(numeric.compare("\u{661}\u{660}", "9"), Ordering::Greater);
+    assert_eq!(
+        collator("und-u-kn", CollatorOptions::default()).compare("10", "9"),
+        Ordering::Greater
+    );
+    assert_eq!(
+        collator("und-u-kn-false", CollatorOptions::default()).compare("10", "9"),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn test_canonical_equivalence() {
+    let collator = Collator::try_new(
+        &langid!("sv").into(),
+        &provider(),
+        CollatorOptions::default(),
+    )
+    .expect("Valid data");
+
+    // Precomposed and decomposed forms, and marks in different orders, are equal.
+    assert_eq!(collator.compare("\u{C5}", "A\u{30A}"), Ordering::Equal);
+    assert_eq!(collator.compare("\u{212B}", "\u{C5}"), Ordering::Equal);
+    assert_eq!(
+        collator.compare("a\u{323}\u{302}", "a\u{302}\u{323}"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        collator.sort_key("\u{1EA0}\u{302}"),
+        collator.sort_key("A\u{302}\u{323}")
+    );
+    // The Swedish "ö" is a letter after "z", also when another mark is between the "o" and
+    // the diaeresis.
+    assert_eq!(collator.compare("o\u{308}", "z"), Ordering::Greater);
+    assert_eq!(collator.compare("o\u{323}\u{308}", "z"), Ordering::Greater);
+}
@@ -0,0 +1,250 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Generates tests/testdata/CollationTailoringTest.txt with ICU4C: words and random strings in
+// the order of some CLDR tailorings and collation options, with the level of each difference.
+//
+//     cc -o generate_tests generate_tests.c $(pkg-config --cflags --libs icu-i18n)
+//     ./generate_tests > ../tests/testdata/CollationTailoringTest.txt
+//
+// The output only depends on the ICU4C version, which is printed in the header.
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <unicode/ucol.h>
+#include <unicode/uloc.h>
+#include <unicode/ulocdata.h>
+#include <unicode/ustring.h>
+#include <unicode/utf16.h>
+#include <unicode/uversion.h>
+
+#define MAX_LENGTH 32
+#define MAX_KEY_LENGTH 1024
+
+static void check(UErrorCode err, const char *what) {
+    if (U_FAILURE(err)) {
+        fprintf(stderr, "%s: %s\n", what, u_errorName(err));
+        exit(1);
+    }
+}
+
+typedef struct {
+    UChar s[MAX_LENGTH];
+    int32_t length;
+} String;
+
+// The strings of the current section.
+static String *strings;
+static size_t strings_length, strings_capacity;
+
+// Adds the string of the `length` code points of `source`.
+static void add(const UChar32 *source, int length) {
+    if (strings_length == strings_capacity) {
+        strings_capacity = strings_capacity ? 2 * strings_capacity : 1024;
+        strings = realloc(strings, strings_capacity * sizeof(String));
+    }
+    String *s = &strings[strings_length];
+    s->length = 0;
+    for (int i = 0; i < length; i++) {
+        U16_APPEND_UNSAFE(s->s, s->length, source[i]);
+    }
+    strings_length++;
+}
+
+// Adds the words of `words`, which are separated by spaces, and where "_" stands for a space.
+static void add_words(const char *words) {
+    UErrorCode err = U_ZERO_ERROR;
+    UChar text[1024];
+    int32_t length;
+    u_strFromUTF8(text, 1024, &length, words, -1, &err);
+    check(err, "u_strFromUTF8");
+    UChar32 word[MAX_LENGTH];
+    int word_length = 0;
+    for (int32_t i = 0; i <= length;) {
+        UChar32 c = ' ';
+        if (i < length) {
+            U16_NEXT(text, i, length, c);
+        } else {
+            i++;
+        }
+        if (c != ' ') {
+            word[word_length++] = c == '_' ? ' ' : c;
+        } else if (word_length > 0) {
+            add(word, word_length);
+            word_length = 0;
+        }
+    }
+}
+
+// Compares two strings by their ICU4C sort keys, which follow the UCA in some cases where
+// ucol_strcoll() doesn't, like ignorable characters after a common prefix in shifted mode.
+static int compare(const UCollator *collator, const String *a, const String *b) {
+    uint8_t a_key[MAX_KEY_LENGTH], b_key[MAX_KEY_LENGTH];
+    int32_t a_length = ucol_getSortKey(collator, a->s, a->length, a_key, MAX_KEY_LENGTH);
+    int32_t b_length = ucol_getSortKey(collator, b->s, b->length, b_key, MAX_KEY_LENGTH);
+    if (a_length > MAX_KEY_LENGTH || b_length > MAX_KEY_LENGTH) {
+        fprintf(stderr, "Sort key too long\n");
+        exit(1);
+    }
+    return strcmp((const char *)a_key, (const char *)b_key);
+}
+
+static const UCollator *sorter;
+static int compare_for_sort(const void *a, const void *b) { return compare(sorter, a, b); }
+
+// A deterministic xorshift generator, so that the output only depends on the ICU4C version.
+static unsigned long long rng_state = 88172645463325252ULL;
+static unsigned rng(void) {
+    rng_state ^= rng_state << 13;
+    rng_state ^= rng_state >> 7;
+    rng_state ^= rng_state << 17;
+    return (unsigned)(rng_state >> 11);
+}
+
+static void print_string(const char *relation, const String *s) {
+    printf("%s", relation);
+    for (int32_t i = 0; i < s->length;) {
+        UChar32 c;
+        U16_NEXT(s->s, i, s->length, c);
+        printf(i > U16_LENGTH(c) ? " %04X" : "%04X", c);
+    }
+    printf("\n");
+}
+
+// Prints the section of the locale `tag`, with the words of `words`, and `random` random
+// strings of up to four code points of `letters`, in the order of the collator of the locale.
+static void print_section(const char *tag, const char *words, const char *letters, int random) {
+    UErrorCode err = U_ZERO_ERROR;
+    char id[128];
+    uloc_forLanguageTag(tag, id, sizeof id, NULL, &err);
+    check(err, "uloc_forLanguageTag");
+    UCollator *collator = ucol_open(id, &err);
+    check(err, "ucol_open");
+    ucol_setAttribute(collator, UCOL_NORMALIZATION_MODE, UCOL_ON, &err);
+    check(err, "ucol_setAttribute");
+
+    strings_length = 0;
+    add_words(words);
+    UChar text[128];
+    int32_t length;
+    u_strFromUTF8(text, 128, &length, letters, -1, &err);
+    check(err, "u_strFromUTF8");
+    UChar32 pool[128];
+    int pool_length = 0;
+    for (int32_t i = 0; i < length;) {
+        UChar32 c;
+        U16_NEXT(text, i, length, c);
+        pool[pool_length++] = c;
+    }
+    for (int n = 0; n < random; n++) {
+        UChar32 source[4];
+        int source_length = 1 + rng() % 4;
+        for (int i = 0; i < source_length; i++) {
+            source[i] = pool[rng() % pool_length];
+        }
+        add(source, source_length);
+    }
+    sorter = collator;
+    qsort(strings, strings_length, sizeof(String), compare_for_sort);
+
+    // Collators with each strength below identical, to find the level of each difference
+    static const UColAttributeValue STRENGTHS[] = {UCOL_PRIMARY, UCOL_SECONDARY, UCOL_TERTIARY,
+                                                   UCOL_QUATERNARY};
+    static const char *const RELATIONS[] = {"<1 ", "<2 ", "<3 ", "<4 "};
+    UCollator *levels[4];
+    for (int level = 0; level < 4; level++) {
+        levels[level] = ucol_clone(collator, &err);
+        check(err, "ucol_clone");
+        ucol_setStrength(levels[level], STRENGTHS[level]);
+    }
+
+    printf("\n@ %s\n", tag);
+    for (size_t i = 0; i < strings_length; i++) {
+        const char *relation = "";
+        if (i > 0) {
+            const String *a = &strings[i - 1], *b = &strings[i];
+            if (compare(collator, a, b) == 0) {
+                if (a->length == b->length && memcmp(a->s, b->s, a->length * sizeof(UChar)) == 0) {
+                    continue;
+                }
+                relation = "= ";
+            } else {
+                relation = "<i ";
+                for (int level = 0; level < 4; level++) {
+                    if (compare(levels[level], a, b) != 0) {
+                        relation = RELATIONS[level];
+                        break;
+                    }
+                }
+            }
+        }
+        print_string(relation, &strings[i]);
+    }
+    for (int level = 0; level < 4; level++) {
+        ucol_close(levels[level]);
+    }
+    ucol_close(collator);
+}
+
+#define LATIN "abcdehlnostyzACENOZ-_"
+// U+0301, U+0308, U+030A and U+0323
+#define MARKS "\xcc\x81\xcc\x88\xcc\x8a\xcc\xa3"
+#define LATIN_WORDS "a A b c C d e E h l n o O s t y z Z ab Ab aB cote coté côte côté"
+
+int main(void) {
+    UErrorCode err = U_ZERO_ERROR;
+    UVersionInfo info;
+    char icu[U_MAX_VERSION_STRING_LENGTH], cldr[U_MAX_VERSION_STRING_LENGTH],
+        unicode[U_MAX_VERSION_STRING_LENGTH];
+    u_getVersion(info);
+    u_versionToString(info, icu);
+    ulocdata_getCLDRVersion(info, &err);
+    check(err, "ulocdata_getCLDRVersion");
+    u_versionToString(info, cldr);
+    u_getUnicodeVersion(info);
+    u_versionToString(info, unicode);
+    printf("# Strings in the order of some CLDR tailorings and collation options.\n"
+           "#\n"
+           "# Each section starts with \"@\" and a locale. Each following line has the relation of\n"
+           "# the string to the one in the previous line: \"<1\" to \"<4\" for a difference at the\n"
+           "# primary to quaternary level, \"<i\" at the identical level, and \"=\" if the strings\n"
+           "# are equal.\n"
+           "#\n"
+           "# Generated by tools/generate_tests.c with ICU4C %s (CLDR %s, Unicode %s).\n",
+           icu, cldr, unicode);
+
+    print_section("da",
+                  "aa Aa AA å Å æ Æ ø Ø ä Ä ö Ö ü Ü y z ð Ð þ Þ th Th đ Đ ŋ ß ss aa_ Aaa aab "
+                  "Andersen Aabenraa Aalborg Ærø Øster Åen Ångström",
+                  LATIN "åÅæÆøØäöüðþ" MARKS, 250);
+    print_section("es", "n N ñ Ñ nz ny o ch Ch ll Ll c d l m canon cañon caña cana",
+                  LATIN "ñÑmchl" MARKS, 150);
+    print_section("sv", "v w V W å Å ä Ä æ ö Ö ø ü Ü y z þ ð ß đ aa Åsa Ära Öl ö_a",
+                  LATIN "åÅäÄöÖæøüvw" MARKS, 200);
+    print_section("tr", "c C ç Ç g G ğ Ğ h ı I i İ o ö O Ö s ş Ş u ü Ü iı ıi İI",
+                  LATIN "çÇğĞıIiİöÖşŞüÜ" MARKS, 250);
+    print_section("und-u-kn",
+                  "0 1 2 9 10 11 20 99 100 101 001 010 0 00 000 1a a1 a2 a10 a01 a001 "
+                  "1.5 1.10 1,000 1_000 -1 -10 ¹ ² ½ ① 12345678901234567890 12345678901234567891 "
+                  "١٢ ٣ १० ١٠ ０ １０ 𝟎 𝟏𝟎 x9 x10 x0009 x0010 9999 10000 99999999 100000000",
+                  "01293a ._١०０", 250);
+    print_section("und-u-kf-upper", LATIN_WORDS, LATIN MARKS "ÅåǅǄǆ", 200);
+    print_section("und-u-kf-lower", LATIN_WORDS, LATIN MARKS "ÅåǅǄǆ", 150);
+    print_section("da-u-kf-false", LATIN_WORDS " aa Aa æ Æ", LATIN "æÆåÅ", 100);
+    print_section("und-u-ks-level1", LATIN_WORDS " ß ss SS", LATIN MARKS "ß", 150);
+    print_section("und-u-ks-level2", LATIN_WORDS " ß ss SS", LATIN MARKS "ß", 150);
+    print_section("und-u-ks-level4", LATIN_WORDS " a-b ab a_b", LATIN MARKS "ß", 150);
+    print_section("und-u-ks-identic", LATIN_WORDS " ᾢ ᾢ Å Å", LATIN MARKS "Åᾢ", 150);
+    print_section("und-u-ka-shifted", "a-b ab a_b a.b a€b a$b a+b a%b ab- -ab a__b",
+                  LATIN ".$€%+ " MARKS, 250);
+    print_section("und-u-ka-shifted-ks-level4", "a-b ab a_b a.b a€b a$b a+b a%b ab- -ab a__b",
+                  LATIN ".$€%+ ", 250);
+    print_section("und-u-ka-shifted-kv-space-ks-level4", "a-b ab a_b a.b a€b a$b a+b",
+                  LATIN ".$€%+ ", 200);
+    print_section("und-u-ka-shifted-kv-currency-ks-level4", "a-b ab a_b a.b a€b a$b a+b a%b",
+                  LATIN ".$€%+ ", 200);
+    print_section("sv-u-ka-shifted-kn", "å a-b ab 10 9 a9 a10 ä-1", LATIN "åäö.9 01", 200);
+    return 0;
+}
@@ -13,6 +13,7 @@ use crate::{
 use alloc::string::String;
 use icu_locid::Locale;
 use icu_provider::prelude::*;
+use writeable::CoreWriteAsPartsWrite;
 
 use crate::{
     date::DateTimeInput, pattern::Pattern, provider, DateTimeFormatError, FormattedDateTime,
@@ -244,7 +245,7 @@ impl<'data> DateTimeFormat<'data> {
             self.symbols.as_ref().map(|s| s.get()),
             value,
             &self.locale,
-            w,
+            &mut CoreWriteAsPartsWrite(w),
         )
         .map_err(|_| core::fmt::Error)
     }
@@ -6,6 +6,7 @@ use crate::arithmetic;
 use crate::date::{DateTimeInput, DateTimeInputWithLocale, LocalizedDateTimeInput};
 use crate::error::DateTimeFormatError as Error;
 use crate::fields::{self, Field, FieldLength, FieldSymbol};
+use crate::parts;
 use crate::pattern::{Pattern, PatternItem};
 use crate::provider;
 use crate::provider::helpers::DateTimeSymbols;
@@ -13,7 +14,7 @@ use crate::provider::helpers::DateTimeSymbols;
 use alloc::string::ToString;
 use core::fmt;
 use icu_locid::Locale;
-use writeable::Writeable;
+use writeable::{CoreWriteAsPartsWrite, PartsWrite, Writeable};
 
 /// [`FormattedDateTime`] is a intermediate structure which can be retrieved as
 /// an output from [`DateTimeFormat`](crate::DateTimeFormat).
@@ -58,6 +59,10 @@ where
     T: DateTimeInput,
 {
     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.write_to_parts(&mut CoreWriteAsPartsWrite(sink))
+    }
+
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
         write_pattern(self.pattern, self.symbols, self.datetime, self.locale, sink)
             .map_err(|_| core::fmt::Error)
     }
@@ -70,8 +75,7 @@ where
     T: DateTimeInput,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_pattern(self.pattern, self.symbols, self.datetime, self.locale, f)
-            .map_err(|_| core::fmt::Error)
+        self.write_to(f)
     }
 }
 
@@ -104,7 +108,7 @@ pub fn write_pattern<T, W>(
 ) -> Result<(), Error>
 where
     T: DateTimeInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let loc_datetime = DateTimeInputWithLocale::new(datetime, locale);
     for item in pattern.items() {
@@ -130,44 +134,35 @@ pub(super) fn write_field<T, W>(
 ) -> Result<(), Error>
 where
     T: DateTimeInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     match field.symbol {
-        FieldSymbol::Year(..) => format_number(
-            w,
-            datetime
+        FieldSymbol::Year(..) => {
+            let year = datetime
                 .datetime()
                 .year()
                 .ok_or(Error::MissingInputField)?
-                .number as isize,
-            field.length,
-        )?,
-        FieldSymbol::Month(month) => match field.length {
-            FieldLength::One | FieldLength::TwoDigit => format_number(
-                w,
-                datetime
-                    .datetime()
-                    .month()
-                    .ok_or(Error::MissingInputField)?
-                    .number as isize,
-                field.length,
-            )?,
-            length => {
-                let symbol = symbols
-                    .expect("Expect symbols to be present")
-                    .get_symbol_for_month(
-                        month,
-                        length,
-                        datetime
-                            .datetime()
-                            .month()
-                            .ok_or(Error::MissingInputField)?
-                            .number as usize
-                            - 1,
-                    );
-                w.write_str(symbol)?
+                .number as isize;
+            w.with_part(parts::YEAR, |w| format_number(w, year, field.length))?
+        }
+        FieldSymbol::Month(month) => {
+            let number = datetime
+                .datetime()
+                .month()
+                .ok_or(Error::MissingInputField)?
+                .number;
+            match field.length {
+                FieldLength::One | FieldLength::TwoDigit => w.with_part(parts::MONTH, |w| {
+                    format_number(w, number as isize, field.length)
+                })?,
+                length => {
+                    let symbol = symbols
+                        .expect("Expect symbols to be present")
+                        .get_symbol_for_month(month, length, number as usize - 1);
+                    w.with_part(parts::MONTH, |w| w.write_str(symbol))?
+                }
             }
-        },
+        }
         FieldSymbol::Weekday(weekday) => {
             let dow = datetime
                 .datetime()
@@ -176,17 +171,16 @@ where
             let symbol = symbols
                 .expect("Expect symbols to be present")
                 .get_symbol_for_weekday(weekday, field.length, dow);
-            w.write_str(symbol)?
+            w.with_part(parts::WEEKDAY, |w| w.write_str(symbol))?
         }
-        FieldSymbol::Day(..) => format_number(
-            w,
-            datetime
+        FieldSymbol::Day(..) => {
+            let day = datetime
                 .datetime()
                 .day_of_month()
                 .ok_or(Error::MissingInputField)?
-                .0 as isize,
-            field.length,
-        )?,
+                .0 as isize;
+            w.with_part(parts::DAY, |w| format_number(w, day, field.length))?
+        }
         FieldSymbol::Hour(hour) => {
             let h =
                 usize::from(datetime.datetime().hour().ok_or(Error::MissingInputField)?) as isize;
@@ -209,28 +203,26 @@ where
                     }
                 }
             };
-            format_number(w, value, field.length)?
+            w.with_part(parts::HOUR, |w| format_number(w, value, field.length))?
         }
-        FieldSymbol::Minute => format_number(
-            w,
-            usize::from(
+        FieldSymbol::Minute => {
+            let minute = usize::from(
                 datetime
                     .datetime()
                     .minute()
                     .ok_or(Error::MissingInputField)?,
-            ) as isize,
-            field.length,
-        )?,
-        FieldSymbol::Second(..) => format_number(
-            w,
-            usize::from(
+            ) as isize;
+            w.with_part(parts::MINUTE, |w| format_number(w, minute, field.length))?
+        }
+        FieldSymbol::Second(..) => {
+            let second = usize::from(
                 datetime
                     .datetime()
                     .second()
                     .ok_or(Error::MissingInputField)?,
-            ) as isize,
-            field.length,
-        )?,
+            ) as isize;
+            w.with_part(parts::SECOND, |w| format_number(w, second, field.length))?
+        }
         FieldSymbol::DayPeriod(period) => {
             let symbol = symbols
                 .expect("Expect symbols to be present")
@@ -244,7 +236,7 @@ where
                         datetime.datetime().second().map(u8::from).unwrap_or(0),
                     ),
                 );
-            w.write_str(symbol)?
+            w.with_part(parts::DAY_PERIOD, |w| w.write_str(symbol))?
         }
         field @ FieldSymbol::TimeZone(_) => return Err(Error::UnsupportedField(field)),
     };
@@ -321,7 +313,7 @@ mod tests {
             Some(data.get()),
             &datetime,
             &"und".parse().unwrap(),
-            &mut sink,
+            &mut CoreWriteAsPartsWrite(&mut sink),
         )
         .unwrap();
         println!("{}", sink);
@@ -6,12 +6,13 @@ use core::fmt;
 
 use crate::error::DateTimeFormatError as Error;
 use crate::fields::{self, FieldSymbol};
+use crate::parts;
 use crate::pattern::{Error as PatternError, PatternItem};
 use crate::{
     date::TimeZoneInput,
     time_zone::{IsoFormat, IsoMinutes, IsoSeconds, TimeZoneFormat},
 };
-use writeable::Writeable;
+use writeable::{CoreWriteAsPartsWrite, PartsWrite, Writeable};
 
 pub struct FormattedTimeZone<'l, T>
 where
@@ -26,6 +27,10 @@ where
     T: TimeZoneInput,
 {
     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.write_to_parts(&mut CoreWriteAsPartsWrite(sink))
+    }
+
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
         write_pattern(self.time_zone_format, self.time_zone, sink).map_err(|_| core::fmt::Error)
     }
 
@@ -37,7 +42,7 @@ where
     T: TimeZoneInput,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_pattern(self.time_zone_format, self.time_zone, f).map_err(|_| core::fmt::Error)
+        self.write_to(f)
     }
 }
 
@@ -49,18 +54,38 @@ pub(crate) fn write_pattern<T, W>(
 ) -> Result<(), Error>
 where
     T: TimeZoneInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let pattern = &time_zone_format.pattern;
     for item in pattern.items() {
         match item {
-            PatternItem::Field(field) => write_field(field, time_zone_format, time_zone, w)?,
+            PatternItem::Field(field) => write_field_part(field, time_zone_format, time_zone, w)?,
             PatternItem::Literal(l) => w.write_str(l)?,
         }
     }
     Ok(())
 }
 
+/// Writes a field with [`write_field`] as a [`parts::TIME_ZONE_NAME`] part.
+pub(super) fn write_field_part<T, W>(
+    field: &fields::Field,
+    time_zone_format: &TimeZoneFormat,
+    time_zone: &T,
+    w: &mut W,
+) -> Result<(), Error>
+where
+    T: TimeZoneInput,
+    W: PartsWrite + ?Sized,
+{
+    // The part can only fail with a fmt::Error, so keep the error of the field.
+    let mut result = Ok(());
+    w.with_part(parts::TIME_ZONE_NAME, |w| {
+        result = write_field(field, time_zone_format, time_zone, w);
+        Ok(())
+    })?;
+    result
+}
+
 /// Write fields according to the UTS-35 specification.
 /// https://unicode.org/reports/tr35/tr35-dates.html#dfst-zone
 pub(super) fn write_field<T, W>(
@@ -8,7 +8,7 @@ use crate::fields::{self, FieldSymbol};
 use crate::pattern::PatternItem;
 use crate::{date::ZonedDateTimeInput, zoned_datetime::ZonedDateTimeFormat};
 use core::fmt;
-use writeable::Writeable;
+use writeable::{CoreWriteAsPartsWrite, PartsWrite, Writeable};
 
 use super::datetime;
 use super::time_zone;
@@ -26,6 +26,10 @@ where
     T: ZonedDateTimeInput,
 {
     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.write_to_parts(&mut CoreWriteAsPartsWrite(sink))
+    }
+
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
         write_pattern(self.zoned_datetime_format, self.zoned_datetime, sink)
             .map_err(|_| core::fmt::Error)
     }
@@ -38,8 +42,7 @@ where
     T: ZonedDateTimeInput,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_pattern(self.zoned_datetime_format, self.zoned_datetime, f)
-            .map_err(|_| core::fmt::Error)
+        self.write_to(f)
     }
 }
 
@@ -50,7 +53,7 @@ pub fn write_pattern<T, W>(
 ) -> Result<(), Error>
 where
     T: ZonedDateTimeInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let locale = &zoned_datetime_format.datetime_format.locale;
     let pattern = &zoned_datetime_format.datetime_format.pattern;
@@ -74,7 +77,7 @@ fn write_field<T, W>(
 ) -> Result<(), Error>
 where
     T: ZonedDateTimeInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let pattern = &zoned_datetime_format.datetime_format.pattern;
     let symbols = zoned_datetime_format
@@ -84,7 +87,7 @@ where
         .map(|s| s.get());
 
     match field.symbol {
-        FieldSymbol::TimeZone(_time_zone) => time_zone::write_field(
+        FieldSymbol::TimeZone(_time_zone) => time_zone::write_field_part(
             field,
             &zoned_datetime_format.time_zone_format,
             loc_datetime.datetime(),
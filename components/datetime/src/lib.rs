@@ -82,6 +82,7 @@ mod fields;
 mod format;
 pub mod mock;
 pub mod options;
+pub mod parts;
 #[doc(hidden)]
 pub mod pattern;
 pub mod provider;
@@ -0,0 +1,103 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The [`Part`]s of a [`FormattedDateTime`](crate::FormattedDateTime) and a
+//! [`FormattedZonedDateTime`](crate::FormattedZonedDateTime), which they write with
+//! [`Writeable::write_to_parts`](writeable::Writeable::write_to_parts).
+//!
+//! Every field of the pattern is written as a part; the literal text between fields is not
+//! annotated.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::datetime::mock::datetime::MockDateTime;
+//! use icu::datetime::{options::length, parts, DateTimeFormat};
+//! use icu::locid::macros::langid;
+//! use icu::locid::Locale;
+//! use writeable::assert_writeable_parts_eq;
+//!
+//! let provider = icu_testdata::get_provider();
+//! let locale: Locale = langid!("en").into();
+//! let options = length::Bag {
+//!     date: Some(length::Date::Medium),
+//!     time: Some(length::Time::Short),
+//!     ..Default::default()
+//! };
+//! let dtf = DateTimeFormat::try_new(locale, &provider, &options.into())
+//!     .expect("Failed to create DateTimeFormat instance.");
+//!
+//! let datetime: MockDateTime = "2020-09-01T12:34:28"
+//!     .parse()
+//!     .expect("Failed to parse DateTime.");
+//!
+//! assert_writeable_parts_eq!(
+//!     "Sep 1, 2020, 12:34 PM",
+//!     &dtf.format(&datetime),
+//!     [
+//!         (0, 3, parts::MONTH),
+//!         (4, 5, parts::DAY),
+//!         (7, 11, parts::YEAR),
+//!         (13, 15, parts::HOUR),
+//!         (16, 18, parts::MINUTE),
+//!         (19, 21, parts::DAY_PERIOD),
+//!     ]
+//! );
+//! ```
+
+use writeable::Part;
+
+/// The year.
+pub const YEAR: Part = Part {
+    category: "datetime",
+    value: "year",
+};
+
+/// The month, as a number or a name.
+pub const MONTH: Part = Part {
+    category: "datetime",
+    value: "month",
+};
+
+/// The day of the month.
+pub const DAY: Part = Part {
+    category: "datetime",
+    value: "day",
+};
+
+/// The name of the day of the week.
+pub const WEEKDAY: Part = Part {
+    category: "datetime",
+    value: "weekday",
+};
+
+/// The day period, like `AM`.
+pub const DAY_PERIOD: Part = Part {
+    category: "datetime",
+    value: "dayPeriod",
+};
+
+/// The hour.
+pub const HOUR: Part = Part {
+    category: "datetime",
+    value: "hour",
+};
+
+/// The minute.
+pub const MINUTE: Part = Part {
+    category: "datetime",
+    value: "minute",
+};
+
+/// The second.
+pub const SECOND: Part = Part {
+    category: "datetime",
+    value: "second",
+};
+
+/// The time zone, as a name, a location or an offset.
+pub const TIME_ZONE_NAME: Part = Part {
+    category: "datetime",
+    value: "timeZoneName",
+};
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use alloc::borrow::Cow;
+use icu_pattern::{DoublePlaceholder, SinglePlaceholder, TypedPattern};
 use icu_provider::yoke::{self, *};
 use litemap::LiteMap;
 use tinystr::TinyStr8;
@@ -50,17 +51,23 @@ macro_rules! map_access {
 pub struct TimeZoneFormatsV1<'data> {
     /// The hour format for displaying GMT offsets.
     pub hour_format: (Cow<'data, str>, Cow<'data, str>),
-    /// The localized GMT-offset format.
-    pub gmt_format: Cow<'data, str>,
+    /// The localized GMT-offset format, with the offset as `{0}`.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub gmt_format: TypedPattern<'data, SinglePlaceholder>,
     /// The localized GMT format with no offset.
     pub gmt_zero_format: Cow<'data, str>,
-    /// The format string for a region.
-    pub region_format: Cow<'data, str>,
-    /// The format strings for region format variants
+    /// The format pattern for a region, with the region as `{0}`.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub region_format: TypedPattern<'data, SinglePlaceholder>,
+    /// The format patterns for region format variants
     /// e.g. daylight, standard.
-    pub region_format_variants: LiteMap<Cow<'data, TinyStr8>, Cow<'data, str>>,
-    /// The format string to fall back to if data is unavailable.
-    pub fallback_format: Cow<'data, str>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub region_format_variants:
+        LiteMap<Cow<'data, TinyStr8>, TypedPattern<'data, SinglePlaceholder>>,
+    /// The format pattern to fall back to if data is unavailable, with the metazone name
+    /// as `{0}` and the location as `{1}`.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub fallback_format: TypedPattern<'data, DoublePlaceholder>,
 }
 
 /// An ICU4X mapping to the CLDR timeZoneNames exemplar cities.
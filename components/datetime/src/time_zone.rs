@@ -14,6 +14,7 @@ use crate::{
 use crate::{format::time_zone, provider::time_zones::TimeZoneFormatsV1Marker};
 use icu_locid::{LanguageIdentifier, Locale};
 use icu_provider::prelude::*;
+use writeable::{CoreWriteAsPartsWrite, Writeable};
 
 use crate::fields::{FieldSymbol, TimeZone};
 use crate::pattern::{Pattern, PatternItem};
@@ -348,7 +349,8 @@ impl<'data> TimeZoneFormat<'data> {
         w: &mut impl core::fmt::Write,
         value: &impl TimeZoneInput,
     ) -> fmt::Result {
-        time_zone::write_pattern(self, value, w).map_err(|_| core::fmt::Error)
+        time_zone::write_pattern(self, value, &mut CoreWriteAsPartsWrite(w))
+            .map_err(|_| core::fmt::Error)
     }
 
     /// Takes a [`TimeZoneInput`] implementer and returns a string with the formatted value.
@@ -398,22 +400,18 @@ impl<'data> TimeZoneFormat<'data> {
         sink: &mut W,
         time_zone: &impl TimeZoneInput,
     ) -> Result<(), DateTimeFormatError> {
-        // TODO(blocked on #277) Use formatter utility instead of replacing "{0}".
-        sink.write_str(
-            &self
-                .exemplar_cities
-                .as_ref()
-                .map(|p| p.get())
-                .and_then(|cities| time_zone.time_zone_id().and_then(|id| cities.get(id)))
-                .map(|location| {
-                    self.zone_formats
-                        .get()
-                        .region_format
-                        .replace("{0}", location)
-                })
-                .ok_or(fmt::Error)?,
-        )
-        .map_err(DateTimeFormatError::from)
+        let location = self
+            .exemplar_cities
+            .as_ref()
+            .map(|p| p.get())
+            .and_then(|cities| time_zone.time_zone_id().and_then(|id| cities.get(id)))
+            .ok_or(fmt::Error)?;
+        self.zone_formats
+            .get()
+            .region_format
+            .interpolate([&**location])
+            .write_to(sink)
+            .map_err(DateTimeFormatError::from)
     }
 
     /// Writes the time zone in short generic non-location format as defined by the UTS-35 spec.
@@ -514,26 +512,22 @@ impl<'data> TimeZoneFormat<'data> {
             sink.write_str(&self.zone_formats.get().gmt_zero_format.clone())
                 .map_err(DateTimeFormatError::from)
         } else {
-            // TODO(blocked on #277) Use formatter utility instead of replacing "{0}".
-            sink.write_str(
-                &self
-                    .zone_formats
-                    .get()
-                    .gmt_format
-                    .replace(
-                        "{0}",
-                        if gmt_offset.is_positive() {
-                            &self.zone_formats.get().hour_format.0
-                        } else {
-                            &self.zone_formats.get().hour_format.1
-                        },
-                    )
-                    // support all combos of "(HH|H):mm" by replacing longest patterns first.
-                    .replace("HH", &self.format_offset_hours(time_zone, ZeroPadding::On))
-                    .replace("mm", &self.format_offset_minutes(time_zone))
-                    .replace("H", &self.format_offset_hours(time_zone, ZeroPadding::Off)),
-            )
-            .map_err(DateTimeFormatError::from)
+            let hour_format = if gmt_offset.is_positive() {
+                &self.zone_formats.get().hour_format.0
+            } else {
+                &self.zone_formats.get().hour_format.1
+            };
+            let offset = hour_format
+                // support all combos of "(HH|H):mm" by replacing longest patterns first.
+                .replace("HH", &self.format_offset_hours(time_zone, ZeroPadding::On))
+                .replace("mm", &self.format_offset_minutes(time_zone))
+                .replace("H", &self.format_offset_hours(time_zone, ZeroPadding::Off));
+            self.zone_formats
+                .get()
+                .gmt_format
+                .interpolate([&*offset])
+                .write_to(sink)
+                .map_err(DateTimeFormatError::from)
         }
     }
 
@@ -5,6 +5,7 @@
 use alloc::string::String;
 use icu_locid::Locale;
 use icu_provider::{DataProvider, DataRequest, ResourceOptions, ResourcePath};
+use writeable::CoreWriteAsPartsWrite;
 
 use crate::{
     date::ZonedDateTimeInput,
@@ -237,7 +238,8 @@ impl<'data> ZonedDateTimeFormat<'data> {
         w: &mut impl core::fmt::Write,
         value: &impl ZonedDateTimeInput,
     ) -> core::fmt::Result {
-        zoned_datetime::write_pattern(self, value, w).map_err(|_| core::fmt::Error)
+        zoned_datetime::write_pattern(self, value, &mut CoreWriteAsPartsWrite(w))
+            .map_err(|_| core::fmt::Error)
     }
 
     /// Takes a [`ZonedDateTimeInput`] implementer and returns it formatted as a string.
@@ -363,3 +363,33 @@ fn constructing_datetime_format_with_time_zone_pattern_symbols_is_err() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_zoned_parts() {
+    use icu_datetime::options::length::{Bag, Time};
+    use icu_datetime::parts;
+    use icu_locid_macros::langid;
+    use writeable::assert_writeable_parts_eq;
+
+    let provider = icu_testdata::get_provider();
+    let options = DateTimeFormatOptions::Length(Bag {
+        date: None,
+        time: Some(Time::Full),
+        ..Default::default()
+    });
+    let zdtf = ZonedDateTimeFormat::try_new(langid!("en"), &provider, &provider, &options)
+        .expect("Failed to create ZonedDateTimeFormat instance.");
+    let value: MockZonedDateTime = "2021-04-08T16:12:37.000-07:00".parse().unwrap();
+
+    assert_writeable_parts_eq!(
+        "4:12:37 PM GMT-07:00",
+        &zdtf.format(&value),
+        [
+            (0, 1, parts::HOUR),
+            (2, 4, parts::MINUTE),
+            (5, 7, parts::SECOND),
+            (8, 10, parts::DAY_PERIOD),
+            (11, 20, parts::TIME_ZONE_NAME),
+        ]
+    );
+}
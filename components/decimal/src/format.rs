@@ -6,10 +6,11 @@
 
 use crate::grouper;
 use crate::options::*;
+use crate::parts;
 use crate::provider::*;
 use crate::sign_selector;
 use fixed_decimal::FixedDecimal;
-use writeable::Writeable;
+use writeable::{CoreWriteAsPartsWrite, Part, PartsWrite, Writeable};
 
 /// An intermediate structure returned by [`FixedDecimalFormat`](crate::FixedDecimalFormat).
 /// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
@@ -21,12 +22,12 @@ pub struct FormattedFixedDecimal<'l> {
 }
 
 impl<'l> FormattedFixedDecimal<'l> {
-    fn get_affixes(&self) -> Option<&AffixesV1> {
+    fn get_affixes(&self) -> Option<(Part, &AffixesV1)> {
         use sign_selector::SignSelection::*;
         match sign_selector::select(self.value.signum(), self.options.sign_display) {
-            Minus => Some(&self.symbols.minus_sign_affixes),
+            Minus => Some((parts::MINUS_SIGN, &self.symbols.minus_sign_affixes)),
             Neither => None,
-            Plus => Some(&self.symbols.plus_sign_affixes),
+            Plus => Some((parts::PLUS_SIGN, &self.symbols.plus_sign_affixes)),
         }
     }
 }
@@ -35,30 +36,57 @@ impl<'l> Writeable for FormattedFixedDecimal<'l> {
     fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
     where
         W: core::fmt::Write + ?Sized,
+    {
+        self.write_to_parts(&mut CoreWriteAsPartsWrite(sink))
+    }
+
+    fn write_to_parts<S>(&self, sink: &mut S) -> core::result::Result<(), core::fmt::Error>
+    where
+        S: PartsWrite + ?Sized,
     {
         let affixes = self.get_affixes();
-        if let Some(affixes) = affixes {
-            sink.write_str(&affixes.prefix)?;
+        if let Some((part, affixes)) = affixes {
+            if !affixes.prefix.is_empty() {
+                sink.with_part(part, |s| s.write_str(&affixes.prefix))?;
+            }
         }
+        // The digit at magnitude 0 is always visible.
         let range = self.value.magnitude_range();
         let upper_magnitude = *range.end();
-        for m in range.rev() {
-            if m == -1 {
-                sink.write_str(&self.symbols.decimal_separator)?;
-            }
-            let d = self.value.digit_at(m);
-            sink.write_char(self.symbols.digits[d as usize])?;
-            if grouper::check(
-                upper_magnitude,
-                m,
-                self.options.grouping_strategy,
-                &self.symbols.grouping_sizes,
-            ) {
-                sink.write_str(&self.symbols.grouping_separator)?;
+        let lower_magnitude = *range.start();
+        sink.with_part(parts::INTEGER, |s| {
+            for m in (0..=upper_magnitude).rev() {
+                let d = self.value.digit_at(m);
+                s.write_char(self.symbols.digits[d as usize])?;
+                if grouper::check(
+                    upper_magnitude,
+                    m,
+                    self.options.grouping_strategy,
+                    &self.symbols.grouping_sizes,
+                ) {
+                    s.with_part(parts::GROUP, |s| {
+                        s.write_str(&self.symbols.grouping_separator)
+                    })?;
+                }
             }
+            Ok(())
+        })?;
+        if lower_magnitude < 0 {
+            sink.with_part(parts::DECIMAL, |s| {
+                s.write_str(&self.symbols.decimal_separator)
+            })?;
+            sink.with_part(parts::FRACTION, |s| {
+                for m in (lower_magnitude..0).rev() {
+                    let d = self.value.digit_at(m);
+                    s.write_char(self.symbols.digits[d as usize])?;
+                }
+                Ok(())
+            })?;
         }
-        if let Some(affixes) = affixes {
-            sink.write_str(&affixes.suffix)?;
+        if let Some((part, affixes)) = affixes {
+            if !affixes.suffix.is_empty() {
+                sink.with_part(part, |s| s.write_str(&affixes.suffix))?;
+            }
         }
         Ok(())
     }
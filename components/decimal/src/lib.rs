@@ -64,6 +64,7 @@ pub mod error;
 pub mod format;
 mod grouper;
 pub mod options;
+pub mod parts;
 pub mod provider;
 mod sign_selector;
 
@@ -0,0 +1,76 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The [`Part`]s of a [`FormattedFixedDecimal`](crate::FormattedFixedDecimal), which it writes
+//! with [`Writeable::write_to_parts`](writeable::Writeable::write_to_parts).
+//!
+//! The [`INTEGER`] part contains the [`GROUP`] separators of the integer digits.
+//!
+//! # Examples
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu::decimal::parts;
+//! use icu::decimal::FixedDecimalFormat;
+//! use icu::locid::Locale;
+//! use writeable::assert_writeable_parts_eq;
+//!
+//! let provider = icu_provider::inv::InvariantDataProvider;
+//! let fdf = FixedDecimalFormat::try_new(Locale::und(), &provider, Default::default())
+//!     .expect("Data should load successfully");
+//!
+//! let fixed_decimal = FixedDecimal::from(-123456)
+//!     .multiplied_pow10(-2)
+//!     .expect("Operation is fully in range");
+//!
+//! assert_writeable_parts_eq!(
+//!     "-1,234.56",
+//!     &fdf.format(&fixed_decimal),
+//!     [
+//!         (0, 1, parts::MINUS_SIGN),
+//!         (1, 6, parts::INTEGER),
+//!         (2, 3, parts::GROUP),
+//!         (6, 7, parts::DECIMAL),
+//!         (7, 9, parts::FRACTION),
+//!     ]
+//! );
+//! ```
+
+use writeable::Part;
+
+/// The integer digits of a number, including the grouping separators.
+pub const INTEGER: Part = Part {
+    category: "decimal",
+    value: "integer",
+};
+
+/// A grouping separator, like `,` in `1,000`.
+pub const GROUP: Part = Part {
+    category: "decimal",
+    value: "group",
+};
+
+/// The decimal separator.
+pub const DECIMAL: Part = Part {
+    category: "decimal",
+    value: "decimal",
+};
+
+/// The fraction digits of a number.
+pub const FRACTION: Part = Part {
+    category: "decimal",
+    value: "fraction",
+};
+
+/// The affix of a negative number, like `-`.
+pub const MINUS_SIGN: Part = Part {
+    category: "decimal",
+    value: "minusSign",
+};
+
+/// The affix of a number with an explicit plus sign, like `+`.
+pub const PLUS_SIGN: Part = Part {
+    category: "decimal",
+    value: "plusSign",
+};
@@ -0,0 +1,218 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Normalization Forms C and KC, see [`ComposingNormalizer`].
+
+use crate::decompose::{DecomposingNormalizer, Decomposition};
+use crate::error::Error;
+use crate::hangul;
+use crate::provider::*;
+use crate::utf16;
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_provider::prelude::*;
+
+/// A normalizer to Normalization Form C (canonical decomposition followed by canonical
+/// composition) or KC (compatibility decomposition followed by canonical composition).
+///
+/// # Examples
+///
+/// ```
+/// use icu_normalizer::ComposingNormalizer;
+///
+/// let provider = icu_provider_uprops::NormalizerDataProvider::new(
+///     icu_testdata::paths::data_root().join("uprops"),
+/// );
+/// let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load successfully");
+/// let nfkc = ComposingNormalizer::try_new_nfkc(&provider).expect("Data should load successfully");
+///
+/// // U+1E9B LATIN SMALL LETTER LONG S WITH DOT ABOVE, U+0323 COMBINING DOT BELOW
+/// assert_eq!(nfc.normalize("\u{1E9B}\u{323}"), "\u{1E9B}\u{323}");
+/// assert_eq!(nfkc.normalize("\u{1E9B}\u{323}"), "\u{1E69}");
+///
+/// assert!(nfc.is_normalized("\u{E9}"));
+/// assert!(!nfc.is_normalized("e\u{301}"));
+/// ```
+pub struct ComposingNormalizer<'data> {
+    decomposing: DecomposingNormalizer<'data>,
+    compositions: DataPayload<'data, CanonicalCompositionsV1Marker>,
+}
+
+impl<'data> ComposingNormalizer<'data> {
+    /// Creates a normalizer to Normalization Form C.
+    pub fn try_new_nfc<D>(provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, DecompositionsV1Marker>
+            + DataProvider<'data, CanonicalCompositionsV1Marker>
+            + ?Sized,
+    {
+        Self::try_new(provider, DecomposingNormalizer::try_new_nfd(provider)?)
+    }
+
+    /// Creates a normalizer to Normalization Form KC.
+    pub fn try_new_nfkc<D>(provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, DecompositionsV1Marker>
+            + DataProvider<'data, CanonicalCompositionsV1Marker>
+            + ?Sized,
+    {
+        Self::try_new(provider, DecomposingNormalizer::try_new_nfkd(provider)?)
+    }
+
+    fn try_new<D>(provider: &D, decomposing: DecomposingNormalizer<'data>) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, CanonicalCompositionsV1Marker> + ?Sized,
+    {
+        let data_req = DataRequest {
+            resource_path: ResourcePath {
+                key: key::CANONICAL_COMPOSITIONS_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: None,
+                },
+            },
+        };
+        let resp: DataResponse<CanonicalCompositionsV1Marker> = provider.load_payload(&data_req)?;
+        Ok(ComposingNormalizer {
+            decomposing,
+            compositions: resp.take_payload()?,
+        })
+    }
+
+    /// Returns an iterator over the normalized characters of `iter`.
+    pub fn normalize_iter<I: Iterator<Item = char>>(&self, iter: I) -> Composition<'_, I> {
+        Composition {
+            decomposition: self.decomposing.normalize_iter(iter),
+            compositions: self.compositions.get(),
+            buffer: Vec::new(),
+            starter: None,
+            last_ccc: None,
+            ready: 0,
+            pos: 0,
+        }
+    }
+
+    /// Returns the normalized form of `text`.
+    pub fn normalize(&self, text: &str) -> String {
+        self.normalize_iter(text.chars()).collect()
+    }
+
+    /// Returns the normalized form of the UTF-16 `text`. Unpaired surrogates are replaced
+    /// with U+FFFD REPLACEMENT CHARACTER.
+    pub fn normalize_utf16(&self, text: &[u16]) -> Vec<u16> {
+        utf16::encode(self.normalize_iter(utf16::decode_lossy(text)))
+    }
+
+    /// Returns whether `text` is in the normalization form.
+    pub fn is_normalized(&self, text: &str) -> bool {
+        self.is_normalized_chars(text.chars())
+    }
+
+    /// Returns whether the UTF-16 `text` is in the normalization form. Text with unpaired
+    /// surrogates is not.
+    pub fn is_normalized_utf16(&self, text: &[u16]) -> bool {
+        utf16::is_well_formed(text) && self.is_normalized_chars(utf16::decode_lossy(text))
+    }
+
+    /// Checks the quick check values of the characters of `chars`, and only normalizes them
+    /// if some of them may combine with the characters before them.
+    fn is_normalized_chars(&self, chars: impl Iterator<Item = char> + Clone) -> bool {
+        let data = self.decomposing.data.get();
+        let qc_shift = if self.decomposing.compatibility {
+            NFKC_QC_SHIFT
+        } else {
+            NFC_QC_SHIFT
+        };
+        let mut last_ccc = 0;
+        let mut maybe = false;
+        for c in chars.clone() {
+            let value = data.get(c);
+            let ccc = (value & CCC_MASK) as u8;
+            if ccc != 0 && last_ccc > ccc {
+                return false;
+            }
+            match (value >> qc_shift) & QC_MASK {
+                QC_NO => return false,
+                QC_MAYBE => maybe = true,
+                _ => {}
+            }
+            last_ccc = ccc;
+        }
+        !maybe || self.normalize_iter(chars.clone()).eq(chars)
+    }
+}
+
+/// An iterator over the composed characters of another iterator, returned by
+/// [`ComposingNormalizer::normalize_iter()`].
+pub struct Composition<'a, I: Iterator<Item = char>> {
+    decomposition: Decomposition<'a, I>,
+    compositions: &'a CanonicalCompositionsV1<'a>,
+    /// The composed characters, from the start of the current segment.
+    buffer: Vec<char>,
+    /// The index in `buffer` of the starter that the following characters may combine with.
+    starter: Option<usize>,
+    /// The Canonical_Combining_Class of the last character in `buffer` that did not combine,
+    /// or `None` if that is the starter.
+    last_ccc: Option<u8>,
+    /// The number of characters at the start of `buffer` that can no longer change.
+    ready: usize,
+    /// The number of characters at the start of `buffer` that have been returned.
+    pos: usize,
+}
+
+impl<'a, I: Iterator<Item = char>> Composition<'a, I> {
+    fn compose(&self, first: char, second: char) -> Option<char> {
+        hangul::compose(first, second).or_else(|| self.compositions.compose(first, second))
+    }
+}
+
+impl<'a, I: Iterator<Item = char>> Iterator for Composition<'a, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(&c) = self.buffer[..self.ready].get(self.pos) {
+                self.pos += 1;
+                return Some(c);
+            }
+            if self.ready > 0 {
+                self.buffer.drain(..self.ready);
+                self.starter = self.starter.map(|starter| starter - self.ready);
+                self.ready = 0;
+                self.pos = 0;
+            }
+            let (c, ccc) = match self.decomposition.next_with_ccc() {
+                Some(item) => item,
+                None if self.buffer.is_empty() => return None,
+                None => {
+                    self.starter = None;
+                    self.ready = self.buffer.len();
+                    continue;
+                }
+            };
+            if let Some(starter) = self.starter {
+                // The decomposition is in canonical order, so a character is blocked from the
+                // starter exactly if the last character that did not combine has a class
+                // that is not lower.
+                let blocked = matches!(self.last_ccc, Some(last_ccc) if last_ccc >= ccc);
+                if !blocked {
+                    if let Some(composite) = self.compose(self.buffer[starter], c) {
+                        self.buffer[starter] = composite;
+                        continue;
+                    }
+                }
+            }
+            if ccc == 0 {
+                // Nothing after a starter that did not combine can combine with the
+                // characters before it.
+                self.ready = self.buffer.len();
+                self.starter = Some(self.buffer.len());
+                self.last_ccc = None;
+            } else {
+                self.last_ccc = Some(ccc);
+            }
+            self.buffer.push(c);
+        }
+    }
+}
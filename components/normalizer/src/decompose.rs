@@ -0,0 +1,220 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Normalization Forms D and KD, see [`DecomposingNormalizer`].
+
+use crate::error::Error;
+use crate::hangul;
+use crate::provider::*;
+use crate::utf16;
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_provider::prelude::*;
+
+/// A normalizer to Normalization Form D (canonical decomposition) or KD (compatibility
+/// decomposition).
+///
+/// # Examples
+///
+/// ```
+/// use icu_normalizer::DecomposingNormalizer;
+///
+/// let provider = icu_provider_uprops::NormalizerDataProvider::new(
+///     icu_testdata::paths::data_root().join("uprops"),
+/// );
+/// let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load successfully");
+/// let nfkd = DecomposingNormalizer::try_new_nfkd(&provider).expect("Data should load successfully");
+///
+/// // U+1E9B LATIN SMALL LETTER LONG S WITH DOT ABOVE, U+0323 COMBINING DOT BELOW
+/// assert_eq!(nfd.normalize("\u{1E9B}\u{323}"), "\u{17F}\u{323}\u{307}");
+/// assert_eq!(nfkd.normalize("\u{1E9B}\u{323}"), "s\u{323}\u{307}");
+///
+/// assert!(nfd.is_normalized("e\u{301}"));
+/// assert!(!nfd.is_normalized("\u{E9}"));
+/// ```
+pub struct DecomposingNormalizer<'data> {
+    pub(crate) data: DataPayload<'data, DecompositionsV1Marker>,
+    pub(crate) compatibility: bool,
+}
+
+impl<'data> DecomposingNormalizer<'data> {
+    /// Creates a normalizer to Normalization Form D.
+    pub fn try_new_nfd<D>(provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, DecompositionsV1Marker> + ?Sized,
+    {
+        Self::try_new(provider, false)
+    }
+
+    /// Creates a normalizer to Normalization Form KD.
+    pub fn try_new_nfkd<D>(provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, DecompositionsV1Marker> + ?Sized,
+    {
+        Self::try_new(provider, true)
+    }
+
+    fn try_new<D>(provider: &D, compatibility: bool) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, DecompositionsV1Marker> + ?Sized,
+    {
+        let data_req = DataRequest {
+            resource_path: ResourcePath {
+                key: key::DECOMPOSITIONS_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: None,
+                },
+            },
+        };
+        let resp: DataResponse<DecompositionsV1Marker> = provider.load_payload(&data_req)?;
+        Ok(DecomposingNormalizer {
+            data: resp.take_payload()?,
+            compatibility,
+        })
+    }
+
+    /// Returns an iterator over the normalized characters of `iter`.
+    pub fn normalize_iter<I: Iterator<Item = char>>(&self, iter: I) -> Decomposition<'_, I> {
+        Decomposition {
+            data: self.data.get(),
+            compatibility: self.compatibility,
+            iter,
+            buffer: Vec::new(),
+            ready: 0,
+            pos: 0,
+        }
+    }
+
+    /// Returns the normalized form of `text`.
+    pub fn normalize(&self, text: &str) -> String {
+        self.normalize_iter(text.chars()).collect()
+    }
+
+    /// Returns the normalized form of the UTF-16 `text`. Unpaired surrogates are replaced
+    /// with U+FFFD REPLACEMENT CHARACTER.
+    pub fn normalize_utf16(&self, text: &[u16]) -> Vec<u16> {
+        utf16::encode(self.normalize_iter(utf16::decode_lossy(text)))
+    }
+
+    /// Returns whether `text` is in the normalization form.
+    pub fn is_normalized(&self, text: &str) -> bool {
+        self.is_normalized_chars(text.chars())
+    }
+
+    /// Returns whether the UTF-16 `text` is in the normalization form. Text with unpaired
+    /// surrogates is not.
+    pub fn is_normalized_utf16(&self, text: &[u16]) -> bool {
+        utf16::is_well_formed(text) && self.is_normalized_chars(utf16::decode_lossy(text))
+    }
+
+    fn is_normalized_chars(&self, chars: impl Iterator<Item = char>) -> bool {
+        let data = self.data.get();
+        let decomposition_flags = if self.compatibility {
+            HAS_CANONICAL_DECOMPOSITION | HAS_COMPATIBILITY_DECOMPOSITION
+        } else {
+            HAS_CANONICAL_DECOMPOSITION
+        };
+        let mut last_ccc = 0;
+        for c in chars {
+            let value = data.get(c);
+            let ccc = (value & CCC_MASK) as u8;
+            if value & decomposition_flags != 0
+                || hangul::is_syllable(c)
+                || (ccc != 0 && last_ccc > ccc)
+            {
+                return false;
+            }
+            last_ccc = ccc;
+        }
+        true
+    }
+}
+
+/// An iterator over the decomposed characters of another iterator, returned by
+/// [`DecomposingNormalizer::normalize_iter()`].
+pub struct Decomposition<'a, I: Iterator<Item = char>> {
+    data: &'a DecompositionsV1<'a>,
+    compatibility: bool,
+    iter: I,
+    /// The decomposed characters with their Canonical_Combining_Class, from the start of the
+    /// current segment.
+    buffer: Vec<(char, u8)>,
+    /// The number of characters at the start of `buffer` that are in canonical order.
+    ready: usize,
+    /// The number of characters at the start of `buffer` that have been returned.
+    pos: usize,
+}
+
+impl<'a, I: Iterator<Item = char>> Decomposition<'a, I> {
+    /// Returns the next character with its Canonical_Combining_Class.
+    pub fn next_with_ccc(&mut self) -> Option<(char, u8)> {
+        loop {
+            if let Some(&item) = self.buffer[..self.ready].get(self.pos) {
+                self.pos += 1;
+                return Some(item);
+            }
+            if self.ready > 0 {
+                self.buffer.drain(..self.ready);
+                self.ready = 0;
+                self.pos = 0;
+            }
+            match self.iter.next() {
+                Some(c) => self.push_decomposition(c),
+                None if self.buffer.is_empty() => return None,
+                None => {
+                    self.sort_marks();
+                    self.ready = self.buffer.len();
+                }
+            }
+        }
+    }
+
+    fn push_decomposition(&mut self, c: char) {
+        let data = self.data;
+        if let Some((l, v, t)) = hangul::decompose(c) {
+            self.push(l);
+            self.push(v);
+            if let Some(t) = t {
+                self.push(t);
+            }
+        } else if let Some(decomposition) = data.decomposition(c, self.compatibility) {
+            for d in decomposition {
+                self.push(d);
+            }
+        } else {
+            self.push(c);
+        }
+    }
+
+    fn push(&mut self, c: char) {
+        let ccc = self.data.canonical_combining_class(c);
+        if ccc == 0 {
+            // A starter ends the segment before it.
+            self.sort_marks();
+            self.ready = self.buffer.len();
+        }
+        self.buffer.push((c, ccc));
+    }
+
+    /// Puts the characters after `ready`, a starter followed by non-starters or only
+    /// non-starters, into canonical order.
+    fn sort_marks(&mut self) {
+        let segment = &mut self.buffer[self.ready..];
+        let marks = match segment.first() {
+            Some(&(_, 0)) => &mut segment[1..],
+            _ => segment,
+        };
+        // The sort is stable, so characters with the same class keep their order.
+        marks.sort_by_key(|&(_, ccc)| ccc);
+    }
+}
+
+impl<'a, I: Iterator<Item = char>> Iterator for Decomposition<'a, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.next_with_ccc().map(|(c, _)| c)
+    }
+}
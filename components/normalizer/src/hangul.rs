@@ -0,0 +1,59 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The algorithmic decomposition and composition of Hangul syllables, see section 3.12 of
+//! the Unicode Standard.
+
+const S_BASE: u32 = 0xac00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11a7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+fn from_u32(c: u32) -> char {
+    char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+/// Returns whether `c` is a precomposed Hangul syllable.
+pub fn is_syllable(c: char) -> bool {
+    (c as u32).wrapping_sub(S_BASE) < S_COUNT
+}
+
+/// Returns the leading consonant, the vowel and the optional trailing consonant jamo of the
+/// Hangul syllable `c`, or `None` if `c` is not a Hangul syllable.
+pub fn decompose(c: char) -> Option<(char, char, Option<char>)> {
+    let s_index = (c as u32).wrapping_sub(S_BASE);
+    if s_index >= S_COUNT {
+        return None;
+    }
+    let l = from_u32(L_BASE + s_index / N_COUNT);
+    let v = from_u32(V_BASE + (s_index % N_COUNT) / T_COUNT);
+    let t_index = s_index % T_COUNT;
+    let t = if t_index == 0 {
+        None
+    } else {
+        Some(from_u32(T_BASE + t_index))
+    };
+    Some((l, v, t))
+}
+
+/// Returns the composition of a leading consonant and a vowel jamo, or of a Hangul syllable
+/// without a trailing consonant and a trailing consonant jamo.
+pub fn compose(first: char, second: char) -> Option<char> {
+    let l_index = (first as u32).wrapping_sub(L_BASE);
+    let v_index = (second as u32).wrapping_sub(V_BASE);
+    if l_index < L_COUNT && v_index < V_COUNT {
+        return Some(from_u32(S_BASE + (l_index * V_COUNT + v_index) * T_COUNT));
+    }
+    let s_index = (first as u32).wrapping_sub(S_BASE);
+    let t_index = (second as u32).wrapping_sub(T_BASE);
+    if s_index < S_COUNT && s_index % T_COUNT == 0 && 0 < t_index && t_index < T_COUNT {
+        return Some(from_u32(first as u32 + t_index));
+    }
+    None
+}
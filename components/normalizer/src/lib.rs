@@ -0,0 +1,63 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_normalizer`](crate) is an implementation of the Unicode Normalization Forms, as
+//! specified in [Unicode Standard Annex #15][UAX15].
+//!
+//! It normalizes text to Normalization Form C or KC with a [`ComposingNormalizer`], and to
+//! Normalization Form D or KD with a [`DecomposingNormalizer`]. Both of them normalize strings,
+//! UTF-16 slices and iterators over characters, and quickly check whether text is already
+//! normalized. The decompositions and compositions they need are loaded from a data provider.
+//!
+//! [UAX15]: https://www.unicode.org/reports/tr15/
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
+//!
+//! let provider = icu_provider_uprops::NormalizerDataProvider::new(
+//!     icu_testdata::paths::data_root().join("uprops"),
+//! );
+//! let nfc = ComposingNormalizer::try_new_nfc(&provider).expect("Data should load successfully");
+//! let nfd = DecomposingNormalizer::try_new_nfd(&provider).expect("Data should load successfully");
+//! let nfkc = ComposingNormalizer::try_new_nfkc(&provider).expect("Data should load successfully");
+//!
+//! // "Å" as U+212B ANGSTROM SIGN, U+00C5 LATIN CAPITAL LETTER A WITH RING ABOVE, and
+//! // U+0041 LATIN CAPITAL LETTER A followed by U+030A COMBINING RING ABOVE
+//! assert_eq!(nfc.normalize("\u{212B}"), "\u{C5}");
+//! assert_eq!(nfd.normalize("\u{212B}"), "A\u{30A}");
+//! assert_eq!(nfc.normalize("A\u{30A}"), "\u{C5}");
+//!
+//! // The compatibility forms also replace formatting variants, like the ligature "ﬁ".
+//! assert_eq!(nfc.normalize("\u{FB01}"), "\u{FB01}");
+//! assert_eq!(nfkc.normalize("\u{FB01}"), "fi");
+//!
+//! // Hangul syllables are composed from their jamo.
+//! let jamo = ['\u{1112}', '\u{1161}', '\u{11AB}'];
+//! assert!(nfc.normalize_iter(jamo.iter().copied()).eq("한".chars()));
+//! assert!(nfc.is_normalized("한"));
+//! assert!(!nfd.is_normalized("한"));
+//! ```
+//!
+//! # Conformance tests
+//!
+//! The conformance tests run every case of `tests/testdata/NormalizationTest.txt`, a copy of the
+//! file of the same name in the Unicode Character Database, for all four normalization forms on
+//! both UTF-8 and UTF-16 text.
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod compose;
+mod decompose;
+pub mod error;
+mod hangul;
+pub mod provider;
+mod utf16;
+
+pub use compose::{ComposingNormalizer, Composition};
+pub use decompose::{DecomposingNormalizer, Decomposition};
+pub use error::Error as NormalizerError;
@@ -0,0 +1,140 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_codepointtrie::codepointtrie::{CodePointTrie, Small};
+use icu_provider::yoke::{self, *};
+use zerovec::ZeroVec;
+
+pub mod key {
+    //! Resource keys for [`icu_normalizer`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: the canonical and compatibility decompositions of all code points.
+    pub const DECOMPOSITIONS_V1: ResourceKey = resource_key!(Normalizer, "decompositions", 1);
+
+    /// Resource key: the canonical compositions of pairs of code points.
+    pub const CANONICAL_COMPOSITIONS_V1: ResourceKey = resource_key!(Normalizer, "compositions", 1);
+}
+
+/// Mask for the Canonical_Combining_Class in a [`DecompositionsV1`] trie value.
+pub const CCC_MASK: u32 = 0xff;
+/// Set in a [`DecompositionsV1`] trie value if the code point has a canonical decomposition.
+pub const HAS_CANONICAL_DECOMPOSITION: u32 = 1 << 8;
+/// Set in a [`DecompositionsV1`] trie value if the code point has a compatibility
+/// decomposition that differs from its canonical decomposition.
+pub const HAS_COMPATIBILITY_DECOMPOSITION: u32 = 1 << 9;
+/// Shift of the NFC_Quick_Check value in a [`DecompositionsV1`] trie value.
+pub const NFC_QC_SHIFT: u32 = 10;
+/// Shift of the NFKC_Quick_Check value in a [`DecompositionsV1`] trie value.
+pub const NFKC_QC_SHIFT: u32 = 12;
+/// Mask for a quick check value, after shifting it by [`NFC_QC_SHIFT`] or [`NFKC_QC_SHIFT`].
+pub const QC_MASK: u32 = 0x3;
+/// The quick check value for code points that may occur in the normalization form.
+pub const QC_YES: u32 = 0;
+/// The quick check value for code points that never occur in the normalization form.
+pub const QC_NO: u32 = 1;
+/// The quick check value for code points that may combine with a preceding code point,
+/// so that whether they occur in the normalization form depends on the context.
+pub const QC_MAYBE: u32 = 2;
+/// Shift of the index into [`DecompositionsV1::mappings`] in a trie value.
+pub const MAPPING_INDEX_SHIFT: u32 = 14;
+
+/// The full canonical and compatibility decompositions of all code points, with their
+/// Canonical_Combining_Class and NFC/NFKC quick check values.
+///
+/// For each code point, `code_point_trie` stores a value with
+/// - the Canonical_Combining_Class in the bits of [`CCC_MASK`],
+/// - the [`HAS_CANONICAL_DECOMPOSITION`] and [`HAS_COMPATIBILITY_DECOMPOSITION`] flags,
+/// - the NFC_Quick_Check and NFKC_Quick_Check values at [`NFC_QC_SHIFT`] and
+///   [`NFKC_QC_SHIFT`], and
+/// - an index into `mappings` in the bits starting at [`MAPPING_INDEX_SHIFT`].
+///
+/// At the index, `mappings` contains the length of the canonical decomposition followed by its
+/// code points if the code point has one, and then the length and code points of the
+/// compatibility decomposition if it differs. The decompositions are fully decomposed and in
+/// canonical order. Hangul syllables are decomposed algorithmically and have no mappings.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct DecompositionsV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub code_point_trie: CodePointTrie<'data, u32, Small>,
+
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub mappings: ZeroVec<'data, u32>,
+}
+
+impl DecompositionsV1<'_> {
+    /// Returns the trie value of `c`.
+    pub fn get(&self, c: char) -> u32 {
+        self.code_point_trie.get(c as u32)
+    }
+
+    /// Returns the Canonical_Combining_Class of `c`.
+    pub fn canonical_combining_class(&self, c: char) -> u8 {
+        (self.get(c) & CCC_MASK) as u8
+    }
+
+    /// Returns the full canonical decomposition of `c`, or the compatibility decomposition if
+    /// `compatibility` is set, unless `c` is a Hangul syllable or decomposes to itself.
+    pub fn decomposition(
+        &self,
+        c: char,
+        compatibility: bool,
+    ) -> Option<impl Iterator<Item = char> + '_> {
+        let value = self.get(c);
+        let mut index = (value >> MAPPING_INDEX_SHIFT) as usize;
+        let canonical = value & HAS_CANONICAL_DECOMPOSITION != 0;
+        if compatibility && value & HAS_COMPATIBILITY_DECOMPOSITION != 0 {
+            if canonical {
+                index += self.mappings.get(index)? as usize + 1;
+            }
+        } else if !canonical {
+            return None;
+        }
+        let len = self.mappings.get(index)? as usize;
+        Some(
+            (index + 1..index + 1 + len)
+                .filter_map(move |i| self.mappings.get(i))
+                .map(|c| char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER)),
+        )
+    }
+}
+
+/// The canonical compositions of pairs of code points into primary composites, except for
+/// Hangul syllables, which are composed algorithmically.
+///
+/// `pairs` contains the pairs of code points, as the first code point shifted left by 32 bits
+/// plus the second one, in ascending order. `composites` contains the composition of each pair.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct CanonicalCompositionsV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub pairs: ZeroVec<'data, u64>,
+
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub composites: ZeroVec<'data, char>,
+}
+
+impl CanonicalCompositionsV1<'_> {
+    /// Returns the primary composite of `first` and `second`, if they have one.
+    pub fn compose(&self, first: char, second: char) -> Option<char> {
+        let pair = (u64::from(first) << 32) | u64::from(second);
+        let i = self.pairs.binary_search(&pair).ok()?;
+        self.composites.get(i)
+    }
+}
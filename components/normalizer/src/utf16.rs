@@ -0,0 +1,27 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Conversions between UTF-16 and characters for the normalizers.
+
+use alloc::vec::Vec;
+
+/// Returns the characters of `text`, with U+FFFD REPLACEMENT CHARACTER for unpaired surrogates.
+pub fn decode_lossy(text: &[u16]) -> impl Iterator<Item = char> + Clone + '_ {
+    core::char::decode_utf16(text.iter().copied()).map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+}
+
+/// Returns whether `text` has no unpaired surrogates.
+pub fn is_well_formed(text: &[u16]) -> bool {
+    core::char::decode_utf16(text.iter().copied()).all(|c| c.is_ok())
+}
+
+/// Encodes `chars` in UTF-16.
+pub fn encode(chars: impl Iterator<Item = char>) -> Vec<u16> {
+    let mut result = Vec::new();
+    let mut buffer = [0; 2];
+    for c in chars {
+        result.extend_from_slice(c.encode_utf16(&mut buffer));
+    }
+    result
+}
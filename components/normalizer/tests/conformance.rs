@@ -0,0 +1,229 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
+use icu_provider_uprops::NormalizerDataProvider;
+use std::char;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+
+struct Normalizers<'data> {
+    nfc: ComposingNormalizer<'data>,
+    nfd: DecomposingNormalizer<'data>,
+    nfkc: ComposingNormalizer<'data>,
+    nfkd: DecomposingNormalizer<'data>,
+}
+
+impl Normalizers<'_> {
+    fn new() -> Self {
+        let provider = NormalizerDataProvider::new(icu_testdata::paths::data_root().join("uprops"));
+        Normalizers {
+            nfc: ComposingNormalizer::try_new_nfc(&provider).expect("Valid data"),
+            nfd: DecomposingNormalizer::try_new_nfd(&provider).expect("Valid data"),
+            nfkc: ComposingNormalizer::try_new_nfkc(&provider).expect("Valid data"),
+            nfkd: DecomposingNormalizer::try_new_nfkd(&provider).expect("Valid data"),
+        }
+    }
+
+    fn check_nfc(&self, source: &str, expected: &str, line: &str) {
+        check("NFC", &self.nfc, source, expected, line);
+    }
+
+    fn check_nfd(&self, source: &str, expected: &str, line: &str) {
+        check("NFD", &self.nfd, source, expected, line);
+    }
+
+    fn check_nfkc(&self, source: &str, expected: &str, line: &str) {
+        check("NFKC", &self.nfkc, source, expected, line);
+    }
+
+    fn check_nfkd(&self, source: &str, expected: &str, line: &str) {
+        check("NFKD", &self.nfkd, source, expected, line);
+    }
+}
+
+/// The methods shared by the composing and decomposing normalizers.
+trait Normalize {
+    fn normalize(&self, text: &str) -> String;
+    fn normalize_utf16(&self, text: &[u16]) -> Vec<u16>;
+    fn is_normalized(&self, text: &str) -> bool;
+    fn is_normalized_utf16(&self, text: &[u16]) -> bool;
+}
+
+macro_rules! impl_normalize {
+    ($normalizer:ty) => {
+        impl Normalize for $normalizer {
+            fn normalize(&self, text: &str) -> String {
+                self.normalize(text)
+            }
+            fn normalize_utf16(&self, text: &[u16]) -> Vec<u16> {
+                self.normalize_utf16(text)
+            }
+            fn is_normalized(&self, text: &str) -> bool {
+                self.is_normalized(text)
+            }
+            fn is_normalized_utf16(&self, text: &[u16]) -> bool {
+                self.is_normalized_utf16(text)
+            }
+        }
+    };
+}
+
+impl_normalize!(ComposingNormalizer<'_>);
+impl_normalize!(DecomposingNormalizer<'_>);
+
+/// Checks the normalization of `source` to the form `name`, and that of its UTF-16 form.
+fn check(name: &str, normalizer: &impl Normalize, source: &str, expected: &str, line: &str) {
+    assert_eq!(normalizer.normalize(source), expected, "{}: {}", name, line);
+    assert_eq!(
+        normalizer.is_normalized(source),
+        source == expected,
+        "{}: {}",
+        name,
+        line
+    );
+    let utf16: Vec<u16> = source.encode_utf16().collect();
+    let expected_utf16: Vec<u16> = expected.encode_utf16().collect();
+    assert_eq!(
+        normalizer.normalize_utf16(&utf16),
+        expected_utf16,
+        "{}: {}",
+        name,
+        line
+    );
+    assert_eq!(
+        normalizer.is_normalized_utf16(&utf16),
+        source == expected,
+        "{}: {}",
+        name,
+        line
+    );
+}
+
+fn parse_code_points(field: &str) -> String {
+    field
+        .split_ascii_whitespace()
+        .map(|c| u32::from_str_radix(c, 16).expect("Valid hex"))
+        .map(|c| char::from_u32(c).expect("Valid code point"))
+        .collect()
+}
+
+#[test]
+fn run_normalization_test() {
+    let normalizers = Normalizers::new();
+    let f = File::open("./tests/testdata/NormalizationTest.txt");
+    let f = BufReader::new(f.unwrap());
+    let mut part = String::new();
+    let mut part1_chars = HashSet::new();
+
+    for line in f.lines() {
+        let line = line.expect("Valid line");
+        let line = line
+            .split('#')
+            .next()
+            .expect("Split has a first element")
+            .trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('@') {
+            part = name.trim().to_string();
+            continue;
+        }
+
+        let fields: Vec<String> = line.split(';').take(5).map(parse_code_points).collect();
+        let (c1, c2, c3, c4, c5) = (&fields[0], &fields[1], &fields[2], &fields[3], &fields[4]);
+        if part == "Part1" {
+            part1_chars.insert(c1.chars().next().expect("One character"));
+        }
+
+        // NFC
+        //   c2 ==  toNFC(c1) ==  toNFC(c2) ==  toNFC(c3)
+        //   c4 ==  toNFC(c4) ==  toNFC(c5)
+        normalizers.check_nfc(c1, c2, line);
+        normalizers.check_nfc(c2, c2, line);
+        normalizers.check_nfc(c3, c2, line);
+        normalizers.check_nfc(c4, c4, line);
+        normalizers.check_nfc(c5, c4, line);
+
+        // NFD
+        //   c3 ==  toNFD(c1) ==  toNFD(c2) ==  toNFD(c3)
+        //   c5 ==  toNFD(c4) ==  toNFD(c5)
+        normalizers.check_nfd(c1, c3, line);
+        normalizers.check_nfd(c2, c3, line);
+        normalizers.check_nfd(c3, c3, line);
+        normalizers.check_nfd(c4, c5, line);
+        normalizers.check_nfd(c5, c5, line);
+
+        // NFKC
+        //   c4 == toNFKC(c1) == toNFKC(c2) == toNFKC(c3) == toNFKC(c4) == toNFKC(c5)
+        for source in fields.iter() {
+            normalizers.check_nfkc(source, c4, line);
+        }
+
+        // NFKD
+        //   c5 == toNFKD(c1) == toNFKD(c2) == toNFKD(c3) == toNFKD(c4) == toNFKD(c5)
+        for source in fields.iter() {
+            normalizers.check_nfkd(source, c5, line);
+        }
+    }
+
+    assert!(!part1_chars.is_empty());
+    // X == toNFC(X) == toNFD(X) == toNFKC(X) == toNFKD(X) for the other characters
+    let others = ('\0'..=char::MAX).filter(|c| !part1_chars.contains(c));
+    for c in others {
+        let x = c.to_string();
+        let line = format!("{:04X}", c as u32);
+        normalizers.check_nfc(&x, &x, &line);
+        normalizers.check_nfd(&x, &x, &line);
+        normalizers.check_nfkc(&x, &x, &line);
+        normalizers.check_nfkd(&x, &x, &line);
+    }
+}
+
+#[test]
+fn test_normalize_iter() {
+    let normalizers = Normalizers::new();
+
+    // A long sequence of combining marks is reordered as a whole.
+    let marks = "\u{301}\u{323}".repeat(100);
+    let source = format!("a{}b", marks);
+    let nfd: String = normalizers.nfd.normalize_iter(source.chars()).collect();
+    assert_eq!(
+        nfd,
+        format!("a{}{}b", "\u{323}".repeat(100), "\u{301}".repeat(100))
+    );
+    let nfc: String = normalizers.nfc.normalize_iter(source.chars()).collect();
+    assert_eq!(
+        nfc,
+        format!("\u{1EA1}{}{}b", "\u{323}".repeat(99), "\u{301}".repeat(100))
+    );
+
+    // The iterators are lazy.
+    let mut input = "\u{212B}\u{FB01}".chars().chain(std::iter::repeat('x'));
+    let mut nfkc = normalizers.nfkc.normalize_iter(&mut input);
+    assert_eq!(nfkc.next(), Some('\u{C5}'));
+    assert_eq!(nfkc.next(), Some('f'));
+    assert_eq!(nfkc.next(), Some('i'));
+    assert_eq!(nfkc.next(), Some('x'));
+}
+
+#[test]
+fn test_unpaired_surrogates() {
+    let normalizers = Normalizers::new();
+
+    let text = [0x61, 0xD800, 0x301];
+    assert_eq!(
+        normalizers.nfc.normalize_utf16(&text),
+        [0x61, 0xFFFD, 0x301]
+    );
+    assert_eq!(
+        normalizers.nfd.normalize_utf16(&text),
+        [0x61, 0xFFFD, 0x301]
+    );
+    assert!(!normalizers.nfc.is_normalized_utf16(&text));
+    assert!(!normalizers.nfd.is_normalized_utf16(&[0x61, 0xDC00]));
+}
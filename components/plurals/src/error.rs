@@ -2,7 +2,10 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+#[cfg(feature = "parser")]
 use crate::rules::parser::ParserError;
+use crate::PluralCategory;
+use alloc::string::String;
 use displaydoc::Display;
 use icu_provider::prelude::DataError;
 
@@ -10,8 +13,16 @@ use icu_provider::prelude::DataError;
 ///
 #[derive(Display, Debug)]
 pub enum PluralRulesError {
+    #[cfg(feature = "parser")]
     #[displaydoc("Parser error: {0}")]
     Parser(ParserError),
+    /// A value of a rule does not fit in the binary form of
+    /// [`PluralRuleV2`](crate::provider::PluralRuleV2)
+    #[displaydoc("Value out of range: {0}")]
+    ValueOutOfRange(u64),
+    /// A sample of the data does not select the category that it is a sample of
+    #[displaydoc("Sample {0} does not select {1:?}")]
+    SampleMismatch(String, PluralCategory),
     /// An error originating inside of the [`DataProvider`](icu_provider::DataProvider)
     #[displaydoc("Data provider error: {0}")]
     DataProvider(DataError),
@@ -20,6 +31,7 @@ pub enum PluralRulesError {
 #[cfg(feature = "std")]
 impl std::error::Error for PluralRulesError {}
 
+#[cfg(feature = "parser")]
 impl From<ParserError> for PluralRulesError {
     fn from(e: ParserError) -> Self {
         PluralRulesError::Parser(e)
@@ -59,6 +59,11 @@
 //! * [`Cardinal`](PluralRuleType::Cardinal): `3 doors`, `1 month`, `10 dollars`
 //! * [`Ordinal`](PluralRuleType::Ordinal): `1st place`, `10th day`, `11th floor`
 //!
+//! ## Plural Ranges
+//!
+//! A range of numbers, like `1–2 days`, has a [`Plural Category`] which depends on the categories
+//! of its start and end. [`PluralRanges`] retrieves it.
+//!
 //! [`ICU4X`]: ../icu/index.html
 //! [`Plural Type`]: PluralRuleType
 //! [`Plural Category`]: PluralCategory
@@ -73,14 +78,18 @@ mod data;
 mod error;
 mod operands;
 pub mod provider;
+mod ranges;
 pub mod rules;
+mod samples;
 
-use core::convert::TryInto;
+use alloc::string::ToString;
 pub use error::PluralRulesError;
+use fixed_decimal::FixedDecimal;
 use icu_locid::LanguageIdentifier;
 use icu_provider::prelude::*;
 pub use operands::PluralOperands;
-use provider::{resolver, PluralRuleStringsV1, PluralRuleStringsV1Marker};
+use provider::{resolver, PluralRulesV2Marker, PluralSamplesV1Marker};
+pub use ranges::PluralRanges;
 
 /// A type of a plural rule which can be associated with the [`PluralRules`] struct.
 ///
@@ -251,12 +260,13 @@ impl PluralCategory {
 /// [`ICU4X`]: ../icu/index.html
 /// [`Plural Type`]: PluralRuleType
 /// [`Plural Category`]: PluralCategory
-pub struct PluralRules {
+pub struct PluralRules<'data> {
     _langid: LanguageIdentifier,
-    selector: data::RulesSelector,
+    selector: data::RulesSelector<'data>,
+    samples: Option<DataPayload<'data, PluralSamplesV1Marker>>,
 }
 
-impl PluralRules {
+impl<'data> PluralRules<'data> {
     /// Constructs a new `PluralRules` for a given locale, [`type`] and [`data provider`].
     ///
     /// This constructor will fail if the [`Data Provider`] does not have the data.
@@ -277,13 +287,46 @@ impl PluralRules {
     ///
     /// [`type`]: PluralRuleType
     /// [`data provider`]: icu_provider::DataProvider
-    pub fn try_new<'data, D: DataProvider<'data, PluralRuleStringsV1Marker> + ?Sized>(
+    pub fn try_new<D: DataProvider<'data, PluralRulesV2Marker> + ?Sized>(
         langid: LanguageIdentifier,
         data_provider: &D,
         type_: PluralRuleType,
     ) -> Result<Self, PluralRulesError> {
         let data = resolver::resolve_plural_data(langid.clone(), data_provider, type_)?;
-        Self::new_from_data(langid, data.get())
+        Self::new_from_data(langid, data)
+    }
+
+    /// Constructs a new `PluralRules` like [`try_new()`](PluralRules::try_new()), which also
+    /// loads the samples of its categories for [`samples()`](PluralRules::samples()).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::macros::langid;
+    /// use icu::plurals::{PluralRules, PluralRuleType};
+    ///
+    /// let lid = langid!("en");
+    ///
+    /// let dp = icu_testdata::get_provider();
+    ///
+    /// let pr = PluralRules::try_new_with_samples(lid, &dp, PluralRuleType::Cardinal)
+    ///     .expect("Failed to construct a PluralRules struct.");
+    ///
+    /// assert!(pr.check_samples().is_ok());
+    /// ```
+    pub fn try_new_with_samples<D>(
+        langid: LanguageIdentifier,
+        data_provider: &D,
+        type_: PluralRuleType,
+    ) -> Result<Self, PluralRulesError>
+    where
+        D: DataProvider<'data, PluralRulesV2Marker>
+            + DataProvider<'data, PluralSamplesV1Marker>
+            + ?Sized,
+    {
+        let data = resolver::resolve_plural_data(langid.clone(), data_provider, type_)?;
+        let samples = resolver::resolve_plural_samples(langid.clone(), data_provider, type_)?;
+        Self::new_from_data_with_samples(langid, data, samples)
     }
 
     /// Returns the [`Plural Category`] appropriate for the given number.
@@ -381,16 +424,99 @@ impl PluralRules {
         self.selector.categories()
     }
 
+    /// Returns sample numbers of a [`Plural Category`], from the `@integer` and `@decimal`
+    /// samples of the CLDR plural rules.
+    ///
+    /// The samples are only available if the [`PluralRules`] was constructed with
+    /// [`try_new_with_samples()`](PluralRules::try_new_with_samples()); the iterator is empty
+    /// otherwise. Samples in compact notation, like `1c6`, are skipped, because
+    /// [`FixedDecimal`] cannot represent their exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::macros::langid;
+    /// use icu::plurals::{PluralRules, PluralRuleType, PluralCategory};
+    ///
+    /// let lid = langid!("en");
+    ///
+    /// let dp = icu_testdata::get_provider();
+    ///
+    /// let pr = PluralRules::try_new_with_samples(lid, &dp, PluralRuleType::Ordinal)
+    ///     .expect("Failed to construct a PluralRules struct.");
+    ///
+    /// let samples: Vec<String> = pr
+    ///     .samples(PluralCategory::Two)
+    ///     .map(|sample| sample.to_string())
+    ///     .collect();
+    /// assert_eq!(samples, ["2", "22", "32", "42", "52", "62", "72", "82", "102", "1002"]);
+    /// ```
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn samples(&self, category: PluralCategory) -> impl Iterator<Item = FixedDecimal> + '_ {
+        self.sample_values(category)
+            .filter_map(samples::SampleValue::to_fixed_decimal)
+    }
+
+    /// Checks that each sample of each [`Plural Category`] selects that category, including the
+    /// samples in compact notation. It returns [`PluralRulesError::SampleMismatch`] for the first
+    /// sample that does not.
+    ///
+    /// This validates the data of a [`PluralRules`] constructed with
+    /// [`try_new_with_samples()`](PluralRules::try_new_with_samples()), and succeeds if there
+    /// are no samples.
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn check_samples(&self) -> Result<(), PluralRulesError> {
+        for &category in PluralCategory::all() {
+            for sample in self.sample_values(category) {
+                let sample = sample.to_string();
+                let operands: PluralOperands = sample
+                    .parse()
+                    .map_err(|_| PluralRulesError::SampleMismatch(sample.clone(), category))?;
+                if self.select(operands) != category {
+                    return Err(PluralRulesError::SampleMismatch(sample, category));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn sample_values(
+        &self,
+        category: PluralCategory,
+    ) -> impl Iterator<Item = samples::SampleValue> + '_ {
+        self.samples
+            .as_ref()
+            .map(|samples| samples.get())
+            .into_iter()
+            .flat_map(move |samples| samples.get(category))
+            .flat_map(samples::sample_values)
+    }
+
     /// Lower-level constructor that allows constructing a [`PluralRules`] directly from
     /// data obtained from a provider.
     pub fn new_from_data(
         langid: LanguageIdentifier,
-        data: &PluralRuleStringsV1,
+        data: DataPayload<'data, PluralRulesV2Marker>,
     ) -> Result<Self, PluralRulesError> {
-        let data: data::PluralRuleList = data.try_into()?;
         Ok(Self {
             _langid: langid,
             selector: data.into(),
+            samples: None,
+        })
+    }
+
+    /// Lower-level constructor like [`new_from_data()`](PluralRules::new_from_data()), which
+    /// also takes the samples of the categories for [`samples()`](PluralRules::samples()).
+    pub fn new_from_data_with_samples(
+        langid: LanguageIdentifier,
+        data: DataPayload<'data, PluralRulesV2Marker>,
+        samples: DataPayload<'data, PluralSamplesV1Marker>,
+    ) -> Result<Self, PluralRulesError> {
+        Ok(Self {
+            samples: Some(samples),
+            ..Self::new_from_data(langid, data)?
         })
     }
 }
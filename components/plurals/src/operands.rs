@@ -57,6 +57,20 @@ use fixed_decimal::FixedDecimal;
 /// }), "123.45".parse())
 /// ```
 ///
+/// From &str in compact notation, where `c` is the exponent
+///
+/// ```
+/// use icu::plurals::PluralOperands;
+/// assert_eq!(Ok(PluralOperands {
+///    i: 1200,
+///    v: 0,
+///    w: 0,
+///    f: 0,
+///    t: 0,
+///    c: 3,
+/// }), "1.2c3".parse())
+/// ```
+///
 /// From [`FixedDecimal`]
 ///
 /// ```
@@ -126,8 +140,10 @@ impl From<std::io::Error> for OperandsError {
     }
 }
 
+/// Splits the exponent of compact notation, as in `1.2c3` or its older spelling `1.2e3`, from
+/// the digits.
 fn get_exponent(input: &str) -> Result<(&str, usize), OperandsError> {
-    if let Some(e_idx) = input.find('e') {
+    if let Some(e_idx) = input.find(&['e', 'c'][..]) {
         let e = usize::from_str(&input[e_idx + 1..])?;
         Ok((&input[..e_idx], e))
     } else {
@@ -138,52 +154,69 @@ fn get_exponent(input: &str) -> Result<(&str, usize), OperandsError> {
 impl FromStr for PluralOperands {
     type Err = OperandsError;
 
+    /// Parses a decimal like `123.45`, optionally followed by the exponent of compact notation,
+    /// like `1.2c3`. The other operands are then those of the number written out, `1200`.
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         if input.is_empty() {
             return Err(OperandsError::Empty);
         }
 
         let abs_str = input.strip_prefix('-').unwrap_or(input);
+        let (abs_str, exponent) = get_exponent(abs_str)?;
 
-        let (
-            integer_digits,
-            num_fraction_digits0,
-            num_fraction_digits,
-            fraction_digits0,
-            fraction_digits,
-            exponent,
-        ) = if let Some(sep_idx) = abs_str.find('.') {
-            let int_str = &abs_str[..sep_idx];
-            let (dec_str, exponent) = get_exponent(&abs_str[(sep_idx + 1)..])?;
+        let (int_str, dec_str) = if let Some(sep_idx) = abs_str.find('.') {
+            let dec_str = &abs_str[(sep_idx + 1)..];
+            if dec_str.is_empty() {
+                return Err(OperandsError::Invalid);
+            }
+            (&abs_str[..sep_idx], dec_str)
+        } else {
+            (abs_str, "")
+        };
 
-            let integer_digits = u64::from_str(int_str)?;
+        // The exponent moves the first fraction digits into the integer part, and scales it
+        // by the remaining power of 10.
+        let shift = core::cmp::min(exponent, dec_str.len());
+        let mut integer_digits = u64::from_str(int_str)?;
+        for digit in dec_str[..shift].bytes() {
+            if !digit.is_ascii_digit() {
+                return Err(OperandsError::Invalid);
+            }
+            integer_digits = integer_digits
+                .checked_mul(10)
+                .and_then(|i| i.checked_add((digit - b'0') as u64))
+                .ok_or(OperandsError::Invalid)?;
+        }
+        integer_digits = 10_u64
+            .checked_pow((exponent - shift) as u32)
+            .and_then(|scale| integer_digits.checked_mul(scale))
+            .ok_or(OperandsError::Invalid)?;
+        let dec_str = &dec_str[shift..];
 
-            let dec_str_no_zeros = dec_str.trim_end_matches('0');
+        let (num_fraction_digits0, num_fraction_digits, fraction_digits0, fraction_digits) =
+            if dec_str.is_empty() {
+                (0, 0, 0, 0)
+            } else {
+                let dec_str_no_zeros = dec_str.trim_end_matches('0');
 
-            let num_fraction_digits0 = dec_str.len() as usize;
-            let num_fraction_digits = dec_str_no_zeros.len() as usize;
+                let num_fraction_digits0 = dec_str.len() as usize;
+                let num_fraction_digits = dec_str_no_zeros.len() as usize;
 
-            let fraction_digits0 = u64::from_str(dec_str)?;
-            let fraction_digits =
-                if num_fraction_digits == 0 || num_fraction_digits == num_fraction_digits0 {
-                    fraction_digits0
-                } else {
-                    u64::from_str(dec_str_no_zeros)?
-                };
+                let fraction_digits0 = u64::from_str(dec_str)?;
+                let fraction_digits =
+                    if num_fraction_digits == 0 || num_fraction_digits == num_fraction_digits0 {
+                        fraction_digits0
+                    } else {
+                        u64::from_str(dec_str_no_zeros)?
+                    };
 
-            (
-                integer_digits,
-                num_fraction_digits0,
-                num_fraction_digits,
-                fraction_digits0,
-                fraction_digits,
-                exponent,
-            )
-        } else {
-            let (abs_str, exponent) = get_exponent(abs_str)?;
-            let integer_digits = u64::from_str(abs_str)?;
-            (integer_digits, 0, 0, 0, 0, exponent)
-        };
+                (
+                    num_fraction_digits0,
+                    num_fraction_digits,
+                    fraction_digits0,
+                    fraction_digits,
+                )
+            };
 
         Ok(Self {
             i: integer_digits,
@@ -6,13 +6,24 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+use crate::rules::ast;
+use crate::{PluralCategory, PluralRulesError};
 use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
 use icu_provider::yoke::{self, *};
+use zerovec::ZeroVec;
 
 pub mod key {
     use icu_provider::{resource_key, ResourceKey};
     pub const CARDINAL_V1: ResourceKey = resource_key!(Plurals, "cardinal", 1);
     pub const ORDINAL_V1: ResourceKey = resource_key!(Plurals, "ordinal", 1);
+    pub const CARDINAL_V2: ResourceKey = resource_key!(Plurals, "cardinal", 2);
+    pub const ORDINAL_V2: ResourceKey = resource_key!(Plurals, "ordinal", 2);
+    pub const RANGES_V1: ResourceKey = resource_key!(Plurals, "ranges", 1);
+    pub const CARDINAL_SAMPLES_V1: ResourceKey = resource_key!(Plurals, "cardinal_samples", 1);
+    pub const ORDINAL_SAMPLES_V1: ResourceKey = resource_key!(Plurals, "ordinal_samples", 1);
 }
 
 pub mod resolver;
@@ -20,6 +31,9 @@ pub mod resolver;
 /// Plural rule strings conforming to UTS 35 syntax. Includes separate fields for five of the six
 /// standard plural forms. If none of the rules match, the "other" category is assumed.
 ///
+/// These strings need the `parser` feature to be used; [`PluralRules`](crate::PluralRules)
+/// loads the pre-parsed [`PluralRulesV2`] instead.
+///
 /// More information: <https://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules>
 #[icu_provider::data_struct]
 #[derive(Debug, PartialEq, Clone, Default)]
@@ -34,3 +48,431 @@ pub struct PluralRuleStringsV1<'data> {
     pub few: Option<Cow<'data, str>>,
     pub many: Option<Cow<'data, str>>,
 }
+
+/// Mask for the operand of a relation in its first word, as an index into [`OPERANDS`].
+pub const OPERAND_MASK: u32 = 0x7;
+/// Set in the first word of a relation if its operator is `!=`.
+pub const NEGATED: u32 = 1 << 3;
+/// Set in the first word of a relation if it is joined to the previous relation with `and`.
+/// Otherwise the relation starts a new alternative of the `or` condition.
+pub const AND: u32 = 1 << 4;
+/// Set in the first word of a relation if its operand is taken modulo the next word.
+pub const MODULUS: u32 = 1 << 5;
+/// Shift of the number of ranges of a relation in its first word.
+pub const RANGE_COUNT_SHIFT: u32 = 8;
+
+/// The operands of plural rules, indexed by their value in the bits of [`OPERAND_MASK`].
+pub const OPERANDS: [ast::Operand; 8] = [
+    ast::Operand::N,
+    ast::Operand::I,
+    ast::Operand::V,
+    ast::Operand::W,
+    ast::Operand::F,
+    ast::Operand::T,
+    ast::Operand::C,
+    ast::Operand::E,
+];
+
+/// Plural rules of UTS 35, parsed when the data is generated. Includes separate fields for five
+/// of the six standard plural forms. If none of the rules match, the "other" category is
+/// assumed.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules>
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct PluralRulesV2<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub zero: Option<PluralRuleV2<'data>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub one: Option<PluralRuleV2<'data>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub two: Option<PluralRuleV2<'data>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub few: Option<PluralRuleV2<'data>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub many: Option<PluralRuleV2<'data>>,
+}
+
+impl<'data> PluralRulesV2<'data> {
+    /// Returns the rule of a category, which is `None` for [`PluralCategory::Other`] and the
+    /// categories that the locale does not use.
+    pub fn get(&self, category: PluralCategory) -> Option<&PluralRuleV2<'data>> {
+        match category {
+            PluralCategory::Zero => self.zero.as_ref(),
+            PluralCategory::One => self.one.as_ref(),
+            PluralCategory::Two => self.two.as_ref(),
+            PluralCategory::Few => self.few.as_ref(),
+            PluralCategory::Many => self.many.as_ref(),
+            PluralCategory::Other => None,
+        }
+    }
+}
+
+#[cfg(feature = "parser")]
+impl TryFrom<&PluralRuleStringsV1<'_>> for PluralRulesV2<'static> {
+    type Error = PluralRulesError;
+    fn try_from(strings: &PluralRuleStringsV1<'_>) -> Result<Self, Self::Error> {
+        fn convert(
+            input: &Option<Cow<str>>,
+        ) -> Result<Option<PluralRuleV2<'static>>, PluralRulesError> {
+            input
+                .as_ref()
+                .map(|input| {
+                    PluralRuleV2::try_from(&crate::rules::parse_condition(input.as_bytes())?)
+                })
+                .transpose()
+        }
+        Ok(Self {
+            zero: convert(&strings.zero)?,
+            one: convert(&strings.one)?,
+            two: convert(&strings.two)?,
+            few: convert(&strings.few)?,
+            many: convert(&strings.many)?,
+        })
+    }
+}
+
+/// The binary form of an [`ast::Condition`].
+///
+/// Each [`ast::Relation`] is stored as a first word with the bits of [`OPERAND_MASK`],
+/// [`NEGATED`], [`AND`], [`MODULUS`] and the number of ranges at [`RANGE_COUNT_SHIFT`],
+/// followed by the modulus if [`MODULUS`] is set, and by the lower and upper bounds of each
+/// range. A single value is stored as a range with equal bounds.
+///
+/// An empty rule matches all operands.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct PluralRuleV2<'data>(
+    #[cfg_attr(feature = "provider_serde", serde(borrow))] pub ZeroVec<'data, u32>,
+);
+
+impl TryFrom<&ast::Condition> for PluralRuleV2<'static> {
+    type Error = PluralRulesError;
+    fn try_from(condition: &ast::Condition) -> Result<Self, Self::Error> {
+        fn value(value: &ast::Value) -> Result<u32, PluralRulesError> {
+            u32::try_from(value.0).map_err(|_| PluralRulesError::ValueOutOfRange(value.0))
+        }
+
+        let mut words = Vec::new();
+        // An empty `and` condition is always true, so the whole condition is.
+        if condition
+            .0
+            .iter()
+            .all(|and_condition| !and_condition.0.is_empty())
+        {
+            for and_condition in condition.0.iter() {
+                for (i, relation) in and_condition.0.iter().enumerate() {
+                    let mut first = OPERANDS
+                        .iter()
+                        .position(|&operand| operand == relation.expression.operand)
+                        .unwrap_or_default() as u32;
+                    if relation.operator == ast::Operator::NotEq {
+                        first |= NEGATED;
+                    }
+                    if i > 0 {
+                        first |= AND;
+                    }
+                    if relation.expression.modulus.is_some() {
+                        first |= MODULUS;
+                    }
+                    first |= (relation.range_list.0.len() as u32) << RANGE_COUNT_SHIFT;
+                    words.push(first);
+                    if let Some(modulus) = &relation.expression.modulus {
+                        words.push(value(modulus)?);
+                    }
+                    for item in relation.range_list.0.iter() {
+                        let (lower, upper) = match item {
+                            ast::RangeListItem::Value(v) => (v, v),
+                            ast::RangeListItem::Range(range) => (range.start(), range.end()),
+                        };
+                        words.push(value(lower)?);
+                        words.push(value(upper)?);
+                    }
+                }
+            }
+        }
+        Ok(PluralRuleV2(ZeroVec::from_aligned(&words)))
+    }
+}
+
+impl From<&PluralRuleV2<'_>> for ast::Condition {
+    fn from(rule: &PluralRuleV2<'_>) -> Self {
+        let mut words = rule.0.iter();
+        let mut and_conditions = Vec::new();
+        let mut relations: Vec<ast::Relation> = Vec::new();
+        while let Some(first) = words.next() {
+            if first & AND == 0 && !relations.is_empty() {
+                and_conditions.push(ast::AndCondition(relations.into_boxed_slice()));
+                relations = Vec::new();
+            }
+            let modulus = if first & MODULUS != 0 {
+                words.next().map(|m| ast::Value(m.into()))
+            } else {
+                None
+            };
+            let count = (first >> RANGE_COUNT_SHIFT) as usize;
+            let range_list = (0..count)
+                .filter_map(|_| {
+                    let lower = ast::Value(words.next()?.into());
+                    let upper = ast::Value(words.next()?.into());
+                    Some(if lower == upper {
+                        ast::RangeListItem::Value(lower)
+                    } else {
+                        ast::RangeListItem::Range(lower..=upper)
+                    })
+                })
+                .collect::<Box<[_]>>();
+            relations.push(ast::Relation {
+                expression: ast::Expression {
+                    operand: OPERANDS[(first & OPERAND_MASK) as usize],
+                    modulus,
+                },
+                operator: if first & NEGATED != 0 {
+                    ast::Operator::NotEq
+                } else {
+                    ast::Operator::Eq
+                },
+                range_list: ast::RangeList(range_list),
+            });
+        }
+        if !relations.is_empty() {
+            and_conditions.push(ast::AndCondition(relations.into_boxed_slice()));
+        }
+        ast::Condition(and_conditions.into_boxed_slice())
+    }
+}
+
+/// The plural categories, indexed by their value in the bits of [`CATEGORY_MASK`].
+pub const CATEGORIES: [PluralCategory; 6] = [
+    PluralCategory::Zero,
+    PluralCategory::One,
+    PluralCategory::Two,
+    PluralCategory::Few,
+    PluralCategory::Many,
+    PluralCategory::Other,
+];
+
+/// Mask for a category of a plural range, as an index into [`CATEGORIES`].
+pub const CATEGORY_MASK: u16 = 0xf;
+/// Shift of the category of the start of a plural range.
+pub const RANGE_START_SHIFT: u16 = 8;
+/// Shift of the category of the end of a plural range.
+pub const RANGE_END_SHIFT: u16 = 4;
+
+/// The plural categories of ranges of numbers, like "1–2 days", from the categories of their
+/// start and end.
+///
+/// Each range is stored as the categories of its start at [`RANGE_START_SHIFT`], of its end at
+/// [`RANGE_END_SHIFT`] and of the range in the lowest bits. Ranges whose category is the one of
+/// their end are not stored.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-numbers.html#Plural_Ranges>
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct PluralRangesV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub ranges: ZeroVec<'data, u16>,
+}
+
+impl PluralRangesV1<'_> {
+    /// Returns the category of a range from the categories of its start and end.
+    pub fn get(&self, start: PluralCategory, end: PluralCategory) -> PluralCategory {
+        let range =
+            category_index(start) << RANGE_START_SHIFT | category_index(end) << RANGE_END_SHIFT;
+        self.ranges
+            .iter()
+            .find(|value| value & !CATEGORY_MASK == range)
+            .map_or(end, |value| CATEGORIES[(value & CATEGORY_MASK) as usize])
+    }
+
+    /// Builds the ranges from triples of the categories of the start, the end and the range.
+    pub fn from_ranges(
+        ranges: impl IntoIterator<Item = (PluralCategory, PluralCategory, PluralCategory)>,
+    ) -> Self {
+        let mut values: Vec<u16> = ranges
+            .into_iter()
+            .filter(|(_, end, category)| end != category)
+            .map(|(start, end, category)| {
+                category_index(start) << RANGE_START_SHIFT
+                    | category_index(end) << RANGE_END_SHIFT
+                    | category_index(category)
+            })
+            .collect();
+        values.sort_unstable();
+        values.dedup();
+        Self {
+            ranges: ZeroVec::from_aligned(&values),
+        }
+    }
+}
+
+/// The samples of the plural categories of a locale, from the `@integer` and `@decimal` samples of
+/// its plural rules.
+///
+/// Each sample range, like `0.0~1.5`, is stored in `ranges` as its category in the bits of
+/// [`CATEGORY_MASK`], its exponent of compact notation at [`SAMPLE_EXPONENT_SHIFT`] and its number
+/// of visible fraction digits at [`SAMPLE_FRACTION_DIGITS_SHIFT`], and in `bounds` as the digits
+/// of its lower and upper bound, without the decimal separator. The ranges are ordered by the
+/// [`CATEGORIES`]. A range whose bounds have different fraction digits or exponents is stored as
+/// its two bounds. The ellipses of the rules, which mark that a category has more numbers than its
+/// samples, are not kept.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-numbers.html#Samples>
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct PluralSamplesV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub ranges: ZeroVec<'data, u16>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub bounds: ZeroVec<'data, u64>,
+}
+
+/// The position of the exponent of compact notation of a sample range in [`PluralSamplesV1`].
+pub const SAMPLE_EXPONENT_SHIFT: u16 = 4;
+/// The position of the number of visible fraction digits of a sample range in
+/// [`PluralSamplesV1`].
+pub const SAMPLE_FRACTION_DIGITS_SHIFT: u16 = 8;
+
+/// A range of sample numbers of a plural category, like `0.0~1.5` or `1c6`.
+///
+/// Its numbers are those from `lower` to `upper` with `fraction_digits` visible fraction digits,
+/// like `0.0`, `0.1`, …, `1.5`, multiplied by 10 to the power of `exponent` in compact notation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PluralSampleRange {
+    /// The digits of the lower bound, without the decimal separator.
+    pub lower: u64,
+    /// The digits of the upper bound, without the decimal separator.
+    pub upper: u64,
+    /// The number of visible fraction digits of both bounds, with trailing zeros.
+    pub fraction_digits: u16,
+    /// The exponent of compact notation of both bounds, which is 0 if they are not compact.
+    pub exponent: u16,
+}
+
+impl PluralSamplesV1<'_> {
+    /// Returns the sample ranges of a category.
+    pub fn get(&self, category: PluralCategory) -> impl Iterator<Item = PluralSampleRange> + '_ {
+        let index = category_index(category);
+        self.ranges
+            .iter()
+            .zip(
+                self.bounds
+                    .iter()
+                    .step_by(2)
+                    .zip(self.bounds.iter().skip(1).step_by(2)),
+            )
+            .filter(move |(range, _)| range & CATEGORY_MASK == index)
+            .map(|(range, (lower, upper))| PluralSampleRange {
+                lower,
+                upper,
+                fraction_digits: range >> SAMPLE_FRACTION_DIGITS_SHIFT,
+                exponent: (range >> SAMPLE_EXPONENT_SHIFT) & 0xf,
+            })
+    }
+
+    /// Builds the samples from the samples of the rules of each category.
+    #[cfg(feature = "parser")]
+    pub fn try_from_samples<'a>(
+        samples: impl IntoIterator<Item = (PluralCategory, &'a ast::Samples)>,
+    ) -> Result<Self, PluralRulesError> {
+        let mut entries = Vec::new();
+        for (category, samples) in samples {
+            let lists = samples.integer.iter().chain(samples.decimal.iter());
+            for range in lists.flat_map(|list| list.sample_ranges.iter()) {
+                let lower = sample_value(&range.lower_val)?;
+                let upper = match &range.upper_val {
+                    Some(upper) => sample_value(upper)?,
+                    None => lower,
+                };
+                if (lower.1, lower.2) == (upper.1, upper.2) {
+                    entries.push((category_index(category), lower, upper.0));
+                } else {
+                    entries.push((category_index(category), lower, lower.0));
+                    entries.push((category_index(category), upper, upper.0));
+                }
+            }
+        }
+        // Keeps the order of the ranges of each category.
+        entries.sort_by_key(|(category, _, _)| *category);
+        let ranges: Vec<u16> = entries
+            .iter()
+            .map(|(category, (_, fraction_digits, exponent), _)| {
+                category
+                    | exponent << SAMPLE_EXPONENT_SHIFT
+                    | fraction_digits << SAMPLE_FRACTION_DIGITS_SHIFT
+            })
+            .collect();
+        let bounds: Vec<u64> = entries
+            .iter()
+            .flat_map(|(_, (lower, _, _), upper)| [*lower, *upper])
+            .collect();
+        Ok(Self {
+            ranges: ZeroVec::from_aligned(&ranges),
+            bounds: ZeroVec::from_aligned(&bounds),
+        })
+    }
+}
+
+/// Parses a sample number like `1.5` or `1.5c3` into its digits without the decimal separator,
+/// its number of visible fraction digits and its exponent of compact notation.
+#[cfg(feature = "parser")]
+fn sample_value(value: &ast::DecimalValue) -> Result<(u64, u16, u16), PluralRulesError> {
+    let invalid = || PluralRulesError::Parser(crate::rules::parser::ParserError::ExpectedValue);
+    let (number, exponent) = match value.0.find(&['e', 'c'][..]) {
+        Some(idx) => (
+            &value.0[..idx],
+            value.0[idx + 1..].parse::<u16>().map_err(|_| invalid())?,
+        ),
+        None => (value.0.as_str(), 0),
+    };
+    let (integer, fraction) = match number.find('.') {
+        Some(idx) => (&number[..idx], &number[idx + 1..]),
+        None => (number, ""),
+    };
+    if integer.is_empty() {
+        return Err(invalid());
+    }
+    let mut digits: u64 = 0;
+    for digit in integer.bytes().chain(fraction.bytes()) {
+        if !digit.is_ascii_digit() {
+            return Err(invalid());
+        }
+        digits = digits
+            .checked_mul(10)
+            .and_then(|d| d.checked_add((digit - b'0') as u64))
+            .ok_or_else(invalid)?;
+    }
+    let fraction_digits = fraction.len() as u16;
+    if exponent > 0xf {
+        return Err(PluralRulesError::ValueOutOfRange(exponent.into()));
+    }
+    if fraction_digits > 0xff {
+        return Err(PluralRulesError::ValueOutOfRange(fraction_digits.into()));
+    }
+    Ok((digits, fraction_digits, exponent))
+}
+
+fn category_index(category: PluralCategory) -> u16 {
+    CATEGORIES
+        .iter()
+        .position(|&c| c == category)
+        .unwrap_or_default() as u16
+}
@@ -2,19 +2,41 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use super::PluralRuleStringsV1Marker;
+use super::{PluralRulesV2Marker, PluralSamplesV1Marker};
 use crate::{PluralRuleType, PluralRulesError};
 use icu_locid::LanguageIdentifier;
 use icu_provider::prelude::*;
 
-pub fn resolve_plural_data<'data, D: DataProvider<'data, PluralRuleStringsV1Marker> + ?Sized>(
+pub fn resolve_plural_data<'data, D: DataProvider<'data, PluralRulesV2Marker> + ?Sized>(
     langid: LanguageIdentifier,
     data_provider: &D,
     type_: PluralRuleType,
-) -> Result<DataPayload<'data, PluralRuleStringsV1Marker>, PluralRulesError> {
+) -> Result<DataPayload<'data, PluralRulesV2Marker>, PluralRulesError> {
     let key = match type_ {
-        PluralRuleType::Cardinal => super::key::CARDINAL_V1,
-        PluralRuleType::Ordinal => super::key::ORDINAL_V1,
+        PluralRuleType::Cardinal => super::key::CARDINAL_V2,
+        PluralRuleType::Ordinal => super::key::ORDINAL_V2,
+    };
+    Ok(data_provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid),
+                },
+            },
+        })?
+        .take_payload()?)
+}
+
+pub fn resolve_plural_samples<'data, D: DataProvider<'data, PluralSamplesV1Marker> + ?Sized>(
+    langid: LanguageIdentifier,
+    data_provider: &D,
+    type_: PluralRuleType,
+) -> Result<DataPayload<'data, PluralSamplesV1Marker>, PluralRulesError> {
+    let key = match type_ {
+        PluralRuleType::Cardinal => super::key::CARDINAL_SAMPLES_V1,
+        PluralRuleType::Ordinal => super::key::ORDINAL_SAMPLES_V1,
     };
     Ok(data_provider
         .load_payload(&DataRequest {
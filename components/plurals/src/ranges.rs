@@ -0,0 +1,109 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::{key, PluralRangesV1Marker};
+use crate::{PluralCategory, PluralRulesError};
+use icu_locid::LanguageIdentifier;
+use icu_provider::prelude::*;
+
+/// A struct which provides an ability to retrieve the [`Plural Category`] of a range of
+/// numbers, like "1–2 days", from the categories of its start and end.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::macros::langid;
+/// use icu::plurals::{PluralCategory, PluralRanges, PluralRuleType, PluralRules};
+///
+/// let lid = langid!("ru");
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let pr = PluralRules::try_new(lid.clone(), &provider, PluralRuleType::Cardinal)
+///     .expect("Failed to construct a PluralRules struct.");
+/// let ranges = PluralRanges::try_new(lid, &provider)
+///     .expect("Failed to construct a PluralRanges struct.");
+///
+/// // "1–2 дня" uses the category of 2.
+/// assert_eq!(pr.select(1_usize), PluralCategory::One);
+/// assert_eq!(pr.select(2_usize), PluralCategory::Few);
+/// assert_eq!(
+///     ranges.resolve(pr.select(1_usize), pr.select(2_usize)),
+///     PluralCategory::Few
+/// );
+/// ```
+///
+/// [`Plural Category`]: PluralCategory
+pub struct PluralRanges<'data> {
+    _langid: LanguageIdentifier,
+    ranges: DataPayload<'data, PluralRangesV1Marker>,
+}
+
+impl<'data> PluralRanges<'data> {
+    /// Constructs a new `PluralRanges` for a given locale and [`data provider`].
+    ///
+    /// This constructor will fail if the [`Data Provider`] does not have the data.
+    ///
+    /// [`data provider`]: icu_provider::DataProvider
+    pub fn try_new<D: DataProvider<'data, PluralRangesV1Marker> + ?Sized>(
+        langid: LanguageIdentifier,
+        data_provider: &D,
+    ) -> Result<Self, PluralRulesError> {
+        let data: DataPayload<PluralRangesV1Marker> = data_provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::RANGES_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(langid.clone()),
+                    },
+                },
+            })?
+            .take_payload()?;
+        Self::new_from_data(langid, data)
+    }
+
+    /// Returns the [`Plural Category`] of a range from the categories of its start and end.
+    ///
+    /// A range has the category of its end unless the data has a different one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::macros::langid;
+    /// use icu::plurals::{PluralCategory, PluralRanges};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    ///
+    /// let ranges = PluralRanges::try_new(langid!("fr"), &provider)
+    ///     .expect("Failed to construct a PluralRanges struct.");
+    ///
+    /// // "0–1 jour", "1–2 jours"
+    /// assert_eq!(
+    ///     ranges.resolve(PluralCategory::One, PluralCategory::One),
+    ///     PluralCategory::One
+    /// );
+    /// assert_eq!(
+    ///     ranges.resolve(PluralCategory::One, PluralCategory::Other),
+    ///     PluralCategory::Other
+    /// );
+    /// ```
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn resolve(&self, start: PluralCategory, end: PluralCategory) -> PluralCategory {
+        self.ranges.get().get(start, end)
+    }
+
+    /// Lower-level constructor that allows constructing a [`PluralRanges`] directly from
+    /// data obtained from a provider.
+    pub fn new_from_data(
+        langid: LanguageIdentifier,
+        data: DataPayload<'data, PluralRangesV1Marker>,
+    ) -> Result<Self, PluralRulesError> {
+        Ok(Self {
+            _langid: langid,
+            ranges: data,
+        })
+    }
+}
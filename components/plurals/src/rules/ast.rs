@@ -9,6 +9,8 @@
 //! # Examples
 //!
 //! ```
+//! # #[cfg(feature = "parser")]
+//! # {
 //! use icu::plurals::rules::parse_condition;
 //! use icu::plurals::rules::ast::*;
 //!
@@ -33,6 +35,7 @@
 //!         }
 //!     ]))
 //! ])));
+//! # }
 //! ```
 //!
 //! [`PluralCategory`]: crate::PluralCategory
@@ -48,6 +51,8 @@ use core::ops::RangeInclusive;
 /// # Examples
 ///
 /// ```
+/// # #[cfg(feature = "parser")]
+/// # {
 /// use icu::plurals::rules::ast::*;
 /// use icu::plurals::rules::{parse, parse_condition};
 ///
@@ -81,6 +86,7 @@ use core::ops::RangeInclusive;
 ///     parse("i = 5 or v = 2 @integer 2, … @decimal 2.5".as_bytes())
 ///          .expect("Parsing failed")
 /// )
+/// # }
 /// ```
 ///
 /// [`AndConditions`]: AndCondition
@@ -96,7 +102,6 @@ pub struct Rule {
 ///
 /// ```
 /// use icu::plurals::rules::ast::*;
-/// use icu::plurals::rules::parse_condition;
 ///
 /// let condition = Condition(Box::new([
 ///     AndCondition(Box::new([Relation {
@@ -117,11 +122,12 @@ pub struct Rule {
 ///     }])),
 /// ]));
 ///
+/// # #[cfg(feature = "parser")]
 /// assert_eq!(
 ///     condition,
-///     parse_condition(b"i = 5 or v = 2")
+///     icu::plurals::rules::parse_condition(b"i = 5 or v = 2")
 ///          .expect("Parsing failed")
-/// )
+/// );
 /// ```
 ///
 /// [`AndConditions`]: AndCondition
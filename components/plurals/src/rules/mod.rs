@@ -55,6 +55,8 @@
 //! When parsed, the resulting [`AST`] will look like this:
 //!
 //! ```
+//! # #[cfg(feature = "parser")]
+//! # {
 //! use icu::plurals::rules::parse_condition;
 //! use icu::plurals::rules::ast::*;
 //!
@@ -90,6 +92,7 @@
 //!         },
 //!     ])),
 //! ])));
+//! # }
 //! ```
 //!
 //! Finally, we can pass this [`AST`] (in fact, just the [`Condition`] node),
@@ -97,6 +100,8 @@
 //! matches:
 //!
 //! ```
+//! # #[cfg(feature = "parser")]
+//! # {
 //! use icu::plurals::rules::{test_condition, parse_condition};
 //! use icu::plurals::PluralOperands;
 //!
@@ -108,12 +113,20 @@
 //!     .expect("Parsing failed.");
 //!
 //! assert!(test_condition(&ast, &operands));
+//! # }
 //! ```
 //!
 //! Since the rule for [`PluralCategory::One`] matches, we will return this category.
 //! Otherwise, we'd test the next rule, in this case [`PluralCategory::Other`], which has an
 //! empty [`Condition`], meaning that it'll match all operands.
 //!
+//! # Parsing
+//!
+//! The parser is only available with the `parser` feature. [`PluralRules`] does not parse
+//! the rules when it is constructed: the data provider returns the [`Condition`] of each
+//! [`PluralCategory`] in the binary form of [`PluralRuleV2`], which is parsed when the data
+//! is generated.
+//!
 //! # Summary
 //!
 //! For [`PluralRuleType::Cardinal`] in English, we can summarize the logic as:
@@ -143,13 +156,21 @@
 //! [`Condition`]: super::rules::ast::Condition
 //! [`Sample`]: super::rules::ast::Samples
 //! [`AST`]: super::rules::ast
+//! [`PluralRules`]: super::PluralRules
+//! [`PluralRuleV2`]: super::provider::PluralRuleV2
 pub mod ast;
+#[cfg(feature = "parser")]
 pub(crate) mod lexer;
+#[cfg(feature = "parser")]
 pub(crate) mod parser;
 pub(crate) mod resolver;
+#[cfg(feature = "parser")]
 pub(crate) mod serializer;
 
+#[cfg(feature = "parser")]
 pub use lexer::Lexer;
+#[cfg(feature = "parser")]
 pub use parser::{parse, parse_condition};
 pub use resolver::test_condition;
+#[cfg(feature = "parser")]
 pub use serializer::serialize;
@@ -303,8 +303,15 @@ impl<'p> Parser<'p> {
             }
         }
 
-        if self.take_if(Token::E) {
-            s.push('e');
+        // The exponent of compact notation, as in `1c6`, or its older spelling `1e6`.
+        let exponent = match self.lexer.peek() {
+            Some(Token::E) => Some('e'),
+            Some(Token::Operand(ast::Operand::C)) => Some('c'),
+            _ => None,
+        };
+        if let Some(exponent) = exponent {
+            self.lexer.next();
+            s.push(exponent);
             match self.lexer.peek() {
                 Some(Token::Zero) => s.push('0'),
                 Some(Token::Number(v)) => {
@@ -4,6 +4,11 @@
 
 use super::ast;
 use crate::operands::PluralOperands;
+use crate::provider::{
+    PluralRuleV2, AND, MODULUS, NEGATED, OPERANDS, OPERAND_MASK, RANGE_COUNT_SHIFT,
+};
+use core::ops::Range;
+use zerovec::ZeroVec;
 
 /// Function used to test [`Condition`] against [`PluralOperands`] to identify
 /// the appropriate [`PluralCategory`].
@@ -11,6 +16,8 @@ use crate::operands::PluralOperands;
 /// # Examples
 ///
 /// ```
+/// # #[cfg(feature = "parser")]
+/// # {
 /// use icu::plurals::PluralOperands;
 /// use icu::plurals::rules::{parse_condition, test_condition};
 ///
@@ -19,6 +26,7 @@ use crate::operands::PluralOperands;
 ///     .expect("Failde to parse a rule.");
 ///
 /// assert_eq!(test_condition(&condition, &operands), true);
+/// # }
 /// ```
 ///
 /// [`PluralCategory`]: crate::PluralCategory
@@ -51,7 +59,16 @@ fn test_relation(relation: &ast::Relation, operands: &PluralOperands) -> bool {
 //
 // If that happens, we'll return `None`, and the matching will return `false`.
 fn calculate_expression(expression: &ast::Expression, operands: &PluralOperands) -> Option<u64> {
-    let value = match expression.operand {
+    let value = calculate_operand(expression.operand, operands)?;
+    if let Some(modulus) = &expression.modulus {
+        value.checked_rem_euclid(modulus.0)
+    } else {
+        Some(value)
+    }
+}
+
+fn calculate_operand(operand: ast::Operand, operands: &PluralOperands) -> Option<u64> {
+    Some(match operand {
         ast::Operand::N => {
             if operands.w == 0 {
                 operands.i
@@ -65,12 +82,7 @@ fn calculate_expression(expression: &ast::Expression, operands: &PluralOperands)
         ast::Operand::W => operands.w as u64,
         ast::Operand::T => operands.t,
         ast::Operand::C | ast::Operand::E => operands.c as u64,
-    };
-    if let Some(modulus) = &expression.modulus {
-        value.checked_rem_euclid(modulus.0)
-    } else {
-        Some(value)
-    }
+    })
 }
 
 fn test_range(range: &ast::RangeList, value: u64, operator: ast::Operator) -> bool {
@@ -93,3 +105,57 @@ fn test_range_item(item: &ast::RangeListItem, value: u64, operator: ast::Operato
         ast::Operator::NotEq => !value,
     }
 }
+
+/// Tests the binary form of a [`Condition`](ast::Condition) against [`PluralOperands`], with
+/// the same result as [`test_condition`].
+pub(crate) fn test_rule(rule: &PluralRuleV2, operands: &PluralOperands) -> bool {
+    let words = &rule.0;
+    let mut index = 0;
+    let mut matches = true;
+    while let Some(first) = words.get(index) {
+        // A relation that is not joined with `and` starts the next alternative.
+        if first & AND == 0 && index > 0 {
+            if matches {
+                return true;
+            }
+            matches = true;
+        }
+        index += 1;
+        let modulus = if first & MODULUS != 0 {
+            index += 1;
+            words.get(index - 1)
+        } else {
+            None
+        };
+        let ranges = index..index + 2 * (first >> RANGE_COUNT_SHIFT) as usize;
+        index = ranges.end;
+        if matches {
+            matches = test_relation_words(words, first, modulus, ranges, operands);
+        }
+    }
+    matches
+}
+
+fn test_relation_words(
+    words: &ZeroVec<u32>,
+    first: u32,
+    modulus: Option<u32>,
+    ranges: Range<usize>,
+    operands: &PluralOperands,
+) -> bool {
+    let operand = OPERANDS[(first & OPERAND_MASK) as usize];
+    let value = match (calculate_operand(operand, operands), modulus) {
+        (Some(value), Some(modulus)) => value.checked_rem_euclid(modulus.into()),
+        (value, None) => value,
+        (None, _) => None,
+    };
+    let value = match value {
+        Some(value) => value,
+        None => return false,
+    };
+    let in_ranges = ranges.step_by(2).any(|i| {
+        matches!((words.get(i), words.get(i + 1)), (Some(lower), Some(upper))
+            if (u64::from(lower)..=u64::from(upper)).contains(&value))
+    });
+    in_ranges != (first & NEGATED != 0)
+}
@@ -0,0 +1,86 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::PluralSampleRange;
+use alloc::string::String;
+use core::fmt::{self, Write};
+use fixed_decimal::FixedDecimal;
+
+/// A sample number of a plural category, like `1.5` or `1.5c3` in compact notation.
+///
+/// It is displayed in the syntax that [`PluralOperands`](crate::PluralOperands) parse.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SampleValue {
+    /// The digits of the number, without the decimal separator.
+    digits: u64,
+    /// The number of visible fraction digits, with trailing zeros.
+    fraction_digits: u16,
+    /// The exponent of compact notation, which is 0 if the number is not compact.
+    exponent: u16,
+}
+
+impl SampleValue {
+    /// Returns the number as a [`FixedDecimal`], or `None` if it is in compact notation, which
+    /// [`FixedDecimal`] cannot represent.
+    pub fn to_fixed_decimal(self) -> Option<FixedDecimal> {
+        if self.exponent != 0 {
+            return None;
+        }
+        FixedDecimal::from(self.digits)
+            .multiplied_pow10(-(self.fraction_digits as i16))
+            .ok()
+    }
+}
+
+impl fmt::Display for SampleValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self.fraction_digits as usize + 1;
+        let mut digits = String::new();
+        write!(digits, "{:0width$}", self.digits, width = width)?;
+        let (integer, fraction) = digits.split_at(digits.len() - self.fraction_digits as usize);
+        f.write_str(integer)?;
+        if !fraction.is_empty() {
+            f.write_char('.')?;
+            f.write_str(fraction)?;
+        }
+        if self.exponent != 0 {
+            write!(f, "c{}", self.exponent)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the sample numbers of a sample range.
+///
+/// The numbers of a range are those between its bounds with the same number of fraction digits,
+/// so `0.0~1.5` has the 16 numbers `0.0`, `0.1`, …, `1.5`.
+pub(crate) fn sample_values(range: PluralSampleRange) -> impl Iterator<Item = SampleValue> {
+    (range.lower..=range.upper).map(move |digits| SampleValue {
+        digits,
+        fraction_digits: range.fraction_digits,
+        exponent: range.exponent,
+    })
+}
+
+impl fmt::Display for PluralSampleRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let lower = SampleValue {
+            digits: self.lower,
+            fraction_digits: self.fraction_digits,
+            exponent: self.exponent,
+        };
+        write!(f, "{}", lower)?;
+        if self.upper != self.lower {
+            write!(
+                f,
+                "~{}",
+                SampleValue {
+                    digits: self.upper,
+                    ..lower
+                }
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -3,12 +3,16 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use icu_locid_macros::langid;
-use icu_plurals::provider::{self, PluralRuleStringsV1};
-use icu_plurals::{PluralCategory, PluralRuleType, PluralRules};
+use icu_plurals::provider::{
+    self, PluralRuleV2, PluralRulesV2, PluralRulesV2Marker, PluralSamplesV1Marker,
+};
+use icu_plurals::{
+    PluralCategory, PluralOperands, PluralRanges, PluralRuleType, PluralRules, PluralRulesError,
+};
 use icu_provider::prelude::*;
 use icu_provider::struct_provider::StructProvider;
-use std::borrow::Cow;
 use std::rc::Rc;
+use zerovec::ZeroVec;
 
 #[test]
 fn test_plural_rules() {
@@ -32,6 +36,140 @@ fn test_plural_rules_missing() {
     assert!(pr.is_err());
 }
 
+#[test]
+fn test_plural_rules_compact() {
+    let provider = icu_testdata::get_provider();
+
+    let lid = langid!("fr");
+
+    let pr = PluralRules::try_new(lid, &provider, PluralRuleType::Cardinal).unwrap();
+
+    let select = |s: &str| pr.select(s.parse::<PluralOperands>().unwrap());
+    assert_eq!(select("1"), PluralCategory::One);
+    assert_eq!(select("1000000"), PluralCategory::Many);
+    assert_eq!(select("1c6"), PluralCategory::Many);
+    assert_eq!(select("1.5c6"), PluralCategory::Many);
+    assert_eq!(select("1c3"), PluralCategory::Other);
+}
+
+#[test]
+fn test_plural_samples() {
+    let provider = icu_testdata::get_provider();
+
+    let lid = langid!("fr");
+
+    let pr = PluralRules::try_new_with_samples(lid, &provider, PluralRuleType::Cardinal).unwrap();
+
+    let samples = |category| -> Vec<String> {
+        pr.samples(category)
+            .map(|sample| sample.to_string())
+            .collect()
+    };
+    assert_eq!(
+        samples(PluralCategory::One),
+        [
+            "0", "1", "0.0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1.0",
+            "1.1", "1.2", "1.3", "1.4", "1.5"
+        ]
+    );
+    // The samples in compact notation are skipped.
+    assert_eq!(samples(PluralCategory::Many), ["1000000"]);
+    assert!(samples(PluralCategory::Zero).is_empty());
+
+    // Without samples loaded, there are none.
+    let pr = PluralRules::try_new(langid!("fr"), &provider, PluralRuleType::Cardinal).unwrap();
+    assert_eq!(pr.samples(PluralCategory::One).count(), 0);
+    assert!(pr.check_samples().is_ok());
+}
+
+#[test]
+fn test_plural_samples_check() {
+    let provider = icu_testdata::get_provider();
+
+    for lid in [
+        langid!("ar"),
+        langid!("bn"),
+        langid!("en"),
+        langid!("es"),
+        langid!("fr"),
+        langid!("ja"),
+        langid!("ru"),
+        langid!("sr"),
+        langid!("th"),
+        langid!("tr"),
+        langid!("und"),
+    ] {
+        for type_ in [PluralRuleType::Cardinal, PluralRuleType::Ordinal] {
+            let pr = PluralRules::try_new_with_samples(lid.clone(), &provider, type_).unwrap();
+            assert!(
+                pr.check_samples().is_ok(),
+                "{:?} {:?}: {:?}",
+                lid,
+                type_,
+                pr.check_samples()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_plural_samples_mismatch() {
+    let provider = icu_testdata::get_provider();
+
+    let request = |key, lid| DataRequest {
+        resource_path: ResourcePath {
+            key,
+            options: ResourceOptions {
+                variant: None,
+                langid: Some(lid),
+            },
+        },
+    };
+    let rules: DataPayload<PluralRulesV2Marker> = provider
+        .load_payload(&request(provider::key::CARDINAL_V2, langid!("en")))
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let samples: DataPayload<PluralSamplesV1Marker> = provider
+        .load_payload(&request(provider::key::CARDINAL_SAMPLES_V1, langid!("fr")))
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    // In English, the French sample 1000000 of "many" is "other".
+    let pr = PluralRules::new_from_data_with_samples(langid!("en"), rules, samples).unwrap();
+    match pr.check_samples() {
+        Err(PluralRulesError::SampleMismatch(sample, category)) => {
+            assert_eq!(sample, "1000000");
+            assert_eq!(category, PluralCategory::Many);
+        }
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn test_plural_ranges() {
+    let provider = icu_testdata::get_provider();
+
+    let ranges = PluralRanges::try_new(langid!("ar"), &provider).unwrap();
+
+    use PluralCategory::*;
+    assert_eq!(ranges.resolve(Zero, One), Zero);
+    assert_eq!(ranges.resolve(One, Two), Other);
+    assert_eq!(ranges.resolve(Other, Two), Other);
+    assert_eq!(ranges.resolve(Few, Many), Many);
+    assert_eq!(ranges.resolve(Other, Few), Few);
+}
+
+#[test]
+fn test_plural_ranges_missing() {
+    let provider = icu_testdata::get_provider();
+
+    let ranges = PluralRanges::try_new(langid!("xx"), &provider);
+
+    assert!(ranges.is_err());
+}
+
 #[test]
 fn test_plural_category_all() {
     let categories: Vec<&PluralCategory> = PluralCategory::all().collect();
@@ -48,16 +186,30 @@ fn test_plural_category_all() {
 
 #[test]
 fn test_plural_rules_non_static_lifetime() {
-    let local_string = "v = 0 and i % 10 = 1".to_string();
-    let local_data = PluralRuleStringsV1 {
+    // "v = 0 and i % 10 = 1", with the operands `v` and `i` at the indices 2 and 1.
+    let local_bytes: Vec<u8> = [
+        2 | 1 << provider::RANGE_COUNT_SHIFT,
+        0,
+        0,
+        1 | provider::AND | provider::MODULUS | 1 << provider::RANGE_COUNT_SHIFT,
+        10,
+        1,
+        1,
+    ]
+    .iter()
+    .flat_map(|word: &u32| word.to_le_bytes())
+    .collect();
+    let local_data = PluralRulesV2 {
         zero: None,
-        one: Some(Cow::Borrowed(&local_string)),
+        one: Some(PluralRuleV2(
+            ZeroVec::try_from_bytes(&local_bytes).expect("Valid bytes"),
+        )),
         two: None,
         few: None,
         many: None,
     };
     let provider = StructProvider {
-        key: provider::key::CARDINAL_V1,
+        key: provider::key::CARDINAL_V2,
         data: DataPayload::from_partial_owned(Rc::from(local_data)),
     };
 
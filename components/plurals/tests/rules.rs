@@ -5,8 +5,13 @@
 mod fixtures;
 mod helpers;
 
+use icu_locid_macros::langid;
+use icu_plurals::provider::{PluralRuleV2, PluralRulesV2};
+use icu_plurals::rules::ast::Condition;
 use icu_plurals::rules::{parse, parse_condition, serialize, test_condition, Lexer};
-use icu_plurals::PluralOperands;
+use icu_plurals::{PluralCategory, PluralOperands, PluralRules};
+use icu_provider::prelude::*;
+use std::convert::TryFrom;
 
 #[test]
 fn test_parsing_operands() {
@@ -45,6 +50,25 @@ fn test_parsing_operands() {
                     );
                 }
 
+                // Test that the binary form of the rule gives the same result.
+                let rule = PluralRuleV2::try_from(&ast).expect("Failed to convert.");
+                assert_eq!(Condition::from(&rule), ast);
+                let rules = PluralRulesV2 {
+                    one: Some(rule),
+                    ..Default::default()
+                };
+                let pr = PluralRules::new_from_data(langid!("und"), DataPayload::from_owned(rules))
+                    .expect("Failed to construct a PluralRules struct.");
+                assert_eq!(
+                    pr.select(operands) == PluralCategory::One,
+                    val,
+                    "\nBinary rule\n\
+                        AST: {:#?}\n\
+                        Operands: {:#?}\n",
+                    ast,
+                    operands
+                );
+
                 // Test that parse/serialize roundtrip completes.
                 let ast = parse(test.rule.as_bytes()).expect("Failed to parse.");
                 let mut string = String::new();
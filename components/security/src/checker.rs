@@ -0,0 +1,165 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Confusable detection and restriction levels, see [`SpoofChecker`].
+
+use crate::error::Error;
+use crate::provider::*;
+use crate::restriction::{RestrictionLevel, ScriptSet};
+use alloc::string::String;
+use icu_normalizer::provider::DecompositionsV1Marker;
+use icu_normalizer::DecomposingNormalizer;
+use icu_provider::prelude::*;
+use icu_uniset::enum_props::Script;
+use icu_uniset::provider::{ScriptExtensionsPropertyV1Marker, UnicodePropertyV1Marker};
+use icu_uniset::script::{self, ScriptWithExtensions};
+
+/// A checker for strings that can be mistaken for others, like identifiers in user names or
+/// domain names, as specified in [UTS #39].
+///
+/// [UTS #39]: https://www.unicode.org/reports/tr39/
+///
+/// # Examples
+///
+/// ```
+/// use icu_security::{RestrictionLevel, SpoofChecker};
+///
+/// let provider = icu_provider_uprops::SecurityDataProvider::new(
+///     icu_testdata::paths::data_root().join("uprops"),
+/// );
+/// let checker = SpoofChecker::try_new(&provider).expect("Data should load successfully");
+///
+/// // "pаypal" with U+0430 CYRILLIC SMALL LETTER A
+/// assert!(checker.are_confusable("paypal", "p\u{430}ypal"));
+/// assert!(checker.is_mixed_script("p\u{430}ypal"));
+/// assert_eq!(
+///     checker.restriction_level("p\u{430}ypal"),
+///     RestrictionLevel::MinimallyRestrictive
+/// );
+/// ```
+pub struct SpoofChecker<'data> {
+    confusables: DataPayload<'data, ConfusablesV1Marker>,
+    allowed: DataPayload<'data, UnicodePropertyV1Marker>,
+    scripts: ScriptWithExtensions<'data>,
+    nfd: DecomposingNormalizer<'data>,
+}
+
+impl<'data> SpoofChecker<'data> {
+    /// Creates a checker with the confusables of UTS #39, the characters that are allowed in
+    /// identifiers, and the Script_Extensions property.
+    pub fn try_new<D>(provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, ConfusablesV1Marker>
+            + DataProvider<'data, UnicodePropertyV1Marker>
+            + DataProvider<'data, ScriptExtensionsPropertyV1Marker>
+            + DataProvider<'data, DecompositionsV1Marker>
+            + ?Sized,
+    {
+        let resp: DataResponse<ConfusablesV1Marker> =
+            provider.load_payload(&DataRequest::from(key::CONFUSABLES_V1))?;
+        Ok(SpoofChecker {
+            confusables: resp.take_payload()?,
+            allowed: icu_uniset::props::get_identifier_status_allowed_property(provider)?,
+            scripts: script::get_script_with_extensions(provider)?,
+            nfd: DecomposingNormalizer::try_new_nfd(provider)?,
+        })
+    }
+
+    /// Returns the skeleton of a string, which is the same for strings that are confusable.
+    ///
+    /// The skeleton is the NFD form of the string with each character replaced by its
+    /// prototype from `confusables.txt`. It is only meant for comparisons with other skeletons,
+    /// and not for display.
+    pub fn skeleton(&self, text: &str) -> String {
+        let confusables = self.confusables.get();
+        let mut mapped = String::with_capacity(text.len());
+        for c in self.nfd.normalize_iter(text.chars()) {
+            match confusables.get(c) {
+                Some(prototype) => mapped.push_str(prototype),
+                None => mapped.push(c),
+            }
+        }
+        self.nfd.normalize(&mapped)
+    }
+
+    /// Returns whether two strings are confusable, that is, whether they have the same
+    /// skeleton.
+    pub fn are_confusable(&self, first: &str, second: &str) -> bool {
+        self.skeleton(first) == self.skeleton(second)
+    }
+
+    /// Returns whether a string mixes characters of different scripts, that is, whether the
+    /// scripts of its characters have no script in common.
+    ///
+    /// Characters that are used with all scripts, like digits and punctuation, do not make a
+    /// string mixed-script, and Han is in common with Hiragana, Katakana, Hangul and Bopomofo.
+    pub fn is_mixed_script(&self, text: &str) -> bool {
+        self.resolved_scripts(text, None).is_empty()
+    }
+
+    /// Returns the most restrictive level of UTS #39 that a string satisfies.
+    pub fn restriction_level(&self, text: &str) -> RestrictionLevel {
+        let allowed = &self.allowed.get().inv_list;
+        if !text.chars().all(|c| allowed.contains(c)) {
+            return RestrictionLevel::Unrestricted;
+        }
+        if text.is_ascii() {
+            return RestrictionLevel::AsciiOnly;
+        }
+        if !self.resolved_scripts(text, None).is_empty() {
+            return RestrictionLevel::SingleScript;
+        }
+        let without_latin = self.resolved_scripts(text, Some(Script::Latin));
+        if without_latin.contains(Script::Japanese)
+            || without_latin.contains(Script::Korean)
+            || without_latin.contains(Script::HanWithBopomofo)
+        {
+            RestrictionLevel::HighlyRestrictive
+        } else if !without_latin.is_empty()
+            && !without_latin.contains(Script::Cyrillic)
+            && !without_latin.contains(Script::Greek)
+            && !without_latin.contains(Script::Cherokee)
+        {
+            RestrictionLevel::ModeratelyRestrictive
+        } else {
+            RestrictionLevel::MinimallyRestrictive
+        }
+    }
+
+    /// Returns the scripts that all characters of a string have in common, ignoring the
+    /// characters of the script `ignored`.
+    fn resolved_scripts(&self, text: &str, ignored: Option<Script>) -> ScriptSet {
+        let mut resolved = ScriptSet::ALL;
+        for c in text.chars() {
+            let scripts = self.augmented_scripts(c);
+            if matches!(ignored, Some(script) if scripts.contains(script)) {
+                continue;
+            }
+            resolved.intersect(&scripts);
+        }
+        resolved
+    }
+
+    /// Returns the Script_Extensions of a character, with the scripts for the writing systems
+    /// that combine Han with other scripts. Characters used with all scripts have all of them.
+    fn augmented_scripts(&self, c: char) -> ScriptSet {
+        let mut scripts = ScriptSet::default();
+        for script in self.scripts.get_script_extensions(c) {
+            match script {
+                Script::Common | Script::Inherited => return ScriptSet::ALL,
+                Script::Han => {
+                    scripts.insert(Script::HanWithBopomofo);
+                    scripts.insert(Script::Japanese);
+                    scripts.insert(Script::Korean);
+                }
+                Script::Hiragana | Script::Katakana => scripts.insert(Script::Japanese),
+                Script::Hangul => scripts.insert(Script::Korean),
+                Script::Bopomofo => scripts.insert(Script::HanWithBopomofo),
+                _ => {}
+            }
+            scripts.insert(script);
+        }
+        scripts
+    }
+}
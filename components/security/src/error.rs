@@ -0,0 +1,42 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Error types for security checks.
+
+use displaydoc::Display;
+use icu_uniset::UnicodeSetError;
+
+#[derive(Display, Debug)]
+pub enum Error {
+    #[displaydoc("error loading data: {0}")]
+    Data(icu_provider::DataError),
+    #[displaydoc("invalid property data: {0}")]
+    Property(UnicodeSetError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<icu_provider::DataError> for Error {
+    fn from(e: icu_provider::DataError) -> Self {
+        Error::Data(e)
+    }
+}
+
+impl From<icu_normalizer::NormalizerError> for Error {
+    fn from(e: icu_normalizer::NormalizerError) -> Self {
+        match e {
+            icu_normalizer::NormalizerError::Data(e) => Error::Data(e),
+        }
+    }
+}
+
+impl From<UnicodeSetError> for Error {
+    fn from(e: UnicodeSetError) -> Self {
+        match e {
+            UnicodeSetError::PropDataLoad(e) => Error::Data(e),
+            e => Error::Property(e),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_security`](crate) is an implementation of the security mechanisms for identifiers of
+//! [Unicode Technical Standard #39][UTS39].
+//!
+//! A [`SpoofChecker`] detects strings that can be mistaken for each other. It computes the
+//! skeletons of strings with the confusable characters of `confusables.txt`, like the Latin "a"
+//! and the Cyrillic "а", detects strings that mix scripts, and determines the restriction level
+//! of an identifier from the scripts of its characters and whether they are allowed in
+//! identifiers.
+//!
+//! [UTS39]: https://www.unicode.org/reports/tr39/
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_security::{RestrictionLevel, SpoofChecker};
+//!
+//! let provider = icu_provider_uprops::SecurityDataProvider::new(
+//!     icu_testdata::paths::data_root().join("uprops"),
+//! );
+//! let checker = SpoofChecker::try_new(&provider).expect("Data should load successfully");
+//!
+//! // The digit one and the letter "l", and the letters "r" and "n" next to each other and "m"
+//! assert!(checker.are_confusable("1ogin", "login"));
+//! assert!(checker.are_confusable("rnail", "mail"));
+//! assert!(!checker.are_confusable("mail", "nail"));
+//!
+//! assert_eq!(checker.restriction_level("login"), RestrictionLevel::AsciiOnly);
+//! assert_eq!(checker.restriction_level("Ελληνικά"), RestrictionLevel::SingleScript);
+//! assert_eq!(checker.restriction_level("東京タワー"), RestrictionLevel::SingleScript);
+//! assert_eq!(
+//!     checker.restriction_level("iPhone電話"),
+//!     RestrictionLevel::HighlyRestrictive
+//! );
+//! assert_eq!(checker.restriction_level("♥"), RestrictionLevel::Unrestricted);
+//! ```
+//!
+//! # Test data
+//!
+//! The conformance tests check the skeleton of every code point against
+//! `tests/testdata/confusables.txt`, a copy of the file of the same name of UTS #39, and read
+//! `tests/testdata/RestrictionLevelTest.txt`, the restriction levels of strings of characters of
+//! different scripts. The latter is generated with the spoof checker of ICU4C by
+//! `tools/generate_tests.c`, whose header has the commands to build and run it.
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod checker;
+pub mod error;
+pub mod provider;
+mod restriction;
+
+pub use checker::SpoofChecker;
+pub use error::Error as SecurityError;
+pub use restriction::RestrictionLevel;
@@ -0,0 +1,48 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::string::String;
+use icu_provider::yoke::{self, *};
+use zerovec::{VarZeroVec, ZeroVec};
+
+pub mod key {
+    //! Resource keys for [`icu_security`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: the prototypes of the confusable characters of `confusables.txt`.
+    pub const CONFUSABLES_V1: ResourceKey = resource_key!(Security, "confusables", 1);
+}
+
+/// The mappings of `confusables.txt` from UTS #39, from characters to the prototypes that they
+/// can be confused with.
+///
+/// `sources` has the code points with a prototype in ascending order, and `prototypes` has
+/// their prototypes at the same indices. Only code points that are unchanged by NFD are
+/// included, and the prototypes are in NFD.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct ConfusablesV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub sources: ZeroVec<'data, u32>,
+
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub prototypes: VarZeroVec<'data, String>,
+}
+
+impl ConfusablesV1<'_> {
+    /// Returns the prototype of `c`, or `None` if it is not confusable with another string.
+    pub fn get(&self, c: char) -> Option<&str> {
+        let index = self.sources.binary_search(&(c as u32)).ok()?;
+        self.prototypes.get(index)
+    }
+}
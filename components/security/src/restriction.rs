@@ -0,0 +1,85 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Restriction levels and sets of scripts, see [`RestrictionLevel`].
+
+use icu_uniset::enum_props::Script;
+
+/// The restriction levels of identifiers of [UTS #39], from the most to the least restrictive.
+///
+/// A string is at a restriction level if it satisfies its condition and not the ones of the
+/// more restrictive levels.
+///
+/// [UTS #39]: https://www.unicode.org/reports/tr39/#Restriction_Level_Detection
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RestrictionLevel {
+    /// All characters are ASCII.
+    AsciiOnly,
+    /// All characters are from a single script, or from a script and characters that are
+    /// used with all scripts, like digits and punctuation.
+    SingleScript,
+    /// The characters are from Latin and one of the combinations of Han with Hiragana and
+    /// Katakana, with Bopomofo or with Hangul.
+    HighlyRestrictive,
+    /// The characters are from Latin and a single other script, which is not Cyrillic, Greek
+    /// or Cherokee.
+    ModeratelyRestrictive,
+    /// The characters are from any scripts, and all are allowed in identifiers.
+    MinimallyRestrictive,
+    /// Some characters are not allowed in identifiers.
+    Unrestricted,
+}
+
+/// A set of scripts, indexed by the integer values of [`Script`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct ScriptSet([u64; 4]);
+
+impl ScriptSet {
+    /// The set of all scripts.
+    pub const ALL: ScriptSet = ScriptSet([u64::MAX; 4]);
+
+    pub fn insert(&mut self, script: Script) {
+        let index = script as usize;
+        self.0[index / 64] |= 1 << (index % 64);
+    }
+
+    pub fn contains(&self, script: Script) -> bool {
+        let index = script as usize;
+        self.0[index / 64] & 1 << (index % 64) != 0
+    }
+
+    pub fn intersect(&mut self, other: &ScriptSet) {
+        for (word, other) in self.0.iter_mut().zip(other.0.iter()) {
+            *word &= other;
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_set() {
+        let mut set = ScriptSet::default();
+        assert!(set.is_empty());
+        set.insert(Script::Latin);
+        set.insert(Script::HanWithBopomofo);
+        assert!(set.contains(Script::Latin));
+        assert!(set.contains(Script::HanWithBopomofo));
+        assert!(!set.contains(Script::Greek));
+
+        let mut all = ScriptSet::ALL;
+        all.intersect(&set);
+        assert_eq!(all, set);
+        let mut greek = ScriptSet::default();
+        greek.insert(Script::Greek);
+        set.intersect(&greek);
+        assert!(set.is_empty());
+    }
+}
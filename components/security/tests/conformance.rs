@@ -0,0 +1,135 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_normalizer::DecomposingNormalizer;
+use icu_provider_uprops::SecurityDataProvider;
+use icu_security::{RestrictionLevel, SpoofChecker};
+use std::char;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::BufReader;
+
+fn parse_code_points(field: &str) -> String {
+    field
+        .split_ascii_whitespace()
+        .map(|c| u32::from_str_radix(c, 16).expect("Valid hex"))
+        .map(|c| char::from_u32(c).expect("Valid code point"))
+        .collect()
+}
+
+/// Returns the fields of the lines of a test file, separated by semicolons, without comments.
+fn read_test_file(path: &str) -> Vec<Vec<String>> {
+    let f = BufReader::new(File::open(path).expect("Test file"));
+    f.lines()
+        .map(|line| line.expect("Valid line"))
+        .filter_map(|line| {
+            let line = line
+                .trim_start_matches('\u{FEFF}')
+                .split('#')
+                .next()
+                .expect("Split has a first element")
+                .trim();
+            if line.is_empty() {
+                return None;
+            }
+            Some(
+                line.split(';')
+                    .map(|field| field.trim().to_string())
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+fn provider() -> SecurityDataProvider {
+    SecurityDataProvider::new(icu_testdata::paths::data_root().join("uprops"))
+}
+
+fn checker() -> SpoofChecker<'static> {
+    SpoofChecker::try_new(&provider()).expect("Valid data")
+}
+
+#[test]
+fn test_restriction_levels() {
+    let checker = checker();
+    let lines = read_test_file("./tests/testdata/RestrictionLevelTest.txt");
+    assert!(!lines.is_empty());
+    for fields in lines {
+        let text = parse_code_points(&fields[0]);
+        let expected = match fields[1].as_str() {
+            "ascii" => RestrictionLevel::AsciiOnly,
+            "single" => RestrictionLevel::SingleScript,
+            "highly" => RestrictionLevel::HighlyRestrictive,
+            "moderately" => RestrictionLevel::ModeratelyRestrictive,
+            "minimally" => RestrictionLevel::MinimallyRestrictive,
+            "unrestricted" => RestrictionLevel::Unrestricted,
+            level => panic!("Unknown restriction level {}", level),
+        };
+        assert_eq!(
+            checker.restriction_level(&text),
+            expected,
+            "{:?} ({})",
+            text,
+            fields[0]
+        );
+    }
+}
+
+#[test]
+fn test_confusables() {
+    let checker = checker();
+    let nfd = DecomposingNormalizer::try_new_nfd(&provider()).expect("Valid data");
+    let lines = read_test_file("./tests/testdata/confusables.txt");
+    assert!(!lines.is_empty());
+    let prototypes: HashMap<char, String> = lines
+        .iter()
+        .map(|fields| {
+            let source = parse_code_points(&fields[0]);
+            let mut chars = source.chars();
+            let source = chars.next().expect("One character");
+            assert_eq!(chars.next(), None);
+            (source, parse_code_points(&fields[1]))
+        })
+        .collect();
+
+    // The skeleton is the NFD form of the NFD form with each character replaced by its prototype.
+    for c in '\0'..=char::MAX {
+        let mapped: String = nfd
+            .normalize_iter(std::iter::once(c))
+            .map(|c| prototypes.get(&c).cloned().unwrap_or_else(|| c.to_string()))
+            .collect();
+        assert_eq!(
+            checker.skeleton(&c.to_string()),
+            nfd.normalize(&mapped),
+            "{:04X}",
+            c as u32
+        );
+    }
+
+    // U+0430 CYRILLIC SMALL LETTER A
+    assert!(checker.are_confusable("paypal", "p\u{430}ypal"));
+    assert!(checker.are_confusable("rnail", "mail"));
+    assert!(checker.are_confusable("A\u{300}", "\u{C0}"));
+    assert!(!checker.are_confusable("mail", "nail"));
+}
+
+#[test]
+fn test_mixed_script() {
+    let checker = checker();
+    assert!(!checker.is_mixed_script(""));
+    assert!(!checker.is_mixed_script("abc"));
+    // Digits, punctuation and combining marks are used with all scripts.
+    assert!(!checker.is_mixed_script("Ελληνικά-1\u{301}"));
+    // Han is used together with Hiragana, Katakana, Hangul and Bopomofo.
+    assert!(!checker.is_mixed_script("東京タワーひ"));
+    assert!(!checker.is_mixed_script("中국"));
+    assert!(!checker.is_mixed_script("中ㄅ"));
+    assert!(checker.is_mixed_script("ひ국"));
+    // U+0430 CYRILLIC SMALL LETTER A
+    assert!(checker.is_mixed_script("p\u{430}ypal"));
+    // U+0640 ARABIC TATWEEL is used with Arabic and Syriac.
+    assert!(!checker.is_mixed_script("\u{628}\u{640}"));
+    assert!(checker.is_mixed_script("a\u{640}"));
+}
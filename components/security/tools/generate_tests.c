@@ -0,0 +1,184 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Generates tests/testdata/RestrictionLevelTest.txt with the spoof checker of ICU4C.
+//
+//     cc -o generate_tests generate_tests.c $(pkg-config --cflags --libs icu-i18n)
+//     ./generate_tests ../tests/testdata
+//
+// The output only depends on the ICU4C version, which is written in the header of the file.
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <unicode/uchar.h>
+#include <unicode/uspoof.h>
+#include <unicode/uset.h>
+#include <unicode/utf16.h>
+#include <unicode/uversion.h>
+
+#define MAX_LENGTH 64
+
+static const char *out_dir;
+static USpoofChecker *checker;
+
+static void check(UErrorCode err, const char *what) {
+    if (U_FAILURE(err)) {
+        fprintf(stderr, "%s: %s\n", what, u_errorName(err));
+        exit(1);
+    }
+}
+
+// Creates the file `name` in the output directory, and writes the first lines of its header.
+static FILE *create(const char *name) {
+    char path[1024];
+    snprintf(path, sizeof path, "%s/%s", out_dir, name);
+    FILE *f = fopen(path, "w");
+    if (f == NULL) {
+        perror(path);
+        exit(1);
+    }
+    char version[U_MAX_VERSION_STRING_LENGTH], unicode[U_MAX_VERSION_STRING_LENGTH];
+    UVersionInfo info;
+    u_getVersion(info);
+    u_versionToString(info, version);
+    u_getUnicodeVersion(info);
+    u_versionToString(info, unicode);
+    fprintf(f, "# %s\n#\n# Generated by tools/generate_tests.c with ICU4C %s (Unicode %s).\n#\n",
+            name, version, unicode);
+    return f;
+}
+
+// A string of code points.
+typedef struct {
+    UChar32 c[MAX_LENGTH];
+    int length;
+} CodePoints;
+
+static void print_code_points(FILE *f, const CodePoints *s) {
+    for (int i = 0; i < s->length; i++) {
+        fprintf(f, i > 0 ? " %04X" : "%04X", s->c[i]);
+    }
+}
+
+static int32_t to_utf16(const CodePoints *s, UChar *text) {
+    int32_t length = 0;
+    for (int i = 0; i < s->length; i++) {
+        U16_APPEND_UNSAFE(text, length, s->c[i]);
+    }
+    return length;
+}
+
+// A deterministic xorshift generator, so that the output only depends on the ICU4C version.
+static unsigned long long rng_state = 88172645463325252ULL;
+static unsigned rng(void) {
+    rng_state ^= rng_state << 13;
+    rng_state ^= rng_state >> 7;
+    rng_state ^= rng_state << 17;
+    return (unsigned)(rng_state >> 11);
+}
+
+static const char *restriction_level_name(URestrictionLevel level) {
+    switch (level) {
+    case USPOOF_ASCII:
+        return "ascii";
+    case USPOOF_SINGLE_SCRIPT_RESTRICTIVE:
+        return "single";
+    case USPOOF_HIGHLY_RESTRICTIVE:
+        return "highly";
+    case USPOOF_MODERATELY_RESTRICTIVE:
+        return "moderately";
+    case USPOOF_MINIMALLY_RESTRICTIVE:
+        return "minimally";
+    default:
+        return "unrestricted";
+    }
+}
+
+// Characters of different scripts, and some with Script_Extensions, that are not allowed in
+// identifiers, or that are invisible.
+static const UChar32 RESTRICTION_POOL[] = {
+    0x2D,   0x2E,   0x31,   0x5A,   0x5F,   0x61,   0xDF,   0xE9,   0x131, 0x1C5, 0x2BB,
+    0x301,  0x3A9,  0x3B1,  0x416,  0x434,  0x561,  0x5D0,  0x628,  0x640, 0x663, 0x915,
+    0x13E3, 0x200D, 0x3001, 0x3072, 0x30AB, 0x30FB, 0x30FC, 0x3105, 0x4E2D, 0x8A9E, 0xD55C,
+};
+
+static int compare_code_points(const void *a, const void *b) {
+    const CodePoints *x = a, *y = b;
+    for (int i = 0; i < x->length && i < y->length; i++) {
+        if (x->c[i] != y->c[i]) {
+            return x->c[i] < y->c[i] ? -1 : 1;
+        }
+    }
+    return x->length - y->length;
+}
+
+// Writes the restriction levels of all strings of one or two characters of RESTRICTION_POOL, and
+// of random strings of three to five of them.
+static void write_restriction_level_test(void) {
+    UErrorCode err = U_ZERO_ERROR;
+    USet *allowed = uset_openEmpty();
+    uset_addAll(allowed, uspoof_getRecommendedSet(&err));
+    uset_addAll(allowed, uspoof_getInclusionSet(&err));
+    uspoof_setAllowedChars(checker, allowed, &err);
+    uspoof_setChecks(checker, USPOOF_RESTRICTION_LEVEL, &err);
+    // The lowest level, so that the check reports the level of every string
+    uspoof_setRestrictionLevel(checker, USPOOF_ASCII);
+    check(err, "uspoof_setChecks");
+
+    const int pool_length = sizeof RESTRICTION_POOL / sizeof RESTRICTION_POOL[0];
+    static CodePoints strings[2048];
+    int count = 0;
+    for (int i = 0; i < pool_length; i++) {
+        strings[count++] = (CodePoints){{RESTRICTION_POOL[i]}, 1};
+        for (int j = 0; j < pool_length; j++) {
+            strings[count++] = (CodePoints){{RESTRICTION_POOL[i], RESTRICTION_POOL[j]}, 2};
+        }
+    }
+    for (int n = 0; n < 400; n++) {
+        CodePoints *s = &strings[count++];
+        s->length = 3 + rng() % 3;
+        for (int i = 0; i < s->length; i++) {
+            s->c[i] = RESTRICTION_POOL[rng() % pool_length];
+        }
+    }
+    qsort(strings, count, sizeof strings[0], compare_code_points);
+
+    FILE *f = create("RestrictionLevelTest.txt");
+    fprintf(f, "# The restriction levels of UTS #39 of strings, with the characters that have the\n"
+               "# Identifier_Status Allowed as the identifier profile.\n"
+               "#\n"
+               "# Format: code points ; restriction level\n"
+               "#\n");
+    USpoofCheckResult *result = uspoof_openCheckResult(&err);
+    for (int i = 0; i < count; i++) {
+        if (i > 0 && compare_code_points(&strings[i - 1], &strings[i]) == 0) {
+            continue;
+        }
+        UChar text[2 * MAX_LENGTH];
+        int32_t length = to_utf16(&strings[i], text);
+        uspoof_check2(checker, text, length, result, &err);
+        URestrictionLevel level = uspoof_getCheckResultRestrictionLevel(result, &err);
+        check(err, "uspoof_check2");
+        print_code_points(f, &strings[i]);
+        fprintf(f, " ; %s\n", restriction_level_name(level));
+    }
+    uspoof_closeCheckResult(result);
+    uset_close(allowed);
+    fclose(f);
+}
+
+int main(int argc, char **argv) {
+    if (argc != 2) {
+        fprintf(stderr, "Usage: %s OUTPUT_DIRECTORY\n", argv[0]);
+        return 1;
+    }
+    out_dir = argv[1];
+    UErrorCode err = U_ZERO_ERROR;
+    checker = uspoof_open(&err);
+    check(err, "uspoof_open");
+
+    write_restriction_level_test();
+    uspoof_close(checker);
+    return 0;
+}
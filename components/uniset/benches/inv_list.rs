@@ -63,6 +63,47 @@ fn uniset_bench(c: &mut Criterion) {
             })
         });
         group.finish();
+
+        let mut group = c.benchmark_group("uniset/span");
+        let sample_str: String = "ABCDEFGHIJ".repeat(100);
+        group.bench_with_input("best", &best_sample, |b, sample| {
+            b.iter(|| sample.span(&sample_str, true))
+        });
+        group.bench_with_input("worst", &worst_sample, |b, sample| {
+            b.iter(|| sample.span(&sample_str, true))
+        });
+        group.finish();
+
+        let mut group = c.benchmark_group("uniset/iter_ranges");
+        group.bench_with_input("best", &best_sample, |b, sample| {
+            b.iter(|| {
+                sample
+                    .iter_ranges()
+                    .map(|range| range.end() - range.start())
+                    .sum::<u32>()
+            })
+        });
+        group.bench_with_input("worst", &worst_sample, |b, sample| {
+            b.iter(|| {
+                sample
+                    .iter_ranges()
+                    .map(|range| range.end() - range.start())
+                    .sum::<u32>()
+            })
+        });
+        group.finish();
+
+        // Deserializing from Postcard borrows the inversion list, while a Vec<u32> needs to be allocated.
+        let mut group = c.benchmark_group("uniset/deserialize");
+        let worst_bytes = postcard::to_allocvec(&worst_sample).unwrap();
+        let worst_vec_bytes = postcard::to_allocvec(&worst_sample.get_inversion_list()).unwrap();
+        group.bench_with_input("zerovec", &worst_bytes, |b, bytes| {
+            b.iter(|| postcard::from_bytes::<UnicodeSet>(bytes).unwrap())
+        });
+        group.bench_with_input("vec", &worst_vec_bytes, |b, bytes| {
+            b.iter(|| postcard::from_bytes::<Vec<u32>>(bytes).unwrap())
+        });
+        group.finish();
     }
 }
 
@@ -23,7 +23,7 @@ impl UnicodeSetBuilder {
     }
 
     /// Returns a [`UnicodeSet`] and consumes the [`UnicodeSetBuilder`]
-    pub fn build(self) -> UnicodeSet {
+    pub fn build(self) -> UnicodeSet<'static> {
         UnicodeSet::from_inversion_list(self.intervals).unwrap()
     }
 
@@ -66,7 +66,7 @@ impl UnicodeSetBuilder {
     /// Accomplishes this through binary search for the start and end indices and merges intervals
     /// in between with inplace memory. Performs `O(1)` operation if adding to end of list, and `O(N)` otherwise,
     /// where `N` is the number of endpoints.
-    fn add(&mut self, start: u32, end: u32) {
+    pub(crate) fn add(&mut self, start: u32, end: u32) {
         if start >= end {
             return;
         }
@@ -145,8 +145,8 @@ impl UnicodeSetBuilder {
     /// assert_eq!(check.iter_chars().next(), Some('A'));
     /// ```
     pub fn add_set(&mut self, set: &UnicodeSet) {
-        for range in set.as_inversion_list().chunks(2) {
-            self.add(range[0], range[1]);
+        for range in set.iter_ranges() {
+            self.add(*range.start(), range.end() + 1);
         }
     }
 
@@ -212,8 +212,8 @@ impl UnicodeSetBuilder {
     /// let check = builder.build();
     /// assert_eq!(check.iter_chars().next(), Some('F'));
     pub fn remove_set(&mut self, set: &UnicodeSet) {
-        for range in set.as_inversion_list().chunks(2) {
-            self.remove(range[0], range[1]);
+        for range in set.iter_ranges() {
+            self.remove(*range.start(), range.end() + 1);
         }
     }
 
@@ -274,9 +274,9 @@ impl UnicodeSetBuilder {
     /// ```
     pub fn retain_set(&mut self, set: &UnicodeSet) {
         let mut prev = 0;
-        for range in set.as_inversion_list().chunks(2) {
-            self.remove(prev, range[0]);
-            prev = range[1];
+        for range in set.iter_ranges() {
+            self.remove(prev, *range.start());
+            prev = range.end() + 1;
         }
         self.remove(prev, (char::MAX as u32) + 1);
     }
@@ -286,20 +286,20 @@ impl UnicodeSetBuilder {
     ///
     /// Performs in `O(B + S)`, where `B` is the number of endpoints in the Builder, and `S` is the number
     /// of endpoints in the argument.
-    fn complement_list(&mut self, set: &[u32]) {
+    fn complement_list(&mut self, set: impl IntoIterator<Item = u32>) {
         let mut res: Vec<u32> = vec![]; // not the biggest fan of having to allocate new memory
-        let mut ai = self.intervals.iter();
-        let mut bi = set.iter();
+        let mut ai = self.intervals.iter().copied();
+        let mut bi = set.into_iter();
         let mut a = ai.next();
         let mut b = bi.next();
         while let (Some(c), Some(d)) = (a, b) {
-            match c.cmp(d) {
+            match c.cmp(&d) {
                 Ordering::Less => {
-                    res.push(*c);
+                    res.push(c);
                     a = ai.next();
                 }
                 Ordering::Greater => {
-                    res.push(*d);
+                    res.push(d);
                     b = bi.next();
                 }
                 Ordering::Equal => {
@@ -309,10 +309,10 @@ impl UnicodeSetBuilder {
             }
         }
         if let Some(c) = a {
-            res.push(*c)
+            res.push(c)
         }
         if let Some(d) = b {
-            res.push(*d)
+            res.push(d)
         }
         res.extend(ai);
         res.extend(bi);
@@ -334,17 +334,15 @@ impl UnicodeSetBuilder {
     /// assert_eq!(check.iter_chars().next(), Some('A'));
     /// ```
     pub fn complement(&mut self) {
-        if !self.intervals.is_empty() {
-            if self.intervals[0] == 0 {
-                self.intervals.drain(0..1);
-            } else {
-                self.intervals.insert(0, 0);
-            }
-            if self.intervals.last() == Some(&((char::MAX as u32) + 1)) {
-                self.intervals.pop();
-            } else {
-                self.intervals.push((char::MAX as u32) + 1);
-            }
+        if self.intervals.first() == Some(&0) {
+            self.intervals.drain(0..1);
+        } else {
+            self.intervals.insert(0, 0);
+        }
+        if self.intervals.last() == Some(&((char::MAX as u32) + 1)) {
+            self.intervals.pop();
+        } else {
+            self.intervals.push((char::MAX as u32) + 1);
         }
     }
 
@@ -365,7 +363,7 @@ impl UnicodeSetBuilder {
     pub fn complement_char(&mut self, c: char) {
         let code_point = c as u32;
         let to_complement = [code_point, code_point + 1];
-        self.complement_list(&to_complement);
+        self.complement_list(to_complement.iter().copied());
     }
 
     /// Complements the range in the builder, adding any elements in the range if not in the builder, and
@@ -385,7 +383,7 @@ impl UnicodeSetBuilder {
     pub fn complement_range(&mut self, range: &impl RangeBounds<char>) {
         let (start, end) = deconstruct_range(range);
         let to_complement = [start, end];
-        self.complement_list(&to_complement);
+        self.complement_list(to_complement.iter().copied());
     }
 
     /// Complements the set in the builder, adding any elements in the set if not in the builder, and
@@ -404,7 +402,7 @@ impl UnicodeSetBuilder {
     /// assert!(!check.contains('N')); // 78
     /// ```
     pub fn complement_set(&mut self, set: &UnicodeSet) {
-        self.complement_list(set.as_inversion_list());
+        self.complement_list(set.as_inversion_list().iter());
     }
 
     /// Returns whether the build is empty.
@@ -791,10 +789,22 @@ mod tests {
         assert_eq!(builder.intervals, expected);
     }
 
+    #[test]
+    fn test_complement_empty() {
+        let mut builder = generate_tester(vec![]);
+        builder.complement();
+        let expected = vec![0x0, (char::MAX as u32) + 1];
+        assert_eq!(builder.intervals, expected);
+
+        builder.complement();
+        let expected: Vec<u32> = vec![];
+        assert_eq!(builder.intervals, expected);
+    }
+
     #[test]
     fn test_complement_interior() {
         let mut builder = generate_tester(vec![0xA, 0x14, 0x28, 0x32]);
-        builder.complement_list(&[0xE, 0x14]);
+        builder.complement_list([0xE, 0x14].iter().copied());
         let expected = vec![0xA, 0xE, 0x28, 0x32];
         assert_eq!(builder.intervals, expected);
     }
@@ -802,7 +812,7 @@ mod tests {
     #[test]
     fn test_complement_exterior() {
         let mut builder = generate_tester(vec![0xA, 0x14, 0x28, 0x32]);
-        builder.complement_list(&[0x19, 0x23]);
+        builder.complement_list([0x19, 0x23].iter().copied());
         let expected = vec![0xA, 0x14, 0x19, 0x23, 0x28, 0x32];
         assert_eq!(builder.intervals, expected);
     }
@@ -810,7 +820,7 @@ mod tests {
     #[test]
     fn test_complement_larger_list() {
         let mut builder = generate_tester(vec![0xA, 0x14, 0x28, 0x32]);
-        builder.complement_list(&[0x1E, 0x37, 0x3C, 0x46]);
+        builder.complement_list([0x1E, 0x37, 0x3C, 0x46].iter().copied());
         let expected = vec![0xA, 0x14, 0x1E, 0x28, 0x32, 0x37, 0x3C, 0x46];
         assert_eq!(builder.intervals, expected);
     }
@@ -4,20 +4,29 @@
 
 #![allow(clippy::upper_case_acronyms)]
 
+use core::convert::TryFrom;
+
 /// Selection constants for Unicode properties.
 /// These constants are used to select one of the Unicode properties.
 /// See UProperty in ICU4C.
 #[derive(Clone, PartialEq, Debug)]
 pub enum EnumeratedProperty {
+    BidiClass = 0x1000,
+    EastAsianWidth = 0x1004,
     GeneralCategory = 0x1005,
+    LineBreak = 0x1008,
     Script = 0x100A,
+    GraphemeClusterBreak = 0x1012,
+    SentenceBreak = 0x1013,
+    WordBreak = 0x1014,
+    BidiPairedBracketType = 0x1015,
     ScriptExtensions = 0x7000,
 }
 
 /// Enumerated Unicode general category types.
 /// See https://www.unicode.org/reports/tr44/ .
 /// See UCharCategory in ICU4C.
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum GeneralCategory {
     Control = 15,
     Format = 16,
@@ -51,11 +60,55 @@ pub enum GeneralCategory {
     SpaceSeparator = 12,
 }
 
+/// Enumerated property Bidi_Class.
+///
+/// The directional type of a character, as used by the Unicode Bidirectional Algorithm.
+/// See https://www.unicode.org/reports/tr9/ .
+/// See UCharDirection in ICU4C.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum BidiClass {
+    LeftToRight = 0,
+    RightToLeft = 1,
+    EuropeanNumber = 2,
+    EuropeanSeparator = 3,
+    EuropeanTerminator = 4,
+    ArabicNumber = 5,
+    CommonSeparator = 6,
+    ParagraphSeparator = 7,
+    SegmentSeparator = 8,
+    WhiteSpace = 9,
+    OtherNeutral = 10,
+    LeftToRightEmbedding = 11,
+    LeftToRightOverride = 12,
+    ArabicLetter = 13,
+    RightToLeftEmbedding = 14,
+    RightToLeftOverride = 15,
+    PopDirectionalFormat = 16,
+    NonspacingMark = 17,
+    BoundaryNeutral = 18,
+    FirstStrongIsolate = 19,
+    LeftToRightIsolate = 20,
+    RightToLeftIsolate = 21,
+    PopDirectionalIsolate = 22,
+}
+
+/// Enumerated property Bidi_Paired_Bracket_Type.
+///
+/// Whether a character is an opening or closing paired bracket, as used by the Unicode
+/// Bidirectional Algorithm.
+/// See UBidiPairedBracketType in ICU4C.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum BidiPairedBracketType {
+    None = 0,
+    Open = 1,
+    Close = 2,
+}
+
 //// Enumerated property Script.
 ///
 /// For more information, see UAX #24: http://www.unicode.org/reports/tr24/.
 /// See UScriptCode in ICU4C.
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum Script {
     Adlam = 167,
     Afaka = 147,
@@ -85,11 +138,12 @@ pub enum Script {
     Cherokee = 6,
     Chorasmian = 189,
     Cirth = 67,
-    CodeLimit = 193,
+    CodeLimit = 200,
     Common = 0,
     Coptic = 7,
     Cuneiform = 101,
     Cypriot = 47,
+    CyproMinoan = 193,
     Cyrillic = 8,
     DemoticEgyptian = 69,
     Deseret = 9,
@@ -134,6 +188,7 @@ pub enum Script {
     Kannada = 21,
     Katakana = 22,
     KatakanaOrHiragana = 54,
+    Kawi = 198,
     KayahLi = 79,
     Kharoshthi = 57,
     KhitanSmallScript = 191,
@@ -178,6 +233,7 @@ pub enum Script {
     Multani = 164,
     Myanmar = 28,
     Nabataean = 143,
+    NagMundari = 199,
     NakhiGeba = 132,
     Nandinagari = 187,
     NewTaiLue = 59,
@@ -195,6 +251,7 @@ pub enum Script {
     OldPersian = 61,
     OldSogdian = 184,
     OldSouthArabian = 133,
+    OldUyghur = 194,
     Oriya = 31,
     Orkhon = 88,
     Osage = 171,
@@ -231,6 +288,7 @@ pub enum Script {
     TaiViet = 127,
     Takri = 153,
     Tamil = 35,
+    Tangsa = 195,
     Tangut = 154,
     Telugu = 36,
     Tengwar = 98,
@@ -239,12 +297,14 @@ pub enum Script {
     Tibetan = 39,
     Tifinagh = 60,
     Tirhuta = 158,
+    Toto = 196,
     TraditionalHan = 74,
     Ugaritic = 53,
     Unknown = 103,
     UnwrittenLanguages = 102,
     Vai = 99,
     VisibleSpeech = 100,
+    Vithkuqi = 197,
     Wancho = 188,
     WarangCiti = 146,
     WesternSyriac = 96,
@@ -253,3 +313,608 @@ pub enum Script {
     Yi = 41,
     ZanabazarSquare = 177,
 }
+
+/// Enumerated property East_Asian_Width.
+///
+/// See "Definition" in UAX #11 for the summary of each property value:
+/// https://www.unicode.org/reports/tr11/#Definitions .
+/// See UEastAsianWidth in ICU4C.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EastAsianWidth {
+    Neutral = 0,
+    Ambiguous = 1,
+    Halfwidth = 2,
+    Fullwidth = 3,
+    Narrow = 4,
+    Wide = 5,
+}
+
+/// Enumerated property Line_Break.
+///
+/// See "Line Breaking Properties" in UAX #14 for the summary of each property
+/// value: https://www.unicode.org/reports/tr14/#Properties .
+/// See ULineBreak in ICU4C.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum LineBreak {
+    Unknown = 0,
+    Ambiguous = 1,
+    Alphabetic = 2,
+    BreakBoth = 3,
+    BreakAfter = 4,
+    BreakBefore = 5,
+    MandatoryBreak = 6,
+    ContingentBreak = 7,
+    ClosePunctuation = 8,
+    CombiningMark = 9,
+    CarriageReturn = 10,
+    Exclamation = 11,
+    Glue = 12,
+    Hyphen = 13,
+    Ideographic = 14,
+    Inseparable = 15,
+    InfixNumeric = 16,
+    LineFeed = 17,
+    Nonstarter = 18,
+    Numeric = 19,
+    OpenPunctuation = 20,
+    PostfixNumeric = 21,
+    PrefixNumeric = 22,
+    Quotation = 23,
+    ComplexContext = 24,
+    Surrogate = 25,
+    Space = 26,
+    BreakSymbols = 27,
+    ZWSpace = 28,
+    NextLine = 29,
+    WordJoiner = 30,
+    H2 = 31,
+    H3 = 32,
+    JL = 33,
+    JT = 34,
+    JV = 35,
+    CloseParenthesis = 36,
+    ConditionalJapaneseStarter = 37,
+    HebrewLetter = 38,
+    RegionalIndicator = 39,
+    EBase = 40,
+    EModifier = 41,
+    ZWJ = 42,
+}
+
+/// Enumerated property Grapheme_Cluster_Break.
+///
+/// See "Default Grapheme Cluster Boundary Specification" in UAX #29 for the
+/// summary of each property value:
+/// https://www.unicode.org/reports/tr29/#Default_Grapheme_Cluster_Table .
+/// See UGraphemeClusterBreak in ICU4C.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum GraphemeClusterBreak {
+    Other = 0,
+    Control = 1,
+    CR = 2,
+    Extend = 3,
+    L = 4,
+    LF = 5,
+    LV = 6,
+    LVT = 7,
+    T = 8,
+    V = 9,
+    SpacingMark = 10,
+    Prepend = 11,
+    RegionalIndicator = 12,
+    EBase = 13,
+    EBaseGAZ = 14,
+    EModifier = 15,
+    GlueAfterZwj = 16,
+    ZWJ = 17,
+}
+
+/// Enumerated property Word_Break.
+///
+/// See "Default Word Boundary Specification" in UAX #29 for the summary of
+/// each property value: https://www.unicode.org/reports/tr29/#Default_Word_Boundaries .
+/// See UWordBreakValues in ICU4C.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum WordBreak {
+    Other = 0,
+    ALetter = 1,
+    Format = 2,
+    Katakana = 3,
+    MidLetter = 4,
+    MidNum = 5,
+    Numeric = 6,
+    ExtendNumLet = 7,
+    CR = 8,
+    Extend = 9,
+    LF = 10,
+    MidNumLet = 11,
+    Newline = 12,
+    RegionalIndicator = 13,
+    HebrewLetter = 14,
+    SingleQuote = 15,
+    DoubleQuote = 16,
+    EBase = 17,
+    EBaseGAZ = 18,
+    EModifier = 19,
+    GlueAfterZwj = 20,
+    ZWJ = 21,
+    WSegSpace = 22,
+}
+
+/// Enumerated property Sentence_Break.
+///
+/// See "Default Sentence Boundary Specification" in UAX #29 for the summary of
+/// each property value: https://www.unicode.org/reports/tr29/#Default_Sentence_Boundaries .
+/// See USentenceBreak in ICU4C.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SentenceBreak {
+    Other = 0,
+    ATerm = 1,
+    Close = 2,
+    Format = 3,
+    Lower = 4,
+    Numeric = 5,
+    OLetter = 6,
+    Sep = 7,
+    Sp = 8,
+    STerm = 9,
+    Upper = 10,
+    CR = 11,
+    Extend = 12,
+    LF = 13,
+    SContinue = 14,
+}
+
+// Conversions from the integer values of the properties, as stored in the
+// property maps and returned by u_getIntPropertyValue() in ICU4C.
+
+impl TryFrom<u32> for BidiClass {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::LeftToRight,
+            1 => Self::RightToLeft,
+            2 => Self::EuropeanNumber,
+            3 => Self::EuropeanSeparator,
+            4 => Self::EuropeanTerminator,
+            5 => Self::ArabicNumber,
+            6 => Self::CommonSeparator,
+            7 => Self::ParagraphSeparator,
+            8 => Self::SegmentSeparator,
+            9 => Self::WhiteSpace,
+            10 => Self::OtherNeutral,
+            11 => Self::LeftToRightEmbedding,
+            12 => Self::LeftToRightOverride,
+            13 => Self::ArabicLetter,
+            14 => Self::RightToLeftEmbedding,
+            15 => Self::RightToLeftOverride,
+            16 => Self::PopDirectionalFormat,
+            17 => Self::NonspacingMark,
+            18 => Self::BoundaryNeutral,
+            19 => Self::FirstStrongIsolate,
+            20 => Self::LeftToRightIsolate,
+            21 => Self::RightToLeftIsolate,
+            22 => Self::PopDirectionalIsolate,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<u32> for BidiPairedBracketType {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::None,
+            1 => Self::Open,
+            2 => Self::Close,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<u32> for EastAsianWidth {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Neutral,
+            1 => Self::Ambiguous,
+            2 => Self::Halfwidth,
+            3 => Self::Fullwidth,
+            4 => Self::Narrow,
+            5 => Self::Wide,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<u32> for GeneralCategory {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Unassigned,
+            1 => Self::UppercaseLetter,
+            2 => Self::LowercaseLetter,
+            3 => Self::TitlecaseLetter,
+            4 => Self::ModifierLetter,
+            5 => Self::OtherLetter,
+            6 => Self::NonspacingMark,
+            7 => Self::EnclosingMark,
+            8 => Self::SpacingMark,
+            9 => Self::Digit,
+            10 => Self::LetterNumber,
+            11 => Self::OtherNumber,
+            12 => Self::SpaceSeparator,
+            13 => Self::LineSeparator,
+            14 => Self::ParagraphSeparator,
+            15 => Self::Control,
+            16 => Self::Format,
+            17 => Self::PrivateUse,
+            18 => Self::Surrogate,
+            19 => Self::DashPunctuation,
+            20 => Self::OpenPunctuation,
+            21 => Self::ClosePunctuation,
+            22 => Self::ConnectorPunctuation,
+            23 => Self::OtherPunctuation,
+            24 => Self::MathSymbol,
+            25 => Self::CurrencySymbol,
+            26 => Self::ModifierSymbol,
+            27 => Self::OtherSymbol,
+            28 => Self::InitialPunctuation,
+            29 => Self::FinalPunctuation,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<u32> for GraphemeClusterBreak {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Other,
+            1 => Self::Control,
+            2 => Self::CR,
+            3 => Self::Extend,
+            4 => Self::L,
+            5 => Self::LF,
+            6 => Self::LV,
+            7 => Self::LVT,
+            8 => Self::T,
+            9 => Self::V,
+            10 => Self::SpacingMark,
+            11 => Self::Prepend,
+            12 => Self::RegionalIndicator,
+            13 => Self::EBase,
+            14 => Self::EBaseGAZ,
+            15 => Self::EModifier,
+            16 => Self::GlueAfterZwj,
+            17 => Self::ZWJ,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<u32> for LineBreak {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Unknown,
+            1 => Self::Ambiguous,
+            2 => Self::Alphabetic,
+            3 => Self::BreakBoth,
+            4 => Self::BreakAfter,
+            5 => Self::BreakBefore,
+            6 => Self::MandatoryBreak,
+            7 => Self::ContingentBreak,
+            8 => Self::ClosePunctuation,
+            9 => Self::CombiningMark,
+            10 => Self::CarriageReturn,
+            11 => Self::Exclamation,
+            12 => Self::Glue,
+            13 => Self::Hyphen,
+            14 => Self::Ideographic,
+            15 => Self::Inseparable,
+            16 => Self::InfixNumeric,
+            17 => Self::LineFeed,
+            18 => Self::Nonstarter,
+            19 => Self::Numeric,
+            20 => Self::OpenPunctuation,
+            21 => Self::PostfixNumeric,
+            22 => Self::PrefixNumeric,
+            23 => Self::Quotation,
+            24 => Self::ComplexContext,
+            25 => Self::Surrogate,
+            26 => Self::Space,
+            27 => Self::BreakSymbols,
+            28 => Self::ZWSpace,
+            29 => Self::NextLine,
+            30 => Self::WordJoiner,
+            31 => Self::H2,
+            32 => Self::H3,
+            33 => Self::JL,
+            34 => Self::JT,
+            35 => Self::JV,
+            36 => Self::CloseParenthesis,
+            37 => Self::ConditionalJapaneseStarter,
+            38 => Self::HebrewLetter,
+            39 => Self::RegionalIndicator,
+            40 => Self::EBase,
+            41 => Self::EModifier,
+            42 => Self::ZWJ,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<u32> for Script {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Common,
+            1 => Self::Inherited,
+            2 => Self::Arabic,
+            3 => Self::Armenian,
+            4 => Self::Bengali,
+            5 => Self::Bopomofo,
+            6 => Self::Cherokee,
+            7 => Self::Coptic,
+            8 => Self::Cyrillic,
+            9 => Self::Deseret,
+            10 => Self::Devanagari,
+            11 => Self::Ethiopic,
+            12 => Self::Georgian,
+            13 => Self::Gothic,
+            14 => Self::Greek,
+            15 => Self::Gujarati,
+            16 => Self::Gurmukhi,
+            17 => Self::Han,
+            18 => Self::Hangul,
+            19 => Self::Hebrew,
+            20 => Self::Hiragana,
+            21 => Self::Kannada,
+            22 => Self::Katakana,
+            23 => Self::Khmer,
+            24 => Self::Lao,
+            25 => Self::Latin,
+            26 => Self::Malayalam,
+            27 => Self::Mongolian,
+            28 => Self::Myanmar,
+            29 => Self::Ogham,
+            30 => Self::OldItalic,
+            31 => Self::Oriya,
+            32 => Self::Runic,
+            33 => Self::Sinhala,
+            34 => Self::Syriac,
+            35 => Self::Tamil,
+            36 => Self::Telugu,
+            37 => Self::Thaana,
+            38 => Self::Thai,
+            39 => Self::Tibetan,
+            40 => Self::CanadianAboriginal,
+            41 => Self::Yi,
+            42 => Self::Tagalog,
+            43 => Self::Hanunoo,
+            44 => Self::Buhid,
+            45 => Self::Tagbanwa,
+            46 => Self::Braille,
+            47 => Self::Cypriot,
+            48 => Self::Limbu,
+            49 => Self::LinearB,
+            50 => Self::Osmanya,
+            51 => Self::Shavian,
+            52 => Self::TaiLe,
+            53 => Self::Ugaritic,
+            54 => Self::KatakanaOrHiragana,
+            55 => Self::Buginese,
+            56 => Self::Glagolitic,
+            57 => Self::Kharoshthi,
+            58 => Self::SylotiNagri,
+            59 => Self::NewTaiLue,
+            60 => Self::Tifinagh,
+            61 => Self::OldPersian,
+            62 => Self::Balinese,
+            63 => Self::Batak,
+            64 => Self::Blissymbols,
+            65 => Self::Brahmi,
+            66 => Self::Cham,
+            67 => Self::Cirth,
+            68 => Self::OldChurchSlavonicCyrillic,
+            69 => Self::DemoticEgyptian,
+            70 => Self::HieraticEgyptian,
+            71 => Self::EgyptianHieroglyphs,
+            72 => Self::Khutsuri,
+            73 => Self::SimplifiedHan,
+            74 => Self::TraditionalHan,
+            75 => Self::PahawhHmong,
+            76 => Self::OldHungarian,
+            77 => Self::HarappanIndus,
+            78 => Self::Javanese,
+            79 => Self::KayahLi,
+            80 => Self::LatinFraktur,
+            81 => Self::LatinGaelic,
+            82 => Self::Lepcha,
+            83 => Self::LinearA,
+            84 => Self::Mandaic,
+            85 => Self::MayanHieroglyphs,
+            86 => Self::MeroiticHieroglyphs,
+            87 => Self::Nko,
+            88 => Self::Orkhon,
+            89 => Self::OldPermic,
+            90 => Self::PhagsPa,
+            91 => Self::Phoenician,
+            92 => Self::Miao,
+            93 => Self::Rongorongo,
+            94 => Self::Sarati,
+            95 => Self::EstrangeloSyriac,
+            96 => Self::WesternSyriac,
+            97 => Self::EasternSyriac,
+            98 => Self::Tengwar,
+            99 => Self::Vai,
+            100 => Self::VisibleSpeech,
+            101 => Self::Cuneiform,
+            102 => Self::UnwrittenLanguages,
+            103 => Self::Unknown,
+            104 => Self::Carian,
+            105 => Self::Japanese,
+            106 => Self::Lanna,
+            107 => Self::Lycian,
+            108 => Self::Lydian,
+            109 => Self::OlChiki,
+            110 => Self::Rejang,
+            111 => Self::Saurashtra,
+            112 => Self::SignWriting,
+            113 => Self::Sundanese,
+            114 => Self::Moon,
+            115 => Self::MeiteiMayek,
+            116 => Self::ImperialAramaic,
+            117 => Self::Avestan,
+            118 => Self::Chakma,
+            119 => Self::Korean,
+            120 => Self::Kaithi,
+            121 => Self::Manichaean,
+            122 => Self::InscriptionalPahlavi,
+            123 => Self::PsalterPahlavi,
+            124 => Self::BookPahlavi,
+            125 => Self::InscriptionalParthian,
+            126 => Self::Samaritan,
+            127 => Self::TaiViet,
+            128 => Self::MathematicalNotation,
+            129 => Self::Symbols,
+            130 => Self::Bamum,
+            131 => Self::Lisu,
+            132 => Self::NakhiGeba,
+            133 => Self::OldSouthArabian,
+            134 => Self::BassaVah,
+            135 => Self::Duployan,
+            136 => Self::Elbasan,
+            137 => Self::Grantha,
+            138 => Self::Kpelle,
+            139 => Self::Loma,
+            140 => Self::Mende,
+            141 => Self::MeroiticCursive,
+            142 => Self::OldNorthArabian,
+            143 => Self::Nabataean,
+            144 => Self::Palmyrene,
+            145 => Self::Khudawadi,
+            146 => Self::WarangCiti,
+            147 => Self::Afaka,
+            148 => Self::Jurchen,
+            149 => Self::Mro,
+            150 => Self::Nushu,
+            151 => Self::Sharada,
+            152 => Self::SoraSompeng,
+            153 => Self::Takri,
+            154 => Self::Tangut,
+            155 => Self::Woleai,
+            156 => Self::AnatolianHieroglyphs,
+            157 => Self::Khojki,
+            158 => Self::Tirhuta,
+            159 => Self::CaucasianAlbanian,
+            160 => Self::Mahajani,
+            161 => Self::Ahom,
+            162 => Self::Hatran,
+            163 => Self::Modi,
+            164 => Self::Multani,
+            165 => Self::PauCinHau,
+            166 => Self::Siddham,
+            167 => Self::Adlam,
+            168 => Self::Bhaiksuki,
+            169 => Self::Marchen,
+            170 => Self::Newa,
+            171 => Self::Osage,
+            172 => Self::HanWithBopomofo,
+            173 => Self::Jamo,
+            174 => Self::SymbolsEmoji,
+            175 => Self::MasaramGondi,
+            176 => Self::Soyombo,
+            177 => Self::ZanabazarSquare,
+            178 => Self::Dogra,
+            179 => Self::GunjalaGondi,
+            180 => Self::Makasar,
+            181 => Self::Medefaidrin,
+            182 => Self::HanifiRohingya,
+            183 => Self::Sogdian,
+            184 => Self::OldSogdian,
+            185 => Self::Elymaic,
+            186 => Self::NyiakengPuachueHmong,
+            187 => Self::Nandinagari,
+            188 => Self::Wancho,
+            189 => Self::Chorasmian,
+            190 => Self::DivesAkuru,
+            191 => Self::KhitanSmallScript,
+            192 => Self::Yezidi,
+            193 => Self::CyproMinoan,
+            194 => Self::OldUyghur,
+            195 => Self::Tangsa,
+            196 => Self::Toto,
+            197 => Self::Vithkuqi,
+            198 => Self::Kawi,
+            199 => Self::NagMundari,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<u32> for SentenceBreak {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Other,
+            1 => Self::ATerm,
+            2 => Self::Close,
+            3 => Self::Format,
+            4 => Self::Lower,
+            5 => Self::Numeric,
+            6 => Self::OLetter,
+            7 => Self::Sep,
+            8 => Self::Sp,
+            9 => Self::STerm,
+            10 => Self::Upper,
+            11 => Self::CR,
+            12 => Self::Extend,
+            13 => Self::LF,
+            14 => Self::SContinue,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<u32> for WordBreak {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Other,
+            1 => Self::ALetter,
+            2 => Self::Format,
+            3 => Self::Katakana,
+            4 => Self::MidLetter,
+            5 => Self::MidNum,
+            6 => Self::Numeric,
+            7 => Self::ExtendNumLet,
+            8 => Self::CR,
+            9 => Self::Extend,
+            10 => Self::LF,
+            11 => Self::MidNumLet,
+            12 => Self::Newline,
+            13 => Self::RegionalIndicator,
+            14 => Self::HebrewLetter,
+            15 => Self::SingleQuote,
+            16 => Self::DoubleQuote,
+            17 => Self::EBase,
+            18 => Self::EBaseGAZ,
+            19 => Self::EModifier,
+            20 => Self::GlueAfterZwj,
+            21 => Self::ZWJ,
+            22 => Self::WSegSpace,
+            _ => return Err(()),
+        })
+    }
+}
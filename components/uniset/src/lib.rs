@@ -57,9 +57,14 @@ extern crate alloc;
 mod builder;
 mod conversions;
 pub mod enum_props;
+pub mod maps;
+mod names;
+pub mod pattern;
 pub mod props;
 pub mod provider;
+pub mod script;
 mod uniset;
+mod uniset_with_strings;
 mod utils;
 
 use alloc::vec::Vec;
@@ -68,6 +73,7 @@ pub use conversions::*;
 use displaydoc::Display;
 use icu_provider::DataError;
 pub use uniset::UnicodeSet;
+pub use uniset_with_strings::{UnicodeSetWithStrings, UnicodeSetWithStringsBuilder};
 pub use utils::*;
 
 /// Custom Errors for [`UnicodeSet`].
@@ -79,6 +85,10 @@ pub enum UnicodeSetError {
     InvalidRange(u32, u32),
     #[displaydoc("{0}")]
     PropDataLoad(DataError),
+    #[displaydoc("Invalid pattern at byte {0}")]
+    InvalidPattern(usize),
+    #[displaydoc("Invalid string list: strings must be sorted, unique, and not single code points")]
+    InvalidStringList,
 }
 
 #[cfg(feature = "std")]
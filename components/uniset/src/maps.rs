@@ -0,0 +1,240 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Lookup of the value of an enumerated property for a code point.
+//!
+//! The functions in [`props`](crate::props) return one [`UnicodeSet`](crate::UnicodeSet)
+//! per property value, which is the right shape for testing membership in a
+//! single value. To find _the_ value of a property for a character, load the
+//! property as a [`CodePointMap`] instead, backed by a `CodePointTrie`.
+//!
+//! There are no getters on the property values themselves, like a
+//! `GeneralCategory::for_char(c)`, as this crate has no built-in data: the
+//! trie is loaded from a data provider, so load the map once and call
+//! [`CodePointMap::get`] for each character.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_uniset::enum_props::{GeneralCategory, Script};
+//! use icu_uniset::maps;
+//!
+//! let provider = icu_provider_uprops::EnumeratedPropertiesDataProvider::new(
+//!     icu_testdata::paths::data_root().join("uprops"),
+//! );
+//!
+//! let gc = maps::get_general_category(&provider).expect("The data should be valid");
+//! assert_eq!(gc.get('木'), GeneralCategory::OtherLetter);
+//! assert_eq!(gc.get('$'), GeneralCategory::CurrencySymbol);
+//!
+//! let sc = maps::get_script(&provider).expect("The data should be valid");
+//! assert_eq!(sc.get('木'), Script::Han);
+//! assert_eq!(sc.get('🎃'), Script::Common);
+//! ```
+
+use crate::enum_props::*;
+use crate::provider::*;
+use crate::{UnicodeSet, UnicodeSetError};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use icu_codepointtrie::codepointtrie::{CodePointTrie, Small, ValueWidth};
+use icu_provider::prelude::*;
+
+/// A value of an enumerated property, as returned by a [`CodePointMap`].
+pub trait EnumeratedPropertyValue: Copy + PartialEq + TryFrom<u32> {
+    /// The value returned for a code point whose value in the data is not a
+    /// known value of the property. This is the value of the property for
+    /// unassigned code points.
+    const FALLBACK: Self;
+}
+
+impl EnumeratedPropertyValue for BidiClass {
+    const FALLBACK: Self = BidiClass::LeftToRight;
+}
+
+impl EnumeratedPropertyValue for EastAsianWidth {
+    const FALLBACK: Self = EastAsianWidth::Neutral;
+}
+
+impl EnumeratedPropertyValue for GeneralCategory {
+    const FALLBACK: Self = GeneralCategory::Unassigned;
+}
+
+impl EnumeratedPropertyValue for GraphemeClusterBreak {
+    const FALLBACK: Self = GraphemeClusterBreak::Other;
+}
+
+impl EnumeratedPropertyValue for LineBreak {
+    const FALLBACK: Self = LineBreak::Unknown;
+}
+
+impl EnumeratedPropertyValue for Script {
+    const FALLBACK: Self = Script::Unknown;
+}
+
+impl EnumeratedPropertyValue for SentenceBreak {
+    const FALLBACK: Self = SentenceBreak::Other;
+}
+
+impl EnumeratedPropertyValue for WordBreak {
+    const FALLBACK: Self = WordBreak::Other;
+}
+
+/// The values of an enumerated property for all code points, loaded from a
+/// [`UnicodePropertyMapV1`].
+pub struct CodePointMap<'data, T: EnumeratedPropertyValue> {
+    data: DataPayload<'data, UnicodePropertyMapV1Marker>,
+    _value: PhantomData<T>,
+}
+
+impl<'data, T: EnumeratedPropertyValue> CodePointMap<'data, T> {
+    /// Returns the value of the property for the character `c`.
+    pub fn get(&self, c: char) -> T {
+        self.get_u32(c as u32)
+    }
+
+    /// Returns the value of the property for the code point `cp`. Values
+    /// outside of the code point range, like surrogates in data from invalid
+    /// UTF-16, are looked up like in ICU4C.
+    pub fn get_u32(&self, cp: u32) -> T {
+        let value = self.data.get().code_point_trie.get(cp);
+        T::try_from(value).unwrap_or(T::FALLBACK)
+    }
+
+    /// Returns the set of code points whose value of the property is `value`.
+    pub fn get_set_for_value(&self, value: T) -> UnicodeSet<'static> {
+        get_set_where(&self.data.get().code_point_trie, |v| T::try_from(v).unwrap_or(T::FALLBACK) == value)
+    }
+}
+
+/// Returns the set of code points whose value in `trie` satisfies `predicate`.
+fn get_set_where(trie: &UnicodePropertyMapTrie, predicate: impl Fn(u32) -> bool) -> UnicodeSet<'static> {
+    match trie {
+        UnicodePropertyMapTrie::Bits8(trie) => get_set_where_in(trie, predicate),
+        UnicodePropertyMapTrie::Bits16(trie) => get_set_where_in(trie, predicate),
+    }
+}
+
+/// Returns the set of code points whose value in `trie` satisfies `predicate`,
+/// testing the value of each range of code points with the same value once.
+fn get_set_where_in<W: ValueWidth>(trie: &CodePointTrie<W, Small>, predicate: impl Fn(u32) -> bool) -> UnicodeSet<'static> {
+    let mut inv_list = Vec::new();
+    for range in trie.iter_ranges() {
+        if predicate(range.value.cast_to_widest()) {
+            // Adjacent ranges with different values that both satisfy the
+            // predicate are a single range of the set.
+            if inv_list.last() == Some(&range.start) {
+                inv_list.pop();
+            } else {
+                inv_list.push(range.start);
+            }
+            inv_list.push(range.end + 1);
+        }
+    }
+    UnicodeSet::from_inversion_list(inv_list).unwrap()
+}
+
+/// Loads the map with the key `resc_key`, and returns the set of code points
+/// whose value in the map satisfies `predicate`.
+pub(crate) fn get_set_for_raw_values<'data, D>(provider: &D, resc_key: ResourceKey, predicate: impl Fn(u32) -> bool) -> Result<UnicodeSet<'static>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let data = load_map(provider, resc_key)?;
+    Ok(get_set_where(&data.get().code_point_trie, predicate))
+}
+
+type CodePointMapResult<'data, T> = Result<CodePointMap<'data, T>, UnicodeSetError>;
+
+// helper fn
+fn load_map<'data, D>(provider: &D, resc_key: ResourceKey) -> Result<DataPayload<'data, UnicodePropertyMapV1Marker>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: resc_key,
+            options: ResourceOptions { variant: None, langid: None },
+        },
+    };
+    let resp: DataResponse<UnicodePropertyMapV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
+
+// helper fn
+fn get_map<'data, T, D>(provider: &D, resc_key: ResourceKey) -> CodePointMapResult<'data, T>
+where
+    T: EnumeratedPropertyValue,
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    Ok(CodePointMap {
+        data: load_map(provider, resc_key)?,
+        _value: PhantomData,
+    })
+}
+
+/// Returns a [`CodePointMap`] for the Bidi_Class property.
+pub fn get_bidi_class<'data, D>(provider: &D) -> CodePointMapResult<'data, BidiClass>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    get_map(provider, key::BIDI_CLASS_V1)
+}
+
+/// Returns a [`CodePointMap`] for the East_Asian_Width property.
+pub fn get_east_asian_width<'data, D>(provider: &D) -> CodePointMapResult<'data, EastAsianWidth>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    get_map(provider, key::EAST_ASIAN_WIDTH_V1)
+}
+
+/// Returns a [`CodePointMap`] for the General_Category property.
+pub fn get_general_category<'data, D>(provider: &D) -> CodePointMapResult<'data, GeneralCategory>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    get_map(provider, key::GENERAL_CATEGORY_V1)
+}
+
+/// Returns a [`CodePointMap`] for the Grapheme_Cluster_Break property.
+pub fn get_grapheme_cluster_break<'data, D>(provider: &D) -> CodePointMapResult<'data, GraphemeClusterBreak>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    get_map(provider, key::GRAPHEME_CLUSTER_BREAK_V1)
+}
+
+/// Returns a [`CodePointMap`] for the Line_Break property.
+pub fn get_line_break<'data, D>(provider: &D) -> CodePointMapResult<'data, LineBreak>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    get_map(provider, key::LINE_BREAK_V1)
+}
+
+/// Returns a [`CodePointMap`] for the Script property.
+pub fn get_script<'data, D>(provider: &D) -> CodePointMapResult<'data, Script>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    get_map(provider, key::SCRIPT_V1)
+}
+
+/// Returns a [`CodePointMap`] for the Sentence_Break property.
+pub fn get_sentence_break<'data, D>(provider: &D) -> CodePointMapResult<'data, SentenceBreak>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    get_map(provider, key::SENTENCE_BREAK_V1)
+}
+
+/// Returns a [`CodePointMap`] for the Word_Break property.
+pub fn get_word_break<'data, D>(provider: &D) -> CodePointMapResult<'data, WordBreak>
+where
+    D: DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    get_map(provider, key::WORD_BREAK_V1)
+}
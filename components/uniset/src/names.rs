@@ -0,0 +1,491 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The names and aliases of properties and property values, generated from the
+// property aliases in ICU4C 73.1 (Unicode 15.0).
+
+use crate::provider::key;
+use icu_provider::ResourceKey;
+
+/// A table of the names of property values, and the integer values used by ICU4C.
+pub(crate) type ValueNames = &'static [(&'static [&'static str], u32)];
+
+/// The names of the binary properties, and the resource keys of their sets.
+pub(crate) const BINARY_PROPERTIES: &[(&[&str], ResourceKey)] = &[
+    (&["AHex", "ASCII_Hex_Digit"], key::ASCII_HEX_DIGIT_V1),
+    (&["alnum"], key::ALNUM_V1),
+    (&["Alpha", "Alphabetic"], key::ALPHABETIC_V1),
+    (&["Bidi_C", "Bidi_Control"], key::BIDI_CONTROL_V1),
+    (&["Bidi_M", "Bidi_Mirrored"], key::BIDI_MIRRORED_V1),
+    (&["blank"], key::BLANK_V1),
+    (&["Cased"], key::CASED_V1),
+    (&["CI", "Case_Ignorable"], key::CASE_IGNORABLE_V1),
+    (&["Comp_Ex", "Full_Composition_Exclusion"], key::FULL_COMPOSITION_EXCLUSION_V1),
+    (&["CWCF", "Changes_When_Casefolded"], key::CHANGES_WHEN_CASEFOLDED_V1),
+    (&["CWCM", "Changes_When_Casemapped"], key::CHANGES_WHEN_CASEMAPPED_V1),
+    (&["CWKCF", "Changes_When_NFKC_Casefolded"], key::CHANGES_WHEN_NFKC_CASEFOLDED_V1),
+    (&["CWL", "Changes_When_Lowercased"], key::CHANGES_WHEN_LOWERCASED_V1),
+    (&["CWT", "Changes_When_Titlecased"], key::CHANGES_WHEN_TITLECASED_V1),
+    (&["CWU", "Changes_When_Uppercased"], key::CHANGES_WHEN_UPPERCASED_V1),
+    (&["Dash"], key::DASH_V1),
+    (&["Dep", "Deprecated"], key::DEPRECATED_V1),
+    (&["DI", "Default_Ignorable_Code_Point"], key::DEFAULT_IGNORABLE_CODE_POINT_V1),
+    (&["Dia", "Diacritic"], key::DIACRITIC_V1),
+    (&["EBase", "Emoji_Modifier_Base"], key::EMOJI_MODIFIER_BASE_V1),
+    (&["EComp", "Emoji_Component"], key::EMOJI_COMPONENT_V1),
+    (&["EMod", "Emoji_Modifier"], key::EMOJI_MODIFIER_V1),
+    (&["Emoji"], key::EMOJI_V1),
+    (&["EPres", "Emoji_Presentation"], key::EMOJI_PRESENTATION_V1),
+    (&["Ext", "Extender"], key::EXTENDER_V1),
+    (&["ExtPict", "Extended_Pictographic"], key::EXTENDED_PICTOGRAPHIC_V1),
+    (&["graph"], key::GRAPH_V1),
+    (&["Gr_Base", "Grapheme_Base"], key::GRAPHEME_BASE_V1),
+    (&["Gr_Ext", "Grapheme_Extend"], key::GRAPHEME_EXTEND_V1),
+    (&["Gr_Link", "Grapheme_Link"], key::GRAPHEME_LINK_V1),
+    (&["Hex", "Hex_Digit"], key::HEX_DIGIT_V1),
+    (&["Hyphen"], key::HYPHEN_V1),
+    (&["IDC", "ID_Continue"], key::ID_CONTINUE_V1),
+    (&["Ideo", "Ideographic"], key::IDEOGRAPHIC_V1),
+    (&["IDS", "ID_Start"], key::ID_START_V1),
+    (&["IDSB", "IDS_Binary_Operator"], key::IDS_BINARY_OPERATOR_V1),
+    (&["IDST", "IDS_Trinary_Operator"], key::IDS_TRINARY_OPERATOR_V1),
+    (&["Join_C", "Join_Control"], key::JOIN_CONTROL_V1),
+    (&["LOE", "Logical_Order_Exception"], key::LOGICAL_ORDER_EXCEPTION_V1),
+    (&["Lower", "Lowercase"], key::LOWERCASE_V1),
+    (&["Math"], key::MATH_V1),
+    (&["NChar", "Noncharacter_Code_Point"], key::NONCHARACTER_CODE_POINT_V1),
+    (&["nfcinert", "NFC_Inert"], key::NFC_INERT_V1),
+    (&["nfdinert", "NFD_Inert"], key::NFD_INERT_V1),
+    (&["nfkcinert", "NFKC_Inert"], key::NFKC_INERT_V1),
+    (&["nfkdinert", "NFKD_Inert"], key::NFKD_INERT_V1),
+    (&["Pat_Syn", "Pattern_Syntax"], key::PATTERN_SYNTAX_V1),
+    (&["Pat_WS", "Pattern_White_Space"], key::PATTERN_WHITE_SPACE_V1),
+    (&["PCM", "Prepended_Concatenation_Mark"], key::PREPENDED_CONCATENATION_MARK_V1),
+    (&["print"], key::PRINT_V1),
+    (&["QMark", "Quotation_Mark"], key::QUOTATION_MARK_V1),
+    (&["Radical"], key::RADICAL_V1),
+    (&["RI", "Regional_Indicator"], key::REGIONAL_INDICATOR_V1),
+    (&["SD", "Soft_Dotted"], key::SOFT_DOTTED_V1),
+    (&["segstart", "Segment_Starter"], key::SEGMENT_STARTER_V1),
+    (&["Sensitive", "Case_Sensitive"], key::CASE_SENSITIVE_V1),
+    (&["STerm", "Sentence_Terminal"], key::SENTENCE_TERMINAL_V1),
+    (&["Term", "Terminal_Punctuation"], key::TERMINAL_PUNCTUATION_V1),
+    (&["UIdeo", "Unified_Ideograph"], key::UNIFIED_IDEOGRAPH_V1),
+    (&["Upper", "Uppercase"], key::UPPERCASE_V1),
+    (&["VS", "Variation_Selector"], key::VARIATION_SELECTOR_V1),
+    (&["WSpace", "White_Space", "space"], key::WHITE_SPACE_V1),
+    (&["xdigit"], key::XDIGIT_V1),
+    (&["XIDC", "XID_Continue"], key::XID_CONTINUE_V1),
+    (&["XIDS", "XID_Start"], key::XID_START_V1),
+];
+
+/// The names of the Bidi_Class values.
+pub(crate) const BIDI_CLASS_VALUES: ValueNames = &[
+    (&["L", "Left_To_Right"], 0),
+    (&["R", "Right_To_Left"], 1),
+    (&["EN", "European_Number"], 2),
+    (&["ES", "European_Separator"], 3),
+    (&["ET", "European_Terminator"], 4),
+    (&["AN", "Arabic_Number"], 5),
+    (&["CS", "Common_Separator"], 6),
+    (&["B", "Paragraph_Separator"], 7),
+    (&["S", "Segment_Separator"], 8),
+    (&["WS", "White_Space"], 9),
+    (&["ON", "Other_Neutral"], 10),
+    (&["LRE", "Left_To_Right_Embedding"], 11),
+    (&["LRO", "Left_To_Right_Override"], 12),
+    (&["AL", "Arabic_Letter"], 13),
+    (&["RLE", "Right_To_Left_Embedding"], 14),
+    (&["RLO", "Right_To_Left_Override"], 15),
+    (&["PDF", "Pop_Directional_Format"], 16),
+    (&["NSM", "Nonspacing_Mark"], 17),
+    (&["BN", "Boundary_Neutral"], 18),
+    (&["FSI", "First_Strong_Isolate"], 19),
+    (&["LRI", "Left_To_Right_Isolate"], 20),
+    (&["RLI", "Right_To_Left_Isolate"], 21),
+    (&["PDI", "Pop_Directional_Isolate"], 22),
+];
+
+/// The names of the East_Asian_Width values.
+pub(crate) const EAST_ASIAN_WIDTH_VALUES: ValueNames = &[
+    (&["N", "Neutral"], 0),
+    (&["A", "Ambiguous"], 1),
+    (&["H", "Halfwidth"], 2),
+    (&["F", "Fullwidth"], 3),
+    (&["Na", "Narrow"], 4),
+    (&["W", "Wide"], 5),
+];
+
+/// The names of the Grapheme_Cluster_Break values.
+pub(crate) const GRAPHEME_CLUSTER_BREAK_VALUES: ValueNames = &[
+    (&["XX", "Other"], 0),
+    (&["CN", "Control"], 1),
+    (&["CR", "CR"], 2),
+    (&["EX", "Extend"], 3),
+    (&["L", "L"], 4),
+    (&["LF", "LF"], 5),
+    (&["LV", "LV"], 6),
+    (&["LVT", "LVT"], 7),
+    (&["T", "T"], 8),
+    (&["V", "V"], 9),
+    (&["SM", "SpacingMark"], 10),
+    (&["PP", "Prepend"], 11),
+    (&["RI", "Regional_Indicator"], 12),
+    (&["EB", "E_Base"], 13),
+    (&["EBG", "E_Base_GAZ"], 14),
+    (&["EM", "E_Modifier"], 15),
+    (&["GAZ", "Glue_After_Zwj"], 16),
+    (&["ZWJ", "ZWJ"], 17),
+];
+
+/// The names of the Line_Break values.
+pub(crate) const LINE_BREAK_VALUES: ValueNames = &[
+    (&["XX", "Unknown"], 0),
+    (&["AI", "Ambiguous"], 1),
+    (&["AL", "Alphabetic"], 2),
+    (&["B2", "Break_Both"], 3),
+    (&["BA", "Break_After"], 4),
+    (&["BB", "Break_Before"], 5),
+    (&["BK", "Mandatory_Break"], 6),
+    (&["CB", "Contingent_Break"], 7),
+    (&["CL", "Close_Punctuation"], 8),
+    (&["CM", "Combining_Mark"], 9),
+    (&["CR", "Carriage_Return"], 10),
+    (&["EX", "Exclamation"], 11),
+    (&["GL", "Glue"], 12),
+    (&["HY", "Hyphen"], 13),
+    (&["ID", "Ideographic"], 14),
+    (&["IN", "Inseparable", "Inseperable"], 15),
+    (&["IS", "Infix_Numeric"], 16),
+    (&["LF", "Line_Feed"], 17),
+    (&["NS", "Nonstarter"], 18),
+    (&["NU", "Numeric"], 19),
+    (&["OP", "Open_Punctuation"], 20),
+    (&["PO", "Postfix_Numeric"], 21),
+    (&["PR", "Prefix_Numeric"], 22),
+    (&["QU", "Quotation"], 23),
+    (&["SA", "Complex_Context"], 24),
+    (&["SG", "Surrogate"], 25),
+    (&["SP", "Space"], 26),
+    (&["SY", "Break_Symbols"], 27),
+    (&["ZW", "ZWSpace"], 28),
+    (&["NL", "Next_Line"], 29),
+    (&["WJ", "Word_Joiner"], 30),
+    (&["H2", "H2"], 31),
+    (&["H3", "H3"], 32),
+    (&["JL", "JL"], 33),
+    (&["JT", "JT"], 34),
+    (&["JV", "JV"], 35),
+    (&["CP", "Close_Parenthesis"], 36),
+    (&["CJ", "Conditional_Japanese_Starter"], 37),
+    (&["HL", "Hebrew_Letter"], 38),
+    (&["RI", "Regional_Indicator"], 39),
+    (&["EB", "E_Base"], 40),
+    (&["EM", "E_Modifier"], 41),
+    (&["ZWJ", "ZWJ"], 42),
+];
+
+/// The names of the Script values.
+pub(crate) const SCRIPT_VALUES: ValueNames = &[
+    (&["Zyyy", "Common"], 0),
+    (&["Zinh", "Inherited", "Qaai"], 1),
+    (&["Arab", "Arabic"], 2),
+    (&["Armn", "Armenian"], 3),
+    (&["Beng", "Bengali"], 4),
+    (&["Bopo", "Bopomofo"], 5),
+    (&["Cher", "Cherokee"], 6),
+    (&["Copt", "Coptic", "Qaac"], 7),
+    (&["Cyrl", "Cyrillic"], 8),
+    (&["Dsrt", "Deseret"], 9),
+    (&["Deva", "Devanagari"], 10),
+    (&["Ethi", "Ethiopic"], 11),
+    (&["Geor", "Georgian"], 12),
+    (&["Goth", "Gothic"], 13),
+    (&["Grek", "Greek"], 14),
+    (&["Gujr", "Gujarati"], 15),
+    (&["Guru", "Gurmukhi"], 16),
+    (&["Hani", "Han"], 17),
+    (&["Hang", "Hangul"], 18),
+    (&["Hebr", "Hebrew"], 19),
+    (&["Hira", "Hiragana"], 20),
+    (&["Knda", "Kannada"], 21),
+    (&["Kana", "Katakana"], 22),
+    (&["Khmr", "Khmer"], 23),
+    (&["Laoo", "Lao"], 24),
+    (&["Latn", "Latin"], 25),
+    (&["Mlym", "Malayalam"], 26),
+    (&["Mong", "Mongolian"], 27),
+    (&["Mymr", "Myanmar"], 28),
+    (&["Ogam", "Ogham"], 29),
+    (&["Ital", "Old_Italic"], 30),
+    (&["Orya", "Oriya"], 31),
+    (&["Runr", "Runic"], 32),
+    (&["Sinh", "Sinhala"], 33),
+    (&["Syrc", "Syriac"], 34),
+    (&["Taml", "Tamil"], 35),
+    (&["Telu", "Telugu"], 36),
+    (&["Thaa", "Thaana"], 37),
+    (&["Thai", "Thai"], 38),
+    (&["Tibt", "Tibetan"], 39),
+    (&["Cans", "Canadian_Aboriginal"], 40),
+    (&["Yiii", "Yi"], 41),
+    (&["Tglg", "Tagalog"], 42),
+    (&["Hano", "Hanunoo"], 43),
+    (&["Buhd", "Buhid"], 44),
+    (&["Tagb", "Tagbanwa"], 45),
+    (&["Brai", "Braille"], 46),
+    (&["Cprt", "Cypriot"], 47),
+    (&["Limb", "Limbu"], 48),
+    (&["Linb", "Linear_B"], 49),
+    (&["Osma", "Osmanya"], 50),
+    (&["Shaw", "Shavian"], 51),
+    (&["Tale", "Tai_Le"], 52),
+    (&["Ugar", "Ugaritic"], 53),
+    (&["Hrkt", "Katakana_Or_Hiragana"], 54),
+    (&["Bugi", "Buginese"], 55),
+    (&["Glag", "Glagolitic"], 56),
+    (&["Khar", "Kharoshthi"], 57),
+    (&["Sylo", "Syloti_Nagri"], 58),
+    (&["Talu", "New_Tai_Lue"], 59),
+    (&["Tfng", "Tifinagh"], 60),
+    (&["Xpeo", "Old_Persian"], 61),
+    (&["Bali", "Balinese"], 62),
+    (&["Batk", "Batak"], 63),
+    (&["Blis", "Blis"], 64),
+    (&["Brah", "Brahmi"], 65),
+    (&["Cham", "Cham"], 66),
+    (&["Cirt", "Cirt"], 67),
+    (&["Cyrs", "Cyrs"], 68),
+    (&["Egyd", "Egyd"], 69),
+    (&["Egyh", "Egyh"], 70),
+    (&["Egyp", "Egyptian_Hieroglyphs"], 71),
+    (&["Geok", "Geok"], 72),
+    (&["Hans", "Hans"], 73),
+    (&["Hant", "Hant"], 74),
+    (&["Hmng", "Pahawh_Hmong"], 75),
+    (&["Hung", "Old_Hungarian"], 76),
+    (&["Inds", "Inds"], 77),
+    (&["Java", "Javanese"], 78),
+    (&["Kali", "Kayah_Li"], 79),
+    (&["Latf", "Latf"], 80),
+    (&["Latg", "Latg"], 81),
+    (&["Lepc", "Lepcha"], 82),
+    (&["Lina", "Linear_A"], 83),
+    (&["Mand", "Mandaic"], 84),
+    (&["Maya", "Maya"], 85),
+    (&["Mero", "Meroitic_Hieroglyphs"], 86),
+    (&["Nkoo", "Nko"], 87),
+    (&["Orkh", "Old_Turkic"], 88),
+    (&["Perm", "Old_Permic"], 89),
+    (&["Phag", "Phags_Pa"], 90),
+    (&["Phnx", "Phoenician"], 91),
+    (&["Plrd", "Miao"], 92),
+    (&["Roro", "Roro"], 93),
+    (&["Sara", "Sara"], 94),
+    (&["Syre", "Syre"], 95),
+    (&["Syrj", "Syrj"], 96),
+    (&["Syrn", "Syrn"], 97),
+    (&["Teng", "Teng"], 98),
+    (&["Vaii", "Vai"], 99),
+    (&["Visp", "Visp"], 100),
+    (&["Xsux", "Cuneiform"], 101),
+    (&["Zxxx", "Zxxx"], 102),
+    (&["Zzzz", "Unknown"], 103),
+    (&["Cari", "Carian"], 104),
+    (&["Jpan", "Jpan"], 105),
+    (&["Lana", "Tai_Tham"], 106),
+    (&["Lyci", "Lycian"], 107),
+    (&["Lydi", "Lydian"], 108),
+    (&["Olck", "Ol_Chiki"], 109),
+    (&["Rjng", "Rejang"], 110),
+    (&["Saur", "Saurashtra"], 111),
+    (&["Sgnw", "SignWriting"], 112),
+    (&["Sund", "Sundanese"], 113),
+    (&["Moon", "Moon"], 114),
+    (&["Mtei", "Meetei_Mayek"], 115),
+    (&["Armi", "Imperial_Aramaic"], 116),
+    (&["Avst", "Avestan"], 117),
+    (&["Cakm", "Chakma"], 118),
+    (&["Kore", "Kore"], 119),
+    (&["Kthi", "Kaithi"], 120),
+    (&["Mani", "Manichaean"], 121),
+    (&["Phli", "Inscriptional_Pahlavi"], 122),
+    (&["Phlp", "Psalter_Pahlavi"], 123),
+    (&["Phlv", "Phlv"], 124),
+    (&["Prti", "Inscriptional_Parthian"], 125),
+    (&["Samr", "Samaritan"], 126),
+    (&["Tavt", "Tai_Viet"], 127),
+    (&["Zmth", "Zmth"], 128),
+    (&["Zsym", "Zsym"], 129),
+    (&["Bamu", "Bamum"], 130),
+    (&["Lisu", "Lisu"], 131),
+    (&["Nkgb", "Nkgb"], 132),
+    (&["Sarb", "Old_South_Arabian"], 133),
+    (&["Bass", "Bassa_Vah"], 134),
+    (&["Dupl", "Duployan"], 135),
+    (&["Elba", "Elbasan"], 136),
+    (&["Gran", "Grantha"], 137),
+    (&["Kpel", "Kpel"], 138),
+    (&["Loma", "Loma"], 139),
+    (&["Mend", "Mende_Kikakui"], 140),
+    (&["Merc", "Meroitic_Cursive"], 141),
+    (&["Narb", "Old_North_Arabian"], 142),
+    (&["Nbat", "Nabataean"], 143),
+    (&["Palm", "Palmyrene"], 144),
+    (&["Sind", "Khudawadi"], 145),
+    (&["Wara", "Warang_Citi"], 146),
+    (&["Afak", "Afak"], 147),
+    (&["Jurc", "Jurc"], 148),
+    (&["Mroo", "Mro"], 149),
+    (&["Nshu", "Nushu"], 150),
+    (&["Shrd", "Sharada"], 151),
+    (&["Sora", "Sora_Sompeng"], 152),
+    (&["Takr", "Takri"], 153),
+    (&["Tang", "Tangut"], 154),
+    (&["Wole", "Wole"], 155),
+    (&["Hluw", "Anatolian_Hieroglyphs"], 156),
+    (&["Khoj", "Khojki"], 157),
+    (&["Tirh", "Tirhuta"], 158),
+    (&["Aghb", "Caucasian_Albanian"], 159),
+    (&["Mahj", "Mahajani"], 160),
+    (&["Ahom", "Ahom"], 161),
+    (&["Hatr", "Hatran"], 162),
+    (&["Modi", "Modi"], 163),
+    (&["Mult", "Multani"], 164),
+    (&["Pauc", "Pau_Cin_Hau"], 165),
+    (&["Sidd", "Siddham"], 166),
+    (&["Adlm", "Adlam"], 167),
+    (&["Bhks", "Bhaiksuki"], 168),
+    (&["Marc", "Marchen"], 169),
+    (&["Newa", "Newa"], 170),
+    (&["Osge", "Osage"], 171),
+    (&["Hanb", "Hanb"], 172),
+    (&["Jamo", "Jamo"], 173),
+    (&["Zsye", "Zsye"], 174),
+    (&["Gonm", "Masaram_Gondi"], 175),
+    (&["Soyo", "Soyombo"], 176),
+    (&["Zanb", "Zanabazar_Square"], 177),
+    (&["Dogr", "Dogra"], 178),
+    (&["Gong", "Gunjala_Gondi"], 179),
+    (&["Maka", "Makasar"], 180),
+    (&["Medf", "Medefaidrin"], 181),
+    (&["Rohg", "Hanifi_Rohingya"], 182),
+    (&["Sogd", "Sogdian"], 183),
+    (&["Sogo", "Old_Sogdian"], 184),
+    (&["Elym", "Elymaic"], 185),
+    (&["Hmnp", "Nyiakeng_Puachue_Hmong"], 186),
+    (&["Nand", "Nandinagari"], 187),
+    (&["Wcho", "Wancho"], 188),
+    (&["Chrs", "Chorasmian"], 189),
+    (&["Diak", "Dives_Akuru"], 190),
+    (&["Kits", "Khitan_Small_Script"], 191),
+    (&["Yezi", "Yezidi"], 192),
+    (&["Cpmn", "Cypro_Minoan"], 193),
+    (&["Ougr", "Old_Uyghur"], 194),
+    (&["Tnsa", "Tangsa"], 195),
+    (&["Toto", "Toto"], 196),
+    (&["Vith", "Vithkuqi"], 197),
+    (&["Kawi", "Kawi"], 198),
+    (&["Nagm", "Nag_Mundari"], 199),
+];
+
+/// The names of the Sentence_Break values.
+pub(crate) const SENTENCE_BREAK_VALUES: ValueNames = &[
+    (&["XX", "Other"], 0),
+    (&["AT", "ATerm"], 1),
+    (&["CL", "Close"], 2),
+    (&["FO", "Format"], 3),
+    (&["LO", "Lower"], 4),
+    (&["NU", "Numeric"], 5),
+    (&["LE", "OLetter"], 6),
+    (&["SE", "Sep"], 7),
+    (&["SP", "Sp"], 8),
+    (&["ST", "STerm"], 9),
+    (&["UP", "Upper"], 10),
+    (&["CR", "CR"], 11),
+    (&["EX", "Extend"], 12),
+    (&["LF", "LF"], 13),
+    (&["SC", "SContinue"], 14),
+];
+
+/// The names of the Word_Break values.
+pub(crate) const WORD_BREAK_VALUES: ValueNames = &[
+    (&["XX", "Other"], 0),
+    (&["LE", "ALetter"], 1),
+    (&["FO", "Format"], 2),
+    (&["KA", "Katakana"], 3),
+    (&["ML", "MidLetter"], 4),
+    (&["MN", "MidNum"], 5),
+    (&["NU", "Numeric"], 6),
+    (&["EX", "ExtendNumLet"], 7),
+    (&["CR", "CR"], 8),
+    (&["Extend", "Extend"], 9),
+    (&["LF", "LF"], 10),
+    (&["MB", "MidNumLet"], 11),
+    (&["NL", "Newline"], 12),
+    (&["RI", "Regional_Indicator"], 13),
+    (&["HL", "Hebrew_Letter"], 14),
+    (&["SQ", "Single_Quote"], 15),
+    (&["DQ", "Double_Quote"], 16),
+    (&["EB", "E_Base"], 17),
+    (&["EBG", "E_Base_GAZ"], 18),
+    (&["EM", "E_Modifier"], 19),
+    (&["GAZ", "Glue_After_Zwj"], 20),
+    (&["ZWJ", "ZWJ"], 21),
+    (&["WSegSpace", "WSegSpace"], 22),
+];
+
+/// The names of the General_Category values and groups of values, as masks of `1 << gc`.
+pub(crate) const GENERAL_CATEGORY_MASKS: ValueNames = &[
+    (&["Cn", "Unassigned"], 0x1),
+    (&["Lu", "Uppercase_Letter"], 0x2),
+    (&["Ll", "Lowercase_Letter"], 0x4),
+    (&["Lt", "Titlecase_Letter"], 0x8),
+    (&["Lm", "Modifier_Letter"], 0x10),
+    (&["Lo", "Other_Letter"], 0x20),
+    (&["Mn", "Nonspacing_Mark"], 0x40),
+    (&["Me", "Enclosing_Mark"], 0x80),
+    (&["Mc", "Spacing_Mark"], 0x100),
+    (&["Nd", "Decimal_Number", "digit"], 0x200),
+    (&["Nl", "Letter_Number"], 0x400),
+    (&["No", "Other_Number"], 0x800),
+    (&["Zs", "Space_Separator"], 0x1000),
+    (&["Zl", "Line_Separator"], 0x2000),
+    (&["Zp", "Paragraph_Separator"], 0x4000),
+    (&["Cc", "Control", "cntrl"], 0x8000),
+    (&["Cf", "Format"], 0x10000),
+    (&["Co", "Private_Use"], 0x20000),
+    (&["Cs", "Surrogate"], 0x40000),
+    (&["Pd", "Dash_Punctuation"], 0x80000),
+    (&["Ps", "Open_Punctuation"], 0x100000),
+    (&["Pe", "Close_Punctuation"], 0x200000),
+    (&["Pc", "Connector_Punctuation"], 0x400000),
+    (&["Po", "Other_Punctuation"], 0x800000),
+    (&["Sm", "Math_Symbol"], 0x1000000),
+    (&["Sc", "Currency_Symbol"], 0x2000000),
+    (&["Sk", "Modifier_Symbol"], 0x4000000),
+    (&["So", "Other_Symbol"], 0x8000000),
+    (&["Pi", "Initial_Punctuation"], 0x10000000),
+    (&["Pf", "Final_Punctuation"], 0x20000000),
+    (&["L", "Letter"], 0x3e),
+    (&["LC", "Cased_Letter"], 0xe),
+    (&["M", "Mark", "Combining_Mark"], 0x1c0),
+    (&["N", "Number"], 0xe00),
+    (&["P", "Punctuation", "punct"], 0x30f80000),
+    (&["S", "Symbol"], 0xf000000),
+    (&["Z", "Separator"], 0x7000),
+    (&["C", "Other"], 0x78001),
+];
+
+/// The names of the enumerated properties that are looked up in a code point map,
+/// the resource keys of the maps, and the names of the values.
+pub(crate) const ENUMERATED_PROPERTIES: &[(&[&str], ResourceKey, ValueNames)] = &[
+    (&["bc", "Bidi_Class"], key::BIDI_CLASS_V1, BIDI_CLASS_VALUES),
+    (&["ea", "East_Asian_Width"], key::EAST_ASIAN_WIDTH_V1, EAST_ASIAN_WIDTH_VALUES),
+    (&["GCB", "Grapheme_Cluster_Break"], key::GRAPHEME_CLUSTER_BREAK_V1, GRAPHEME_CLUSTER_BREAK_VALUES),
+    (&["lb", "Line_Break"], key::LINE_BREAK_V1, LINE_BREAK_VALUES),
+    (&["sc", "Script"], key::SCRIPT_V1, SCRIPT_VALUES),
+    (&["SB", "Sentence_Break"], key::SENTENCE_BREAK_V1, SENTENCE_BREAK_VALUES),
+    (&["WB", "Word_Break"], key::WORD_BREAK_V1, WORD_BREAK_VALUES),
+];
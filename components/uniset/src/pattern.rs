@@ -0,0 +1,746 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing and formatting of [`UnicodeSet`] and [`UnicodeSetWithStrings`] patterns.
+//!
+//! The pattern syntax is that of [UTS #35](https://unicode.org/reports/tr35/#Unicode_Sets)
+//! and of the [ICU4C UnicodeSet API](https://unicode-org.github.io/icu/userguide/strings/unicodeset.html):
+//!
+//! - `[abc]`, `[a-z]`: characters and ranges of characters
+//! - `[^a-z]`: the complement of a set, without any strings
+//! - `[a-z{ch}{ll}]`: strings, in sets parsed with [`parse_with_strings()`]
+//! - `\u0300`, `\U0001F600`, `\x{1F600}`, `\-`: escaped characters
+//! - `[:Lu:]`, `[:^Script=Greek:]`, `\p{Alphabetic}`, `\P{gc=Nd}`: the code points with a property value
+//! - `[[a-z][0-9]]`, `[[:L:]-[a-z]]`, `[[:L:]&[:Latin:]]`: union, difference and intersection of sets.
+//!   The difference and intersection can also be written as `--` and `&&`.
+//!
+//! Whitespace is ignored, unless it is escaped. Operators are applied from left to right.
+//!
+//! Property names and values are matched loosely, ignoring case, whitespace, `_` and `-`.
+//! The supported properties are the binary properties, General_Category (including groups
+//! like `L`), Script, Bidi_Class, East_Asian_Width, Line_Break, Grapheme_Cluster_Break,
+//! Sentence_Break and Word_Break. A value of General_Category or Script, or the name of a
+//! binary property, can be used without the name of the property.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_uniset::pattern;
+//!
+//! let provider = icu_provider_uprops::PropertiesDataProvider::new(
+//!     icu_testdata::paths::data_root().join("uprops"),
+//! );
+//!
+//! let set = pattern::parse("[a-z\\u0300-\\u036f[:Script=Greek:]&&[:Lu:]]", &provider)
+//!     .expect("The pattern should be valid");
+//! assert!(set.contains('Ω'));
+//! assert!(!set.contains('ω'));
+//! assert!(!set.contains('a'));
+//!
+//! let set = pattern::parse("[[:L:]-[a-z]]", &provider).expect("The pattern should be valid");
+//! assert!(set.contains('A'));
+//! assert!(!set.contains('a'));
+//!
+//! let set = pattern::parse("[ a-c x \\u0020]", &provider).expect("The pattern should be valid");
+//! assert_eq!(set.to_string(), "[\\u0020a-cx]");
+//!
+//! let set = pattern::parse_with_strings("[a-z{ch}{ll}]", &provider).expect("The pattern should be valid");
+//! assert!(set.contains_str("ch"));
+//! assert_eq!(set.to_string(), "[a-z{ch}{ll}]");
+//! ```
+
+use crate::enum_props::GeneralCategory;
+use crate::maps;
+use crate::names;
+use crate::props;
+use crate::provider::*;
+use crate::{UnicodeSet, UnicodeSetBuilder, UnicodeSetError, UnicodeSetWithStrings, UnicodeSetWithStringsBuilder};
+use alloc::string::String;
+use core::fmt::{self, Write};
+use icu_provider::prelude::*;
+
+/// Parses a [`UnicodeSet`] pattern, like `[[:L:]-[a-z]]`, loading the data
+/// of the properties in the pattern from `provider`.
+///
+/// See the [module documentation](self) for the syntax. Strings, like `{ch}`, are
+/// an error; use [`parse_with_strings()`] for patterns with strings.
+pub fn parse<'data, D>(pattern: &str, provider: &D) -> Result<UnicodeSet<'static>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyV1Marker> + DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let mut parser = Parser {
+        pattern,
+        pos: 0,
+        provider,
+        allow_strings: false,
+    };
+    Ok(parser.parse_pattern()?.code_points.build())
+}
+
+/// Parses a [`UnicodeSetWithStrings`] pattern, like `[a-z{ch}{ll}]`, loading the data
+/// of the properties in the pattern from `provider`.
+///
+/// See the [module documentation](self) for the syntax.
+pub fn parse_with_strings<'data, D>(pattern: &str, provider: &D) -> Result<UnicodeSetWithStrings<'static>, UnicodeSetError>
+where
+    D: DataProvider<'data, UnicodePropertyV1Marker> + DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    let mut parser = Parser {
+        pattern,
+        pos: 0,
+        provider,
+        allow_strings: true,
+    };
+    Ok(parser.parse_pattern()?.build())
+}
+
+#[derive(Clone, Copy)]
+enum Operator {
+    Intersection,
+    Difference,
+}
+
+struct Parser<'a, 'p, D: ?Sized> {
+    pattern: &'a str,
+    pos: usize,
+    provider: &'p D,
+    allow_strings: bool,
+}
+
+impl<'a, 'p, 'data, D> Parser<'a, 'p, D>
+where
+    D: DataProvider<'data, UnicodePropertyV1Marker> + DataProvider<'data, UnicodePropertyMapV1Marker> + ?Sized,
+{
+    fn rest(&self) -> &'a str {
+        &self.pattern[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn error(&self) -> UnicodeSetError {
+        UnicodeSetError::InvalidPattern(self.pos)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), UnicodeSetError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if !is_pattern_white_space(c as u32) {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+    }
+
+    /// Parses the whole pattern, which is a single set.
+    fn parse_pattern(&mut self) -> Result<UnicodeSetWithStringsBuilder, UnicodeSetError> {
+        self.skip_whitespace();
+        if !self.at_set() {
+            return Err(self.error());
+        }
+        let set = self.parse_set()?;
+        self.skip_whitespace();
+        if self.pos != self.pattern.len() {
+            return Err(self.error());
+        }
+        Ok(set)
+    }
+
+    /// Whether a set starts at the current position: a bracketed set or a property.
+    fn at_set(&self) -> bool {
+        let rest = self.rest();
+        rest.starts_with('[') || rest.starts_with("\\p") || rest.starts_with("\\P")
+    }
+
+    /// Parses a bracketed set or a property.
+    fn parse_set(&mut self) -> Result<UnicodeSetWithStringsBuilder, UnicodeSetError> {
+        if self.rest().starts_with("[:") {
+            return self.parse_posix_property().map(UnicodeSetWithStringsBuilder::from);
+        }
+        if self.rest().starts_with("\\p") || self.rest().starts_with("\\P") {
+            return self.parse_perl_property().map(UnicodeSetWithStringsBuilder::from);
+        }
+        self.expect('[')?;
+        self.skip_whitespace();
+        let negated = self.eat('^');
+
+        let mut builder = UnicodeSetWithStringsBuilder::new();
+        let mut is_empty = true;
+        loop {
+            self.skip_whitespace();
+            let operator = match self.peek() {
+                None => return Err(self.error()),
+                Some(']') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some('&') if !is_empty => {
+                    self.pos += 1;
+                    self.eat('&');
+                    Some(Operator::Intersection)
+                }
+                Some('-') if !is_empty && !self.at_literal_hyphen() => {
+                    self.pos += 1;
+                    self.eat('-');
+                    Some(Operator::Difference)
+                }
+                _ => None,
+            };
+
+            if let Some(operator) = operator {
+                self.skip_whitespace();
+                if !self.at_set() {
+                    return Err(self.error());
+                }
+                let operand = self.parse_set()?.build();
+                match operator {
+                    Operator::Intersection => builder.retain_set(&operand),
+                    Operator::Difference => builder.remove_set(&operand),
+                }
+            } else if self.at_set() {
+                builder.add_set(&self.parse_set()?.build());
+            } else if self.peek() == Some('{') && self.allow_strings {
+                builder.add_str(&self.parse_string()?);
+            } else {
+                let (start, end) = self.parse_range()?;
+                builder.code_points.add(start, end + 1);
+            }
+            is_empty = false;
+        }
+
+        if negated {
+            // As in ICU4C, the complement of a set in a pattern does not contain any strings.
+            builder.complement();
+            builder.remove_all_strings();
+        }
+        Ok(builder)
+    }
+
+    /// Parses a string in braces, like `{ch}`. Whitespace in the braces is ignored, unless it is escaped.
+    fn parse_string(&mut self) -> Result<String, UnicodeSetError> {
+        self.expect('{')?;
+        let mut string = String::new();
+        loop {
+            self.skip_whitespace();
+            if self.eat('}') {
+                return Ok(string);
+            }
+            let cp = self.parse_char()?;
+            string.push(char::from_u32(cp).ok_or_else(|| self.error())?);
+        }
+    }
+
+    /// Whether the `-` at the current position is a literal, which it is at the end of a set.
+    fn at_literal_hyphen(&self) -> bool {
+        self.rest()[1..].trim_start_matches(|c| is_pattern_white_space(c as u32)).starts_with(']')
+    }
+
+    /// Parses a character or a range of characters, like `a` or `a-z`.
+    fn parse_range(&mut self) -> Result<(u32, u32), UnicodeSetError> {
+        let start = self.parse_char()?;
+        let before_hyphen = self.pos;
+        self.skip_whitespace();
+        if !self.eat('-') {
+            self.pos = before_hyphen;
+            return Ok((start, start));
+        }
+        self.skip_whitespace();
+        match self.peek() {
+            // The hyphen is an operator or a literal; leave it for the caller.
+            Some('-') | Some('[') | Some(']') => {
+                self.pos = before_hyphen;
+                return Ok((start, start));
+            }
+            _ if self.at_set() => {
+                self.pos = before_hyphen;
+                return Ok((start, start));
+            }
+            _ => (),
+        }
+        let end_pos = self.pos;
+        let end = self.parse_char()?;
+        if end < start {
+            return Err(UnicodeSetError::InvalidPattern(end_pos));
+        }
+        Ok((start, end))
+    }
+
+    /// Parses a literal or escaped character.
+    fn parse_char(&mut self) -> Result<u32, UnicodeSetError> {
+        let c = match self.peek() {
+            Some(c) => c,
+            None => return Err(self.error()),
+        };
+        match c {
+            '\\' => {
+                self.pos += 1;
+                self.parse_escape()
+            }
+            // Strings, like `{ch}`, are parsed by the caller if they are allowed.
+            '{' | '}' | '[' | ']' | '&' => Err(self.error()),
+            _ => {
+                self.pos += c.len_utf8();
+                Ok(c as u32)
+            }
+        }
+    }
+
+    /// Parses the character after a backslash.
+    fn parse_escape(&mut self) -> Result<u32, UnicodeSetError> {
+        let start = self.pos;
+        let c = match self.peek() {
+            Some(c) => c,
+            None => return Err(self.error()),
+        };
+        self.pos += c.len_utf8();
+        let cp = match c {
+            'u' if self.peek() == Some('{') => self.parse_braced_hex()?,
+            'u' => self.parse_hex(4)?,
+            'U' => self.parse_hex(8)?,
+            'x' if self.peek() == Some('{') => self.parse_braced_hex()?,
+            'x' => self.parse_hex(2)?,
+            'N' => return Err(UnicodeSetError::InvalidPattern(start)),
+            'a' => 0x07,
+            'e' => 0x1B,
+            'f' => 0x0C,
+            'n' => 0x0A,
+            'r' => 0x0D,
+            't' => 0x09,
+            'v' => 0x0B,
+            _ => c as u32,
+        };
+        if cp > char::MAX as u32 {
+            return Err(UnicodeSetError::InvalidPattern(start));
+        }
+        // Combine an escaped surrogate pair, like `\uD83D\uDE00`.
+        if (0xD800..0xDC00).contains(&cp) && self.rest().starts_with("\\u") {
+            let before = self.pos;
+            self.pos += 2;
+            match self.parse_hex(4) {
+                Ok(trail) if (0xDC00..0xE000).contains(&trail) => return Ok(0x10000 + ((cp - 0xD800) << 10) + (trail - 0xDC00)),
+                _ => self.pos = before,
+            }
+        }
+        Ok(cp)
+    }
+
+    fn parse_hex(&mut self, len: usize) -> Result<u32, UnicodeSetError> {
+        let digits = self.rest().get(..len).ok_or_else(|| self.error())?;
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(self.error());
+        }
+        let value = u32::from_str_radix(digits, 16).map_err(|_| self.error())?;
+        self.pos += len;
+        Ok(value)
+    }
+
+    fn parse_braced_hex(&mut self) -> Result<u32, UnicodeSetError> {
+        self.expect('{')?;
+        let len = self.rest().find('}').ok_or_else(|| self.error())?;
+        if len == 0 || len > 6 {
+            return Err(self.error());
+        }
+        let value = self.parse_hex(len)?;
+        self.expect('}')?;
+        Ok(value)
+    }
+
+    /// Parses a property in the POSIX-like syntax, like `[:Lu:]` or `[:^Script=Greek:]`.
+    fn parse_posix_property(&mut self) -> Result<UnicodeSetBuilder, UnicodeSetError> {
+        let start = self.pos;
+        self.pos += 2;
+        let negated = self.eat('^');
+        let len = self.rest().find(":]").ok_or_else(|| self.error())?;
+        let query = &self.rest()[..len];
+        let mut builder = self.get_property_set(query).ok_or(UnicodeSetError::InvalidPattern(start))??;
+        self.pos += len + 2;
+        if negated {
+            builder.complement();
+        }
+        Ok(builder)
+    }
+
+    /// Parses a property in the Perl-like syntax, like `\p{Lu}`, `\P{Script=Greek}` or `\pL`.
+    fn parse_perl_property(&mut self) -> Result<UnicodeSetBuilder, UnicodeSetError> {
+        let start = self.pos;
+        let negated = self.rest().starts_with("\\P");
+        self.pos += 2;
+        let query = if self.eat('{') {
+            let len = self.rest().find('}').ok_or_else(|| self.error())?;
+            let query = &self.rest()[..len];
+            self.pos += len + 1;
+            query
+        } else {
+            let c = self.peek().ok_or_else(|| self.error())?;
+            let query = &self.rest()[..c.len_utf8()];
+            self.pos += c.len_utf8();
+            query
+        };
+        let mut builder = self.get_property_set(query).ok_or(UnicodeSetError::InvalidPattern(start))??;
+        if negated {
+            builder.complement();
+        }
+        Ok(builder)
+    }
+
+    /// Returns the set for a property query, like `Lu` or `Script=Greek`, or `None` if
+    /// the property or the value is unknown.
+    fn get_property_set(&self, query: &str) -> Option<Result<UnicodeSetBuilder, UnicodeSetError>> {
+        let set = match query.find('=') {
+            Some(i) => self.get_property_value_set(&query[..i], &query[i + 1..]),
+            None => self.get_lone_property_set(query),
+        }?;
+        Some(set.map(|set| {
+            let mut builder = UnicodeSetBuilder::new();
+            builder.add_set(&set);
+            builder
+        }))
+    }
+
+    /// Returns the set for a property without a value, which is a binary property,
+    /// a General_Category value or a Script value.
+    fn get_lone_property_set(&self, name: &str) -> Option<Result<UnicodeSet<'static>, UnicodeSetError>> {
+        if loose_eq(name, "Any") {
+            return Some(Ok(UnicodeSet::all()));
+        }
+        if loose_eq(name, "ASCII") {
+            let mut builder = UnicodeSetBuilder::new();
+            builder.add_range(&('\0'..='\x7F'));
+            return Some(Ok(builder.build()));
+        }
+        if loose_eq(name, "Assigned") {
+            let unassigned = 1 << (GeneralCategory::Unassigned as u32);
+            return Some(self.get_general_category_set(!unassigned));
+        }
+        if let Some(resc_key) = find(names::BINARY_PROPERTIES, name) {
+            return Some(props::get_prop(self.provider, resc_key).map(|payload| payload.get().inv_list.clone().into_owned()));
+        }
+        if let Some(mask) = find(names::GENERAL_CATEGORY_MASKS, name) {
+            return Some(self.get_general_category_set(mask));
+        }
+        let script = find(names::SCRIPT_VALUES, name)?;
+        Some(maps::get_set_for_raw_values(self.provider, key::SCRIPT_V1, |v| v == script))
+    }
+
+    /// Returns the set for a property with a value, like `Script=Greek` or `Alphabetic=No`.
+    fn get_property_value_set(&self, name: &str, value: &str) -> Option<Result<UnicodeSet<'static>, UnicodeSetError>> {
+        if loose_eq(name, "gc") || loose_eq(name, "General_Category") {
+            let mask = find(names::GENERAL_CATEGORY_MASKS, value)?;
+            return Some(self.get_general_category_set(mask));
+        }
+        if let Some((_, resc_key, values)) = names::ENUMERATED_PROPERTIES.iter().find(|(names, _, _)| names.iter().any(|n| loose_eq(n, name))) {
+            let value = find(values, value)?;
+            return Some(maps::get_set_for_raw_values(self.provider, *resc_key, |v| v == value));
+        }
+        let resc_key = find(names::BINARY_PROPERTIES, name)?;
+        let contained = if ["Y", "Yes", "T", "True"].iter().any(|v| loose_eq(value, v)) {
+            true
+        } else if ["N", "No", "F", "False"].iter().any(|v| loose_eq(value, v)) {
+            false
+        } else {
+            return None;
+        };
+        Some(props::get_prop(self.provider, resc_key).map(|payload| {
+            let set = &payload.get().inv_list;
+            if contained {
+                set.clone().into_owned()
+            } else {
+                let mut builder = UnicodeSetBuilder::new();
+                builder.add_set(set);
+                builder.complement();
+                builder.build()
+            }
+        }))
+    }
+
+    /// Returns the set of code points whose General_Category is in `mask`, a mask of `1 << gc`.
+    fn get_general_category_set(&self, mask: u32) -> Result<UnicodeSet<'static>, UnicodeSetError> {
+        maps::get_set_for_raw_values(self.provider, key::GENERAL_CATEGORY_V1, |v| v < 32 && (1 << v) & mask != 0)
+    }
+}
+
+/// Returns the value for `name` in a table of names, matching names loosely.
+fn find<T: Copy>(table: &[(&[&str], T)], name: &str) -> Option<T> {
+    table.iter().find(|(names, _)| names.iter().any(|n| loose_eq(n, name))).map(|(_, value)| *value)
+}
+
+/// Compares property names and values loosely, ignoring case, whitespace, `_` and `-`,
+/// following [UAX44-LM3](https://www.unicode.org/reports/tr44/#UAX44-LM3).
+fn loose_eq(a: &str, b: &str) -> bool {
+    let significant = |c: &char| !(c.is_whitespace() || *c == '_' || *c == '-');
+    a.chars()
+        .filter(significant)
+        .map(|c| c.to_ascii_lowercase())
+        .eq(b.chars().filter(significant).map(|c| c.to_ascii_lowercase()))
+}
+
+/// The Pattern_White_Space characters, which are ignored in patterns unless escaped.
+fn is_pattern_white_space(cp: u32) -> bool {
+    matches!(cp, 0x09..=0x0D | 0x20 | 0x85 | 0x200E | 0x200F | 0x2028 | 0x2029)
+}
+
+fn is_noncharacter(cp: u32) -> bool {
+    (0xFDD0..=0xFDEF).contains(&cp) || cp & 0xFFFE == 0xFFFE
+}
+
+/// Formats the set as a pattern, like `[a-z\u0300-\u036F]`.
+///
+/// The pattern lists the ranges of the set, or of its complement if the set contains both
+/// U+0000 and U+10FFFF. Characters that are syntax in patterns are escaped with a backslash,
+/// and control characters, whitespace, surrogates and noncharacters are written as `\uXXXX` or `\UXXXXXXXX`.
+/// The pattern can be parsed with [`parse()`] to get the same set.
+///
+/// # Examples
+///
+/// ```
+/// use icu_uniset::UnicodeSet;
+///
+/// let set = UnicodeSet::from_inversion_list(vec![0x2D, 0x2E, 0x41, 0x43, 0x61, 0x7B]).unwrap();
+/// assert_eq!(set.to_string(), "[\\-ABa-z]");
+///
+/// let set = UnicodeSet::from_inversion_list(vec![0x0, 0x61, 0x62, 0x110000]).unwrap();
+/// assert_eq!(set.to_string(), "[^a]");
+/// ```
+impl fmt::Display for UnicodeSet<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_char('[')?;
+        write_ranges(f, &self.get_inversion_list(), true)?;
+        f.write_char(']')
+    }
+}
+
+/// Formats the set as a pattern, like `[a-z{ch}{ll}]`.
+///
+/// The code points are formatted like those of a [`UnicodeSet`], except that the complement
+/// is never used if the set has strings, since it would not contain them. The strings follow
+/// in braces, with the same escapes as code points.
+/// The pattern can be parsed with [`parse_with_strings()`] to get the same set.
+///
+/// # Examples
+///
+/// ```
+/// use icu_uniset::UnicodeSetWithStringsBuilder;
+///
+/// let mut builder = UnicodeSetWithStringsBuilder::new();
+/// builder.add_range(&('a'..='c'));
+/// builder.add_str("ch");
+/// builder.add_str("a b");
+/// assert_eq!(builder.build().to_string(), "[a-c{a\\u0020b}{ch}]");
+/// ```
+impl fmt::Display for UnicodeSetWithStrings<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_char('[')?;
+        write_ranges(f, &self.code_points().get_inversion_list(), !self.has_strings())?;
+        for string in self.iter_strings() {
+            f.write_char('{')?;
+            for c in string.chars() {
+                write_code_point(f, c as u32)?;
+            }
+            f.write_char('}')?;
+        }
+        f.write_char(']')
+    }
+}
+
+/// Writes the ranges of an inversion list, or of its complement after a `^` if `allow_complement`
+/// is true and the set contains both U+0000 and U+10FFFF.
+fn write_ranges(f: &mut fmt::Formatter, inv_list: &[u32], allow_complement: bool) -> fmt::Result {
+    let inv_list = match inv_list {
+        [0, middle @ .., end] if allow_complement && middle.len() > 1 && *end == (char::MAX as u32) + 1 => {
+            f.write_char('^')?;
+            middle
+        }
+        _ => inv_list,
+    };
+    for range in inv_list.chunks(2) {
+        let (start, end) = (range[0], range[1] - 1);
+        write_code_point(f, start)?;
+        if end > start + 1 {
+            f.write_char('-')?;
+        }
+        if end > start {
+            write_code_point(f, end)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_code_point(f: &mut fmt::Formatter, cp: u32) -> fmt::Result {
+    match char::from_u32(cp) {
+        Some(c) if matches!(c, '[' | ']' | '-' | '^' | '&' | '\\' | '{' | '}' | '$' | ':') => {
+            f.write_char('\\')?;
+            f.write_char(c)
+        }
+        Some(c) if !(c.is_control() || is_pattern_white_space(cp) || is_noncharacter(cp)) => f.write_char(c),
+        _ if cp <= 0xFFFF => write!(f, "\\u{:04X}", cp),
+        _ => write!(f, "\\U{:08X}", cp),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    /// A provider without any data, for patterns without properties.
+    struct NoDataProvider;
+
+    impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for NoDataProvider {
+        fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, UnicodePropertyV1Marker>, DataError> {
+            Err(DataError::MissingResourceKey(req.resource_path.key))
+        }
+    }
+
+    impl<'data> DataProvider<'data, UnicodePropertyMapV1Marker> for NoDataProvider {
+        fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'data, UnicodePropertyMapV1Marker>, DataError> {
+            Err(DataError::MissingResourceKey(req.resource_path.key))
+        }
+    }
+
+    fn parse_inv_list(pattern: &str) -> Vec<u32> {
+        parse(pattern, &NoDataProvider).expect("The pattern should be valid").get_inversion_list()
+    }
+
+    fn parse_strings(pattern: &str) -> (Vec<u32>, Vec<String>) {
+        let set = parse_with_strings(pattern, &NoDataProvider).expect("The pattern should be valid");
+        (set.code_points().get_inversion_list(), set.iter_strings().map(String::from).collect())
+    }
+
+    fn parse_error(pattern: &str) -> usize {
+        match parse(pattern, &NoDataProvider) {
+            Err(UnicodeSetError::InvalidPattern(pos)) => pos,
+            other => panic!("{:?} for {}", other.map(|set| set.to_string()), pattern),
+        }
+    }
+
+    #[test]
+    fn test_parse_chars_and_ranges() {
+        assert!(parse_inv_list("[]").is_empty());
+        assert_eq!(parse_inv_list("[abc]"), vec![0x61, 0x64]);
+        assert_eq!(parse_inv_list("[a-z]"), vec![0x61, 0x7B]);
+        assert_eq!(parse_inv_list("[a-zA-Z0]"), vec![0x30, 0x31, 0x41, 0x5B, 0x61, 0x7B]);
+        assert_eq!(parse_inv_list("[^a-z]"), vec![0x0, 0x61, 0x7B, 0x110000]);
+        assert_eq!(parse_inv_list("[木-林🎃]"), vec![0x6728, 0x6798, 0x1F383, 0x1F384]);
+        assert_eq!(parse_inv_list(" [ a - c  x ] "), vec![0x61, 0x64, 0x78, 0x79]);
+        assert_eq!(parse_inv_list("[-a]"), vec![0x2D, 0x2E, 0x61, 0x62]);
+        assert_eq!(parse_inv_list("[a-]"), vec![0x2D, 0x2E, 0x61, 0x62]);
+        assert_eq!(parse_inv_list("[^]"), vec![0x0, 0x110000]);
+    }
+
+    #[test]
+    fn test_parse_escapes() {
+        assert_eq!(parse_inv_list("[\\u0041\\x42\\x{43}\\u{44}]"), vec![0x41, 0x45]);
+        assert_eq!(parse_inv_list("[\\U0001F600-\\U0001F64F]"), vec![0x1F600, 0x1F650]);
+        assert_eq!(parse_inv_list("[\\uD83D\\uDE00]"), vec![0x1F600, 0x1F601]);
+        assert_eq!(parse_inv_list("[\\uD800]"), vec![0xD800, 0xD801]);
+        assert_eq!(parse_inv_list("[\\-\\[\\]\\\\\\^]"), vec![0x2D, 0x2E, 0x5B, 0x5F]);
+        assert_eq!(parse_inv_list("[\\ \\t\\n]"), vec![0x9, 0xB, 0x20, 0x21]);
+    }
+
+    #[test]
+    fn test_parse_operators() {
+        assert_eq!(parse_inv_list("[[a-c][x-z]]"), vec![0x61, 0x64, 0x78, 0x7B]);
+        assert_eq!(parse_inv_list("[[a-z]-[b-y]]"), vec![0x61, 0x62, 0x7A, 0x7B]);
+        assert_eq!(parse_inv_list("[a-z--[b-y]]"), vec![0x61, 0x62, 0x7A, 0x7B]);
+        assert_eq!(parse_inv_list("[[a-z]&[x-z0-9]]"), vec![0x78, 0x7B]);
+        assert_eq!(parse_inv_list("[a-z && [x-z0-9]]"), vec![0x78, 0x7B]);
+        // Operators are applied from left to right.
+        assert_eq!(parse_inv_list("[a-z&&[a-c]x]"), vec![0x61, 0x64, 0x78, 0x79]);
+        assert_eq!(parse_inv_list("[^[a-z]-[b-y]]"), vec![0x0, 0x61, 0x62, 0x7A, 0x7B, 0x110000]);
+    }
+
+    #[test]
+    fn test_parse_strings() {
+        let strings = |strings: &[&str]| strings.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(parse_strings("[a-c{ch}{ll}]"), (vec![0x61, 0x64], strings(&["ch", "ll"])));
+        assert_eq!(parse_strings("[{}{a}{ c h }{\\ \\u0301}]"), (vec![0x61, 0x62], strings(&["", " \u{301}", "ch"])));
+        assert_eq!(parse_strings("[{ab}{cd}a-c&[{ab}b]]"), (vec![0x62, 0x63], strings(&["ab"])));
+        assert_eq!(parse_strings("[{ab}{cd}a-c-[{ab}b]]"), (vec![0x61, 0x62, 0x63, 0x64], strings(&["cd"])));
+        // The complement of a set in a pattern does not contain strings.
+        assert_eq!(parse_strings("[^a{bc}]"), (vec![0x0, 0x61, 0x62, 0x110000], strings(&[])));
+        assert_eq!(parse_strings("[[^a]{bc}]"), (vec![0x0, 0x61, 0x62, 0x110000], strings(&["bc"])));
+        assert!(matches!(parse_with_strings("[{ab]", &NoDataProvider), Err(UnicodeSetError::InvalidPattern(4))));
+        assert!(matches!(parse_with_strings("[{a{b}}]", &NoDataProvider), Err(UnicodeSetError::InvalidPattern(3))));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_error("abc"), 0);
+        assert_eq!(parse_error("[abc"), 4);
+        assert_eq!(parse_error("[abc]]"), 5);
+        assert_eq!(parse_error("[z-a]"), 3);
+        assert_eq!(parse_error("[a&b]"), 3);
+        assert_eq!(parse_error("[&[a]]"), 1);
+        assert_eq!(parse_error("[{ab}]"), 1);
+        assert_eq!(parse_error("[\\u00]"), 3);
+        assert_eq!(parse_error("[\\x{110000}]"), 2);
+        assert_eq!(parse_error("[[:Foo:]]"), 1);
+        assert_eq!(parse_error("[\\p{Script=Unknown_Script}]"), 1);
+        assert!(matches!(parse("[:Lu:]", &NoDataProvider), Err(UnicodeSetError::PropDataLoad(_))));
+    }
+
+    #[test]
+    fn test_display() {
+        let set = |inv_list: Vec<u32>| UnicodeSet::from_inversion_list(inv_list).unwrap();
+        assert_eq!(set(vec![]).to_string(), "[]");
+        assert_eq!(set(vec![0x61, 0x7B]).to_string(), "[a-z]");
+        assert_eq!(set(vec![0x61, 0x63, 0x78, 0x79]).to_string(), "[abx]");
+        assert_eq!(set(vec![0x0, 0x110000]).to_string(), "[\\u0000-\\U0010FFFF]");
+        assert_eq!(set(vec![0x0, 0x61, 0x7B, 0x110000]).to_string(), "[^a-z]");
+        assert_eq!(set(vec![0x9, 0xB, 0x20, 0x21, 0x85, 0x86]).to_string(), "[\\u0009\\u000A\\u0020\\u0085]");
+        assert_eq!(set(vec![0x24, 0x27, 0x5B, 0x5F]).to_string(), "[\\$-\\&\\[-\\^]");
+        assert_eq!(set(vec![0xD800, 0xE000, 0x1F600, 0x1F601]).to_string(), "[\\uD800-\\uDFFF😀]");
+    }
+
+    #[test]
+    fn test_display_with_strings() {
+        let display = |pattern: &str| parse_with_strings(pattern, &NoDataProvider).expect("The pattern should be valid").to_string();
+        assert_eq!(display("[{ch}]"), "[{ch}]");
+        assert_eq!(display("[{}]"), "[{}]");
+        assert_eq!(display("[^a]"), "[^a]");
+        assert_eq!(display("[[^a]{ch}]"), "[\\u0000-`b-\\U0010FFFF{ch}]");
+        assert_eq!(display("[{\\{\\}}{\\ \\u0009}]"), "[{\\u0020\\u0009}{\\{\\}}]");
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for pattern in &[
+            "[]",
+            "[a-z]",
+            "[^a]",
+            "[\\u0000-\\U0010FFFF]",
+            "[\\-\\[\\]\\\\\\^\\{\\}\\&$:]",
+            "[\\uD800-\\uDFFF\\U0010FFFF]",
+            "[\\u0009\\u0020\\u2028]",
+            "[^\\u0000]",
+        ] {
+            let set = parse(pattern, &NoDataProvider).expect("The pattern should be valid");
+            let reparsed = parse(&set.to_string(), &NoDataProvider).expect("The output should be valid");
+            assert_eq!(set, reparsed, "{}", pattern);
+        }
+        for pattern in &["[{}]", "[a-z{ch}{ll}]", "[[^a]{a\\ b}]", "[{\\{\\}\\u0000}{\\U0001F600\\uD83D\\uDE00}]"] {
+            let set = parse_with_strings(pattern, &NoDataProvider).expect("The pattern should be valid");
+            let reparsed = parse_with_strings(&set.to_string(), &NoDataProvider).expect("The output should be valid");
+            assert_eq!(set, reparsed, "{}", pattern);
+        }
+    }
+}
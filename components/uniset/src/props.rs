@@ -4,19 +4,21 @@
 
 //! *** Note: DO NOT USE THESE APIs FOR NOW. ****
 //!  Performance improvements and other fixes are still needed on this component.
+//!
+//! The getters return the [`DataPayload`] of the property, so that the [`UnicodeSet`](crate::UnicodeSet)
+//! in its `inv_list` can borrow from the data instead of being copied.
 
 #![allow(clippy::unreadable_literal, dead_code)]
 
 use crate::enum_props::*;
 use crate::provider::*;
-use crate::{UnicodeSet, UnicodeSetError};
-use core::convert::TryInto;
+use crate::UnicodeSetError;
 use icu_provider::prelude::*;
 
-type UnisetResult = Result<UnicodeSet, UnicodeSetError>;
+type UnisetResult<'data> = Result<DataPayload<'data, UnicodePropertyV1Marker>, UnicodeSetError>;
 
 // helper fn
-fn get_prop<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(ppucd_provider: &D, resc_key: ResourceKey) -> UnisetResult {
+pub(crate) fn get_prop<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(ppucd_provider: &D, resc_key: ResourceKey) -> UnisetResult<'data> {
     let data_req = DataRequest {
         resource_path: ResourcePath {
             key: resc_key,
@@ -25,283 +27,335 @@ fn get_prop<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(ppu
     };
     let resp: DataResponse<UnicodePropertyV1Marker> = ppucd_provider.load_payload(&data_req)?;
 
-    let ppucd_property_payload: DataPayload<UnicodePropertyV1Marker> = resp.take_payload()?;
-    let ppucd_property: UnicodePropertyV1 = ppucd_property_payload.get().clone();
-    ppucd_property.try_into()
+    let property_payload: DataPayload<UnicodePropertyV1Marker> = resp.take_payload()?;
+    Ok(property_payload)
 }
 
 //
 // Binary property getter fns
 //
 
-pub fn get_ascii_hex_digit_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_ascii_hex_digit_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::ASCII_HEX_DIGIT_V1)
 }
 
-pub fn get_alnum_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_alnum_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::ALNUM_V1)
 }
 
-pub fn get_alphabetic_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_alphabetic_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::ALPHABETIC_V1)
 }
 
-pub fn get_bidi_control_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_bidi_control_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::BIDI_CONTROL_V1)
 }
 
-pub fn get_bidi_mirrored_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_bidi_mirrored_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::BIDI_MIRRORED_V1)
 }
 
-pub fn get_blank_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_blank_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::BLANK_V1)
 }
 
-pub fn get_cased_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_cased_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::CASED_V1)
 }
 
-pub fn get_case_ignorable_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_case_ignorable_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::CASE_IGNORABLE_V1)
 }
 
-pub fn get_full_composition_exclusion_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_full_composition_exclusion_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::FULL_COMPOSITION_EXCLUSION_V1)
 }
 
-pub fn get_changes_when_casefolded_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_changes_when_casefolded_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::CHANGES_WHEN_CASEFOLDED_V1)
 }
 
-pub fn get_changes_when_casemapped_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_changes_when_casemapped_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::CHANGES_WHEN_CASEMAPPED_V1)
 }
 
-pub fn get_changes_when_nfkc_casefolded_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_changes_when_nfkc_casefolded_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::CHANGES_WHEN_NFKC_CASEFOLDED_V1)
 }
 
-pub fn get_changes_when_lowercased_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_changes_when_lowercased_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::CHANGES_WHEN_LOWERCASED_V1)
 }
 
-pub fn get_changes_when_titlecased_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_changes_when_titlecased_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::CHANGES_WHEN_TITLECASED_V1)
 }
 
-pub fn get_changes_when_uppercased_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_changes_when_uppercased_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::CHANGES_WHEN_UPPERCASED_V1)
 }
 
-pub fn get_dash_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_dash_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::DASH_V1)
 }
 
-pub fn get_deprecated_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_deprecated_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::DEPRECATED_V1)
 }
 
-pub fn get_default_ignorable_code_point_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_default_ignorable_code_point_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::DEFAULT_IGNORABLE_CODE_POINT_V1)
 }
 
-pub fn get_diacritic_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_diacritic_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::DIACRITIC_V1)
 }
 
-pub fn get_emoji_modifier_base_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_emoji_modifier_base_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::EMOJI_MODIFIER_BASE_V1)
 }
 
-pub fn get_emoji_component_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_emoji_component_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::EMOJI_COMPONENT_V1)
 }
 
-pub fn get_emoji_modifier_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_emoji_modifier_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::EMOJI_MODIFIER_V1)
 }
 
-pub fn get_emoji_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_emoji_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::EMOJI_V1)
 }
 
-pub fn get_emoji_presentation_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_emoji_presentation_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::EMOJI_PRESENTATION_V1)
 }
 
-pub fn get_extender_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_extender_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::EXTENDER_V1)
 }
 
-pub fn get_extended_pictographic_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_extended_pictographic_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::EXTENDED_PICTOGRAPHIC_V1)
 }
 
-pub fn get_graph_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_graph_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::GRAPH_V1)
 }
 
-pub fn get_grapheme_base_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_grapheme_base_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::GRAPHEME_BASE_V1)
 }
 
-pub fn get_grapheme_extend_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_grapheme_extend_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::GRAPHEME_EXTEND_V1)
 }
 
-pub fn get_grapheme_link_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_grapheme_link_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::GRAPHEME_LINK_V1)
 }
 
-pub fn get_hex_digit_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_hex_digit_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::HEX_DIGIT_V1)
 }
 
-pub fn get_hyphen_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_hyphen_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::HYPHEN_V1)
 }
 
-pub fn get_id_continue_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_id_continue_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::ID_CONTINUE_V1)
 }
 
-pub fn get_ideographic_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_ideographic_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::IDEOGRAPHIC_V1)
 }
 
-pub fn get_id_start_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_id_start_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::ID_START_V1)
 }
 
-pub fn get_ids_binary_operator_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_ids_binary_operator_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::IDS_BINARY_OPERATOR_V1)
 }
 
-pub fn get_ids_trinary_operator_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_ids_trinary_operator_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::IDS_TRINARY_OPERATOR_V1)
 }
 
-pub fn get_join_control_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_join_control_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::JOIN_CONTROL_V1)
 }
 
-pub fn get_logical_order_exception_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_logical_order_exception_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::LOGICAL_ORDER_EXCEPTION_V1)
 }
 
-pub fn get_lowercase_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_lowercase_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::LOWERCASE_V1)
 }
 
-pub fn get_math_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_math_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::MATH_V1)
 }
 
-pub fn get_noncharacter_code_point_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_noncharacter_code_point_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::NONCHARACTER_CODE_POINT_V1)
 }
 
-pub fn get_nfc_inert_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_nfc_inert_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::NFC_INERT_V1)
 }
 
-pub fn get_nfd_inert_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_nfd_inert_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::NFD_INERT_V1)
 }
 
-pub fn get_nfkc_inert_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_nfkc_inert_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::NFKC_INERT_V1)
 }
 
-pub fn get_nfkd_inert_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_nfkd_inert_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::NFKD_INERT_V1)
 }
 
-pub fn get_pattern_syntax_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_pattern_syntax_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::PATTERN_SYNTAX_V1)
 }
 
-pub fn get_pattern_white_space_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_pattern_white_space_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::PATTERN_WHITE_SPACE_V1)
 }
 
-pub fn get_prepended_concatenation_mark_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_prepended_concatenation_mark_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::PREPENDED_CONCATENATION_MARK_V1)
 }
 
-pub fn get_print_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_print_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::PRINT_V1)
 }
 
-pub fn get_quotation_mark_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_quotation_mark_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::QUOTATION_MARK_V1)
 }
 
-pub fn get_radical_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_radical_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::RADICAL_V1)
 }
 
-pub fn get_regional_indicator_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_regional_indicator_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::REGIONAL_INDICATOR_V1)
 }
 
-pub fn get_soft_dotted_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_soft_dotted_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::SOFT_DOTTED_V1)
 }
 
-pub fn get_segment_starter_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_segment_starter_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::SEGMENT_STARTER_V1)
 }
 
-pub fn get_case_sensitive_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_case_sensitive_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::CASE_SENSITIVE_V1)
 }
 
-pub fn get_sentence_terminal_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_sentence_terminal_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::SENTENCE_TERMINAL_V1)
 }
 
-pub fn get_terminal_punctuation_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_terminal_punctuation_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::TERMINAL_PUNCTUATION_V1)
 }
 
-pub fn get_unified_ideograph_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_unified_ideograph_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::UNIFIED_IDEOGRAPH_V1)
 }
 
-pub fn get_uppercase_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_uppercase_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::UPPERCASE_V1)
 }
 
-pub fn get_variation_selector_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_variation_selector_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::VARIATION_SELECTOR_V1)
 }
 
-pub fn get_white_space_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_white_space_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::WHITE_SPACE_V1)
 }
 
-pub fn get_xdigit_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_xdigit_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::XDIGIT_V1)
 }
 
-pub fn get_xid_continue_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_xid_continue_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::XID_CONTINUE_V1)
 }
 
-pub fn get_xid_start_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult {
+pub fn get_xid_start_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
     get_prop(provider, key::XID_START_V1)
 }
 
+//
+// Identifier property getter fns
+//
+
+/// Return the [`UnicodeSet`](crate::UnicodeSet) data for the characters with the Identifier_Status
+/// value Allowed of [UTS #39](https://www.unicode.org/reports/tr39/#Identifier_Status_and_Type),
+/// which are recommended for identifiers.
+pub fn get_identifier_status_allowed_property<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D) -> UnisetResult<'data> {
+    get_prop(provider, key::IDENTIFIER_STATUS_ALLOWED_V1)
+}
+
 //
 // Enumerated property getter fns
 //
 
-/// Return a [`UnicodeSet`] for a particular value of the General_Category Unicode enumerated property
+/// Return the [`UnicodeSet`](crate::UnicodeSet) data for a particular value of the Bidi_Class Unicode enumerated property
+/// Bidi_Class specifies the directional types of characters for the Unicode Bidirectional Algorithm.
+/// See https://www.unicode.org/reports/tr9/ .
+pub fn get_bidi_class_val_set<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D, enum_val: BidiClass) -> UnisetResult<'data> {
+    match enum_val {
+        BidiClass::LeftToRight => get_prop(provider, key::BIDI_CLASS_LEFT_TO_RIGHT_V1),
+        BidiClass::RightToLeft => get_prop(provider, key::BIDI_CLASS_RIGHT_TO_LEFT_V1),
+        BidiClass::EuropeanNumber => get_prop(provider, key::BIDI_CLASS_EUROPEAN_NUMBER_V1),
+        BidiClass::EuropeanSeparator => get_prop(provider, key::BIDI_CLASS_EUROPEAN_SEPARATOR_V1),
+        BidiClass::EuropeanTerminator => get_prop(provider, key::BIDI_CLASS_EUROPEAN_TERMINATOR_V1),
+        BidiClass::ArabicNumber => get_prop(provider, key::BIDI_CLASS_ARABIC_NUMBER_V1),
+        BidiClass::CommonSeparator => get_prop(provider, key::BIDI_CLASS_COMMON_SEPARATOR_V1),
+        BidiClass::ParagraphSeparator => get_prop(provider, key::BIDI_CLASS_PARAGRAPH_SEPARATOR_V1),
+        BidiClass::SegmentSeparator => get_prop(provider, key::BIDI_CLASS_SEGMENT_SEPARATOR_V1),
+        BidiClass::WhiteSpace => get_prop(provider, key::BIDI_CLASS_WHITE_SPACE_V1),
+        BidiClass::OtherNeutral => get_prop(provider, key::BIDI_CLASS_OTHER_NEUTRAL_V1),
+        BidiClass::LeftToRightEmbedding => get_prop(provider, key::BIDI_CLASS_LEFT_TO_RIGHT_EMBEDDING_V1),
+        BidiClass::LeftToRightOverride => get_prop(provider, key::BIDI_CLASS_LEFT_TO_RIGHT_OVERRIDE_V1),
+        BidiClass::ArabicLetter => get_prop(provider, key::BIDI_CLASS_ARABIC_LETTER_V1),
+        BidiClass::RightToLeftEmbedding => get_prop(provider, key::BIDI_CLASS_RIGHT_TO_LEFT_EMBEDDING_V1),
+        BidiClass::RightToLeftOverride => get_prop(provider, key::BIDI_CLASS_RIGHT_TO_LEFT_OVERRIDE_V1),
+        BidiClass::PopDirectionalFormat => get_prop(provider, key::BIDI_CLASS_POP_DIRECTIONAL_FORMAT_V1),
+        BidiClass::NonspacingMark => get_prop(provider, key::BIDI_CLASS_NONSPACING_MARK_V1),
+        BidiClass::BoundaryNeutral => get_prop(provider, key::BIDI_CLASS_BOUNDARY_NEUTRAL_V1),
+        BidiClass::FirstStrongIsolate => get_prop(provider, key::BIDI_CLASS_FIRST_STRONG_ISOLATE_V1),
+        BidiClass::LeftToRightIsolate => get_prop(provider, key::BIDI_CLASS_LEFT_TO_RIGHT_ISOLATE_V1),
+        BidiClass::RightToLeftIsolate => get_prop(provider, key::BIDI_CLASS_RIGHT_TO_LEFT_ISOLATE_V1),
+        BidiClass::PopDirectionalIsolate => get_prop(provider, key::BIDI_CLASS_POP_DIRECTIONAL_ISOLATE_V1),
+    }
+}
+
+/// Return the [`UnicodeSet`](crate::UnicodeSet) data for a particular value of the Bidi_Paired_Bracket_Type Unicode enumerated property
+/// Bidi_Paired_Bracket_Type specifies the opening and closing paired brackets for the Unicode Bidirectional Algorithm.
+/// See https://www.unicode.org/reports/tr9/ .
+pub fn get_bidi_paired_bracket_type_val_set<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D, enum_val: BidiPairedBracketType) -> UnisetResult<'data> {
+    match enum_val {
+        BidiPairedBracketType::None => get_prop(provider, key::BIDI_PAIRED_BRACKET_TYPE_NONE_V1),
+        BidiPairedBracketType::Open => get_prop(provider, key::BIDI_PAIRED_BRACKET_TYPE_OPEN_V1),
+        BidiPairedBracketType::Close => get_prop(provider, key::BIDI_PAIRED_BRACKET_TYPE_CLOSE_V1),
+    }
+}
+
+/// Return the [`UnicodeSet`](crate::UnicodeSet) data for a particular value of the General_Category Unicode enumerated property
 /// General_Category specifies enumerated Unicode general category types.
 /// See https://www.unicode.org/reports/tr44/ .
-pub fn get_general_category_val_set<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D, enum_val: GeneralCategory) -> UnisetResult {
+pub fn get_general_category_val_set<'data, D: DataProvider<'data, UnicodePropertyV1Marker> + ?Sized>(provider: &D, enum_val: GeneralCategory) -> UnisetResult<'data> {
     match enum_val {
         GeneralCategory::Control => get_prop(provider, key::GENERAL_CATEGORY_CONTROL_V1),
         GeneralCategory::Format => get_prop(provider, key::GENERAL_CATEGORY_FORMAT_V1),
@@ -10,7 +10,9 @@ use crate::builder::UnicodeSetBuilder;
 use crate::uniset::UnicodeSet;
 use alloc::borrow::Cow;
 use core::convert::TryInto;
+use icu_codepointtrie::codepointtrie::{CodePointTrie, Small};
 use icu_provider::yoke::{self, *};
+use zerovec::ZeroVec;
 //
 // resource key structs - the structs used directly by users of data provider
 //
@@ -27,7 +29,7 @@ pub mod key {
         };
     }
 
-    define_resource_keys!(95;
+    define_resource_keys!(122;
 
         //
         // Binary properties
@@ -99,6 +101,12 @@ pub mod key {
         (XID_CONTINUE_V1, "XIDC"),
         (XID_START_V1, "XIDS"),
 
+        //
+        // Identifier properties of UTS #39
+        //
+
+        (IDENTIFIER_STATUS_ALLOWED_V1, "IdStatus=Allowed"),
+
         //
         // Enumerated properties
         //
@@ -107,6 +115,32 @@ pub mod key {
         // short name of the enumerated property and the short name of the
         // property value.
 
+        (BIDI_CLASS_LEFT_TO_RIGHT_V1, "bc=L"),
+        (BIDI_CLASS_RIGHT_TO_LEFT_V1, "bc=R"),
+        (BIDI_CLASS_EUROPEAN_NUMBER_V1, "bc=EN"),
+        (BIDI_CLASS_EUROPEAN_SEPARATOR_V1, "bc=ES"),
+        (BIDI_CLASS_EUROPEAN_TERMINATOR_V1, "bc=ET"),
+        (BIDI_CLASS_ARABIC_NUMBER_V1, "bc=AN"),
+        (BIDI_CLASS_COMMON_SEPARATOR_V1, "bc=CS"),
+        (BIDI_CLASS_PARAGRAPH_SEPARATOR_V1, "bc=B"),
+        (BIDI_CLASS_SEGMENT_SEPARATOR_V1, "bc=S"),
+        (BIDI_CLASS_WHITE_SPACE_V1, "bc=WS"),
+        (BIDI_CLASS_OTHER_NEUTRAL_V1, "bc=ON"),
+        (BIDI_CLASS_LEFT_TO_RIGHT_EMBEDDING_V1, "bc=LRE"),
+        (BIDI_CLASS_LEFT_TO_RIGHT_OVERRIDE_V1, "bc=LRO"),
+        (BIDI_CLASS_ARABIC_LETTER_V1, "bc=AL"),
+        (BIDI_CLASS_RIGHT_TO_LEFT_EMBEDDING_V1, "bc=RLE"),
+        (BIDI_CLASS_RIGHT_TO_LEFT_OVERRIDE_V1, "bc=RLO"),
+        (BIDI_CLASS_POP_DIRECTIONAL_FORMAT_V1, "bc=PDF"),
+        (BIDI_CLASS_NONSPACING_MARK_V1, "bc=NSM"),
+        (BIDI_CLASS_BOUNDARY_NEUTRAL_V1, "bc=BN"),
+        (BIDI_CLASS_FIRST_STRONG_ISOLATE_V1, "bc=FSI"),
+        (BIDI_CLASS_LEFT_TO_RIGHT_ISOLATE_V1, "bc=LRI"),
+        (BIDI_CLASS_RIGHT_TO_LEFT_ISOLATE_V1, "bc=RLI"),
+        (BIDI_CLASS_POP_DIRECTIONAL_ISOLATE_V1, "bc=PDI"),
+        (BIDI_PAIRED_BRACKET_TYPE_NONE_V1, "bpt=n"),
+        (BIDI_PAIRED_BRACKET_TYPE_OPEN_V1, "bpt=o"),
+        (BIDI_PAIRED_BRACKET_TYPE_CLOSE_V1, "bpt=c"),
         (GENERAL_CATEGORY_CONTROL_V1, "gc=Cc"),
         (GENERAL_CATEGORY_FORMAT_V1, "gc=Cf"),
         (GENERAL_CATEGORY_UNASSIGNED_V1, "gc=Cn"),
@@ -138,14 +172,49 @@ pub mod key {
         (GENERAL_CATEGORY_PARAGRAPH_SEPARATOR_V1, "gc=Zp"),
         (GENERAL_CATEGORY_SPACE_SEPARATOR_V1, "gc=Zs"),
     );
+
+    //
+    // Enumerated property maps
+    //
+
+    // Note: The ResourceKey subcategory strings are the short names of the
+    // enumerated properties.
+
+    pub const BIDI_CLASS_V1: ResourceKey = resource_key!(UnicodeSet, "bc", 1);
+    pub const EAST_ASIAN_WIDTH_V1: ResourceKey = resource_key!(UnicodeSet, "ea", 1);
+    pub const GENERAL_CATEGORY_V1: ResourceKey = resource_key!(UnicodeSet, "gc", 1);
+    pub const GRAPHEME_CLUSTER_BREAK_V1: ResourceKey = resource_key!(UnicodeSet, "GCB", 1);
+    pub const LINE_BREAK_V1: ResourceKey = resource_key!(UnicodeSet, "lb", 1);
+    pub const SCRIPT_V1: ResourceKey = resource_key!(UnicodeSet, "sc", 1);
+    pub const SENTENCE_BREAK_V1: ResourceKey = resource_key!(UnicodeSet, "SB", 1);
+    pub const WORD_BREAK_V1: ResourceKey = resource_key!(UnicodeSet, "WB", 1);
+
+    pub const ALL_MAP_KEYS: [ResourceKey; 8] = [
+        BIDI_CLASS_V1,
+        EAST_ASIAN_WIDTH_V1,
+        GENERAL_CATEGORY_V1,
+        GRAPHEME_CLUSTER_BREAK_V1,
+        LINE_BREAK_V1,
+        SCRIPT_V1,
+        SENTENCE_BREAK_V1,
+        WORD_BREAK_V1,
+    ];
+
+    //
+    // Script_Extensions
+    //
+
+    pub const SCRIPT_EXTENSIONS_V1: ResourceKey = resource_key!(UnicodeSet, "scx", 1);
 }
 
 #[icu_provider::data_struct]
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
 #[cfg_attr(feature = "provider_serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnicodePropertyV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
     pub name: Cow<'data, str>,
-    pub inv_list: UnicodeSet,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub inv_list: UnicodeSet<'data>,
 }
 
 impl Default for UnicodePropertyV1<'static> {
@@ -159,14 +228,87 @@ impl Default for UnicodePropertyV1<'static> {
 }
 
 impl<'data> UnicodePropertyV1<'data> {
-    pub fn from_uniset(set: &UnicodeSet, name: Cow<'data, str>) -> UnicodePropertyV1<'data> {
+    pub fn from_uniset(set: &UnicodeSet<'data>, name: Cow<'data, str>) -> UnicodePropertyV1<'data> {
         UnicodePropertyV1 { name, inv_list: set.clone() }
     }
 }
 
-impl<'data> TryInto<UnicodeSet> for UnicodePropertyV1<'data> {
+impl<'data> TryInto<UnicodeSet<'data>> for UnicodePropertyV1<'data> {
     type Error = crate::UnicodeSetError;
-    fn try_into(self) -> Result<UnicodeSet, Self::Error> {
+    fn try_into(self) -> Result<UnicodeSet<'data>, Self::Error> {
         Ok(self.inv_list)
     }
 }
+
+/// The code point trie of an enumerated property map, with the width of the
+/// values needed for the property.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "provider_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnicodePropertyMapTrie<'data> {
+    /// For properties whose values all fit in 8 bits, like General_Category.
+    Bits8(#[cfg_attr(feature = "provider_serde", serde(borrow))] CodePointTrie<'data, u8, Small>),
+    /// For properties with 16-bit values, like Script.
+    Bits16(#[cfg_attr(feature = "provider_serde", serde(borrow))] CodePointTrie<'data, u16, Small>),
+}
+
+impl UnicodePropertyMapTrie<'_> {
+    /// Returns the value of the property for the code point `cp`.
+    pub fn get(&self, cp: u32) -> u32 {
+        match self {
+            UnicodePropertyMapTrie::Bits8(trie) => trie.get_u32(cp),
+            UnicodePropertyMapTrie::Bits16(trie) => trie.get_u32(cp),
+        }
+    }
+}
+
+/// A map from code points to the values of an enumerated property, such as
+/// General_Category or Script, as a [`CodePointTrie`]. The values in the trie
+/// are the integer values of the property used by ICU4C.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "provider_serde", derive(serde::Serialize, serde::Deserialize))]
+#[yoke(cloning_zcf)]
+pub struct UnicodePropertyMapV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub name: Cow<'data, str>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub code_point_trie: UnicodePropertyMapTrie<'data>,
+}
+
+/// The Script and Script_Extensions properties of all code points, in the
+/// representation used by ICU4C.
+///
+/// Each value in the trie is either a Script value, for code points without
+/// extensions, or an offset into `extensions` in the low 10 bits, with the
+/// Script value given by the bits above them:
+///
+/// - [`SCRIPT_X_WITH_COMMON`]: Script is Common, and the extensions start at the offset.
+/// - [`SCRIPT_X_WITH_INHERITED`]: Script is Inherited, and the extensions start at the offset.
+/// - [`SCRIPT_X_WITH_OTHER`]: `extensions` has the Script value at the offset, followed by
+///   the offset of the extensions.
+///
+/// Each list of extensions in `extensions` is sorted, and its last element has bit 15 set.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "provider_serde", derive(serde::Serialize, serde::Deserialize))]
+#[yoke(cloning_zcf)]
+pub struct ScriptExtensionsPropertyV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub code_point_trie: CodePointTrie<'data, u16, Small>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub extensions: ZeroVec<'data, u16>,
+}
+
+/// Mask for the bits of a [`ScriptExtensionsPropertyV1`] trie value that
+/// select how to read the value.
+pub const SCRIPT_X_MASK: u16 = 0x0c00;
+/// See [`ScriptExtensionsPropertyV1`].
+pub const SCRIPT_X_WITH_COMMON: u16 = 0x0400;
+/// See [`ScriptExtensionsPropertyV1`].
+pub const SCRIPT_X_WITH_INHERITED: u16 = 0x0800;
+/// See [`ScriptExtensionsPropertyV1`].
+pub const SCRIPT_X_WITH_OTHER: u16 = 0x0c00;
+/// Mask for the Script value or the offset in a [`ScriptExtensionsPropertyV1`] trie value.
+pub const SCRIPT_VALUE_MASK: u16 = 0x03ff;
+/// Set on the last element of a list of extensions in [`ScriptExtensionsPropertyV1`].
+pub const SCRIPT_X_LAST: u16 = 0x8000;
@@ -0,0 +1,139 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Lookup of the Script and Script_Extensions properties.
+//!
+//! The Script_Extensions property of a code point is the set of scripts that the
+//! character is commonly used with. Many characters with a Script value of
+//! Common or Inherited, like punctuation shared by a few scripts, have a more
+//! specific set of Script_Extensions. See [UAX #24](https://www.unicode.org/reports/tr24/).
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_uniset::enum_props::Script;
+//! use icu_uniset::script;
+//!
+//! let provider = icu_provider_uprops::EnumeratedPropertiesDataProvider::new(
+//!     icu_testdata::paths::data_root().join("uprops"),
+//! );
+//! let swe = script::get_script_with_extensions(&provider).expect("The data should be valid");
+//!
+//! // U+0640 ARABIC TATWEEL
+//! assert_eq!(swe.get_script('ـ'), Script::Common);
+//! assert!(swe.has_script('ـ', Script::Arabic));
+//! assert!(swe.has_script('ـ', Script::Syriac));
+//! assert!(!swe.has_script('ـ', Script::Common));
+//! assert!(!swe.has_script('ـ', Script::Greek));
+//!
+//! // U+0915 DEVANAGARI LETTER KA has no extensions.
+//! assert!(swe.get_script_extensions('क').eq([Script::Devanagari]));
+//! ```
+
+use crate::enum_props::Script;
+use crate::provider::*;
+use crate::UnicodeSetError;
+use core::convert::TryFrom;
+use icu_provider::prelude::*;
+use zerovec::ZeroVec;
+
+/// The Script and Script_Extensions properties of all code points, loaded
+/// from a [`ScriptExtensionsPropertyV1`].
+pub struct ScriptWithExtensions<'data> {
+    data: DataPayload<'data, ScriptExtensionsPropertyV1Marker>,
+}
+
+impl<'data> ScriptWithExtensions<'data> {
+    /// Returns the Script property value of the character `c`.
+    pub fn get_script(&self, c: char) -> Script {
+        let data = self.data.get();
+        let value = data.code_point_trie.get(c as u32);
+        let script = match value & SCRIPT_X_MASK {
+            0 => value,
+            SCRIPT_X_WITH_COMMON => return Script::Common,
+            SCRIPT_X_WITH_INHERITED => return Script::Inherited,
+            _ => data.extensions.get((value & SCRIPT_VALUE_MASK) as usize).unwrap_or(0),
+        };
+        Script::try_from(u32::from(script)).unwrap_or(Script::Unknown)
+    }
+
+    /// Returns the Script_Extensions property value of the character `c`,
+    /// sorted by the integer values of the scripts. For a character without
+    /// extensions, this is its Script value.
+    pub fn get_script_extensions(&self, c: char) -> ScriptExtensionsSet<'_> {
+        let data = self.data.get();
+        let value = data.code_point_trie.get(c as u32);
+        let index = match value & SCRIPT_X_MASK {
+            0 => {
+                return ScriptExtensionsSet {
+                    extensions: &data.extensions,
+                    next: Next::Script(value),
+                }
+            }
+            SCRIPT_X_WITH_OTHER => data.extensions.get((value & SCRIPT_VALUE_MASK) as usize + 1).map(|index| index & SCRIPT_VALUE_MASK).unwrap_or(0),
+            _ => value & SCRIPT_VALUE_MASK,
+        };
+        ScriptExtensionsSet {
+            extensions: &data.extensions,
+            next: Next::Index(index as usize),
+        }
+    }
+
+    /// Returns whether `script` is in the Script_Extensions of the character `c`.
+    ///
+    /// As in ICU4C, this is `false` for Common and Inherited if the character has
+    /// other Script_Extensions, even if its Script value is Common or Inherited.
+    pub fn has_script(&self, c: char, script: Script) -> bool {
+        self.get_script_extensions(c).any(|s| s == script)
+    }
+}
+
+/// An iterator over the Script_Extensions of a code point, returned by
+/// [`ScriptWithExtensions::get_script_extensions()`].
+pub struct ScriptExtensionsSet<'a> {
+    extensions: &'a ZeroVec<'a, u16>,
+    next: Next,
+}
+
+enum Next {
+    Script(u16),
+    Index(usize),
+    Done,
+}
+
+impl Iterator for ScriptExtensionsSet<'_> {
+    type Item = Script;
+
+    fn next(&mut self) -> Option<Script> {
+        let value = match self.next {
+            Next::Script(value) => {
+                self.next = Next::Done;
+                value
+            }
+            Next::Index(index) => {
+                let value = self.extensions.get(index)?;
+                self.next = if value & SCRIPT_X_LAST != 0 { Next::Done } else { Next::Index(index + 1) };
+                value & !SCRIPT_X_LAST
+            }
+            Next::Done => return None,
+        };
+        Some(Script::try_from(u32::from(value)).unwrap_or(Script::Unknown))
+    }
+}
+
+/// Returns a [`ScriptWithExtensions`] for looking up the Script and
+/// Script_Extensions properties.
+pub fn get_script_with_extensions<'data, D>(provider: &D) -> Result<ScriptWithExtensions<'data>, UnicodeSetError>
+where
+    D: DataProvider<'data, ScriptExtensionsPropertyV1Marker> + ?Sized,
+{
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::SCRIPT_EXTENSIONS_V1,
+            options: ResourceOptions { variant: None, langid: None },
+        },
+    };
+    let resp: DataResponse<ScriptExtensionsPropertyV1Marker> = provider.load_payload(&data_req)?;
+    Ok(ScriptWithExtensions { data: resp.take_payload()? })
+}
@@ -2,15 +2,13 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-#[cfg(feature = "serde")]
+#[cfg(feature = "provider_serde")]
 use alloc::format;
-use alloc::vec;
 use alloc::vec::Vec;
-use core::{char, ops::RangeBounds, ops::RangeInclusive, slice::Chunks};
+use core::hash::{Hash, Hasher};
+use core::{char, ops::RangeBounds, ops::RangeInclusive};
 use icu_provider::yoke::{self, *};
-
-#[cfg(feature = "serde")]
-use serde::ser::SerializeSeq;
+use zerovec::{ule::AsULE, ZeroVec};
 
 use super::UnicodeSetError;
 use crate::utils::{deconstruct_range, is_valid};
@@ -22,9 +20,12 @@ const BMP_MAX: u32 = 0xFFFF;
 ///
 /// Provides exposure to membership functions and constructors from serialized [`UnicodeSets`](UnicodeSet)
 /// and predefined ranges.
-#[derive(Debug, PartialEq, Hash, Eq, Clone, Yokeable, ZeroCopyFrom)]
+///
+/// The inversion list is a [`ZeroVec`], so a [`UnicodeSet`] deserialized from a binary format like
+/// Postcard borrows its code points from the buffer instead of allocating.
+#[derive(Debug, PartialEq, Clone, Yokeable, ZeroCopyFrom)]
 #[yoke(cloning_zcf)]
-pub struct UnicodeSet {
+pub struct UnicodeSet<'data> {
     // TODO: need advice - how should we remove Hash and Eq from UnicodeSet unless we need it?
 
     // If we wanted to use an array to keep the memory on the stack, there is an unsafe nightly feature
@@ -32,20 +33,28 @@ pub struct UnicodeSet {
     // Allows for traits of fixed size arrays
 
     // Implements an [inversion list.](https://en.wikipedia.org/wiki/Inversion_list)
-    inv_list: Vec<u32>,
+    inv_list: ZeroVec<'data, u32>,
     size: usize,
 }
 
-#[cfg(feature = "serde")]
-impl<'de> serde::Deserialize<'de> for UnicodeSet {
+impl Eq for UnicodeSet<'_> {}
+
+impl Hash for UnicodeSet<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inv_list.as_bytes().hash(state)
+    }
+}
+
+#[cfg(feature = "provider_serde")]
+impl<'de: 'data, 'data> serde::Deserialize<'de> for UnicodeSet<'data> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         use serde::de::Error;
-        let parsed_inv_list = Vec::<u32>::deserialize(deserializer)?;
+        let parsed_inv_list = ZeroVec::<u32>::deserialize(deserializer)?;
 
-        UnicodeSet::from_inversion_list(parsed_inv_list).map_err(|e| Error::custom(format!("Cannot deserialize invalid inversion list for UnicodeSet: {:?}", e)))
+        UnicodeSet::from_inversion_list_zerovec(parsed_inv_list).map_err(|e| Error::custom(format!("Cannot deserialize invalid inversion list for UnicodeSet: {:?}", e)))
     }
 }
 
@@ -53,21 +62,19 @@ impl<'de> serde::Deserialize<'de> for UnicodeSet {
 // to replace the struct when serializing. The error message from the default
 // serialization is: "can only flatten structs and maps (got a sequence)".
 
-#[cfg(feature = "serde")]
-impl serde::Serialize for UnicodeSet {
+/// Serializes the inversion list as a sequence of code points in human-readable
+/// formats, and as little-endian bytes that can be borrowed on deserialization otherwise.
+#[cfg(feature = "provider_serde")]
+impl serde::Serialize for UnicodeSet<'_> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let mut seq = serializer.serialize_seq(Some(self.inv_list.len()))?;
-        for e in &self.inv_list {
-            seq.serialize_element(e)?;
-        }
-        seq.end()
+        self.inv_list.serialize(serializer)
     }
 }
 
-impl UnicodeSet {
+impl<'data> UnicodeSet<'data> {
     /// Returns [`UnicodeSet`] from an [inversion list.](https://en.wikipedia.org/wiki/Inversion_list)
     /// represented by a [`Vec`]`<`[`u32`]`>` of codepoints.
     ///
@@ -88,19 +95,59 @@ impl UnicodeSet {
     /// ```
     pub fn from_inversion_list(inv_list: Vec<u32>) -> Result<Self, UnicodeSetError> {
         if is_valid(&inv_list) {
-            let size: usize = inv_list.chunks(2).map(|end_points| end_points[1] - end_points[0]).sum::<u32>() as usize;
-            Ok(Self { inv_list, size })
+            Ok(Self::from_valid_zerovec(ZeroVec::from_aligned(&inv_list)))
         } else {
             Err(UnicodeSetError::InvalidSet(inv_list))
         }
     }
 
+    /// Returns [`UnicodeSet`] from an [inversion list.](https://en.wikipedia.org/wiki/Inversion_list)
+    /// represented by a [`ZeroVec`]`<`[`u32`]`>` of codepoints, which may borrow from a buffer.
+    ///
+    /// The inversion list must be valid as for [`UnicodeSet::from_inversion_list()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::UnicodeSet;
+    /// use zerovec::ZeroVec;
+    ///
+    /// // The little-endian bytes of the inversion list [0x41, 0x44].
+    /// let bytes: &[u8] = &[0x41, 0x00, 0x00, 0x00, 0x44, 0x00, 0x00, 0x00];
+    /// let inv_list = ZeroVec::try_from_bytes(bytes).expect("infallible");
+    /// let set = UnicodeSet::from_inversion_list_zerovec(inv_list).unwrap();
+    /// assert!(set.contains('B'));
+    /// assert!(!set.contains('D'));
+    /// ```
+    pub fn from_inversion_list_zerovec(inv_list: ZeroVec<'data, u32>) -> Result<Self, UnicodeSetError> {
+        let sorted = inv_list.as_slice().windows(2).all(|pair| u32::from_unaligned(&pair[0]) < u32::from_unaligned(&pair[1]));
+        if inv_list.len() % 2 == 0 && sorted && inv_list.last().unwrap_or(0) <= (char::MAX as u32) + 1 {
+            Ok(Self::from_valid_zerovec(inv_list))
+        } else {
+            Err(UnicodeSetError::InvalidSet(inv_list.to_vec()))
+        }
+    }
+
+    fn from_valid_zerovec(inv_list: ZeroVec<'data, u32>) -> Self {
+        let size = inv_list
+            .as_slice()
+            .chunks(2)
+            .map(|end_points| u32::from_unaligned(&end_points[1]) - u32::from_unaligned(&end_points[0]))
+            .sum::<u32>() as usize;
+        Self { inv_list, size }
+    }
+
+    /// Returns a [`UnicodeSet`] that owns its inversion list, copying it if it is borrowed.
+    pub fn into_owned(self) -> UnicodeSet<'static> {
+        UnicodeSet {
+            inv_list: self.inv_list.into_owned(),
+            size: self.size,
+        }
+    }
+
     /// Returns an owned inversion list representing the current [`UnicodeSet`]
     pub fn get_inversion_list(&self) -> Vec<u32> {
-        let result: Vec<u32> = self
-            .as_inversion_list() // Only crate public, to not leak impl
-            .to_vec();
-        result
+        self.inv_list.to_vec()
     }
 
     /// Returns [`UnicodeSet`] spanning entire Unicode range
@@ -108,7 +155,7 @@ impl UnicodeSet {
     /// The range spans from `0x0 -> 0x10FFFF` inclusive
     pub fn all() -> Self {
         Self {
-            inv_list: vec![0x0, (char::MAX as u32) + 1],
+            inv_list: ZeroVec::from_aligned(&[0x0, (char::MAX as u32) + 1]),
             size: (char::MAX as usize) + 1,
         }
     }
@@ -118,15 +165,15 @@ impl UnicodeSet {
     /// The range spans from `0x0 -> 0xFFFF` inclusive
     pub fn bmp() -> Self {
         Self {
-            inv_list: vec![0x0, BMP_MAX + 1],
+            inv_list: ZeroVec::from_aligned(&[0x0, BMP_MAX + 1]),
             size: (BMP_MAX as usize) + 1,
         }
     }
 
-    /// Returns the inversion list as a slice
+    /// Returns the inversion list as a [`ZeroVec`]
     ///
     /// Public only to the crate, not exposed to public
-    pub(crate) fn as_inversion_list(&self) -> &[u32] {
+    pub(crate) fn as_inversion_list(&self) -> &ZeroVec<'data, u32> {
         &self.inv_list
     }
 
@@ -146,7 +193,7 @@ impl UnicodeSet {
     /// assert_eq!(None, ex_iter_chars.next());
     /// ```
     pub fn iter_chars(&self) -> impl Iterator<Item = char> + '_ {
-        self.inv_list.chunks(2).flat_map(|pair| (pair[0]..pair[1])).filter_map(char::from_u32)
+        self.iter_ranges().flatten().filter_map(char::from_u32)
     }
 
     /// Yields an [`Iterator`] returning the ranges of the code points that are
@@ -168,7 +215,10 @@ impl UnicodeSet {
     /// assert_eq!(None, example_iter_ranges.next());
     /// ```
     pub fn iter_ranges(&self) -> impl ExactSizeIterator<Item = RangeInclusive<u32>> + '_ {
-        self.inv_list.chunks(2).map(|pair| RangeInclusive::new(pair[0], pair[1] - 1))
+        self.inv_list
+            .as_slice()
+            .chunks(2)
+            .map(|pair| RangeInclusive::new(u32::from_unaligned(&pair[0]), u32::from_unaligned(&pair[1]) - 1))
     }
 
     /// Returns the number of ranges contained in this [`UnicodeSet`]
@@ -294,7 +344,7 @@ impl UnicodeSet {
             return false;
         }
         match self.contains_query(from) {
-            Some(pos) => (till) <= self.inv_list[pos + 1],
+            Some(pos) => matches!(self.inv_list.get(pos + 1), Some(end) if till <= end),
             None => false,
         }
     }
@@ -318,12 +368,12 @@ impl UnicodeSet {
         if set.size() > self.size() {
             return false;
         }
-        let mut set_ranges: Chunks<u32> = set.as_inversion_list().chunks(2);
+        let mut set_ranges = set.iter_ranges();
         let mut check = set_ranges.next();
-        for range in self.inv_list.chunks(2) {
-            match check {
+        for range in self.iter_ranges() {
+            match &check {
                 Some(r) => {
-                    if r[0] >= range[0] && r[1] <= range[1] {
+                    if r.start() >= range.start() && r.end() <= range.end() {
                         check = set_ranges.next();
                     }
                 }
@@ -373,12 +423,13 @@ impl UnicodeSet {
 mod tests {
     use super::{UnicodeSet, UnicodeSetError, BMP_MAX};
     use std::{char, vec::Vec};
+    use zerovec::ZeroVec;
 
     #[test]
     fn test_unicodeset_try_from_vec() {
         let ex = vec![0x2, 0x3, 0x4, 0x5];
         let check = UnicodeSet::from_inversion_list(ex.clone()).unwrap();
-        assert_eq!(ex, check.inv_list);
+        assert_eq!(ex, check.inv_list.to_vec());
         assert_eq!(0x2, check.size());
     }
 
@@ -395,14 +446,14 @@ mod tests {
     #[test]
     fn test_unicodeset_all() {
         let expected = vec![0x0, (char::MAX as u32) + 1];
-        assert_eq!(UnicodeSet::all().inv_list, expected);
+        assert_eq!(UnicodeSet::all().inv_list.to_vec(), expected);
         assert_eq!(UnicodeSet::all().size(), (expected[1] - expected[0]) as usize)
     }
 
     #[test]
     fn test_unicodeset_bmp() {
         let expected = vec![0x0, BMP_MAX + 1];
-        assert_eq!(UnicodeSet::bmp().inv_list, expected);
+        assert_eq!(UnicodeSet::bmp().inv_list.to_vec(), expected);
         assert_eq!(UnicodeSet::bmp().size(), (expected[1] - expected[0]) as usize);
     }
 
@@ -492,13 +543,19 @@ mod tests {
         let check = UnicodeSet::all();
         let expected = (char::MAX as u32) + 1;
         assert_eq!(expected as usize, check.size());
-        let check = UnicodeSet { inv_list: Vec::new(), size: 0 };
+        let check = UnicodeSet {
+            inv_list: ZeroVec::Owned(Vec::new()),
+            size: 0,
+        };
         assert_eq!(check.size(), 0);
     }
 
     #[test]
     fn test_unicodeset_is_empty() {
-        let check = UnicodeSet { inv_list: vec![], size: 0 };
+        let check = UnicodeSet {
+            inv_list: ZeroVec::Owned(vec![]),
+            size: 0,
+        };
         assert!(check.is_empty());
     }
 
@@ -623,4 +680,18 @@ mod tests {
         let act_result: Result<UnicodeSet, serde_json::Error> = serde_json::from_str(inv_list_str);
         assert!(matches!(act_result, Err(_)));
     }
+
+    #[test]
+    fn test_serde_postcard_borrows() {
+        let uniset = UnicodeSet::from_inversion_list(vec![0x41, 0x46, 0x4B, 0x55]).unwrap();
+        let bytes = postcard::to_allocvec(&uniset).unwrap();
+        let act_uniset: UnicodeSet = postcard::from_bytes(&bytes).unwrap();
+        assert!(matches!(act_uniset.inv_list, ZeroVec::Borrowed(_)));
+        assert_eq!(act_uniset, uniset);
+
+        let invalid = ZeroVec::from_aligned(&[0x41u32, 0x46, 0x4B]);
+        let bytes = postcard::to_allocvec(&invalid).unwrap();
+        let act_result: Result<UnicodeSet, postcard::Error> = postcard::from_bytes(&bytes);
+        assert!(act_result.is_err());
+    }
 }
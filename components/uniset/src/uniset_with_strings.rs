@@ -0,0 +1,414 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::RangeBounds;
+use icu_provider::yoke::{self, *};
+use zerovec::VarZeroVec;
+
+use crate::{UnicodeSet, UnicodeSetBuilder, UnicodeSetError};
+
+/// A set of code points and strings, like the exemplar characters of a locale,
+/// which can contain strings like "ch", or a set of emoji, which contains
+/// sequences of several code points.
+///
+/// The code points are stored in a [`UnicodeSet`], and the strings in a sorted
+/// list. A string of exactly one code point is always stored as the code point,
+/// so the strings of the set are the elements that are not a single code point,
+/// including the empty string.
+///
+/// # Examples
+///
+/// ```
+/// use icu::uniset::UnicodeSetWithStringsBuilder;
+///
+/// let mut builder = UnicodeSetWithStringsBuilder::new();
+/// builder.add_range(&('a'..='z'));
+/// builder.add_str("ch");
+/// let set = builder.build();
+///
+/// assert!(set.contains('c'));
+/// assert!(set.contains_str("c"));
+/// assert!(set.contains_str("ch"));
+/// assert!(!set.contains_str("cz"));
+/// assert_eq!(set.span("chocolate", true), 9);
+/// ```
+#[derive(Debug, PartialEq, Clone, Yokeable, ZeroCopyFrom)]
+#[yoke(cloning_zcf)]
+#[cfg_attr(feature = "provider_serde", derive(serde::Serialize))]
+pub struct UnicodeSetWithStrings<'data> {
+    code_points: UnicodeSet<'data>,
+    strings: VarZeroVec<'data, String>,
+}
+
+#[cfg(feature = "provider_serde")]
+impl<'de: 'data, 'data> serde::Deserialize<'de> for UnicodeSetWithStrings<'data> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        struct Parts<'data> {
+            #[serde(borrow)]
+            code_points: UnicodeSet<'data>,
+            #[serde(borrow)]
+            strings: VarZeroVec<'data, String>,
+        }
+
+        let parts = Parts::deserialize(deserializer)?;
+        UnicodeSetWithStrings::try_from_parts(parts.code_points, parts.strings).map_err(|_| serde::de::Error::custom("Invalid string list"))
+    }
+}
+
+impl<'data> UnicodeSetWithStrings<'data> {
+    /// Returns a [`UnicodeSetWithStrings`] from a set of code points and a list of strings.
+    ///
+    /// The strings must be sorted by their UTF-8 bytes, without duplicates, and without
+    /// strings of a single code point, or else [`UnicodeSetError::InvalidStringList`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::{UnicodeSet, UnicodeSetWithStrings};
+    /// use zerovec::VarZeroVec;
+    ///
+    /// let code_points = UnicodeSet::from_inversion_list(vec![0x61, 0x7B]).unwrap();
+    /// let strings = VarZeroVec::from(vec!["ch".to_string(), "ll".to_string()]);
+    /// let set = UnicodeSetWithStrings::try_from_parts(code_points, strings).unwrap();
+    /// assert!(set.contains_str("ll"));
+    ///
+    /// let code_points = UnicodeSet::from_inversion_list(vec![0x61, 0x7B]).unwrap();
+    /// let strings = VarZeroVec::from(vec!["ll".to_string(), "ch".to_string()]);
+    /// assert!(UnicodeSetWithStrings::try_from_parts(code_points, strings).is_err());
+    /// ```
+    pub fn try_from_parts(code_points: UnicodeSet<'data>, strings: VarZeroVec<'data, String>) -> Result<Self, UnicodeSetError> {
+        let set = UnicodeSetWithStrings { code_points, strings };
+        let mut previous: Option<&str> = None;
+        for s in set.iter_strings() {
+            if is_single_code_point(s) || matches!(previous, Some(p) if p >= s) {
+                return Err(UnicodeSetError::InvalidStringList);
+            }
+            previous = Some(s);
+        }
+        Ok(set)
+    }
+
+    /// Returns the code points of the set.
+    pub fn code_points(&self) -> &UnicodeSet<'data> {
+        &self.code_points
+    }
+
+    /// Yields an [`Iterator`] over the strings of the set, sorted by their UTF-8 bytes.
+    pub fn iter_strings(&self) -> impl Iterator<Item = &str> + '_ {
+        (0..self.strings.len()).filter_map(move |i| self.strings.get(i))
+    }
+
+    /// Returns whether the set contains any strings.
+    pub fn has_strings(&self) -> bool {
+        !self.strings.is_empty()
+    }
+
+    /// Returns the number of code points and strings in the set.
+    pub fn size(&self) -> usize {
+        self.code_points.size() + self.strings.len()
+    }
+
+    /// Returns whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.code_points.is_empty() && self.strings.is_empty()
+    }
+
+    /// Returns whether the set contains the code point `c`.
+    pub fn contains(&self, c: char) -> bool {
+        self.code_points.contains(c)
+    }
+
+    /// Returns whether the set contains the string `s`, which is either a string
+    /// of the set, or a single code point in the set.
+    pub fn contains_str(&self, s: &str) -> bool {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => self.code_points.contains(c),
+            _ => self.strings.binary_search(s).is_ok(),
+        }
+    }
+
+    /// Returns the length in bytes of the initial substring of `span_str` that
+    /// consists of elements of the set, if `contained` is true, or that does not
+    /// contain any element of the set, if `contained` is false.
+    ///
+    /// At each position, the longest element of the set that matches is taken,
+    /// so in a set containing "ch" and "cha", the whole of "chcha" is spanned.
+    /// Empty strings in the set are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::UnicodeSetWithStringsBuilder;
+    ///
+    /// let mut builder = UnicodeSetWithStringsBuilder::new();
+    /// builder.add_char('h');
+    /// builder.add_str("ch");
+    /// builder.add_str("cha");
+    /// let set = builder.build();
+    ///
+    /// assert_eq!(set.span("chchax", true), 5);
+    /// assert_eq!(set.span("xyzch", false), 3);
+    /// assert_eq!(set.span("ca", true), 0);
+    /// ```
+    pub fn span(&self, span_str: &str, contained: bool) -> usize {
+        let mut pos = 0;
+        while pos < span_str.len() {
+            let match_len = self.match_len(&span_str[pos..]);
+            if contained {
+                if match_len == 0 {
+                    break;
+                }
+                pos += match_len;
+            } else {
+                if match_len != 0 {
+                    break;
+                }
+                pos += span_str[pos..].chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        pos
+    }
+
+    /// Returns the length in bytes of the longest element of the set at the start of `s`.
+    fn match_len(&self, s: &str) -> usize {
+        let cp_len = match s.chars().next() {
+            Some(c) if self.code_points.contains(c) => c.len_utf8(),
+            _ => 0,
+        };
+        self.iter_strings().filter(|string| s.starts_with(string)).map(str::len).fold(cp_len, usize::max)
+    }
+}
+
+impl<'data> From<UnicodeSet<'data>> for UnicodeSetWithStrings<'data> {
+    fn from(code_points: UnicodeSet<'data>) -> Self {
+        UnicodeSetWithStrings {
+            code_points,
+            strings: VarZeroVec::from(Vec::new()),
+        }
+    }
+}
+
+fn is_single_code_point(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some() && chars.next().is_none()
+}
+
+/// A builder for [`UnicodeSetWithStrings`].
+#[derive(Default)]
+pub struct UnicodeSetWithStringsBuilder {
+    pub(crate) code_points: UnicodeSetBuilder,
+    strings: BTreeSet<String>,
+}
+
+impl UnicodeSetWithStringsBuilder {
+    /// Returns an empty [`UnicodeSetWithStringsBuilder`].
+    pub fn new() -> Self {
+        Self {
+            code_points: UnicodeSetBuilder::new(),
+            strings: BTreeSet::new(),
+        }
+    }
+
+    /// Returns a [`UnicodeSetWithStrings`] and consumes the builder.
+    pub fn build(self) -> UnicodeSetWithStrings<'static> {
+        UnicodeSetWithStrings {
+            code_points: self.code_points.build(),
+            strings: VarZeroVec::from(self.strings.into_iter().collect::<Vec<String>>()),
+        }
+    }
+
+    /// Adds the character to the builder.
+    pub fn add_char(&mut self, c: char) {
+        self.code_points.add_char(c);
+    }
+
+    /// Adds the range of characters to the builder.
+    pub fn add_range(&mut self, range: &impl RangeBounds<char>) {
+        self.code_points.add_range(range);
+    }
+
+    /// Adds the string to the builder. A string of a single code point is added
+    /// as the code point.
+    pub fn add_str(&mut self, s: &str) {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => self.code_points.add_char(c),
+            _ => {
+                self.strings.insert(String::from(s));
+            }
+        }
+    }
+
+    /// Removes the string from the builder. A string of a single code point is
+    /// removed as the code point.
+    pub fn remove_str(&mut self, s: &str) {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => self.code_points.remove_char(c),
+            _ => {
+                self.strings.remove(s);
+            }
+        }
+    }
+
+    /// Adds the code points and strings of the set to the builder.
+    pub fn add_set(&mut self, set: &UnicodeSetWithStrings) {
+        self.code_points.add_set(&set.code_points);
+        self.strings.extend(set.iter_strings().map(String::from));
+    }
+
+    /// Removes the code points and strings of the set from the builder.
+    pub fn remove_set(&mut self, set: &UnicodeSetWithStrings) {
+        self.code_points.remove_set(&set.code_points);
+        for s in set.iter_strings() {
+            self.strings.remove(s);
+        }
+    }
+
+    /// Retains only the code points and strings that are also in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::UnicodeSetWithStringsBuilder;
+    ///
+    /// let mut builder = UnicodeSetWithStringsBuilder::new();
+    /// builder.add_str("ab");
+    /// builder.add_str("b");
+    /// let set = builder.build();
+    ///
+    /// let mut builder = UnicodeSetWithStringsBuilder::new();
+    /// builder.add_range(&('a'..='c'));
+    /// builder.add_str("ab");
+    /// builder.add_str("cd");
+    /// builder.retain_set(&set);
+    /// let set = builder.build();
+    ///
+    /// assert!(set.contains_str("ab"));
+    /// assert!(set.contains_str("b"));
+    /// assert_eq!(set.size(), 2);
+    /// ```
+    pub fn retain_set(&mut self, set: &UnicodeSetWithStrings) {
+        self.code_points.retain_set(&set.code_points);
+        self.strings.retain(|s| set.strings.binary_search(s).is_ok());
+    }
+
+    /// Complements the code points of the builder. Like in ICU4C, the strings
+    /// are not affected; use [`Self::remove_all_strings()`] to remove them.
+    pub fn complement(&mut self) {
+        self.code_points.complement();
+    }
+
+    /// Removes all of the strings from the builder, keeping the code points.
+    pub fn remove_all_strings(&mut self) {
+        self.strings.clear();
+    }
+}
+
+impl From<UnicodeSetBuilder> for UnicodeSetWithStringsBuilder {
+    fn from(code_points: UnicodeSetBuilder) -> Self {
+        Self {
+            code_points,
+            strings: BTreeSet::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    fn set_with_strings(inv_list: Vec<u32>, strings: &[&str]) -> UnicodeSetWithStrings<'static> {
+        let mut builder = UnicodeSetWithStringsBuilder::new();
+        builder.code_points.add_set(&UnicodeSet::from_inversion_list(inv_list).unwrap());
+        for s in strings {
+            builder.add_str(s);
+        }
+        builder.build()
+    }
+
+    #[test]
+    fn test_strings_are_sorted_and_unique() {
+        let set = set_with_strings(vec![], &["cha", "ch", "", "x", "ch", "👨‍👩‍👧"]);
+        assert_eq!(set.iter_strings().collect::<Vec<_>>(), vec!["", "ch", "cha", "👨‍👩‍👧"]);
+        assert!(set.contains('x'));
+        assert!(set.contains_str(""));
+        assert!(set.contains_str("x"));
+        assert!(!set.contains_str("c"));
+        assert_eq!(set.size(), 5);
+    }
+
+    #[test]
+    fn test_try_from_parts() {
+        let code_points = || UnicodeSet::from_inversion_list(vec![0x61, 0x62]).unwrap();
+        let strings = |strings: &[&str]| VarZeroVec::from(strings.iter().map(|s| s.to_string()).collect::<Vec<String>>());
+        assert!(UnicodeSetWithStrings::try_from_parts(code_points(), strings(&["", "ab", "b\u{301}"])).is_ok());
+        assert!(UnicodeSetWithStrings::try_from_parts(code_points(), strings(&["ab", "ab"])).is_err());
+        assert!(UnicodeSetWithStrings::try_from_parts(code_points(), strings(&["b", "ab"])).is_err());
+        assert!(UnicodeSetWithStrings::try_from_parts(code_points(), strings(&["ab", "b"])).is_err());
+    }
+
+    #[test]
+    fn test_span() {
+        let set = set_with_strings(vec![0x61, 0x62], &["", "ab", "abc", "bcd"]);
+        assert_eq!(set.span("", true), 0);
+        assert_eq!(set.span("aaab", true), 4);
+        assert_eq!(set.span("abcabx", true), 5);
+        // The longest match is taken at each position, without backtracking.
+        assert_eq!(set.span("abcd", true), 3);
+        assert_eq!(set.span("xbcda", false), 1);
+        assert_eq!(set.span("xyz", false), 3);
+        assert_eq!(set.span("ä€bcd", false), 5);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let mut builder = UnicodeSetWithStringsBuilder::new();
+        builder.add_set(&set_with_strings(vec![0x61, 0x64], &["ab", "cd"]));
+        builder.add_set(&set_with_strings(vec![0x78, 0x79], &["xy"]));
+        builder.remove_set(&set_with_strings(vec![0x62, 0x63], &["cd"]));
+        builder.remove_str("c");
+        let set = builder.build();
+        assert_eq!(set, set_with_strings(vec![0x61, 0x62, 0x78, 0x79], &["ab", "xy"]));
+
+        let mut builder = UnicodeSetWithStringsBuilder::new();
+        builder.add_set(&set);
+        builder.complement();
+        let complement = builder.build();
+        assert_eq!(complement, set_with_strings(vec![0x0, 0x61, 0x62, 0x78, 0x79, 0x110000], &["ab", "xy"]));
+
+        let mut builder = UnicodeSetWithStringsBuilder::new();
+        builder.add_set(&complement);
+        builder.remove_all_strings();
+        assert!(!builder.build().has_strings());
+    }
+
+    #[cfg(feature = "provider_serde")]
+    #[test]
+    fn test_serde() {
+        let set = set_with_strings(vec![0x61, 0x7B], &["ch", "ll", "👨‍👩‍👧"]);
+
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, r#"{"code_points":[97,123],"strings":["ch","ll","👨‍👩‍👧"]}"#);
+        let from_json: UnicodeSetWithStrings = serde_json::from_str(&json).unwrap();
+        assert_eq!(set, from_json);
+
+        let bytes = postcard::to_allocvec(&set).unwrap();
+        let from_bytes: UnicodeSetWithStrings = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(set, from_bytes);
+
+        let unsorted = r#"{"code_points":[97,123],"strings":["ll","ch"]}"#;
+        assert!(serde_json::from_str::<UnicodeSetWithStrings>(unsorted).is_err());
+    }
+}
@@ -0,0 +1,45 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_provider_uprops::EnumeratedPropertiesDataProvider;
+use icu_uniset::enum_props::{GeneralCategory, Script};
+use icu_uniset::maps::{self, CodePointMap, EnumeratedPropertyValue};
+use std::fmt::Debug;
+
+fn check_sets<T: EnumeratedPropertyValue + Debug>(map: &CodePointMap<T>, values: &[T]) {
+    for &value in values {
+        let set = map.get_set_for_value(value);
+        for cp in 0..=(char::MAX as u32) {
+            assert_eq!(
+                map.get_u32(cp) == value,
+                set.contains_u32(cp),
+                "U+{:04X} in the set of {:?}",
+                cp,
+                value
+            );
+        }
+    }
+}
+
+#[test]
+fn test_get_set_for_value() {
+    let provider =
+        EnumeratedPropertiesDataProvider::new(icu_testdata::paths::data_root().join("uprops"));
+
+    let gc = maps::get_general_category(&provider).expect("The data should be valid");
+    check_sets(
+        &gc,
+        &[
+            GeneralCategory::Unassigned,
+            GeneralCategory::UppercaseLetter,
+            GeneralCategory::OtherLetter,
+        ],
+    );
+
+    let sc = maps::get_script(&provider).expect("The data should be valid");
+    check_sets(
+        &sc,
+        &[Script::Common, Script::Han, Script::Latin, Script::Unknown],
+    );
+}
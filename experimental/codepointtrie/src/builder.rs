@@ -0,0 +1,1244 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A mutable builder for [`CodePointTrie`]s.
+//!
+//! This is a port of ICU4C's `umutablecptrie.cpp`. The compaction steps are
+//! kept identical to ICU4C so that the built trie has exactly the same `index`
+//! and `data` arrays as `umutablecptrie_buildImmutable()` for the same values.
+
+use crate::codepointtrie::{
+    CodePointTrie, CodePointTrieHeader, TrieType, TrieTypeEnum, ValueWidth, ValueWidthEnum,
+};
+use crate::error::Error;
+use crate::impl_const::*;
+use alloc::vec;
+use alloc::vec::Vec;
+use zerovec::ZeroVec;
+
+const UNICODE_LIMIT: u32 = 0x110000;
+const BMP_LIMIT: u32 = 0x10000;
+const ASCII_LIMIT: usize = 0x80;
+
+const I_LIMIT: usize = (UNICODE_LIMIT >> SHIFT_3) as usize;
+const BMP_I_LIMIT: usize = (BMP_LIMIT >> SHIFT_3) as usize;
+const ASCII_I_LIMIT: usize = ASCII_LIMIT >> SHIFT_3;
+
+const SMALL_DATA_BLOCKS_PER_BMP_BLOCK: usize = 1 << (FAST_TYPE_SHIFT as u32 - SHIFT_3);
+
+const FAST_DATA_BLOCK_LENGTH: usize = FAST_TYPE_DATA_BLOCK_LENGTH as usize;
+const SMALL_BLOCK_LENGTH: usize = SMALL_DATA_BLOCK_LENGTH as usize;
+const INDEX_3_LENGTH: usize = INDEX_3_BLOCK_LENGTH as usize;
+const INDEX_3_18BIT_BLOCK_LENGTH: usize = INDEX_3_LENGTH + INDEX_3_LENGTH / 8;
+
+// Flag values for data blocks.
+const ALL_SAME: u8 = 0;
+const MIXED: u8 = 1;
+const SAME_AS: u8 = 2;
+
+// Flag values for index-3 blocks while compacting/building.
+const I3_NULL: u8 = 0;
+const I3_BMP: u8 = 1;
+const I3_16: u8 = 2;
+const I3_18: u8 = 3;
+
+/// A mutable trie of 32-bit values for all Unicode code points, which is
+/// compacted into an immutable [`CodePointTrie`] with [`CodePointTrieBuilder::build`].
+/// See UMutableCPTrie in ICU4C.
+///
+/// Values are always stored with 32 bits while building. When a trie with
+/// narrower values is built, the values are truncated to the `ValueWidth`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_codepointtrie::builder::CodePointTrieBuilder;
+/// use icu_codepointtrie::codepointtrie::{CodePointTrie, Fast};
+///
+/// let mut builder = CodePointTrieBuilder::new(0, 0xff);
+/// builder.set_range(0x41, 0x5a, 1).expect("valid range");
+/// builder.set(0x1f600, 2).expect("valid code point");
+///
+/// let trie: CodePointTrie<u8, Fast> = builder.build().expect("trie fits");
+/// assert_eq!(trie.get(0x40), 0);
+/// assert_eq!(trie.get(0x41), 1);
+/// assert_eq!(trie.get(0x1f600), 2);
+/// assert_eq!(trie.get(0x110000), 0xff);
+/// ```
+pub struct CodePointTrieBuilder {
+    index: Vec<u32>,
+    flags: Vec<u8>,
+    data: Vec<u32>,
+    initial_value: u32,
+    error_value: u32,
+    high_start: u32,
+    high_value: u32,
+    // The following are only set while building.
+    index3_null_offset: i32,
+    data_null_offset: u32,
+    index16: Vec<u16>,
+}
+
+impl CodePointTrieBuilder {
+    /// Creates a builder in which all code points map to `initial_value`.
+    /// Lookups of code points out of range return `error_value`.
+    pub fn new(initial_value: u32, error_value: u32) -> Self {
+        CodePointTrieBuilder {
+            index: vec![0; BMP_I_LIMIT],
+            flags: vec![ALL_SAME; I_LIMIT],
+            data: Vec::new(),
+            initial_value,
+            error_value,
+            high_start: 0,
+            high_value: initial_value,
+            index3_null_offset: -1,
+            data_null_offset: 0,
+            index16: Vec::new(),
+        }
+    }
+
+    /// Returns the value currently set for `code_point`, or the error value if
+    /// `code_point` is not a Unicode code point.
+    pub fn get(&self, code_point: u32) -> u32 {
+        if code_point > CODE_POINT_MAX {
+            return self.error_value;
+        }
+        if code_point >= self.high_start {
+            return self.high_value;
+        }
+        let i = (code_point >> SHIFT_3) as usize;
+        if self.flags[i] == ALL_SAME {
+            self.index[i]
+        } else {
+            self.data[self.index[i] as usize + (code_point & SMALL_DATA_MASK) as usize]
+        }
+    }
+
+    /// Sets the value for `code_point`.
+    pub fn set(&mut self, code_point: u32, value: u32) -> Result<(), Error> {
+        if code_point > CODE_POINT_MAX {
+            return Err(Error::InvalidRange {
+                start: code_point,
+                end: code_point,
+            });
+        }
+        self.ensure_high_start(code_point);
+        let block = self.get_data_block((code_point >> SHIFT_3) as usize);
+        self.data[block + (code_point & SMALL_DATA_MASK) as usize] = value;
+        Ok(())
+    }
+
+    /// Sets the value for all code points in the inclusive range `start..=end`.
+    pub fn set_range(&mut self, start: u32, end: u32, value: u32) -> Result<(), Error> {
+        if start > CODE_POINT_MAX || end > CODE_POINT_MAX || start > end {
+            return Err(Error::InvalidRange { start, end });
+        }
+        self.ensure_high_start(end);
+
+        let mut start = start;
+        let mut limit = end + 1;
+        if start & SMALL_DATA_MASK != 0 {
+            // Set partial block at [start..following block boundary[.
+            let block = self.get_data_block((start >> SHIFT_3) as usize);
+            let next_start = (start + SMALL_DATA_MASK) & !SMALL_DATA_MASK;
+            if next_start <= limit {
+                self.data[block + (start & SMALL_DATA_MASK) as usize..block + SMALL_BLOCK_LENGTH]
+                    .fill(value);
+                start = next_start;
+            } else {
+                self.data[block + (start & SMALL_DATA_MASK) as usize
+                    ..block + (limit & SMALL_DATA_MASK) as usize]
+                    .fill(value);
+                return Ok(());
+            }
+        }
+
+        // Number of positions in the last, partial block.
+        let rest = (limit & SMALL_DATA_MASK) as usize;
+
+        // Round down limit to a block boundary.
+        limit &= !SMALL_DATA_MASK;
+
+        // Iterate over all-value blocks.
+        while start < limit {
+            let i = (start >> SHIFT_3) as usize;
+            if self.flags[i] == ALL_SAME {
+                self.index[i] = value;
+            } else {
+                let block = self.index[i] as usize;
+                self.data[block..block + SMALL_BLOCK_LENGTH].fill(value);
+            }
+            start += SMALL_DATA_BLOCK_LENGTH;
+        }
+
+        if rest > 0 {
+            // Set partial block at [last block boundary..limit[.
+            let block = self.get_data_block((start >> SHIFT_3) as usize);
+            self.data[block..block + rest].fill(value);
+        }
+        Ok(())
+    }
+
+    /// Compacts the data into an immutable [`CodePointTrie`] of type `T` with
+    /// values of width `W`. See `umutablecptrie_buildImmutable()` in ICU4C.
+    ///
+    /// Values that do not fit into `W` are truncated to their lower bits.
+    pub fn build<W: ValueWidth, T: TrieType>(
+        mut self,
+    ) -> Result<CodePointTrie<'static, W, T>, Error> {
+        // The mutable trie always stores 32-bit values.
+        // When we build a trie for a smaller value width, we first mask off unused bits
+        // before compacting the data.
+        match W::ENUM_VALUE {
+            ValueWidthEnum::Bits32 => {}
+            ValueWidthEnum::Bits16 => self.mask_values(0xffff),
+            ValueWidthEnum::Bits8 => self.mask_values(0xff),
+        }
+
+        let fast_limit = match T::ENUM_VALUE {
+            TrieTypeEnum::Fast => BMP_LIMIT,
+            TrieTypeEnum::Small => SMALL_LIMIT,
+        };
+        let mut index_length = self.compact_trie((fast_limit >> SHIFT_3) as usize)?;
+
+        let mut index: Vec<u16> = if self.high_start <= fast_limit {
+            // Condense only the fast index from the mutable-trie index.
+            (0..index_length)
+                .map(|j| self.index[j * SMALL_DATA_BLOCKS_PER_BMP_BLOCK] as u16)
+                .collect()
+        } else {
+            self.index16.truncate(index_length);
+            core::mem::take(&mut self.index16)
+        };
+
+        // Ensure data table alignment: The index length must be even for 32-bit data.
+        if W::ENUM_VALUE == ValueWidthEnum::Bits32 && index_length & 1 != 0 {
+            index.push(0xffee); // arbitrary value
+            index_length += 1;
+        }
+
+        // Make the total trie structure length a multiple of 4 bytes by padding the data table,
+        // and store special values as the last two data values.
+        let error_value = self.error_value;
+        let high_value = self.high_value;
+        let data = &mut self.data;
+        let has_special_values = |data: &[u32]| {
+            data[data.len() - 1] == error_value && data[data.len() - 2] == high_value
+        };
+        match W::ENUM_VALUE {
+            ValueWidthEnum::Bits16 => {
+                if (index_length ^ data.len()) & 1 != 0 {
+                    // padding
+                    data.push(error_value);
+                }
+                if !has_special_values(data) {
+                    data.push(high_value);
+                    data.push(error_value);
+                }
+            }
+            ValueWidthEnum::Bits32 => {
+                // 32-bit data words never need padding to a multiple of 4 bytes.
+                if !has_special_values(data) {
+                    if data[data.len() - 1] != high_value {
+                        data.push(high_value);
+                    }
+                    data.push(error_value);
+                }
+            }
+            ValueWidthEnum::Bits8 => {
+                let mut and3 = (index_length * 2 + data.len()) & 3;
+                if and3 == 0 && has_special_values(data) {
+                    // all set
+                } else if and3 == 3 && data[data.len() - 1] == high_value {
+                    data.push(error_value);
+                } else {
+                    while and3 != 2 {
+                        data.push(high_value);
+                        and3 = (and3 + 1) & 3;
+                    }
+                    data.push(high_value);
+                    data.push(error_value);
+                }
+            }
+        }
+
+        let header = CodePointTrieHeader {
+            index_length: index_length as u32,
+            data_length: self.data.len() as u32,
+            high_start: self.high_start,
+            // Round up shifted12_high_start to a multiple of 0x1000 for easy testing from UTF-8 lead bytes.
+            shifted12_high_start: ((self.high_start + 0xfff) >> 12) as u16,
+            index3_null_offset: self.index3_null_offset as u16,
+            data_null_offset: self.data_null_offset,
+            null_value: self.initial_value,
+        };
+        let data: Vec<W> = self
+            .data
+            .iter()
+            .map(|&value| W::cast_from_widest(value))
+            .collect();
+        CodePointTrie::try_new(
+            header,
+            ZeroVec::from_aligned(&index),
+            ZeroVec::from_aligned(&data),
+        )
+    }
+
+    fn ensure_high_start(&mut self, code_point: u32) {
+        if code_point >= self.high_start {
+            // Round up to a CP_PER_INDEX_2_ENTRY boundary to simplify compaction.
+            let limit = (code_point + CP_PER_INDEX_2_ENTRY) & !(CP_PER_INDEX_2_ENTRY - 1);
+            let i_limit = (limit >> SHIFT_3) as usize;
+            if i_limit > self.index.len() {
+                self.index.resize(I_LIMIT, 0);
+            }
+            for i in (self.high_start >> SHIFT_3) as usize..i_limit {
+                self.flags[i] = ALL_SAME;
+                self.index[i] = self.initial_value;
+            }
+            self.high_start = limit;
+        }
+    }
+
+    fn alloc_data_block(&mut self, block_length: usize) -> usize {
+        let new_block = self.data.len();
+        self.data.resize(new_block + block_length, 0);
+        new_block
+    }
+
+    /// Returns the start of the data block for index block `i`,
+    /// turning it (and its fast-block siblings in the BMP) into a mixed block if necessary.
+    fn get_data_block(&mut self, i: usize) -> usize {
+        if self.flags[i] == MIXED {
+            return self.index[i] as usize;
+        }
+        if i < BMP_I_LIMIT {
+            let mut new_block = self.alloc_data_block(FAST_DATA_BLOCK_LENGTH);
+            let i_start = i & !(SMALL_DATA_BLOCKS_PER_BMP_BLOCK - 1);
+            for i_block in i_start..i_start + SMALL_DATA_BLOCKS_PER_BMP_BLOCK {
+                debug_assert!(self.flags[i_block] == ALL_SAME);
+                let value = self.index[i_block];
+                self.data[new_block..new_block + SMALL_BLOCK_LENGTH].fill(value);
+                self.flags[i_block] = MIXED;
+                self.index[i_block] = new_block as u32;
+                new_block += SMALL_BLOCK_LENGTH;
+            }
+            self.index[i] as usize
+        } else {
+            let new_block = self.alloc_data_block(SMALL_BLOCK_LENGTH);
+            let value = self.index[i];
+            self.data[new_block..new_block + SMALL_BLOCK_LENGTH].fill(value);
+            self.flags[i] = MIXED;
+            self.index[i] = new_block as u32;
+            new_block
+        }
+    }
+
+    fn mask_values(&mut self, mask: u32) {
+        self.initial_value &= mask;
+        self.error_value &= mask;
+        self.high_value &= mask;
+        let i_limit = (self.high_start >> SHIFT_3) as usize;
+        for i in 0..i_limit {
+            if self.flags[i] == ALL_SAME {
+                self.index[i] &= mask;
+            }
+        }
+        for value in self.data.iter_mut() {
+            *value &= mask;
+        }
+    }
+
+    fn find_high_start(&self) -> u32 {
+        let mut i = (self.high_start >> SHIFT_3) as usize;
+        while i > 0 {
+            i -= 1;
+            let is_match = if self.flags[i] == ALL_SAME {
+                self.index[i] == self.high_value
+            } else {
+                let block = self.index[i] as usize;
+                self.data[block..block + SMALL_BLOCK_LENGTH]
+                    .iter()
+                    .all(|&value| value == self.high_value)
+            };
+            if !is_match {
+                return ((i + 1) as u32) << SHIFT_3;
+            }
+        }
+        0
+    }
+
+    /// Returns the index length, and leaves the compacted data in `self.data`.
+    fn compact_trie(&mut self, fast_i_limit: usize) -> Result<usize, Error> {
+        // Find the real high_start and round it up.
+        debug_assert!(self.high_start & (CP_PER_INDEX_2_ENTRY - 1) == 0);
+        self.high_value = self.get(CODE_POINT_MAX);
+        let mut real_high_start = self.find_high_start();
+        real_high_start =
+            (real_high_start + (CP_PER_INDEX_2_ENTRY - 1)) & !(CP_PER_INDEX_2_ENTRY - 1);
+        if real_high_start == UNICODE_LIMIT {
+            self.high_value = self.initial_value;
+        }
+
+        // We always store indexes and data values for the fast range.
+        // Pin high_start to the top of that range while building.
+        let fast_limit = (fast_i_limit as u32) << SHIFT_3;
+        if real_high_start < fast_limit {
+            for i in (real_high_start >> SHIFT_3) as usize..fast_i_limit {
+                self.flags[i] = ALL_SAME;
+                self.index[i] = self.high_value;
+            }
+            self.high_start = fast_limit;
+        } else {
+            self.high_start = real_high_start;
+        }
+
+        let mut ascii_data = [0u32; ASCII_LIMIT];
+        for (c, value) in ascii_data.iter_mut().enumerate() {
+            *value = self.get(c as u32);
+        }
+
+        // First we look for which data blocks have the same value repeated over the whole block,
+        // deduplicate such blocks, find a good null data block (for faster enumeration),
+        // and get an upper bound for the necessary data array length.
+        let mut all_same_blocks = AllSameBlocks::new();
+        let new_data_capacity = self.compact_whole_data_blocks(fast_i_limit, &mut all_same_blocks);
+        let mut new_data = vec![0u32; new_data_capacity];
+        new_data[..ASCII_LIMIT].copy_from_slice(&ascii_data);
+
+        let data_null_index = all_same_blocks.find_most_used();
+
+        let new_data_length = self.compact_data(fast_i_limit, &mut new_data, data_null_index);
+        debug_assert!(new_data_length <= new_data_capacity);
+        new_data.truncate(new_data_length);
+        self.data = new_data;
+        if self.data.len() > (0x3ffff + SMALL_BLOCK_LENGTH) {
+            // The offset of the last data block is too high to be stored in the index table.
+            return Err(Error::Build {
+                reason: "Data array is too long to be indexed",
+            });
+        }
+
+        if let Some(i) = data_null_index {
+            self.data_null_offset = self.index[i];
+            // The null value is the value of the most-used same-value data block.
+            self.initial_value = self.data[self.data_null_offset as usize];
+        } else {
+            self.data_null_offset = NO_DATA_NULL_OFFSET;
+        }
+
+        let index_length = self.compact_index(fast_i_limit)?;
+        self.high_start = real_high_start;
+        Ok(index_length)
+    }
+
+    /// Turns data blocks with a single repeated value into `ALL_SAME` or `SAME_AS` blocks,
+    /// and returns an upper bound for the compacted data length.
+    fn compact_whole_data_blocks(
+        &mut self,
+        fast_i_limit: usize,
+        all_same_blocks: &mut AllSameBlocks,
+    ) -> usize {
+        // ASCII data will be stored as a linear table, even if the following code
+        // does not yet count it that way.
+        let mut new_data_capacity = ASCII_LIMIT;
+        // Add room for a small data null block in case it would match the start of
+        // a fast data block where data_null_offset must not be set in that case.
+        new_data_capacity += SMALL_BLOCK_LENGTH;
+        // Add room for special values (error_value, high_value) and padding.
+        new_data_capacity += 4;
+        let i_limit = (self.high_start >> SHIFT_3) as usize;
+        let mut block_length = FAST_DATA_BLOCK_LENGTH;
+        let mut inc = SMALL_DATA_BLOCKS_PER_BMP_BLOCK;
+        let mut i = 0;
+        while i < i_limit {
+            if i == fast_i_limit {
+                block_length = SMALL_BLOCK_LENGTH;
+                inc = 1;
+            }
+            let mut value = self.index[i];
+            if self.flags[i] == MIXED {
+                // Really mixed?
+                let block = value as usize;
+                value = self.data[block];
+                if all_values_same_as(&self.data[block + 1..block + block_length], value) {
+                    self.flags[i] = ALL_SAME;
+                    self.index[i] = value;
+                    // Fall through to ALL_SAME handling.
+                } else {
+                    new_data_capacity += block_length;
+                    i += inc;
+                    continue;
+                }
+            } else {
+                debug_assert!(self.flags[i] == ALL_SAME);
+                // Do all of the fast-range data block's ALL_SAME parts have the same value?
+                if inc > 1 && !all_values_same_as(&self.index[i + 1..i + inc], value) {
+                    // Turn it into a MIXED block.
+                    self.get_data_block(i);
+                    new_data_capacity += block_length;
+                    i += inc;
+                    continue;
+                }
+            }
+
+            // Is there another ALL_SAME block with the same value?
+            let mut other = all_same_blocks.find_or_add(i, inc, value);
+            if other == AllSameBlock::Overflow {
+                // The fixed-size array overflowed. Slow check for a duplicate block.
+                let mut j_inc = SMALL_DATA_BLOCKS_PER_BMP_BLOCK;
+                let mut j = 0;
+                loop {
+                    if j == i {
+                        all_same_blocks.add(i, inc, value);
+                        break;
+                    }
+                    if j == fast_i_limit {
+                        j_inc = 1;
+                    }
+                    if self.flags[j] == ALL_SAME && self.index[j] == value {
+                        all_same_blocks.add(j, j_inc + inc, value);
+                        other = AllSameBlock::Found(j);
+                        break;
+                        // We could keep counting blocks with the same value
+                        // before we add the first one, which may improve compaction in rare cases,
+                        // but it would make it slower.
+                    }
+                    j += j_inc;
+                }
+            }
+            if let AllSameBlock::Found(j) = other {
+                self.flags[i] = SAME_AS;
+                self.index[i] = j as u32;
+            } else {
+                // New unique same-value block.
+                new_data_capacity += block_length;
+            }
+            i += inc;
+        }
+        new_data_capacity
+    }
+
+    /// Writes the compacted data into `new_data`, which already contains the linear
+    /// ASCII data, and returns its length.
+    fn compact_data(
+        &mut self,
+        fast_i_limit: usize,
+        new_data: &mut [u32],
+        data_null_index: Option<usize>,
+    ) -> usize {
+        // The linear ASCII data has been copied into new_data already.
+        let mut new_data_length = 0;
+        let mut i = 0;
+        while new_data_length < ASCII_LIMIT {
+            self.index[i] = new_data_length as u32;
+            new_data_length += FAST_DATA_BLOCK_LENGTH;
+            i += SMALL_DATA_BLOCKS_PER_BMP_BLOCK;
+        }
+
+        let mut block_length = FAST_DATA_BLOCK_LENGTH;
+        let mut mixed_blocks = MixedBlocks::new(new_data.len(), block_length);
+        mixed_blocks.extend(new_data, 0, 0, new_data_length);
+
+        let i_limit = (self.high_start >> SHIFT_3) as usize;
+        let mut inc = SMALL_DATA_BLOCKS_PER_BMP_BLOCK;
+        let mut fast_length = 0;
+        let mut i = ASCII_I_LIMIT;
+        while i < i_limit {
+            if i == fast_i_limit {
+                block_length = SMALL_BLOCK_LENGTH;
+                inc = 1;
+                fast_length = new_data_length;
+                mixed_blocks = MixedBlocks::new(new_data.len(), block_length);
+                mixed_blocks.extend(new_data, 0, 0, new_data_length);
+            }
+            if self.flags[i] == ALL_SAME {
+                let value = self.index[i];
+                // Find an earlier part of the data array of length block_length
+                // that is filled with this value.
+                let mut n = mixed_blocks.find_all_same_block(new_data, value);
+                // If we find a match, and the current block is the data null block,
+                // and it is not a fast block but matches the start of a fast block,
+                // then we need to continue looking.
+                // This is because this small block is shorter than the fast block,
+                // and not all of the rest of the fast block is filled with this value.
+                // Otherwise trie.getRange() would detect that the fast block starts at
+                // data_null_offset and assume incorrectly that it is filled with the null value.
+                while let Some(found) = n {
+                    if data_null_index == Some(i)
+                        && i >= fast_i_limit
+                        && found < fast_length
+                        && is_start_of_some_fast_block(found as u32, &self.index, fast_i_limit)
+                    {
+                        n = find_all_same_block(
+                            new_data,
+                            found + 1,
+                            new_data_length,
+                            value,
+                            block_length,
+                        );
+                    } else {
+                        break;
+                    }
+                }
+                if let Some(found) = n {
+                    self.index[i] = found as u32;
+                } else {
+                    let mut n =
+                        get_all_same_overlap(new_data, new_data_length, value, block_length);
+                    self.index[i] = (new_data_length - n) as u32;
+                    let prev_data_length = new_data_length;
+                    while n < block_length {
+                        new_data[new_data_length] = value;
+                        new_data_length += 1;
+                        n += 1;
+                    }
+                    mixed_blocks.extend(new_data, 0, prev_data_length, new_data_length);
+                }
+            } else if self.flags[i] == MIXED {
+                let block = self.index[i] as usize;
+                if let Some(found) = mixed_blocks.find_block(new_data, &self.data, block) {
+                    self.index[i] = found as u32;
+                } else {
+                    let mut n =
+                        get_overlap(new_data, new_data_length, &self.data, block, block_length);
+                    self.index[i] = (new_data_length - n) as u32;
+                    let prev_data_length = new_data_length;
+                    while n < block_length {
+                        new_data[new_data_length] = self.data[block + n];
+                        new_data_length += 1;
+                        n += 1;
+                    }
+                    mixed_blocks.extend(new_data, 0, prev_data_length, new_data_length);
+                }
+            } else {
+                debug_assert!(self.flags[i] == SAME_AS);
+                let j = self.index[i] as usize;
+                self.index[i] = self.index[j];
+            }
+            i += inc;
+        }
+        new_data_length
+    }
+
+    /// Builds the multi-stage index in `self.index16` if one is needed,
+    /// and returns the index length.
+    #[allow(clippy::many_single_char_names)]
+    fn compact_index(&mut self, fast_i_limit: usize) -> Result<usize, Error> {
+        let fast_index_length = fast_i_limit >> (FAST_TYPE_SHIFT as u32 - SHIFT_3);
+        if (self.high_start >> FAST_TYPE_SHIFT) as usize <= fast_index_length {
+            // Only the linear fast index, no multi-stage index tables.
+            self.index3_null_offset = NO_INDEX3_NULL_OFFSET as i32;
+            return Ok(fast_index_length);
+        }
+
+        // Condense the fast index table.
+        // Also, does it contain an index-3 block with all data_null_offset?
+        let mut fast_index = vec![0u16; fast_index_length];
+        let mut i3_first_null: i32 = -1;
+        let mut i = 0;
+        let mut j = 0;
+        while i < fast_i_limit {
+            let mut i3 = self.index[i];
+            fast_index[j] = i3 as u16;
+            if i3 == self.data_null_offset {
+                if i3_first_null < 0 {
+                    i3_first_null = j as i32;
+                } else if self.index3_null_offset < 0
+                    && (j as i32 - i3_first_null + 1) == INDEX_3_LENGTH as i32
+                {
+                    self.index3_null_offset = i3_first_null;
+                }
+            } else {
+                i3_first_null = -1;
+            }
+            // Set the index entries that compact_data() skipped.
+            // Needed when the multi-stage index covers the fast index range as well.
+            let i_next = i + SMALL_DATA_BLOCKS_PER_BMP_BLOCK;
+            i += 1;
+            while i < i_next {
+                i3 += SMALL_DATA_BLOCK_LENGTH;
+                self.index[i] = i3;
+                i += 1;
+            }
+            j += 1;
+        }
+
+        let mut mixed_blocks = MixedBlocks::new(fast_index_length, INDEX_3_LENGTH);
+        mixed_blocks.extend(&fast_index, 0, 0, fast_index_length);
+
+        // Examine index-3 blocks. For each determine one of:
+        // - same as the index-3 null block
+        // - same as a fast-index block
+        // - 16-bit indexes
+        // - 18-bit indexes
+        // We store this in the first flags entry for the index-3 block.
+        //
+        // Also determine an upper limit for the index-3 table length.
+        let mut index3_capacity = 0;
+        i3_first_null = self.index3_null_offset;
+        let mut has_long_i3_blocks = false;
+        // If the fast index covers the whole BMP, then
+        // the multi-stage index is only for supplementary code points.
+        // Otherwise, the multi-stage index covers all of Unicode.
+        let i_start = if fast_i_limit < BMP_I_LIMIT {
+            0
+        } else {
+            BMP_I_LIMIT
+        };
+        let i_limit = (self.high_start >> SHIFT_3) as usize;
+        let mut i = i_start;
+        while i < i_limit {
+            let j_limit = i + INDEX_3_LENGTH;
+            let mut ored_i3 = 0;
+            let mut is_null = true;
+            for &i3 in &self.index[i..j_limit] {
+                ored_i3 |= i3;
+                if i3 != self.data_null_offset {
+                    is_null = false;
+                }
+            }
+            if is_null {
+                self.flags[i] = I3_NULL;
+                if i3_first_null < 0 {
+                    if ored_i3 <= 0xffff {
+                        index3_capacity += INDEX_3_LENGTH;
+                    } else {
+                        index3_capacity += INDEX_3_18BIT_BLOCK_LENGTH;
+                        has_long_i3_blocks = true;
+                    }
+                    i3_first_null = 0;
+                }
+            } else if ored_i3 <= 0xffff {
+                if let Some(n) = mixed_blocks.find_block(&fast_index, &self.index, i) {
+                    self.flags[i] = I3_BMP;
+                    self.index[i] = n as u32;
+                } else {
+                    self.flags[i] = I3_16;
+                    index3_capacity += INDEX_3_LENGTH;
+                }
+            } else {
+                self.flags[i] = I3_18;
+                index3_capacity += INDEX_3_18BIT_BLOCK_LENGTH;
+                has_long_i3_blocks = true;
+            }
+            i = j_limit;
+        }
+
+        let index2_capacity = (i_limit - i_start) >> SHIFT_2_3;
+
+        // Length of the index-1 table, rounded up.
+        let index1_length = (index2_capacity + INDEX_2_MASK as usize) >> SHIFT_1_2;
+
+        // Index table: Fast index, index-1, index-3, index-2.
+        // +1 for possible index table padding.
+        let index16_capacity =
+            fast_index_length + index1_length + index3_capacity + index2_capacity + 1;
+        let mut index16 = vec![0u16; index16_capacity];
+        index16[..fast_index_length].copy_from_slice(&fast_index);
+
+        let mut mixed_blocks = MixedBlocks::new(index16_capacity, INDEX_3_LENGTH);
+        let mut long_i3_blocks = if has_long_i3_blocks {
+            Some(MixedBlocks::new(
+                index16_capacity,
+                INDEX_3_18BIT_BLOCK_LENGTH,
+            ))
+        } else {
+            None
+        };
+
+        // Compact the index-3 table and write an uncompacted version of the index-2 table.
+        let mut index2 = vec![0u16; index2_capacity];
+        let mut i2_length = 0;
+        i3_first_null = self.index3_null_offset;
+        let index3_start = fast_index_length + index1_length;
+        let mut index_length = index3_start;
+        let mut i = i_start;
+        while i < i_limit {
+            let i3;
+            let mut f = self.flags[i];
+            if f == I3_NULL && i3_first_null < 0 {
+                // First index-3 null block. Write & overlap it like a normal block, then remember it.
+                f = if self.data_null_offset <= 0xffff {
+                    I3_16
+                } else {
+                    I3_18
+                };
+                i3_first_null = 0;
+            }
+            if f == I3_NULL {
+                i3 = self.index3_null_offset as usize;
+            } else if f == I3_BMP {
+                i3 = self.index[i] as usize;
+            } else if f == I3_16 {
+                if let Some(n) = mixed_blocks.find_block(&index16, &self.index, i) {
+                    i3 = n;
+                } else {
+                    let mut n = if index_length == index3_start {
+                        // No overlap at the boundary between the index-1 and index-3 tables.
+                        0
+                    } else {
+                        get_overlap(&index16, index_length, &self.index, i, INDEX_3_LENGTH)
+                    };
+                    i3 = index_length - n;
+                    let prev_index_length = index_length;
+                    while n < INDEX_3_LENGTH {
+                        index16[index_length] = self.index[i + n] as u16;
+                        index_length += 1;
+                        n += 1;
+                    }
+                    mixed_blocks.extend(&index16, index3_start, prev_index_length, index_length);
+                    if let Some(long_i3_blocks) = long_i3_blocks.as_mut() {
+                        long_i3_blocks.extend(
+                            &index16,
+                            index3_start,
+                            prev_index_length,
+                            index_length,
+                        );
+                    }
+                }
+            } else {
+                debug_assert!(f == I3_18);
+                // Encode an index-3 block that contains one or more data indexes exceeding 16 bits.
+                let mut j = i;
+                let j_limit = i + INDEX_3_LENGTH;
+                let mut k = index_length;
+                while j < j_limit {
+                    k += 1;
+                    let mut upper_bits = 0;
+                    for shift in (2..=16).step_by(2) {
+                        let v = self.index[j];
+                        j += 1;
+                        upper_bits |= (v & 0x30000) >> shift;
+                        index16[k] = v as u16;
+                        k += 1;
+                    }
+                    index16[k - 9] = upper_bits as u16;
+                }
+                let long_i3_blocks = match long_i3_blocks.as_mut() {
+                    Some(long_i3_blocks) => long_i3_blocks,
+                    None => unreachable!("18-bit index-3 blocks were counted above"),
+                };
+                if let Some(n) = long_i3_blocks.find_block(&index16, &index16, index_length) {
+                    i3 = n | 0x8000;
+                } else {
+                    let mut n = if index_length == index3_start {
+                        // No overlap at the boundary between the index-1 and index-3 tables.
+                        0
+                    } else {
+                        get_overlap(
+                            &index16,
+                            index_length,
+                            &index16,
+                            index_length,
+                            INDEX_3_18BIT_BLOCK_LENGTH,
+                        )
+                    };
+                    i3 = (index_length - n) | 0x8000;
+                    let prev_index_length = index_length;
+                    if n > 0 {
+                        let start = index_length;
+                        while n < INDEX_3_18BIT_BLOCK_LENGTH {
+                            index16[index_length] = index16[start + n];
+                            index_length += 1;
+                            n += 1;
+                        }
+                    } else {
+                        index_length += INDEX_3_18BIT_BLOCK_LENGTH;
+                    }
+                    mixed_blocks.extend(&index16, index3_start, prev_index_length, index_length);
+                    long_i3_blocks.extend(&index16, index3_start, prev_index_length, index_length);
+                }
+            }
+            if self.index3_null_offset < 0 && i3_first_null >= 0 {
+                self.index3_null_offset = i3 as i32;
+            }
+            // Set the index-2 table entry.
+            index2[i2_length] = i3 as u16;
+            i2_length += 1;
+            i += INDEX_3_LENGTH;
+        }
+        debug_assert!(i2_length == index2_capacity);
+        debug_assert!(index_length <= index3_start + index3_capacity);
+
+        if self.index3_null_offset < 0 {
+            self.index3_null_offset = NO_INDEX3_NULL_OFFSET as i32;
+        }
+        if index_length >= (NO_INDEX3_NULL_OFFSET + INDEX_3_BLOCK_LENGTH) as usize {
+            // The index-3 offsets exceed 15 bits, or
+            // the last one cannot be distinguished from the no-null-block value.
+            return Err(Error::Build {
+                reason: "Index-3 offsets do not fit into 15 bits",
+            });
+        }
+
+        // Compact the index-2 table and write the index-1 table.
+        // The index-2 and index-3 blocks have the same length, so mixed_blocks can be reused.
+        let mut block_length = INDEX_2_BLOCK_LENGTH as usize;
+        let mut i1 = fast_index_length;
+        let mut i = 0;
+        while i < i2_length {
+            let n = if i2_length - i >= block_length {
+                // normal block
+                mixed_blocks.find_block(&index16, &index2, i)
+            } else {
+                // high_start is inside the last index-2 block. Shorten it.
+                block_length = i2_length - i;
+                find_same_block(
+                    &index16,
+                    index3_start,
+                    index_length,
+                    &index2[i..i + block_length],
+                )
+            };
+            let i2 = if let Some(n) = n {
+                n
+            } else {
+                let mut n = if index_length == index3_start {
+                    // No overlap at the boundary between the index-1 and index-3/2 tables.
+                    0
+                } else {
+                    get_overlap(&index16, index_length, &index2, i, block_length)
+                };
+                let i2 = index_length - n;
+                let prev_index_length = index_length;
+                while n < block_length {
+                    index16[index_length] = index2[i + n];
+                    index_length += 1;
+                    n += 1;
+                }
+                mixed_blocks.extend(&index16, index3_start, prev_index_length, index_length);
+                i2
+            };
+            // Set the index-1 table entry.
+            index16[i1] = i2 as u16;
+            i1 += 1;
+            i += block_length;
+        }
+        debug_assert!(i1 == index3_start);
+        debug_assert!(index_length <= index16_capacity);
+
+        self.index16 = index16;
+        Ok(index_length)
+    }
+}
+
+fn all_values_same_as(values: &[u32], value: u32) -> bool {
+    values.iter().all(|&v| v == value)
+}
+
+fn equal_blocks<A: Copy + Into<u32>, B: Copy + Into<u32>>(a: &[A], b: &[B]) -> bool {
+    a.iter().zip(b).all(|(&a, &b)| a.into() == b.into())
+}
+
+/// Returns the start of the first block of `block_length` copies of `value`
+/// in `data[start..limit]`.
+fn find_all_same_block(
+    data: &[u32],
+    start: usize,
+    limit: usize,
+    value: u32,
+    block_length: usize,
+) -> Option<usize> {
+    if limit < block_length {
+        return None;
+    }
+    // Ensure that we do not even partially get past limit.
+    let limit = limit - block_length;
+    let mut block = start;
+    while block <= limit {
+        if data[block] == value {
+            let mut i = 1;
+            loop {
+                if i == block_length {
+                    return Some(block);
+                }
+                if data[block + i] != value {
+                    block += i;
+                    break;
+                }
+                i += 1;
+            }
+        }
+        block += 1;
+    }
+    None
+}
+
+/// Returns the start of the first block in `data[start..limit]` equal to `block`.
+fn find_same_block(data: &[u16], start: usize, limit: usize, block: &[u16]) -> Option<usize> {
+    if limit < block.len() {
+        return None;
+    }
+    // Ensure that we do not even partially get past limit.
+    let limit = limit - block.len();
+    (start..=limit).find(|&p| data[p..p + block.len()] == *block)
+}
+
+/// Returns the number of trailing values of `data[..length]` that equal `value`,
+/// up to `block_length - 1`.
+fn get_all_same_overlap(data: &[u32], length: usize, value: u32, block_length: usize) -> usize {
+    let min = length - (block_length - 1);
+    let mut i = length;
+    while min < i && data[i - 1] == value {
+        i -= 1;
+    }
+    length - i
+}
+
+/// Returns the length of the longest overlap of the end of `data[..length]`
+/// with the start of `block[block_start..block_start + block_length]`.
+fn get_overlap<A: Copy + Into<u32>, B: Copy + Into<u32>>(
+    data: &[A],
+    length: usize,
+    block: &[B],
+    block_start: usize,
+    block_length: usize,
+) -> usize {
+    let mut overlap = block_length - 1;
+    debug_assert!(overlap <= length);
+    while overlap > 0
+        && !equal_blocks(
+            &data[length - overlap..length],
+            &block[block_start..block_start + overlap],
+        )
+    {
+        overlap -= 1;
+    }
+    overlap
+}
+
+fn is_start_of_some_fast_block(data_offset: u32, index: &[u32], fast_i_limit: usize) -> bool {
+    index[..fast_i_limit]
+        .iter()
+        .step_by(SMALL_DATA_BLOCKS_PER_BMP_BLOCK)
+        .any(|&i3| i3 == data_offset)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum AllSameBlock {
+    /// Index of an earlier block with the same value.
+    Found(usize),
+    NewUnique,
+    Overflow,
+}
+
+/// Tracks a fixed number of `ALL_SAME` data blocks with distinct values,
+/// and how often each is used.
+struct AllSameBlocks {
+    length: usize,
+    most_recent: Option<usize>,
+    indexes: [usize; AllSameBlocks::CAPACITY],
+    values: [u32; AllSameBlocks::CAPACITY],
+    ref_counts: [usize; AllSameBlocks::CAPACITY],
+}
+
+impl AllSameBlocks {
+    const CAPACITY: usize = 32;
+
+    fn new() -> Self {
+        AllSameBlocks {
+            length: 0,
+            most_recent: None,
+            indexes: [0; Self::CAPACITY],
+            values: [0; Self::CAPACITY],
+            ref_counts: [0; Self::CAPACITY],
+        }
+    }
+
+    fn find_or_add(&mut self, index: usize, count: usize, value: u32) -> AllSameBlock {
+        if let Some(most_recent) = self.most_recent {
+            if self.values[most_recent] == value {
+                self.ref_counts[most_recent] += count;
+                return AllSameBlock::Found(self.indexes[most_recent]);
+            }
+        }
+        for i in 0..self.length {
+            if self.values[i] == value {
+                self.most_recent = Some(i);
+                self.ref_counts[i] += count;
+                return AllSameBlock::Found(self.indexes[i]);
+            }
+        }
+        if self.length == Self::CAPACITY {
+            return AllSameBlock::Overflow;
+        }
+        self.most_recent = Some(self.length);
+        self.indexes[self.length] = index;
+        self.values[self.length] = value;
+        self.ref_counts[self.length] = count;
+        self.length += 1;
+        AllSameBlock::NewUnique
+    }
+
+    /// Replaces the block which has the lowest reference count.
+    fn add(&mut self, index: usize, count: usize, value: u32) {
+        debug_assert!(self.length == Self::CAPACITY);
+        let mut least = 0;
+        let mut least_count = I_LIMIT;
+        for i in 0..self.length {
+            debug_assert!(self.values[i] != value);
+            if self.ref_counts[i] < least_count {
+                least = i;
+                least_count = self.ref_counts[i];
+            }
+        }
+        self.most_recent = Some(least);
+        self.indexes[least] = index;
+        self.values[least] = value;
+        self.ref_counts[least] = count;
+    }
+
+    fn find_most_used(&self) -> Option<usize> {
+        let mut max = None;
+        let mut max_count = 0;
+        for i in 0..self.length {
+            if self.ref_counts[i] > max_count {
+                max = Some(self.indexes[i]);
+                max_count = self.ref_counts[i];
+            }
+        }
+        max
+    }
+}
+
+/// A hash table of the blocks of a fixed length starting at every position of a
+/// data array, used to find the first occurrence of a block in that array.
+struct MixedBlocks {
+    // The length is a prime number, larger than the maximum data length.
+    // The "shift" lower bits store a data index + 1.
+    // The remaining upper bits store a partial hash code of the block data values.
+    table: Vec<u32>,
+    shift: u32,
+    mask: u32,
+    block_length: usize,
+}
+
+impl MixedBlocks {
+    fn new(max_length: usize, block_length: usize) -> Self {
+        // We store actual data indexes + 1 to reserve 0 for empty entries.
+        let max_data_index = max_length as isize - block_length as isize + 1;
+        let (length, shift, mask) = if max_data_index <= 0xfff {
+            // 4k
+            (6007, 12, 0xfff)
+        } else if max_data_index <= 0x7fff {
+            // 32k
+            (50021, 15, 0x7fff)
+        } else if max_data_index <= 0x1ffff {
+            // 128k
+            (200003, 17, 0x1ffff)
+        } else {
+            // max_data_index up to around UNICODE_LIMIT, ca. 1.1M
+            (1500007, 21, 0x1fffff)
+        };
+        MixedBlocks {
+            table: vec![0; length],
+            shift,
+            mask,
+            block_length,
+        }
+    }
+
+    /// Adds the blocks starting at `min_start` or later which end in
+    /// `data[prev_data_length..new_data_length]`.
+    fn extend<T: Copy + Into<u32>>(
+        &mut self,
+        data: &[T],
+        min_start: usize,
+        prev_data_length: usize,
+        new_data_length: usize,
+    ) {
+        let mut start = if prev_data_length >= min_start + self.block_length {
+            // Skip the last block that we added last time.
+            prev_data_length - self.block_length + 1
+        } else {
+            // Begin with the first full block.
+            min_start
+        };
+        while start + self.block_length <= new_data_length {
+            let hash_code = self.make_hash_code(data, start);
+            self.add_entry(data, start, hash_code);
+            start += 1;
+        }
+    }
+
+    fn find_block<A: Copy + Into<u32>, B: Copy + Into<u32>>(
+        &self,
+        data: &[A],
+        block_data: &[B],
+        block_start: usize,
+    ) -> Option<usize> {
+        let hash_code = self.make_hash_code(block_data, block_start);
+        self.find_entry(data, block_data, block_start, hash_code)
+            .map(|entry_index| self.data_index(self.table[entry_index]))
+            .ok()
+    }
+
+    fn find_all_same_block(&self, data: &[u32], block_value: u32) -> Option<usize> {
+        let mut hash_code = block_value;
+        for _ in 1..self.block_length {
+            hash_code = hash_code.wrapping_mul(37).wrapping_add(block_value);
+        }
+        self.probe(hash_code, |data_index| {
+            all_values_same_as(
+                &data[data_index..data_index + self.block_length],
+                block_value,
+            )
+        })
+        .map(|entry_index| self.data_index(self.table[entry_index]))
+        .ok()
+    }
+
+    fn make_hash_code<T: Copy + Into<u32>>(&self, block_data: &[T], block_start: usize) -> u32 {
+        let block = &block_data[block_start..block_start + self.block_length];
+        let mut hash_code: u32 = block[0].into();
+        for &value in &block[1..] {
+            hash_code = hash_code.wrapping_mul(37).wrapping_add(value.into());
+        }
+        hash_code
+    }
+
+    fn add_entry<T: Copy + Into<u32>>(&mut self, data: &[T], block_start: usize, hash_code: u32) {
+        debug_assert!(block_start < self.mask as usize);
+        if let Err(entry_index) = self.find_entry(data, data, block_start, hash_code) {
+            self.table[entry_index] = (hash_code << self.shift) | (block_start as u32 + 1);
+        }
+    }
+
+    /// Returns the table index of the entry for an equal block,
+    /// or else the index of the empty entry where it would be added.
+    fn find_entry<A: Copy + Into<u32>, B: Copy + Into<u32>>(
+        &self,
+        data: &[A],
+        block_data: &[B],
+        block_start: usize,
+        hash_code: u32,
+    ) -> Result<usize, usize> {
+        let block = &block_data[block_start..block_start + self.block_length];
+        self.probe(hash_code, |data_index| {
+            equal_blocks(&data[data_index..data_index + self.block_length], block)
+        })
+    }
+
+    fn probe(&self, hash_code: u32, is_equal: impl Fn(usize) -> bool) -> Result<usize, usize> {
+        let shifted_hash_code = hash_code << self.shift;
+        let length = self.table.len();
+        let initial_entry_index = (hash_code % (length as u32 - 1)) as usize + 1; // 1..length-1
+        let mut entry_index = initial_entry_index;
+        loop {
+            let entry = self.table[entry_index];
+            if entry == 0 {
+                return Err(entry_index);
+            }
+            if (entry & !self.mask) == shifted_hash_code && is_equal(self.data_index(entry)) {
+                return Ok(entry_index);
+            }
+            entry_index = (entry_index + initial_entry_index) % length;
+        }
+    }
+
+    fn data_index(&self, entry: u32) -> usize {
+        ((entry & self.mask) - 1) as usize
+    }
+}
@@ -6,7 +6,7 @@
 
 use crate::error::Error;
 use crate::impl_const::*;
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 use zerovec::ZeroVec;
 
 // Enums
@@ -47,6 +47,8 @@ pub trait ValueWidth: Copy + zerovec::ule::AsULE {
     /// which is stored that in the `data` array.
     const DATA_GET_ERROR_VALUE: Self;
     fn cast_to_widest(self) -> u32;
+    /// Truncates a 32-bit value to this width.
+    fn cast_from_widest(n: u32) -> Self;
 }
 
 impl ValueWidth for u8 {
@@ -56,6 +58,10 @@ impl ValueWidth for u8 {
     fn cast_to_widest(self) -> u32 {
         self as u32
     }
+
+    fn cast_from_widest(n: u32) -> Self {
+        n as u8
+    }
 }
 
 impl ValueWidth for u16 {
@@ -65,6 +71,10 @@ impl ValueWidth for u16 {
     fn cast_to_widest(self) -> u32 {
         self as u32
     }
+
+    fn cast_from_widest(n: u32) -> Self {
+        n as u16
+    }
 }
 
 impl ValueWidth for u32 {
@@ -74,6 +84,10 @@ impl ValueWidth for u32 {
     fn cast_to_widest(self) -> u32 {
         self
     }
+
+    fn cast_from_widest(n: u32) -> Self {
+        n
+    }
 }
 
 // TrieType trait
@@ -95,6 +109,7 @@ pub trait TrieType {
 
 /// An empty struct to represent "fast" type code point tries for the
 ///  [`TrieType`] trait. The "fast max" limit is set to 0xffff.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Fast;
 
 impl TrieType for Fast {
@@ -104,6 +119,7 @@ impl TrieType for Fast {
 
 /// An empty struct to represent "small" type code point tries for the
 ///  [`TrieType`] trait. The "fast max" limit is set to 0x0fff.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Small;
 
 impl TrieType for Small {
@@ -117,14 +133,27 @@ impl TrieType for Small {
 /// For more information:
 /// - [ICU Site design doc](http://site.icu-project.org/design/struct/utrie)
 /// - [ICU User Guide section on Properties lookup](https://unicode-org.github.io/icu/userguide/strings/properties.html#lookup)
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CodePointTrie<'trie, W: ValueWidth, T: TrieType> {
     header: CodePointTrieHeader,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     index: ZeroVec<'trie, u16>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            bound(deserialize = "ZeroVec<'trie, W>: serde::Deserialize<'de>")
+        )
+    )]
     data: ZeroVec<'trie, W>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     _marker_ty: PhantomData<T>,
 }
 
 /// This struct contains the fixed-length header fields of a [`CodePointTrie`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CodePointTrieHeader {
     /// Length of the trie's `index` array
     pub index_length: u32,
@@ -157,6 +186,20 @@ pub struct CodePointTrieHeader {
     pub null_value: u32,
 }
 
+impl<'trie, W: ValueWidth, T: TrieType> Clone for CodePointTrie<'trie, W, T>
+where
+    <W as zerovec::ule::AsULE>::ULE: Clone,
+{
+    fn clone(&self) -> Self {
+        CodePointTrie {
+            header: self.header,
+            index: self.index.clone(),
+            data: self.data.clone(),
+            _marker_ty: PhantomData,
+        }
+    }
+}
+
 impl<'trie, W: ValueWidth, T: TrieType> CodePointTrie<'trie, W, T> {
     /// Returns a new [`CodePointTrie`] backed by borrowed data for the `index`
     /// array and `data` array, whose data values have width `W`, for a trie
@@ -248,7 +291,7 @@ impl<'trie, W: ValueWidth, T: TrieType> CodePointTrie<'trie, W, T> {
             } else {
                 return self.trie_error_val_index();
             };
-            data_block = ((data_block << (2 + (2 * index3_pos))) as u32 & 0x30000) as u32;
+            data_block = (data_block << (2 + (2 * index3_pos))) & 0x30000;
             index3_block += 1;
             data_block =
                 if let Some(index3_val) = self.index.get((index3_block + index3_pos) as usize) {
@@ -337,4 +380,193 @@ impl<'trie, W: ValueWidth, T: TrieType> CodePointTrie<'trie, W, T> {
     pub fn get_u32(&self, code_point: u32) -> u32 {
         self.get(code_point).cast_to_widest()
     }
+
+    /// Returns the range of code points that starts at `start` and ends at
+    /// the last code point that has the same value in this [`CodePointTrie`],
+    /// with that value, or `None` if `start` is not a code point.
+    ///
+    /// Blocks of the `index` and `data` arrays that are shared by consecutive
+    /// ranges of code points, like the null blocks, are only checked once,
+    /// which makes this much faster than calling
+    /// [`get`](CodePointTrie::get) for each code point.
+    /// See ucptrie_getRange in ICU4C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_codepointtrie::planes;
+    /// let trie = planes::get_planes_trie();
+    /// let range = trie.get_range(0x41).unwrap();
+    /// assert_eq!((0x41, 0xffff, 0), (range.start, range.end, range.value));
+    /// let range = trie.get_range(0x10044).unwrap();
+    /// assert_eq!((0x10044, 0x1ffff, 1), (range.start, range.end, range.value));
+    /// assert!(trie.get_range(0x110000).is_none());
+    /// ```
+    pub fn get_range(&self, start: u32) -> Option<CodePointMapRange<W>> {
+        if start > CODE_POINT_MAX {
+            return None;
+        }
+        let value = self.get_u32(start);
+        let range = |end: u32| {
+            Some(CodePointMapRange {
+                start,
+                end,
+                value: W::cast_from_widest(value),
+            })
+        };
+        let high_start = self.header.high_start;
+        if start >= high_start {
+            return range(CODE_POINT_MAX);
+        }
+
+        let null_value = self.header.null_value;
+        let mut prev_index3_block: Option<u32> = None;
+        let mut prev_data_block: Option<u32> = None;
+        let mut c = start;
+        while c < high_start {
+            let index3_block: u32;
+            let mut index3_pos: u32;
+            let index3_block_length: u32;
+            let data_block_length: u32;
+            if c <= 0xffff
+                && (T::ENUM_VALUE == TrieTypeEnum::Fast || c <= SMALL_TYPE_FAST_INDEXING_MAX)
+            {
+                index3_block = 0;
+                index3_pos = c >> FAST_TYPE_SHIFT;
+                index3_block_length = if T::ENUM_VALUE == TrieTypeEnum::Fast {
+                    BMP_INDEX_LENGTH
+                } else {
+                    SMALL_INDEX_LENGTH
+                };
+                data_block_length = FAST_TYPE_DATA_BLOCK_LENGTH;
+            } else {
+                let mut index1_pos = c >> SHIFT_1;
+                if T::ENUM_VALUE == TrieTypeEnum::Fast {
+                    index1_pos = index1_pos + BMP_INDEX_LENGTH - OMITTED_BMP_INDEX_1_LENGTH;
+                } else {
+                    index1_pos += SMALL_INDEX_LENGTH;
+                }
+                index3_block =
+                    self.index_at(self.index_at(index1_pos) + ((c >> SHIFT_2) & INDEX_2_MASK));
+                if prev_index3_block == Some(index3_block) && c - start >= CP_PER_INDEX_2_ENTRY {
+                    // The index-3 block is the same as the previous one, which
+                    // only had `value`.
+                    c += CP_PER_INDEX_2_ENTRY;
+                    continue;
+                }
+                prev_index3_block = Some(index3_block);
+                if index3_block == self.header.index3_null_offset as u32 {
+                    if value != null_value {
+                        return range(c - 1);
+                    }
+                    prev_data_block = Some(self.header.data_null_offset);
+                    c = (c + CP_PER_INDEX_2_ENTRY) & !(CP_PER_INDEX_2_ENTRY - 1);
+                    continue;
+                }
+                index3_pos = (c >> SHIFT_3) & INDEX_3_MASK;
+                index3_block_length = INDEX_3_BLOCK_LENGTH;
+                data_block_length = SMALL_DATA_BLOCK_LENGTH;
+            }
+
+            // Check the data blocks of the index-3 block.
+            while index3_pos < index3_block_length {
+                let data_block = if index3_block & 0x8000 == 0 {
+                    // 16-bit indexes
+                    self.index_at(index3_block + index3_pos)
+                } else {
+                    // 18-bit indexes stored in groups of 9 entries per 8 indexes.
+                    let group = (index3_block & 0x7fff) + (index3_pos & !7) + (index3_pos >> 3);
+                    let group_pos = index3_pos & 7;
+                    ((self.index_at(group) << (2 + (2 * group_pos))) & 0x30000)
+                        | self.index_at(group + 1 + group_pos)
+                };
+                let data_mask = data_block_length - 1;
+                if prev_data_block == Some(data_block) && c - start >= data_block_length {
+                    // The data block is the same as the previous one, which
+                    // only had `value`.
+                    c += data_block_length;
+                } else if data_block == self.header.data_null_offset {
+                    if value != null_value {
+                        return range(c - 1);
+                    }
+                    prev_data_block = Some(data_block);
+                    c = (c + data_block_length) & !data_mask;
+                } else {
+                    prev_data_block = Some(data_block);
+                    let mut data_pos = data_block + (c & data_mask);
+                    loop {
+                        if self.data_at(data_pos) != value {
+                            return range(c - 1);
+                        }
+                        c += 1;
+                        data_pos += 1;
+                        if c & data_mask == 0 {
+                            break;
+                        }
+                    }
+                }
+                index3_pos += 1;
+            }
+        }
+
+        if self.data_at(self.header.data_length - HIGH_VALUE_NEG_DATA_OFFSET) != value {
+            range(c - 1)
+        } else {
+            range(CODE_POINT_MAX)
+        }
+    }
+
+    /// Returns an iterator over the ranges of code points from U+0000 to
+    /// U+10FFFF with the same value in this [`CodePointTrie`], as returned by
+    /// [`get_range`](CodePointTrie::get_range).
+    pub fn iter_ranges(&self) -> CodePointMapRangeIterator<'_, 'trie, W, T> {
+        CodePointMapRangeIterator {
+            trie: self,
+            start: 0,
+        }
+    }
+
+    /// Returns the element of the `index` array at `pos`. The position of a
+    /// missing element, in an invalid trie, is returned as 0, so that ranges
+    /// can still be returned.
+    fn index_at(&self, pos: u32) -> u32 {
+        self.index.get(pos as usize).map_or(0, u32::from)
+    }
+
+    /// Returns the element of the `data` array at `pos` as a `u32`.
+    fn data_at(&self, pos: u32) -> u32 {
+        self.data
+            .get(pos as usize)
+            .unwrap_or(W::DATA_GET_ERROR_VALUE)
+            .cast_to_widest()
+    }
+}
+
+/// A range of code points with the same value in a [`CodePointTrie`], as
+/// returned by [`CodePointTrie::get_range`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CodePointMapRange<W> {
+    /// The first code point of the range.
+    pub start: u32,
+    /// The last code point of the range.
+    pub end: u32,
+    /// The value of all code points of the range.
+    pub value: W,
+}
+
+/// An iterator over the ranges of code points with the same value in a
+/// [`CodePointTrie`], returned by [`CodePointTrie::iter_ranges`].
+pub struct CodePointMapRangeIterator<'a, 'trie, W: ValueWidth, T: TrieType> {
+    trie: &'a CodePointTrie<'trie, W, T>,
+    start: u32,
+}
+
+impl<W: ValueWidth, T: TrieType> Iterator for CodePointMapRangeIterator<'_, '_, W, T> {
+    type Item = CodePointMapRange<W>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range = self.trie.get_range(self.start)?;
+        self.start = range.end + 1;
+        Some(range)
+    }
 }
@@ -2,10 +2,17 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use thiserror::Error;
+use displaydoc::Display;
 
-#[derive(Error, Debug, PartialEq)]
+#[derive(Display, Debug, PartialEq)]
 pub enum Error {
-    #[error("Could not construct CodePointTrie from deserialized values: {reason}")]
+    #[displaydoc("Could not construct CodePointTrie from deserialized values: {reason}")]
     FromDeserialized { reason: &'static str },
+    #[displaydoc("Invalid code point range for CodePointTrieBuilder: {start:#x}..={end:#x}")]
+    InvalidRange { start: u32, end: u32 },
+    #[displaydoc("Could not build CodePointTrie: {reason}")]
+    Build { reason: &'static str },
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
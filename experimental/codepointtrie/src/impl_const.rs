@@ -59,7 +59,7 @@ pub const INDEX_2_BLOCK_LENGTH: u32 = 1 << SHIFT_1_2;
 pub const INDEX_2_MASK: u32 = INDEX_2_BLOCK_LENGTH - 1;
 
 /// Number of code points per index-2 table entry. 512=0x200
-pub const _CP_PER_INDEX_2_ENTRY: u32 = 1 << SHIFT_2;
+pub const CP_PER_INDEX_2_ENTRY: u32 = 1 << SHIFT_2;
 
 /// Number of entries in an index-3 block. 32=0x20
 pub const INDEX_3_BLOCK_LENGTH: u32 = 1 << SHIFT_2_3;
@@ -74,3 +74,9 @@ pub const SMALL_DATA_BLOCK_LENGTH: u32 = 1 << SHIFT_3;
 pub const SMALL_DATA_MASK: u32 = SMALL_DATA_BLOCK_LENGTH - 1;
 
 pub const CODE_POINT_MAX: u32 = 0x10ffff;
+
+/// The index3_null_offset value if there is no index-3 block for the null data block.
+pub const NO_INDEX3_NULL_OFFSET: u32 = 0x7fff;
+
+/// The data_null_offset value if there is no data block for the null value.
+pub const NO_DATA_NULL_OFFSET: u32 = 0xfffff;
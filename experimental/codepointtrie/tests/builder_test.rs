@@ -0,0 +1,87 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+mod test_util;
+
+use icu_codepointtrie::builder::CodePointTrieBuilder;
+use icu_codepointtrie::codepointtrie::{CodePointTrie, Small};
+use icu_codepointtrie::error::Error;
+
+#[test]
+fn code_point_trie_builder_test() {
+    test_util::run_builder_test_from_test_data("tests/testdata/free-blocks.16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/free-blocks.32.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/free-blocks.8.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/free-blocks.small16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/grow-data.16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/grow-data.32.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/grow-data.8.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/grow-data.small16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set1.16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set1.32.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set1.8.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set1.small16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set2-overlap.16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set2-overlap.32.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set2-overlap.small16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set3-initial-9.16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set3-initial-9.32.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set3-initial-9.8.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set3-initial-9.small16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set-empty.16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set-empty.32.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set-empty.8.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set-empty.small16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set-single-value.16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set-single-value.32.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set-single-value.8.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/set-single-value.small16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/short-all-same.16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/short-all-same.8.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/short-all-same.small16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/small0-in-fast.16.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/small0-in-fast.32.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/small0-in-fast.8.toml");
+    test_util::run_builder_test_from_test_data("tests/testdata/small0-in-fast.small16.toml");
+}
+
+#[test]
+fn code_point_trie_builder_set_test() {
+    let mut builder = CodePointTrieBuilder::new(7, 0xbad);
+    assert_eq!(builder.get(0x61), 7);
+    assert_eq!(builder.get(0x110000), 0xbad);
+
+    builder.set_range(0x60, 0x7a, 1).unwrap();
+    builder.set(0x61, 2).unwrap();
+    builder.set_range(0x10fff0, 0x10ffff, 3).unwrap();
+    assert_eq!(builder.get(0x5f), 7);
+    assert_eq!(builder.get(0x60), 1);
+    assert_eq!(builder.get(0x61), 2);
+    assert_eq!(builder.get(0x7a), 1);
+    assert_eq!(builder.get(0x10ffff), 3);
+
+    assert_eq!(
+        builder.set(0x110000, 1),
+        Err(Error::InvalidRange {
+            start: 0x110000,
+            end: 0x110000
+        })
+    );
+    assert_eq!(
+        builder.set_range(0x7a, 0x60, 1),
+        Err(Error::InvalidRange {
+            start: 0x7a,
+            end: 0x60
+        })
+    );
+
+    let trie: CodePointTrie<u8, Small> = builder.build().unwrap();
+    assert_eq!(trie.get(0x5f), 7);
+    assert_eq!(trie.get(0x61), 2);
+    assert_eq!(trie.get(0x7a), 1);
+    assert_eq!(trie.get(0x10ffef), 7);
+    assert_eq!(trie.get(0x10ffff), 3);
+    // The error value is truncated to the value width.
+    assert_eq!(trie.get(0x110000), 0xad);
+}
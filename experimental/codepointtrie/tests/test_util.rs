@@ -2,6 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use icu_codepointtrie::builder::CodePointTrieBuilder;
 use icu_codepointtrie::codepointtrie::*;
 use icu_codepointtrie::error::Error;
 use std::fs::File;
@@ -28,6 +29,27 @@ pub fn check_trie<W: ValueWidth, T: TrieType>(trie: &CodePointTrie<W, T>, check_
             i += 1;
         }
     }
+
+    // Check the ranges of the trie, where adjacent check ranges with the same
+    // value are a single range
+    let mut expected_ranges: Vec<(u32, u32, u32)> = Vec::new();
+    let mut start: u32 = 0;
+    for range_tuple in check_ranges.chunks(2) {
+        let (range_end, range_value) = (range_tuple[0], range_tuple[1]);
+        if range_end == start {
+            continue;
+        }
+        match expected_ranges.last_mut() {
+            Some(last) if last.2 == range_value => last.1 = range_end - 1,
+            _ => expected_ranges.push((start, range_end - 1, range_value)),
+        }
+        start = range_end;
+    }
+    let ranges: Vec<(u32, u32, u32)> = trie
+        .iter_ranges()
+        .map(|range| (range.start, range.end, range.value.cast_to_widest()))
+        .collect();
+    assert_eq!(expected_ranges, ranges);
 }
 
 /// Converts the serialized `u8` value for the trie type into a [`TrieTypeEnum`].
@@ -274,3 +296,133 @@ pub fn run_deserialize_test_from_test_data(test_file_path: &str) {
         }
     };
 }
+
+/// Builds a trie with [`CodePointTrieBuilder`] from the "check ranges" and
+/// asserts that it is identical to the `expected` trie built by ICU4C.
+pub fn check_built_trie<W, T>(
+    expected: &CodePointTrie<W, T>,
+    null_value: u32,
+    error_value: u32,
+    check_ranges: &[u32],
+) where
+    W: ValueWidth + std::fmt::Debug + PartialEq,
+    T: TrieType + std::fmt::Debug + PartialEq,
+{
+    let mut builder = CodePointTrieBuilder::new(null_value, error_value);
+    let mut start = 0;
+    for range_tuple in check_ranges.chunks(2) {
+        let range_limit = range_tuple[0];
+        if range_limit > start {
+            builder
+                .set_range(start, range_limit - 1, range_tuple[1])
+                .expect("valid range");
+        }
+        start = range_limit;
+    }
+    let actual: CodePointTrie<W, T> = builder.build().expect("trie is buildable");
+    assert_eq!(expected, &actual);
+}
+
+// Given a .toml file dumped from ICU4C test data for UCPTrie, build a trie
+// with the same values using `CodePointTrieBuilder`, and test that its header,
+// `index` array and `data` array are identical to those of the ICU4C trie.
+#[allow(dead_code)]
+pub fn run_builder_test_from_test_data(test_file_path: &str) {
+    let path = Path::new(test_file_path);
+    let display = path.display();
+
+    let mut file = match File::open(path) {
+        Err(err) => panic!("couldn't open {}: {}", display, err),
+        Ok(file) => file,
+    };
+
+    let mut toml_str = String::new();
+
+    if let Err(err) = file.read_to_string(&mut toml_str) {
+        panic!("couldn't read {}: {}", display, err)
+    }
+
+    let test_file: TestFile = toml::from_str(&toml_str).unwrap();
+    let test_struct = test_file.code_point_trie.trie_struct;
+    let check_ranges = &test_file.code_point_trie.test_data.check_ranges;
+
+    println!(
+        "Running CodePointTrieBuilder test on test data file: {}",
+        test_struct.name
+    );
+
+    let trie_header = CodePointTrieHeader {
+        index_length: test_struct.index_length,
+        data_length: test_struct.data_length,
+        high_start: test_struct.high_start,
+        shifted12_high_start: test_struct.shifted12_high_start,
+        index3_null_offset: test_struct.index3_null_offset,
+        data_null_offset: test_struct.data_null_offset,
+        null_value: test_struct.null_value,
+    };
+    let null_value = test_struct.null_value;
+
+    let index = ZeroVec::from_aligned(&test_struct.index);
+
+    let trie_type_enum = get_code_point_trie_type_enum(test_struct.trie_type_enum_val);
+
+    // The error value is always stored as the last value of the data array.
+    match (
+        test_struct.data_8,
+        test_struct.data_16,
+        test_struct.data_32,
+        trie_type_enum,
+    ) {
+        (Some(data_8), _, _, Some(TrieTypeEnum::Fast)) => {
+            let error_value = *data_8.last().unwrap() as u32;
+            let data = ZeroVec::from_aligned(&data_8);
+            let trie: CodePointTrie<u8, Fast> =
+                CodePointTrie::try_new(trie_header, index, data).unwrap();
+            check_built_trie(&trie, null_value, error_value, check_ranges);
+        }
+
+        (_, Some(data_16), _, Some(TrieTypeEnum::Fast)) => {
+            let error_value = *data_16.last().unwrap() as u32;
+            let data = ZeroVec::from_aligned(&data_16);
+            let trie: CodePointTrie<u16, Fast> =
+                CodePointTrie::try_new(trie_header, index, data).unwrap();
+            check_built_trie(&trie, null_value, error_value, check_ranges);
+        }
+
+        (_, _, Some(data_32), Some(TrieTypeEnum::Fast)) => {
+            let error_value = *data_32.last().unwrap();
+            let data = ZeroVec::from_aligned(&data_32);
+            let trie: CodePointTrie<u32, Fast> =
+                CodePointTrie::try_new(trie_header, index, data).unwrap();
+            check_built_trie(&trie, null_value, error_value, check_ranges);
+        }
+
+        (Some(data_8), _, _, Some(TrieTypeEnum::Small)) => {
+            let error_value = *data_8.last().unwrap() as u32;
+            let data = ZeroVec::from_aligned(&data_8);
+            let trie: CodePointTrie<u8, Small> =
+                CodePointTrie::try_new(trie_header, index, data).unwrap();
+            check_built_trie(&trie, null_value, error_value, check_ranges);
+        }
+
+        (_, Some(data_16), _, Some(TrieTypeEnum::Small)) => {
+            let error_value = *data_16.last().unwrap() as u32;
+            let data = ZeroVec::from_aligned(&data_16);
+            let trie: CodePointTrie<u16, Small> =
+                CodePointTrie::try_new(trie_header, index, data).unwrap();
+            check_built_trie(&trie, null_value, error_value, check_ranges);
+        }
+
+        (_, _, Some(data_32), Some(TrieTypeEnum::Small)) => {
+            let error_value = *data_32.last().unwrap();
+            let data = ZeroVec::from_aligned(&data_32);
+            let trie: CodePointTrie<u32, Small> =
+                CodePointTrie::try_new(trie_header, index, data).unwrap();
+            check_built_trie(&trie, null_value, error_value, check_ranges);
+        }
+
+        (_, _, _, _) => {
+            panic!("Could not match test trie data to a known value width or trie type");
+        }
+    };
+}
@@ -0,0 +1,23 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_provider::prelude::DataError;
+
+/// A list of possible error outcomes for the [`DisplayNames`](crate::DisplayNames) struct.
+#[derive(Display, Debug)]
+pub enum DisplayNamesError {
+    /// An error originating inside of the [`DataProvider`](icu_provider::DataProvider)
+    #[displaydoc("Data provider error: {0}")]
+    DataProvider(DataError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DisplayNamesError {}
+
+impl From<DataError> for DisplayNamesError {
+    fn from(e: DataError) -> Self {
+        DisplayNamesError::DataProvider(e)
+    }
+}
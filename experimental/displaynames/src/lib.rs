@@ -0,0 +1,257 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_displaynames`](crate) provides the names of languages, scripts, regions and variants
+//! in a locale, and composes the names of whole locales, like "German (Switzerland)".
+//!
+//! The names of locales follow the locale display name algorithm of CLDR: the name of the
+//! language is qualified by the names of the script, region, variants and Unicode extension
+//! keywords of the locale. With [`LanguageDisplay::Dialect`], a language is named together with
+//! its script or region where the locale has a name for the combination, like "Swiss High
+//! German" for "de-CH".
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_displaynames::options::{DisplayNamesOptions, LanguageDisplay};
+//! use icu_displaynames::DisplayNames;
+//! use icu_locid_macros::{langid, region};
+//!
+//! let provider = icu_testdata::get_provider();
+//!
+//! let display_names = DisplayNames::try_new(langid!("en"), &provider, Default::default())
+//!     .expect("Data should load successfully");
+//!
+//! assert_eq!(display_names.region_name(region!("CH")), Some("Switzerland"));
+//! assert_eq!(display_names.locale_name(&langid!("de-CH").into()), "Swiss High German");
+//!
+//! let options = DisplayNamesOptions {
+//!     language_display: LanguageDisplay::Standard,
+//!     ..Default::default()
+//! };
+//! let display_names = DisplayNames::try_new(langid!("en"), &provider, options)
+//!     .expect("Data should load successfully");
+//!
+//! assert_eq!(
+//!     display_names.locale_name(&langid!("de-CH").into()),
+//!     "German (Switzerland)"
+//! );
+//! ```
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod error;
+pub mod options;
+pub mod provider;
+
+pub use error::DisplayNamesError;
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use icu_locid::subtags::{Language, Region, Script, Variant};
+use icu_locid::{LanguageIdentifier, Locale};
+use icu_provider::prelude::*;
+use options::{DisplayNamesOptions, LanguageDisplay};
+use provider::*;
+use writeable::Writeable;
+
+/// The names of languages, scripts, regions, variants and locales in a locale.
+///
+/// See the crate-level documentation for examples.
+pub struct DisplayNames<'data> {
+    languages: DataPayload<'data, DisplayNamesV1Marker>,
+    scripts: DataPayload<'data, DisplayNamesV1Marker>,
+    regions: DataPayload<'data, DisplayNamesV1Marker>,
+    variants: DataPayload<'data, DisplayNamesV1Marker>,
+    locale: DataPayload<'data, LocaleDisplayNamesV1Marker>,
+    options: DisplayNamesOptions,
+}
+
+impl<'data> DisplayNames<'data> {
+    /// Creates a new [`DisplayNames`] from locale data.
+    pub fn try_new<T, D>(
+        locale: T,
+        data_provider: &D,
+        options: DisplayNamesOptions,
+    ) -> Result<Self, DisplayNamesError>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, DisplayNamesV1Marker>
+            + DataProvider<'data, LocaleDisplayNamesV1Marker>
+            + ?Sized,
+    {
+        let langid: LanguageIdentifier = locale.into().into();
+        let request = |key| DataRequest {
+            resource_path: ResourcePath {
+                key,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid.clone()),
+                },
+            },
+        };
+        let load = |key| -> Result<DataPayload<'data, DisplayNamesV1Marker>, DataError> {
+            data_provider.load_payload(&request(key))?.take_payload()
+        };
+        Ok(Self {
+            languages: load(key::LANGUAGES_V1)?,
+            scripts: load(key::SCRIPTS_V1)?,
+            regions: load(key::REGIONS_V1)?,
+            variants: load(key::VARIANTS_V1)?,
+            locale: data_provider
+                .load_payload(&request(key::LOCALE_V1))?
+                .take_payload()?,
+            options,
+        })
+    }
+
+    /// Returns the name of a language, like "German" for "de".
+    pub fn language_name(&self, language: Language) -> Option<&str> {
+        self.languages
+            .get()
+            .get(language.as_str(), self.options.style)
+    }
+
+    /// Returns the name of a script, like "Cyrillic" for "Cyrl".
+    pub fn script_name(&self, script: Script) -> Option<&str> {
+        self.scripts.get().get(script.as_str(), self.options.style)
+    }
+
+    /// Returns the name of a region, like "Switzerland" for "CH".
+    pub fn region_name(&self, region: Region) -> Option<&str> {
+        self.regions.get().get(region.as_str(), self.options.style)
+    }
+
+    /// Returns the name of a variant, like "Valencian" for "valencia".
+    pub fn variant_name(&self, variant: Variant) -> Option<&str> {
+        self.variants
+            .get()
+            .get(variant.as_str(), self.options.style)
+    }
+
+    /// Returns the name of a locale, like "German (Switzerland)" for "de-CH".
+    ///
+    /// The subtags and Unicode extension keywords without a name in the data are named by
+    /// their codes, like "xyz" for the language "xyz" or "Calendar: chinese" for "ca-chinese".
+    ///
+    /// More information: <https://unicode.org/reports/tr35/tr35-general.html#locale_display_name_algorithm>
+    pub fn locale_name(&self, locale: &Locale) -> String {
+        let id = &locale.id;
+        let style = self.options.style;
+        let mut script = id.script;
+        let mut region = id.region;
+
+        let mut language_name = None;
+        if self.options.language_display == LanguageDisplay::Dialect {
+            // The combinations of the language with its script and region, from the most to
+            // the least specific.
+            let combinations = [(script, region), (None, region), (script, None)];
+            for (combined_script, combined_region) in combinations.iter().copied() {
+                if combined_script.is_none() && combined_region.is_none() {
+                    continue;
+                }
+                let code = LanguageIdentifier {
+                    language: id.language,
+                    script: combined_script,
+                    region: combined_region,
+                    variants: Default::default(),
+                }
+                .to_string();
+                if let Some(name) = self.languages.get().get(&code, style) {
+                    language_name = Some(name);
+                    if combined_script.is_some() {
+                        script = None;
+                    }
+                    if combined_region.is_some() {
+                        region = None;
+                    }
+                    break;
+                }
+            }
+        }
+        let language_name = language_name
+            .or_else(|| self.language_name(id.language))
+            .unwrap_or_else(|| id.language.as_str());
+
+        let mut qualifiers: Vec<Cow<str>> = Vec::new();
+        if let Some(script) = &script {
+            qualifiers.push(Cow::Borrowed(
+                self.script_name(*script).unwrap_or_else(|| script.as_str()),
+            ));
+        }
+        if let Some(region) = &region {
+            qualifiers.push(Cow::Borrowed(
+                self.region_name(*region).unwrap_or_else(|| region.as_str()),
+            ));
+        }
+        for variant in id.variants.iter() {
+            qualifiers.push(Cow::Borrowed(
+                self.variant_name(*variant)
+                    .unwrap_or_else(|| variant.as_str()),
+            ));
+        }
+        let locale_names = self.locale.get();
+        for (key, value) in locale.extensions.unicode.keywords.iter() {
+            let mut value = value.writeable_to_string();
+            if value.is_empty() {
+                // A key without a type has the type "true".
+                value = String::from("true");
+            }
+            let qualifier = match locale_names
+                .types
+                .get(format!("{}-{}", key.as_str(), value).as_str())
+            {
+                Some(name) => Cow::Borrowed(&**name),
+                None => {
+                    let key_name = locale_names
+                        .keys
+                        .get(key.as_str())
+                        .map_or(key.as_str(), |name| &**name);
+                    Cow::Owned(
+                        locale_names
+                            .key_type_pattern
+                            .interpolate([key_name, value.as_str()])
+                            .writeable_to_string(),
+                    )
+                }
+            };
+            qualifiers.push(qualifier);
+        }
+
+        let mut qualifiers = qualifiers.into_iter().map(replace_parentheses);
+        let first = match qualifiers.next() {
+            Some(first) => first,
+            None => return language_name.to_string(),
+        };
+        let qualifiers = qualifiers.fold(first, |joined, qualifier| {
+            locale_names
+                .separator
+                .interpolate([&*joined, &*qualifier])
+                .writeable_to_string()
+        });
+        locale_names
+            .pattern
+            .interpolate([language_name, qualifiers.as_str()])
+            .writeable_to_string()
+    }
+}
+
+/// Replaces the parentheses in a qualifier by brackets, like "Myanmar [Burma]" for
+/// "Myanmar (Burma)", so that they do not nest in the parentheses of the locale pattern.
+fn replace_parentheses(qualifier: Cow<str>) -> String {
+    qualifier
+        .chars()
+        .map(|c| match c {
+            '(' => '[',
+            ')' => ']',
+            '（' => '［',
+            '）' => '］',
+            c => c,
+        })
+        .collect()
+}
@@ -0,0 +1,57 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`DisplayNames`](crate::DisplayNames).
+
+/// A bag of options defining how display names are selected and composed.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::{DisplayNamesOptions, LanguageDisplay, Style};
+///
+/// let options = DisplayNamesOptions {
+///     style: Style::Short,
+///     language_display: LanguageDisplay::Standard,
+/// };
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub struct DisplayNamesOptions {
+    /// The length of the names.
+    pub style: Style,
+    /// How the language and the script or region of a locale are named.
+    pub language_display: LanguageDisplay,
+}
+
+/// The length of display names.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Style {
+    /// The full names, like "United Kingdom".
+    Long,
+    /// The short names where a locale has them, like "UK", and the full names otherwise.
+    Short,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self::Long
+    }
+}
+
+/// How the language and the script or region of a locale are named.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum LanguageDisplay {
+    /// Names the language together with its script or region where the locale has a name for
+    /// the combination, like "British English" for "en-GB".
+    Dialect,
+    /// Names the language on its own and qualifies it with its script and region, like
+    /// "English (United Kingdom)" for "en-GB".
+    Standard,
+}
+
+impl Default for LanguageDisplay {
+    fn default() -> Self {
+        Self::Dialect
+    }
+}
@@ -0,0 +1,101 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use crate::options::Style;
+use alloc::borrow::Cow;
+use icu_pattern::{DoublePlaceholder, TypedPattern};
+use icu_provider::yoke::{self, *};
+use litemap::LiteMap;
+
+pub mod key {
+    //! Resource keys for [`icu_displaynames`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: the names of languages, like "German" for "de", and of languages with a
+    /// script or region, like "Swiss High German" for "de-CH".
+    pub const LANGUAGES_V1: ResourceKey = resource_key!(DisplayNames, "languages", 1);
+
+    /// Resource key: the names of scripts, like "Cyrillic" for "Cyrl".
+    pub const SCRIPTS_V1: ResourceKey = resource_key!(DisplayNames, "scripts", 1);
+
+    /// Resource key: the names of regions, like "Switzerland" for "CH".
+    pub const REGIONS_V1: ResourceKey = resource_key!(DisplayNames, "regions", 1);
+
+    /// Resource key: the names of variants, like "Valencian" for "valencia".
+    pub const VARIANTS_V1: ResourceKey = resource_key!(DisplayNames, "variants", 1);
+
+    /// Resource key: the patterns composing the names of locales, and the names of the keys and
+    /// types of their Unicode extensions.
+    pub const LOCALE_V1: ResourceKey = resource_key!(DisplayNames, "locale", 1);
+}
+
+/// The names of the codes of one kind, like languages or regions.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-general.html#Display_Name_Elements>
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct DisplayNamesV1<'data> {
+    /// The names by their codes, like "United Kingdom" for "GB".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub names: LiteMap<Cow<'data, str>, Cow<'data, str>>,
+    /// The short names by their codes, like "UK" for "GB", for the codes whose short names
+    /// differ from their names.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub short_names: LiteMap<Cow<'data, str>, Cow<'data, str>>,
+}
+
+impl<'data> DisplayNamesV1<'data> {
+    /// Returns the name of a code in a [`Style`], falling back to the long name of the code
+    /// for [`Style::Short`].
+    pub fn get(&self, code: &str, style: Style) -> Option<&str> {
+        let short_name = match style {
+            Style::Short => self.short_names.get(code),
+            Style::Long => None,
+        };
+        short_name
+            .or_else(|| self.names.get(code))
+            .map(|name| &**name)
+    }
+}
+
+/// The patterns composing the names of locales, like "German (Switzerland)", and the names of
+/// the keys and types of Unicode extensions.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-general.html#locale_display_name_algorithm>
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct LocaleDisplayNamesV1<'data> {
+    /// The pattern qualifying the name of a language, like "{0} ({1})", with the name of the
+    /// language as `{0}` and the qualifiers as `{1}`.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub pattern: TypedPattern<'data, DoublePlaceholder>,
+    /// The pattern joining two qualifiers, like "{0}, {1}".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub separator: TypedPattern<'data, DoublePlaceholder>,
+    /// The pattern of the keywords of Unicode extensions without a name of their own, like
+    /// "{0}: {1}", with the name of the key as `{0}` and the type as `{1}`.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub key_type_pattern: TypedPattern<'data, DoublePlaceholder>,
+    /// The names of the keys of Unicode extensions, like "Calendar" for "ca".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub keys: LiteMap<Cow<'data, str>, Cow<'data, str>>,
+    /// The names of the keywords of Unicode extensions by their keys and types joined by a
+    /// hyphen, like "Gregorian Calendar" for "ca-gregory".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub types: LiteMap<Cow<'data, str>, Cow<'data, str>>,
+}
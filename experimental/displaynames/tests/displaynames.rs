@@ -0,0 +1,131 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_displaynames::options::{DisplayNamesOptions, LanguageDisplay, Style};
+use icu_displaynames::DisplayNames;
+use icu_locid::{LanguageIdentifier, Locale};
+use icu_locid_macros::{langid, language, region, script, variant};
+
+fn display_names(
+    langid: LanguageIdentifier,
+    style: Style,
+    language_display: LanguageDisplay,
+) -> DisplayNames<'static> {
+    let provider = icu_testdata::get_provider();
+    let options = DisplayNamesOptions {
+        style,
+        language_display,
+    };
+    DisplayNames::try_new(langid, &provider, options).unwrap()
+}
+
+fn assert_locale_name(display_names: &DisplayNames<'_>, locale: &str, expected: &str) {
+    let locale: Locale = locale.parse().unwrap();
+    assert_eq!(display_names.locale_name(&locale), expected, "{}", locale);
+}
+
+#[test]
+fn test_subtag_names() {
+    let long = display_names(langid!("en"), Style::Long, LanguageDisplay::Dialect);
+    assert_eq!(long.language_name(language!("de")), Some("German"));
+    assert_eq!(long.script_name(script!("Cyrl")), Some("Cyrillic"));
+    assert_eq!(long.region_name(region!("GB")), Some("United Kingdom"));
+    assert_eq!(long.variant_name(variant!("valencia")), Some("Valencian"));
+    assert_eq!(long.language_name(language!("xyz")), None);
+    assert_eq!(long.region_name(region!("QQ")), None);
+
+    let short = display_names(langid!("en"), Style::Short, LanguageDisplay::Dialect);
+    assert_eq!(short.region_name(region!("GB")), Some("UK"));
+    assert_eq!(short.region_name(region!("CH")), Some("Switzerland"));
+}
+
+#[test]
+fn test_dialect() {
+    let names = display_names(langid!("en"), Style::Long, LanguageDisplay::Dialect);
+    assert_locale_name(&names, "en", "English");
+    assert_locale_name(&names, "en-GB", "British English");
+    assert_locale_name(&names, "de-CH", "Swiss High German");
+    assert_locale_name(&names, "de-DE", "German (Germany)");
+    assert_locale_name(&names, "zh-Hans", "Simplified Chinese");
+    assert_locale_name(&names, "zh-Hans-CN", "Simplified Chinese (China)");
+    assert_locale_name(&names, "en-Latn-US", "American English (Latin)");
+    assert_locale_name(&names, "es-419", "Latin American Spanish");
+}
+
+#[test]
+fn test_standard() {
+    let names = display_names(langid!("en"), Style::Long, LanguageDisplay::Standard);
+    assert_locale_name(&names, "en-GB", "English (United Kingdom)");
+    assert_locale_name(&names, "de-CH", "German (Switzerland)");
+    assert_locale_name(&names, "zh-Hans-CN", "Chinese (Simplified, China)");
+    assert_locale_name(&names, "es-419", "Spanish (Latin America)");
+}
+
+#[test]
+fn test_short() {
+    let dialect = display_names(langid!("en"), Style::Short, LanguageDisplay::Dialect);
+    assert_locale_name(&dialect, "en-GB", "UK English");
+    assert_locale_name(&dialect, "en-US", "US English");
+    assert_locale_name(&dialect, "de-CH", "Swiss High German");
+
+    let standard = display_names(langid!("en"), Style::Short, LanguageDisplay::Standard);
+    assert_locale_name(&standard, "en-GB", "English (UK)");
+    assert_locale_name(&standard, "my-MM", "Burmese (Myanmar)");
+}
+
+#[test]
+fn test_variants_and_keywords() {
+    let names = display_names(langid!("en"), Style::Long, LanguageDisplay::Dialect);
+    assert_locale_name(&names, "ca-ES-valencia", "Catalan (Spain, Valencian)");
+    assert_locale_name(&names, "de-1996", "German (German orthography of 1996)");
+    assert_locale_name(
+        &names,
+        "en-US-u-ca-gregory",
+        "American English (Gregorian Calendar)",
+    );
+    assert_locale_name(
+        &names,
+        "en-u-ca-japanese-nu-arab",
+        "English (Japanese Calendar, Arabic-Indic Digits)",
+    );
+    // Keywords without a name of their own are named by the pattern of their key and type.
+    assert_locale_name(&names, "en-u-ca-coptic", "English (Calendar: coptic)");
+    assert_locale_name(&names, "en-u-kn", "English (kn: true)");
+}
+
+#[test]
+fn test_brackets() {
+    let names = display_names(langid!("en"), Style::Long, LanguageDisplay::Dialect);
+    assert_locale_name(&names, "my-MM", "Burmese (Myanmar [Burma])");
+    assert_locale_name(&names, "en-u-hc-h12", "English (12 Hour System [1–12])");
+}
+
+#[test]
+fn test_unknown_codes() {
+    let names = display_names(langid!("en"), Style::Long, LanguageDisplay::Dialect);
+    assert_locale_name(&names, "xyz", "xyz");
+    assert_locale_name(&names, "xyz-QQ", "xyz (QQ)");
+    assert_locale_name(&names, "de-Zyyy-CH", "Swiss High German (Zyyy)");
+}
+
+#[test]
+fn test_other_locales() {
+    let es = display_names(langid!("es"), Style::Long, LanguageDisplay::Standard);
+    assert_locale_name(&es, "en-US", "inglés (Estados Unidos)");
+
+    let fr = display_names(langid!("fr"), Style::Long, LanguageDisplay::Dialect);
+    assert_locale_name(&fr, "fr-CA", "français canadien");
+    assert_locale_name(&fr, "fr-u-cu-eur", "français (devise : eur)");
+
+    let ja = display_names(langid!("ja"), Style::Long, LanguageDisplay::Dialect);
+    assert_locale_name(&ja, "de-CH", "スイス高地ドイツ語");
+    assert_locale_name(
+        &ja,
+        "ca-ES-valencia",
+        "カタロニア語 (スペイン、バレンシア語)",
+    );
+
+    let ru = display_names(langid!("ru"), Style::Short, LanguageDisplay::Standard);
+    assert_locale_name(&ru, "en-US", "английский (США)");
+}
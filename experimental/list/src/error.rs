@@ -0,0 +1,31 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use displaydoc::Display;
+use icu_provider::prelude::DataError;
+
+/// A list of possible error outcomes for the [`ListFormatter`](crate::ListFormatter) struct.
+#[derive(Display, Debug)]
+pub enum ListError {
+    /// A list pattern does not start with `{0}` followed by `{1}`.
+    #[displaydoc("Invalid list pattern: {0}")]
+    InvalidPattern(String),
+    /// The condition of a special case is not in the syntax of a
+    /// [`StringMatcher`](crate::provider::StringMatcher).
+    #[displaydoc("Invalid special case condition: {0}")]
+    InvalidCondition(String),
+    /// An error originating inside of the [`DataProvider`](icu_provider::DataProvider)
+    #[displaydoc("Data provider error: {0}")]
+    DataProvider(DataError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ListError {}
+
+impl From<DataError> for ListError {
+    fn from(e: DataError) -> Self {
+        ListError::DataProvider(e)
+    }
+}
@@ -0,0 +1,140 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Lower-level types for list formatting.
+
+use crate::parts;
+use crate::provider::ConditionalListJoinerPattern;
+use crate::ListFormatter;
+use alloc::vec::Vec;
+use core::fmt;
+use writeable::{CoreWriteAsPartsWrite, LengthHint, PartsWrite, Writeable};
+
+/// An intermediate structure returned by [`ListFormatter`](crate::ListFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted list to a string or buffer.
+pub struct FormattedList<'l, 'data, W, I>
+where
+    W: Writeable,
+    I: Iterator<Item = W> + Clone,
+{
+    pub(crate) formatter: &'l ListFormatter<'data>,
+    pub(crate) values: I,
+}
+
+impl<'l, 'data, W, I> Writeable for FormattedList<'l, 'data, W, I>
+where
+    W: Writeable,
+    I: Iterator<Item = W> + Clone,
+{
+    fn write_to<V: fmt::Write + ?Sized>(&self, sink: &mut V) -> fmt::Result {
+        self.write_to_parts(&mut CoreWriteAsPartsWrite(sink))
+    }
+
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let patterns = self.formatter.patterns();
+        let mut values = self.values.clone();
+
+        let first = match values.next() {
+            Some(first) => first,
+            None => return Ok(()),
+        };
+        let second = match values.next() {
+            Some(second) => second,
+            None => return write_element(sink, &first),
+        };
+        let mut next = match values.next() {
+            Some(third) => third,
+            None => {
+                let (between, after) = patterns.pair.get(&second).parts();
+                write_element(sink, &first)?;
+                write_literal(sink, between)?;
+                write_element(sink, &second)?;
+                return write_literal(sink, after);
+            }
+        };
+
+        // The text after `{1}` in the start and middle patterns follows the rest of the list.
+        let mut suffixes = Vec::new();
+        let (between, after) = patterns.start.get(&second).parts();
+        write_element(sink, &first)?;
+        write_literal(sink, between)?;
+        if !after.is_empty() {
+            suffixes.push(after);
+        }
+
+        let mut current = second;
+        for following in values {
+            let (between, after) = patterns.middle.get(&next).parts();
+            write_element(sink, &current)?;
+            write_literal(sink, between)?;
+            if !after.is_empty() {
+                suffixes.push(after);
+            }
+            current = core::mem::replace(&mut next, following);
+        }
+
+        let (between, after) = patterns.end.get(&next).parts();
+        write_element(sink, &current)?;
+        write_literal(sink, between)?;
+        write_element(sink, &next)?;
+        write_literal(sink, after)?;
+        for suffix in suffixes.iter().rev() {
+            write_literal(sink, suffix)?;
+        }
+        Ok(())
+    }
+
+    fn write_len(&self) -> LengthHint {
+        let patterns = self.formatter.patterns();
+        let elements: LengthHint = self.values.clone().map(|value| value.write_len()).sum();
+        let literals = match self.values.clone().count() {
+            0 | 1 => LengthHint::Exact(0),
+            2 => pattern_len(&patterns.pair),
+            count => {
+                pattern_len(&patterns.start)
+                    + (0..count - 3)
+                        .map(|_| pattern_len(&patterns.middle))
+                        .sum::<LengthHint>()
+                    + pattern_len(&patterns.end)
+            }
+        };
+        elements + literals
+    }
+}
+
+impl<'l, 'data, W, I> fmt::Display for FormattedList<'l, 'data, W, I>
+where
+    W: Writeable,
+    I: Iterator<Item = W> + Clone,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+fn write_element<S, W>(sink: &mut S, element: &W) -> fmt::Result
+where
+    S: PartsWrite + ?Sized,
+    W: Writeable,
+{
+    sink.with_part(parts::ELEMENT, |sink| element.write_to_parts(sink))
+}
+
+fn write_literal<S: PartsWrite + ?Sized>(sink: &mut S, literal: &str) -> fmt::Result {
+    if literal.is_empty() {
+        return Ok(());
+    }
+    sink.with_part(parts::LITERAL, |sink| sink.write_str(literal))
+}
+
+/// Returns the length of the literal text of a pattern, which is only known if it does not
+/// depend on the following element.
+fn pattern_len(pattern: &ConditionalListJoinerPattern) -> LengthHint {
+    match &pattern.special_case {
+        Some(special_case) if special_case.pattern.string.len() != pattern.default.string.len() => {
+            LengthHint::Undefined
+        }
+        _ => LengthHint::Exact(pattern.default.string.len()),
+    }
+}
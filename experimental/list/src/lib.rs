@@ -0,0 +1,139 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_list`](crate) formats lists of elements in a locale-sensitive way, like "A, B, and C".
+//!
+//! A [`ListFormatter`] joins the elements of a list with the words of a [`ListType`]:
+//!
+//! * [`ListType::And`] for elements which all apply, like "A, B, and C"
+//! * [`ListType::Or`] for alternatives, like "A, B, or C"
+//! * [`ListType::Unit`] for the values of a compound unit, like "3 feet, 7 inches"
+//!
+//! in a [`ListWidth`], which is wide, short or narrow. The elements can be any
+//! [`Writeable`](writeable::Writeable), like strings or formatted numbers.
+//!
+//! Some locales change the words joining two elements depending on the second element. For
+//! example, the Spanish "y" becomes "e" before words starting with an /i/ sound, and "o"
+//! becomes "u" before words starting with an /o/ sound.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_list::{ListFormatter, ListType, ListWidth};
+//! use icu_locid_macros::langid;
+//! use writeable::Writeable;
+//!
+//! let provider = icu_testdata::get_provider();
+//!
+//! let list_formatter =
+//!     ListFormatter::try_new(langid!("en"), &provider, ListType::And, ListWidth::Wide)
+//!         .expect("Data should load successfully");
+//!
+//! assert_eq!(
+//!     list_formatter
+//!         .format(["Spain", "France", "Switzerland"].iter())
+//!         .writeable_to_string(),
+//!     "Spain, France, and Switzerland"
+//! );
+//!
+//! let list_formatter =
+//!     ListFormatter::try_new(langid!("es"), &provider, ListType::And, ListWidth::Wide)
+//!         .expect("Data should load successfully");
+//!
+//! assert_eq!(
+//!     list_formatter
+//!         .format(["España", "Francia", "Italia"].iter())
+//!         .writeable_to_string(),
+//!     "España, Francia e Italia"
+//! );
+//! ```
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod error;
+pub mod format;
+pub mod options;
+pub mod parts;
+pub mod provider;
+
+pub use error::ListError;
+pub use format::FormattedList;
+pub use options::{ListType, ListWidth};
+
+use alloc::string::String;
+use icu_locid::Locale;
+use icu_provider::prelude::*;
+use provider::{ListFormatterPatternsV1Marker, ListJoinerPatternsV1};
+use writeable::Writeable;
+
+/// A formatter for lists in a locale, which joins the elements of a list with the words of a
+/// [`ListType`] in a [`ListWidth`].
+///
+/// See the crate-level documentation for examples.
+pub struct ListFormatter<'data> {
+    data: DataPayload<'data, ListFormatterPatternsV1Marker>,
+    width: ListWidth,
+}
+
+impl<'data> ListFormatter<'data> {
+    /// Creates a new [`ListFormatter`] for a [`ListType`] and a [`ListWidth`] from locale data.
+    pub fn try_new<T, D>(
+        locale: T,
+        data_provider: &D,
+        list_type: ListType,
+        width: ListWidth,
+    ) -> Result<Self, ListError>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, ListFormatterPatternsV1Marker> + ?Sized,
+    {
+        let key = match list_type {
+            ListType::And => provider::key::LIST_FORMAT_AND_V1,
+            ListType::Or => provider::key::LIST_FORMAT_OR_V1,
+            ListType::Unit => provider::key::LIST_FORMAT_UNIT_V1,
+        };
+        let data = data_provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(locale.into().into()),
+                    },
+                },
+            })?
+            .take_payload()?;
+        Ok(Self { data, width })
+    }
+
+    /// Formats a list of elements, returning a [`FormattedList`].
+    ///
+    /// The iterator is cloned to write the elements more than once, so it should be cheap to
+    /// clone, like an iterator over a slice.
+    pub fn format<'l, W, I>(&'l self, values: I) -> FormattedList<'l, 'data, W, I>
+    where
+        W: Writeable,
+        I: Iterator<Item = W> + Clone,
+    {
+        FormattedList {
+            formatter: self,
+            values,
+        }
+    }
+
+    /// Formats a list of elements to a [`String`].
+    pub fn format_to_string<W, I>(&self, values: I) -> String
+    where
+        W: Writeable,
+        I: Iterator<Item = W> + Clone,
+    {
+        self.format(values).writeable_to_string()
+    }
+
+    fn patterns(&self) -> &ListJoinerPatternsV1<'_> {
+        self.data.get().get(self.width)
+    }
+}
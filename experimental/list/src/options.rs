@@ -0,0 +1,33 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`ListFormatter`](crate::ListFormatter).
+
+/// The type of a list, which selects the words joining its elements.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ListType {
+    /// A list of elements which all apply, like "A, B, and C".
+    And,
+    /// A list of alternatives, like "A, B, or C".
+    Or,
+    /// A list of the values of a compound unit, like "3 feet, 7 inches".
+    Unit,
+}
+
+/// The width of the words joining the elements of a list.
+///
+/// For example, English "and" lists are "A, B, and C" in the wide width, "A, B, & C" in the
+/// short width and "A, B, C" in the narrow width.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ListWidth {
+    Wide,
+    Short,
+    Narrow,
+}
+
+impl Default for ListWidth {
+    fn default() -> Self {
+        Self::Wide
+    }
+}
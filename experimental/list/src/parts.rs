@@ -0,0 +1,22 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The [`Part`]s of a [`FormattedList`](crate::FormattedList), which it writes with
+//! [`Writeable::write_to_parts`](writeable::Writeable::write_to_parts).
+//!
+//! The parts of the elements are nested in their [`ELEMENT`] parts.
+
+use writeable::Part;
+
+/// An element of the list.
+pub const ELEMENT: Part = Part {
+    category: "list",
+    value: "element",
+};
+
+/// The text joining the elements of the list, like `", and "`.
+pub const LITERAL: Part = Part {
+    category: "list",
+    value: "literal",
+};
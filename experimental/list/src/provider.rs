@@ -0,0 +1,215 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use crate::error::ListError;
+use crate::options::ListWidth;
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use core::convert::TryFrom;
+use icu_provider::yoke::{self, *};
+
+mod string_matcher;
+
+pub use string_matcher::StringMatcher;
+
+pub mod key {
+    //! Resource keys for [`icu_list`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: patterns for lists joined with "and".
+    pub const LIST_FORMAT_AND_V1: ResourceKey = resource_key!(List, "and", 1);
+
+    /// Resource key: patterns for lists of alternatives joined with "or".
+    pub const LIST_FORMAT_OR_V1: ResourceKey = resource_key!(List, "or", 1);
+
+    /// Resource key: patterns for lists of the values of a compound unit, like "3 feet, 7 inches".
+    pub const LIST_FORMAT_UNIT_V1: ResourceKey = resource_key!(List, "unit", 1);
+}
+
+/// The patterns to join the elements of a list of one type, like "and" lists, in each
+/// [`ListWidth`].
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-general.html#ListPatterns>
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct ListFormatterPatternsV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub wide: ListJoinerPatternsV1<'data>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub short: ListJoinerPatternsV1<'data>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub narrow: ListJoinerPatternsV1<'data>,
+}
+
+impl<'data> ListFormatterPatternsV1<'data> {
+    /// Returns the patterns of a [`ListWidth`].
+    pub fn get(&self, width: ListWidth) -> &ListJoinerPatternsV1<'data> {
+        match width {
+            ListWidth::Wide => &self.wide,
+            ListWidth::Short => &self.short,
+            ListWidth::Narrow => &self.narrow,
+        }
+    }
+}
+
+/// The patterns to join the elements of a list in one [`ListWidth`].
+///
+/// A list of two elements is joined with the `pair` pattern. A longer list joins its first
+/// element to the rest of the list with the `start` pattern, each following element but the
+/// last two with the `middle` pattern, and the last two elements with the `end` pattern.
+#[derive(Debug, PartialEq, Clone, Yokeable, ZeroCopyFrom)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct ListJoinerPatternsV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub start: ConditionalListJoinerPattern<'data>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub middle: ConditionalListJoinerPattern<'data>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub end: ConditionalListJoinerPattern<'data>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub pair: ConditionalListJoinerPattern<'data>,
+}
+
+/// A [`ListJoinerPattern`] which may be replaced by another pattern depending on the
+/// following element, like the Spanish "y", which becomes "e" before words starting with an
+/// /i/ sound.
+#[derive(Debug, PartialEq, Clone, Yokeable, ZeroCopyFrom)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct ConditionalListJoinerPattern<'data> {
+    /// The pattern used if the special case does not apply.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub default: ListJoinerPattern<'data>,
+    /// The special case, if the locale has one for this pattern.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub special_case: Option<SpecialCasePattern<'data>>,
+}
+
+impl<'data> ConditionalListJoinerPattern<'data> {
+    /// Returns the pattern to join an element to the following element `next`.
+    ///
+    /// `next` is only written to a string if there is a special case.
+    pub fn get<W: writeable::Writeable + ?Sized>(&self, next: &W) -> &ListJoinerPattern<'data> {
+        match &self.special_case {
+            Some(special_case) if special_case.condition.matches(&next.writeable_to_string()) => {
+                &special_case.pattern
+            }
+            _ => &self.default,
+        }
+    }
+}
+
+impl<'data> From<ListJoinerPattern<'data>> for ConditionalListJoinerPattern<'data> {
+    fn from(default: ListJoinerPattern<'data>) -> Self {
+        Self {
+            default,
+            special_case: None,
+        }
+    }
+}
+
+/// A pattern which replaces the default pattern of a [`ConditionalListJoinerPattern`] if the
+/// following element matches a condition.
+#[derive(Debug, PartialEq, Clone, Yokeable, ZeroCopyFrom)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct SpecialCasePattern<'data> {
+    /// The condition on the start of the following element.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub condition: StringMatcher<'data>,
+    /// The pattern used if the condition matches.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub pattern: ListJoinerPattern<'data>,
+}
+
+/// A pattern like `"{0}, {1}"` joining an element `{0}` to the following elements `{1}`.
+///
+/// The pattern is stored without its placeholders: `{0}` is at the start of `string`, and
+/// `{1}` is at the byte index `index_1`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_list::provider::ListJoinerPattern;
+/// use std::convert::TryFrom;
+///
+/// let pattern = ListJoinerPattern::try_from("{0}, and {1}").expect("The pattern is valid");
+/// assert_eq!(pattern.parts(), (", and ", ""));
+///
+/// assert!(ListJoinerPattern::try_from("{1} and {0}").is_err());
+/// ```
+#[derive(Debug, PartialEq, Clone, Yokeable, ZeroCopyFrom)]
+#[cfg_attr(feature = "provider_serde", derive(serde::Serialize))]
+pub struct ListJoinerPattern<'data> {
+    /// The literal text of the pattern.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub string: Cow<'data, str>,
+    /// The byte index of the `{1}` placeholder in `string`.
+    pub index_1: u8,
+}
+
+impl<'data> ListJoinerPattern<'data> {
+    /// Returns the literal text between `{0}` and `{1}`, and the text after `{1}`.
+    pub fn parts(&self) -> (&str, &str) {
+        // `index_1` is validated on construction and deserialization.
+        self.string
+            .split_at(usize::from(self.index_1).min(self.string.len()))
+    }
+
+    fn try_from_parts(string: Cow<'data, str>, index_1: u8) -> Result<Self, ListError> {
+        if !string.is_char_boundary(usize::from(index_1)) {
+            return Err(ListError::InvalidPattern(string.to_string()));
+        }
+        Ok(Self { string, index_1 })
+    }
+}
+
+impl<'data> TryFrom<&str> for ListJoinerPattern<'data> {
+    type Error = ListError;
+
+    /// Parses a pattern like `"{0}, {1}"`, which must start with `{0}` and contain `{1}`.
+    fn try_from(pattern: &str) -> Result<Self, Self::Error> {
+        let invalid = || ListError::InvalidPattern(pattern.to_string());
+        let rest = pattern.strip_prefix("{0}").ok_or_else(invalid)?;
+        let index_1 = rest.find("{1}").ok_or_else(invalid)?;
+        let string = [&rest[..index_1], &rest[index_1 + 3..]].concat();
+        if string.contains("{0}") || string.contains("{1}") {
+            return Err(invalid());
+        }
+        let index_1 = u8::try_from(index_1).map_err(|_| invalid())?;
+        Self::try_from_parts(Cow::Owned(string), index_1)
+    }
+}
+
+#[cfg(feature = "provider_serde")]
+impl<'de: 'data, 'data> serde::Deserialize<'de> for ListJoinerPattern<'data> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        struct Raw<'data> {
+            #[serde(borrow)]
+            string: Cow<'data, str>,
+            index_1: u8,
+        }
+        let raw = Raw::deserialize(deserializer)?;
+        Self::try_from_parts(raw.string, raw.index_1).map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,370 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::ListError;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::iter::Peekable;
+use core::str::Chars;
+use icu_provider::yoke::{self, *};
+
+/// A small regular expression which is matched against the start of a string, used for the
+/// conditions of [`SpecialCasePattern`](super::SpecialCasePattern)s.
+///
+/// The syntax is a subset of common regular expressions:
+///
+/// * literal characters, and `\` followed by a character to match it literally
+/// * `.` for any character, `\d` for an ASCII digit, and `\D` for any other character
+/// * character classes like `[0-9]` or `[^ae]`, which may contain ranges, `\d` and escaped
+///   characters
+/// * groups with alternatives, like `(a|b)`, and alternatives at the top level, like `a|b`
+/// * the quantifiers `?`, `*` and `+`
+/// * `$` for the end of the string
+///
+/// The pattern only needs to match a prefix of the string. The string is lowercased before
+/// matching, so the pattern should not contain uppercase letters. The pattern is parsed when the
+/// matcher is created or deserialized, and only its parsed form is used for matching.
+///
+/// # Examples
+///
+/// ```
+/// use icu_list::provider::StringMatcher;
+///
+/// let matcher = StringMatcher::try_new("[ií]|hí|hi([^ae]|$)").expect("The pattern is valid");
+/// assert!(matcher.matches("Iván"));
+/// assert!(matcher.matches("ídolo"));
+/// assert!(matcher.matches("hijo"));
+/// assert!(matcher.matches("hi"));
+/// assert!(!matcher.matches("hielo"));
+/// assert!(!matcher.matches("Ana"));
+/// ```
+#[derive(Debug, PartialEq, Clone, Yokeable, ZeroCopyFrom)]
+#[cfg_attr(feature = "provider_serde", derive(serde::Serialize))]
+#[yoke(cloning_zcf)]
+pub struct StringMatcher<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pattern: Cow<'data, str>,
+    // The alternatives of `pattern`, each a sequence of nodes.
+    #[cfg_attr(feature = "provider_serde", serde(skip))]
+    alternatives: Vec<Vec<Node>>,
+}
+
+impl<'data> StringMatcher<'data> {
+    /// Creates a [`StringMatcher`] from a pattern, failing if the pattern is not in the
+    /// supported syntax.
+    pub fn try_new<T: Into<Cow<'data, str>>>(pattern: T) -> Result<Self, ListError> {
+        let pattern = pattern.into();
+        let alternatives = parse(&pattern)?;
+        Ok(Self {
+            pattern,
+            alternatives,
+        })
+    }
+
+    /// Returns the pattern of this [`StringMatcher`].
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns whether the pattern matches the start of a string.
+    pub fn matches(&self, string: &str) -> bool {
+        let chars: Vec<char> = string
+            .chars()
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect();
+        self.alternatives
+            .iter()
+            .any(|nodes| match_nodes(nodes, &chars, 0, &mut |_| true))
+    }
+
+    /// Converts this [`StringMatcher`] into one which owns its pattern.
+    pub fn into_owned(self) -> StringMatcher<'static> {
+        StringMatcher {
+            pattern: Cow::Owned(self.pattern.into_owned()),
+            alternatives: self.alternatives,
+        }
+    }
+}
+
+#[cfg(feature = "provider_serde")]
+impl<'de: 'data, 'data> serde::Deserialize<'de> for StringMatcher<'data> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        struct Raw<'data> {
+            #[serde(borrow)]
+            pattern: Cow<'data, str>,
+        }
+        let raw = Raw::deserialize(deserializer)?;
+        Self::try_new(raw.pattern).map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Node {
+    Char(char),
+    Any,
+    Digit(bool),
+    Class(bool, Vec<ClassItem>),
+    End,
+    Group(Vec<Vec<Node>>),
+    Repeat(Box<Node>, usize, Option<usize>),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum ClassItem {
+    Range(char, char),
+    Digit(bool),
+}
+
+impl Node {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Node::Char(expected) => c == *expected,
+            Node::Any => true,
+            Node::Digit(negated) => c.is_ascii_digit() != *negated,
+            Node::Class(negated, items) => {
+                items.iter().any(|item| match item {
+                    ClassItem::Range(start, end) => (*start..=*end).contains(&c),
+                    ClassItem::Digit(negated) => c.is_ascii_digit() != *negated,
+                }) != *negated
+            }
+            _ => false,
+        }
+    }
+}
+
+fn invalid(pattern: &str) -> ListError {
+    ListError::InvalidCondition(pattern.to_string())
+}
+
+/// Parses a pattern into its alternatives.
+fn parse(pattern: &str) -> Result<Vec<Vec<Node>>, ListError> {
+    let mut chars = pattern.chars().peekable();
+    let alternatives = parse_alternatives(&mut chars).ok_or_else(|| invalid(pattern))?;
+    if chars.next().is_some() {
+        // An unmatched `)`.
+        return Err(invalid(pattern));
+    }
+    Ok(alternatives)
+}
+
+fn parse_alternatives(chars: &mut Peekable<Chars>) -> Option<Vec<Vec<Node>>> {
+    let mut alternatives = Vec::new();
+    let mut nodes = Vec::new();
+    while let Some(&c) = chars.peek() {
+        let node = match c {
+            ')' => break,
+            '|' => {
+                chars.next();
+                alternatives.push(core::mem::take(&mut nodes));
+                continue;
+            }
+            '?' | '*' | '+' => {
+                chars.next();
+                let (min, max) = match c {
+                    '?' => (0, Some(1)),
+                    '*' => (0, None),
+                    _ => (1, None),
+                };
+                match nodes.pop()? {
+                    Node::End | Node::Repeat(..) => return None,
+                    node => Node::Repeat(Box::new(node), min, max),
+                }
+            }
+            '(' => {
+                chars.next();
+                let group = parse_alternatives(chars)?;
+                if chars.next() != Some(')') {
+                    return None;
+                }
+                Node::Group(group)
+            }
+            '[' => {
+                chars.next();
+                parse_class(chars)?
+            }
+            '.' => {
+                chars.next();
+                Node::Any
+            }
+            '$' => {
+                chars.next();
+                Node::End
+            }
+            '\\' => {
+                chars.next();
+                match chars.next()? {
+                    'd' => Node::Digit(false),
+                    'D' => Node::Digit(true),
+                    c => Node::Char(c),
+                }
+            }
+            c => {
+                chars.next();
+                Node::Char(c)
+            }
+        };
+        nodes.push(node);
+    }
+    alternatives.push(nodes);
+    Some(alternatives)
+}
+
+fn parse_class(chars: &mut Peekable<Chars>) -> Option<Node> {
+    let negated = chars.peek() == Some(&'^');
+    if negated {
+        chars.next();
+    }
+    let mut items = Vec::new();
+    loop {
+        let start = match chars.next()? {
+            ']' if !items.is_empty() => return Some(Node::Class(negated, items)),
+            '\\' => match chars.next()? {
+                'd' => {
+                    items.push(ClassItem::Digit(false));
+                    continue;
+                }
+                'D' => {
+                    items.push(ClassItem::Digit(true));
+                    continue;
+                }
+                c => c,
+            },
+            c => c,
+        };
+        let end = if chars.peek() == Some(&'-') {
+            chars.next();
+            match chars.next()? {
+                '\\' => chars.next()?,
+                ']' => return None,
+                c => c,
+            }
+        } else {
+            start
+        };
+        if end < start {
+            return None;
+        }
+        items.push(ClassItem::Range(start, end));
+    }
+}
+
+/// Matches a sequence of nodes at a position, calling the continuation `k` with each
+/// position where the sequence could end until it returns `true`.
+fn match_nodes(
+    nodes: &[Node],
+    chars: &[char],
+    pos: usize,
+    k: &mut dyn FnMut(usize) -> bool,
+) -> bool {
+    match nodes.split_first() {
+        None => k(pos),
+        Some((node, rest)) => match_node(node, chars, pos, &mut |pos| {
+            match_nodes(rest, chars, pos, k)
+        }),
+    }
+}
+
+fn match_node(node: &Node, chars: &[char], pos: usize, k: &mut dyn FnMut(usize) -> bool) -> bool {
+    match node {
+        Node::End => pos == chars.len() && k(pos),
+        Node::Group(alternatives) => alternatives
+            .iter()
+            .any(|nodes| match_nodes(nodes, chars, pos, k)),
+        Node::Repeat(node, min, max) => match_repeat(node, *min, *max, chars, pos, k),
+        node => match chars.get(pos) {
+            Some(&c) if node.matches(c) => k(pos + 1),
+            _ => false,
+        },
+    }
+}
+
+/// Matches a node repeated between `min` and `max` times, preferring more repetitions.
+fn match_repeat(
+    node: &Node,
+    min: usize,
+    max: Option<usize>,
+    chars: &[char],
+    pos: usize,
+    k: &mut dyn FnMut(usize) -> bool,
+) -> bool {
+    if max == Some(0) {
+        return min == 0 && k(pos);
+    }
+    let mut repeat = |next| {
+        // Stop repeating a node which matched the empty string.
+        next != pos
+            && match_repeat(
+                node,
+                min.saturating_sub(1),
+                max.map(|max| max - 1),
+                chars,
+                next,
+                k,
+            )
+    };
+    match_node(node, chars, pos, &mut repeat) || (min == 0 && k(pos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_syntax() {
+        for pattern in ["a|b", "(a|b)c", "[a-c\\d]+", "\\.?x*", "a$", "[^\\]]", ""] {
+            assert!(StringMatcher::try_new(pattern).is_ok(), "{}", pattern);
+        }
+        for pattern in ["(a", "a)", "[a", "[]", "[c-a]", "*a", "a**", "$?", "\\"] {
+            assert!(
+                matches!(
+                    StringMatcher::try_new(pattern),
+                    Err(ListError::InvalidCondition(p)) if p == pattern
+                ),
+                "{}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn test_spanish_and() {
+        let matcher = StringMatcher::try_new("[ií]|hí|hi([^ae]|$)").unwrap();
+        for string in ["Iván", "ídolo", "Ínsula", "hijo", "hi", "híbrido", "Híades"] {
+            assert!(matcher.matches(string), "{}", string);
+        }
+        for string in ["Ana", "hielo", "hiato", "hora", "fin"] {
+            assert!(!matcher.matches(string), "{}", string);
+        }
+    }
+
+    #[test]
+    fn test_spanish_or() {
+        let matcher =
+            StringMatcher::try_new("[oó]|h[oó]|8|(11(\\.?\\d\\d\\d)*(,\\d*)?([^\\.,\\d]|$))")
+                .unwrap();
+        for string in [
+            "otro", "Óscar", "Hora", "hórreo", "8", "80", "11", "11.000", "11000,5", "11 años",
+        ] {
+            assert!(matcher.matches(string), "{}", string);
+        }
+        for string in ["uno", "hielo", "110", "1100", "11.00", "7"] {
+            assert!(!matcher.matches(string), "{}", string);
+        }
+    }
+
+    #[test]
+    fn test_hebrew() {
+        let matcher = StringMatcher::try_new("[^\u{0590}-\u{05FF}]").unwrap();
+        assert!(matcher.matches("ICU"));
+        assert!(matcher.matches("4"));
+        assert!(!matcher.matches("אביב"));
+        assert!(!matcher.matches(""));
+    }
+}
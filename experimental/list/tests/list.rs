@@ -0,0 +1,92 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_list::{parts, ListFormatter, ListType, ListWidth};
+use icu_locid::LanguageIdentifier;
+use icu_locid_macros::langid;
+use writeable::{assert_writeable_eq, assert_writeable_parts_eq, Writeable};
+
+fn formatter(
+    langid: LanguageIdentifier,
+    list_type: ListType,
+    width: ListWidth,
+) -> ListFormatter<'static> {
+    let provider = icu_testdata::get_provider();
+    ListFormatter::try_new(langid, &provider, list_type, width).unwrap()
+}
+
+#[test]
+fn test_en() {
+    let and = formatter(langid!("en"), ListType::And, ListWidth::Wide);
+    assert_writeable_eq!("", and.format(core::iter::empty::<&str>()));
+    assert_writeable_eq!("A", and.format(["A"].iter()));
+    assert_writeable_eq!("A and B", and.format(["A", "B"].iter()));
+    assert_writeable_eq!("A, B, and C", and.format(["A", "B", "C"].iter()));
+    assert_writeable_eq!("A, B, C, and D", and.format(["A", "B", "C", "D"].iter()));
+
+    let and_short = formatter(langid!("en"), ListType::And, ListWidth::Short);
+    assert_writeable_eq!("A & B", and_short.format(["A", "B"].iter()));
+    assert_writeable_eq!("A, B, & C", and_short.format(["A", "B", "C"].iter()));
+
+    let or = formatter(langid!("en"), ListType::Or, ListWidth::Wide);
+    assert_writeable_eq!("A, B, or C", or.format(["A", "B", "C"].iter()));
+
+    let unit_narrow = formatter(langid!("en"), ListType::Unit, ListWidth::Narrow);
+    assert_writeable_eq!("3ft 7in", unit_narrow.format(["3ft", "7in"].iter()));
+}
+
+#[test]
+fn test_en_001() {
+    let and = formatter(langid!("en-001"), ListType::And, ListWidth::Wide);
+    assert_eq!(and.format_to_string(["A", "B", "C"].iter()), "A, B and C");
+}
+
+#[test]
+fn test_es_special_cases() {
+    let and = formatter(langid!("es"), ListType::And, ListWidth::Wide);
+    assert_writeable_eq!("agua y hielo", and.format(["agua", "hielo"].iter()));
+    assert_writeable_eq!("padre e hijo", and.format(["padre", "hijo"].iter()));
+    assert_writeable_eq!("templos e ídolos", and.format(["templos", "ídolos"].iter()));
+    assert_writeable_eq!(
+        "España, Francia e Italia",
+        and.format(["España", "Francia", "Italia"].iter())
+    );
+    assert_writeable_eq!(
+        "Italia, Francia y España",
+        and.format(["Italia", "Francia", "España"].iter())
+    );
+
+    let or = formatter(langid!("es"), ListType::Or, ListWidth::Wide);
+    assert_writeable_eq!("uno u otro", or.format(["uno", "otro"].iter()));
+    assert_writeable_eq!("Juan u Óscar", or.format(["Juan", "Óscar"].iter()));
+    assert_writeable_eq!("7 u 8", or.format(["7", "8"].iter()));
+    assert_writeable_eq!("10 u 11", or.format(["10", "11"].iter()));
+    assert_writeable_eq!("10 o 110", or.format(["10", "110"].iter()));
+}
+
+#[test]
+fn test_write_len() {
+    let and = formatter(langid!("en"), ListType::And, ListWidth::Wide);
+    let values = ["one", "two", "three", "four"];
+    assert_eq!(
+        and.format(values.iter()).write_len(),
+        writeable::LengthHint::Exact("one, two, three, and four".len())
+    );
+}
+
+#[test]
+fn test_parts() {
+    let and = formatter(langid!("en"), ListType::And, ListWidth::Wide);
+    assert_writeable_parts_eq!(
+        "A, BC, and D",
+        &and.format(["A", "BC", "D"].iter()),
+        [
+            (0, 1, parts::ELEMENT),
+            (1, 3, parts::LITERAL),
+            (3, 5, parts::ELEMENT),
+            (5, 11, parts::LITERAL),
+            (11, 12, parts::ELEMENT),
+        ]
+    );
+}
@@ -0,0 +1,132 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use fixed_decimal::FixedDecimal;
+use icu_datetime::date::*;
+use litemap::LiteMap;
+
+/// The value of an argument of a message.
+pub enum ArgumentValue<'a> {
+    /// A string, for arguments without a type and `select` arguments.
+    String(Cow<'a, str>),
+    /// A number, for arguments without a type and `number`, `plural` and `selectordinal`
+    /// arguments.
+    Number(FixedDecimal),
+    /// A date and time, for `date` and `time` arguments.
+    DateTime(&'a dyn DateTimeInput),
+}
+
+impl<'a> From<&'a str> for ArgumentValue<'a> {
+    fn from(input: &'a str) -> Self {
+        Self::String(Cow::Borrowed(input))
+    }
+}
+
+impl From<String> for ArgumentValue<'_> {
+    fn from(input: String) -> Self {
+        Self::String(Cow::Owned(input))
+    }
+}
+
+impl From<FixedDecimal> for ArgumentValue<'_> {
+    fn from(input: FixedDecimal) -> Self {
+        Self::Number(input)
+    }
+}
+
+macro_rules! impl_integer_type {
+    ($($ty:ident)+) => {
+        $(
+            impl From<$ty> for ArgumentValue<'_> {
+                fn from(input: $ty) -> Self {
+                    Self::Number(input.into())
+                }
+            }
+        )+
+    };
+}
+
+impl_integer_type!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+impl<'a, T: DateTimeInput> From<&'a T> for ArgumentValue<'a> {
+    fn from(input: &'a T) -> Self {
+        Self::DateTime(input)
+    }
+}
+
+/// The values of the arguments of a message by their names.
+///
+/// # Examples
+///
+/// ```
+/// use icu_messageformat::MessageArguments;
+///
+/// let mut args = MessageArguments::new();
+/// args.insert("name", "Ana");
+/// args.insert("count", 3);
+/// ```
+#[derive(Default)]
+pub struct MessageArguments<'a>(LiteMap<&'a str, ArgumentValue<'a>>);
+
+impl<'a> MessageArguments<'a> {
+    /// Creates an empty [`MessageArguments`].
+    pub fn new() -> Self {
+        Self(LiteMap::new())
+    }
+
+    /// Sets the value of an argument, replacing its previous value.
+    pub fn insert(&mut self, name: &'a str, value: impl Into<ArgumentValue<'a>>) {
+        self.0.insert(name, value.into());
+    }
+
+    /// Returns the value of an argument.
+    pub fn get(&self, name: &str) -> Option<&ArgumentValue<'a>> {
+        self.0.get(name)
+    }
+}
+
+/// A [`DateTimeInput`] behind a reference, which the formatters take by type.
+pub(crate) struct DynDateTime<'a>(pub &'a dyn DateTimeInput);
+
+impl DateInput for DynDateTime<'_> {
+    fn year(&self) -> Option<Year> {
+        self.0.year()
+    }
+
+    fn month(&self) -> Option<Month> {
+        self.0.month()
+    }
+
+    fn day_of_month(&self) -> Option<DayOfMonth> {
+        self.0.day_of_month()
+    }
+
+    fn iso_weekday(&self) -> Option<IsoWeekday> {
+        self.0.iso_weekday()
+    }
+
+    fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
+        self.0.day_of_year_info()
+    }
+}
+
+impl IsoTimeInput for DynDateTime<'_> {
+    fn hour(&self) -> Option<IsoHour> {
+        self.0.hour()
+    }
+
+    fn minute(&self) -> Option<IsoMinute> {
+        self.0.minute()
+    }
+
+    fn second(&self) -> Option<IsoSecond> {
+        self.0.second()
+    }
+
+    fn fraction(&self) -> Option<FractionalSecond> {
+        self.0.fraction()
+    }
+}
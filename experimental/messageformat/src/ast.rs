@@ -0,0 +1,118 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The syntax tree of a parsed message.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_messageformat::ast::*;
+//! use icu_messageformat::parse;
+//! use icu_plurals::{PluralCategory, PluralRuleType};
+//!
+//! let message = parse("{count, plural, one {# file} other {# files}}")
+//!     .expect("Parsing failed.");
+//!
+//! assert_eq!(
+//!     message,
+//!     Message(vec![MessagePart::Plural(PluralArgument {
+//!         name: "count".to_string(),
+//!         rule_type: PluralRuleType::Cardinal,
+//!         offset: 0,
+//!         variants: vec![
+//!             (
+//!                 PluralKey::Category(PluralCategory::One),
+//!                 Message(vec![MessagePart::Number, MessagePart::Literal(" file".to_string())]),
+//!             ),
+//!             (
+//!                 PluralKey::Category(PluralCategory::Other),
+//!                 Message(vec![MessagePart::Number, MessagePart::Literal(" files".to_string())]),
+//!             ),
+//!         ],
+//!     })])
+//! );
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+use icu_datetime::options::length;
+use icu_plurals::{PluralCategory, PluralRuleType};
+
+/// A message, as a sequence of literal text and arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message(pub Vec<MessagePart>);
+
+/// A part of a [`Message`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessagePart {
+    /// Literal text, with the quoting apostrophes removed.
+    Literal(String),
+    /// An argument formatted on its own, like `{name}` or `{count, number}`.
+    Simple(SimpleArgument),
+    /// A `plural` or `selectordinal` argument.
+    Plural(PluralArgument),
+    /// A `select` argument.
+    Select(SelectArgument),
+    /// The `#` of the message of a [`PluralArgument`], which stands for its number minus the
+    /// offset.
+    Number,
+}
+
+/// An argument formatted on its own, like `{name}`, `{count, number}` or `{when, date, short}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimpleArgument {
+    /// The name of the argument.
+    pub name: String,
+    /// How the argument is formatted.
+    pub kind: ArgumentKind,
+}
+
+/// The type and style of a [`SimpleArgument`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArgumentKind {
+    /// No type, as in `{name}`: strings are written as they are, and numbers are formatted.
+    None,
+    /// `{name, number}`
+    Number,
+    /// `{name, date}` or `{name, date, style}`, where the style defaults to `medium`.
+    Date(length::Date),
+    /// `{name, time}` or `{name, time, style}`, where the style defaults to `medium`.
+    Time(length::Time),
+}
+
+/// A `plural` or `selectordinal` argument, like
+/// `{count, plural, offset:1 =0 {nobody} one {# other} other {# others}}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluralArgument {
+    /// The name of the argument.
+    pub name: String,
+    /// [`PluralRuleType::Cardinal`] for `plural` and [`PluralRuleType::Ordinal`] for
+    /// `selectordinal`.
+    pub rule_type: PluralRuleType,
+    /// The offset that is subtracted from the number before its category is selected.
+    pub offset: u32,
+    /// The messages of the argument with their keys, in the order of the source. One of them
+    /// has the key [`PluralCategory::Other`].
+    pub variants: Vec<(PluralKey, Message)>,
+}
+
+/// The key of a message of a [`PluralArgument`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PluralKey {
+    /// An explicit value, like `=0`, which is matched before the offset is subtracted.
+    Exact(FixedDecimal),
+    /// A plural category, like `one`.
+    Category(PluralCategory),
+}
+
+/// A `select` argument, like `{gender, select, female {she} male {he} other {they}}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectArgument {
+    /// The name of the argument.
+    pub name: String,
+    /// The messages of the argument with their keywords, in the order of the source. One of
+    /// them has the keyword `other`.
+    pub variants: Vec<(String, Message)>,
+}
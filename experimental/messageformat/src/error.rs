@@ -0,0 +1,71 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use displaydoc::Display;
+use icu_datetime::DateTimeFormatError;
+use icu_decimal::FixedDecimalFormatError;
+use icu_plurals::PluralRulesError;
+use icu_provider::prelude::DataError;
+
+/// A list of possible error outcomes for the [`MessageFormat`](crate::MessageFormat) struct.
+#[derive(Display, Debug)]
+pub enum MessageFormatError {
+    /// The message is not well-formed at a byte offset.
+    #[displaydoc("Syntax error at offset {0}")]
+    Syntax(usize),
+    /// An argument has a type other than `number`, `date`, `time`, `plural`,
+    /// `selectordinal` and `select`.
+    #[displaydoc("Unknown argument type: {0}")]
+    UnknownType(String),
+    /// An argument has a style that is not supported for its type.
+    #[displaydoc("Unknown argument style: {0}")]
+    UnknownStyle(String),
+    /// A `plural` or `selectordinal` argument has a keyword which is not a plural category.
+    #[displaydoc("Unknown plural keyword: {0}")]
+    UnknownKeyword(String),
+    /// A `plural`, `selectordinal` or `select` argument starting at a byte offset does not
+    /// have an `other` message.
+    #[displaydoc("Missing other message in the argument at offset {0}")]
+    MissingOther(usize),
+    /// An error originating inside of the [`DataProvider`](icu_provider::DataProvider)
+    #[displaydoc("Data provider error: {0}")]
+    DataProvider(DataError),
+    /// An error from loading the plural rules
+    #[displaydoc("Plural rules error: {0}")]
+    PluralRules(PluralRulesError),
+    /// An error from loading the decimal format
+    #[displaydoc("Decimal format error: {0}")]
+    Decimal(FixedDecimalFormatError),
+    /// An error from loading a date or time format
+    #[displaydoc("Date time format error: {0}")]
+    DateTime(DateTimeFormatError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MessageFormatError {}
+
+impl From<DataError> for MessageFormatError {
+    fn from(e: DataError) -> Self {
+        MessageFormatError::DataProvider(e)
+    }
+}
+
+impl From<PluralRulesError> for MessageFormatError {
+    fn from(e: PluralRulesError) -> Self {
+        MessageFormatError::PluralRules(e)
+    }
+}
+
+impl From<FixedDecimalFormatError> for MessageFormatError {
+    fn from(e: FixedDecimalFormatError) -> Self {
+        MessageFormatError::Decimal(e)
+    }
+}
+
+impl From<DateTimeFormatError> for MessageFormatError {
+    fn from(e: DateTimeFormatError) -> Self {
+        MessageFormatError::DateTime(e)
+    }
+}
@@ -0,0 +1,194 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Lower-level types for message formatting.
+
+use crate::arguments::{ArgumentValue, DynDateTime, MessageArguments};
+use crate::ast::*;
+use crate::MessageFormat;
+use alloc::string::String;
+use core::fmt;
+use fixed_decimal::FixedDecimal;
+use icu_plurals::{PluralCategory, PluralRuleType};
+use writeable::Writeable;
+
+/// An intermediate structure returned by [`MessageFormat`](crate::MessageFormat).
+/// Use [`Writeable`][Writeable] to render the formatted message to a string or buffer.
+///
+/// An argument which is missing or whose value does not fit its type, like a string for a
+/// `plural` argument, is written as its name in braces, like `{count}`.
+pub struct FormattedMessage<'l, 'data> {
+    pub(crate) format: &'l MessageFormat<'data>,
+    pub(crate) args: &'l MessageArguments<'l>,
+}
+
+impl FormattedMessage<'_, '_> {
+    /// Writes a message. `number` is the number of the innermost `plural` or `selectordinal`
+    /// argument, which `#` stands for.
+    fn write_message<W>(
+        &self,
+        message: &Message,
+        number: Option<&FixedDecimal>,
+        sink: &mut W,
+    ) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        for part in message.0.iter() {
+            match part {
+                MessagePart::Literal(literal) => sink.write_str(literal)?,
+                MessagePart::Number => match number {
+                    Some(number) => self.format.decimal.format(number).write_to(sink)?,
+                    None => sink.write_char('#')?,
+                },
+                MessagePart::Simple(arg) => self.write_simple(arg, sink)?,
+                MessagePart::Plural(arg) => match self.args.get(&arg.name) {
+                    Some(ArgumentValue::Number(value)) => match self.select_plural(arg, value) {
+                        Some((message, number)) => {
+                            self.write_message(message, Some(&number), sink)?
+                        }
+                        None => write_placeholder(&arg.name, sink)?,
+                    },
+                    _ => write_placeholder(&arg.name, sink)?,
+                },
+                MessagePart::Select(arg) => match self.args.get(&arg.name) {
+                    Some(ArgumentValue::String(value)) => {
+                        let message = arg
+                            .variants
+                            .iter()
+                            .find(|(keyword, _)| keyword == value)
+                            .or_else(|| arg.variants.iter().find(|(keyword, _)| keyword == "other"))
+                            .map(|(_, message)| message);
+                        match message {
+                            Some(message) => self.write_message(message, None, sink)?,
+                            None => write_placeholder(&arg.name, sink)?,
+                        }
+                    }
+                    _ => write_placeholder(&arg.name, sink)?,
+                },
+            }
+        }
+        Ok(())
+    }
+
+    fn write_simple<W>(&self, arg: &SimpleArgument, sink: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        match (self.args.get(&arg.name), arg.kind) {
+            (Some(ArgumentValue::String(value)), ArgumentKind::None) => sink.write_str(value),
+            (Some(ArgumentValue::Number(value)), ArgumentKind::None)
+            | (Some(ArgumentValue::Number(value)), ArgumentKind::Number) => {
+                self.format.decimal.format(value).write_to(sink)
+            }
+            (Some(ArgumentValue::DateTime(value)), ArgumentKind::Date(_))
+            | (Some(ArgumentValue::DateTime(value)), ArgumentKind::Time(_)) => {
+                match self
+                    .format
+                    .date_times
+                    .iter()
+                    .find(|(kind, _)| *kind == arg.kind)
+                {
+                    Some((_, dtf)) => dtf.format(&DynDateTime(*value)).write_to(sink),
+                    None => write_placeholder(&arg.name, sink),
+                }
+            }
+            _ => write_placeholder(&arg.name, sink),
+        }
+    }
+
+    /// Returns the message of a `plural` or `selectordinal` argument for a value, and the value
+    /// minus the offset.
+    fn select_plural<'a>(
+        &self,
+        arg: &'a PluralArgument,
+        value: &FixedDecimal,
+    ) -> Option<(&'a Message, FixedDecimal)> {
+        let number = subtract(value, arg.offset)?;
+        let exact = arg.variants.iter().find(|(key, _)| match key {
+            PluralKey::Exact(exact) => normalize(exact) == normalize(value),
+            PluralKey::Category(_) => false,
+        });
+        let message = match exact {
+            Some((_, message)) => message,
+            None => {
+                let rules = match arg.rule_type {
+                    PluralRuleType::Cardinal => self.format.cardinal.as_ref()?,
+                    PluralRuleType::Ordinal => self.format.ordinal.as_ref()?,
+                };
+                let category = rules.select(&number);
+                let find = |category| {
+                    arg.variants
+                        .iter()
+                        .find(|(key, _)| *key == PluralKey::Category(category))
+                };
+                &find(category).or_else(|| find(PluralCategory::Other))?.1
+            }
+        };
+        Some((message, number))
+    }
+}
+
+impl Writeable for FormattedMessage<'_, '_> {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.write_message(&self.format.message, None, sink)
+    }
+}
+
+impl fmt::Display for FormattedMessage<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+fn write_placeholder<W: fmt::Write + ?Sized>(name: &str, sink: &mut W) -> fmt::Result {
+    sink.write_char('{')?;
+    sink.write_str(name)?;
+    sink.write_char('}')
+}
+
+/// Returns a decimal as a string without trailing fraction zeros and negative zero, so that
+/// equal values have equal strings.
+fn normalize(value: &FixedDecimal) -> String {
+    let mut result = value.writeable_to_string();
+    if result.contains('.') {
+        let len = result.trim_end_matches('0').trim_end_matches('.').len();
+        result.truncate(len);
+    }
+    if result == "-0" {
+        result.remove(0);
+    }
+    result
+}
+
+/// Subtracts the offset of a `plural` argument from its value, keeping the visible fraction
+/// digits. Returns `None` if the value has more digits than an `i128`.
+fn subtract(value: &FixedDecimal, offset: u32) -> Option<FixedDecimal> {
+    if offset == 0 {
+        return Some(value.clone());
+    }
+    let string = value.writeable_to_string();
+    let (negative, digits) = match string.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, string.as_str()),
+    };
+    let (integer, fraction) = match digits.find('.') {
+        Some(idx) => (&digits[..idx], &digits[idx + 1..]),
+        None => (digits, ""),
+    };
+    let mut mantissa: i128 = 0;
+    for digit in integer.bytes().chain(fraction.bytes()) {
+        mantissa = mantissa
+            .checked_mul(10)?
+            .checked_add((digit - b'0') as i128)?;
+    }
+    if negative {
+        mantissa = -mantissa;
+    }
+    let scale = 10_i128.checked_pow(fraction.len() as u32)?;
+    let mantissa = mantissa.checked_sub((offset as i128).checked_mul(scale)?)?;
+    FixedDecimal::from(mantissa)
+        .multiplied_pow10(-(fraction.len() as i16))
+        .ok()
+}
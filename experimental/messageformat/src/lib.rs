@@ -0,0 +1,229 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_messageformat`](crate) formats messages in the syntax of [ICU MessageFormat], like
+//! `"{count, plural, one {# file} other {# files}} in {folder}"`.
+//!
+//! A [`MessageFormat`] parses a message and loads the data of its arguments:
+//!
+//! * `{name}` writes a string, or formats a number like `{name, number}`
+//! * `{name, number}` formats a number with [`FixedDecimalFormat`]
+//! * `{name, date, style}` and `{name, time, style}` format a date or a time with
+//!   [`DateTimeFormat`], in the style `short`, `medium` (the default), `long` or `full`
+//! * `{name, plural, ...}` and `{name, selectordinal, ...}` select a message by an explicit
+//!   value, like `=0`, or by the cardinal or ordinal [`PluralCategory`] of a number, after
+//!   subtracting an optional `offset:n`; `#` in the message stands for that number
+//! * `{name, select, ...}` selects a message by a string
+//!
+//! The syntax of MessageFormat 2 is not supported.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locid_macros::langid;
+//! use icu_messageformat::{MessageArguments, MessageFormat};
+//! use writeable::Writeable;
+//!
+//! let provider = icu_testdata::get_provider();
+//!
+//! let mf = MessageFormat::try_new(
+//!     langid!("en"),
+//!     &provider,
+//!     "{count, plural, =0 {No files} one {# file} other {# files}} in {folder}",
+//! )
+//! .expect("Failed to create a MessageFormat.");
+//!
+//! let mut args = MessageArguments::new();
+//! args.insert("folder", "Documents");
+//!
+//! args.insert("count", 0);
+//! assert_eq!(mf.format(&args).writeable_to_string(), "No files in Documents");
+//! args.insert("count", 1);
+//! assert_eq!(mf.format(&args).writeable_to_string(), "1 file in Documents");
+//! args.insert("count", 1200);
+//! assert_eq!(mf.format(&args).writeable_to_string(), "1,200 files in Documents");
+//! ```
+//!
+//! [ICU MessageFormat]: https://unicode-org.github.io/icu/userguide/format_parse/messages/
+//! [`FixedDecimalFormat`]: icu_decimal::FixedDecimalFormat
+//! [`DateTimeFormat`]: icu_datetime::DateTimeFormat
+//! [`PluralCategory`]: icu_plurals::PluralCategory
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod arguments;
+pub mod ast;
+mod error;
+pub mod format;
+mod parser;
+
+pub use arguments::{ArgumentValue, MessageArguments};
+pub use error::MessageFormatError;
+pub use format::FormattedMessage;
+pub use parser::parse;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use ast::{ArgumentKind, Message, MessagePart};
+use icu_datetime::options::length;
+use icu_datetime::provider::gregory::{DatePatternsV1Marker, DateSymbolsV1Marker};
+use icu_datetime::{DateTimeFormat, DateTimeFormatOptions};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormat;
+use icu_locid::Locale;
+use icu_plurals::provider::PluralRulesV2Marker;
+use icu_plurals::{PluralRuleType, PluralRules};
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+/// A formatter for a message in the syntax of ICU MessageFormat, which holds the parsed
+/// message and the formatters of its arguments.
+///
+/// See the crate-level documentation for examples.
+pub struct MessageFormat<'data> {
+    message: Message,
+    decimal: FixedDecimalFormat<'data>,
+    cardinal: Option<PluralRules<'data>>,
+    ordinal: Option<PluralRules<'data>>,
+    date_times: Vec<(ArgumentKind, DateTimeFormat<'data>)>,
+}
+
+impl<'data> MessageFormat<'data> {
+    /// Parses a message and loads the data to format its arguments in a locale.
+    ///
+    /// This constructor will fail if the message is not well-formed, or if the [`DataProvider`]
+    /// does not have the data of its arguments.
+    pub fn try_new<T, D>(
+        locale: T,
+        data_provider: &D,
+        message: &str,
+    ) -> Result<Self, MessageFormatError>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, DecimalSymbolsV1Marker>
+            + DataProvider<'data, PluralRulesV2Marker>
+            + DataProvider<'data, DateSymbolsV1Marker>
+            + DataProvider<'data, DatePatternsV1Marker>
+            + ?Sized,
+    {
+        Self::try_new_from_message(locale, data_provider, parse(message)?)
+    }
+
+    /// Loads the data to format the arguments of a parsed [`Message`] in a locale.
+    pub fn try_new_from_message<T, D>(
+        locale: T,
+        data_provider: &D,
+        message: Message,
+    ) -> Result<Self, MessageFormatError>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, DecimalSymbolsV1Marker>
+            + DataProvider<'data, PluralRulesV2Marker>
+            + DataProvider<'data, DateSymbolsV1Marker>
+            + DataProvider<'data, DatePatternsV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let mut needs = Needs::default();
+        needs.visit(&message);
+
+        let decimal =
+            FixedDecimalFormat::try_new(locale.clone(), data_provider, Default::default())?;
+        let load_rules = |needed: bool, rule_type| -> Result<_, MessageFormatError> {
+            if needed {
+                Ok(Some(PluralRules::try_new(
+                    locale.clone().into(),
+                    data_provider,
+                    rule_type,
+                )?))
+            } else {
+                Ok(None)
+            }
+        };
+        let cardinal = load_rules(needs.cardinal, PluralRuleType::Cardinal)?;
+        let ordinal = load_rules(needs.ordinal, PluralRuleType::Ordinal)?;
+        let date_times = needs
+            .date_times
+            .into_iter()
+            .map(|kind| {
+                let (date, time) = match kind {
+                    ArgumentKind::Date(date) => (Some(date), None),
+                    ArgumentKind::Time(time) => (None, Some(time)),
+                    _ => (None, None),
+                };
+                let options = DateTimeFormatOptions::Length(length::Bag {
+                    date,
+                    time,
+                    preferences: None,
+                });
+                let dtf = DateTimeFormat::try_new(locale.clone(), data_provider, &options)?;
+                Ok((kind, dtf))
+            })
+            .collect::<Result<_, MessageFormatError>>()?;
+
+        Ok(Self {
+            message,
+            decimal,
+            cardinal,
+            ordinal,
+            date_times,
+        })
+    }
+
+    /// Formats the message with the values of its arguments, returning a [`FormattedMessage`].
+    pub fn format<'l>(&'l self, args: &'l MessageArguments<'l>) -> FormattedMessage<'l, 'data> {
+        FormattedMessage { format: self, args }
+    }
+
+    /// Formats the message with the values of its arguments to a [`String`].
+    pub fn format_to_string(&self, args: &MessageArguments) -> String {
+        self.format(args).writeable_to_string()
+    }
+
+    /// Returns the parsed message.
+    pub fn message(&self) -> &Message {
+        &self.message
+    }
+}
+
+/// The formatters that the arguments of a message need.
+#[derive(Default)]
+struct Needs {
+    cardinal: bool,
+    ordinal: bool,
+    date_times: Vec<ArgumentKind>,
+}
+
+impl Needs {
+    fn visit(&mut self, message: &Message) {
+        for part in message.0.iter() {
+            match part {
+                MessagePart::Simple(arg) => {
+                    if matches!(arg.kind, ArgumentKind::Date(_) | ArgumentKind::Time(_))
+                        && !self.date_times.contains(&arg.kind)
+                    {
+                        self.date_times.push(arg.kind);
+                    }
+                }
+                MessagePart::Plural(arg) => {
+                    match arg.rule_type {
+                        PluralRuleType::Cardinal => self.cardinal = true,
+                        PluralRuleType::Ordinal => self.ordinal = true,
+                    }
+                    for (_, message) in arg.variants.iter() {
+                        self.visit(message);
+                    }
+                }
+                MessagePart::Select(arg) => {
+                    for (_, message) in arg.variants.iter() {
+                        self.visit(message);
+                    }
+                }
+                MessagePart::Literal(_) | MessagePart::Number => {}
+            }
+        }
+    }
+}
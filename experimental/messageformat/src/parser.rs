@@ -0,0 +1,314 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::ast::*;
+use crate::error::MessageFormatError;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::str::FromStr;
+use fixed_decimal::FixedDecimal;
+use icu_datetime::options::length;
+use icu_plurals::{PluralCategory, PluralRuleType};
+
+/// Parses a message in the syntax of ICU MessageFormat.
+///
+/// Apostrophes quote the syntax characters `{`, `}`, `|` and, in the messages of `plural` and
+/// `selectordinal` arguments, `#`. Two apostrophes stand for one.
+///
+/// # Examples
+///
+/// ```
+/// use icu_messageformat::ast::{Message, MessagePart};
+/// use icu_messageformat::parse;
+///
+/// assert_eq!(
+///     parse("It''s '{'quoted'}'").expect("Parsing failed."),
+///     Message(vec![MessagePart::Literal("It's {quoted}".to_string())])
+/// );
+/// assert!(parse("{unclosed").is_err());
+/// ```
+pub fn parse(input: &str) -> Result<Message, MessageFormatError> {
+    let mut parser = Parser { input, pos: 0 };
+    let message = parser.parse_message(false, false)?;
+    if parser.pos < input.len() {
+        return Err(MessageFormatError::Syntax(parser.pos));
+    }
+    Ok(message)
+}
+
+struct Parser<'s> {
+    input: &'s str,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn take_if(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), MessageFormatError> {
+        if self.take_if(c) {
+            Ok(())
+        } else {
+            Err(MessageFormatError::Syntax(self.pos))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+    }
+
+    /// Returns the next word, up to whitespace or a syntax character.
+    fn word(&mut self) -> Result<&'s str, MessageFormatError> {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || matches!(c, '{' | '}' | ',' | '\'' | '#') {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+        if start == self.pos {
+            Err(MessageFormatError::Syntax(start))
+        } else {
+            Ok(&self.input[start..self.pos])
+        }
+    }
+
+    /// Parses a message up to the end of the input or, if it is `nested` in an argument, up to
+    /// the `}` that closes it, which is not consumed.
+    fn parse_message(
+        &mut self,
+        nested: bool,
+        in_plural: bool,
+    ) -> Result<Message, MessageFormatError> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        loop {
+            match self.peek() {
+                None if nested => return Err(MessageFormatError::Syntax(self.pos)),
+                None => break,
+                Some('}') if nested => break,
+                Some('}') => return Err(MessageFormatError::Syntax(self.pos)),
+                Some('{') => {
+                    if !literal.is_empty() {
+                        parts.push(MessagePart::Literal(core::mem::take(&mut literal)));
+                    }
+                    parts.push(self.parse_argument()?);
+                }
+                Some('#') if in_plural => {
+                    self.bump();
+                    if !literal.is_empty() {
+                        parts.push(MessagePart::Literal(core::mem::take(&mut literal)));
+                    }
+                    parts.push(MessagePart::Number);
+                }
+                Some('\'') => {
+                    self.bump();
+                    match self.peek() {
+                        Some('\'') => {
+                            self.bump();
+                            literal.push('\'');
+                        }
+                        Some(c) if matches!(c, '{' | '}' | '|') || (c == '#' && in_plural) => {
+                            // A quoted literal, which ends at the next single apostrophe or at
+                            // the end of the input.
+                            while let Some(c) = self.bump() {
+                                if c != '\'' {
+                                    literal.push(c);
+                                } else if self.take_if('\'') {
+                                    literal.push('\'');
+                                } else {
+                                    break;
+                                }
+                            }
+                        }
+                        _ => literal.push('\''),
+                    }
+                }
+                Some(c) => {
+                    self.bump();
+                    literal.push(c);
+                }
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(MessagePart::Literal(literal));
+        }
+        Ok(Message(parts))
+    }
+
+    fn parse_argument(&mut self) -> Result<MessagePart, MessageFormatError> {
+        self.expect('{')?;
+        self.skip_whitespace();
+        let name = self.word()?.to_string();
+        self.skip_whitespace();
+        if self.take_if('}') {
+            return Ok(MessagePart::Simple(SimpleArgument {
+                name,
+                kind: ArgumentKind::None,
+            }));
+        }
+        self.expect(',')?;
+        self.skip_whitespace();
+        let type_ = self.word()?;
+        self.skip_whitespace();
+        let part = match type_ {
+            "number" => {
+                if self.take_if(',') {
+                    self.skip_whitespace();
+                    return Err(MessageFormatError::UnknownStyle(self.word()?.to_string()));
+                }
+                MessagePart::Simple(SimpleArgument {
+                    name,
+                    kind: ArgumentKind::Number,
+                })
+            }
+            "date" => MessagePart::Simple(SimpleArgument {
+                name,
+                kind: ArgumentKind::Date(match self.parse_style()? {
+                    Some("short") => length::Date::Short,
+                    None | Some("medium") => length::Date::Medium,
+                    Some("long") => length::Date::Long,
+                    Some("full") => length::Date::Full,
+                    Some(style) => return Err(MessageFormatError::UnknownStyle(style.to_string())),
+                }),
+            }),
+            "time" => MessagePart::Simple(SimpleArgument {
+                name,
+                kind: ArgumentKind::Time(match self.parse_style()? {
+                    Some("short") => length::Time::Short,
+                    None | Some("medium") => length::Time::Medium,
+                    Some("long") => length::Time::Long,
+                    Some("full") => length::Time::Full,
+                    Some(style) => return Err(MessageFormatError::UnknownStyle(style.to_string())),
+                }),
+            }),
+            "plural" => MessagePart::Plural(self.parse_plural(name, PluralRuleType::Cardinal)?),
+            "selectordinal" => {
+                MessagePart::Plural(self.parse_plural(name, PluralRuleType::Ordinal)?)
+            }
+            "select" => MessagePart::Select(self.parse_select(name)?),
+            _ => return Err(MessageFormatError::UnknownType(type_.to_string())),
+        };
+        self.skip_whitespace();
+        self.expect('}')?;
+        Ok(part)
+    }
+
+    /// Parses the optional style of a `date` or `time` argument.
+    fn parse_style(&mut self) -> Result<Option<&'s str>, MessageFormatError> {
+        if self.take_if(',') {
+            self.skip_whitespace();
+            Ok(Some(self.word()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn parse_plural(
+        &mut self,
+        name: String,
+        rule_type: PluralRuleType,
+    ) -> Result<PluralArgument, MessageFormatError> {
+        self.expect(',')?;
+        self.skip_whitespace();
+        let start = self.pos;
+        let mut offset = 0;
+        if self.input[self.pos..].starts_with("offset:") {
+            self.pos += "offset:".len();
+            self.skip_whitespace();
+            let value_start = self.pos;
+            offset = self
+                .word()?
+                .parse()
+                .map_err(|_| MessageFormatError::Syntax(value_start))?;
+        }
+        let mut variants = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') {
+                break;
+            }
+            let key = if self.take_if('=') {
+                let value_start = self.pos;
+                PluralKey::Exact(
+                    FixedDecimal::from_str(self.word()?)
+                        .map_err(|_| MessageFormatError::Syntax(value_start))?,
+                )
+            } else {
+                PluralKey::Category(match self.word()? {
+                    "zero" => PluralCategory::Zero,
+                    "one" => PluralCategory::One,
+                    "two" => PluralCategory::Two,
+                    "few" => PluralCategory::Few,
+                    "many" => PluralCategory::Many,
+                    "other" => PluralCategory::Other,
+                    keyword => return Err(MessageFormatError::UnknownKeyword(keyword.to_string())),
+                })
+            };
+            variants.push((key, self.parse_variant(true)?));
+        }
+        if !variants
+            .iter()
+            .any(|(key, _)| *key == PluralKey::Category(PluralCategory::Other))
+        {
+            return Err(MessageFormatError::MissingOther(start));
+        }
+        Ok(PluralArgument {
+            name,
+            rule_type,
+            offset,
+            variants,
+        })
+    }
+
+    fn parse_select(&mut self, name: String) -> Result<SelectArgument, MessageFormatError> {
+        self.expect(',')?;
+        self.skip_whitespace();
+        let start = self.pos;
+        let mut variants = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') {
+                break;
+            }
+            let keyword = self.word()?.to_string();
+            variants.push((keyword, self.parse_variant(false)?));
+        }
+        if !variants.iter().any(|(keyword, _)| keyword == "other") {
+            return Err(MessageFormatError::MissingOther(start));
+        }
+        Ok(SelectArgument { name, variants })
+    }
+
+    /// Parses the message of a variant of a `plural`, `selectordinal` or `select` argument,
+    /// with its braces.
+    fn parse_variant(&mut self, in_plural: bool) -> Result<Message, MessageFormatError> {
+        self.skip_whitespace();
+        self.expect('{')?;
+        let message = self.parse_message(true, in_plural)?;
+        self.expect('}')?;
+        Ok(message)
+    }
+}
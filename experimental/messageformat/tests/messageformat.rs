@@ -0,0 +1,166 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_datetime::mock::datetime::MockDateTime;
+use icu_locid_macros::langid;
+use icu_messageformat::{MessageArguments, MessageFormat, MessageFormatError};
+
+fn format(message: &str, args: &MessageArguments) -> String {
+    let provider = icu_testdata::get_provider();
+    MessageFormat::try_new(langid!("en"), &provider, message)
+        .expect("Failed to create a MessageFormat.")
+        .format_to_string(args)
+}
+
+#[test]
+fn test_plural() {
+    let message = "{count, plural, =0 {none} one {# item} other {# items}}";
+    let mut args = MessageArguments::new();
+    for (count, expected) in [
+        (0, "none"),
+        (1, "1 item"),
+        (2, "2 items"),
+        (1000, "1,000 items"),
+    ] {
+        args.insert("count", count);
+        assert_eq!(format(message, &args), expected);
+    }
+
+    args.insert("count", "1".parse::<fixed_decimal::FixedDecimal>().unwrap());
+    assert_eq!(format(message, &args), "1 item");
+    args.insert(
+        "count",
+        "1.0".parse::<fixed_decimal::FixedDecimal>().unwrap(),
+    );
+    assert_eq!(format(message, &args), "1.0 items");
+}
+
+#[test]
+fn test_plural_offset() {
+    let message = "{guests, plural, offset:1 =0 {Nobody came} =1 {{host} came} \
+                   one {{host} and # guest came} other {{host} and # guests came}}";
+    let mut args = MessageArguments::new();
+    args.insert("host", "Ana");
+    for (guests, expected) in [
+        (0, "Nobody came"),
+        (1, "Ana came"),
+        (2, "Ana and 1 guest came"),
+        (3, "Ana and 2 guests came"),
+    ] {
+        args.insert("guests", guests);
+        assert_eq!(format(message, &args), expected);
+    }
+}
+
+#[test]
+fn test_selectordinal() {
+    let message = "{place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}";
+    let mut args = MessageArguments::new();
+    for (place, expected) in [
+        (1, "1st"),
+        (2, "2nd"),
+        (3, "3rd"),
+        (4, "4th"),
+        (11, "11th"),
+        (22, "22nd"),
+    ] {
+        args.insert("place", place);
+        assert_eq!(format(message, &args), expected);
+    }
+}
+
+#[test]
+fn test_select() {
+    let message = "{gender, select, female {She} male {He} other {They}} replied \
+                   {count, plural, one {once} other {# times}}.";
+    let mut args = MessageArguments::new();
+    args.insert("count", 5);
+    for (gender, expected) in [
+        ("female", "She replied 5 times."),
+        ("male", "He replied 5 times."),
+        ("unknown", "They replied 5 times."),
+    ] {
+        args.insert("gender", gender);
+        assert_eq!(format(message, &args), expected);
+    }
+}
+
+#[test]
+fn test_quoting() {
+    let args = MessageArguments::new();
+    assert_eq!(format("It''s '{'name'}'", &args), "It's {name}");
+    assert_eq!(format("don't # here", &args), "don't # here");
+    assert_eq!(
+        format("{n, plural, other {'#' is #}}", &{
+            let mut args = MessageArguments::new();
+            args.insert("n", 3);
+            args
+        }),
+        "# is 3"
+    );
+}
+
+#[test]
+fn test_number_and_string() {
+    let mut args = MessageArguments::new();
+    args.insert("name", "Ana");
+    args.insert("count", -12345);
+    assert_eq!(
+        format("{name} has {count, number} points, {count}", &args),
+        "Ana has -12,345 points, -12,345"
+    );
+}
+
+#[test]
+fn test_date_time() {
+    let datetime: MockDateTime = "2020-09-24T13:21:00".parse().unwrap();
+    let mut args = MessageArguments::new();
+    args.insert("when", &datetime);
+    assert_eq!(
+        format("{when, date, long} at {when, time, short}", &args),
+        "September 24, 2020 at 1:21 PM"
+    );
+    assert_eq!(format("{when, date, short}", &args), "9/24/20");
+}
+
+#[test]
+fn test_missing_arguments() {
+    let mut args = MessageArguments::new();
+    args.insert("count", "many");
+    assert_eq!(
+        format("{name}: {count, plural, other {#}}", &args),
+        "{name}: {count}"
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    let provider = icu_testdata::get_provider();
+    let error = |message| {
+        MessageFormat::try_new(langid!("en"), &provider, message)
+            .err()
+            .expect("Parsing should fail.")
+    };
+    assert!(matches!(error("{name"), MessageFormatError::Syntax(5)));
+    assert!(matches!(error("a } b"), MessageFormatError::Syntax(2)));
+    assert!(
+        matches!(error("{a, currency}"), MessageFormatError::UnknownType(t) if t == "currency")
+    );
+    assert!(
+        matches!(error("{a, number, percent}"), MessageFormatError::UnknownStyle(s) if s == "percent")
+    );
+    assert!(matches!(error("{a, date, tiny}"), MessageFormatError::UnknownStyle(s) if s == "tiny"));
+    assert!(matches!(
+        error("{a, plural, single {x} other {y}}"),
+        MessageFormatError::UnknownKeyword(k) if k == "single"
+    ));
+    assert!(matches!(
+        error("{a, plural, one {x}}"),
+        MessageFormatError::MissingOther(12)
+    ));
+    assert!(matches!(
+        error("{a, select, x {y}}"),
+        MessageFormatError::MissingOther(12)
+    ));
+}
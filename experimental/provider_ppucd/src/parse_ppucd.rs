@@ -274,7 +274,7 @@ fn get_code_point_prop_vals<'s>(
 fn get_binary_prop_unisets<'s>(
     prop_aliases: &HashMap<&'s str, HashSet<&'s str>>,
     code_points: &HashMap<u32, HashMap<&'s str, &'s str>>,
-) -> HashMap<&'s str, UnicodeSet> {
+) -> HashMap<&'s str, UnicodeSet<'static>> {
     let mut m: HashMap<&'s str, UnicodeSet> = HashMap::new();
 
     for (canonical_name, all_names) in prop_aliases {
@@ -304,7 +304,7 @@ fn get_enum_prop_unisets<'s>(
     enum_prop_aliases: &HashMap<&'s str, HashSet<&'s str>>,
     enum_val_aliases: &HashMap<&'s str, HashMap<&'s str, HashSet<&'s str>>>,
     code_points: &HashMap<u32, HashMap<&'s str, &'s str>>,
-) -> HashMap<Cow<'s, TinyStr16>, UnicodeSet> {
+) -> HashMap<Cow<'s, TinyStr16>, UnicodeSet<'static>> {
     let mut m: HashMap<&str, HashMap<&str, UnicodeSetBuilder>> = HashMap::new();
 
     let enum_val_mappings: HashMap<&str, HashMap<&str, &str>> =
@@ -8,7 +8,10 @@ use icu_segmenter::LineBreakIterator;
 use icu_segmenter::LineBreakIteratorLatin1;
 use icu_segmenter::LineBreakIteratorUtf16;
 use icu_segmenter::LineBreakRule;
+use icu_segmenter::LstmModels;
 use icu_segmenter::WordBreakRule;
+use icu_segmenter_lstm::LstmJsonDataProvider;
+use std::path::PathBuf;
 
 // Example is MIT license.
 const TEST_STR: &str = "Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.";
@@ -42,8 +45,10 @@ fn line_break_iter_utf8(c: &mut Criterion) {
         b.iter(|| LineBreakIterator::new(TEST_STR).count())
     });
 
+    let provider = LstmJsonDataProvider::new(PathBuf::from("../segmenter_lstm/tests/testdata"));
+    let lstm = LstmModels::try_new(&provider).expect("Data should load successfully");
     group.bench_function("Th", |b| {
-        b.iter(|| LineBreakIterator::new(TEST_STR2).count())
+        b.iter(|| LineBreakIterator::new_with_lstm(TEST_STR2, &lstm).count())
     });
 }
 
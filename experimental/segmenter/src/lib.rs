@@ -28,6 +28,31 @@
 //! println!("{:?}", result);
 //! ```
 //!
+//! With the options of a locale, such as `-u-lb-` for the strictness of line breaking.
+//! ```rust
+//! use icu_locid::Locale;
+//! use icu_segmenter::{LineBreakIterator, LineBreakOptions};
+//!
+//! let locale: Locale = "ja-u-lb-loose".parse().expect("Valid locale");
+//! let options = LineBreakOptions::new_with_locale(&locale);
+//! let iter = LineBreakIterator::new_with_options("Hello World", options);
+//! let result: Vec<usize> = iter.collect();
+//! println!("{:?}", result);
+//! ```
+//!
+//! Break opportunities can also be found backwards, or around a position.
+//! ```rust
+//! use icu_segmenter::LineBreakIterator;
+//!
+//! let iter = LineBreakIterator::new("Hello World");
+//! assert!(iter.is_boundary(6));
+//! assert_eq!(iter.preceding(8), Some(6));
+//! assert_eq!(iter.following(8), Some(11));
+//!
+//! let result: Vec<usize> = iter.rev().collect();
+//! assert_eq!(result, [11, 6]);
+//! ```
+//!
 //! Use Latin 1 string for C binding and etc.
 //!
 //! ```rust
@@ -53,16 +78,17 @@ mod properties_other;
 mod property_table;
 mod rule_table;
 
-#[macro_use]
-extern crate lazy_static;
-
 pub use crate::line_breaker::*;
+pub use crate::lstm::LstmModels;
 
 #[cfg(test)]
 mod tests {
     use crate::LineBreakIterator;
     use crate::LineBreakIteratorLatin1;
     use crate::LineBreakIteratorUtf16;
+    use crate::LineBreakOptions;
+    use crate::LineBreakRule;
+    use crate::WordBreakRule;
 
     #[test]
     fn linebreak() {
@@ -144,4 +170,156 @@ mod tests {
         iter = LineBreakIterator::new("\u{1F3FB} \u{1F3FB}");
         assert_eq!(Some(5), iter.next());
     }
+
+    #[test]
+    fn linebreak_reverse() {
+        let mut iter = LineBreakIterator::new("hello world\r\nfoo bar");
+        assert_eq!(Some(20), iter.next_back());
+        assert_eq!(Some(17), iter.next_back());
+        assert_eq!(Some(6), iter.next());
+        assert_eq!(Some(13), iter.next_back());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+
+        let input: Vec<u16> = "(0,1) \u{1F600}\u{1F600}".encode_utf16().collect();
+        let iter = LineBreakIteratorUtf16::new(&input);
+        assert_eq!(vec![10, 8, 6], iter.rev().collect::<Vec<usize>>());
+
+        let iter = LineBreakIteratorLatin1::new(b"[  abc def");
+        assert_eq!(vec![10, 7], iter.rev().collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn linebreak_random_access() {
+        let iter = LineBreakIterator::new("hello world\r\nfoo bar");
+        assert!(!iter.is_boundary(0));
+        assert!(iter.is_boundary(6));
+        assert!(!iter.is_boundary(12));
+        assert!(iter.is_boundary(13));
+        assert!(iter.is_boundary(20));
+        assert!(!iter.is_boundary(21));
+        assert_eq!(None, iter.preceding(6));
+        assert_eq!(Some(6), iter.preceding(7));
+        assert_eq!(Some(6), iter.preceding(13));
+        assert_eq!(Some(13), iter.preceding(16));
+        assert_eq!(Some(6), iter.following(0));
+        assert_eq!(Some(13), iter.following(11));
+        assert_eq!(Some(17), iter.following(13));
+        assert_eq!(None, iter.following(20));
+
+        // Indices inside a character
+        let iter = LineBreakIterator::new("\u{3042}\u{3044}");
+        assert!(!iter.is_boundary(1));
+        assert_eq!(Some(3), iter.preceding(4));
+        assert_eq!(Some(6), iter.following(4));
+
+        let input: Vec<u16> = "a \u{1F600}\u{1F600}".encode_utf16().collect();
+        let iter = LineBreakIteratorUtf16::new(&input);
+        assert!(!iter.is_boundary(3));
+        assert_eq!(Some(4), iter.following(3));
+        assert_eq!(Some(2), iter.preceding(3));
+    }
+
+    #[test]
+    fn linebreak_random_access_long_paragraph() {
+        // A single paragraph, so every query could otherwise scan from the start of the text.
+        let input = "word ".repeat(20_000);
+        let iter = LineBreakIterator::new(&input);
+        let expected: Vec<usize> = (1..=20_000).map(|i| i * 5).collect();
+
+        let result: Vec<usize> = (0..=input.len()).filter(|i| iter.is_boundary(*i)).collect();
+        assert_eq!(expected, result);
+
+        let mut result = Vec::new();
+        let mut pos = input.len() + 1;
+        while let Some(prev) = iter.preceding(pos) {
+            result.push(prev);
+            pos = prev;
+        }
+        result.reverse();
+        assert_eq!(expected, result);
+
+        let mut result = Vec::new();
+        let mut pos = 0;
+        while let Some(next) = iter.following(pos) {
+            result.push(next);
+            pos = next;
+        }
+        assert_eq!(expected, result);
+
+        // Going back to a previous paragraph
+        let input = format!("{}\n{}", input, input);
+        let iter = LineBreakIterator::new(&input);
+        assert_eq!(Some(100_001), iter.following(100_000));
+        assert_eq!(Some(100_006), iter.following(100_001));
+        assert_eq!(Some(99_995), iter.preceding(100_000));
+        assert_eq!(Some(100_001), iter.preceding(100_006));
+
+        // Queries going backwards, each before the break opportunities found so far. These
+        // only go back to the last space before a letter instead of the start of the text.
+        let input = "word ".repeat(200_000);
+        let iter = LineBreakIterator::new(&input);
+        for i in (0..input.len()).rev().step_by(997) {
+            let expected = i / 5 * 5 + 5;
+            assert_eq!(Some(expected), iter.following(i));
+            assert_eq!(i % 5 == 0 && i > 0, iter.is_boundary(i));
+        }
+    }
+
+    #[test]
+    fn linebreak_random_access_safe_points() {
+        // Text before the spaces that the rules look back across: opening punctuation,
+        // possibly with combining marks, closing punctuation before NS, quotation marks before
+        // OP, numbers, regional indicators and zero width spaces.
+        let inputs = [
+            "ab (  cd ef",
+            "ab (\u{0301} cd ef",
+            "ab ) \u{3005}cd ef",
+            "ab \" ( cd ef",
+            "$1. 2 ab cd",
+            "\u{1F1E6}\u{1F1E6}\u{1F1E6} a b",
+            "ab\u{200B}  cd ef",
+            "\u{0E01}\u{0E32} ab \u{3042} \u{3002}cd",
+            "a\r\n b\n\n c",
+        ];
+        let line_break_rules = [
+            LineBreakRule::Strict,
+            LineBreakRule::Normal,
+            LineBreakRule::Loose,
+            LineBreakRule::Anywhere,
+        ];
+        let word_break_rules = [
+            WordBreakRule::Normal,
+            WordBreakRule::BreakAll,
+            WordBreakRule::KeepAll,
+        ];
+        for input in inputs {
+            for line_break_rule in line_break_rules {
+                for word_break_rule in word_break_rules {
+                    let options = LineBreakOptions {
+                        line_break_rule,
+                        word_break_rule,
+                        ja_zh: true,
+                        overflow_wrap_anywhere: false,
+                    };
+                    let expected: Vec<usize> =
+                        LineBreakIterator::new_with_options(input, options).collect();
+                    let mut reversed: Vec<usize> =
+                        LineBreakIterator::new_with_options(input, options)
+                            .rev()
+                            .collect();
+                    reversed.reverse();
+                    assert_eq!(expected, reversed, "{:?}", input);
+
+                    // A new iterator for each query, so that none of them are cached.
+                    let result: Vec<usize> = (0..=input.len())
+                        .filter(|i| {
+                            LineBreakIterator::new_with_options(input, options).is_boundary(*i)
+                        })
+                        .collect();
+                    assert_eq!(expected, result, "{:?}", input);
+                }
+            }
+        }
+    }
 }
@@ -9,11 +9,14 @@ use crate::lstm::*;
 use crate::property_table::*;
 use crate::rule_table::*;
 
+use core::cell::RefCell;
 use core::char;
 use core::str::CharIndices;
+use icu_locid::extensions::unicode::Key;
+use icu_locid::Locale;
 use unicode_width::UnicodeWidthChar;
 
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub enum LineBreakRule {
     /// Use `line-break: normal;` line break rule
     Normal,
@@ -25,7 +28,7 @@ pub enum LineBreakRule {
     Anywhere,
 }
 
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub enum WordBreakRule {
     /// Use `word-break: normal;` line break rule
     Normal,
@@ -33,6 +36,96 @@ pub enum WordBreakRule {
     BreakAll,
     /// Use `word-break: keep-all;` line break rule
     KeepAll,
+    /// Use `word-break: auto-phrase;` line break rule
+    ///
+    /// Japanese text is only broken between phrases. Phrases are approximated without a
+    /// dictionary: a run of hiragana ends a phrase, since particles and inflections are written in
+    /// hiragana after the word they attach to. Other text is broken like `keep-all`.
+    Phrase,
+}
+
+/// Options to tailor line breaking to the CSS `line-break`, `word-break` and `overflow-wrap`
+/// properties, or to a locale.
+///
+/// # Examples
+///
+/// ```rust
+/// use icu_locid::Locale;
+/// use icu_segmenter::{LineBreakIterator, LineBreakOptions, LineBreakRule, WordBreakRule};
+///
+/// let locale: Locale = "ja-u-lb-loose-lw-phrase".parse().expect("Valid locale");
+/// let options = LineBreakOptions::new_with_locale(&locale);
+/// assert_eq!(options.line_break_rule, LineBreakRule::Loose);
+/// assert_eq!(options.word_break_rule, WordBreakRule::Phrase);
+/// assert!(options.ja_zh);
+///
+/// let breaks: Vec<usize> =
+///     LineBreakIterator::new_with_options("私はその人を常に先生と呼んでいた", options).collect();
+/// assert_eq!(breaks, [12, 18, 24, 33, 48]);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct LineBreakOptions {
+    /// Strictness of line breaking, as given by the CSS `line-break` property.
+    pub line_break_rule: LineBreakRule,
+    /// Line break opportunities between letters, as given by the CSS `word-break` property.
+    pub word_break_rule: WordBreakRule,
+    /// Use `true` if the content language is Chinese or Japanese. Some line break opportunities
+    /// of `line-break: normal;` and `line-break: loose;` only apply to these languages.
+    pub ja_zh: bool,
+    /// Use `true` for `overflow-wrap: anywhere;`. A word that doesn't fit on a line may then be
+    /// broken between any two characters, except inside grapheme clusters or before spaces, if
+    /// the line has no other break opportunity. These emergency break opportunities are not
+    /// returned by the iterators, but are given by their `is_emergency_boundary` methods.
+    pub overflow_wrap_anywhere: bool,
+}
+
+impl Default for LineBreakOptions {
+    fn default() -> Self {
+        Self {
+            line_break_rule: LineBreakRule::Strict,
+            word_break_rule: WordBreakRule::Normal,
+            ja_zh: false,
+            overflow_wrap_anywhere: false,
+        }
+    }
+}
+
+impl LineBreakOptions {
+    /// Creates the line break options of a locale.
+    ///
+    /// The strictness is read from the `lb` Unicode extension keyword (`loose`, `normal` or
+    /// `strict`), and the word break rule from the `lw` keyword (`normal`, `breakall`, `keepall`
+    /// or `phrase`). Missing or unknown values use the defaults. `ja_zh` is set for the Japanese
+    /// and Chinese languages.
+    pub fn new_with_locale(locale: &Locale) -> Self {
+        let mut options = Self::default();
+        let language = locale.id.language;
+        options.ja_zh = language == "ja" || language == "zh";
+
+        let keywords = &locale.extensions.unicode.keywords;
+        if let Some(value) = keywords.get(key(b"lb")) {
+            options.line_break_rule = match value.to_string().as_str() {
+                "loose" => LineBreakRule::Loose,
+                "normal" => LineBreakRule::Normal,
+                "strict" => LineBreakRule::Strict,
+                _ => options.line_break_rule,
+            };
+        }
+        if let Some(value) = keywords.get(key(b"lw")) {
+            options.word_break_rule = match value.to_string().as_str() {
+                "normal" => WordBreakRule::Normal,
+                "breakall" => WordBreakRule::BreakAll,
+                "keepall" => WordBreakRule::KeepAll,
+                "phrase" => WordBreakRule::Phrase,
+                _ => options.word_break_rule,
+            };
+        }
+        options
+    }
+}
+
+fn key(key: &[u8]) -> Key {
+    Key::from_bytes(key).expect("Valid Unicode extension key")
 }
 
 fn get_linebreak_property_utf32_with_rule(
@@ -206,46 +299,261 @@ fn is_non_break_by_keepall(left: u8, right: u8) -> bool {
 }
 
 #[inline]
-fn get_break_state_from_table(rule_table: &[i8], property_count: usize, left: u8, right: u8) -> i8 {
-    rule_table[((left as usize) - 1) * property_count + (right as usize) - 1]
+fn is_break_by_phrase(left_codepoint: u32, right_codepoint: u32) -> bool {
+    // A phrase ends after a run of hiragana. The prolonged sound mark continues the run, but
+    // doesn't start one, since it mostly follows katakana.
+    let is_hiragana = |c: u32| (0x3041..=0x309f).contains(&c);
+    is_hiragana(left_codepoint) && !is_hiragana(right_codepoint) && right_codepoint != 0x30fc
 }
 
 #[inline]
-fn get_break_state(left: u8, right: u8) -> i8 {
-    get_break_state_from_table(&UAX14_RULE_TABLE, PROP_COUNT, left, right)
+fn is_mandatory_break(left: u8, right: u8) -> bool {
+    // LB4 and LB5
+    left == BK || left == LF || left == NL || (left == CR && right != LF)
+}
+
+#[inline]
+fn is_emergency_break(left: u8, right: u8) -> bool {
+    // Grapheme clusters are kept together, and spaces and line breaks stay at the end of the
+    // line.
+    !(left == ZWJ
+        || right == CM
+        || right == ZWJ
+        || right == EM
+        || right == SP
+        || right == BK
+        || right == CR
+        || right == LF
+        || right == NL
+        || right == ZW)
 }
 
 #[inline]
-fn use_complex_breaking_utf32(codepoint: u32) -> bool {
-    // Thai
-    (0xe01..=0xe7f).contains(&codepoint)
+fn get_break_state_from_table(rule_table: &[i8], property_count: usize, left: u8, right: u8) -> i8 {
+    rule_table[((left as usize) - 1) * property_count + (right as usize) - 1]
 }
 
-/*
 #[inline]
-fn use_complex_breaking_utf32(codepoint: u32) -> bool {
-    // Thai, Lao and Khmer
-    (codepoint >= 0xe01 && codepoint <= 0xeff) || (codepoint >= 0x1780 && codepoint <= 0x17ff)
+fn get_break_state(left: u8, right: u8) -> i8 {
+    get_break_state_from_table(&UAX14_RULE_TABLE, PROP_COUNT, left, right)
+}
+
+/// Break opportunities found by `is_boundary`, `preceding` and `following`.
+///
+/// The break opportunities after a safe point don't depend on the text before it, so the ones
+/// found from `start` are kept and only extended for later queries at or after `start`. Going
+/// through the text in either direction then takes linear time overall.
+struct Boundaries<'a> {
+    // Safe point the break opportunities were found from.
+    start: usize,
+    // Break opportunities after `start` found so far, in order.
+    breaks: Vec<usize>,
+    // Finds the break opportunities after the last one in `breaks`.
+    iter: Box<dyn Iterator<Item = usize> + 'a>,
 }
-*/
 
 macro_rules! break_iterator_impl {
-    ($name:ident, $iter_attr:ty, $char_type:ty) => {
+    ($name:ident, $iter_attr:ty, $char_type:ty, $input_type:ty) => {
         #[allow(dead_code)]
         pub struct $name<'a> {
+            input: &'a $input_type,
             iter: $iter_attr,
             len: usize,
             current_pos_data: Option<(usize, $char_type)>,
             result_cache: Vec<usize>,
-            break_rule: LineBreakRule,
-            word_break_rule: WordBreakRule,
-            ja_zh: bool,
+            options: LineBreakOptions,
+            lstm: Option<&'a LstmModels<'a>>,
+            // Last break opportunity returned by `next`, or 0.
+            front_pos: usize,
+            // Last break opportunity returned by `next_back`, or `len + 1`.
+            back_pos: usize,
+            // Break opportunities before `back_pos` that `next_back` hasn't returned yet.
+            back_cache: Vec<usize>,
+            boundaries: RefCell<Option<Boundaries<'a>>>,
         }
 
         impl<'a> Iterator for $name<'a> {
             type Item = usize;
 
             fn next(&mut self) -> Option<Self::Item> {
+                let pos = self.next_forward()?;
+                if pos >= self.back_pos {
+                    // Met the break opportunities returned by `next_back`.
+                    return None;
+                }
+                self.front_pos = pos;
+                Some(pos)
+            }
+        }
+
+        impl<'a> DoubleEndedIterator for $name<'a> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.back_cache.is_empty() && self.back_pos > self.front_pos + 1 {
+                    // Break opportunities are only found going forward, so find all of those
+                    // after the safe point before `back_pos` at once.
+                    let start = self.safe_point(self.back_pos - 2);
+                    let back_pos = self.back_pos;
+                    if start > 0 {
+                        self.back_cache.push(start);
+                    }
+                    let breaks = self.iter_from(start).take_while(|pos| *pos < back_pos);
+                    self.back_cache.extend(breaks);
+                }
+                match self.back_cache.pop() {
+                    Some(pos) if pos > self.front_pos => {
+                        self.back_pos = pos;
+                        Some(pos)
+                    }
+                    _ => {
+                        // Met the break opportunities returned by `next`.
+                        self.back_cache.clear();
+                        self.back_pos = self.front_pos + 1;
+                        None
+                    }
+                }
+            }
+        }
+
+        impl<'a> $name<'a> {
+            /// Returns `true` if there is a break opportunity at `index`.
+            ///
+            /// Like the iterator, the start of the text is not a break opportunity, and the end of
+            /// non-empty text is. This doesn't move the iterator.
+            pub fn is_boundary(&self, index: usize) -> bool {
+                index > 0 && self.following(index - 1) == Some(index)
+            }
+
+            /// Returns `true` if there is an emergency break opportunity of
+            /// `overflow-wrap: anywhere;` at `index`, where a word may be broken if its line has
+            /// no other break opportunity. There are none unless `overflow_wrap_anywhere` is set
+            /// in the options, and the break opportunities returned by the iterator are not
+            /// emergency ones. This doesn't move the iterator.
+            pub fn is_emergency_boundary(&self, index: usize) -> bool {
+                if !self.options.overflow_wrap_anywhere
+                    || index >= self.len
+                    || self.floor_char_boundary(index) != index
+                {
+                    return false;
+                }
+                let (left, right) = match (self.previous_char(index), self.next_char(index)) {
+                    (Some((_, left)), Some(right)) => (left, right),
+                    _ => return false,
+                };
+                let prop = |c: $char_type| {
+                    get_linebreak_property_utf32_with_rule(
+                        c as u32,
+                        LineBreakRule::Strict,
+                        WordBreakRule::Normal,
+                    )
+                };
+                is_emergency_break(prop(left), prop(right)) && !self.is_boundary(index)
+            }
+
+            /// Returns the last break opportunity before `index`. This doesn't move the iterator.
+            pub fn preceding(&self, index: usize) -> Option<usize> {
+                if index == 0 {
+                    return None;
+                }
+                self.with_boundaries(index - 1, |start, breaks| {
+                    match breaks.partition_point(|pos| *pos < index) {
+                        0 if start > 0 => Some(start),
+                        0 => None,
+                        i => Some(breaks[i - 1]),
+                    }
+                })
+            }
+
+            /// Returns the first break opportunity after `index`. This doesn't move the iterator.
+            pub fn following(&self, index: usize) -> Option<usize> {
+                self.with_boundaries(index, |_, breaks| {
+                    let i = breaks.partition_point(|pos| *pos <= index);
+                    breaks.get(i).copied()
+                })
+            }
+
+            /// Calls `f` with a safe point at or before `index` and the break
+            /// opportunities after it, which include the first one after `index` if any.
+            fn with_boundaries<R>(&self, index: usize, f: impl FnOnce(usize, &[usize]) -> R) -> R {
+                let mut boundaries = self.boundaries.borrow_mut();
+                if !matches!(&*boundaries, Some(boundaries) if boundaries.start <= index) {
+                    let start = self.safe_point(index);
+                    *boundaries = Some(Boundaries {
+                        start,
+                        breaks: Vec::new(),
+                        iter: Box::new(self.iter_from(start)),
+                    });
+                }
+                let boundaries = boundaries.as_mut().unwrap();
+                while boundaries.breaks.last().map_or(true, |pos| *pos <= index) {
+                    match boundaries.iter.next() {
+                        Some(pos) => boundaries.breaks.push(pos),
+                        None => break,
+                    }
+                }
+                f(boundaries.start, &boundaries.breaks)
+            }
+
+            /// Returns the last safe point at or before `index`: the start of the text, or a
+            /// break opportunity that the break opportunities after it don't depend on the text
+            /// before. These are the mandatory breaks and the letters after spaces, unless the
+            /// spaces follow an opening punctuation. No rule looks back across a space and the
+            /// letter after it, so going back to one doesn't depend on the length of the line.
+            fn safe_point(&self, index: usize) -> usize {
+                let mut pos = self.floor_char_boundary(index.min(self.len));
+                let mut right_prop = None;
+                while let Some((start, c)) = self.previous_char(pos) {
+                    let left_prop = get_linebreak_property_utf32_with_rule(
+                        c as u32,
+                        LineBreakRule::Strict,
+                        WordBreakRule::Normal,
+                    );
+                    let safe = match right_prop {
+                        Some(right_prop) => {
+                            is_mandatory_break(left_prop, right_prop)
+                                || (left_prop == SP
+                                    && (right_prop == AL || right_prop == HL)
+                                    && self.is_break_after_spaces(start))
+                        }
+                        // Whether CR is followed by a break depends on the next character.
+                        None => left_prop != CR && is_mandatory_break(left_prop, EOT),
+                    };
+                    if safe {
+                        return pos;
+                    }
+                    right_prop = Some(left_prop);
+                    pos = start;
+                }
+                0
+            }
+
+            /// Returns `true` if the spaces ending at `index` are followed by a break
+            /// opportunity before a letter: they don't follow an opening punctuation (LB14),
+            /// which may be hidden by combining marks (LB9).
+            fn is_break_after_spaces(&self, index: usize) -> bool {
+                let mut pos = index;
+                while let Some((start, c)) = self.previous_char(pos) {
+                    match get_linebreak_property_utf32_with_rule(
+                        c as u32,
+                        LineBreakRule::Strict,
+                        WordBreakRule::Normal,
+                    ) {
+                        SP => pos = start,
+                        OP_EA | OP_OP30 | CM | ZWJ => return false,
+                        _ => return true,
+                    }
+                }
+                true
+            }
+
+            /// Returns an iterator over the break opportunities after `start`, which must be
+            /// a safe point.
+            fn iter_from(&self, start: usize) -> impl Iterator<Item = usize> + 'a {
+                let mut iter = Self::new_with_options(&self.input[start..], self.options);
+                iter.lstm = self.lstm;
+                iter.map(move |pos| start + pos)
+            }
+
+            fn next_forward(&mut self) -> Option<usize> {
                 if self.is_eof() {
                     return None;
                 }
@@ -279,8 +587,13 @@ macro_rules! break_iterator_impl {
                     }
                     let right_prop = self.get_linebreak_property();
 
+                    // Mandatory breaks aren't tailored by the CSS properties.
+                    if is_mandatory_break(left_prop, right_prop) {
+                        return Some(self.current_pos_data.unwrap().0);
+                    }
+
                     // CSS word-break property handling
-                    match self.word_break_rule {
+                    match self.options.word_break_rule {
                         WordBreakRule::BreakAll => {
                             left_prop = match left_prop {
                                 AL => ID,
@@ -294,11 +607,21 @@ macro_rules! break_iterator_impl {
                                 continue;
                             }
                         }
+                        WordBreakRule::Phrase => {
+                            if is_non_break_by_keepall(left_prop, right_prop)
+                                && !is_break_by_phrase(
+                                    left_codepoint.unwrap().1 as u32,
+                                    self.current_pos_data.unwrap().1 as u32,
+                                )
+                            {
+                                continue;
+                            }
+                        }
                         _ => (),
                     }
 
                     // CSS line-break property handling
-                    match self.break_rule {
+                    match self.options.line_break_rule {
                         LineBreakRule::Normal => {
                             if self.is_break_by_normal() {
                                 return Some(self.current_pos_data.unwrap().0);
@@ -310,7 +633,7 @@ macro_rules! break_iterator_impl {
                                 self.current_pos_data.unwrap().1 as u32,
                                 left_prop,
                                 right_prop,
-                                self.ja_zh,
+                                self.options.ja_zh,
                             ) {
                                 if breakable {
                                     return Some(self.current_pos_data.unwrap().0);
@@ -325,9 +648,10 @@ macro_rules! break_iterator_impl {
                     };
 
                     // UAX14 doesn't have Thai etc, so use another way.
-                    if self.word_break_rule != WordBreakRule::BreakAll
-                        && $name::use_complex_breaking(left_codepoint.unwrap().1)
-                        && $name::use_complex_breaking(self.current_pos_data.unwrap().1)
+                    let left_script = self.complex_script(left_codepoint.unwrap().1);
+                    if self.options.word_break_rule != WordBreakRule::BreakAll
+                        && left_script.is_some()
+                        && left_script == self.complex_script(self.current_pos_data.unwrap().1)
                     {
                         let result = self.handle_complex_language(left_codepoint.unwrap().1);
                         if result.is_some() {
@@ -381,9 +705,7 @@ macro_rules! break_iterator_impl {
                     }
                 }
             }
-        }
 
-        impl<'a> $name<'a> {
             #[inline]
             fn is_eof(&mut self) -> bool {
                 if self.current_pos_data.is_none() {
@@ -395,9 +717,30 @@ macro_rules! break_iterator_impl {
                 return false;
             }
 
+            /// Returns the script of a character if it is broken by one of the LSTM models of
+            /// the iterator.
+            #[inline]
+            fn complex_script(&self, c: $char_type) -> Option<ComplexScript> {
+                let script = ComplexScript::from_codepoint(c as u32)?;
+                self.lstm?.get(script).map(|_| script)
+            }
+
+            fn get_line_break_by_lstm(&self, input: &[u16], script: ComplexScript) -> Vec<usize> {
+                let breaks = self
+                    .lstm
+                    .and_then(|lstm| lstm.get(script))
+                    .and_then(|lstm| get_line_break_utf16(lstm, input));
+                if let Some(mut ret) = breaks {
+                    ret.push(input.len());
+                    return ret;
+                }
+                [input.len()].to_vec()
+            }
+
             // UAX14 doesn't define line break rules for some languages such as Thai.
-            // These languages uses dictionary-based breaker, so we use OS's line breaker instead.
+            // These languages uses dictionary-based breaker, so we use the LSTM model of the script instead.
             fn handle_complex_language(&mut self, left_codepoint: $char_type) -> Option<usize> {
+                let script = self.complex_script(left_codepoint)?;
                 let start_iter = self.iter.clone();
                 let start_point = self.current_pos_data;
                 let mut s = vec![left_codepoint as u16];
@@ -407,14 +750,14 @@ macro_rules! break_iterator_impl {
                     if self.current_pos_data.is_none() {
                         break;
                     }
-                    if !$name::use_complex_breaking(self.current_pos_data.unwrap().1) {
+                    if self.complex_script(self.current_pos_data.unwrap().1) != Some(script) {
                         break;
                     }
                 }
                 // Restore iterator to move to head of complex string
                 self.iter = start_iter;
                 self.current_pos_data = start_point;
-                let breaks = self.get_line_break_by_platform_fallback(&s);
+                let breaks = self.get_line_break_by_lstm(&s, script);
                 let mut i = 1;
                 self.result_cache = breaks;
                 // result_cache vector is utf-16 index that is in BMP.
@@ -436,20 +779,12 @@ macro_rules! break_iterator_impl {
     };
 }
 
-break_iterator_impl!(LineBreakIterator, CharIndices<'a>, char);
+break_iterator_impl!(LineBreakIterator, CharIndices<'a>, char, str);
 
 impl<'a> LineBreakIterator<'a> {
     /// Create line break iterator
     pub fn new(input: &str) -> LineBreakIterator {
-        LineBreakIterator {
-            iter: input.char_indices(),
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            break_rule: LineBreakRule::Strict,
-            word_break_rule: WordBreakRule::Normal,
-            ja_zh: false,
-        }
+        Self::new_with_options(input, LineBreakOptions::default())
     }
 
     /// Create line break iterator with CSS rules
@@ -459,40 +794,80 @@ impl<'a> LineBreakIterator<'a> {
         word_break_rule: WordBreakRule,
         ja_zh: bool,
     ) -> LineBreakIterator {
+        Self::new_with_options(
+            input,
+            LineBreakOptions {
+                line_break_rule,
+                word_break_rule,
+                ja_zh,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Create line break iterator with options
+    pub fn new_with_options(input: &str, options: LineBreakOptions) -> LineBreakIterator {
         LineBreakIterator {
+            input,
             iter: input.char_indices(),
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
-            break_rule: line_break_rule,
-            word_break_rule,
-            ja_zh,
+            options,
+            lstm: None,
+            front_pos: 0,
+            back_pos: input.len() + 1,
+            back_cache: Vec::new(),
+            boundaries: RefCell::new(None),
         }
     }
 
+    /// Create line break iterator that breaks Thai, Lao, Khmer and Burmese text with LSTM models
+    pub fn new_with_lstm(input: &'a str, lstm: &'a LstmModels<'a>) -> LineBreakIterator<'a> {
+        Self::new_with_options_and_lstm(input, LineBreakOptions::default(), lstm)
+    }
+
+    /// Create line break iterator with options that breaks Thai, Lao, Khmer and Burmese text
+    /// with LSTM models
+    pub fn new_with_options_and_lstm(
+        input: &'a str,
+        options: LineBreakOptions,
+        lstm: &'a LstmModels<'a>,
+    ) -> LineBreakIterator<'a> {
+        let mut iter = Self::new_with_options(input, options);
+        iter.lstm = Some(lstm);
+        iter
+    }
+
     fn get_linebreak_property(&mut self) -> u8 {
         self.get_linebreak_property_with_rule(self.current_pos_data.unwrap().1)
     }
 
     fn get_linebreak_property_with_rule(&mut self, c: char) -> u8 {
-        get_linebreak_property_with_rule(c, self.break_rule, self.word_break_rule)
+        get_linebreak_property_with_rule(
+            c,
+            self.options.line_break_rule,
+            self.options.word_break_rule,
+        )
     }
 
     fn is_break_by_normal(&mut self) -> bool {
-        is_break_utf32_by_normal(self.current_pos_data.unwrap().1 as u32, self.ja_zh)
+        is_break_utf32_by_normal(self.current_pos_data.unwrap().1 as u32, self.options.ja_zh)
     }
 
-    #[inline]
-    fn use_complex_breaking(c: char) -> bool {
-        use_complex_breaking_utf32(c as u32)
+    fn floor_char_boundary(&self, mut index: usize) -> usize {
+        while !self.input.is_char_boundary(index) {
+            index -= 1;
+        }
+        index
     }
 
-    fn get_line_break_by_platform_fallback(&mut self, input: &[u16]) -> Vec<usize> {
-        if let Some(mut ret) = get_line_break_utf16(input) {
-            ret.push(input.len());
-            return ret;
-        }
-        [input.len()].to_vec()
+    fn previous_char(&self, index: usize) -> Option<(usize, char)> {
+        self.input[..index].char_indices().next_back()
+    }
+
+    fn next_char(&self, index: usize) -> Option<char> {
+        self.input[index..].chars().next()
     }
 
     /*
@@ -539,23 +914,12 @@ impl<'a> Iterator for Latin1Indices<'a> {
     }
 }
 
-break_iterator_impl!(LineBreakIteratorLatin1, Latin1Indices<'a>, u8);
+break_iterator_impl!(LineBreakIteratorLatin1, Latin1Indices<'a>, u8, [u8]);
 
 impl<'a> LineBreakIteratorLatin1<'a> {
     /// Create line break iterator using Latin-1/8-bit string.
     pub fn new(input: &[u8]) -> LineBreakIteratorLatin1 {
-        LineBreakIteratorLatin1 {
-            iter: Latin1Indices {
-                front_offset: 0,
-                iter: input,
-            },
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            break_rule: LineBreakRule::Strict,
-            word_break_rule: WordBreakRule::Normal,
-            ja_zh: false,
-        }
+        Self::new_with_options(input, LineBreakOptions::default())
     }
 
     /// Create line break iterator with CSS rules using Latin-1/8-bit string.
@@ -565,7 +929,21 @@ impl<'a> LineBreakIteratorLatin1<'a> {
         word_break_rule: WordBreakRule,
         ja_zh: bool,
     ) -> LineBreakIteratorLatin1 {
+        Self::new_with_options(
+            input,
+            LineBreakOptions {
+                line_break_rule,
+                word_break_rule,
+                ja_zh,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Create line break iterator with options using Latin-1/8-bit string.
+    pub fn new_with_options(input: &[u8], options: LineBreakOptions) -> LineBreakIteratorLatin1 {
         LineBreakIteratorLatin1 {
+            input,
             iter: Latin1Indices {
                 front_offset: 0,
                 iter: input,
@@ -573,9 +951,12 @@ impl<'a> LineBreakIteratorLatin1<'a> {
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
-            break_rule: line_break_rule,
-            word_break_rule,
-            ja_zh,
+            options,
+            lstm: None,
+            front_pos: 0,
+            back_pos: input.len() + 1,
+            back_cache: Vec::new(),
+            boundaries: RefCell::new(None),
         }
     }
 
@@ -590,16 +971,20 @@ impl<'a> LineBreakIteratorLatin1<'a> {
     }
 
     fn is_break_by_normal(&mut self) -> bool {
-        is_break_utf32_by_normal(self.current_pos_data.unwrap().1 as u32, self.ja_zh)
+        is_break_utf32_by_normal(self.current_pos_data.unwrap().1 as u32, self.options.ja_zh)
     }
 
-    #[inline]
-    fn use_complex_breaking(_c: u8) -> bool {
-        false
+    fn floor_char_boundary(&self, index: usize) -> usize {
+        index
     }
 
-    fn get_line_break_by_platform_fallback(&mut self, _input: &[u16]) -> Vec<usize> {
-        panic!("not reachable");
+    fn previous_char(&self, index: usize) -> Option<(usize, u8)> {
+        let index = index.checked_sub(1)?;
+        Some((index, self.input[index]))
+    }
+
+    fn next_char(&self, index: usize) -> Option<u8> {
+        self.input.get(index).copied()
     }
 }
 
@@ -639,23 +1024,12 @@ impl<'a> Iterator for Utf16Indices<'a> {
     }
 }
 
-break_iterator_impl!(LineBreakIteratorUtf16, Utf16Indices<'a>, u32);
+break_iterator_impl!(LineBreakIteratorUtf16, Utf16Indices<'a>, u32, [u16]);
 
 impl<'a> LineBreakIteratorUtf16<'a> {
     /// Create line break iterator using UTF-16 string.
     pub fn new(input: &[u16]) -> LineBreakIteratorUtf16 {
-        LineBreakIteratorUtf16 {
-            iter: Utf16Indices {
-                front_offset: 0,
-                iter: input,
-            },
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            break_rule: LineBreakRule::Strict,
-            word_break_rule: WordBreakRule::Normal,
-            ja_zh: false,
-        }
+        Self::new_with_options(input, LineBreakOptions::default())
     }
 
     /// Create line break iterator with CSS rules using UTF-16 string.
@@ -665,7 +1039,21 @@ impl<'a> LineBreakIteratorUtf16<'a> {
         word_break_rule: WordBreakRule,
         ja_zh: bool,
     ) -> LineBreakIteratorUtf16 {
+        Self::new_with_options(
+            input,
+            LineBreakOptions {
+                line_break_rule,
+                word_break_rule,
+                ja_zh,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Create line break iterator with options using UTF-16 string.
+    pub fn new_with_options(input: &[u16], options: LineBreakOptions) -> LineBreakIteratorUtf16 {
         LineBreakIteratorUtf16 {
+            input,
             iter: Utf16Indices {
                 front_offset: 0,
                 iter: input,
@@ -673,35 +1061,79 @@ impl<'a> LineBreakIteratorUtf16<'a> {
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
-            break_rule: line_break_rule,
-            word_break_rule,
-            ja_zh,
+            options,
+            lstm: None,
+            front_pos: 0,
+            back_pos: input.len() + 1,
+            back_cache: Vec::new(),
+            boundaries: RefCell::new(None),
         }
     }
 
+    /// Create line break iterator using UTF-16 string that breaks Thai, Lao, Khmer and Burmese
+    /// text with LSTM models
+    pub fn new_with_lstm(input: &'a [u16], lstm: &'a LstmModels<'a>) -> LineBreakIteratorUtf16<'a> {
+        Self::new_with_options_and_lstm(input, LineBreakOptions::default(), lstm)
+    }
+
+    /// Create line break iterator with options using UTF-16 string that breaks Thai, Lao, Khmer
+    /// and Burmese text with LSTM models
+    pub fn new_with_options_and_lstm(
+        input: &'a [u16],
+        options: LineBreakOptions,
+        lstm: &'a LstmModels<'a>,
+    ) -> LineBreakIteratorUtf16<'a> {
+        let mut iter = Self::new_with_options(input, options);
+        iter.lstm = Some(lstm);
+        iter
+    }
+
     fn get_linebreak_property(&mut self) -> u8 {
         self.get_linebreak_property_with_rule(self.current_pos_data.unwrap().1)
     }
 
     fn get_linebreak_property_with_rule(&mut self, c: u32) -> u8 {
-        get_linebreak_property_utf32_with_rule(c, self.break_rule, self.word_break_rule)
+        get_linebreak_property_utf32_with_rule(
+            c,
+            self.options.line_break_rule,
+            self.options.word_break_rule,
+        )
     }
 
     fn is_break_by_normal(&mut self) -> bool {
-        is_break_utf32_by_normal(self.current_pos_data.unwrap().1 as u32, self.ja_zh)
+        is_break_utf32_by_normal(self.current_pos_data.unwrap().1 as u32, self.options.ja_zh)
     }
 
-    #[inline]
-    fn use_complex_breaking(c: u32) -> bool {
-        use_complex_breaking_utf32(c)
+    fn floor_char_boundary(&self, index: usize) -> usize {
+        // Don't split a surrogate pair.
+        if index > 0
+            && index < self.len
+            && (self.input[index] & 0xfc00) == 0xdc00
+            && (self.input[index - 1] & 0xfc00) == 0xd800
+        {
+            return index - 1;
+        }
+        index
     }
 
-    fn get_line_break_by_platform_fallback(&mut self, input: &[u16]) -> Vec<usize> {
-        if let Some(mut ret) = get_line_break_utf16(input) {
-            ret.push(input.len());
-            return ret;
+    fn previous_char(&self, index: usize) -> Option<(usize, u32)> {
+        let index = index.checked_sub(1)?;
+        let ch = self.input[index] as u32;
+        if (ch & 0xfc00) == 0xdc00 && index > 0 {
+            let lead = self.input[index - 1] as u32;
+            if (lead & 0xfc00) == 0xd800 {
+                return Some((index - 1, ((lead & 0x3ff) << 10) + (ch & 0x3ff) + 0x10000));
+            }
         }
-        [input.len()].to_vec()
+        Some((index, ch))
+    }
+
+    fn next_char(&self, index: usize) -> Option<u32> {
+        let mut iter = Utf16Indices {
+            front_offset: index,
+            iter: self.input,
+        };
+        iter.next().map(|(_, ch)| ch)
     }
 }
 
@@ -2,23 +2,102 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use icu_locid::LanguageIdentifier;
+use icu_provider::prelude::*;
 use icu_segmenter_lstm::lstm::Lstm;
+use icu_segmenter_lstm::provider::LstmDataV1Marker;
+use icu_segmenter_lstm::LstmError;
 use std::char::decode_utf16;
+use std::str::FromStr;
 
-// TODO:
-// json file is big, So I should use anoher binary format like npy.
-// But provided npy uses tensorflow dtype.
-const THAI_MODEL: &[u8; 373466] = include_bytes!(
-    "../../segmenter_lstm/tests/testdata/Thai_codepoints_exclusive_model4_heavy/weights.json"
-);
-
-lazy_static! {
-    static ref THAI_LSTM: Lstm = {
-        let lstm_data = serde_json::from_slice(THAI_MODEL).expect("JSON syntax error");
-        Lstm::try_new(lstm_data).unwrap()
-    };
+/// A script that is segmented by an LSTM model, since UAX #14 doesn't define its rules.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub(crate) enum ComplexScript {
+    Thai,
+    Lao,
+    Khmer,
+    Burmese,
 }
 
+impl ComplexScript {
+    /// Returns the script of a code point, or `None` if the code point is not in one of the
+    /// scripts handled by an LSTM model.
+    #[inline]
+    pub(crate) fn from_codepoint(codepoint: u32) -> Option<Self> {
+        match codepoint {
+            0x0e01..=0x0e7f => Some(ComplexScript::Thai),
+            0x0e80..=0x0eff => Some(ComplexScript::Lao),
+            0x1000..=0x109f => Some(ComplexScript::Burmese),
+            0x1780..=0x17ff => Some(ComplexScript::Khmer),
+            _ => None,
+        }
+    }
+}
+
+/// The LSTM models used to segment text in Thai, Lao, Khmer and Burmese, loaded from a data
+/// provider.
+///
+/// Models that are missing from the data provider are skipped; text in those scripts is then
+/// only broken at the boundaries given by UAX #14, as it is by the segmenters that are not given
+/// models.
+///
+/// # Examples
+///
+/// ```no_run
+/// use icu_segmenter::{LineBreakIterator, LstmModels};
+/// use icu_segmenter_lstm::LstmJsonDataProvider;
+/// use std::path::PathBuf;
+///
+/// let provider = LstmJsonDataProvider::new(PathBuf::from("/path/to/models"));
+/// let lstm = LstmModels::try_new(&provider).expect("Data should load successfully");
+///
+/// let breaks: Vec<usize> = LineBreakIterator::new_with_lstm("ภาษาไทยภาษาไทย", &lstm).collect();
+/// ```
+pub struct LstmModels<'data> {
+    thai: Option<Lstm<'data>>,
+    lao: Option<Lstm<'data>>,
+    khmer: Option<Lstm<'data>>,
+    burmese: Option<Lstm<'data>>,
+}
+
+impl<'data> LstmModels<'data> {
+    /// Loads the models of all supported scripts from a data provider.
+    pub fn try_new<D>(data_provider: &D) -> Result<Self, LstmError>
+    where
+        D: DataProvider<'data, LstmDataV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            thai: load_model("th", data_provider)?,
+            lao: load_model("lo", data_provider)?,
+            khmer: load_model("km", data_provider)?,
+            burmese: load_model("my", data_provider)?,
+        })
+    }
+
+    /// Returns the model used for a script, if it was loaded.
+    pub(crate) fn get(&self, script: ComplexScript) -> Option<&Lstm<'data>> {
+        match script {
+            ComplexScript::Thai => self.thai.as_ref(),
+            ComplexScript::Lao => self.lao.as_ref(),
+            ComplexScript::Khmer => self.khmer.as_ref(),
+            ComplexScript::Burmese => self.burmese.as_ref(),
+        }
+    }
+}
+
+fn load_model<'data, D>(language: &str, data_provider: &D) -> Result<Option<Lstm<'data>>, LstmError>
+where
+    D: DataProvider<'data, LstmDataV1Marker> + ?Sized,
+{
+    let langid = LanguageIdentifier::from_str(language).expect("Valid language identifier");
+    match Lstm::try_new(langid, data_provider) {
+        Ok(lstm) => Ok(Some(lstm)),
+        Err(LstmError::DataProvider(DataError::MissingResourceOptions(_))) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
 struct LstmSegmenterIterator {
     input: String,
     bies_str: String,
@@ -26,6 +105,7 @@ struct LstmSegmenterIterator {
     pos_utf8: usize,
 }
 
+#[cfg(test)]
 impl Iterator for LstmSegmenterIterator {
     type Item = usize;
 
@@ -41,8 +121,8 @@ impl Iterator for LstmSegmenterIterator {
     }
 }
 
+#[cfg(test)]
 impl LstmSegmenterIterator {
-    #[cfg(test)]
     pub fn new(lstm: &Lstm, input: &str) -> Self {
         let lstm_output = lstm.word_segmenter(input);
         Self {
@@ -85,8 +165,8 @@ impl LstmSegmenterIteratorUtf16 {
 }
 
 #[cfg(test)]
-pub fn get_line_break_utf8(input: &str) -> Option<Vec<usize>> {
-    let iter = LstmSegmenterIterator::new(&*THAI_LSTM, input);
+pub fn get_line_break_utf8(lstm: &Lstm, input: &str) -> Option<Vec<usize>> {
+    let iter = LstmSegmenterIterator::new(lstm, input);
     let result: Vec<usize> = iter.collect();
     if result.is_empty() {
         return None;
@@ -94,11 +174,11 @@ pub fn get_line_break_utf8(input: &str) -> Option<Vec<usize>> {
     Some(result)
 }
 
-pub fn get_line_break_utf16(input: &[u16]) -> Option<Vec<usize>> {
+pub fn get_line_break_utf16(lstm: &Lstm, input: &[u16]) -> Option<Vec<usize>> {
     let s: String = decode_utf16(input.iter().cloned())
         .map(|r| r.unwrap())
         .collect();
-    let iter = LstmSegmenterIteratorUtf16::new(&*THAI_LSTM, &s);
+    let iter = LstmSegmenterIteratorUtf16::new(lstm, &s);
     let result: Vec<usize> = iter.collect();
     if result.is_empty() {
         return None;
@@ -110,26 +190,72 @@ pub fn get_line_break_utf16(input: &[u16]) -> Option<Vec<usize>> {
 mod tests {
     use crate::lstm::get_line_break_utf16;
     use crate::lstm::get_line_break_utf8;
+    use crate::lstm::{ComplexScript, LstmModels};
+    use icu_segmenter_lstm::LstmJsonDataProvider;
+    use std::path::PathBuf;
+
+    fn load_models() -> LstmModels<'static> {
+        let provider = LstmJsonDataProvider::new(PathBuf::from("../segmenter_lstm/tests/testdata"));
+        LstmModels::try_new(&provider).expect("Data should load successfully")
+    }
 
     #[test]
     fn thai_word_break() {
         const TEST_STR: &str = "ภาษาไทยภาษาไทย";
+        let models = load_models();
+        let lstm = models
+            .get(ComplexScript::Thai)
+            .expect("Thai model is present");
 
-        let breaks = get_line_break_utf8(TEST_STR);
+        let breaks = get_line_break_utf8(lstm, TEST_STR);
         assert_eq!(breaks.unwrap(), [12, 21, 33], "Thai test");
     }
 
     #[test]
     fn thai_word_break_utf16() {
+        let models = load_models();
+        let lstm = models
+            .get(ComplexScript::Thai)
+            .expect("Thai model is present");
+
         let text: [u16; 14] = [
             0x0e20, 0x0e32, 0x0e29, 0x0e32, 0x0e44, 0x0e17, 0x0e22, 0x0e20, 0x0e32, 0x0e29, 0x0e32,
             0x0e44, 0x0e17, 0x0e22,
         ];
-        let breaks = get_line_break_utf16(&text);
+        let breaks = get_line_break_utf16(lstm, &text);
         assert_eq!(breaks.unwrap(), [4, 7, 11], "Thai test");
 
         let text: [u16; 4] = [0x0e20, 0x0e32, 0x0e29, 0x0e32];
-        let breaks = get_line_break_utf16(&text);
+        let breaks = get_line_break_utf16(lstm, &text);
         assert_eq!(breaks, None, "Thai test");
     }
+
+    #[test]
+    fn missing_models() {
+        let models = load_models();
+        assert!(models.get(ComplexScript::Lao).is_none());
+        assert!(models.get(ComplexScript::Khmer).is_none());
+        assert!(models.get(ComplexScript::Burmese).is_none());
+    }
+
+    #[test]
+    fn script_from_codepoint() {
+        assert_eq!(
+            ComplexScript::from_codepoint(0x0e01),
+            Some(ComplexScript::Thai)
+        );
+        assert_eq!(
+            ComplexScript::from_codepoint(0x0e9a),
+            Some(ComplexScript::Lao)
+        );
+        assert_eq!(
+            ComplexScript::from_codepoint(0x1780),
+            Some(ComplexScript::Khmer)
+        );
+        assert_eq!(
+            ComplexScript::from_codepoint(0x1019),
+            Some(ComplexScript::Burmese)
+        );
+        assert_eq!(ComplexScript::from_codepoint(0x0041), None);
+    }
 }
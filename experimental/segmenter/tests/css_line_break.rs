@@ -2,8 +2,10 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use icu_locid::Locale;
 use icu_segmenter::LineBreakIterator;
 use icu_segmenter::LineBreakIteratorUtf16;
+use icu_segmenter::LineBreakOptions;
 use icu_segmenter::LineBreakRule;
 use icu_segmenter::WordBreakRule;
 
@@ -91,6 +93,48 @@ fn anywhere(s: &str, ja_zh: bool, expect_utf8: Vec<usize>, expect_utf16: Vec<usi
     assert_eq!(expect_utf16, result, "{}", s);
 }
 
+fn with_options(
+    s: &str,
+    options: LineBreakOptions,
+    expect_utf8: Vec<usize>,
+    expect_utf16: Vec<usize>,
+) {
+    let iter = LineBreakIterator::new_with_options(s, options);
+    let result: Vec<usize> = iter.collect();
+    assert_eq!(expect_utf8, result, "{}", s);
+
+    let s_utf16: Vec<u16> = s.encode_utf16().collect();
+    let iter = LineBreakIteratorUtf16::new_with_options(&s_utf16, options);
+    let result: Vec<usize> = iter.collect();
+    assert_eq!(expect_utf16, result, "{}", s);
+}
+
+fn emergency(
+    s: &str,
+    options: LineBreakOptions,
+    expect_utf8: Vec<usize>,
+    expect_utf16: Vec<usize>,
+) {
+    let iter = LineBreakIterator::new_with_options(s, options);
+    let result: Vec<usize> = (0..=s.len())
+        .filter(|i| iter.is_emergency_boundary(*i))
+        .collect();
+    assert_eq!(expect_utf8, result, "{}", s);
+
+    let s_utf16: Vec<u16> = s.encode_utf16().collect();
+    let iter = LineBreakIteratorUtf16::new_with_options(&s_utf16, options);
+    let result: Vec<usize> = (0..=s_utf16.len())
+        .filter(|i| iter.is_emergency_boundary(*i))
+        .collect();
+    assert_eq!(expect_utf16, result, "{}", s);
+}
+
+fn with_locale(locale: &str, s: &str, expect_utf8: Vec<usize>, expect_utf16: Vec<usize>) {
+    let locale: Locale = locale.parse().expect("Valid locale");
+    let options = LineBreakOptions::new_with_locale(&locale);
+    with_options(s, options, expect_utf8, expect_utf16);
+}
+
 #[test]
 fn linebreak_strict() {
     // from css/css-text/line-break/line-break-*-011.xht
@@ -345,3 +389,137 @@ fn linebreak_anywhere() {
         vec![1, 2, 3, 4, 5, 6, 7],
     );
 }
+
+#[test]
+fn linebreak_overflow_wrap_anywhere() {
+    let options = LineBreakOptions {
+        overflow_wrap_anywhere: true,
+        ..Default::default()
+    };
+
+    // css/css-text/overflow-wrap/overflow-wrap-anywhere-001.html
+    // Words are only broken anywhere if the line has no other break opportunity, so these
+    // emergency break opportunities are separate from those of the iterator.
+    with_options("XXXXX XX", options, vec![6, 8], vec![6, 8]);
+    emergency(
+        "XXXXX XX",
+        options,
+        vec![1, 2, 3, 4, 7],
+        vec![1, 2, 3, 4, 7],
+    );
+
+    // Combining marks and zero width joiners aren't separated from the preceding character.
+    emergency("ae\u{0301}a\u{200D}a", options, vec![1, 4], vec![1, 3]);
+
+    // Unlike `line-break: anywhere;`, spaces and line feeds stay at the end of the line.
+    with_options("a  b\nc", options, vec![3, 5, 6], vec![3, 5, 6]);
+    emergency("a  b\nc", options, vec![], vec![]);
+
+    // Supplementary characters aren't split.
+    emergency("a\u{10400}b", options, vec![1, 5], vec![1, 3]);
+
+    // There are none without `overflow-wrap: anywhere;`.
+    emergency("XXXXX XX", LineBreakOptions::default(), vec![], vec![]);
+}
+
+#[test]
+fn linebreak_mandatory() {
+    // Mandatory breaks aren't tailored by `overflow-wrap`, even before a combining mark or a
+    // zero width joiner.
+    let options = LineBreakOptions {
+        overflow_wrap_anywhere: true,
+        ..Default::default()
+    };
+    with_options("a\n\u{0301}b", options, vec![2, 5], vec![2, 4]);
+    emergency("a\n\u{0301}b", options, vec![4], vec![3]);
+    with_options("a\r\n\u{200D}b", options, vec![3, 7], vec![3, 5]);
+
+    // Nor by `word-break`.
+    for word_break_rule in [
+        WordBreakRule::KeepAll,
+        WordBreakRule::BreakAll,
+        WordBreakRule::Phrase,
+    ] {
+        let options = LineBreakOptions {
+            word_break_rule,
+            ..Default::default()
+        };
+        with_options("字\n字", options, vec![4, 7], vec![2, 3]);
+        with_options("a\u{2028}\u{0301}", options, vec![4, 6], vec![2, 3]);
+    }
+}
+
+#[test]
+fn linebreak_phrase() {
+    let options = LineBreakOptions {
+        word_break_rule: WordBreakRule::Phrase,
+        ja_zh: true,
+        ..Default::default()
+    };
+
+    // A phrase ends after the hiragana that follows a word.
+    with_options(
+        "私はその人を常に先生と呼んでいた。",
+        options,
+        vec![12, 18, 24, 33, 51],
+        vec![4, 6, 8, 11, 17],
+    );
+
+    // Katakana words and the prolonged sound mark stay together.
+    with_options("コーヒーを飲みます", options, vec![15, 27], vec![5, 9]);
+
+    // Text without hiragana is broken like `word-break: keep-all;`.
+    with_options("한국어 문장", options, vec![10, 16], vec![4, 6]);
+}
+
+#[test]
+fn linebreak_locale() {
+    // The default strictness is strict.
+    with_locale("ja", "サ\u{3005}サ", vec![6, 9], vec![2, 3]);
+    with_locale("ja-u-lb-strict", "サ\u{3005}サ", vec![6, 9], vec![2, 3]);
+    with_locale(
+        "ja-u-lb-loose",
+        "サ\u{3005}サ",
+        vec![3, 6, 9],
+        vec![1, 2, 3],
+    );
+
+    // Unknown values are ignored.
+    with_locale("ja-u-lb-xyz", "サ\u{3005}サ", vec![6, 9], vec![2, 3]);
+
+    // Some rules of `line-break: normal;` only apply to Chinese and Japanese.
+    with_locale(
+        "ja-u-lb-normal",
+        "サ\u{301C}サ",
+        vec![3, 6, 9],
+        vec![1, 2, 3],
+    );
+    with_locale(
+        "zh-u-lb-normal",
+        "サ\u{301C}サ",
+        vec![3, 6, 9],
+        vec![1, 2, 3],
+    );
+    with_locale("ko-u-lb-normal", "サ\u{301C}サ", vec![6, 9], vec![2, 3]);
+
+    // The `lw` keyword selects the word break rule.
+    with_locale(
+        "ko",
+        "한국어 문장",
+        vec![3, 6, 10, 13, 16],
+        vec![1, 2, 4, 5, 6],
+    );
+    with_locale("ko-u-lw-keepall", "한국어 문장", vec![10, 16], vec![4, 6]);
+    with_locale(
+        "en-u-lw-breakall",
+        "latin",
+        vec![1, 2, 3, 4, 5],
+        vec![1, 2, 3, 4, 5],
+    );
+    with_locale(
+        "ja-u-lw-phrase",
+        "私はその人を常に先生と呼んでいた",
+        vec![12, 18, 24, 33, 48],
+        vec![4, 6, 8, 11, 16],
+    );
+}
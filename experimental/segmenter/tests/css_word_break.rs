@@ -4,8 +4,12 @@
 
 use icu_segmenter::LineBreakIterator;
 use icu_segmenter::LineBreakIteratorUtf16;
+use icu_segmenter::LineBreakOptions;
 use icu_segmenter::LineBreakRule;
+use icu_segmenter::LstmModels;
 use icu_segmenter::WordBreakRule;
+use icu_segmenter_lstm::LstmJsonDataProvider;
+use std::path::PathBuf;
 
 fn break_all(s: &str, expect_utf8: Vec<usize>, expect_utf16: Vec<usize>) {
     let iter = LineBreakIterator::new_with_break_rule(
@@ -49,6 +53,24 @@ fn keep_all(s: &str, expect_utf8: Vec<usize>, expect_utf16: Vec<usize>) {
     assert_eq!(expect_utf16, result, "{}", s);
 }
 
+fn keep_all_with_lstm(s: &str, expect_utf8: Vec<usize>, expect_utf16: Vec<usize>) {
+    let provider = LstmJsonDataProvider::new(PathBuf::from("../segmenter_lstm/tests/testdata"));
+    let lstm = LstmModels::try_new(&provider).expect("Data should load successfully");
+    let options = LineBreakOptions {
+        word_break_rule: WordBreakRule::KeepAll,
+        ..Default::default()
+    };
+
+    let iter = LineBreakIterator::new_with_options_and_lstm(s, options, &lstm);
+    let result: Vec<usize> = iter.collect();
+    assert_eq!(expect_utf8, result, "{}", s);
+
+    let s_utf16: Vec<u16> = s.encode_utf16().collect();
+    let iter = LineBreakIteratorUtf16::new_with_options_and_lstm(&s_utf16, options, &lstm);
+    let result: Vec<usize> = iter.collect();
+    assert_eq!(expect_utf16, result, "{}", s);
+}
+
 fn normal(s: &str, expect_utf8: Vec<usize>, expect_utf16: Vec<usize>) {
     let iter = LineBreakIterator::new_with_break_rule(
         s,
@@ -157,7 +179,8 @@ fn wordbreak_keepall() {
 
     // from css/css-text/word-break/word-break-keep-all-003.html
     let s = "และและ";
-    keep_all(s, vec![9, 18], vec![3, 6]);
+    keep_all(s, vec![18], vec![6]);
+    keep_all_with_lstm(s, vec![9, 18], vec![3, 6]);
 
     // from css/css-text/word-break/word-break-keep-all-005.html
     let s = "字\u{3000}字";
@@ -176,11 +199,27 @@ fn wordbreak_keepall() {
     keep_all(s, vec![27], vec![9]);
 }
 
+fn normal_with_lstm(s: &str, expect_utf8: Vec<usize>, expect_utf16: Vec<usize>) {
+    let provider = LstmJsonDataProvider::new(PathBuf::from("../segmenter_lstm/tests/testdata"));
+    let lstm = LstmModels::try_new(&provider).expect("Data should load successfully");
+
+    let iter = LineBreakIterator::new_with_lstm(s, &lstm);
+    let result: Vec<usize> = iter.collect();
+    assert_eq!(expect_utf8, result, "{}", s);
+
+    let s_utf16: Vec<u16> = s.encode_utf16().collect();
+    let iter = LineBreakIteratorUtf16::new_with_lstm(&s_utf16, &lstm);
+    let result: Vec<usize> = iter.collect();
+    assert_eq!(expect_utf16, result, "{}", s);
+}
+
 #[test]
 fn wordbreak_normal() {
     {
         let s = "\u{0e20}\u{0e32}\u{0e29}\u{0e32}\u{0e44}\u{0e17}\u{0e22}\u{0e20}\u{0e32}\u{0e29}\u{0e32}\u{0e44}\u{0e17}\u{0e22}";
-        normal(s, vec![12, 21, 33, 42], vec![4, 7, 11, 14]);
+        // Without LSTM models, Thai text is only broken by the rules of UAX #14.
+        normal(s, vec![42], vec![14]);
+        normal_with_lstm(s, vec![12, 21, 33, 42], vec![4, 7, 11, 14]);
     }
 }
 
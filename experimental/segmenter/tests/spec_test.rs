@@ -82,6 +82,15 @@ fn run_line_break_test() {
             println!("UTF8: {}", line);
             let result: Vec<usize> = iter.collect();
             assert_eq!(result, char_break, "{}", line);
+
+            let iter = LineBreakIterator::new(&s);
+            let mut result: Vec<usize> = iter.rev().collect();
+            result.reverse();
+            assert_eq!(result, char_break, "reverse: {}", line);
+
+            let iter = LineBreakIterator::new(&s);
+            let result: Vec<usize> = (0..=s.len()).filter(|i| iter.is_boundary(*i)).collect();
+            assert_eq!(result, char_break, "is_boundary: {}", line);
         }
 
         {
@@ -89,6 +98,11 @@ fn run_line_break_test() {
             let iter = LineBreakIteratorUtf16::new(&u16_vec);
             let result: Vec<usize> = iter.collect();
             assert_eq!(result, u16_break, "UTF16: {}", line);
+
+            let iter = LineBreakIteratorUtf16::new(&u16_vec);
+            let mut result: Vec<usize> = iter.rev().collect();
+            result.reverse();
+            assert_eq!(result, u16_break, "UTF16 reverse: {}", line);
         }
 
         if ascii_only {
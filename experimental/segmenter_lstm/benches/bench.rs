@@ -0,0 +1,59 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+mod ndarray_lstm;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use icu_provider::prelude::*;
+use icu_segmenter_lstm::lstm::Lstm;
+use icu_segmenter_lstm::provider::LstmDataV1Marker;
+use ndarray_lstm::NdarrayLstm;
+use serde::Deserialize;
+use std::fs;
+use std::rc::Rc;
+
+#[derive(Deserialize)]
+struct TestCase {
+    unseg: String,
+}
+
+#[derive(Deserialize)]
+struct TestTextData {
+    testcases: Vec<TestCase>,
+}
+
+fn load_lstm_data(embedding: &str) -> DataPayload<'static, LstmDataV1Marker> {
+    let filename = format!(
+        "tests/testdata/Thai_{}_exclusive_model4_heavy/weights.json",
+        embedding
+    );
+    let bytes: Rc<[u8]> = fs::read(filename).expect("File should be present").into();
+    DataPayload::try_from_rc_buffer_badly(bytes, |bytes| serde_json::from_slice(bytes))
+        .expect("JSON syntax error")
+}
+
+fn load_test_text(embedding: &str) -> String {
+    let filename = format!("tests/testdata/test_text_{}.json", embedding);
+    let bytes = fs::read(filename).expect("File should be present");
+    let data: TestTextData = serde_json::from_slice(&bytes).expect("JSON syntax error");
+    data.testcases.into_iter().map(|t| t.unseg).collect()
+}
+
+fn lstm_thai(c: &mut Criterion) {
+    for embedding in ["codepoints", "graphclust"].iter() {
+        let payload = load_lstm_data(embedding);
+        let baseline = NdarrayLstm::new(payload.get());
+        let lstm = Lstm::try_new_from_payload(payload).expect("The model should be valid");
+        let text = load_test_text(embedding);
+
+        let mut group = c.benchmark_group(format!("LSTM/Thai/{}", embedding));
+        group.throughput(Throughput::Elements(text.chars().count() as u64));
+        group.bench_function("ndarray", |b| b.iter(|| baseline.word_segmenter(&text)));
+        group.bench_function("zerovec", |b| b.iter(|| lstm.word_segmenter(&text)));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, lstm_thai);
+criterion_main!(benches);
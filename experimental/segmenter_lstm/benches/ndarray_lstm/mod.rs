@@ -0,0 +1,176 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The `ndarray` implementation of the LSTM inference that `icu_segmenter_lstm` used to ship,
+//! kept as a baseline for the benchmarks.
+
+use icu_segmenter_lstm::provider::{LstmDataV1, LstmMatrix};
+use ndarray::{s, Array1, Array2, ArrayView1};
+use std::collections::HashMap;
+use unicode_segmentation::UnicodeSegmentation;
+
+fn to_arr1(mat: &LstmMatrix) -> Array1<f32> {
+    Array1::from(mat.data.to_vec())
+}
+
+fn to_arr2(mat: &LstmMatrix) -> Array2<f32> {
+    Array2::from_shape_vec((mat.axis_len(0), mat.axis_len(1)), mat.data.to_vec())
+        .expect("Dimensions are checked by the data provider")
+}
+
+fn sigmoid_arr1(arr: ArrayView1<f32>) -> Array1<f32> {
+    arr.mapv(|x| 1.0 / (1.0 + (-x).exp()))
+}
+
+fn tanh_arr1(arr: ArrayView1<f32>) -> Array1<f32> {
+    arr.mapv(f32::tanh)
+}
+
+fn softmax(arr: Array1<f32>) -> Array1<f32> {
+    let sm: f32 = arr.iter().map(|x| x.exp()).sum();
+    arr.mapv(|x| x.exp() / sm)
+}
+
+fn max_arr1(arr: ArrayView1<f32>) -> usize {
+    let mut mx: f32 = 0.0;
+    let mut ind = 0;
+    for (i, x) in arr.iter().enumerate() {
+        if mx < *x {
+            mx = *x;
+            ind = i;
+        }
+    }
+    ind
+}
+
+fn change_row(mut arr: Array2<f32>, row_id: usize, new_row: &Array1<f32>) -> Array2<f32> {
+    for i in 0..new_row.shape()[0] {
+        arr[[row_id, i]] = new_row[i];
+    }
+    arr
+}
+
+fn concatenate_arr1(arr1: ArrayView1<f32>, arr2: ArrayView1<f32>) -> Array1<f32> {
+    let mut out = Array1::<f32>::zeros(arr1.len() + arr2.len());
+    for i in 0..arr1.len() {
+        out[i] = arr1[i]
+    }
+    for i in 0..arr2.len() {
+        out[arr1.len() + i] = arr2[i]
+    }
+    out
+}
+
+pub struct NdarrayLstm {
+    codepoints: bool,
+    dic: HashMap<String, i16>,
+    mat1: Array2<f32>,
+    mat2: Array2<f32>,
+    mat3: Array2<f32>,
+    mat4: Array1<f32>,
+    mat5: Array2<f32>,
+    mat6: Array2<f32>,
+    mat7: Array1<f32>,
+    mat8: Array2<f32>,
+    mat9: Array1<f32>,
+}
+
+impl NdarrayLstm {
+    pub fn new(data: &LstmDataV1) -> Self {
+        Self {
+            codepoints: data.model.contains("_codepoints_"),
+            dic: data.dic.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+            mat1: to_arr2(&data.mat1),
+            mat2: to_arr2(&data.mat2),
+            mat3: to_arr2(&data.mat3),
+            mat4: to_arr1(&data.mat4),
+            mat5: to_arr2(&data.mat5),
+            mat6: to_arr2(&data.mat6),
+            mat7: to_arr1(&data.mat7),
+            mat8: to_arr2(&data.mat8),
+            mat9: to_arr1(&data.mat9),
+        }
+    }
+
+    fn return_id(&self, g: &str) -> i16 {
+        match self.dic.get(g) {
+            Some(g_id) => *g_id,
+            None => self.dic.len() as i16,
+        }
+    }
+
+    fn compute_hc(
+        x_t: ArrayView1<f32>,
+        h_tm1: &Array1<f32>,
+        c_tm1: &Array1<f32>,
+        warr: &Array2<f32>,
+        uarr: &Array2<f32>,
+        barr: &Array1<f32>,
+    ) -> (Array1<f32>, Array1<f32>) {
+        let s_t = x_t.dot(warr) + h_tm1.dot(uarr) + barr;
+        let hunits = uarr.shape()[0];
+        let i = sigmoid_arr1(s_t.slice(s![..hunits]));
+        let f = sigmoid_arr1(s_t.slice(s![hunits..2 * hunits]));
+        let _c = tanh_arr1(s_t.slice(s![2 * hunits..3 * hunits]));
+        let o = sigmoid_arr1(s_t.slice(s![3 * hunits..]));
+        let c_t = i * _c + f * c_tm1;
+        let h_t = o * tanh_arr1(c_t.view());
+        (h_t, c_t)
+    }
+
+    pub fn word_segmenter(&self, input: &str) -> String {
+        let input_seq: Vec<i16> = if self.codepoints {
+            input
+                .chars()
+                .map(|c| self.return_id(&c.to_string()))
+                .collect()
+        } else {
+            UnicodeSegmentation::graphemes(input, true)
+                .map(|s| self.return_id(s))
+                .collect()
+        };
+        let input_seq_len = input_seq.len();
+        let hunits = self.mat3.shape()[0];
+
+        let mut c_fw = Array1::<f32>::zeros(hunits);
+        let mut h_fw = Array1::<f32>::zeros(hunits);
+        let mut all_h_fw = Array2::<f32>::zeros((input_seq_len, hunits));
+        for (i, g_id) in input_seq.iter().enumerate() {
+            let x_t = self.mat1.slice(s![*g_id as isize, ..]);
+            let (new_h, new_c) =
+                Self::compute_hc(x_t, &h_fw, &c_fw, &self.mat2, &self.mat3, &self.mat4);
+            h_fw = new_h;
+            c_fw = new_c;
+            all_h_fw = change_row(all_h_fw, i, &h_fw);
+        }
+
+        let mut c_bw = Array1::<f32>::zeros(hunits);
+        let mut h_bw = Array1::<f32>::zeros(hunits);
+        let mut all_h_bw = Array2::<f32>::zeros((input_seq_len, hunits));
+        for (i, g_id) in input_seq.iter().rev().enumerate() {
+            let x_t = self.mat1.slice(s![*g_id as isize, ..]);
+            let (new_h, new_c) =
+                Self::compute_hc(x_t, &h_bw, &c_bw, &self.mat5, &self.mat6, &self.mat7);
+            h_bw = new_h;
+            c_bw = new_c;
+            all_h_bw = change_row(all_h_bw, input_seq_len - 1 - i, &h_bw);
+        }
+
+        let mut bies = String::from("");
+        for i in 0..input_seq_len {
+            let curr_fw = all_h_fw.slice(s![i, ..]);
+            let curr_bw = all_h_bw.slice(s![i, ..]);
+            let concat_lstm = concatenate_arr1(curr_fw, curr_bw);
+            let curr_est = concat_lstm.dot(&self.mat8) + &self.mat9;
+            let probs = softmax(curr_est);
+            bies.push(match max_arr1(probs.view()) {
+                0 => 'b',
+                1 => 'i',
+                2 => 'e',
+                _ => 's',
+            });
+        }
+        bies
+    }
+}
@@ -2,10 +2,12 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use icu_provider::prelude::DataError;
+
 #[derive(Debug)]
 pub enum Error {
     /// This error shows that if size of a data component is larger that what is should be.
-    /// Example: the list of grapheme clusters must have at most std::i16::MAX number of elements
+    /// Example: the list of grapheme clusters must have at most i16::MAX number of elements
     Limit,
 
     /// This error shows that if there is a syntax error.
@@ -14,4 +16,13 @@ pub enum Error {
 
     /// This error shows if matrices are not compatible for multiplication.
     DimensionMismatch,
+
+    /// An error originating inside of the data provider.
+    DataProvider(DataError),
+}
+
+impl From<DataError> for Error {
+    fn from(e: DataError) -> Self {
+        Error::DataProvider(e)
+    }
 }
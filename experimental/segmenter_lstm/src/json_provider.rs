@@ -0,0 +1,140 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::*;
+use icu_locid::LanguageIdentifier;
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use std::fs;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::str::FromStr;
+
+/// Language names used as prefixes of the model directories, and the language identifiers
+/// under which the models are exported.
+const MODEL_LANGUAGES: [(&str, &str); 4] = [
+    ("Thai", "th"),
+    ("Lao", "lo"),
+    ("Khmer", "km"),
+    ("Burmese", "my"),
+];
+
+/// A data provider reading LSTM models from the `weights.json` files produced by the model
+/// training scripts.
+///
+/// The root directory contains one directory per model, named after the language and the
+/// embedding system of the model, for example `Thai_codepoints_exclusive_model4_heavy`. Only
+/// models using the `codepoints` embedding are served, since the segmenters compute boundaries
+/// per code point.
+///
+/// **Important:** This data provider implementation is not optimized for production use.
+/// It is much more efficient if you export the models with `icu4x-datagen` instead.
+pub struct LstmJsonDataProvider {
+    root_dir: PathBuf,
+}
+
+impl LstmJsonDataProvider {
+    pub fn new(root_dir: PathBuf) -> Self {
+        LstmJsonDataProvider { root_dir }
+    }
+
+    /// Returns the path of the `weights.json` file of the model for the given language name,
+    /// if there is one.
+    fn get_model_path(&self, language_name: &str) -> Option<PathBuf> {
+        let prefix = format!("{}_codepoints_", language_name);
+        let mut entries: Vec<PathBuf> = fs::read_dir(&self.root_dir)
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map(|name| name.starts_with(&prefix))
+                    .unwrap_or(false)
+            })
+            .map(|path| path.join("weights.json"))
+            .filter(|path| path.is_file())
+            .collect();
+        // Pick a deterministic model if there are several for the same language.
+        entries.sort();
+        entries.into_iter().next()
+    }
+}
+
+impl<'data> DataProvider<'data, LstmDataV1Marker> for LstmJsonDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, LstmDataV1Marker>, DataError> {
+        req.resource_path.key.match_key(key::LSTM_V1)?;
+        let langid = req.try_langid()?;
+        let path = MODEL_LANGUAGES
+            .iter()
+            .find(|(_, id)| langid.language == *id)
+            .and_then(|(name, _)| self.get_model_path(name))
+            .ok_or_else(|| DataError::MissingResourceOptions(req.clone()))?;
+        let bytes: Rc<[u8]> = fs::read(&path).map_err(DataError::new_resc_error)?.into();
+        let payload =
+            DataPayload::try_from_rc_buffer_badly(bytes, |bytes| serde_json::from_slice(bytes))
+                .map_err(DataError::new_resc_error)?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: Some(langid.clone()),
+            },
+            payload: Some(payload),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(LstmJsonDataProvider, {
+    _ => LstmDataV1Marker,
+}, SERDE_SE, 'data);
+
+impl IterableDataProviderCore for LstmJsonDataProvider {
+    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        resc_key.match_key(key::LSTM_V1)?;
+        let list: Vec<ResourceOptions> = MODEL_LANGUAGES
+            .iter()
+            .filter(|(name, _)| self.get_model_path(name).is_some())
+            .map(|(_, id)| ResourceOptions {
+                variant: None,
+                langid: LanguageIdentifier::from_str(id).ok(),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[test]
+fn test_basic() {
+    let provider = LstmJsonDataProvider::new(PathBuf::from("tests/testdata"));
+
+    let payload: DataPayload<'_, LstmDataV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::LSTM_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: LanguageIdentifier::from_str("th").ok(),
+                },
+            },
+        })
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful");
+
+    assert_eq!(
+        payload.get().model,
+        "Thai_codepoints_exclusive_model4_heavy"
+    );
+
+    let options = provider
+        .supported_options_for_key(&key::LSTM_V1)
+        .expect("The key should be supported");
+    assert_eq!(options.count(), 1);
+}
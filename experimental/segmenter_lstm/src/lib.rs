@@ -2,9 +2,17 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
 mod error;
+#[cfg(feature = "std")]
+mod json_provider;
 pub mod lstm;
 pub mod math_helper;
-pub mod structs;
+pub mod provider;
 
 pub use error::Error as LstmError;
+#[cfg(feature = "std")]
+pub use json_provider::LstmJsonDataProvider;
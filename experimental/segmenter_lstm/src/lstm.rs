@@ -3,50 +3,89 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::error::Error;
-use crate::math_helper;
-use crate::structs;
-use ndarray::{Array1, Array2, ArrayBase, Dim, ViewRepr};
-use std::str;
+use crate::math_helper::{self, F32ULE};
+use crate::provider::{key, LstmDataV1Marker};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use icu_locid::LanguageIdentifier;
+use icu_provider::prelude::*;
 use unicode_segmentation::UnicodeSegmentation;
 
-pub struct Lstm {
-    data: structs::LstmData,
+pub struct Lstm<'data> {
+    data: DataPayload<'data, LstmDataV1Marker>,
 }
 
-impl Lstm {
-    /// `try_new` is the initiator of struct `Lstm`
-    pub fn try_new(data: structs::LstmData) -> Result<Self, Error> {
-        if data.dic.len() > std::i16::MAX as usize {
+impl<'data> Lstm<'data> {
+    /// `try_new` loads the LSTM model for the given language from a data provider.
+    pub fn try_new<T: Into<LanguageIdentifier>, D>(
+        langid: T,
+        data_provider: &D,
+    ) -> Result<Self, Error>
+    where
+        D: DataProvider<'data, LstmDataV1Marker> + ?Sized,
+    {
+        let payload = data_provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::LSTM_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(langid.into()),
+                    },
+                },
+            })?
+            .take_payload()?;
+        Self::try_new_from_payload(payload)
+    }
+
+    /// `try_new_from_payload` is the initiator of struct `Lstm` from an already loaded model.
+    pub fn try_new_from_payload(
+        payload: DataPayload<'data, LstmDataV1Marker>,
+    ) -> Result<Self, Error> {
+        let data = payload.get();
+        if data.dic.len() > i16::MAX as usize {
             return Err(Error::Limit);
         }
         if !data.model.contains("_codepoints_") && !data.model.contains("_graphclust_") {
             return Err(Error::Syntax);
         }
-        let embedd_dim = data.mat1.shape()[1];
-        let hunits = data.mat3.shape()[0];
-        if data.mat2.shape() != [embedd_dim, 4 * hunits]
-            || data.mat3.shape() != [hunits, 4 * hunits]
-            || data.mat4.shape() != [4 * hunits]
-            || data.mat5.shape() != [embedd_dim, 4 * hunits]
-            || data.mat6.shape() != [hunits, 4 * hunits]
-            || data.mat7.shape() != [4 * hunits]
-            || data.mat8.shape() != [2 * hunits, 4]
-            || data.mat9.shape() != [4]
+        // The ids of the dictionary index the rows of the embedding matrix, and `dic.len()` is the
+        // id of unknown grapheme clusters.
+        if data
+            .dic
+            .iter_values()
+            .any(|id| *id < 0 || *id as usize > data.dic.len())
+        {
+            return Err(Error::Syntax);
+        }
+        let embedd_dim = data.mat1.axis_len(1);
+        let hunits = data.mat3.axis_len(0);
+        if hunits == 0
+            || !data.mat1.has_dim(&[data.dic.len() + 1, embedd_dim])
+            || !data.mat2.has_dim(&[embedd_dim, 4 * hunits])
+            || !data.mat3.has_dim(&[hunits, 4 * hunits])
+            || !data.mat4.has_dim(&[4 * hunits])
+            || !data.mat5.has_dim(&[embedd_dim, 4 * hunits])
+            || !data.mat6.has_dim(&[hunits, 4 * hunits])
+            || !data.mat7.has_dim(&[4 * hunits])
+            || !data.mat8.has_dim(&[2 * hunits, 4])
+            || !data.mat9.has_dim(&[4])
         {
             return Err(Error::DimensionMismatch);
         }
-        Ok(Self { data })
+        Ok(Self { data: payload })
     }
 
     /// `get_model_name` returns the name of the LSTM model.
     pub fn get_model_name(&self) -> &str {
-        &self.data.model
+        &self.data.get().model
     }
 
     // TODO(#421): Use common BIES normalizer code
     /// `compute_bies` uses the computed probabilities of BIES and pick the letter with the largest probability
-    fn compute_bies(&self, arr: Array1<f32>) -> Result<char, Error> {
-        let ind = math_helper::max_arr1(arr.view());
+    fn compute_bies(&self, arr: &[f32]) -> Result<char, Error> {
+        let ind = math_helper::max_arr1(arr);
         match ind {
             0 => Ok('b'),
             1 => Ok('i'),
@@ -58,34 +97,42 @@ impl Lstm {
 
     /// `_return_id` returns the id corresponding to a code point or a grapheme cluster based on the model dictionary.
     fn return_id(&self, g: &str) -> i16 {
-        let g_id: i16 = if self.data.dic.contains_key(g) {
-            self.data.dic[g]
-        } else {
-            self.data.dic.len() as i16
-        };
-        g_id
+        let dic = &self.data.get().dic;
+        match dic.get(g) {
+            Some(g_id) => *g_id,
+            None => dic.len() as i16,
+        }
     }
 
-    /// `compute_hc1` implemens the evaluation of one LSTM layer.
+    /// `compute_hc` implements the evaluation of one LSTM cell. It reads the previous hidden and
+    /// cell states from `h_t` and `c_t`, and overwrites them with the new ones. `s_t` is a scratch
+    /// buffer of `4 * hunits` elements.
+    #[allow(clippy::too_many_arguments)]
     fn compute_hc(
-        &self,
-        x_t: ArrayBase<ViewRepr<&f32>, Dim<[usize; 1]>>,
-        h_tm1: &Array1<f32>,
-        c_tm1: &Array1<f32>,
-        warr: ArrayBase<ViewRepr<&f32>, Dim<[usize; 2]>>,
-        uarr: ArrayBase<ViewRepr<&f32>, Dim<[usize; 2]>>,
-        barr: ArrayBase<ViewRepr<&f32>, Dim<[usize; 1]>>,
-    ) -> (Array1<f32>, Array1<f32>) {
+        x_t: &[f32],
+        h_t: &mut [f32],
+        c_t: &mut [f32],
+        warr: &[F32ULE],
+        uarr: &[F32ULE],
+        barr: &[F32ULE],
+        s_t: &mut [f32],
+    ) {
+        math_helper::copy_ule(s_t, barr);
+        math_helper::add_dot_vec_mat(s_t, x_t, warr);
+        math_helper::add_dot_vec_mat(s_t, h_t, uarr);
         // i, f, and o respectively stand for input, forget, and output gates
-        let s_t = x_t.dot(&warr) + h_tm1.dot(&uarr) + barr;
-        let hunits = uarr.shape()[0];
-        let i = math_helper::sigmoid_arr1(s_t.slice(ndarray::s![..hunits]));
-        let f = math_helper::sigmoid_arr1(s_t.slice(ndarray::s![hunits..2 * hunits]));
-        let _c = math_helper::tanh_arr1(s_t.slice(ndarray::s![2 * hunits..3 * hunits]));
-        let o = math_helper::sigmoid_arr1(s_t.slice(ndarray::s![3 * hunits..]));
-        let c_t = i * _c + f * c_tm1;
-        let h_t = o * math_helper::tanh_arr1(c_t.view());
-        (h_t, c_t)
+        let hunits = h_t.len();
+        let (s_i, rest) = s_t.split_at(hunits);
+        let (s_f, rest) = rest.split_at(hunits);
+        let (s_c, s_o) = rest.split_at(hunits);
+        for j in 0..hunits {
+            let i = math_helper::sigmoid(s_i[j]);
+            let f = math_helper::sigmoid(s_f[j]);
+            let _c = math_helper::tanh(s_c[j]);
+            let o = math_helper::sigmoid(s_o[j]);
+            c_t[j] = i * _c + f * c_t[j];
+            h_t[j] = o * math_helper::tanh(c_t[j]);
+        }
     }
 
     /// `word_segmenter` is a function that gets a "clean" unsegmented string as its input and returns a BIES (B: Beginning, I: Inside, E: End,
@@ -94,72 +141,94 @@ impl Lstm {
         // input_seq is a sequence of id numbers that represents grapheme clusters or code points in the input line. These ids are used later
         // in the embedding layer of the model.
         // Already checked that the name of the model is either "codepoints" or "graphclsut"
-        // TODO: Avoid allocating a string for each code point
-        let input_seq: Vec<i16> = if self.data.model.contains("_codepoints_") {
+        let data = self.data.get();
+        let input_seq: Vec<usize> = if data.model.contains("_codepoints_") {
+            let mut buf = [0; 4];
             input
                 .chars()
-                .map(|c| self.return_id(&c.to_string()))
+                .map(|c| self.return_id(c.encode_utf8(&mut buf)) as usize)
                 .collect()
         } else {
             UnicodeSegmentation::graphemes(input, true)
-                .map(|s| self.return_id(s))
+                .map(|s| self.return_id(s) as usize)
                 .collect()
         };
 
         // x_data is the data ready to be feed into the model
         let input_seq_len = input_seq.len();
 
+        // embedd_dim is the size of the embedding of a grapheme cluster
+        let embedd_dim = data.mat1.axis_len(1);
         // hunits is the number of hidden unints in each LSTM cell
-        let hunits = self.data.mat3.shape()[0];
+        let hunits = data.mat3.axis_len(0);
+
+        // All the buffers are allocated up front and reused for every grapheme cluster.
+        let embedding = data.mat1.data.as_slice();
+        let mut x_t = vec![0.0; embedd_dim];
+        let mut s_t = vec![0.0; 4 * hunits];
+        let mut h = vec![0.0; hunits];
+        let mut c = vec![0.0; hunits];
+
         // Forward LSTM
-        let mut c_fw = Array1::<f32>::zeros(hunits);
-        let mut h_fw = Array1::<f32>::zeros(hunits);
-        let mut all_h_fw = Array2::<f32>::zeros((input_seq_len, hunits));
-        for (i, g_id) in input_seq.iter().enumerate() {
-            let x_t = self.data.mat1.slice(ndarray::s![*g_id as isize, ..]);
-            let (new_h, new_c) = self.compute_hc(
-                x_t,
-                &h_fw,
-                &c_fw,
-                self.data.mat2.view(),
-                self.data.mat3.view(),
-                self.data.mat4.view(),
+        let mut all_h_fw = vec![0.0; input_seq_len * hunits];
+        for (g_id, h_fw) in input_seq.iter().zip(all_h_fw.chunks_exact_mut(hunits)) {
+            math_helper::copy_ule(
+                &mut x_t,
+                &embedding[g_id * embedd_dim..(g_id + 1) * embedd_dim],
+            );
+            Self::compute_hc(
+                &x_t,
+                &mut h,
+                &mut c,
+                data.mat2.data.as_slice(),
+                data.mat3.data.as_slice(),
+                data.mat4.data.as_slice(),
+                &mut s_t,
             );
-            h_fw = new_h;
-            c_fw = new_c;
-            all_h_fw = math_helper::change_row(all_h_fw, i, &h_fw);
+            h_fw.copy_from_slice(&h);
         }
 
         // Backward LSTM
-        let mut c_bw = Array1::<f32>::zeros(hunits);
-        let mut h_bw = Array1::<f32>::zeros(hunits);
-        let mut all_h_bw = Array2::<f32>::zeros((input_seq_len, hunits));
-        for (i, g_id) in input_seq.iter().rev().enumerate() {
-            let x_t = self.data.mat1.slice(ndarray::s![*g_id as isize, ..]);
-            let (new_h, new_c) = self.compute_hc(
-                x_t,
-                &h_bw,
-                &c_bw,
-                self.data.mat5.view(),
-                self.data.mat6.view(),
-                self.data.mat7.view(),
+        h.iter_mut().for_each(|x| *x = 0.0);
+        c.iter_mut().for_each(|x| *x = 0.0);
+        let mut all_h_bw = vec![0.0; input_seq_len * hunits];
+        for (g_id, h_bw) in input_seq
+            .iter()
+            .rev()
+            .zip(all_h_bw.chunks_exact_mut(hunits).rev())
+        {
+            math_helper::copy_ule(
+                &mut x_t,
+                &embedding[g_id * embedd_dim..(g_id + 1) * embedd_dim],
+            );
+            Self::compute_hc(
+                &x_t,
+                &mut h,
+                &mut c,
+                data.mat5.data.as_slice(),
+                data.mat6.data.as_slice(),
+                data.mat7.data.as_slice(),
+                &mut s_t,
             );
-            h_bw = new_h;
-            c_bw = new_c;
-            all_h_bw = math_helper::change_row(all_h_bw, input_seq_len - 1 - i, &h_bw);
+            h_bw.copy_from_slice(&h);
         }
 
-        // Combining forward and backward LSTMs using the dense time-distributed layer
-        let timew = self.data.mat8.view();
-        let timeb = self.data.mat9.view();
-        let mut bies = String::from("");
-        for i in 0..input_seq_len {
-            let curr_fw = all_h_fw.slice(ndarray::s![i, ..]);
-            let curr_bw = all_h_bw.slice(ndarray::s![i, ..]);
-            let concat_lstm = math_helper::concatenate_arr1(curr_fw, curr_bw);
-            let curr_est = concat_lstm.dot(&timew) + timeb;
-            let probs = math_helper::softmax(curr_est);
-            bies.push(self.compute_bies(probs).unwrap());
+        // Combining forward and backward LSTMs using the dense time-distributed layer. The first
+        // `hunits` rows of the weights apply to the forward LSTM, and the others to the backward
+        // LSTM.
+        let (timew_fw, timew_bw) = data.mat8.data.as_slice().split_at(hunits * 4);
+        let timeb = data.mat9.data.as_slice();
+        let mut curr_est = [0.0; 4];
+        let mut bies = String::with_capacity(input_seq_len);
+        for (curr_fw, curr_bw) in all_h_fw
+            .chunks_exact(hunits)
+            .zip(all_h_bw.chunks_exact(hunits))
+        {
+            math_helper::copy_ule(&mut curr_est, timeb);
+            math_helper::add_dot_vec_mat(&mut curr_est, curr_fw, timew_fw);
+            math_helper::add_dot_vec_mat(&mut curr_est, curr_bw, timew_bw);
+            math_helper::softmax(&mut curr_est);
+            bies.push(self.compute_bies(&curr_est).unwrap());
         }
         bies
     }
@@ -2,77 +2,92 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use ndarray::{Array1, Array2, ArrayBase, Dim, ViewRepr};
+//! Vector and matrix operations used to evaluate the LSTM models.
+//!
+//! All functions write into buffers supplied by the caller, so that evaluating a model does not
+//! allocate once its buffers are set up. Matrices are read directly from the little-endian
+//! weights of the data struct, in row-major order.
 
-/// `sigmoid` computes the sigmoid function for a scalar value.
-pub fn sigmoid(x: f32) -> f32 {
-    1.0 / (1.0 + (-x).exp())
-}
+use zerovec::ule::AsULE;
 
-/// `softmax` gets a 1d array of `f32` numbers, and compute the softmax probability for each element in the array.
-pub fn softmax(arr: Array1<f32>) -> Array1<f32> {
-    let mut sm: f32 = 0.0;
-    for i in 0..arr.shape()[0] {
-        sm += arr[[i]].exp();
+/// The unaligned representation of an `f32` weight, as stored in a [`zerovec::ZeroVec`].
+pub type F32ULE = <f32 as AsULE>::ULE;
+
+/// `exp` computes the exponential function for a scalar value.
+#[inline]
+pub fn exp(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    {
+        x.exp()
     }
-    let mut out = Array1::<f32>::zeros(arr.len());
-    for i in 0..out.shape()[0] {
-        out[[i]] = arr[[i]].exp() / sm;
+    #[cfg(not(feature = "std"))]
+    {
+        libm::expf(x)
     }
-    out
 }
 
-/// `max_arr1` returns the index of the maximum value in a 1d array.
-pub fn max_arr1(arr: ArrayBase<ViewRepr<&f32>, Dim<[usize; 1]>>) -> usize {
-    let mut mx: f32 = 0.0;
-    let mut ind = 0;
-    for i in 0..arr.shape()[0] {
-        if mx < arr[[i]] {
-            mx = arr[[i]];
-            ind = i;
-        }
+/// `tanh` computes the hyperbolic tangent for a scalar value.
+#[inline]
+pub fn tanh(x: f32) -> f32 {
+    #[cfg(feature = "std")]
+    {
+        x.tanh()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::tanhf(x)
     }
-    ind
 }
 
-/// `tanh_arr1` computes elementwise sigmoid funciton for elements of a 1d array
-pub fn sigmoid_arr1(arr: ArrayBase<ViewRepr<&f32>, Dim<[usize; 1]>>) -> Array1<f32> {
-    let mut out = Array1::<f32>::zeros(arr.shape()[0]);
-    for i in 0..arr.shape()[0] {
-        out[[i]] = sigmoid(arr[i]);
-    }
-    out
+/// `sigmoid` computes the sigmoid function for a scalar value.
+#[inline]
+pub fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + exp(-x))
 }
 
-/// `tanh_arr1` computes elementwise tanh funciton for elements of a 1d array
-pub fn tanh_arr1(arr: ArrayBase<ViewRepr<&f32>, Dim<[usize; 1]>>) -> Array1<f32> {
-    let mut out = Array1::<f32>::zeros(arr.shape()[0]);
-    for i in 0..arr.shape()[0] {
-        out[i] = arr[i].tanh();
+/// `copy_ule` copies the weights in `src` into `dst`. Both must have the same length.
+#[inline]
+pub fn copy_ule(dst: &mut [f32], src: &[F32ULE]) {
+    debug_assert_eq!(dst.len(), src.len());
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d = f32::from_unaligned(s);
     }
-    out
 }
 
-/// `change_row` gets one 2d array (`arr`), one 1d array (`arr1`), and an index (`row_id`), and replaces the `row_id`-th row of
-/// `arr` with `arr1`
-pub fn change_row(mut arr: Array2<f32>, row_id: usize, new_row: &Array1<f32>) -> Array2<f32> {
-    for i in 0..new_row.shape()[0] {
-        arr[[row_id, i]] = new_row[i];
+/// `add_dot_vec_mat` adds the product of the row vector `x` and the matrix `w` to `out`. The
+/// matrix `w` has `x.len()` rows and `out.len()` columns.
+#[inline]
+pub fn add_dot_vec_mat(out: &mut [f32], x: &[f32], w: &[F32ULE]) {
+    debug_assert_eq!(x.len() * out.len(), w.len());
+    // Going through `w` row by row reads it sequentially.
+    for (x_i, row) in x.iter().zip(w.chunks_exact(out.len())) {
+        for (o, w_ij) in out.iter_mut().zip(row) {
+            *o += x_i * f32::from_unaligned(w_ij);
+        }
     }
-    arr
 }
 
-/// `concatenate_arr1` concatenates two 1d arrays to form another 1d array.
-pub fn concatenate_arr1(
-    arr1: ArrayBase<ViewRepr<&f32>, Dim<[usize; 1]>>,
-    arr2: ArrayBase<ViewRepr<&f32>, Dim<[usize; 1]>>,
-) -> Array1<f32> {
-    let mut out = Array1::<f32>::zeros(arr1.len() + arr2.len());
-    for i in 0..arr1.len() {
-        out[i] = arr1[i]
+/// `softmax` replaces each element of `arr` with its softmax probability.
+pub fn softmax(arr: &mut [f32]) {
+    let mut sm: f32 = 0.0;
+    for x in arr.iter_mut() {
+        *x = exp(*x);
+        sm += *x;
     }
-    for i in 0..arr2.len() {
-        out[arr1.len() + i] = arr2[[i]]
+    for x in arr.iter_mut() {
+        *x /= sm;
     }
-    out
+}
+
+/// `max_arr1` returns the index of the maximum value in a 1d array.
+pub fn max_arr1(arr: &[f32]) -> usize {
+    let mut mx: f32 = 0.0;
+    let mut ind = 0;
+    for (i, x) in arr.iter().enumerate() {
+        if mx < *x {
+            mx = *x;
+            ind = i;
+        }
+    }
+    ind
 }
@@ -0,0 +1,86 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_provider::yoke::{self, *};
+use litemap::LiteMap;
+use serde::{Deserialize, Serialize};
+use zerovec::ZeroVec;
+
+pub mod key {
+    //! Resource keys for [`icu_segmenter_lstm`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: LSTM model for word segmentation, keyed by the language of the model.
+    pub const LSTM_V1: ResourceKey = resource_key!(Segmenter, "lstm", 1);
+}
+
+/// 'LstmDataV1' is a struct that store a LSTM model. Its attributes are:
+/// `model`: name of the model
+/// `dic`: the grapheme cluster dictionary used to train the model
+/// `mat1`: the matrix associateed with embedding layer
+/// `mat2` - `mat4`: the matrices associated with forward LSTM layer (embedding to hunits, hunits to hunits, and bias respectively)
+/// `mat5` - `mat7`: the matrices associated with backward LSTM layer (embedding to hunits, hunits to hunits, and bias respectively)
+/// `mat8` - `mat9`: the matrices associated with output layer (weight and bias term respectiely)
+#[icu_provider::data_struct]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[yoke(cloning_zcf)]
+pub struct LstmDataV1<'data> {
+    #[serde(borrow)]
+    pub model: Cow<'data, str>,
+    #[serde(borrow)]
+    pub dic: LiteMap<Cow<'data, str>, i16>,
+    #[serde(borrow)]
+    pub mat1: LstmMatrix<'data>,
+    #[serde(borrow)]
+    pub mat2: LstmMatrix<'data>,
+    #[serde(borrow)]
+    pub mat3: LstmMatrix<'data>,
+    #[serde(borrow)]
+    pub mat4: LstmMatrix<'data>,
+    #[serde(borrow)]
+    pub mat5: LstmMatrix<'data>,
+    #[serde(borrow)]
+    pub mat6: LstmMatrix<'data>,
+    #[serde(borrow)]
+    pub mat7: LstmMatrix<'data>,
+    #[serde(borrow)]
+    pub mat8: LstmMatrix<'data>,
+    #[serde(borrow)]
+    pub mat9: LstmMatrix<'data>,
+}
+
+/// `LstmMatrix` is a vector or a matrix of `f32` weights, stored in row-major order. Its
+/// attributes are:
+/// `dim`: the dimensions of the matrix; it has one element for vectors and two for matrices
+/// `data`: the elements of the matrix
+///
+/// The serialized form has the same fields as an `ndarray` array, so the `weights.json` files of
+/// the trained models can be read directly.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct LstmMatrix<'data> {
+    #[serde(borrow)]
+    pub dim: ZeroVec<'data, u16>,
+    #[serde(borrow)]
+    pub data: ZeroVec<'data, f32>,
+}
+
+impl LstmMatrix<'_> {
+    /// `axis_len` returns the number of elements along the given axis, or 0 if the matrix has
+    /// fewer axes.
+    pub fn axis_len(&self, axis: usize) -> usize {
+        self.dim.get(axis).map(usize::from).unwrap_or(0)
+    }
+
+    /// `has_dim` checks that the matrix has the given dimensions, and that it holds exactly the
+    /// number of elements implied by them.
+    pub fn has_dim(&self, dim: &[usize]) -> bool {
+        self.dim.iter().map(usize::from).eq(dim.iter().copied())
+            && self.data.len() == dim.iter().product::<usize>()
+    }
+}
@@ -2,11 +2,17 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use icu_locid_macros::langid;
+use icu_provider::prelude::*;
 use icu_segmenter_lstm::lstm::Lstm;
-use icu_segmenter_lstm::structs;
+use icu_segmenter_lstm::provider::LstmDataV1Marker;
+use icu_segmenter_lstm::LstmJsonDataProvider;
 use serde::{Deserialize, Serialize};
+use std::fs;
 use std::fs::File;
 use std::io::BufReader;
+use std::path::PathBuf;
+use std::rc::Rc;
 
 /// `TestCase` is a struct used to store a single test case.
 /// Each test case has two attributs: `unseg` which denots the unsegmented line, and `true_bies` which indicates the Bies
@@ -35,10 +41,10 @@ impl TestText {
     }
 }
 
-fn load_lstm_data(filename: &str) -> structs::LstmData {
-    let file = File::open(filename).expect("File should be present");
-    let reader = BufReader::new(file);
-    serde_json::from_reader(reader).expect("JSON syntax error")
+fn load_lstm_data(filename: &str) -> DataPayload<'static, LstmDataV1Marker> {
+    let bytes: Rc<[u8]> = fs::read(filename).expect("File should be present").into();
+    DataPayload::try_from_rc_buffer_badly(bytes, |bytes| serde_json::from_slice(bytes))
+        .expect("JSON syntax error")
 }
 
 fn load_test_text(filename: &str) -> TestTextData {
@@ -51,7 +57,7 @@ fn load_test_text(filename: &str) -> TestTextData {
 fn test_model_loading() {
     let filename = "tests/testdata/Thai_graphclust_exclusive_model4_heavy/weights.json";
     let lstm_data = load_lstm_data(filename);
-    let lstm = Lstm::try_new(lstm_data).unwrap();
+    let lstm = Lstm::try_new_from_payload(lstm_data).unwrap();
     assert_eq!(
         lstm.get_model_name(),
         String::from("Thai_graphclust_exclusive_model4_heavy")
@@ -66,7 +72,7 @@ fn segment_file_by_lstm() {
     model_filename.push_str(embedding);
     model_filename.push_str("_exclusive_model4_heavy/weights.json");
     let lstm_data = load_lstm_data(&model_filename);
-    let lstm = Lstm::try_new(lstm_data).unwrap();
+    let lstm = Lstm::try_new_from_payload(lstm_data).unwrap();
 
     // Importing the test data
     let mut test_text_filename = "tests/testdata/test_text_".to_owned();
@@ -86,3 +92,16 @@ fn segment_file_by_lstm() {
         assert_eq!(test_case.expected_bies, lstm_output);
     }
 }
+
+#[test]
+fn test_model_loading_from_provider() {
+    let provider = LstmJsonDataProvider::new(PathBuf::from("tests/testdata"));
+    let lstm = Lstm::try_new(langid!("th"), &provider).unwrap();
+    assert_eq!(
+        lstm.get_model_name(),
+        String::from("Thai_codepoints_exclusive_model4_heavy")
+    );
+
+    // There is no Lao model in the test data.
+    assert!(Lstm::try_new(langid!("lo"), &provider).is_err());
+}
@@ -0,0 +1,240 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Conversion between units and selection of the preferred units of regions.
+
+use crate::error::UnitsError;
+use crate::measure_unit::MeasureUnit;
+use crate::provider::*;
+use alloc::string::{String, ToString};
+use icu_locid::LanguageIdentifier;
+use icu_provider::prelude::*;
+use litemap::LiteMap;
+
+/// A converter between units, which also selects the preferred units of regions, from the
+/// CLDR unit conversion and preference data.
+///
+/// See the crate-level documentation for examples.
+pub struct UnitsConverter<'data> {
+    conversions: DataPayload<'data, UnitsConversionV1Marker>,
+    preferences: DataPayload<'data, UnitPreferencesV1Marker>,
+}
+
+/// The simple base units of a unit with their powers, like `{"meter": 1, "second": -1}` for
+/// "kilometer-per-hour", and the factor converting a value of the unit to the base units.
+struct BaseUnits {
+    units: LiteMap<String, i16>,
+    factor: f64,
+    /// The offset of the conversion, which only applies to single units like "celsius".
+    offset: f64,
+}
+
+impl<'data> UnitsConverter<'data> {
+    /// Creates a new [`UnitsConverter`] from the unit conversion and preference data.
+    pub fn try_new<D>(data_provider: &D) -> Result<Self, UnitsError>
+    where
+        D: DataProvider<'data, UnitsConversionV1Marker>
+            + DataProvider<'data, UnitPreferencesV1Marker>
+            + ?Sized,
+    {
+        let request = |key| DataRequest {
+            resource_path: ResourcePath {
+                key,
+                options: ResourceOptions::default(),
+            },
+        };
+        let conversions: DataPayload<UnitsConversionV1Marker> = data_provider
+            .load_payload(&request(key::UNITS_CONVERSION_V1))?
+            .take_payload()?;
+        let preferences: DataPayload<UnitPreferencesV1Marker> = data_provider
+            .load_payload(&request(key::UNITS_PREFERENCES_V1))?
+            .take_payload()?;
+        Ok(Self {
+            conversions,
+            preferences,
+        })
+    }
+
+    /// Parses a core unit identifier, like "kilometer-per-hour", whose simple units are in the
+    /// conversion data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_units::{UnitsConverter, UnitsError};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let converter = UnitsConverter::try_new(&provider).expect("Data should load successfully");
+    ///
+    /// let unit = converter.parse_unit("kilometer-per-hour").expect("The unit should be valid");
+    /// assert_eq!(unit.to_string(), "kilometer-per-hour");
+    ///
+    /// assert!(matches!(
+    ///     converter.parse_unit("kilometre"),
+    ///     Err(UnitsError::InvalidUnit(_))
+    /// ));
+    /// ```
+    pub fn parse_unit(&self, id: &str) -> Result<MeasureUnit, UnitsError> {
+        let conversions = &self.conversions.get().conversions;
+        MeasureUnit::try_from_str(id, |unit| conversions.contains_key(unit))
+    }
+
+    /// Converts a value from a unit to another unit measuring the same quantity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_units::UnitsConverter;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let converter = UnitsConverter::try_new(&provider).expect("Data should load successfully");
+    ///
+    /// let celsius = converter.parse_unit("celsius").unwrap();
+    /// let fahrenheit = converter.parse_unit("fahrenheit").unwrap();
+    /// let value = converter.convert(100.0, &celsius, &fahrenheit).unwrap();
+    /// assert!((value - 212.0).abs() < 1e-9);
+    /// ```
+    pub fn convert(
+        &self,
+        value: f64,
+        from: &MeasureUnit,
+        to: &MeasureUnit,
+    ) -> Result<f64, UnitsError> {
+        let from_base = self.base_units(from)?;
+        let to_base = self.base_units(to)?;
+        if from_base.units != to_base.units {
+            return Err(UnitsError::IncompatibleUnits(
+                from.to_string(),
+                to.to_string(),
+            ));
+        }
+        Ok((value * from_base.factor + from_base.offset - to_base.offset) / to_base.factor)
+    }
+
+    /// Returns the preferred unit of a usage, like "road" or "person-height", in the region of
+    /// a locale for a value of a unit, with the value converted to the preferred unit.
+    ///
+    /// The preferences of the "default" usage are used if the quantity of the unit has none
+    /// for the usage, and the preferences of the world, "001", if the locale has no region or
+    /// its region has none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid_macros::langid;
+    /// use icu_units::UnitsConverter;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let converter = UnitsConverter::try_new(&provider).expect("Data should load successfully");
+    ///
+    /// let kilometer = converter.parse_unit("kilometer").unwrap();
+    ///
+    /// let (value, unit) = converter
+    ///     .preferred_unit(16.09344, &kilometer, "road", &langid!("en-GB"))
+    ///     .expect("Road distances should have preferences");
+    /// assert!((value - 10.0).abs() < 1e-9);
+    /// assert_eq!(unit.to_string(), "mile");
+    ///
+    /// let (value, unit) = converter
+    ///     .preferred_unit(0.1, &kilometer, "road", &langid!("fr-FR"))
+    ///     .expect("Road distances should have preferences");
+    /// assert!((value - 100.0).abs() < 1e-9);
+    /// assert_eq!(unit.to_string(), "meter");
+    /// ```
+    pub fn preferred_unit(
+        &self,
+        value: f64,
+        unit: &MeasureUnit,
+        usage: &str,
+        langid: &LanguageIdentifier,
+    ) -> Result<(f64, MeasureUnit), UnitsError> {
+        let missing = || UnitsError::MissingPreferences(unit.to_string(), usage.to_string());
+        let base = self.base_units(unit)?;
+        let quantity = self
+            .conversions
+            .get()
+            .quantities
+            .iter()
+            .find(|(base_unit, _)| {
+                self.parse_unit(base_unit)
+                    .and_then(|base_unit| self.base_units(&base_unit))
+                    .map_or(false, |base_unit| base_unit.units == base.units)
+            })
+            .map(|(_, quantity)| quantity)
+            .ok_or_else(missing)?;
+        let preferences = self.preferences.get();
+        let usage_preferences = preferences
+            .get(quantity, usage)
+            .or_else(|| preferences.get(quantity, "default"))
+            .ok_or_else(missing)?;
+        let region = langid
+            .region
+            .as_ref()
+            .map_or("001", |region| region.as_str());
+        let regional_preferences = usage_preferences
+            .regions
+            .get(region)
+            .or_else(|| usage_preferences.regions.get("001"))
+            .ok_or_else(missing)?;
+
+        let mut preferred = None;
+        for preference in regional_preferences.iter() {
+            let preferred_unit = self.parse_unit(&preference.unit)?;
+            let converted = self.convert(value, unit, &preferred_unit)?;
+            let is_large_enough = converted.abs() >= preference.geq;
+            preferred = Some((converted, preferred_unit));
+            if is_large_enough {
+                break;
+            }
+        }
+        preferred.ok_or_else(missing)
+    }
+
+    fn base_units(&self, unit: &MeasureUnit) -> Result<BaseUnits, UnitsError> {
+        let conversions = &self.conversions.get().conversions;
+        let mut base = BaseUnits {
+            units: LiteMap::new(),
+            factor: 1.0,
+            offset: 0.0,
+        };
+        for single_unit in unit.units.iter() {
+            let info = conversions
+                .get(single_unit.unit.as_str())
+                .ok_or_else(|| UnitsError::InvalidUnit(unit.to_string()))?;
+            let prefix_factor = single_unit.prefix.map_or(1.0, |prefix| prefix.factor());
+            base.factor *= crate::pow(prefix_factor * info.factor, i32::from(single_unit.power));
+            if info.base_unit == single_unit.unit {
+                add_power(&mut base.units, &single_unit.unit, single_unit.power.into());
+            } else {
+                // Base units like "kilogram" are simple units of their own, so they are parsed
+                // without prefixes.
+                let base_unit = self.parse_unit(&info.base_unit)?;
+                for base_single_unit in base_unit.units.iter() {
+                    add_power(
+                        &mut base.units,
+                        &base_single_unit.unit,
+                        i16::from(base_single_unit.power) * i16::from(single_unit.power),
+                    );
+                }
+            }
+        }
+        if let [single_unit] = unit.units.as_slice() {
+            if single_unit.power == 1 && single_unit.prefix.is_none() {
+                if let Some(info) = conversions.get(single_unit.unit.as_str()) {
+                    base.offset = info.offset;
+                }
+            }
+        }
+        Ok(base)
+    }
+}
+
+fn add_power(units: &mut LiteMap<String, i16>, unit: &str, power: i16) {
+    let total = units.get(unit).copied().unwrap_or(0) + power;
+    if total == 0 {
+        units.remove(unit);
+    } else {
+        units.insert(unit.to_string(), total);
+    }
+}
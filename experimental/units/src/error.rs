@@ -0,0 +1,58 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use displaydoc::Display;
+use icu_decimal::FixedDecimalFormatError;
+use icu_plurals::PluralRulesError;
+use icu_provider::prelude::DataError;
+
+/// A list of possible error outcomes for the [`UnitsFormatter`](crate::UnitsFormatter) and
+/// [`UnitsConverter`](crate::UnitsConverter) structs.
+#[derive(Display, Debug)]
+pub enum UnitsError {
+    /// A unit identifier is not a valid core unit identifier, or contains a unit which is not
+    /// in the conversion data.
+    #[displaydoc("Invalid unit: {0}")]
+    InvalidUnit(String),
+    /// The locale data has no patterns for a unit.
+    #[displaydoc("No patterns for the unit: {0}")]
+    MissingPatterns(String),
+    /// Two units measure different quantities, like a length and a duration.
+    #[displaydoc("Cannot convert between {0} and {1}")]
+    IncompatibleUnits(String, String),
+    /// A unit has no preferences for a usage, or does not measure a known quantity.
+    #[displaydoc("No preferences for {0} in usage {1}")]
+    MissingPreferences(String, String),
+    /// An error originating inside of the [`DataProvider`](icu_provider::DataProvider)
+    #[displaydoc("Data provider error: {0}")]
+    DataProvider(DataError),
+    /// An error from loading the plural rules
+    #[displaydoc("Plural rules error: {0}")]
+    PluralRules(PluralRulesError),
+    /// An error from loading the decimal format
+    #[displaydoc("Decimal format error: {0}")]
+    Decimal(FixedDecimalFormatError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnitsError {}
+
+impl From<DataError> for UnitsError {
+    fn from(e: DataError) -> Self {
+        UnitsError::DataProvider(e)
+    }
+}
+
+impl From<PluralRulesError> for UnitsError {
+    fn from(e: PluralRulesError) -> Self {
+        UnitsError::PluralRules(e)
+    }
+}
+
+impl From<FixedDecimalFormatError> for UnitsError {
+    fn from(e: FixedDecimalFormatError) -> Self {
+        UnitsError::Decimal(e)
+    }
+}
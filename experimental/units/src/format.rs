@@ -0,0 +1,205 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Lower-level types for unit formatting.
+
+use crate::error::UnitsError;
+use crate::measure_unit::{MeasureUnit, SingleUnit};
+use crate::parts;
+use crate::provider::UnitPatternsV1;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use icu_decimal::FormattedFixedDecimal;
+use icu_pattern::{PatternToken, PlaceholderSchema, SinglePlaceholder, TypedPattern};
+use icu_plurals::PluralCategory;
+use writeable::{CoreWriteAsPartsWrite, LengthHint, PartsWrite, Writeable};
+
+/// A number with a unit, returned by [`UnitsFormatter::format`](crate::UnitsFormatter::format).
+///
+/// Use [`Writeable`] to render it to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedUnit<'l> {
+    pub(crate) number: FormattedFixedDecimal<'l>,
+    pub(crate) text: UnitText,
+}
+
+impl Writeable for FormattedUnit<'_> {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.write_to_parts(&mut CoreWriteAsPartsWrite(sink))
+    }
+
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        if !self.text.before.is_empty() {
+            sink.with_part(parts::UNIT, |sink| sink.write_str(&self.text.before))?;
+        }
+        self.number.write_to_parts(sink)?;
+        if !self.text.after.is_empty() {
+            sink.with_part(parts::UNIT, |sink| sink.write_str(&self.text.after))?;
+        }
+        Ok(())
+    }
+
+    fn write_len(&self) -> LengthHint {
+        self.number.write_len() + self.text.before.len() + self.text.after.len()
+    }
+}
+
+impl fmt::Display for FormattedUnit<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+/// The text of a unit around its number, like `("", " kilometers")` for "{0} kilometers".
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct UnitText {
+    before: String,
+    after: String,
+}
+
+impl UnitText {
+    /// Splits a pattern with the number as `{0}`, like "{0} kilometers", around the number.
+    fn from_pattern<S: PlaceholderSchema>(pattern: &TypedPattern<S>) -> Self {
+        Self::default().wrap(pattern, "")
+    }
+
+    /// Interpolates a pattern with this text around the number as `{0}` and `other` as `{1}`.
+    fn wrap<S: PlaceholderSchema>(self, pattern: &TypedPattern<S>, other: &str) -> Self {
+        let mut result = Self::default();
+        let mut after_number = false;
+        for token in pattern.tokens() {
+            let side = if after_number {
+                &mut result.after
+            } else {
+                &mut result.before
+            };
+            match token {
+                PatternToken::Literal { content, .. } => side.push_str(&content),
+                PatternToken::Placeholder("0") => {
+                    side.push_str(&self.before);
+                    result.after.push_str(&self.after);
+                    after_number = true;
+                }
+                PatternToken::Placeholder(_) => side.push_str(other),
+            }
+        }
+        result
+    }
+
+    /// Returns the name of the unit in the text, which is its longest side without spaces,
+    /// like "kilometers" for "{0} kilometers".
+    fn name(&self) -> &str {
+        if self.name_is_before() {
+            self.before.trim()
+        } else {
+            self.after.trim()
+        }
+    }
+
+    fn name_is_before(&self) -> bool {
+        self.before.trim().len() > self.after.trim().len()
+    }
+
+    /// Replaces the name of the unit in the text, like "meters" in "{0} meters".
+    fn map_name(mut self, f: impl FnOnce(&str) -> String) -> Self {
+        let side = if self.name_is_before() {
+            &mut self.before
+        } else {
+            &mut self.after
+        };
+        let name = side.trim();
+        let start = side.find(name).unwrap_or(0);
+        let end = start + name.len();
+        let replacement = f(name);
+        side.replace_range(start..end, &replacement);
+        self
+    }
+}
+
+/// Interpolates a pattern of names, like "kilo{0}", with a name.
+fn apply(pattern: &TypedPattern<SinglePlaceholder>, name: &str) -> String {
+    pattern.interpolate([name]).writeable_to_string()
+}
+
+/// Composes the text of a unit with a number of a [`PluralCategory`].
+///
+/// The patterns of the unit are used if the locale has them. Otherwise, the text is composed
+/// from the patterns of its single units, like "{0} per {1}" for the units after "per" and
+/// "{0}-{1}" for products. The single units after "per" and before the last one of a product
+/// use their singular names.
+pub(crate) fn compose(
+    patterns: &UnitPatternsV1,
+    unit: &MeasureUnit,
+    category: PluralCategory,
+) -> Result<UnitText, UnitsError> {
+    if let Some(plural_patterns) = patterns.units.get(unit.to_string().as_str()) {
+        return Ok(UnitText::from_pattern(plural_patterns.get(category)));
+    }
+    let numerator: Vec<&SingleUnit> = unit.numerator().collect();
+    let denominator: Vec<&SingleUnit> = unit.denominator().collect();
+    let text = compose_product(patterns, &numerator, category)?;
+    if denominator.is_empty() {
+        return Ok(text);
+    }
+    if let [single_unit] = denominator.as_slice() {
+        if let Some(pattern) = patterns.per_unit.get(single_unit.to_string().as_str()) {
+            return Ok(text.wrap(pattern, ""));
+        }
+    }
+    let denominator = compose_product(patterns, &denominator, PluralCategory::One)?;
+    Ok(text.wrap(&patterns.per, denominator.name()))
+}
+
+fn compose_product(
+    patterns: &UnitPatternsV1,
+    units: &[&SingleUnit],
+    category: PluralCategory,
+) -> Result<UnitText, UnitsError> {
+    let (last, rest) = match units.split_last() {
+        Some(split) => split,
+        None => return Ok(UnitText::default()),
+    };
+    let mut text = compose_single(patterns, last, category)?;
+    for single_unit in rest.iter().rev() {
+        let single_text = compose_single(patterns, single_unit, PluralCategory::One)?;
+        text = text.map_name(|name| {
+            patterns
+                .times
+                .interpolate([single_text.name(), name])
+                .writeable_to_string()
+        });
+    }
+    Ok(text)
+}
+
+fn compose_single(
+    patterns: &UnitPatternsV1,
+    single_unit: &SingleUnit,
+    category: PluralCategory,
+) -> Result<UnitText, UnitsError> {
+    if let Some(plural_patterns) = patterns.units.get(single_unit.to_string().as_str()) {
+        return Ok(UnitText::from_pattern(plural_patterns.get(category)));
+    }
+    let missing = || UnitsError::MissingPatterns(single_unit.to_string());
+    let mut text = patterns
+        .units
+        .get(single_unit.unit.as_str())
+        .map(|plural_patterns| UnitText::from_pattern(plural_patterns.get(category)))
+        .ok_or_else(missing)?;
+    if let Some(prefix) = single_unit.prefix {
+        let pattern = patterns
+            .prefixes
+            .get(prefix.cldr_id())
+            .ok_or_else(missing)?;
+        text = text.map_name(|name| apply(pattern, name));
+    }
+    match single_unit.power.unsigned_abs() {
+        1 => {}
+        2 => text = text.map_name(|name| apply(&patterns.power2, name)),
+        3 => text = text.map_name(|name| apply(&patterns.power3, name)),
+        _ => return Err(missing()),
+    }
+    Ok(text)
+}
@@ -0,0 +1,172 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_units`](crate) formats numbers with measurement units, like "3 kilometers", and
+//! converts values between units, like kilometers to miles.
+//!
+//! A [`UnitsFormatter`] formats a [`FixedDecimal`] with a [`MeasureUnit`] in a [`UnitWidth`],
+//! which is long, short or narrow. The units without their own patterns in a locale, like
+//! "kilometer-per-minute", "cubic-mile" or "kibibyte", are composed from the patterns of their
+//! parts.
+//!
+//! A [`UnitsConverter`] parses unit identifiers, converts values between units which measure
+//! the same quantity, and selects the preferred unit of a usage, like "road" distances, in the
+//! region of a locale.
+//!
+//! # Examples
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_locid_macros::langid;
+//! use icu_units::{UnitWidth, UnitsConverter, UnitsFormatter};
+//! use writeable::Writeable;
+//!
+//! let provider = icu_testdata::get_provider();
+//!
+//! let converter = UnitsConverter::try_new(&provider).expect("Data should load successfully");
+//! let kilometer = converter.parse_unit("kilometer").expect("The unit should be valid");
+//!
+//! // A road of 16 kilometers in the United Kingdom is a road of 10 miles.
+//! let (value, unit) = converter
+//!     .preferred_unit(16.09344, &kilometer, "road", &langid!("en-GB"))
+//!     .expect("Road distances should have preferences");
+//! assert_eq!(unit.to_string(), "mile");
+//!
+//! let formatter = UnitsFormatter::try_new(langid!("en"), &provider, UnitWidth::Long)
+//!     .expect("Data should load successfully");
+//!
+//! let value = FixedDecimal::from(value.round() as i64);
+//! assert_eq!(
+//!     formatter
+//!         .format(&value, &unit)
+//!         .expect("The unit should have patterns")
+//!         .writeable_to_string(),
+//!     "10 miles"
+//! );
+//!
+//! let speed = converter.parse_unit("kilometer-per-hour").unwrap();
+//! let formatter = UnitsFormatter::try_new(langid!("fr"), &provider, UnitWidth::Short)
+//!     .expect("Data should load successfully");
+//!
+//! assert_eq!(
+//!     formatter
+//!         .format(&FixedDecimal::from(90), &speed)
+//!         .expect("The unit should have patterns")
+//!         .writeable_to_string(),
+//!     "90\u{a0}km/h"
+//! );
+//! ```
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+mod converter;
+mod error;
+pub mod format;
+mod measure_unit;
+pub mod options;
+pub mod parts;
+pub mod provider;
+
+pub use converter::UnitsConverter;
+pub use error::UnitsError;
+pub use format::FormattedUnit;
+pub use measure_unit::{MeasureUnit, SingleUnit, UnitPrefix};
+pub use options::UnitWidth;
+
+use alloc::string::String;
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormat;
+use icu_locid::Locale;
+use icu_plurals::provider::PluralRulesV2Marker;
+use icu_plurals::{PluralRuleType, PluralRules};
+use icu_provider::prelude::*;
+use provider::UnitPatternsV1Marker;
+use writeable::Writeable;
+
+/// A formatter for numbers with measurement units in a locale, like "3 kilometers".
+///
+/// See the crate-level documentation for examples.
+pub struct UnitsFormatter<'data> {
+    patterns: DataPayload<'data, UnitPatternsV1Marker>,
+    plural_rules: PluralRules<'data>,
+    fixed_decimal_format: FixedDecimalFormat<'data>,
+}
+
+impl<'data> UnitsFormatter<'data> {
+    /// Creates a new [`UnitsFormatter`] for a [`UnitWidth`] from locale data.
+    pub fn try_new<T, D>(locale: T, data_provider: &D, width: UnitWidth) -> Result<Self, UnitsError>
+    where
+        T: Into<Locale>,
+        D: DataProvider<'data, UnitPatternsV1Marker>
+            + DataProvider<'data, PluralRulesV2Marker>
+            + DataProvider<'data, DecimalSymbolsV1Marker>
+            + ?Sized,
+    {
+        let locale = locale.into();
+        let patterns = data_provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: provider::key::for_width(width),
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(locale.clone().into()),
+                    },
+                },
+            })?
+            .take_payload()?;
+        let plural_rules = PluralRules::try_new(
+            locale.clone().into(),
+            data_provider,
+            PluralRuleType::Cardinal,
+        )?;
+        let fixed_decimal_format =
+            FixedDecimalFormat::try_new(locale, data_provider, Default::default())?;
+        Ok(Self {
+            patterns,
+            plural_rules,
+            fixed_decimal_format,
+        })
+    }
+
+    /// Formats a number with a unit, returning a [`FormattedUnit`].
+    ///
+    /// Returns [`UnitsError::MissingPatterns`] if the locale has no patterns for the unit or
+    /// for the parts it is composed of.
+    pub fn format<'l>(
+        &'l self,
+        value: &'l FixedDecimal,
+        unit: &MeasureUnit,
+    ) -> Result<FormattedUnit<'l>, UnitsError> {
+        let category = self.plural_rules.select(value);
+        Ok(FormattedUnit {
+            number: self.fixed_decimal_format.format(value),
+            text: format::compose(self.patterns.get(), unit, category)?,
+        })
+    }
+
+    /// Formats a number with a unit to a [`String`].
+    pub fn format_to_string(
+        &self,
+        value: &FixedDecimal,
+        unit: &MeasureUnit,
+    ) -> Result<String, UnitsError> {
+        Ok(self.format(value, unit)?.writeable_to_string())
+    }
+}
+
+/// Returns `base` to the power of `exponent`, since `f64::powi` needs `std`.
+pub(crate) fn pow(base: f64, exponent: i32) -> f64 {
+    let mut result = 1.0;
+    for _ in 0..exponent.unsigned_abs() {
+        result *= base;
+    }
+    if exponent < 0 {
+        1.0 / result
+    } else {
+        result
+    }
+}
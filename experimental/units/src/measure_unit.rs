@@ -0,0 +1,338 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsed unit identifiers.
+
+use crate::error::UnitsError;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// An SI prefix like "kilo" (10³) or a binary prefix like "kibi" (2¹⁰) of a [`SingleUnit`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct UnitPrefix {
+    /// The base of the prefix, which is 10 for SI prefixes and 2 for binary prefixes.
+    pub base: u8,
+    /// The exponent of the prefix, like 3 for "kilo".
+    pub exponent: i8,
+}
+
+/// The prefixes of unit identifiers, with their names and their identifiers in CLDR.
+const PREFIXES: &[(&str, &str, UnitPrefix)] = &[
+    ("yotta", "10p24", UnitPrefix::si(24)),
+    ("zetta", "10p21", UnitPrefix::si(21)),
+    ("exa", "10p18", UnitPrefix::si(18)),
+    ("peta", "10p15", UnitPrefix::si(15)),
+    ("tera", "10p12", UnitPrefix::si(12)),
+    ("giga", "10p9", UnitPrefix::si(9)),
+    ("mega", "10p6", UnitPrefix::si(6)),
+    ("kilo", "10p3", UnitPrefix::si(3)),
+    ("hecto", "10p2", UnitPrefix::si(2)),
+    ("deka", "10p1", UnitPrefix::si(1)),
+    ("deci", "10p-1", UnitPrefix::si(-1)),
+    ("centi", "10p-2", UnitPrefix::si(-2)),
+    ("milli", "10p-3", UnitPrefix::si(-3)),
+    ("micro", "10p-6", UnitPrefix::si(-6)),
+    ("nano", "10p-9", UnitPrefix::si(-9)),
+    ("pico", "10p-12", UnitPrefix::si(-12)),
+    ("femto", "10p-15", UnitPrefix::si(-15)),
+    ("atto", "10p-18", UnitPrefix::si(-18)),
+    ("zepto", "10p-21", UnitPrefix::si(-21)),
+    ("yocto", "10p-24", UnitPrefix::si(-24)),
+    ("kibi", "2p10", UnitPrefix::binary(10)),
+    ("mebi", "2p20", UnitPrefix::binary(20)),
+    ("gibi", "2p30", UnitPrefix::binary(30)),
+    ("tebi", "2p40", UnitPrefix::binary(40)),
+    ("pebi", "2p50", UnitPrefix::binary(50)),
+    ("exbi", "2p60", UnitPrefix::binary(60)),
+    ("zebi", "2p70", UnitPrefix::binary(70)),
+    ("yobi", "2p80", UnitPrefix::binary(80)),
+];
+
+impl UnitPrefix {
+    const fn si(exponent: i8) -> Self {
+        Self { base: 10, exponent }
+    }
+
+    const fn binary(exponent: i8) -> Self {
+        Self { base: 2, exponent }
+    }
+
+    fn entry(&self) -> &'static (&'static str, &'static str, UnitPrefix) {
+        PREFIXES
+            .iter()
+            .find(|(_, _, prefix)| prefix == self)
+            .unwrap_or(&PREFIXES[0])
+    }
+
+    /// Returns the name of the prefix in unit identifiers, like "kilo".
+    pub fn name(&self) -> &'static str {
+        self.entry().0
+    }
+
+    /// Returns the identifier of the prefix in CLDR, like "10p3" for "kilo".
+    pub fn cldr_id(&self) -> &'static str {
+        self.entry().1
+    }
+
+    /// Returns the prefix with an identifier in CLDR, like "10p3".
+    pub fn from_cldr_id(id: &str) -> Option<Self> {
+        PREFIXES
+            .iter()
+            .find(|(_, cldr_id, _)| *cldr_id == id)
+            .map(|(_, _, prefix)| *prefix)
+    }
+
+    /// Returns the factor of the prefix, like 1000 for "kilo".
+    pub fn factor(&self) -> f64 {
+        crate::pow(f64::from(self.base), i32::from(self.exponent))
+    }
+}
+
+/// A simple unit with an optional [`UnitPrefix`] and a power, like "square-kilometer".
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SingleUnit {
+    /// The power of the unit, like 2 for "square-meter", which is negative for the units after
+    /// "per", like -1 for the "second" of "meter-per-second".
+    pub power: i8,
+    /// The prefix of the unit, like "kilo" for "kilometer".
+    pub prefix: Option<UnitPrefix>,
+    /// The simple unit, like "meter" for "kilometer".
+    pub unit: String,
+}
+
+impl fmt::Display for SingleUnit {
+    /// Writes the identifier of the unit, ignoring the sign of its power.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.power.unsigned_abs() {
+            1 => {}
+            2 => f.write_str("square-")?,
+            3 => f.write_str("cubic-")?,
+            power => write!(f, "pow{}-", power)?,
+        }
+        if let Some(prefix) = self.prefix {
+            f.write_str(prefix.name())?;
+        }
+        f.write_str(&self.unit)
+    }
+}
+
+/// A unit parsed from a core unit identifier, like "kilometer-per-hour", which is a product of
+/// [`SingleUnit`]s.
+///
+/// Unit identifiers are parsed with [`UnitsConverter::parse_unit`], which knows the simple
+/// units, and written back with [`Display`](fmt::Display).
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-general.html#Unit_Identifiers>
+///
+/// [`UnitsConverter::parse_unit`]: crate::UnitsConverter::parse_unit
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MeasureUnit {
+    /// The units of the product, with the units before "per" first.
+    pub units: Vec<SingleUnit>,
+}
+
+impl MeasureUnit {
+    /// Parses a core unit identifier, where `is_simple_unit` tells whether a string is a simple
+    /// unit, like "meter" or "light-year".
+    ///
+    /// Mixed units like "foot-and-inch" are not supported.
+    pub(crate) fn try_from_str(
+        id: &str,
+        is_simple_unit: impl Fn(&str) -> bool,
+    ) -> Result<Self, UnitsError> {
+        let invalid = || UnitsError::InvalidUnit(id.to_string());
+        let (numerator, denominator) = match id.strip_prefix("per-") {
+            Some(denominator) => ("", Some(denominator)),
+            None => match id.find("-per-") {
+                Some(index) => (&id[..index], Some(&id[index + 5..])),
+                None => (id, None),
+            },
+        };
+        let mut units = Vec::new();
+        if !numerator.is_empty() {
+            parse_product(numerator, 1, &is_simple_unit, &mut units).ok_or_else(invalid)?;
+        }
+        if let Some(denominator) = denominator {
+            parse_product(denominator, -1, &is_simple_unit, &mut units).ok_or_else(invalid)?;
+        }
+        if units.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self { units })
+    }
+
+    /// Returns the units before "per".
+    pub fn numerator(&self) -> impl Iterator<Item = &SingleUnit> + '_ {
+        self.units.iter().filter(|unit| unit.power > 0)
+    }
+
+    /// Returns the units after "per".
+    pub fn denominator(&self) -> impl Iterator<Item = &SingleUnit> + '_ {
+        self.units.iter().filter(|unit| unit.power < 0)
+    }
+}
+
+impl From<SingleUnit> for MeasureUnit {
+    fn from(unit: SingleUnit) -> Self {
+        Self { units: vec![unit] }
+    }
+}
+
+impl fmt::Display for MeasureUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for unit in self.numerator() {
+            write!(f, "{}{}", separator, unit)?;
+            separator = "-";
+        }
+        let mut separator = if separator.is_empty() {
+            "per-"
+        } else {
+            "-per-"
+        };
+        for unit in self.denominator() {
+            write!(f, "{}{}", separator, unit)?;
+            separator = "-";
+        }
+        Ok(())
+    }
+}
+
+/// Parses a product of single units separated by hyphens, like "square-kilometer-hour",
+/// multiplying their powers by `sign`.
+fn parse_product(
+    mut rest: &str,
+    sign: i8,
+    is_simple_unit: &impl Fn(&str) -> bool,
+    units: &mut Vec<SingleUnit>,
+) -> Option<()> {
+    loop {
+        let power = if let Some(after) = rest.strip_prefix("square-") {
+            rest = after;
+            2
+        } else if let Some(after) = rest.strip_prefix("cubic-") {
+            rest = after;
+            3
+        } else if let Some(after) = rest.strip_prefix("pow") {
+            let (digit, after) = after.split_at(after.find('-')?);
+            match digit.parse::<i8>() {
+                Ok(power @ 2..=9) if digit.len() == 1 => {
+                    rest = &after[1..];
+                    power
+                }
+                _ => 1,
+            }
+        } else {
+            1
+        };
+
+        // The longest prefix of `rest` ending at a hyphen which is a, possibly prefixed,
+        // simple unit, since simple units like "light-year" may contain hyphens.
+        let ends = rest
+            .match_indices('-')
+            .map(|(index, _)| index)
+            .chain(core::iter::once(rest.len()));
+        let (end, prefix, unit) = ends.collect::<Vec<_>>().into_iter().rev().find_map(|end| {
+            let candidate = &rest[..end];
+            if is_simple_unit(candidate) {
+                return Some((end, None, candidate));
+            }
+            PREFIXES.iter().find_map(|(name, _, prefix)| {
+                candidate
+                    .strip_prefix(name)
+                    .filter(|unit| is_simple_unit(unit))
+                    .map(|unit| (end, Some(*prefix), unit))
+            })
+        })?;
+        units.push(SingleUnit {
+            power: power * sign,
+            prefix,
+            unit: unit.to_string(),
+        });
+        if end == rest.len() {
+            return Some(());
+        }
+        rest = &rest[end + 1..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(id: &str) -> Result<MeasureUnit, UnitsError> {
+        MeasureUnit::try_from_str(id, |unit| {
+            [
+                "meter",
+                "second",
+                "hour",
+                "gram",
+                "light-year",
+                "byte",
+                "foot",
+                "pound-force",
+            ]
+            .contains(&unit)
+        })
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for id in [
+            "meter",
+            "kilometer-per-hour",
+            "meter-per-square-second",
+            "light-year",
+            "cubic-centimeter",
+            "kibibyte",
+            "pound-force-foot",
+            "per-second",
+            "pow4-meter-per-hour-second",
+        ] {
+            assert_eq!(parse(id).unwrap().to_string(), id);
+        }
+    }
+
+    #[test]
+    fn test_units() {
+        assert_eq!(
+            parse("square-kilometer-per-hour").unwrap().units,
+            vec![
+                SingleUnit {
+                    power: 2,
+                    prefix: Some(UnitPrefix::si(3)),
+                    unit: "meter".into(),
+                },
+                SingleUnit {
+                    power: -1,
+                    prefix: None,
+                    unit: "hour".into(),
+                },
+            ]
+        );
+        assert!((UnitPrefix::binary(10).factor() - 1024.0).abs() < f64::EPSILON);
+        assert_eq!(UnitPrefix::si(-3).cldr_id(), "10p-3");
+    }
+
+    #[test]
+    fn test_invalid() {
+        for id in [
+            "",
+            "meters",
+            "meter-",
+            "square-",
+            "meter-per-",
+            "foot-and-inch",
+            "pow1-meter",
+        ] {
+            assert!(
+                matches!(parse(id), Err(UnitsError::InvalidUnit(unit)) if unit == id),
+                "{}",
+                id
+            );
+        }
+    }
+}
@@ -0,0 +1,23 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`UnitsFormatter`](crate::UnitsFormatter).
+
+/// The width of the unit names, like "3 kilometers" in [`UnitWidth::Long`], "3 km" in
+/// [`UnitWidth::Short`] and "3km" in [`UnitWidth::Narrow`] in English.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum UnitWidth {
+    /// The full names of the units, like "3 kilometers".
+    Long,
+    /// Abbreviated names of the units, like "3 km".
+    Short,
+    /// The shortest names of the units, like "3km", which may be ambiguous.
+    Narrow,
+}
+
+impl Default for UnitWidth {
+    fn default() -> Self {
+        Self::Short
+    }
+}
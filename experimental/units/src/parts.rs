@@ -0,0 +1,16 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The [`Part`]s of a [`FormattedUnit`](crate::FormattedUnit), which it writes with
+//! [`Writeable::write_to_parts`](writeable::Writeable::write_to_parts).
+//!
+//! The number is written with the parts of [`icu_decimal::parts`].
+
+use writeable::Part;
+
+/// The text of the unit around the number, like `" kilometers"`.
+pub const UNIT: Part = Part {
+    category: "unit",
+    value: "unit",
+};
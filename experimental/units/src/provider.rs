@@ -0,0 +1,215 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use icu_pattern::{DoublePlaceholder, SinglePlaceholder, TypedPattern};
+use icu_plurals::PluralCategory;
+use icu_provider::yoke::{self, *};
+use litemap::LiteMap;
+
+pub mod key {
+    //! Resource keys for [`icu_units`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: the patterns of the units in [`UnitWidth::Long`](crate::UnitWidth::Long).
+    pub const UNITS_LONG_V1: ResourceKey = resource_key!(Units, "long", 1);
+
+    /// Resource key: the patterns of the units in [`UnitWidth::Short`](crate::UnitWidth::Short).
+    pub const UNITS_SHORT_V1: ResourceKey = resource_key!(Units, "short", 1);
+
+    /// Resource key: the patterns of the units in
+    /// [`UnitWidth::Narrow`](crate::UnitWidth::Narrow).
+    pub const UNITS_NARROW_V1: ResourceKey = resource_key!(Units, "narrow", 1);
+
+    /// Resource key: the conversions of the units to their base units.
+    pub const UNITS_CONVERSION_V1: ResourceKey = resource_key!(Units, "conversion", 1);
+
+    /// Resource key: the preferred units of each usage in each region.
+    pub const UNITS_PREFERENCES_V1: ResourceKey = resource_key!(Units, "preferences", 1);
+
+    /// Returns the key of the patterns of the units in a [`UnitWidth`](crate::UnitWidth).
+    pub fn for_width(width: crate::UnitWidth) -> ResourceKey {
+        match width {
+            crate::UnitWidth::Long => UNITS_LONG_V1,
+            crate::UnitWidth::Short => UNITS_SHORT_V1,
+            crate::UnitWidth::Narrow => UNITS_NARROW_V1,
+        }
+    }
+}
+
+/// The patterns of the units of a locale in one [`UnitWidth`](crate::UnitWidth).
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-general.html#Unit_Elements>
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct UnitPatternsV1<'data> {
+    /// The patterns of the units by their identifiers without category, like "kilometer" or
+    /// "kilometer-per-hour", with the number as `{0}`.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub units: LiteMap<Cow<'data, str>, PluralPatternsV1<'data>>,
+    /// The patterns of the units after "per", like "{0} per hour" for "hour", with the value
+    /// of the unit before "per" as `{0}`.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub per_unit: LiteMap<Cow<'data, str>, TypedPattern<'data, SinglePlaceholder>>,
+    /// The pattern of the units without a pattern in `per_unit`, like "{0} per {1}".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub per: TypedPattern<'data, DoublePlaceholder>,
+    /// The pattern of products of units, like "{0}-{1}".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub times: TypedPattern<'data, DoublePlaceholder>,
+    /// The pattern of the names of squared units, like "square {0}".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub power2: TypedPattern<'data, SinglePlaceholder>,
+    /// The pattern of the names of cubed units, like "cubic {0}".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub power3: TypedPattern<'data, SinglePlaceholder>,
+    /// The patterns of the names of prefixed units by the identifiers of the prefixes in CLDR,
+    /// like "kilo{0}" for "10p3".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub prefixes: LiteMap<Cow<'data, str>, TypedPattern<'data, SinglePlaceholder>>,
+}
+
+/// The patterns of a unit in each plural category, with the number as `{0}`.
+///
+/// Only `other` is required; the patterns of the other categories fall back to it.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct PluralPatternsV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub zero: Option<TypedPattern<'data, SinglePlaceholder>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub one: Option<TypedPattern<'data, SinglePlaceholder>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub two: Option<TypedPattern<'data, SinglePlaceholder>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub few: Option<TypedPattern<'data, SinglePlaceholder>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub many: Option<TypedPattern<'data, SinglePlaceholder>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub other: TypedPattern<'data, SinglePlaceholder>,
+}
+
+impl<'data> PluralPatternsV1<'data> {
+    /// Returns the pattern of a [`PluralCategory`].
+    pub fn get(&self, category: PluralCategory) -> &TypedPattern<'data, SinglePlaceholder> {
+        match category {
+            PluralCategory::Zero => self.zero.as_ref(),
+            PluralCategory::One => self.one.as_ref(),
+            PluralCategory::Two => self.two.as_ref(),
+            PluralCategory::Few => self.few.as_ref(),
+            PluralCategory::Many => self.many.as_ref(),
+            PluralCategory::Other => None,
+        }
+        .unwrap_or(&self.other)
+    }
+}
+
+/// The conversions of the units to their base units, which are products of the SI base units
+/// like "meter" or "kilogram-meter-per-square-second", and the quantities measured by the
+/// base units.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-info.html#Unit_Conversion>
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct UnitsConversionV1<'data> {
+    /// The conversions of the simple units, like "mile" or "light-year", by their identifiers.
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub conversions: LiteMap<Cow<'data, str>, ConversionInfoV1<'data>>,
+    /// The quantities measured by the base units, like "speed" for "meter-per-second".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub quantities: LiteMap<Cow<'data, str>, Cow<'data, str>>,
+}
+
+/// The conversion of a simple unit to its base unit: a value of the unit is
+/// `value * factor + offset` in the base unit.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct ConversionInfoV1<'data> {
+    /// The base unit, like "meter" for "mile".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub base_unit: Cow<'data, str>,
+    pub factor: f64,
+    /// The offset of units which do not start at zero, like "fahrenheit".
+    pub offset: f64,
+}
+
+/// The preferred units of each usage in each region, like miles for road distances in the
+/// United Kingdom.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-info.html#Unit_Preferences>
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct UnitPreferencesV1<'data> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub preferences: Vec<UsagePreferencesV1<'data>>,
+}
+
+impl<'data> UnitPreferencesV1<'data> {
+    /// Returns the preferences of a usage, like "road", of a quantity, like "length".
+    pub fn get(&self, quantity: &str, usage: &str) -> Option<&UsagePreferencesV1<'data>> {
+        self.preferences
+            .iter()
+            .find(|preferences| preferences.quantity == quantity && preferences.usage == usage)
+    }
+}
+
+/// The preferred units of a usage of a quantity in each region, by the region codes, where
+/// "001" has the preferences of the regions without their own.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct UsagePreferencesV1<'data> {
+    /// The quantity, like "length".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub quantity: Cow<'data, str>,
+    /// The usage, like "road" or "default".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub usage: Cow<'data, str>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub regions: LiteMap<Cow<'data, str>, Vec<UnitPreferenceV1<'data>>>,
+}
+
+/// A preferred unit, which is used for values of at least `geq` of the unit.
+///
+/// The preferences of a region are ordered from the largest unit to the smallest one, and the
+/// last one is used for the values too small for all of them.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct UnitPreferenceV1<'data> {
+    /// The identifier of the unit, like "kilometer".
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub unit: Cow<'data, str>,
+    pub geq: f64,
+}
@@ -0,0 +1,234 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_locid::LanguageIdentifier;
+use icu_locid_macros::langid;
+use icu_units::{parts, MeasureUnit, UnitWidth, UnitsConverter, UnitsError, UnitsFormatter};
+use writeable::{assert_writeable_eq, assert_writeable_parts_eq};
+
+fn formatter(langid: LanguageIdentifier, width: UnitWidth) -> UnitsFormatter<'static> {
+    let provider = icu_testdata::get_provider();
+    UnitsFormatter::try_new(langid, &provider, width).unwrap()
+}
+
+fn converter() -> UnitsConverter<'static> {
+    let provider = icu_testdata::get_provider();
+    UnitsConverter::try_new(&provider).unwrap()
+}
+
+fn unit(id: &str) -> MeasureUnit {
+    converter().parse_unit(id).unwrap()
+}
+
+fn decimal(value: &str) -> FixedDecimal {
+    value.parse().unwrap()
+}
+
+fn assert_format(formatter: &UnitsFormatter<'_>, value: &str, unit_id: &str, expected: &str) {
+    let value = decimal(value);
+    let unit = unit(unit_id);
+    assert_writeable_eq!(expected, formatter.format(&value, &unit).unwrap());
+}
+
+fn assert_approx_eq(expected: f64, actual: f64) {
+    assert!(
+        (expected - actual).abs() <= 1e-9 * expected.abs().max(1.0),
+        "{} != {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_en() {
+    let long = formatter(langid!("en"), UnitWidth::Long);
+    assert_format(&long, "1", "kilometer", "1 kilometer");
+    assert_format(&long, "3", "kilometer", "3 kilometers");
+    assert_format(&long, "2.5", "kilometer", "2.5 kilometers");
+    assert_format(&long, "1000", "kilometer", "1,000 kilometers");
+    assert_format(&long, "2", "square-mile", "2 square miles");
+
+    let short = formatter(langid!("en"), UnitWidth::Short);
+    assert_format(&short, "5", "kilometer-per-hour", "5 km/h");
+    assert_format(&short, "20", "celsius", "20°C");
+
+    let narrow = formatter(langid!("en"), UnitWidth::Narrow);
+    assert_format(&narrow, "5", "kilometer", "5km");
+}
+
+#[test]
+fn test_en_compound() {
+    let long = formatter(langid!("en"), UnitWidth::Long);
+    let short = formatter(langid!("en"), UnitWidth::Short);
+    assert_format(
+        &long,
+        "5",
+        "kilometer-per-minute",
+        "5 kilometers per minute",
+    );
+    assert_format(&short, "5", "kilometer-per-minute", "5 km/min");
+    assert_format(
+        &long,
+        "5",
+        "kilometer-per-square-hour",
+        "5 kilometers per square hour",
+    );
+    assert_format(&short, "5", "kilometer-per-square-hour", "5 km/hr²");
+    assert_format(&long, "5", "kibibyte", "5 kibibytes");
+    assert_format(&long, "1", "kibibyte", "1 kibibyte");
+    assert_format(&long, "5", "kilogram-meter", "5 kilogram-meters");
+}
+
+#[test]
+fn test_fr() {
+    let short = formatter(langid!("fr"), UnitWidth::Short);
+    assert_format(&short, "90", "kilometer-per-hour", "90\u{a0}km/h");
+
+    let long = formatter(langid!("fr"), UnitWidth::Long);
+    assert_format(&long, "1.5", "kilometer", "1,5 kilomètre");
+    assert_format(&long, "2", "kilometer", "2 kilomètres");
+    assert_format(
+        &long,
+        "5",
+        "kilometer-per-minute",
+        "5 kilomètres par minute",
+    );
+}
+
+#[test]
+fn test_ja() {
+    let long = formatter(langid!("ja"), UnitWidth::Long);
+    assert_format(&long, "5", "kilometer-per-hour", "時速 5 キロメートル");
+    // The denominator comes first in the Japanese "per" pattern.
+    assert_format(&long, "5", "kilometer-per-minute", "分あたり5 キロメートル");
+}
+
+#[test]
+fn test_ru_plurals() {
+    let long = formatter(langid!("ru"), UnitWidth::Long);
+    for (value, expected) in [
+        ("1", "1 километр"),
+        ("3", "3 километра"),
+        ("5", "5 километров"),
+        ("21", "21 километр"),
+        ("1.5", "1,5 километра"),
+    ] {
+        assert_format(&long, value, "kilometer", expected);
+    }
+}
+
+#[test]
+fn test_missing_patterns() {
+    let long = formatter(langid!("en"), UnitWidth::Long);
+    let one = decimal("1");
+    for id in ["light-year", "pow4-meter"] {
+        assert!(matches!(
+            long.format(&one, &unit(id)),
+            Err(UnitsError::MissingPatterns(_))
+        ));
+    }
+}
+
+#[test]
+fn test_convert() {
+    let converter = converter();
+    let convert = |value, from, to| converter.convert(value, &unit(from), &unit(to));
+
+    assert_approx_eq(1.609344, convert(1.0, "mile", "kilometer").unwrap());
+    assert_approx_eq(212.0, convert(100.0, "celsius", "fahrenheit").unwrap());
+    assert_approx_eq(-40.0, convert(-40.0, "fahrenheit", "celsius").unwrap());
+    assert_approx_eq(
+        10.0,
+        convert(36.0, "kilometer-per-hour", "meter-per-second").unwrap(),
+    );
+    assert_approx_eq(8192.0, convert(1.0, "kibibyte", "bit").unwrap());
+    assert_approx_eq(
+        2.589988110336,
+        convert(1.0, "square-mile", "square-kilometer").unwrap(),
+    );
+
+    assert!(matches!(
+        convert(1.0, "meter", "second"),
+        Err(UnitsError::IncompatibleUnits(from, to)) if from == "meter" && to == "second"
+    ));
+    assert!(matches!(
+        converter.parse_unit("furlong"),
+        Err(UnitsError::InvalidUnit(unit)) if unit == "furlong"
+    ));
+}
+
+#[test]
+fn test_preferences() {
+    let converter = converter();
+    let kilometer = unit("kilometer");
+    let preferred = |value, usage, langid| {
+        let (value, unit) = converter
+            .preferred_unit(value, &kilometer, usage, &langid)
+            .unwrap();
+        (value, unit.to_string())
+    };
+
+    let (value, unit) = preferred(1.0, "road", langid!("en-US"));
+    assert_eq!(unit, "mile");
+    assert_approx_eq(1.0 / 1.609344, value);
+    let (value, unit) = preferred(0.5, "road", langid!("en-US"));
+    assert_eq!(unit, "foot");
+    assert_approx_eq(500.0 / 0.3048, value);
+    assert_eq!(preferred(0.5, "road", langid!("en-GB")).1, "yard");
+
+    // Without a region, the preferences of the world apply.
+    assert_eq!(
+        preferred(5.0, "road", langid!("en")),
+        (5.0, "kilometer".into())
+    );
+    assert_eq!(
+        preferred(0.5, "road", langid!("fr")),
+        (500.0, "meter".into())
+    );
+
+    // Usages without preferences fall back to the default usage.
+    assert_eq!(preferred(2.0, "hiking", langid!("de")).1, "kilometer");
+    assert_eq!(preferred(2.0, "hiking", langid!("en-GB")).1, "mile");
+
+    let (value, unit) = converter
+        .preferred_unit(100.0, &self::unit("celsius"), "default", &langid!("en-US"))
+        .unwrap();
+    assert_eq!(unit.to_string(), "fahrenheit");
+    assert_approx_eq(212.0, value);
+
+    assert!(matches!(
+        converter.preferred_unit(1.0, &self::unit("byte"), "default", &langid!("en")),
+        Err(UnitsError::MissingPreferences(unit, usage)) if unit == "byte" && usage == "default"
+    ));
+}
+
+#[test]
+fn test_parts() {
+    let short = formatter(langid!("en"), UnitWidth::Short);
+    let value = decimal("1234");
+    let kilometer = unit("kilometer");
+    assert_writeable_parts_eq!(
+        "1,234 km",
+        &short.format(&value, &kilometer).unwrap(),
+        [
+            (0, 5, icu_decimal::parts::INTEGER),
+            (1, 2, icu_decimal::parts::GROUP),
+            (5, 8, parts::UNIT),
+        ]
+    );
+
+    let long = formatter(langid!("ja"), UnitWidth::Long);
+    let value = decimal("5");
+    let kilometer_per_hour = unit("kilometer-per-hour");
+    assert_writeable_parts_eq!(
+        "時速 5 キロメートル",
+        &long.format(&value, &kilometer_per_hour).unwrap(),
+        [
+            (0, 7, parts::UNIT),
+            (7, 8, icu_decimal::parts::INTEGER),
+            (8, 27, parts::UNIT),
+        ]
+    );
+}
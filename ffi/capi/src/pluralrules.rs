@@ -38,7 +38,7 @@ pub mod ffi {
     /// FFI version of `PluralRules`.
     /// See [the Rust docs](https://unicode-org.github.io/icu4x-docs/doc/icu_plurals/struct.PluralRules.html) for more details.
     #[diplomat::opaque]
-    pub struct ICU4XPluralRules(PluralRules);
+    pub struct ICU4XPluralRules(PluralRules<'static>);
 
     impl ICU4XPluralRules {
         /// FFI version of `PluralRules::try_new()`.
@@ -0,0 +1,415 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::{get_subdirectories, open_reader};
+use crate::CldrPaths;
+use icu_displaynames::provider::*;
+use icu_locid::LanguageIdentifier;
+use icu_pattern::{DoublePlaceholder, TypedPattern};
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use litemap::LiteMap;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 5] = [
+    key::LANGUAGES_V1,
+    key::SCRIPTS_V1,
+    key::REGIONS_V1,
+    key::VARIANTS_V1,
+    key::LOCALE_V1,
+];
+
+/// The files of each locale in cldr-localenames, which all contain parts of the
+/// `localeDisplayNames` of the locale.
+const FILES: [&str; 5] = [
+    "languages.json",
+    "scripts.json",
+    "territories.json",
+    "variants.json",
+    "localeDisplayNames.json",
+];
+
+/// The BCP47 keys of the Unicode extension keys which CLDR names by longer names.
+const KEY_ALIASES: &[(&str, &str)] = &[
+    ("calendar", "ca"),
+    ("colAlternate", "ka"),
+    ("colBackwards", "kb"),
+    ("colCaseFirst", "kf"),
+    ("colCaseLevel", "kc"),
+    ("colHiraganaQuaternary", "kh"),
+    ("collation", "co"),
+    ("colNormalization", "kk"),
+    ("colNumeric", "kn"),
+    ("colStrength", "ks"),
+    ("currency", "cu"),
+    ("numbers", "nu"),
+    ("timezone", "tz"),
+    ("variableTop", "vt"),
+];
+
+/// The BCP47 types of the Unicode extension keywords which CLDR names by longer names, by
+/// their BCP47 keys.
+const TYPE_ALIASES: &[(&str, &str, &str)] = &[
+    ("ca", "ethiopic-amete-alem", "ethioaa"),
+    ("ca", "gregorian", "gregory"),
+    ("co", "dictionary", "dict"),
+    ("co", "gb2312han", "gb2312"),
+    ("co", "phonebook", "phonebk"),
+    ("co", "traditional", "trad"),
+    ("ka", "non-ignorable", "noignore"),
+    ("ks", "identical", "identic"),
+    ("ks", "primary", "level1"),
+    ("ks", "quaternary", "level4"),
+    ("ks", "secondary", "level2"),
+    ("ks", "tertiary", "level3"),
+    ("nu", "traditional", "traditio"),
+];
+
+/// A data provider reading from CLDR JSON locale display name files.
+#[derive(PartialEq, Debug)]
+pub struct DisplayNamesProvider<'data> {
+    data: Vec<(CldrLangID, cldr_json::LocaleDisplayNames)>,
+    phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for DisplayNamesProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let mut data: Vec<(CldrLangID, cldr_json::LocaleDisplayNames)> = vec![];
+
+        let path = cldr_paths.cldr_localenames()?.join("main");
+
+        let locale_dirs = get_subdirectories(&path)?;
+
+        for dir in locale_dirs {
+            let start = data.len();
+            for file in FILES.iter() {
+                let path = dir.join(file);
+
+                let resource: cldr_json::Resource =
+                    serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+                for (langid, names) in resource.main.0 {
+                    match data[start..].iter_mut().find(|(l, _)| *l == langid) {
+                        Some((_, merged)) => merged.merge(names.locale_display_names),
+                        None => data.push((langid, names.locale_display_names)),
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            data,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for DisplayNamesProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        if resc_key.category != ResourceCategory::DisplayNames || resc_key.version != 1 {
+            return Err(resc_key.into());
+        }
+        Ok(())
+    }
+}
+
+impl<'data> DisplayNamesProvider<'data> {
+    fn get(&self, req: &DataRequest) -> Result<&cldr_json::LocaleDisplayNames, DataError> {
+        Self::supports_key(&req.resource_path.key)?;
+        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+        match self
+            .data
+            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+        {
+            Ok(idx) => Ok(&self.data[idx].1),
+            Err(_) => Err(DataError::MissingResourceOptions(req.clone())),
+        }
+    }
+}
+
+impl<'data> DataProvider<'data, DisplayNamesV1Marker> for DisplayNamesProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, DisplayNamesV1Marker>, DataError> {
+        let names = self.get(req)?;
+        let display_names = match req.resource_path.key {
+            key::LANGUAGES_V1 => to_display_names(&names.languages, false),
+            key::SCRIPTS_V1 => to_display_names(&names.scripts, false),
+            key::REGIONS_V1 => to_display_names(&names.territories, false),
+            // The variants are uppercase in CLDR and lowercase in locale identifiers.
+            key::VARIANTS_V1 => to_display_names(&names.variants, true),
+            _ => return Err(DataError::MissingResourceKey(req.resource_path.key)),
+        };
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(display_names)),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, LocaleDisplayNamesV1Marker> for DisplayNamesProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, LocaleDisplayNamesV1Marker>, DataError> {
+        if req.resource_path.key != key::LOCALE_V1 {
+            return Err(DataError::MissingResourceKey(req.resource_path.key));
+        }
+        let names = self.get(req)?;
+        let langid = req.try_langid()?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(
+                to_locale_display_names(names, langid).map_err(DataError::new_resc_error)?,
+            )),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(DisplayNamesProvider<'data>, {
+    key::LANGUAGES_V1 => DisplayNamesV1Marker,
+    key::SCRIPTS_V1 => DisplayNamesV1Marker,
+    key::REGIONS_V1 => DisplayNamesV1Marker,
+    key::VARIANTS_V1 => DisplayNamesV1Marker,
+    key::LOCALE_V1 => LocaleDisplayNamesV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for DisplayNamesProvider<'data> {
+    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = self
+            .data
+            .iter()
+            .map(|(l, _)| ResourceOptions {
+                variant: None,
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Converts names keyed like "GB" or "GB-alt-short", skipping the alternative names other than
+/// the short names, like "CZ-alt-variant".
+fn to_display_names(names: &BTreeMap<String, String>, lowercase: bool) -> DisplayNamesV1<'static> {
+    let mut display_names = DisplayNamesV1::default();
+    for (code, name) in names.iter() {
+        let (map, code) = match code.split_once("-alt-") {
+            None => (&mut display_names.names, code.as_str()),
+            Some((code, "short")) => (&mut display_names.short_names, code),
+            Some(_) => continue,
+        };
+        let code = if lowercase {
+            code.to_ascii_lowercase()
+        } else {
+            code.to_string()
+        };
+        map.insert(Cow::Owned(code), Cow::Owned(name.clone()));
+    }
+    display_names
+}
+
+/// Returns the BCP47 key of a Unicode extension key in CLDR, like "ca" for "calendar".
+fn to_bcp47_key(key: &str) -> Option<&str> {
+    match KEY_ALIASES.iter().find(|(long, _)| *long == key) {
+        Some((_, bcp47)) => Some(bcp47),
+        None if key.len() == 2 => Some(key),
+        None => None,
+    }
+}
+
+fn to_locale_display_names(
+    names: &cldr_json::LocaleDisplayNames,
+    langid: &LanguageIdentifier,
+) -> Result<LocaleDisplayNamesV1<'static>, Error> {
+    let patterns = names.locale_display_pattern.as_ref().ok_or_else(|| {
+        Error::Custom(
+            "Missing localeDisplayPattern".to_string(),
+            Some(langid.clone()),
+        )
+    })?;
+    let parse = |pattern: &str| -> Result<TypedPattern<'static, DoublePlaceholder>, Error> {
+        TypedPattern::try_from(pattern)
+            .map_err(|e| Error::Custom(format!("{}: {:?}", e, pattern), Some(langid.clone())))
+    };
+
+    let mut keys = LiteMap::new();
+    for (key, name) in names.keys.iter() {
+        if let Some(key) = to_bcp47_key(key) {
+            keys.insert(Cow::Owned(key.to_string()), Cow::Owned(name.clone()));
+        }
+    }
+    let mut types = LiteMap::new();
+    for (key, key_types) in names.types.iter() {
+        let key = match to_bcp47_key(key) {
+            Some(key) => key,
+            None => continue,
+        };
+        for (value, name) in key_types.iter() {
+            let value = TYPE_ALIASES
+                .iter()
+                .find(|(alias_key, long, _)| *alias_key == key && long == value)
+                .map_or(value.as_str(), |(_, _, bcp47)| bcp47);
+            types.insert(
+                Cow::Owned(format!("{}-{}", key, value)),
+                Cow::Owned(name.clone()),
+            );
+        }
+    }
+
+    Ok(LocaleDisplayNamesV1 {
+        pattern: parse(&patterns.locale_pattern)?,
+        separator: parse(&patterns.locale_separator)?,
+        key_type_pattern: parse(&patterns.locale_key_type_pattern)?,
+        keys,
+        types,
+    })
+}
+
+pub(self) mod cldr_json {
+    //! Serde structs representing the CLDR JSON files of locale display names, like
+    //! languages.json or localeDisplayNames.json.
+    //!
+    //! Sample file:
+    //! https://github.com/unicode-org/cldr-json/blob/master/cldr-json/cldr-localenames-full/main/en/territories.json
+
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LocaleDisplayPattern {
+        #[serde(rename = "localePattern")]
+        pub locale_pattern: String,
+        #[serde(rename = "localeSeparator")]
+        pub locale_separator: String,
+        #[serde(rename = "localeKeyTypePattern")]
+        pub locale_key_type_pattern: String,
+    }
+
+    /// The parts of the `localeDisplayNames` of a locale, of which each file contains some.
+    #[derive(PartialEq, Debug, Default, Deserialize)]
+    pub struct LocaleDisplayNames {
+        #[serde(default)]
+        pub languages: BTreeMap<String, String>,
+        #[serde(default)]
+        pub scripts: BTreeMap<String, String>,
+        #[serde(default)]
+        pub territories: BTreeMap<String, String>,
+        #[serde(default)]
+        pub variants: BTreeMap<String, String>,
+        #[serde(default)]
+        pub keys: BTreeMap<String, String>,
+        #[serde(default)]
+        pub types: BTreeMap<String, BTreeMap<String, String>>,
+        #[serde(rename = "localeDisplayPattern")]
+        pub locale_display_pattern: Option<LocaleDisplayPattern>,
+    }
+
+    impl LocaleDisplayNames {
+        /// Adds the parts of the `localeDisplayNames` of another file of the same locale.
+        pub fn merge(&mut self, other: Self) {
+            self.languages.extend(other.languages);
+            self.scripts.extend(other.scripts);
+            self.territories.extend(other.territories);
+            self.variants.extend(other.variants);
+            self.keys.extend(other.keys);
+            self.types.extend(other.types);
+            if other.locale_display_pattern.is_some() {
+                self.locale_display_pattern = other.locale_display_pattern;
+            }
+        }
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangDisplayNames {
+        #[serde(rename = "localeDisplayNames")]
+        pub locale_display_names: LocaleDisplayNames,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangData(
+        #[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, LangDisplayNames)>,
+    );
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub main: LangData,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_displaynames::options::Style;
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = DisplayNamesProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let request = |key| DataRequest {
+        resource_path: ResourcePath {
+            key,
+            options: ResourceOptions {
+                variant: None,
+                langid: Some(langid!("en")),
+            },
+        },
+    };
+    let load = |key| -> DataPayload<DisplayNamesV1Marker> {
+        provider
+            .load_payload(&request(key))
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    let languages = load(key::LANGUAGES_V1);
+    assert_eq!(
+        languages.get().get("de-CH", Style::Long),
+        Some("Swiss High German")
+    );
+    assert_eq!(
+        languages.get().get("en-GB", Style::Short),
+        Some("UK English")
+    );
+
+    let regions = load(key::REGIONS_V1);
+    assert_eq!(regions.get().get("GB", Style::Long), Some("United Kingdom"));
+    assert_eq!(regions.get().get("GB", Style::Short), Some("UK"));
+    assert_eq!(regions.get().get("CH", Style::Short), Some("Switzerland"));
+
+    let variants = load(key::VARIANTS_V1);
+    assert_eq!(
+        variants.get().get("valencia", Style::Long),
+        Some("Valencian")
+    );
+
+    let locale: DataPayload<LocaleDisplayNamesV1Marker> = provider
+        .load_payload(&request(key::LOCALE_V1))
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(locale.get().pattern.to_string(), "{0} ({1})");
+    assert_eq!(
+        locale.get().keys.get("ca").map(|name| &**name),
+        Some("Calendar")
+    );
+    assert_eq!(
+        locale.get().types.get("ca-gregory").map(|name| &**name),
+        Some("Gregorian Calendar")
+    );
+}
@@ -0,0 +1,303 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::{get_subdirectories, open_reader};
+use crate::CldrPaths;
+use icu_list::provider::*;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 3] = [
+    key::LIST_FORMAT_AND_V1,
+    key::LIST_FORMAT_OR_V1,
+    key::LIST_FORMAT_UNIT_V1,
+];
+
+/// A data provider reading from CLDR JSON list pattern files.
+#[derive(PartialEq, Debug)]
+pub struct ListProvider<'data> {
+    data: Vec<(CldrLangID, cldr_json::LangListPatterns)>,
+    phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for ListProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let mut data = vec![];
+
+        let path = cldr_paths.cldr_misc()?.join("main");
+
+        let locale_dirs = get_subdirectories(&path)?;
+
+        for dir in locale_dirs {
+            let path = dir.join("listPatterns.json");
+
+            let mut resource: cldr_json::Resource =
+                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+            data.append(&mut resource.main.0);
+        }
+
+        Ok(Self {
+            data,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for ListProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        if resc_key.category != ResourceCategory::List || resc_key.version != 1 {
+            return Err(resc_key.into());
+        }
+        Ok(())
+    }
+}
+
+impl<'data> DataProvider<'data, ListFormatterPatternsV1Marker> for ListProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, ListFormatterPatternsV1Marker>, DataError> {
+        Self::supports_key(&req.resource_path.key)?;
+        let langid = req.try_langid()?;
+        let cldr_langid: CldrLangID = langid.clone().into();
+        let list_patterns = match self
+            .data
+            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+        {
+            Ok(idx) => &self.data[idx].1.list_patterns,
+            Err(_) => return Err(DataError::MissingResourceOptions(req.clone())),
+        };
+
+        let (wide, short, narrow) = match req.resource_path.key {
+            key::LIST_FORMAT_AND_V1 => (
+                &list_patterns.standard,
+                &list_patterns.standard_short,
+                &list_patterns.standard_narrow,
+            ),
+            key::LIST_FORMAT_OR_V1 => (
+                &list_patterns.or,
+                &list_patterns.or_short,
+                &list_patterns.or_narrow,
+            ),
+            key::LIST_FORMAT_UNIT_V1 => (
+                &list_patterns.unit,
+                &list_patterns.unit_short,
+                &list_patterns.unit_narrow,
+            ),
+            _ => return Err(DataError::MissingResourceKey(req.resource_path.key)),
+        };
+
+        let mut patterns = ListFormatterPatternsV1 {
+            wide: wide.try_into_patterns(langid)?,
+            short: short.try_into_patterns(langid)?,
+            narrow: narrow.try_into_patterns(langid)?,
+        };
+
+        // CLDR describes the special cases of these languages only in prose, see
+        // https://unicode.org/reports/tr35/tr35-general.html#ListPatterns
+        let special_cases: &[(&str, &str, &str)] = match langid.language.as_str() {
+            "es" => &[
+                // "y" becomes "e" before words starting with an /i/ sound, stressed or not, but
+                // not before diphthongs like "hie" or "hia".
+                ("{0} y {1}", "[ií]|hí|hi([^ae]|$)", "{0} e {1}"),
+                // "o" becomes "u" before words starting with an /o/ sound, stressed or not, and
+                // before numbers read with an /o/ sound, like "8" or "11".
+                (
+                    "{0} o {1}",
+                    "[oó]|h[oó]|8|(11(\\.?\\d\\d\\d)*(,\\d*)?([^\\.,\\d]|$))",
+                    "{0} u {1}",
+                ),
+            ],
+            // "ו" takes a hyphen before words which are not in Hebrew script.
+            "he" => &[("{0} ו{1}", "[^\u{0590}-\u{05FF}]", "{0} ו-{1}")],
+            _ => &[],
+        };
+        for &(default, condition, replacement) in special_cases {
+            add_special_case(&mut patterns, default, condition, replacement)
+                .map_err(|e| Error::Custom(e.to_string(), Some(langid.clone())))
+                .map_err(DataError::new_resc_error)?;
+        }
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(patterns)),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(ListProvider<'data>, {
+    key::LIST_FORMAT_AND_V1 => ListFormatterPatternsV1Marker,
+    key::LIST_FORMAT_OR_V1 => ListFormatterPatternsV1Marker,
+    key::LIST_FORMAT_UNIT_V1 => ListFormatterPatternsV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for ListProvider<'data> {
+    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = self
+            .data
+            .iter()
+            .map(|(l, _)| ResourceOptions {
+                variant: None,
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Adds a special case to every pattern of `patterns` which is `default`, replacing it with
+/// `replacement` if the following element matches `condition`.
+fn add_special_case(
+    patterns: &mut ListFormatterPatternsV1<'static>,
+    default: &str,
+    condition: &str,
+    replacement: &str,
+) -> Result<(), icu_list::ListError> {
+    let default = ListJoinerPattern::try_from(default)?;
+    let special_case = SpecialCasePattern {
+        condition: StringMatcher::try_new(condition.to_string())?,
+        pattern: ListJoinerPattern::try_from(replacement)?,
+    };
+    for joiners in [
+        &mut patterns.wide,
+        &mut patterns.short,
+        &mut patterns.narrow,
+    ] {
+        for pattern in [
+            &mut joiners.start,
+            &mut joiners.middle,
+            &mut joiners.end,
+            &mut joiners.pair,
+        ] {
+            if pattern.default == default {
+                pattern.special_case = Some(special_case.clone());
+            }
+        }
+    }
+    Ok(())
+}
+
+impl cldr_json::ListPatterns {
+    fn try_into_patterns(
+        &self,
+        langid: &icu_locid::LanguageIdentifier,
+    ) -> Result<ListJoinerPatternsV1<'static>, DataError> {
+        let parse = |pattern: &str| {
+            ListJoinerPattern::try_from(pattern)
+                .map(ConditionalListJoinerPattern::from)
+                .map_err(|e| Error::Custom(e.to_string(), Some(langid.clone())))
+                .map_err(DataError::new_resc_error)
+        };
+        Ok(ListJoinerPatternsV1 {
+            start: parse(&self.start)?,
+            middle: parse(&self.middle)?,
+            end: parse(&self.end)?,
+            pair: parse(&self.pair)?,
+        })
+    }
+}
+
+pub(self) mod cldr_json {
+    //! Serde structs representing CLDR JSON listPatterns.json files.
+    //!
+    //! Sample file:
+    //! https://github.com/unicode-org/cldr-json/blob/master/cldr-json/cldr-misc-full/main/en/listPatterns.json
+
+    use crate::cldr_langid::CldrLangID;
+    use serde::Deserialize;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct ListPatterns {
+        pub start: String,
+        pub middle: String,
+        pub end: String,
+        #[serde(rename = "2")]
+        pub pair: String,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct ListPatternsByType {
+        #[serde(rename = "listPattern-type-standard")]
+        pub standard: ListPatterns,
+        #[serde(rename = "listPattern-type-standard-short")]
+        pub standard_short: ListPatterns,
+        #[serde(rename = "listPattern-type-standard-narrow")]
+        pub standard_narrow: ListPatterns,
+        #[serde(rename = "listPattern-type-or")]
+        pub or: ListPatterns,
+        #[serde(rename = "listPattern-type-or-short")]
+        pub or_short: ListPatterns,
+        #[serde(rename = "listPattern-type-or-narrow")]
+        pub or_narrow: ListPatterns,
+        #[serde(rename = "listPattern-type-unit")]
+        pub unit: ListPatterns,
+        #[serde(rename = "listPattern-type-unit-short")]
+        pub unit_short: ListPatterns,
+        #[serde(rename = "listPattern-type-unit-narrow")]
+        pub unit_narrow: ListPatterns,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangListPatterns {
+        #[serde(rename = "listPatterns")]
+        pub list_patterns: ListPatternsByType,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangData(
+        #[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, LangListPatterns)>,
+    );
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub main: LangData,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = ListProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let load = |key, langid| -> DataPayload<ListFormatterPatternsV1Marker> {
+        provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(langid),
+                    },
+                },
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    let en_and = load(key::LIST_FORMAT_AND_V1, langid!("en"));
+    assert_eq!(en_and.get().wide.end.default.parts(), (", and ", ""));
+    assert_eq!(en_and.get().short.pair.default.parts(), (" & ", ""));
+    assert_eq!(en_and.get().wide.end.special_case, None);
+
+    let es_or = load(key::LIST_FORMAT_OR_V1, langid!("es"));
+    let special_case = es_or.get().wide.end.special_case.as_ref().unwrap();
+    assert!(special_case.condition.matches("otro"));
+    assert_eq!(special_case.pattern.parts(), (" u ", ""));
+    assert_eq!(es_or.get().wide.start.special_case, None);
+}
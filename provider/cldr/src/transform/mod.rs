@@ -4,16 +4,22 @@
 
 mod aliases;
 mod dates;
+mod displaynames;
 mod likelysubtags;
+mod list;
 mod numbers;
 mod plurals;
 mod time_zones;
+mod units;
 
 pub use aliases::AliasesProvider;
 pub use dates::{patterns::DatePatternsProvider, symbols::DateSymbolsProvider};
+pub use displaynames::DisplayNamesProvider;
 pub use likelysubtags::LikelySubtagsProvider;
+pub use list::ListProvider;
 pub use numbers::NumbersProvider;
 pub use plurals::PluralsProvider;
+pub use units::{conversion::UnitsConversionProvider, patterns::UnitPatternsProvider};
 
 use crate::support::LazyCldrProvider;
 use crate::CldrPaths;
@@ -29,10 +35,14 @@ pub fn get_all_cldr_keys() -> Vec<ResourceKey> {
     result.extend(&aliases::ALL_KEYS);
     result.extend(&dates::symbols::ALL_KEYS);
     result.extend(&dates::patterns::ALL_KEYS);
+    result.extend(&displaynames::ALL_KEYS);
     result.extend(&likelysubtags::ALL_KEYS);
+    result.extend(&list::ALL_KEYS);
     result.extend(&numbers::ALL_KEYS);
     result.extend(&plurals::ALL_KEYS);
     result.extend(&time_zones::ALL_KEYS);
+    result.extend(&units::conversion::ALL_KEYS);
+    result.extend(&units::patterns::ALL_KEYS);
     result
 }
 
@@ -42,10 +52,14 @@ pub struct CldrJsonDataProvider<'a, 'data> {
     aliases: LazyCldrProvider<AliasesProvider<'data>>,
     date_symbols: LazyCldrProvider<DateSymbolsProvider<'data>>,
     date_patterns: LazyCldrProvider<DatePatternsProvider<'data>>,
+    display_names: LazyCldrProvider<DisplayNamesProvider<'data>>,
     likelysubtags: LazyCldrProvider<LikelySubtagsProvider<'data>>,
+    list: LazyCldrProvider<ListProvider<'data>>,
     numbers: LazyCldrProvider<NumbersProvider>,
     plurals: LazyCldrProvider<PluralsProvider<'data>>,
     time_zones: LazyCldrProvider<TimeZonesProvider<'data>>,
+    units_conversion: LazyCldrProvider<UnitsConversionProvider<'data>>,
+    unit_patterns: LazyCldrProvider<UnitPatternsProvider<'data>>,
 }
 
 impl<'a> CldrJsonDataProvider<'a, '_> {
@@ -55,10 +69,14 @@ impl<'a> CldrJsonDataProvider<'a, '_> {
             aliases: Default::default(),
             date_symbols: Default::default(),
             date_patterns: Default::default(),
+            display_names: Default::default(),
             likelysubtags: Default::default(),
+            list: Default::default(),
             numbers: Default::default(),
             plurals: Default::default(),
             time_zones: Default::default(),
+            units_conversion: Default::default(),
+            unit_patterns: Default::default(),
         }
     }
 }
@@ -77,9 +95,15 @@ impl<'a, 'data> DataProvider<'data, SerdeSeDataStructMarker> for CldrJsonDataPro
         if let Some(result) = self.date_patterns.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.display_names.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         if let Some(result) = self.likelysubtags.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.list.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         if let Some(result) = self.numbers.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
@@ -89,6 +113,12 @@ impl<'a, 'data> DataProvider<'data, SerdeSeDataStructMarker> for CldrJsonDataPro
         if let Some(result) = self.time_zones.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.units_conversion.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
+        if let Some(result) = self.unit_patterns.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         Err(DataError::MissingResourceKey(req.resource_path.key))
     }
 }
@@ -116,12 +146,21 @@ impl<'a> IterableDataProviderCore for CldrJsonDataProvider<'a, '_> {
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self
+            .display_names
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
         if let Some(resp) = self
             .likelysubtags
             .try_supported_options(resc_key, self.cldr_paths)?
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self.list.try_supported_options(resc_key, self.cldr_paths)? {
+            return Ok(Box::new(resp.into_iter()));
+        }
         if let Some(resp) = self
             .numbers
             .try_supported_options(resc_key, self.cldr_paths)?
@@ -140,6 +179,18 @@ impl<'a> IterableDataProviderCore for CldrJsonDataProvider<'a, '_> {
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self
+            .units_conversion
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
+        if let Some(resp) = self
+            .unit_patterns
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
         Err(DataError::MissingResourceKey(*resc_key))
     }
 }
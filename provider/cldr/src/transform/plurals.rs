@@ -2,11 +2,14 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use crate::cldr_langid::CldrLangID;
 use crate::error::Error;
 use crate::reader::open_reader;
 use crate::CldrPaths;
 use icu_plurals::provider::*;
+use icu_plurals::rules::ast::Rule;
 use icu_plurals::rules::{parse, serialize};
+use icu_plurals::PluralCategory;
 use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
 use icu_provider::prelude::*;
 use std::borrow::Cow;
@@ -14,9 +17,14 @@ use std::convert::TryFrom;
 use std::marker::PhantomData;
 
 /// All keys that this module is able to produce.
-pub const ALL_KEYS: [ResourceKey; 2] = [
-    key::CARDINAL_V1, //
-    key::ORDINAL_V1,  //
+pub const ALL_KEYS: [ResourceKey; 7] = [
+    key::CARDINAL_V1,         //
+    key::ORDINAL_V1,          //
+    key::CARDINAL_V2,         //
+    key::ORDINAL_V2,          //
+    key::RANGES_V1,           //
+    key::CARDINAL_SAMPLES_V1, //
+    key::ORDINAL_SAMPLES_V1,  //
 ];
 
 /// A data provider reading from CLDR JSON plural rule files.
@@ -24,6 +32,7 @@ pub const ALL_KEYS: [ResourceKey; 2] = [
 pub struct PluralsProvider<'data> {
     cardinal_rules: Option<cldr_json::Rules>,
     ordinal_rules: Option<cldr_json::Rules>,
+    ranges: Option<cldr_json::Ranges>,
     _phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
 }
 
@@ -48,9 +57,19 @@ impl TryFrom<&dyn CldrPaths> for PluralsProvider<'_> {
                 serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
             data.supplemental.plurals_type_ordinal
         };
+        let ranges = {
+            let path = cldr_paths
+                .cldr_core()?
+                .join("supplemental")
+                .join("pluralRanges.json");
+            let data: cldr_json::Resource =
+                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+            data.supplemental.plurals
+        };
         Ok(PluralsProvider {
             cardinal_rules,
             ordinal_rules,
+            ranges,
             _phantom: PhantomData,
         })
     }
@@ -58,7 +77,7 @@ impl TryFrom<&dyn CldrPaths> for PluralsProvider<'_> {
 
 impl<'data> KeyedDataProvider for PluralsProvider<'data> {
     fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
-        if resc_key.category != ResourceCategory::Plurals || resc_key.version != 1 {
+        if resc_key.category != ResourceCategory::Plurals || !(1..=2).contains(&resc_key.version) {
             return Err(resc_key.into());
         }
         Ok(())
@@ -69,8 +88,34 @@ impl<'data> PluralsProvider<'data> {
     fn get_rules_for(&self, resc_key: &ResourceKey) -> Result<&cldr_json::Rules, DataError> {
         PluralsProvider::supports_key(resc_key)?;
         match *resc_key {
-            key::CARDINAL_V1 => self.cardinal_rules.as_ref(),
-            key::ORDINAL_V1 => self.ordinal_rules.as_ref(),
+            key::CARDINAL_V1 | key::CARDINAL_V2 | key::CARDINAL_SAMPLES_V1 => {
+                self.cardinal_rules.as_ref()
+            }
+            key::ORDINAL_V1 | key::ORDINAL_V2 | key::ORDINAL_SAMPLES_V1 => {
+                self.ordinal_rules.as_ref()
+            }
+            _ => return Err(resc_key.into()),
+        }
+        .ok_or_else(|| resc_key.into())
+    }
+
+    fn get_locale_rules(
+        &self,
+        req: &DataRequest,
+    ) -> Result<&cldr_json::LocalePluralRules, DataError> {
+        let cldr_rules = self.get_rules_for(&req.resource_path.key)?;
+        // TODO: Implement language fallback?
+        let cldr_langid = req.try_langid()?.clone().into();
+        match cldr_rules.0.binary_search_by_key(&&cldr_langid, |(l, _)| l) {
+            Ok(idx) => Ok(&cldr_rules.0[idx].1),
+            Err(_) => Err(req.clone().into()),
+        }
+    }
+
+    fn get_ranges(&self, resc_key: &ResourceKey) -> Result<&cldr_json::Ranges, DataError> {
+        PluralsProvider::supports_key(resc_key)?;
+        match *resc_key {
+            key::RANGES_V1 => self.ranges.as_ref(),
             _ => return Err(resc_key.into()),
         }
         .ok_or_else(|| resc_key.into())
@@ -82,24 +127,77 @@ impl<'data> DataProvider<'data, PluralRuleStringsV1Marker> for PluralsProvider<'
         &self,
         req: &DataRequest,
     ) -> Result<DataResponse<'data, PluralRuleStringsV1Marker>, DataError> {
-        let cldr_rules = self.get_rules_for(&req.resource_path.key)?;
+        let r = self.get_locale_rules(req)?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(PluralRuleStringsV1::from(r))),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, PluralRulesV2Marker> for PluralsProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, PluralRulesV2Marker>, DataError> {
+        let r = self.get_locale_rules(req)?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(PluralRulesV2::from(r))),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, PluralSamplesV1Marker> for PluralsProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, PluralSamplesV1Marker>, DataError> {
+        let r = self.get_locale_rules(req)?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(PluralSamplesV1::from(r))),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, PluralRangesV1Marker> for PluralsProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, PluralRangesV1Marker>, DataError> {
+        let cldr_ranges = self.get_ranges(&req.resource_path.key)?;
         // TODO: Implement language fallback?
         let cldr_langid = req.try_langid()?.clone().into();
-        let (_, r) = match cldr_rules.0.binary_search_by_key(&&cldr_langid, |(l, _)| l) {
-            Ok(idx) => &cldr_rules.0[idx],
+        let (_, r) = match cldr_ranges
+            .0
+            .binary_search_by_key(&&cldr_langid, |(l, _)| l)
+        {
+            Ok(idx) => &cldr_ranges.0[idx],
             Err(_) => return Err(req.clone().into()),
         };
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
             },
-            payload: Some(DataPayload::from_owned(PluralRuleStringsV1::from(r))),
+            payload: Some(DataPayload::from_owned(PluralRangesV1::from(r))),
         })
     }
 }
 
 icu_provider::impl_dyn_provider!(PluralsProvider<'data>, {
-    _ => PluralRuleStringsV1Marker,
+    key::CARDINAL_V1 => PluralRuleStringsV1Marker,
+    key::ORDINAL_V1 => PluralRuleStringsV1Marker,
+    key::RANGES_V1 => PluralRangesV1Marker,
+    key::CARDINAL_SAMPLES_V1 => PluralSamplesV1Marker,
+    key::ORDINAL_SAMPLES_V1 => PluralSamplesV1Marker,
+    _ => PluralRulesV2Marker,
 }, SERDE_SE, 'data);
 
 impl<'data> IterableDataProviderCore for PluralsProvider<'data> {
@@ -108,11 +206,22 @@ impl<'data> IterableDataProviderCore for PluralsProvider<'data> {
         &self,
         resc_key: &ResourceKey,
     ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
-        let cldr_rules = self.get_rules_for(resc_key)?;
-        let list: Vec<ResourceOptions> = cldr_rules
-            .0
-            .iter()
-            .map(|(l, _)| ResourceOptions {
+        let langids: Vec<&CldrLangID> = if *resc_key == key::RANGES_V1 {
+            self.get_ranges(resc_key)?
+                .0
+                .iter()
+                .map(|(l, _)| l)
+                .collect()
+        } else {
+            self.get_rules_for(resc_key)?
+                .0
+                .iter()
+                .map(|(l, _)| l)
+                .collect()
+        };
+        let list: Vec<ResourceOptions> = langids
+            .into_iter()
+            .map(|l| ResourceOptions {
                 variant: None,
                 // TODO: Avoid the clone
                 langid: Some(l.langid.clone()),
@@ -144,6 +253,76 @@ impl From<&cldr_json::LocalePluralRules> for PluralRuleStringsV1<'static> {
     }
 }
 
+impl From<&cldr_json::LocalePluralRules> for PluralRulesV2<'static> {
+    fn from(other: &cldr_json::LocalePluralRules) -> Self {
+        /// Parses a plural rule string and converts its condition to the binary form.
+        #[allow(clippy::ptr_arg)]
+        fn convert(s: &String) -> PluralRuleV2<'static> {
+            let ast = parse(s.as_bytes()).expect("Rule parsing failed.");
+            PluralRuleV2::try_from(&ast.condition).expect("Rule conversion failed.")
+        }
+        Self {
+            zero: other.zero.as_ref().map(convert),
+            one: other.one.as_ref().map(convert),
+            two: other.two.as_ref().map(convert),
+            few: other.few.as_ref().map(convert),
+            many: other.many.as_ref().map(convert),
+        }
+    }
+}
+
+impl From<&cldr_json::LocalePluralRules> for PluralSamplesV1<'static> {
+    fn from(other: &cldr_json::LocalePluralRules) -> Self {
+        let rules = [
+            (PluralCategory::Zero, &other.zero),
+            (PluralCategory::One, &other.one),
+            (PluralCategory::Two, &other.two),
+            (PluralCategory::Few, &other.few),
+            (PluralCategory::Many, &other.many),
+            (PluralCategory::Other, &other.other),
+        ];
+        let asts: Vec<(PluralCategory, Rule)> = rules
+            .iter()
+            .filter_map(|(category, rule)| {
+                let rule = rule.as_ref()?;
+                Some((
+                    *category,
+                    parse(rule.as_bytes()).expect("Rule parsing failed."),
+                ))
+            })
+            .collect();
+        Self::try_from_samples(
+            asts.iter()
+                .filter_map(|(category, ast)| Some((*category, ast.samples.as_ref()?))),
+        )
+        .expect("Sample conversion failed.")
+    }
+}
+
+impl From<&cldr_json::LocalePluralRanges> for PluralRangesV1<'static> {
+    fn from(other: &cldr_json::LocalePluralRanges) -> Self {
+        /// Parses a CLDR plural category.
+        fn category(s: &str) -> PluralCategory {
+            match s {
+                "zero" => PluralCategory::Zero,
+                "one" => PluralCategory::One,
+                "two" => PluralCategory::Two,
+                "few" => PluralCategory::Few,
+                "many" => PluralCategory::Many,
+                "other" => PluralCategory::Other,
+                _ => panic!("Unknown plural category: {}", s),
+            }
+        }
+        Self::from_ranges(other.0.iter().map(|(range, result)| {
+            let (start, end) = range
+                .strip_prefix("start-")
+                .and_then(|range| range.split_once("+end-"))
+                .expect("Range parsing failed.");
+            (category(start), category(end), category(result))
+        }))
+    }
+}
+
 /// Serde structs for the CLDR JSON plurals files.
 pub(self) mod cldr_json {
     use crate::cldr_langid::CldrLangID;
@@ -164,6 +343,8 @@ pub(self) mod cldr_json {
         pub few: Option<String>,
         #[serde(rename = "pluralRule-count-many")]
         pub many: Option<String>,
+        #[serde(rename = "pluralRule-count-other")]
+        pub other: Option<String>,
     }
 
     #[derive(PartialEq, Debug, Deserialize)]
@@ -171,12 +352,25 @@ pub(self) mod cldr_json {
         #[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, LocalePluralRules)>,
     );
 
+    /// The plural ranges of a locale, as pairs of "start-{category}+end-{category}" and the
+    /// category of the range.
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LocalePluralRanges(
+        #[serde(with = "tuple_vec_map")] pub(crate) Vec<(String, String)>,
+    );
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Ranges(
+        #[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, LocalePluralRanges)>,
+    );
+
     #[derive(PartialEq, Debug, Deserialize)]
     pub struct Supplemental {
         #[serde(rename = "plurals-type-cardinal")]
         pub plurals_type_cardinal: Option<Rules>,
         #[serde(rename = "plurals-type-ordinal")]
         pub plurals_type_ordinal: Option<Rules>,
+        pub plurals: Option<Ranges>,
     }
 
     #[derive(PartialEq, Debug, Deserialize)]
@@ -223,3 +417,129 @@ fn test_basic() {
         cs_rules.get().many.as_ref().map(|v| v.borrow())
     );
 }
+
+#[test]
+fn test_basic_v2() {
+    use icu_locid_macros::langid;
+    use icu_plurals::rules::ast::Condition;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = PluralsProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let cs_rules: DataPayload<PluralRulesV2Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::CARDINAL_V2,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("cs")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    // The rules have the same conditions as the source strings, without their samples.
+    let to_string = |rule: &Option<PluralRuleV2>| {
+        rule.as_ref().map(|rule| {
+            let rule = Rule {
+                condition: Condition::from(rule),
+                samples: None,
+            };
+            let mut result = String::new();
+            serialize(&rule, &mut result).unwrap();
+            result
+        })
+    };
+    assert_eq!(None, to_string(&cs_rules.get().zero));
+    assert_eq!(
+        Some("i = 1 and v = 0".to_string()),
+        to_string(&cs_rules.get().one)
+    );
+    assert_eq!(None, to_string(&cs_rules.get().two));
+    assert_eq!(
+        Some("i = 2..4 and v = 0".to_string()),
+        to_string(&cs_rules.get().few)
+    );
+    assert_eq!(Some("v != 0".to_string()), to_string(&cs_rules.get().many));
+}
+
+#[test]
+fn test_ranges() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = PluralsProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let ranges: DataPayload<PluralRangesV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::RANGES_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("ar")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    use PluralCategory::*;
+    assert_eq!(ranges.get().get(Zero, One), Zero);
+    assert_eq!(ranges.get().get(One, Two), Other);
+    assert_eq!(ranges.get().get(Few, Many), Many);
+    assert_eq!(ranges.get().get(Other, One), Other);
+    // Only the ranges with another category than their end are stored.
+    assert_eq!(ranges.get().ranges.len(), 5);
+}
+
+#[test]
+fn test_samples() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = PluralsProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let cs_samples: DataPayload<PluralSamplesV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::CARDINAL_SAMPLES_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("cs")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let samples = |category| -> Vec<String> {
+        cs_samples
+            .get()
+            .get(category)
+            .map(|range| range.to_string())
+            .collect()
+    };
+    assert!(samples(PluralCategory::Zero).is_empty());
+    assert_eq!(samples(PluralCategory::One), ["1"]);
+    assert_eq!(samples(PluralCategory::Few), ["2~4"]);
+    assert_eq!(
+        samples(PluralCategory::Many),
+        [
+            "0.0~1.5",
+            "10.0",
+            "100.0",
+            "1000.0",
+            "10000.0",
+            "100000.0",
+            "1000000.0"
+        ]
+    );
+    assert_eq!(
+        samples(PluralCategory::Other),
+        ["0", "5~19", "100", "1000", "10000", "100000", "1000000"]
+    );
+}
@@ -8,8 +8,10 @@ use icu_datetime::provider::time_zones::{
     MetaZoneSpecificNamesLongV1, MetaZoneSpecificNamesShortV1, MetaZoneSpecificNamesV1,
     TimeZoneFormatsV1,
 };
+use icu_pattern::TypedPattern;
 use litemap::LiteMap;
 use std::borrow::Cow;
+use std::convert::TryFrom;
 use tinystr::TinyStr8;
 
 fn parse_hour_format<'data>(hour_format: &str) -> (Cow<'data, str>, Cow<'data, str>) {
@@ -24,9 +26,11 @@ impl<'data> From<TimeZoneNames> for TimeZoneFormatsV1<'data> {
     fn from(other: TimeZoneNames) -> Self {
         Self {
             hour_format: parse_hour_format(&other.hour_format),
-            gmt_format: other.gmt_format.into(),
+            gmt_format: TypedPattern::try_from(other.gmt_format.as_str())
+                .expect("Invalid GMT format pattern"),
             gmt_zero_format: other.gmt_zero_format.into(),
-            region_format: other.region_format.into(),
+            region_format: TypedPattern::try_from(other.region_format.as_str())
+                .expect("Invalid region format pattern"),
             region_format_variants: other
                 .region_format_variants
                 .into_iter()
@@ -36,11 +40,13 @@ impl<'data> From<TimeZoneNames> for TimeZoneFormatsV1<'data> {
                             key.parse::<TinyStr8>()
                                 .expect("Time-zone variant was not compatible with TinyStr8"),
                         ),
-                        value.into(),
+                        TypedPattern::try_from(value.as_str())
+                            .expect("Invalid region format variant pattern"),
                     )
                 })
                 .collect(),
-            fallback_format: other.fallback_format.into(),
+            fallback_format: TypedPattern::try_from(other.fallback_format.as_str())
+                .expect("Invalid fallback format pattern"),
         }
     }
 }
@@ -0,0 +1,347 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::reader::open_reader;
+use crate::CldrPaths;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use icu_units::provider::*;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 2] = [key::UNITS_CONVERSION_V1, key::UNITS_PREFERENCES_V1];
+
+/// A data provider reading from the CLDR JSON unit conversion and preference files.
+#[derive(PartialEq, Debug)]
+pub struct UnitsConversionProvider<'data> {
+    units: cldr_json::units::Resource,
+    preferences: cldr_json::preferences::Resource,
+    phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for UnitsConversionProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let supplemental = cldr_paths.cldr_core()?.join("supplemental");
+        let units = {
+            let path = supplemental.join("units.json");
+            serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?
+        };
+        let preferences = {
+            let path = supplemental.join("unitPreferenceData.json");
+            serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?
+        };
+        Ok(Self {
+            units,
+            preferences,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for UnitsConversionProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        if !ALL_KEYS.contains(resc_key) {
+            return Err(resc_key.into());
+        }
+        Ok(())
+    }
+}
+
+impl<'data> DataProvider<'data, UnitsConversionV1Marker> for UnitsConversionProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnitsConversionV1Marker>, DataError> {
+        key::UNITS_CONVERSION_V1.match_key(req.resource_path.key)?;
+        // The conversions are not localized, so only und is supported.
+        if req.resource_path.options.langid.is_some() {
+            return Err(DataError::MissingResourceOptions(req.clone()));
+        }
+        let data = &self.units.supplemental;
+        let conversions = data
+            .convert_units
+            .iter()
+            .map(|(unit, conversion)| {
+                let evaluate = |expression: &Option<String>, default| match expression {
+                    Some(expression) => evaluate(expression, &data.unit_constants),
+                    None => Ok(default),
+                };
+                Ok((
+                    Cow::Owned(unit.clone()),
+                    ConversionInfoV1 {
+                        base_unit: Cow::Owned(conversion.base_unit.clone()),
+                        factor: evaluate(&conversion.factor, 1.0)?,
+                        offset: evaluate(&conversion.offset, 0.0)?,
+                    },
+                ))
+            })
+            .collect::<Result<_, Error>>()
+            .map_err(DataError::new_resc_error)?;
+        let quantities = data
+            .unit_quantities
+            .iter()
+            .map(|(unit, quantity)| {
+                (
+                    Cow::Owned(unit.clone()),
+                    Cow::Owned(quantity.quantity.clone()),
+                )
+            })
+            .collect();
+        Ok(DataResponse {
+            metadata: DataResponseMetadata { data_langid: None },
+            payload: Some(DataPayload::from_owned(UnitsConversionV1 {
+                conversions,
+                quantities,
+            })),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, UnitPreferencesV1Marker> for UnitsConversionProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnitPreferencesV1Marker>, DataError> {
+        key::UNITS_PREFERENCES_V1.match_key(req.resource_path.key)?;
+        // The preferences are keyed by regions, so only und is supported.
+        if req.resource_path.options.langid.is_some() {
+            return Err(DataError::MissingResourceOptions(req.clone()));
+        }
+        let mut preferences = vec![];
+        for (quantity, usages) in self.preferences.supplemental.unit_preference_data.iter() {
+            for (usage, regions) in usages.iter() {
+                let mut usage_preferences = UsagePreferencesV1 {
+                    quantity: Cow::Owned(quantity.clone()),
+                    usage: Cow::Owned(usage.clone()),
+                    regions: Default::default(),
+                };
+                for (region_list, region_preferences) in regions.iter() {
+                    let region_preferences = region_preferences
+                        .iter()
+                        .map(|preference| {
+                            let geq = match &preference.geq {
+                                Some(geq) => geq.parse().map_err(|_| {
+                                    Error::Custom(format!("Invalid geq: {}", geq), None)
+                                })?,
+                                None => 1.0,
+                            };
+                            Ok(UnitPreferenceV1 {
+                                unit: Cow::Owned(preference.unit.clone()),
+                                geq,
+                            })
+                        })
+                        .collect::<Result<Vec<_>, Error>>()
+                        .map_err(DataError::new_resc_error)?;
+                    // Regions with the same preferences may share an entry, like "GB IE".
+                    for region in region_list.split_whitespace() {
+                        usage_preferences
+                            .regions
+                            .insert(Cow::Owned(region.to_string()), region_preferences.clone());
+                    }
+                }
+                preferences.push(usage_preferences);
+            }
+        }
+        Ok(DataResponse {
+            metadata: DataResponseMetadata { data_langid: None },
+            payload: Some(DataPayload::from_owned(UnitPreferencesV1 { preferences })),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(UnitsConversionProvider<'data>, {
+    key::UNITS_CONVERSION_V1 => UnitsConversionV1Marker,
+    key::UNITS_PREFERENCES_V1 => UnitPreferencesV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for UnitsConversionProvider<'data> {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Evaluates the expression of a conversion factor or offset, like "ft_to_m*5280" or
+/// "1/2*PI", which is a product of terms optionally divided by another product of terms, and
+/// whose terms are numbers or the names of unit constants.
+fn evaluate(
+    expression: &str,
+    constants: &BTreeMap<String, cldr_json::units::UnitConstant>,
+) -> Result<f64, Error> {
+    let product = |terms: &str| -> Result<f64, Error> {
+        let mut value = 1.0;
+        for term in terms.split('*').map(str::trim) {
+            value *= match term.parse::<f64>() {
+                Ok(term) => term,
+                Err(_) => match constants.get(term) {
+                    Some(constant) => evaluate(&constant.value, constants)?,
+                    None => {
+                        return Err(Error::Custom(
+                            format!("Invalid unit conversion: {}", expression),
+                            None,
+                        ))
+                    }
+                },
+            };
+        }
+        Ok(value)
+    };
+    match expression.split_once('/') {
+        Some((numerator, denominator)) => Ok(product(numerator)? / product(denominator)?),
+        None => product(expression),
+    }
+}
+
+pub(self) mod cldr_json {
+    //! Serde structs representing the CLDR JSON units.json and unitPreferenceData.json files.
+    //!
+    //! Sample files:
+    //! https://github.com/unicode-org/cldr-json/blob/master/cldr-json/cldr-core/supplemental/units.json
+    //! https://github.com/unicode-org/cldr-json/blob/master/cldr-json/cldr-core/supplemental/unitPreferenceData.json
+
+    pub mod units {
+        use serde::Deserialize;
+        use std::collections::BTreeMap;
+
+        #[derive(PartialEq, Debug, Deserialize)]
+        pub struct UnitConstant {
+            #[serde(rename = "_value")]
+            pub value: String,
+        }
+
+        #[derive(PartialEq, Debug, Deserialize)]
+        pub struct UnitQuantity {
+            #[serde(rename = "_quantity")]
+            pub quantity: String,
+        }
+
+        #[derive(PartialEq, Debug, Deserialize)]
+        pub struct ConvertUnit {
+            #[serde(rename = "_baseUnit")]
+            pub base_unit: String,
+            #[serde(rename = "_factor")]
+            pub factor: Option<String>,
+            #[serde(rename = "_offset")]
+            pub offset: Option<String>,
+        }
+
+        #[derive(PartialEq, Debug, Deserialize)]
+        pub struct Supplemental {
+            #[serde(rename = "unitConstants")]
+            pub unit_constants: BTreeMap<String, UnitConstant>,
+            #[serde(rename = "unitQuantities")]
+            pub unit_quantities: BTreeMap<String, UnitQuantity>,
+            #[serde(rename = "convertUnits")]
+            pub convert_units: BTreeMap<String, ConvertUnit>,
+        }
+
+        #[derive(PartialEq, Debug, Deserialize)]
+        pub struct Resource {
+            pub supplemental: Supplemental,
+        }
+    }
+
+    pub mod preferences {
+        use serde::Deserialize;
+        use std::collections::BTreeMap;
+
+        #[derive(PartialEq, Debug, Deserialize)]
+        pub struct UnitPreference {
+            pub unit: String,
+            pub geq: Option<String>,
+        }
+
+        /// The preferences of each quantity, like "length", for each usage, like "road", in
+        /// each list of regions, like "GB".
+        pub type UnitPreferenceData =
+            BTreeMap<String, BTreeMap<String, BTreeMap<String, Vec<UnitPreference>>>>;
+
+        #[derive(PartialEq, Debug, Deserialize)]
+        pub struct Supplemental {
+            #[serde(rename = "unitPreferenceData")]
+            pub unit_preference_data: UnitPreferenceData,
+        }
+
+        #[derive(PartialEq, Debug, Deserialize)]
+        pub struct Resource {
+            pub supplemental: Supplemental,
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)] // The expected values are computed with the same operations.
+fn test_evaluate() {
+    let constants: BTreeMap<String, cldr_json::units::UnitConstant> = serde_json::from_str(
+        r#"{
+            "ft_to_m": { "_value": "0.3048" },
+            "ft2_to_m2": { "_value": "ft_to_m*ft_to_m" }
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(evaluate("2298.35/9", &constants).unwrap(), 2298.35 / 9.0);
+    assert_eq!(evaluate("ft_to_m * 3", &constants).unwrap(), 0.3048 * 3.0);
+    assert_eq!(
+        evaluate("ft2_to_m2*43560", &constants).unwrap(),
+        0.3048 * 0.3048 * 43560.0
+    );
+    assert_eq!(
+        evaluate("1.602176634E-19", &constants).unwrap(),
+        1.602176634E-19
+    );
+    assert_eq!(
+        evaluate("1/2*ft_to_m", &constants).unwrap(),
+        1.0 / (2.0 * 0.3048)
+    );
+    assert!(evaluate("in_to_m", &constants).is_err());
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = UnitsConversionProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let request = |key| DataRequest {
+        resource_path: ResourcePath {
+            key,
+            options: ResourceOptions::default(),
+        },
+    };
+
+    let conversion: DataPayload<UnitsConversionV1Marker> = provider
+        .load_payload(&request(key::UNITS_CONVERSION_V1))
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let mile = conversion.get().conversions.get("mile").unwrap();
+    assert_eq!(mile.base_unit, "meter");
+    assert!((mile.factor - 1609.344).abs() < 1e-9);
+    assert_eq!(conversion.get().quantities.get("meter").unwrap(), "length");
+
+    let preferences: DataPayload<UnitPreferencesV1Marker> = provider
+        .load_payload(&request(key::UNITS_PREFERENCES_V1))
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let road = preferences.get().get("length", "road").unwrap();
+    assert_eq!(road.regions.get("GB").unwrap()[0].unit, "mile");
+    assert!((road.regions.get("GB").unwrap()[0].geq - 0.5).abs() < f64::EPSILON);
+
+    let mut en_request = request(key::UNITS_CONVERSION_V1);
+    en_request.resource_path.options.langid = Some(langid!("en"));
+    assert!(matches!(
+        DataProvider::<UnitsConversionV1Marker>::load_payload(&provider, &en_request),
+        Err(DataError::MissingResourceOptions(_))
+    ));
+}
@@ -0,0 +1,277 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::reader::{get_subdirectories, open_reader};
+use crate::CldrPaths;
+use icu_locid::LanguageIdentifier;
+use icu_pattern::{PlaceholderSchema, TypedPattern};
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use icu_units::provider::*;
+use icu_units::UnitPrefix;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 3] = [
+    key::UNITS_LONG_V1,
+    key::UNITS_SHORT_V1,
+    key::UNITS_NARROW_V1,
+];
+
+/// A data provider reading from CLDR JSON unit pattern files.
+#[derive(PartialEq, Debug)]
+pub struct UnitPatternsProvider<'data> {
+    data: Vec<(CldrLangID, cldr_json::LangUnits)>,
+    phantom: PhantomData<&'data ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for UnitPatternsProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let mut data = vec![];
+
+        let path = cldr_paths.cldr_units()?.join("main");
+
+        let locale_dirs = get_subdirectories(&path)?;
+
+        for dir in locale_dirs {
+            let path = dir.join("units.json");
+
+            let mut resource: cldr_json::Resource =
+                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+            data.append(&mut resource.main.0);
+        }
+
+        Ok(Self {
+            data,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<'data> KeyedDataProvider for UnitPatternsProvider<'data> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        if !ALL_KEYS.contains(resc_key) {
+            return Err(resc_key.into());
+        }
+        Ok(())
+    }
+}
+
+impl<'data> DataProvider<'data, UnitPatternsV1Marker> for UnitPatternsProvider<'data> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnitPatternsV1Marker>, DataError> {
+        Self::supports_key(&req.resource_path.key)?;
+        let langid = req.try_langid()?;
+        let cldr_langid: CldrLangID = langid.clone().into();
+        let units = match self
+            .data
+            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+        {
+            Ok(idx) => &self.data[idx].1.units,
+            Err(_) => return Err(DataError::MissingResourceOptions(req.clone())),
+        };
+
+        let units = match req.resource_path.key {
+            key::UNITS_LONG_V1 => &units.long,
+            key::UNITS_SHORT_V1 => &units.short,
+            key::UNITS_NARROW_V1 => &units.narrow,
+            _ => return Err(DataError::MissingResourceKey(req.resource_path.key)),
+        };
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(
+                try_into_patterns(units, langid).map_err(DataError::new_resc_error)?,
+            )),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(UnitPatternsProvider<'data>, {
+    _ => UnitPatternsV1Marker,
+}, SERDE_SE, 'data);
+
+impl<'data> IterableDataProviderCore for UnitPatternsProvider<'data> {
+    #[allow(clippy::needless_collect)] // https://github.com/rust-lang/rust-clippy/issues/7526
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = self
+            .data
+            .iter()
+            .map(|(l, _)| ResourceOptions {
+                variant: None,
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Converts the units of one width, keyed like "length-kilometer", "per" or "10p3", into the
+/// patterns of the units keyed by their identifiers without category, like "kilometer".
+fn try_into_patterns(
+    units: &cldr_json::Units,
+    langid: &LanguageIdentifier,
+) -> Result<UnitPatternsV1<'static>, Error> {
+    let mut patterns = UnitPatternsV1 {
+        per: get_pattern(units, "per", "compoundUnitPattern", langid)?,
+        times: get_pattern(units, "times", "compoundUnitPattern", langid)?,
+        power2: get_pattern(units, "power2", "compoundUnitPattern1", langid)?,
+        power3: get_pattern(units, "power3", "compoundUnitPattern1", langid)?,
+        ..Default::default()
+    };
+    for (key, entries) in units.iter() {
+        if UnitPrefix::from_cldr_id(key).is_some() {
+            let pattern = get_pattern(units, key, "unitPrefixPattern", langid)?;
+            patterns.prefixes.insert(Cow::Owned(key.clone()), pattern);
+            continue;
+        }
+        // The keys of the units are prefixed with their categories, like "length-kilometer".
+        let unit = match (key.split_once('-'), entries.get("unitPattern-count-other")) {
+            (Some((_, unit)), Some(_)) => unit,
+            _ => continue,
+        };
+        let plural_pattern = |category: &str| {
+            entries
+                .get(&format!("unitPattern-count-{}", category))
+                .map(|pattern| parse(pattern, langid))
+                .transpose()
+        };
+        let plural_patterns = PluralPatternsV1 {
+            zero: plural_pattern("zero")?,
+            one: plural_pattern("one")?,
+            two: plural_pattern("two")?,
+            few: plural_pattern("few")?,
+            many: plural_pattern("many")?,
+            other: get_pattern(units, key, "unitPattern-count-other", langid)?,
+        };
+        patterns
+            .units
+            .insert(Cow::Owned(unit.to_string()), plural_patterns);
+        if let Some(per_unit_pattern) = entries.get("perUnitPattern") {
+            patterns.per_unit.insert(
+                Cow::Owned(unit.to_string()),
+                parse(per_unit_pattern, langid)?,
+            );
+        }
+    }
+    Ok(patterns)
+}
+
+fn parse<S: PlaceholderSchema>(
+    pattern: &str,
+    langid: &LanguageIdentifier,
+) -> Result<TypedPattern<'static, S>, Error> {
+    TypedPattern::try_from(pattern)
+        .map_err(|e| Error::Custom(format!("{}: {:?}", e, pattern), Some(langid.clone())))
+}
+
+fn get_pattern<S: PlaceholderSchema>(
+    units: &cldr_json::Units,
+    unit: &str,
+    name: &str,
+    langid: &LanguageIdentifier,
+) -> Result<TypedPattern<'static, S>, Error> {
+    match units.get(unit).and_then(|entries| entries.get(name)) {
+        Some(pattern) => parse(pattern, langid),
+        None => Err(Error::Custom(
+            format!("Missing pattern: {}/{}", unit, name),
+            Some(langid.clone()),
+        )),
+    }
+}
+
+pub(self) mod cldr_json {
+    //! Serde structs representing CLDR JSON units.json files.
+    //!
+    //! Sample file:
+    //! https://github.com/unicode-org/cldr-json/blob/master/cldr-json/cldr-units-full/main/en/units.json
+
+    use super::*;
+    use serde::Deserialize;
+
+    /// The entries of the units of one width, like "length-kilometer", by their keys, like
+    /// "unitPattern-count-one".
+    pub type Units = BTreeMap<String, BTreeMap<String, String>>;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct UnitsByWidth {
+        pub long: Units,
+        pub short: Units,
+        pub narrow: Units,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangUnits {
+        pub units: UnitsByWidth,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangData(#[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, LangUnits)>);
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub main: LangData,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid_macros::langid;
+    use icu_plurals::PluralCategory;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = UnitPatternsProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let load = |key, langid| -> DataPayload<UnitPatternsV1Marker> {
+        provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(langid),
+                    },
+                },
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    let en_long = load(key::UNITS_LONG_V1, langid!("en"));
+    let kilometer = en_long.get().units.get("kilometer").unwrap();
+    assert_eq!(
+        kilometer.get(PluralCategory::One).to_string(),
+        "{0} kilometer"
+    );
+    assert_eq!(
+        kilometer.get(PluralCategory::Few).to_string(),
+        "{0} kilometers"
+    );
+    assert_eq!(
+        en_long.get().per_unit.get("hour").unwrap().to_string(),
+        "{0} per hour"
+    );
+    assert_eq!(
+        en_long.get().prefixes.get("2p10").unwrap().to_string(),
+        "kibi{0}"
+    );
+    assert!(en_long.get().units.get("kilometer-per-hour").is_some());
+
+    let ru_short = load(key::UNITS_SHORT_V1, langid!("ru"));
+    assert_eq!(ru_short.get().per.to_string(), "{0}/{1}");
+}
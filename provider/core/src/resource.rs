@@ -21,13 +21,22 @@ use tinystr::{TinyStr16, TinyStr4};
 #[non_exhaustive]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug)]
 pub enum ResourceCategory {
+    Bidi,
+    CaseMapping,
+    Collator,
     Core,
     DateTime,
     Decimal,
+    DisplayNames,
+    List,
     LocaleCanonicalizer,
+    Normalizer,
     Plurals,
+    Security,
+    Segmenter,
     TimeZone,
     UnicodeSet,
+    Units,
     PrivateUse(TinyStr4),
 }
 
@@ -35,13 +44,22 @@ impl ResourceCategory {
     /// Gets or builds a string form of this [`ResourceCategory`].
     pub fn as_str(&self) -> Cow<'static, str> {
         match self {
+            Self::Bidi => Cow::Borrowed("bidi"),
+            Self::CaseMapping => Cow::Borrowed("case_mapping"),
+            Self::Collator => Cow::Borrowed("collator"),
             Self::Core => Cow::Borrowed("core"),
             Self::DateTime => Cow::Borrowed("datetime"),
             Self::Decimal => Cow::Borrowed("decimal"),
+            Self::DisplayNames => Cow::Borrowed("display_names"),
+            Self::List => Cow::Borrowed("list"),
             Self::LocaleCanonicalizer => Cow::Borrowed("locale_canonicalizer"),
+            Self::Normalizer => Cow::Borrowed("normalizer"),
             Self::Plurals => Cow::Borrowed("plurals"),
+            Self::Security => Cow::Borrowed("security"),
+            Self::Segmenter => Cow::Borrowed("segmenter"),
             Self::TimeZone => Cow::Borrowed("time_zone"),
             Self::UnicodeSet => Cow::Borrowed("uniset"),
+            Self::Units => Cow::Borrowed("units"),
             Self::PrivateUse(id) => {
                 let mut result = String::from("x-");
                 result.push_str(id.as_str());
@@ -37,6 +37,11 @@
 //! - `cargo make testdata-build-blob` re-generates the ICU4X blob file
 //! - `cargo make bincode-gen-testdata` generates Bincode filesystem testdata
 //!
+//! The Unicode properties in `data/uprops`, other than the sets of the binary properties, are
+//! exported from ICU4C by `provider/uprops/tools/upropdump.c`, whose header has the commands to
+//! build and run it, and the collation data in `data/uprops/collation` by
+//! `provider/uprops/tools/colldump.c` and `collation.py`.
+//!
 //! # Examples
 //!
 //! ```
@@ -0,0 +1,137 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::binary::BinaryPropertiesDataProvider;
+use crate::error::Error;
+use crate::upropdump_serde;
+use icu_bidi::provider::*;
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use icu_uniset::provider::*;
+use std::fs;
+use std::path::PathBuf;
+use zerovec::ZeroVec;
+
+/// A data provider for [`icu_bidi`], reading from .toml files produced by the ICU4C upropdump
+/// tool.
+///
+/// The Bidi_Class and Bidi_Paired_Bracket_Type sets are read like those of the
+/// [`BinaryPropertiesDataProvider`], from `bc=<value>.toml` and `bpt=<value>.toml`. The
+/// Bidi_Mirroring_Glyph and Bidi_Paired_Bracket mappings are read from `bmg.toml` and
+/// `bpb.toml`.
+pub struct BidiDataProvider {
+    root_dir: PathBuf,
+    properties: BinaryPropertiesDataProvider,
+}
+
+impl BidiDataProvider {
+    pub fn new(root_dir: PathBuf) -> Self {
+        BidiDataProvider {
+            properties: BinaryPropertiesDataProvider::new(root_dir.clone()),
+            root_dir,
+        }
+    }
+
+    fn get_mappings(
+        &self,
+        name: &str,
+    ) -> Result<(ZeroVec<'static, char>, ZeroVec<'static, char>), Error> {
+        let mut path: PathBuf = self.root_dir.clone().join(name);
+        path.set_extension("toml");
+        let toml_str = fs::read_to_string(&path).map_err(|e| Error::Io(e, path.clone()))?;
+        let toml_data: upropdump_serde::code_point_map::Main =
+            toml::from_str(&toml_str).map_err(|e| Error::Toml(e, path))?;
+
+        let to_char = |c| std::char::from_u32(c).ok_or(Error::InvalidCodePoint(c));
+        let mut mappings = toml_data
+            .code_point_map
+            .data
+            .mappings
+            .into_iter()
+            .map(|(from, to)| Ok((to_char(from)?, to_char(to)?)))
+            .collect::<Result<Vec<(char, char)>, Error>>()?;
+        mappings.sort_unstable();
+        let (keys, values): (Vec<char>, Vec<char>) = mappings.into_iter().unzip();
+        Ok((ZeroVec::from_aligned(&keys), ZeroVec::from_aligned(&values)))
+    }
+}
+
+impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for BidiDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyV1Marker>, DataError> {
+        self.properties.load_payload(req)
+    }
+}
+
+impl<'data> DataProvider<'data, BidiMirroringV1Marker> for BidiDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, BidiMirroringV1Marker>, DataError> {
+        req.resource_path
+            .key
+            .match_key(icu_bidi::provider::key::MIRRORING_V1)?;
+        let (mirrored, mirroring_glyphs) = self
+            .get_mappings("bmg")
+            .map_err(DataError::new_resc_error)?;
+        let (brackets, paired_brackets) = self
+            .get_mappings("bpb")
+            .map_err(DataError::new_resc_error)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(BidiMirroringV1 {
+                mirrored,
+                mirroring_glyphs,
+                brackets,
+                paired_brackets,
+            })),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(BidiDataProvider, {
+    icu_bidi::provider::key::MIRRORING_V1 => BidiMirroringV1Marker,
+    _ => UnicodePropertyV1Marker,
+}, SERDE_SE, 'data);
+
+impl IterableDataProviderCore for BidiDataProvider {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[test]
+fn test_mirroring() {
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = BidiDataProvider::new(root_dir);
+
+    let payload: DataPayload<'_, BidiMirroringV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: icu_bidi::provider::key::MIRRORING_V1,
+                options: ResourceOptions::default(),
+            },
+        })
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful");
+    let mirroring = payload.get();
+
+    assert_eq!(mirroring.mirroring_glyph('('), Some(')'));
+    assert_eq!(mirroring.mirroring_glyph('≤'), Some('≥'));
+    assert_eq!(mirroring.mirroring_glyph('a'), None);
+
+    assert_eq!(mirroring.paired_bracket('['), Some(']'));
+    assert_eq!(mirroring.paired_bracket('〉'), Some('〈'));
+    assert_eq!(mirroring.paired_bracket('<'), None);
+}
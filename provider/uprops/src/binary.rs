@@ -102,3 +102,24 @@ fn test_basic() {
 
     assert!(!whitespace.contains('A'));
 }
+
+#[test]
+fn test_identifier_status() {
+    use icu_uniset::UnicodeSet;
+    use std::convert::TryInto;
+
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = BinaryPropertiesDataProvider::new(root_dir);
+
+    let payload = icu_uniset::props::get_identifier_status_allowed_property(&provider)
+        .expect("The data should be valid");
+    let allowed: UnicodeSet = payload.get().clone().try_into().expect("Valid unicode set");
+
+    assert!(allowed.contains('a'));
+    assert!(allowed.contains('-'));
+    assert!(allowed.contains('\u{E9}'));
+    assert!(allowed.contains('\u{4E00}'));
+
+    assert!(!allowed.contains(' '));
+    assert!(!allowed.contains('\u{2665}')); // U+2665 BLACK HEART SUIT
+}
@@ -0,0 +1,217 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::upropdump_serde;
+use icu_casemapping::provider::*;
+use icu_codepointtrie::builder::CodePointTrieBuilder;
+use icu_codepointtrie::codepointtrie::Small;
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use std::fs;
+use std::path::PathBuf;
+use zerovec::VarZeroVec;
+
+/// A data provider for [`icu_casemapping`], reading from a .toml file produced from ICU4C.
+///
+/// The case properties of all code points and their full case mappings in the root locale
+/// are read from `casemap.toml`.
+pub struct CaseMappingDataProvider {
+    root_dir: PathBuf,
+}
+
+impl CaseMappingDataProvider {
+    pub fn new(root_dir: PathBuf) -> Self {
+        CaseMappingDataProvider { root_dir }
+    }
+
+    fn get_toml_data<T: serde::de::DeserializeOwned>(&self, name: &str) -> Result<T, Error> {
+        let mut path: PathBuf = self.root_dir.clone().join(name);
+        path.set_extension("toml");
+        let toml_str = fs::read_to_string(&path).map_err(|e| Error::Io(e, path.clone()))?;
+        toml::from_str(&toml_str).map_err(|e| Error::Toml(e, path))
+    }
+
+    fn get_case_mapping(&self) -> Result<CaseMappingV1<'static>, Error> {
+        let toml_data: upropdump_serde::case_mapping::Main = self.get_toml_data("casemap")?;
+        let data = toml_data.case_mapping.data;
+        let trie_error = |e| Error::CodePointTrie(e, self.root_dir.clone());
+
+        let mut builder = CodePointTrieBuilder::new(0, 0);
+        for (start, end, case_type, ignorable, dot_type, lns) in data.ranges {
+            let mut value =
+                (case_type & CASE_TYPE_MASK) | (dot_type & DOT_TYPE_MASK) << DOT_TYPE_SHIFT;
+            if ignorable != 0 {
+                value |= CASE_IGNORABLE;
+            }
+            if lns != 0 {
+                value |= LETTER_NUMBER_SYMBOL;
+            }
+            builder.set_range(start, end, value).map_err(trie_error)?;
+        }
+
+        let to_string = |mapping: &[u32]| {
+            mapping
+                .iter()
+                .map(|&c| std::char::from_u32(c).ok_or(Error::InvalidCodePoint(c)))
+                .collect::<Result<String, Error>>()
+        };
+        let mut exceptions = Vec::new();
+        for mapping in data.mappings {
+            let code_point = mapping.code_point;
+            let single = |m: &Option<Vec<u32>>| match m.as_deref() {
+                None => Some(code_point),
+                Some(&[c]) => Some(c),
+                Some(_) => None,
+            };
+            let (lower, upper, title, fold) = (
+                single(&mapping.lower),
+                single(&mapping.upper),
+                single(&mapping.title),
+                single(&mapping.fold),
+            );
+            let value = builder.get(code_point);
+            let delta = if value & CASE_TYPE_MASK <= CASE_TYPE_LOWER {
+                match (lower, fold, upper, title) {
+                    (Some(l), Some(f), Some(u), Some(t)) if l == code_point && f == l && t == u => {
+                        Some(u as i32 - code_point as i32)
+                    }
+                    _ => None,
+                }
+            } else {
+                match (upper, title, lower, fold) {
+                    (Some(u), Some(t), Some(l), Some(f)) if u == code_point && t == u && f == l => {
+                        Some(l as i32 - code_point as i32)
+                    }
+                    _ => None,
+                }
+            };
+            let value = match delta {
+                Some(delta) => value | (delta as u32) << DELTA_SHIFT,
+                None => {
+                    let index = exceptions.len() as u32 / 4;
+                    for m in &[
+                        &mapping.lower,
+                        &mapping.upper,
+                        &mapping.title,
+                        &mapping.fold,
+                    ] {
+                        exceptions.push(match m {
+                            Some(m) => to_string(m)?,
+                            None => String::new(),
+                        });
+                    }
+                    value | EXCEPTION | index << DELTA_SHIFT
+                }
+            };
+            builder.set(code_point, value).map_err(trie_error)?;
+        }
+
+        Ok(CaseMappingV1 {
+            code_point_trie: builder.build::<u32, Small>().map_err(trie_error)?,
+            exceptions: VarZeroVec::from(exceptions),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, CaseMappingV1Marker> for CaseMappingDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, CaseMappingV1Marker>, DataError> {
+        req.resource_path.key.match_key(key::CASE_MAPPING_V1)?;
+        let case_mapping = self.get_case_mapping().map_err(DataError::new_resc_error)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(case_mapping)),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(CaseMappingDataProvider, {
+    _ => CaseMappingV1Marker,
+}, SERDE_SE, 'data);
+
+impl IterableDataProviderCore for CaseMappingDataProvider {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[test]
+fn test_case_mapping() {
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = CaseMappingDataProvider::new(root_dir);
+
+    let payload: DataPayload<'_, CaseMappingV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::CASE_MAPPING_V1,
+                options: ResourceOptions::default(),
+            },
+        })
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful");
+    let case_mapping = payload.get();
+
+    assert_eq!(
+        case_mapping.mapping('A', MappingKind::Lower),
+        FullMapping::Char('a')
+    );
+    assert_eq!(
+        case_mapping.mapping('a', MappingKind::Title),
+        FullMapping::Char('A')
+    );
+    assert_eq!(
+        case_mapping.mapping('a', MappingKind::Lower),
+        FullMapping::Char('a')
+    );
+    assert_eq!(
+        case_mapping.mapping('\u{DF}', MappingKind::Upper),
+        FullMapping::String("SS")
+    );
+    assert_eq!(
+        case_mapping.mapping('\u{DF}', MappingKind::Title),
+        FullMapping::String("Ss")
+    );
+    assert_eq!(
+        case_mapping.mapping('\u{3A3}', MappingKind::Fold),
+        FullMapping::Char('\u{3C3}')
+    );
+    assert_eq!(
+        case_mapping.mapping('\u{3C2}', MappingKind::Fold),
+        FullMapping::Char('\u{3C3}')
+    );
+    assert_eq!(
+        case_mapping.mapping('\u{1C5}', MappingKind::Upper),
+        FullMapping::Char('\u{1C4}')
+    );
+    assert_eq!(
+        case_mapping.mapping('\u{130}', MappingKind::Lower),
+        FullMapping::String("i\u{307}")
+    );
+
+    assert_eq!(case_mapping.get('a') & CASE_TYPE_MASK, CASE_TYPE_LOWER);
+    assert_eq!(
+        case_mapping.get('\u{1C5}') & CASE_TYPE_MASK,
+        CASE_TYPE_TITLE
+    );
+    assert_ne!(case_mapping.get('\'') & CASE_IGNORABLE, 0);
+    assert_eq!(
+        case_mapping.get('i') >> DOT_TYPE_SHIFT & DOT_TYPE_MASK,
+        DOT_TYPE_SOFT_DOTTED
+    );
+    assert_eq!(
+        case_mapping.get('\u{301}') >> DOT_TYPE_SHIFT & DOT_TYPE_MASK,
+        DOT_TYPE_ABOVE
+    );
+}
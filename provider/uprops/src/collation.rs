@@ -0,0 +1,367 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::normalizer::NormalizerDataProvider;
+use crate::upropdump_serde;
+use icu_codepointtrie::builder::CodePointTrieBuilder;
+use icu_codepointtrie::codepointtrie::Small;
+use icu_collator::provider::*;
+use icu_locid::LanguageIdentifier;
+use icu_normalizer::provider::DecompositionsV1Marker;
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+use zerovec::{VarZeroVec, ZeroVec};
+
+/// A data provider for [`icu_collator`], reading from .toml files produced from ICU4C.
+///
+/// The collation elements of the CLDR root collation and its special primary weights are read
+/// from `collation/root.toml`, and those of the tailoring of a language from
+/// `collation/<language>.toml`. The decompositions for NFD are provided like those of the
+/// [`NormalizerDataProvider`].
+pub struct CollationDataProvider {
+    root_dir: PathBuf,
+    normalizer: NormalizerDataProvider,
+}
+
+impl CollationDataProvider {
+    pub fn new(root_dir: PathBuf) -> Self {
+        CollationDataProvider {
+            normalizer: NormalizerDataProvider::new(root_dir.clone()),
+            root_dir,
+        }
+    }
+
+    fn get_toml_data<T: serde::de::DeserializeOwned>(&self, name: &str) -> Result<T, Error> {
+        let mut path: PathBuf = self.root_dir.clone().join("collation").join(name);
+        path.set_extension("toml");
+        let toml_str = fs::read_to_string(&path).map_err(|e| Error::Io(e, path.clone()))?;
+        toml::from_str(&toml_str).map_err(|e| Error::Toml(e, path))
+    }
+
+    fn get_collation(&self, name: &str) -> Result<upropdump_serde::collation::Collation, Error> {
+        let toml_data: upropdump_serde::collation::Main = self.get_toml_data(name)?;
+        Ok(toml_data.collation.data)
+    }
+
+    /// Returns the name of the tailoring of the language of a request, if it has one.
+    fn get_tailoring_name(&self, req: &DataRequest) -> Option<String> {
+        let name = req
+            .resource_path
+            .options
+            .langid
+            .as_ref()?
+            .language
+            .to_string();
+        let path = self.root_dir.join("collation").join(&name);
+        if name != "root" && path.with_extension("toml").exists() {
+            Some(name)
+        } else {
+            None
+        }
+    }
+
+    fn get_data(&self, name: &str) -> Result<CollationDataV1<'static>, Error> {
+        let data = self.get_collation(name)?;
+        let trie_error = |e| Error::CodePointTrie(e, self.root_dir.clone());
+
+        let mut singles = BTreeMap::new();
+        for (start, end, ce) in data.ranges {
+            for code_point in start..=end {
+                singles.insert(code_point, vec![ce + (u64::from(code_point - start) << 32)]);
+            }
+        }
+        let mut contractions: BTreeMap<u32, Vec<(String, Vec<u64>)>> = BTreeMap::new();
+        for (code_points, mapping) in data.mappings {
+            match code_points.split_first() {
+                Some((&code_point, [])) => {
+                    singles.insert(code_point, mapping);
+                }
+                Some((&starter, suffix)) => {
+                    let suffix = suffix
+                        .iter()
+                        .map(|&c| std::char::from_u32(c).ok_or(Error::InvalidCodePoint(c)))
+                        .collect::<Result<String, Error>>()?;
+                    contractions
+                        .entry(starter)
+                        .or_default()
+                        .push((suffix, mapping));
+                }
+                None => {}
+            }
+        }
+        let digits: HashMap<u32, u32> = data.digits.into_iter().collect();
+
+        let mut ces = Vec::new();
+        let mut expansion = |mapping: &[u64]| {
+            let value = TAG_EXPANSION
+                | (mapping.len() as u32) << LENGTH_SHIFT
+                | (ces.len() as u32) << INDEX_SHIFT;
+            ces.extend_from_slice(mapping);
+            value
+        };
+        let mut builder = CodePointTrieBuilder::new(TAG_NONE, TAG_NONE);
+        for (&code_point, mapping) in &singles {
+            let mut value = expansion(mapping);
+            if let Some(&digit) = digits.get(&code_point) {
+                value |= DIGIT | digit << DIGIT_VALUE_SHIFT;
+            }
+            builder.set(code_point, value).map_err(trie_error)?;
+        }
+        let mut contraction_strings = Vec::new();
+        let mut contraction_values = Vec::new();
+        for (starter, mut entries) in contractions {
+            let value = TAG_CONTRACTION
+                | (entries.len() as u32 + 1) << LENGTH_SHIFT
+                | (contraction_strings.len() as u32) << INDEX_SHIFT;
+            contraction_strings.push(String::new());
+            contraction_values.push(builder.get(starter));
+            entries.sort();
+            for (suffix, mapping) in entries {
+                contraction_strings.push(suffix);
+                contraction_values.push(expansion(&mapping));
+            }
+            builder.set(starter, value).map_err(trie_error)?;
+        }
+        if let Some(mut primary) = data.han_primary {
+            for (start, end) in data.han_runs {
+                for code_point in start..=end {
+                    let value = TAG_IMPLICIT | primary << IMPLICIT_PRIMARY_SHIFT;
+                    builder.set(code_point, value).map_err(trie_error)?;
+                    primary += 1;
+                }
+            }
+        }
+
+        Ok(CollationDataV1 {
+            trie: builder.build::<u32, Small>().map_err(trie_error)?,
+            ces: ZeroVec::from_aligned(&ces).into_owned(),
+            contractions: VarZeroVec::from(contraction_strings),
+            contraction_values: ZeroVec::from_aligned(&contraction_values).into_owned(),
+        })
+    }
+
+    fn get_metadata(&self, name: Option<&str>) -> Result<CollationMetadataV1, Error> {
+        // The defaults of the root collation
+        let mut bits = 1 << MAX_VARIABLE_SHIFT;
+        if let Some(name) = name {
+            let data = self.get_collation(name)?;
+            bits = TAILORED;
+            bits |= match data.case_first.as_deref() {
+                Some("lower") => 1,
+                Some("upper") => 2,
+                _ => 0,
+            } << CASE_FIRST_SHIFT;
+            if data.alternate.as_deref() == Some("shifted") {
+                bits |= ALTERNATE_SHIFTED;
+            }
+            bits |= match data.max_variable.as_deref() {
+                Some("space") => 0,
+                Some("symbol") => 2,
+                Some("currency") => 3,
+                _ => 1,
+            } << MAX_VARIABLE_SHIFT;
+        }
+        Ok(CollationMetadataV1 { bits })
+    }
+
+    fn get_special_primaries(&self) -> Result<CollationSpecialPrimariesV1<'static>, Error> {
+        let data = self.get_collation("root")?;
+        Ok(CollationSpecialPrimariesV1 {
+            variable_tops: ZeroVec::from_aligned(&data.variable_tops).into_owned(),
+            numeric_primary: data.numeric_primary.unwrap_or_default(),
+            unassigned_primary: data.unassigned_primary.unwrap_or_default(),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, CollationDataV1Marker> for CollationDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, CollationDataV1Marker>, DataError> {
+        req.resource_path.key.match_key(key::COLLATION_DATA_V1)?;
+        let name = match (
+            &req.resource_path.options.langid,
+            self.get_tailoring_name(req),
+        ) {
+            (None, _) => "root".to_string(),
+            (Some(_), Some(name)) => name,
+            (Some(_), None) => return Err(DataError::MissingResourceOptions(req.clone())),
+        };
+        let data = self.get_data(&name).map_err(DataError::new_resc_error)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(data)),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, CollationMetadataV1Marker> for CollationDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, CollationMetadataV1Marker>, DataError> {
+        req.resource_path
+            .key
+            .match_key(key::COLLATION_METADATA_V1)?;
+        let metadata = self
+            .get_metadata(self.get_tailoring_name(req).as_deref())
+            .map_err(DataError::new_resc_error)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(metadata)),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, CollationSpecialPrimariesV1Marker> for CollationDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, CollationSpecialPrimariesV1Marker>, DataError> {
+        req.resource_path
+            .key
+            .match_key(key::COLLATION_SPECIAL_PRIMARIES_V1)?;
+        let special_primaries = self
+            .get_special_primaries()
+            .map_err(DataError::new_resc_error)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(special_primaries)),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, DecompositionsV1Marker> for CollationDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, DecompositionsV1Marker>, DataError> {
+        self.normalizer.load_payload(req)
+    }
+}
+
+icu_provider::impl_dyn_provider!(CollationDataProvider, {
+    key::COLLATION_METADATA_V1 => CollationMetadataV1Marker,
+    key::COLLATION_SPECIAL_PRIMARIES_V1 => CollationSpecialPrimariesV1Marker,
+    icu_normalizer::provider::key::DECOMPOSITIONS_V1 => DecompositionsV1Marker,
+    _ => CollationDataV1Marker,
+}, SERDE_SE, 'data);
+
+impl IterableDataProviderCore for CollationDataProvider {
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let mut list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        if *resc_key == key::COLLATION_DATA_V1 || *resc_key == key::COLLATION_METADATA_V1 {
+            let dir = self.root_dir.join("collation");
+            let entries = fs::read_dir(&dir)
+                .map_err(|e| DataError::new_resc_error(Error::Io(e, dir.clone())))?;
+            for entry in entries {
+                let path = entry
+                    .map_err(|e| DataError::new_resc_error(Error::Io(e, dir.clone())))?
+                    .path();
+                let langid = path
+                    .file_stem()
+                    .and_then(|name| name.to_str())
+                    .filter(|&name| name != "root")
+                    .and_then(|name| name.parse::<LanguageIdentifier>().ok());
+                if let Some(langid) = langid {
+                    list.push(ResourceOptions {
+                        variant: None,
+                        langid: Some(langid),
+                    });
+                }
+            }
+        }
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[test]
+fn test_collation_data() {
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = CollationDataProvider::new(root_dir);
+
+    let load = |langid: Option<LanguageIdentifier>| -> DataPayload<'_, CollationDataV1Marker> {
+        provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::COLLATION_DATA_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid,
+                    },
+                },
+            })
+            .expect("The data should be valid")
+            .take_payload()
+            .expect("Loading was successful")
+    };
+    let root = load(None);
+    let root = root.get();
+    let da = load(Some("da".parse().unwrap()));
+    let da = da.get();
+
+    // The collation elements of a code point alone, also if it starts contractions
+    let ces = |data: &CollationDataV1, c| {
+        let mut value = data.get(c);
+        if value & TAG_MASK == TAG_CONTRACTION {
+            value = data.contractions(value).next().expect("Starter alone").1;
+        }
+        data.expansion(value).collect::<Vec<u64>>()
+    };
+    // "a" has a single collation element, and "A" differs only in its tertiary weight.
+    let a = ces(root, 'a');
+    assert_eq!(a.len(), 1);
+    assert_eq!(ces(root, 'A')[0] >> 16, a[0] >> 16);
+    assert_ne!(ces(root, 'A')[0], a[0]);
+    assert!(ces(root, 'b')[0] >> 32 > a[0] >> 32);
+    // "æ" is an expansion in the root collation, and a letter after "z" in Danish.
+    assert_eq!(ces(root, '\u{E6}').len(), 2);
+    assert_eq!(ces(da, '\u{E6}').len(), 1);
+    assert!(ces(da, '\u{E6}')[0] >> 32 > ces(root, 'z')[0] >> 32);
+    assert_eq!(root.get('7') & DIGIT, DIGIT);
+    assert_eq!(root.get('7') >> DIGIT_VALUE_SHIFT & DIGIT_VALUE_MASK, 7);
+    assert_eq!(root.get('\u{4E00}') & TAG_MASK, TAG_IMPLICIT);
+    assert_eq!(root.get('\u{378}') & TAG_MASK, TAG_NONE);
+    assert_eq!(da.get('b') & TAG_MASK, TAG_NONE);
+
+    // The Danish "aa" is a contraction.
+    let value = da.get('a');
+    assert_eq!(value & TAG_MASK, TAG_CONTRACTION);
+    let contractions: Vec<&str> = da.contractions(value).map(|(s, _)| s).collect();
+    assert_eq!(contractions.first(), Some(&""));
+    assert!(contractions.contains(&"a"));
+
+    let metadata: DataPayload<'_, CollationMetadataV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::COLLATION_METADATA_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some("da".parse().unwrap()),
+                },
+            },
+        })
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful");
+    assert!(metadata.get().tailored());
+    assert_eq!(metadata.get().bits >> CASE_FIRST_SHIFT & CASE_FIRST_MASK, 2);
+}
@@ -0,0 +1,286 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::upropdump_serde;
+use icu_codepointtrie::codepointtrie::{CodePointTrie, CodePointTrieHeader, TrieTypeEnum};
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use icu_uniset::provider::*;
+use std::borrow::Cow;
+use std::fs;
+use std::path::{Path, PathBuf};
+use zerovec::ZeroVec;
+
+/// A data provider for the code point maps of enumerated properties, reading from .toml files
+/// produced by the ICU4C upropdump tool. The map of a property is read from the file named
+/// after the short name of the property, like `gc.toml` for General_Category.
+pub struct EnumeratedPropertiesDataProvider {
+    root_dir: PathBuf,
+}
+
+impl EnumeratedPropertiesDataProvider {
+    pub fn new(root_dir: PathBuf) -> Self {
+        EnumeratedPropertiesDataProvider { root_dir }
+    }
+
+    fn get_toml_data<T: serde::de::DeserializeOwned>(&self, name: &str) -> Result<T, Error> {
+        let path = self.get_toml_path(name);
+        let toml_str = fs::read_to_string(&path).map_err(|e| Error::Io(e, path.clone()))?;
+        toml::from_str(&toml_str).map_err(|e| Error::Toml(e, path))
+    }
+
+    fn get_toml_path(&self, name: &str) -> PathBuf {
+        let mut path: PathBuf = self.root_dir.clone().join(name);
+        path.set_extension("toml");
+        path
+    }
+
+    fn get_map(&self, name: &str) -> Result<UnicodePropertyMapV1<'static>, Error> {
+        let toml_data: upropdump_serde::enumerated::Main = self.get_toml_data(name)?;
+        let path = self.get_toml_path(name);
+        let trie_struct = toml_data.code_point_trie.trie_struct;
+        let (header, index) = get_small_trie_parts(&trie_struct, &path)?;
+
+        let code_point_trie = match (trie_struct.data_8, trie_struct.data_16) {
+            (Some(data_8), _) => UnicodePropertyMapTrie::Bits8(
+                CodePointTrie::try_new(header, index, ZeroVec::from_aligned(&data_8).into_owned())
+                    .map_err(|e| Error::CodePointTrie(e, path))?,
+            ),
+            (_, Some(data_16)) => UnicodePropertyMapTrie::Bits16(
+                CodePointTrie::try_new(header, index, ZeroVec::from_aligned(&data_16).into_owned())
+                    .map_err(|e| Error::CodePointTrie(e, path))?,
+            ),
+            _ => {
+                return Err(Error::UnsupportedTrie(
+                    "expected 8-bit or 16-bit values",
+                    path,
+                ))
+            }
+        };
+
+        Ok(UnicodePropertyMapV1 {
+            name: Cow::from(toml_data.code_point_map.data.name),
+            code_point_trie,
+        })
+    }
+
+    fn get_script_extensions(&self) -> Result<ScriptExtensionsPropertyV1<'static>, Error> {
+        let name = &key::SCRIPT_EXTENSIONS_V1.sub_category;
+        let toml_data: upropdump_serde::script_extensions::Main = self.get_toml_data(name)?;
+        let path = self.get_toml_path(name);
+        let trie_struct = toml_data.code_point_trie.trie_struct;
+        let (header, index) = get_small_trie_parts(&trie_struct, &path)?;
+
+        let data_16 = match trie_struct.data_16 {
+            Some(data_16) => data_16,
+            None => return Err(Error::UnsupportedTrie("expected 16-bit values", path)),
+        };
+        let code_point_trie =
+            CodePointTrie::try_new(header, index, ZeroVec::from_aligned(&data_16).into_owned())
+                .map_err(|e| Error::CodePointTrie(e, path))?;
+
+        Ok(ScriptExtensionsPropertyV1 {
+            code_point_trie,
+            extensions: ZeroVec::from_aligned(&toml_data.script_extensions.data.script_code_array)
+                .into_owned(),
+        })
+    }
+}
+
+fn get_small_trie_parts(
+    trie_struct: &upropdump_serde::enumerated::SerializedCodePointTrie,
+    path: &Path,
+) -> Result<(CodePointTrieHeader, ZeroVec<'static, u16>), Error> {
+    if trie_struct.trie_type != TrieTypeEnum::Small as u8 {
+        return Err(Error::UnsupportedTrie(
+            "expected a small trie",
+            path.to_path_buf(),
+        ));
+    }
+
+    let header = CodePointTrieHeader {
+        index_length: trie_struct.index_length,
+        data_length: trie_struct.data_length,
+        high_start: trie_struct.high_start,
+        shifted12_high_start: trie_struct.shifted12_high_start,
+        index3_null_offset: trie_struct.index3_null_offset,
+        data_null_offset: trie_struct.data_null_offset,
+        null_value: trie_struct.null_value,
+    };
+    let index = ZeroVec::from_aligned(&trie_struct.index).into_owned();
+    Ok((header, index))
+}
+
+impl<'data> DataProvider<'data, UnicodePropertyMapV1Marker> for EnumeratedPropertiesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyMapV1Marker>, DataError> {
+        if !key::ALL_MAP_KEYS.contains(&req.resource_path.key) {
+            return Err(DataError::MissingResourceKey(req.resource_path.key));
+        }
+        let map = self
+            .get_map(&req.resource_path.key.sub_category)
+            .map_err(DataError::new_resc_error)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(map)),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, ScriptExtensionsPropertyV1Marker>
+    for EnumeratedPropertiesDataProvider
+{
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, ScriptExtensionsPropertyV1Marker>, DataError> {
+        if req.resource_path.key != key::SCRIPT_EXTENSIONS_V1 {
+            return Err(DataError::MissingResourceKey(req.resource_path.key));
+        }
+        let scx = self
+            .get_script_extensions()
+            .map_err(DataError::new_resc_error)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(scx)),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(EnumeratedPropertiesDataProvider, {
+    key::SCRIPT_EXTENSIONS_V1 => ScriptExtensionsPropertyV1Marker,
+    _ => UnicodePropertyMapV1Marker,
+}, SERDE_SE, 'data);
+
+impl IterableDataProviderCore for EnumeratedPropertiesDataProvider {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[test]
+fn test_general_category() {
+    use icu_uniset::enum_props::GeneralCategory;
+    use icu_uniset::maps;
+
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = EnumeratedPropertiesDataProvider::new(root_dir);
+
+    let gc = maps::get_general_category(&provider).expect("The data should be valid");
+
+    assert_eq!(gc.get('A'), GeneralCategory::UppercaseLetter);
+    assert_eq!(gc.get('a'), GeneralCategory::LowercaseLetter);
+    assert_eq!(gc.get(' '), GeneralCategory::SpaceSeparator);
+    assert_eq!(gc.get('\u{0301}'), GeneralCategory::NonspacingMark);
+    assert_eq!(gc.get('\u{E000}'), GeneralCategory::PrivateUse);
+    assert_eq!(gc.get('\u{10FFFF}'), GeneralCategory::Unassigned);
+    assert_eq!(gc.get_u32(0xD800), GeneralCategory::Surrogate);
+}
+
+#[test]
+fn test_maps_match_ranges() {
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = EnumeratedPropertiesDataProvider::new(root_dir);
+
+    for resc_key in key::ALL_MAP_KEYS.iter() {
+        let name = &resc_key.sub_category;
+        let map = provider.get_map(name).expect("The data should be valid");
+        let ranges = provider
+            .get_toml_data::<upropdump_serde::enumerated::Main>(name)
+            .expect("The data should be valid")
+            .code_point_map
+            .data
+            .ranges;
+        assert_eq!(name.as_str(), map.name);
+        for (start, end, value) in ranges {
+            for cp in start..=end {
+                assert_eq!(value, map.code_point_trie.get(cp), "{}: {:#x}", name, cp);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_script_extensions_match_ranges() {
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = EnumeratedPropertiesDataProvider::new(root_dir);
+
+    let scx = provider
+        .get_script_extensions()
+        .expect("The data should be valid");
+    let ranges = provider
+        .get_toml_data::<upropdump_serde::script_extensions::Main>("scx")
+        .expect("The data should be valid")
+        .script_extensions
+        .data
+        .ranges;
+    for (start, end, value) in ranges {
+        for cp in start..=end {
+            assert_eq!(value, scx.code_point_trie.get(cp), "scx: {:#x}", cp);
+        }
+    }
+}
+
+#[test]
+fn test_script_extensions() {
+    use icu_uniset::enum_props::Script;
+    use icu_uniset::script;
+
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = EnumeratedPropertiesDataProvider::new(root_dir);
+
+    let swe = script::get_script_with_extensions(&provider).expect("The data should be valid");
+
+    // U+0020 SPACE has no extensions.
+    assert_eq!(swe.get_script(' '), Script::Common);
+    assert!(swe.get_script_extensions(' ').eq([Script::Common]));
+    assert!(swe.has_script(' ', Script::Common));
+
+    // U+0964 DEVANAGARI DANDA is Common, but used with many Indic scripts.
+    assert_eq!(swe.get_script('\u{0964}'), Script::Common);
+    assert!(swe.has_script('\u{0964}', Script::Devanagari));
+    assert!(swe.has_script('\u{0964}', Script::Bengali));
+    assert!(!swe.has_script('\u{0964}', Script::Common));
+    assert!(!swe.has_script('\u{0964}', Script::Latin));
+
+    // U+0363 COMBINING LATIN SMALL LETTER A is Inherited, and used with Latin.
+    assert_eq!(swe.get_script('\u{0363}'), Script::Inherited);
+    assert!(swe.get_script_extensions('\u{0363}').eq([Script::Latin]));
+    assert!(!swe.has_script('\u{0363}', Script::Inherited));
+
+    // U+30FC KATAKANA-HIRAGANA PROLONGED SOUND MARK
+    assert_eq!(swe.get_script('\u{30FC}'), Script::Common);
+    assert!(swe
+        .get_script_extensions('\u{30FC}')
+        .eq([Script::Hiragana, Script::Katakana]));
+
+    // U+1DFA COMBINING DOT BELOW LEFT is Inherited, and used with Syriac.
+    assert!(swe.has_script('\u{1DFA}', Script::Syriac));
+
+    // U+0660 ARABIC-INDIC DIGIT ZERO has a Script value of its own, and is
+    // also used with Thaana and Yezidi.
+    assert_eq!(swe.get_script('\u{0660}'), Script::Arabic);
+    assert!(swe.get_script_extensions('\u{0660}').eq([
+        Script::Arabic,
+        Script::Thaana,
+        Script::Yezidi
+    ]));
+    assert!(swe.has_script('\u{0660}', Script::Arabic));
+    assert!(!swe.has_script('\u{0660}', Script::Common));
+
+    assert_eq!(swe.get_script('\u{10FFFF}'), Script::Unknown);
+}
@@ -14,6 +14,12 @@ pub enum Error {
     Toml(toml::de::Error, PathBuf),
     #[displaydoc("Invalid range: {0}-{1}")]
     InvalidCharRange(u32, u32),
+    #[displaydoc("Invalid code point: {0}")]
+    InvalidCodePoint(u32),
+    #[displaydoc("{0}: {1:?}")]
+    CodePointTrie(icu_codepointtrie::error::Error, PathBuf),
+    #[displaydoc("Unsupported code point trie: {0}: {1:?}")]
+    UnsupportedTrie(&'static str, PathBuf),
 }
 
 impl std::error::Error for Error {}
@@ -3,10 +3,19 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 //! `icu_provider_uprops` contains implementations of the [`ICU4X`]
-//! [`DataProvider`] interface backed by TOML files exported by the
-//! ICU4C upropdump tool. Create a directory containing TOML files for
+//! [`DataProvider`] interface backed by TOML files exported from
+//! ICU4C by `tools/upropdump.c`, and for collation by `tools/colldump.c` and
+//! `tools/collation.py`. Create a directory containing TOML files for
 //! the necessary Unicode properties and then pass the path into the
-//! [`BinaryPropertiesDataProvider`].
+//! [`BinaryPropertiesDataProvider`], into the [`EnumeratedPropertiesDataProvider`]
+//! for code point maps of enumerated properties and for Script_Extensions, or into
+//! the [`BidiDataProvider`] for the data of the bidirectional algorithm, or into the
+//! [`NormalizerDataProvider`] for the decompositions and compositions of normalization, or into
+//! the [`CaseMappingDataProvider`] for the case mappings and case folding, or into the
+//! [`CollationDataProvider`] for the collation elements of the root collation and its
+//! tailorings, or into the [`SecurityDataProvider`] for the confusables of UTS #39. The
+//! [`PropertiesDataProvider`] provides the data of both the binary and the
+//! enumerated properties, as needed for [`UnicodeSet`] patterns.
 //!
 //! **Important:** This data provider implementation is not optimized
 //! for production use.  It is much more efficient if you use
@@ -17,9 +26,31 @@
 //! [`FsDataProvider`]: ../icu_provider_fs/struct.FsDataProvider.html
 //! [`StaticDataProvider`]: ../icu_provider_blob/struct.StaticDataProvider.html
 //! [`BinaryPropertiesDataProvider`]: binary::BinaryPropertiesDataProvider
+//! [`EnumeratedPropertiesDataProvider`]: enumerated::EnumeratedPropertiesDataProvider
+//! [`BidiDataProvider`]: bidi::BidiDataProvider
+//! [`CaseMappingDataProvider`]: casemapping::CaseMappingDataProvider
+//! [`CollationDataProvider`]: collation::CollationDataProvider
+//! [`NormalizerDataProvider`]: normalizer::NormalizerDataProvider
+//! [`PropertiesDataProvider`]: properties::PropertiesDataProvider
+//! [`SecurityDataProvider`]: security::SecurityDataProvider
+//! [`UnicodeSet`]: icu_uniset::UnicodeSet
 
+mod bidi;
 mod binary;
+mod casemapping;
+mod collation;
+mod enumerated;
 mod error;
+mod normalizer;
+mod properties;
+mod security;
 mod upropdump_serde;
 
+pub use bidi::BidiDataProvider;
 pub use binary::BinaryPropertiesDataProvider;
+pub use casemapping::CaseMappingDataProvider;
+pub use collation::CollationDataProvider;
+pub use enumerated::EnumeratedPropertiesDataProvider;
+pub use normalizer::NormalizerDataProvider;
+pub use properties::PropertiesDataProvider;
+pub use security::SecurityDataProvider;
@@ -0,0 +1,274 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::upropdump_serde;
+use icu_codepointtrie::builder::CodePointTrieBuilder;
+use icu_codepointtrie::codepointtrie::Small;
+use icu_normalizer::provider::*;
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+use zerovec::ZeroVec;
+
+/// The quick check values in the .toml files of ICU4C.
+const ICU_QC_NO: u32 = 0;
+const ICU_QC_MAYBE: u32 = 2;
+
+/// A data provider for [`icu_normalizer`], reading from .toml files produced from ICU4C.
+///
+/// The Canonical_Combining_Class, NFC_Quick_Check and NFKC_Quick_Check properties are read
+/// like those of the [`EnumeratedPropertiesDataProvider`](crate::EnumeratedPropertiesDataProvider),
+/// from `ccc.toml`, `NFC_QC.toml` and `NFKC_QC.toml`. The full canonical and compatibility
+/// decompositions are read from `nfd.toml` and `nfkd.toml`, and the canonical compositions
+/// from `comp.toml`.
+pub struct NormalizerDataProvider {
+    root_dir: PathBuf,
+}
+
+impl NormalizerDataProvider {
+    pub fn new(root_dir: PathBuf) -> Self {
+        NormalizerDataProvider { root_dir }
+    }
+
+    fn get_toml_data<T: serde::de::DeserializeOwned>(&self, name: &str) -> Result<T, Error> {
+        let mut path: PathBuf = self.root_dir.clone().join(name);
+        path.set_extension("toml");
+        let toml_str = fs::read_to_string(&path).map_err(|e| Error::Io(e, path.clone()))?;
+        toml::from_str(&toml_str).map_err(|e| Error::Toml(e, path))
+    }
+
+    fn get_ranges(&self, name: &str) -> Result<Vec<(u32, u32, u32)>, Error> {
+        let toml_data: upropdump_serde::enumerated::Main = self.get_toml_data(name)?;
+        Ok(toml_data.code_point_map.data.ranges)
+    }
+
+    fn get_mappings(&self, name: &str) -> Result<BTreeMap<u32, Vec<u32>>, Error> {
+        let toml_data: upropdump_serde::decomposition_mapping::Main = self.get_toml_data(name)?;
+        Ok(toml_data
+            .decomposition_mapping
+            .data
+            .mappings
+            .into_iter()
+            .filter_map(|mut mapping| {
+                let code_point = mapping.first().copied()?;
+                mapping.remove(0);
+                Some((code_point, mapping))
+            })
+            .collect())
+    }
+
+    fn get_decompositions(&self) -> Result<DecompositionsV1<'static>, Error> {
+        let trie_error = |e| Error::CodePointTrie(e, self.root_dir.clone());
+        let mut builder = CodePointTrieBuilder::new(0, 0);
+        for (start, end, ccc) in self.get_ranges("ccc")? {
+            builder.set_range(start, end, ccc).map_err(trie_error)?;
+        }
+        for &(name, shift) in &[("NFC_QC", NFC_QC_SHIFT), ("NFKC_QC", NFKC_QC_SHIFT)] {
+            for (start, end, icu_qc) in self.get_ranges(name)? {
+                let qc = match icu_qc {
+                    ICU_QC_NO => QC_NO,
+                    ICU_QC_MAYBE => QC_MAYBE,
+                    _ => continue,
+                };
+                for code_point in start..=end {
+                    let value = builder.get(code_point) | qc << shift;
+                    builder.set(code_point, value).map_err(trie_error)?;
+                }
+            }
+        }
+
+        let canonical = self.get_mappings("nfd")?;
+        let compatibility = self.get_mappings("nfkd")?;
+        let code_points: BTreeSet<u32> = canonical
+            .keys()
+            .chain(compatibility.keys())
+            .copied()
+            .collect();
+        let mut mappings = Vec::new();
+        for code_point in code_points {
+            let canonical_mapping = canonical.get(&code_point);
+            let compatibility_mapping = compatibility.get(&code_point).or(canonical_mapping);
+            let mut value =
+                builder.get(code_point) | (mappings.len() as u32) << MAPPING_INDEX_SHIFT;
+            if let Some(canonical_mapping) = canonical_mapping {
+                value |= HAS_CANONICAL_DECOMPOSITION;
+                mappings.push(canonical_mapping.len() as u32);
+                mappings.extend_from_slice(canonical_mapping);
+            }
+            if let Some(compatibility_mapping) =
+                compatibility_mapping.filter(|&mapping| Some(mapping) != canonical_mapping)
+            {
+                value |= HAS_COMPATIBILITY_DECOMPOSITION;
+                mappings.push(compatibility_mapping.len() as u32);
+                mappings.extend_from_slice(compatibility_mapping);
+            }
+            builder.set(code_point, value).map_err(trie_error)?;
+        }
+
+        Ok(DecompositionsV1 {
+            code_point_trie: builder.build::<u32, Small>().map_err(trie_error)?,
+            mappings: ZeroVec::from_aligned(&mappings).into_owned(),
+        })
+    }
+
+    fn get_compositions(&self) -> Result<CanonicalCompositionsV1<'static>, Error> {
+        let toml_data: upropdump_serde::canonical_compositions::Main =
+            self.get_toml_data("comp")?;
+        let to_char = |c| std::char::from_u32(c).ok_or(Error::InvalidCodePoint(c));
+        let mut compositions = toml_data
+            .canonical_compositions
+            .data
+            .compositions
+            .into_iter()
+            .map(|(first, second, composite)| {
+                Ok((
+                    (u64::from(first) << 32) | u64::from(second),
+                    to_char(composite)?,
+                ))
+            })
+            .collect::<Result<Vec<(u64, char)>, Error>>()?;
+        compositions.sort_unstable();
+        let (pairs, composites): (Vec<u64>, Vec<char>) = compositions.into_iter().unzip();
+
+        Ok(CanonicalCompositionsV1 {
+            pairs: ZeroVec::from_aligned(&pairs).into_owned(),
+            composites: ZeroVec::from_aligned(&composites).into_owned(),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, DecompositionsV1Marker> for NormalizerDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, DecompositionsV1Marker>, DataError> {
+        req.resource_path.key.match_key(key::DECOMPOSITIONS_V1)?;
+        let decompositions = self
+            .get_decompositions()
+            .map_err(DataError::new_resc_error)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(decompositions)),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, CanonicalCompositionsV1Marker> for NormalizerDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, CanonicalCompositionsV1Marker>, DataError> {
+        req.resource_path
+            .key
+            .match_key(key::CANONICAL_COMPOSITIONS_V1)?;
+        let compositions = self.get_compositions().map_err(DataError::new_resc_error)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(compositions)),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(NormalizerDataProvider, {
+    key::CANONICAL_COMPOSITIONS_V1 => CanonicalCompositionsV1Marker,
+    _ => DecompositionsV1Marker,
+}, SERDE_SE, 'data);
+
+impl IterableDataProviderCore for NormalizerDataProvider {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[test]
+fn test_decompositions() {
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = NormalizerDataProvider::new(root_dir);
+
+    let payload: DataPayload<'_, DecompositionsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::DECOMPOSITIONS_V1,
+                options: ResourceOptions::default(),
+            },
+        })
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful");
+    let decompositions = payload.get();
+
+    let decompose = |c, compatibility| {
+        decompositions
+            .decomposition(c, compatibility)
+            .map(|d| d.collect::<String>())
+    };
+    assert_eq!(decompose('a', false), None);
+    assert_eq!(decompose('\u{1E0A}', false).as_deref(), Some("D\u{307}"));
+    assert_eq!(decompose('\u{1E0A}', true).as_deref(), Some("D\u{307}"));
+    assert_eq!(decompose('\u{FB01}', false), None);
+    assert_eq!(decompose('\u{FB01}', true).as_deref(), Some("fi"));
+    assert_eq!(
+        decompose('\u{1E9B}', false).as_deref(),
+        Some("\u{17F}\u{307}")
+    );
+    assert_eq!(decompose('\u{1E9B}', true).as_deref(), Some("s\u{307}"));
+
+    assert_eq!(decompositions.canonical_combining_class('a'), 0);
+    assert_eq!(decompositions.canonical_combining_class('\u{301}'), 230);
+    assert_eq!(decompositions.canonical_combining_class('\u{323}'), 220);
+    assert_eq!(
+        decompositions.get('\u{301}') >> NFC_QC_SHIFT & QC_MASK,
+        QC_MAYBE
+    );
+    assert_eq!(
+        decompositions.get('\u{1E0A}') >> NFC_QC_SHIFT & QC_MASK,
+        QC_YES
+    );
+    assert_eq!(
+        decompositions.get('\u{FB01}') >> NFC_QC_SHIFT & QC_MASK,
+        QC_YES
+    );
+    assert_eq!(
+        decompositions.get('\u{FB01}') >> NFKC_QC_SHIFT & QC_MASK,
+        QC_NO
+    );
+}
+
+#[test]
+fn test_compositions() {
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = NormalizerDataProvider::new(root_dir);
+
+    let payload: DataPayload<'_, CanonicalCompositionsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::CANONICAL_COMPOSITIONS_V1,
+                options: ResourceOptions::default(),
+            },
+        })
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful");
+    let compositions = payload.get();
+
+    assert_eq!(compositions.compose('A', '\u{30A}'), Some('\u{C5}'));
+    assert_eq!(compositions.compose('\u{17F}', '\u{307}'), Some('\u{1E9B}'));
+    assert_eq!(compositions.compose('A', '\u{323}'), Some('\u{1EA0}'));
+    // U+0958 DEVANAGARI LETTER QA is a composition exclusion.
+    assert_eq!(compositions.compose('\u{915}', '\u{93C}'), None);
+    assert_eq!(compositions.compose('A', 'B'), None);
+}
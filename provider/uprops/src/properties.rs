@@ -0,0 +1,124 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::binary::BinaryPropertiesDataProvider;
+use crate::enumerated::EnumeratedPropertiesDataProvider;
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use icu_uniset::provider::*;
+use std::path::PathBuf;
+
+/// A data provider for all of the property data in a directory of .toml files produced by
+/// the ICU4C upropdump tool: the sets of [`BinaryPropertiesDataProvider`], and the code point
+/// maps and Script_Extensions of [`EnumeratedPropertiesDataProvider`].
+pub struct PropertiesDataProvider {
+    binary: BinaryPropertiesDataProvider,
+    enumerated: EnumeratedPropertiesDataProvider,
+}
+
+impl PropertiesDataProvider {
+    pub fn new(root_dir: PathBuf) -> Self {
+        PropertiesDataProvider {
+            binary: BinaryPropertiesDataProvider::new(root_dir.clone()),
+            enumerated: EnumeratedPropertiesDataProvider::new(root_dir),
+        }
+    }
+}
+
+impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for PropertiesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyV1Marker>, DataError> {
+        self.binary.load_payload(req)
+    }
+}
+
+impl<'data> DataProvider<'data, UnicodePropertyMapV1Marker> for PropertiesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyMapV1Marker>, DataError> {
+        self.enumerated.load_payload(req)
+    }
+}
+
+impl<'data> DataProvider<'data, ScriptExtensionsPropertyV1Marker> for PropertiesDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, ScriptExtensionsPropertyV1Marker>, DataError> {
+        self.enumerated.load_payload(req)
+    }
+}
+
+icu_provider::impl_dyn_provider!(PropertiesDataProvider, {
+    key::BIDI_CLASS_V1 => UnicodePropertyMapV1Marker,
+    key::EAST_ASIAN_WIDTH_V1 => UnicodePropertyMapV1Marker,
+    key::GENERAL_CATEGORY_V1 => UnicodePropertyMapV1Marker,
+    key::GRAPHEME_CLUSTER_BREAK_V1 => UnicodePropertyMapV1Marker,
+    key::LINE_BREAK_V1 => UnicodePropertyMapV1Marker,
+    key::SCRIPT_V1 => UnicodePropertyMapV1Marker,
+    key::SENTENCE_BREAK_V1 => UnicodePropertyMapV1Marker,
+    key::WORD_BREAK_V1 => UnicodePropertyMapV1Marker,
+    key::SCRIPT_EXTENSIONS_V1 => ScriptExtensionsPropertyV1Marker,
+    _ => UnicodePropertyV1Marker,
+}, SERDE_SE, 'data);
+
+impl IterableDataProviderCore for PropertiesDataProvider {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[test]
+fn test_pattern_properties() {
+    use icu_uniset::pattern;
+
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = PropertiesDataProvider::new(root_dir);
+
+    // The sizes of the sets in ICU4C 73.1, which the code point maps of the
+    // enumerated properties in the test data are generated from.
+    let expected = [
+        ("[:L:]", 136104),
+        ("[:Lu:]", 1831),
+        ("[:gc=Cased_Letter:]", 4095),
+        ("\\p{Nd}", 680),
+        ("[:Assigned:]", 288767),
+        ("[:Script=Greek:]", 518),
+        ("[:Han:]", 98408),
+        ("[a-z\\u0300-\\u036f[:Script=Greek:]&&[:Lu:]]", 123),
+        ("[[:L:]-[a-z]]", 136078),
+        ("[:bc=AL:]", 1769),
+        ("[:ea=W:]", 182412),
+        ("[:lb=ID:]", 172465),
+        ("[:GCB=Extend:]", 2130),
+        ("[:sb=Upper:]", 1936),
+        ("[:WB=ALetter:]", 29489),
+    ];
+    for (pattern, size) in expected.iter() {
+        let set = pattern::parse(pattern, &provider).expect("The pattern should be valid");
+        assert_eq!(set.size(), *size, "{}", pattern);
+    }
+
+    let alpha = pattern::parse("[:Alphabetic:]", &provider).expect("The pattern should be valid");
+    let not_alpha = pattern::parse("\\P{Alpha}", &provider).expect("The pattern should be valid");
+    assert!(alpha.contains('a'));
+    assert!(!not_alpha.contains('a'));
+    assert_eq!(alpha.size() + not_alpha.size(), 0x110000);
+    assert_eq!(
+        not_alpha,
+        pattern::parse("[:alpha=no:]", &provider).expect("The pattern should be valid")
+    );
+
+    let set = pattern::parse("[\\p{ general category = uppercase letter }]", &provider)
+        .expect("The pattern should be valid");
+    assert!(set.contains('A'));
+    assert!(!set.contains('a'));
+}
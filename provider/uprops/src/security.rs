@@ -0,0 +1,150 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::Error;
+use crate::normalizer::NormalizerDataProvider;
+use crate::properties::PropertiesDataProvider;
+use crate::upropdump_serde;
+use icu_normalizer::provider::DecompositionsV1Marker;
+use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
+use icu_security::provider::*;
+use icu_uniset::provider::{ScriptExtensionsPropertyV1Marker, UnicodePropertyV1Marker};
+use std::fs;
+use std::path::PathBuf;
+use zerovec::{VarZeroVec, ZeroVec};
+
+/// A data provider for [`icu_security`], reading from .toml files produced from ICU4C.
+///
+/// The confusables of UTS #39 are read from `confusables.toml`. The set of characters that are
+/// allowed in identifiers and Script_Extensions are provided like those of the
+/// [`PropertiesDataProvider`], and the decompositions for NFD like those of the
+/// [`NormalizerDataProvider`].
+pub struct SecurityDataProvider {
+    root_dir: PathBuf,
+    properties: PropertiesDataProvider,
+    normalizer: NormalizerDataProvider,
+}
+
+impl SecurityDataProvider {
+    pub fn new(root_dir: PathBuf) -> Self {
+        SecurityDataProvider {
+            properties: PropertiesDataProvider::new(root_dir.clone()),
+            normalizer: NormalizerDataProvider::new(root_dir.clone()),
+            root_dir,
+        }
+    }
+
+    fn get_toml_data<T: serde::de::DeserializeOwned>(&self, name: &str) -> Result<T, Error> {
+        let mut path: PathBuf = self.root_dir.clone().join(name);
+        path.set_extension("toml");
+        let toml_str = fs::read_to_string(&path).map_err(|e| Error::Io(e, path.clone()))?;
+        toml::from_str(&toml_str).map_err(|e| Error::Toml(e, path))
+    }
+
+    fn get_confusables(&self) -> Result<ConfusablesV1<'static>, Error> {
+        let toml_data: upropdump_serde::confusables::Main = self.get_toml_data("confusables")?;
+        let mut mappings = toml_data.confusables.data.mappings;
+        mappings.sort_unstable_by_key(|&(source, _)| source);
+
+        let mut sources = Vec::with_capacity(mappings.len());
+        let mut prototypes = Vec::with_capacity(mappings.len());
+        for (source, prototype) in mappings {
+            sources.push(source);
+            prototypes.push(
+                prototype
+                    .iter()
+                    .map(|&c| std::char::from_u32(c).ok_or(Error::InvalidCodePoint(c)))
+                    .collect::<Result<String, Error>>()?,
+            );
+        }
+
+        Ok(ConfusablesV1 {
+            sources: ZeroVec::from_aligned(&sources).into_owned(),
+            prototypes: VarZeroVec::from(prototypes),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, ConfusablesV1Marker> for SecurityDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, ConfusablesV1Marker>, DataError> {
+        req.resource_path.key.match_key(key::CONFUSABLES_V1)?;
+        let confusables = self.get_confusables().map_err(DataError::new_resc_error)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(confusables)),
+        })
+    }
+}
+
+impl<'data> DataProvider<'data, UnicodePropertyV1Marker> for SecurityDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, UnicodePropertyV1Marker>, DataError> {
+        self.properties.load_payload(req)
+    }
+}
+
+impl<'data> DataProvider<'data, ScriptExtensionsPropertyV1Marker> for SecurityDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, ScriptExtensionsPropertyV1Marker>, DataError> {
+        self.properties.load_payload(req)
+    }
+}
+
+impl<'data> DataProvider<'data, DecompositionsV1Marker> for SecurityDataProvider {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'data, DecompositionsV1Marker>, DataError> {
+        self.normalizer.load_payload(req)
+    }
+}
+
+icu_provider::impl_dyn_provider!(SecurityDataProvider, {
+    key::CONFUSABLES_V1 => ConfusablesV1Marker,
+    icu_uniset::provider::key::SCRIPT_EXTENSIONS_V1 => ScriptExtensionsPropertyV1Marker,
+    icu_normalizer::provider::key::DECOMPOSITIONS_V1 => DecompositionsV1Marker,
+    _ => UnicodePropertyV1Marker,
+}, SERDE_SE, 'data);
+
+impl IterableDataProviderCore for SecurityDataProvider {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = vec![ResourceOptions::default()];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[test]
+fn test_confusables() {
+    let root_dir = icu_testdata::paths::data_root().join("uprops");
+    let provider = SecurityDataProvider::new(root_dir);
+
+    let payload: DataPayload<'_, ConfusablesV1Marker> = provider
+        .load_payload(&DataRequest::from(key::CONFUSABLES_V1))
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("Loading was successful");
+    let confusables = payload.get();
+
+    assert_eq!(confusables.get('0'), Some("O"));
+    assert_eq!(confusables.get('m'), Some("rn"));
+    // U+0430 CYRILLIC SMALL LETTER A
+    assert_eq!(confusables.get('\u{430}'), Some("a"));
+    assert_eq!(confusables.get('a'), None);
+    // Characters that change in NFD are not included.
+    assert_eq!(confusables.get('\u{E0}'), None);
+}
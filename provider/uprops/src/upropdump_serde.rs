@@ -22,6 +22,229 @@ pub mod binary {
     }
 }
 
+pub mod code_point_map {
+    #[derive(serde::Deserialize)]
+    pub struct CodePointMap {
+        pub long_name: String,
+        pub name: String,
+        pub mappings: Vec<(u32, u32)>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Level1 {
+        pub data: CodePointMap,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Main {
+        pub code_point_map: Level1,
+    }
+}
+
 pub mod enumerated {
-    // TODO(#883)
+    #[derive(serde::Deserialize)]
+    pub struct EnumeratedPropertyMap {
+        pub long_name: String,
+        pub name: String,
+        pub ranges: Vec<(u32, u32, u32)>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Level1 {
+        pub data: EnumeratedPropertyMap,
+    }
+
+    /// The fields of a UCPTrie, as written by usrc_writeUCPTrie() in ICU4C.
+    #[derive(serde::Deserialize)]
+    pub struct SerializedCodePointTrie {
+        pub name: String,
+        pub index: Vec<u16>,
+        pub data_8: Option<Vec<u8>>,
+        pub data_16: Option<Vec<u16>>,
+        pub data_32: Option<Vec<u32>>,
+        #[serde(rename = "indexLength")]
+        pub index_length: u32,
+        #[serde(rename = "dataLength")]
+        pub data_length: u32,
+        #[serde(rename = "highStart")]
+        pub high_start: u32,
+        #[serde(rename = "shifted12HighStart")]
+        pub shifted12_high_start: u16,
+        #[serde(rename = "type")]
+        pub trie_type: u8,
+        #[serde(rename = "valueWidth")]
+        pub value_width: u8,
+        #[serde(rename = "index3NullOffset")]
+        pub index3_null_offset: u16,
+        #[serde(rename = "dataNullOffset")]
+        pub data_null_offset: u32,
+        #[serde(rename = "nullValue")]
+        pub null_value: u32,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct CodePointTrie {
+        #[serde(rename = "struct")]
+        pub trie_struct: SerializedCodePointTrie,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Main {
+        pub code_point_map: Level1,
+        pub code_point_trie: CodePointTrie,
+    }
+}
+
+pub mod script_extensions {
+    use super::enumerated::CodePointTrie;
+
+    #[derive(serde::Deserialize)]
+    pub struct ScriptExtensionsProperty {
+        pub long_name: String,
+        pub name: String,
+        pub script_code_array: Vec<u16>,
+        pub ranges: Vec<(u32, u32, u16)>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Level1 {
+        pub data: ScriptExtensionsProperty,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Main {
+        pub script_extensions: Level1,
+        pub code_point_trie: CodePointTrie,
+    }
+}
+
+pub mod decomposition_mapping {
+    #[derive(serde::Deserialize)]
+    pub struct DecompositionMapping {
+        pub long_name: String,
+        pub name: String,
+        /// Each mapping is a code point followed by the code points of its decomposition.
+        pub mappings: Vec<Vec<u32>>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Level1 {
+        pub data: DecompositionMapping,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Main {
+        pub decomposition_mapping: Level1,
+    }
+}
+
+pub mod canonical_compositions {
+    #[derive(serde::Deserialize)]
+    pub struct CanonicalCompositions {
+        pub long_name: String,
+        pub name: String,
+        /// The first code point, the second code point and the composite of each composition.
+        pub compositions: Vec<(u32, u32, u32)>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Level1 {
+        pub data: CanonicalCompositions,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Main {
+        pub canonical_compositions: Level1,
+    }
+}
+
+pub mod case_mapping {
+    #[derive(serde::Deserialize)]
+    pub struct Mapping {
+        pub code_point: u32,
+        pub lower: Option<Vec<u32>>,
+        pub upper: Option<Vec<u32>>,
+        pub title: Option<Vec<u32>>,
+        pub fold: Option<Vec<u32>>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct CaseMapping {
+        pub long_name: String,
+        pub name: String,
+        /// The start, the end, the case type, Case_Ignorable, the dot type and whether the
+        /// code points are letters, numbers or symbols, of each range.
+        pub ranges: Vec<(u32, u32, u32, u32, u32, u32)>,
+        /// The full case mappings of the root locale that differ from the code point.
+        pub mappings: Vec<Mapping>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Level1 {
+        pub data: CaseMapping,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Main {
+        pub case_mapping: Level1,
+    }
+}
+
+pub mod collation {
+    #[derive(serde::Deserialize)]
+    pub struct Collation {
+        pub long_name: String,
+        pub name: String,
+        /// The default options of a tailoring.
+        pub case_first: Option<String>,
+        pub alternate: Option<String>,
+        pub max_variable: Option<String>,
+        /// The start, the end and the collation element of the start of each range of code
+        /// points with single collation elements whose primary weights increase by one.
+        pub ranges: Vec<(u32, u32, u64)>,
+        /// The code points and the collation elements of all other mappings.
+        pub mappings: Vec<(Vec<u32>, Vec<u64>)>,
+        /// The code point and the value of each decimal digit of the root collation.
+        #[serde(default)]
+        pub digits: Vec<(u32, u32)>,
+        /// The runs of Han ideographs of the root collation, in the order of their consecutive
+        /// primary weights, starting at `han_primary`.
+        pub han_primary: Option<u32>,
+        #[serde(default)]
+        pub han_runs: Vec<(u32, u32)>,
+        pub unassigned_primary: Option<u32>,
+        pub numeric_primary: Option<u32>,
+        #[serde(default)]
+        pub variable_tops: Vec<u32>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Level1 {
+        pub data: Collation,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Main {
+        pub collation: Level1,
+    }
+}
+
+pub mod confusables {
+    #[derive(serde::Deserialize)]
+    pub struct Confusables {
+        pub long_name: String,
+        pub name: String,
+        /// Each code point with a prototype, and the code points of the prototype.
+        pub mappings: Vec<(u32, Vec<u32>)>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Level1 {
+        pub data: Confusables,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct Main {
+        pub confusables: Level1,
+    }
 }
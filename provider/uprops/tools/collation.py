@@ -0,0 +1,225 @@
+#!/usr/bin/env python3
+
+# This file is part of ICU4X. For terms of use, please see the file
+# called LICENSE at the top level of the ICU4X source tree
+# (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+# Converts the collation elements dumped by colldump.c into the TOML files read by
+# icu_provider_uprops, with the primary weights renumbered densely from 0x02000000:
+#
+#     python3 collation.py DUMP_DIRECTORY OUTPUT_DIRECTORY
+
+import bisect
+import os
+import sys
+
+COMMON = 0x05000500
+NUMERIC_GAP = 0x3000
+LOCALES = ["da", "es", "sv", "tr"]
+
+if len(sys.argv) != 3:
+    sys.exit("Usage: %s DUMP_DIRECTORY OUTPUT_DIRECTORY" % sys.argv[0])
+dump_dir, out_dir = sys.argv[1:]
+
+
+def parse(path):
+    entries, extra = [], []
+    for line in open(path):
+        tag, rest = line[0], line[1:].strip()
+        if tag in "CS":
+            key, ces = rest.split(";")
+            key = key.split()
+            ces = [int(x, 16) for x in ces.split()]
+            info = None
+            if tag == "C" and len(key) == 5:
+                info = tuple(int(x) for x in key[:4])
+                key = key[4:]
+            key = tuple(int(x, 16) for x in key)
+            # ICU's contractions of U+FDD0 and U+FDD1 are for its own boundaries in the root
+            # collation, not for text.
+            if len(key) > 1 and key[0] in (0xFDD0, 0xFDD1):
+                continue
+            entries.append((key, info, ces))
+        else:
+            extra.append((tag, rest.split()))
+    return entries, extra
+
+
+root, root_extra = parse(os.path.join(dump_dir, "root.txt"))
+tailorings = {loc: parse(os.path.join(dump_dir, "%s.txt" % loc)) for loc in LOCALES}
+
+
+def is_unassigned_like(ces):
+    return len(ces) == 1 and ces[0] & 0xFFFFFFFF == COMMON and ces[0] >> 56 == 0xFE
+
+
+implicit = {}  # code point -> ICU primary
+explicit = {}  # code point sequence -> ces
+digits = {}
+for key, info, ces in root:
+    if info is not None:
+        _, uideo, digit, _ = info
+        if digit >= 0:
+            digits[key[0]] = digit
+        if is_unassigned_like(ces) or (
+            uideo and len(ces) == 1 and ces[0] & 0xFFFFFFFF == COMMON
+        ):
+            implicit[key[0]] = ces[0] >> 32
+            continue
+    explicit[key] = ces
+
+# Primaries, except for those of unassigned code points, which are computed from the code point.
+primaries = set()
+for ces in explicit.values():
+    primaries.update(ce >> 32 for ce in ces)
+for _, (entries, _) in tailorings.items():
+    for _, _, ces in entries:
+        primaries.update(ce >> 32 for ce in ces)
+unassigned = sorted((p, c) for c, p in implicit.items() if p >> 24 == 0xFE)
+assert [c for _, c in unassigned] == sorted(c for _, c in unassigned), "unassigned order"
+han = {c: p for c, p in implicit.items() if p >> 24 != 0xFE}
+primaries.update(han.values())
+primaries.discard(0)
+assert not any(p >> 24 == 0xFE for p in primaries), "explicit primary in the unassigned range"
+primaries = sorted(primaries)
+
+zero = explicit[(0x30,)][0] >> 32
+new_primary = {}
+next_primary = 0x02000000
+numeric_primary = unassigned_primary = None
+for p in primaries:
+    if p == zero:
+        numeric_primary = next_primary
+        next_primary += NUMERIC_GAP
+    if p >> 24 == 0xFF and unassigned_primary is None:
+        unassigned_primary = next_primary
+        next_primary += 0x110000
+    new_primary[p] = next_primary
+    next_primary += 1
+assert new_primary[explicit[(0xFFFE,)][0] >> 32] == 0x02000000
+assert min(primaries) == explicit[(0xFFFE,)][0] >> 32
+
+
+def renumber(ces):
+    return [new_primary[ce >> 32] << 32 | ce & 0xFFFFFFFF if ce >> 32 else ce for ce in ces]
+
+
+for ces in list(explicit.values()) + [c for e, _ in tailorings.values() for _, _, c in e]:
+    for ce in ces:
+        s, t = ce >> 16 & 0xFFFF, ce & 0xFFFF
+        assert s == 0 or s >> 8 >= 2, hex(ce)
+        assert t == 0 or (t & 0x3F3F) >> 8 >= 2, hex(ce)
+
+variable_tops = []
+for tag, fields in root_extra:
+    if tag == "V":
+        vt = int(fields[0], 16)
+        variable_tops.append(new_primary[primaries[bisect.bisect_right(primaries, vt) - 1]])
+
+# Runs of Han ideographs with consecutive primaries, which follow each other without gaps.
+implicit_ranges = []
+for c in sorted(han):
+    p = new_primary[han[c]]
+    last = implicit_ranges[-1] if implicit_ranges else None
+    if last and last[1] == c - 1 and last[2] + c - last[0] == p:
+        last[1] = c
+    else:
+        implicit_ranges.append([c, c, p])
+runs = sorted(implicit_ranges, key=lambda r: r[2])
+for a, b in zip(runs, runs[1:]):
+    assert a[2] + a[1] - a[0] + 1 == b[2], "gap between Han runs"
+
+
+def write_mappings(f, entries):
+    """Writes runs of code points with single, consecutive CEs as ranges, and the rest as
+    mappings."""
+    singles = sorted(
+        (k[0], ces[0]) for k, ces in entries.items() if len(k) == 1 and len(ces) == 1
+    )
+    ranges = []
+    for c, ce in singles:
+        last = ranges[-1] if ranges else None
+        if last and last[1] == c - 1 and last[2] + ((c - last[0]) << 32) == ce and ce >> 32:
+            last[1] = c
+        else:
+            ranges.append([c, c, ce])
+    f.write("# [start, end, collation element of start], where the primary weight increases by"
+            " one\n")
+    f.write("# for each following code point\n")
+    f.write("ranges = [\n")
+    for start, end, ce in ranges:
+        f.write("  [0x%x, 0x%x, 0x%x],\n" % (start, end, ce))
+    f.write("]\n")
+    f.write("# [[code points], [collation elements]]\n")
+    f.write("mappings = [\n")
+    for key in sorted(entries):
+        ces = entries[key]
+        if len(key) == 1 and len(ces) == 1:
+            continue
+        code_points = ", ".join("0x%x" % c for c in key)
+        f.write("  [[%s], [%s]],\n" % (code_points, ", ".join("0x%x" % ce for ce in ces)))
+    f.write("]\n")
+
+
+HEADER = """# Copyright (C) 2021 and later: Unicode, Inc. and others.
+# License & terms of use: http://www.unicode.org/copyright.html
+#
+# file name: %s
+#
+# machine-generated by: provider/uprops/tools/colldump.c and collation.py with ICU4C %s
+# (CLDR %s, Unicode %s)
+
+"""
+
+versions = tuple(dict((tag, fields) for tag, fields in root_extra)["I"])
+with open(os.path.join(out_dir, "root.toml"), "w") as f:
+    f.write(HEADER % (("root",) + versions))
+    f.write("[collation.data]\nlong_name = \"Collation\"\nname = \"root\"\n")
+    f.write("# The collation elements are primary << 32 | secondary << 16 | tertiary, where the"
+            " two\n")
+    f.write("# highest bits of the tertiary weight are the case bits.\n")
+    write_mappings(f, {k: renumber(v) for k, v in explicit.items()})
+    f.write("# [code point, digit value] of the decimal digits\n")
+    f.write("digits = [\n")
+    for c in sorted(digits):
+        f.write("  [0x%x, %d],\n" % (c, digits[c]))
+    f.write("]\n")
+    f.write("# The primary weight of the first Han ideograph with an implicit weight\n")
+    f.write("han_primary = 0x%x\n" % min(r[2] for r in implicit_ranges))
+    f.write("# [start, end] of the runs of Han ideographs with implicit weights, in the order of"
+            " their\n")
+    f.write("# consecutive primary weights\n")
+    f.write("han_runs = [\n")
+    for r in sorted(implicit_ranges, key=lambda r: r[2]):
+        f.write("  [0x%x, 0x%x],\n" % (r[0], r[1]))
+    f.write("]\n")
+    f.write("# The primary weight of U+0000 if it was unassigned; other unassigned code points"
+            " and\n")
+    f.write("# private use characters have the primary weight of their code point added to"
+            " it.\n")
+    f.write("unassigned_primary = 0x%x\n" % unassigned_primary)
+    f.write("# The first of the primary weights reserved for numeric collation\n")
+    f.write("numeric_primary = 0x%x\n" % numeric_primary)
+    f.write("# The highest primary weights of the space, punctuation, symbol and currency"
+            " groups\n")
+    f.write("variable_tops = [%s]\n" % ", ".join("0x%x" % v for v in variable_tops))
+
+# ICU attribute values
+ICU_CASE_FIRST = {16: "off", 24: "lower", 25: "upper"}
+ICU_ALTERNATE = {21: "non-ignorable", 20: "shifted"}
+ICU_MAX_VARIABLE = {0x1000: "space", 0x1001: "punct", 0x1002: "symbol", 0x1003: "currency"}
+for loc, (entries, extra) in tailorings.items():
+    tailored = {}
+    for key, _, ces in entries:
+        tailored[key] = renumber(ces)
+    options = dict((tag, fields) for tag, fields in extra)["O"]
+    with open(os.path.join(out_dir, "%s.toml" % loc), "w") as f:
+        f.write(HEADER % ((loc,) + versions))
+        f.write("[collation.data]\nlong_name = \"Collation\"\nname = \"%s\"\n" % loc)
+        f.write("case_first = \"%s\"\n" % ICU_CASE_FIRST[int(options[0])])
+        f.write("alternate = \"%s\"\n" % ICU_ALTERNATE[int(options[1])])
+        f.write("max_variable = \"%s\"\n" % ICU_MAX_VARIABLE[int(options[2])])
+        f.write("# The tailored code points and contractions, with all contractions that start"
+            " with\n")
+        f.write("# the same code points\n")
+        write_mappings(f, tailored)
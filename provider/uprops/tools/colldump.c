@@ -0,0 +1,300 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Dumps the collation elements of the CLDR root collation and of some tailorings from ICU4C,
+// which collation.py converts to the TOML files in provider/testdata/data/uprops/collation.
+//
+//     cc -o colldump colldump.c $(pkg-config --cflags --libs icu-i18n)
+//     mkdir dump && ./colldump dump
+//     python3 collation.py dump ../../testdata/data/uprops/collation
+//
+// Each locale is dumped to `locale`.txt, one line per entry:
+//
+//     I icu cldr unicode       the versions of ICU4C, CLDR and Unicode (root only)
+//     C flags cp ; ces         a code point, with flags in the root only
+//     S cps ; ces              a contraction, or a code point after a prefix
+//     V variable top           the variable top of each max variable group (root only)
+//     O case first alternate max variable
+//                              the attributes of the tailoring, as ICU4C values
+//
+// The flags of a code point are whether it is unassigned, whether it is a unified ideograph,
+// its digit value or -1, and whether it is a combining mark. The collation elements are
+// primary << 32 | secondary << 16 | tertiary, in hexadecimal.
+
+#include <stdbool.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <unicode/uchar.h>
+#include <unicode/ucol.h>
+#include <unicode/ucoleitr.h>
+#include <unicode/ulocdata.h>
+#include <unicode/unorm2.h>
+#include <unicode/uset.h>
+#include <unicode/utf16.h>
+#include <unicode/uversion.h>
+
+#define MAX_LENGTH 64
+#define MAX_ELEMENTS 512
+
+static const char *out_dir;
+static const UNormalizer2 *nfd;
+
+static void check(UErrorCode err, const char *what) {
+    if (U_FAILURE(err)) {
+        fprintf(stderr, "%s: %s\n", what, u_errorName(err));
+        exit(1);
+    }
+}
+
+// Creates the file `locale`.txt in the output directory.
+static FILE *create(const char *locale) {
+    char path[1024];
+    snprintf(path, sizeof path, "%s/%s.txt", out_dir, locale);
+    FILE *f = fopen(path, "w");
+    if (f == NULL) {
+        perror(path);
+        exit(1);
+    }
+    return f;
+}
+
+static bool is_nfd(const UChar *s, int32_t length) {
+    UErrorCode err = U_ZERO_ERROR;
+    UBool normalized = unorm2_isNormalized(nfd, s, length, &err);
+    check(err, "unorm2_isNormalized");
+    return normalized;
+}
+
+// Writes the line of `s` with the tag `tag`, and the 64-bit collation elements that
+// `collator` gives it.
+static void write_entry(FILE *f, const char *tag, const UCollator *collator, const UChar *s,
+                        int32_t length) {
+    UErrorCode err = U_ZERO_ERROR;
+    UCollationElements *elements = ucol_openElements(collator, s, length, &err);
+    check(err, "ucol_openElements");
+    uint32_t orders[MAX_ELEMENTS];
+    int32_t count = 0;
+    for (int32_t order = ucol_next(elements, &err); order != UCOL_NULLORDER;
+         order = ucol_next(elements, &err)) {
+        if (count == MAX_ELEMENTS) {
+            fprintf(stderr, "Too many collation elements\n");
+            exit(1);
+        }
+        orders[count++] = (uint32_t)order;
+    }
+    check(err, "ucol_next");
+    ucol_closeElements(elements);
+
+    fprintf(f, "%s", tag);
+    for (int32_t i = 0; i < length;) {
+        UChar32 c;
+        U16_NEXT(s, i, length, c);
+        fprintf(f, " %X", c);
+    }
+    fprintf(f, " ;");
+    for (int32_t i = 0; i < count; i++) {
+        uint64_t primary = orders[i] & 0xffff0000, secondary = orders[i] & 0xff00,
+                 tertiary = (orders[i] & 0xff) << 8;
+        // A continuation has the rest of the weights of the previous collation element.
+        if (i + 1 < count && (orders[i + 1] & 0xc0) == 0xc0) {
+            i++;
+            primary |= orders[i] >> 16;
+            secondary |= (orders[i] >> 8) & 0xff;
+            tertiary |= orders[i] & 0x3f;
+        }
+        uint64_t ce = primary << 32 | secondary << 16 | tertiary;
+        if (ce != 0) {
+            fprintf(f, " %llX", (unsigned long long)ce);
+        }
+    }
+    fprintf(f, "\n");
+}
+
+// Writes the contractions and the code points with a prefix of `data` whose first code point is
+// in `starters`, or all of them if it is NULL, with the collation elements of `collator`.
+static void write_contractions(FILE *f, const UCollator *collator, const UCollator *data,
+                               const USet *starters) {
+    UErrorCode err = U_ZERO_ERROR;
+    USet *contractions = uset_openEmpty();
+    ucol_getContractionsAndExpansions(data, contractions, NULL, true, &err);
+    check(err, "ucol_getContractionsAndExpansions");
+    for (int32_t i = 0; i < uset_getItemCount(contractions); i++) {
+        UChar32 start, end;
+        UChar s[MAX_LENGTH];
+        int32_t length = uset_getItem(contractions, i, &start, &end, s, MAX_LENGTH, &err);
+        check(err, "uset_getItem");
+        if (length == 0 || !is_nfd(s, length)) {
+            continue;
+        }
+        UChar32 first;
+        int32_t j = 0;
+        U16_NEXT(s, j, length, first);
+        if (starters == NULL || uset_contains(starters, first)) {
+            write_entry(f, "S", collator, s, length);
+        }
+    }
+    uset_close(contractions);
+}
+
+static void dump_root(void) {
+    UErrorCode err = U_ZERO_ERROR;
+    UCollator *collator = ucol_open("", &err);
+    check(err, "ucol_open");
+    FILE *f = create("root");
+
+    UVersionInfo version;
+    char icu[U_MAX_VERSION_STRING_LENGTH], cldr[U_MAX_VERSION_STRING_LENGTH],
+        unicode[U_MAX_VERSION_STRING_LENGTH];
+    u_getVersion(version);
+    u_versionToString(version, icu);
+    ulocdata_getCLDRVersion(version, &err);
+    check(err, "ulocdata_getCLDRVersion");
+    u_versionToString(version, cldr);
+    u_getUnicodeVersion(version);
+    u_versionToString(version, unicode);
+    fprintf(f, "I %s %s %s\n", icu, cldr, unicode);
+
+    for (UChar32 c = 0; c <= 0x10ffff; c++) {
+        UChar s[2];
+        int32_t length = 0;
+        if (U_IS_SURROGATE(c)) {
+            continue;
+        }
+        U16_APPEND_UNSAFE(s, length, c);
+        if (!is_nfd(s, length)) {
+            continue;
+        }
+        int8_t type = u_charType(c);
+        char tag[64];
+        snprintf(tag, sizeof tag, "C %d %d %d %d",
+                 type == U_UNASSIGNED && !u_hasBinaryProperty(c, UCHAR_NONCHARACTER_CODE_POINT),
+                 u_hasBinaryProperty(c, UCHAR_UNIFIED_IDEOGRAPH),
+                 type == U_DECIMAL_DIGIT_NUMBER ? u_charDigitValue(c) : -1,
+                 type == U_NON_SPACING_MARK || type == U_ENCLOSING_MARK ||
+                     type == U_COMBINING_SPACING_MARK);
+        write_entry(f, tag, collator, s, length);
+    }
+    write_contractions(f, collator, collator, NULL);
+
+    static const UColReorderCode GROUPS[] = {
+        UCOL_REORDER_CODE_SPACE,
+        UCOL_REORDER_CODE_PUNCTUATION,
+        UCOL_REORDER_CODE_SYMBOL,
+        UCOL_REORDER_CODE_CURRENCY,
+    };
+    for (size_t i = 0; i < sizeof GROUPS / sizeof GROUPS[0]; i++) {
+        ucol_setMaxVariable(collator, GROUPS[i], &err);
+        fprintf(f, "V %X\n", ucol_getVariableTop(collator, &err));
+        check(err, "ucol_getVariableTop");
+    }
+    fclose(f);
+    ucol_close(collator);
+}
+
+// Adds the NFD form of the `length` code units of `s` to `set`.
+static void add_nfd(USet *set, const UChar *s, int32_t length) {
+    UErrorCode err = U_ZERO_ERROR;
+    UChar normalized[MAX_LENGTH];
+    int32_t normalized_length = unorm2_normalize(nfd, s, length, normalized, MAX_LENGTH, &err);
+    check(err, "unorm2_normalize");
+    uset_addString(set, normalized, normalized_length);
+}
+
+// Dumps the attributes of the tailoring of `locale`, the collation elements of the first code
+// points of its tailored strings, of the tailored strings, and of all contractions that start
+// with those code points.
+static void dump_tailoring(const char *locale) {
+    UErrorCode err = U_ZERO_ERROR;
+    UCollator *collator = ucol_open(locale, &err);
+    UCollator *root = ucol_open("", &err);
+    check(err, "ucol_open");
+    FILE *f = create(locale);
+    fprintf(f, "O %d %d %d\n", ucol_getAttribute(collator, UCOL_CASE_FIRST, &err),
+            ucol_getAttribute(collator, UCOL_ALTERNATE_HANDLING, &err),
+            ucol_getMaxVariable(collator));
+    check(err, "ucol_getAttribute");
+
+    // The NFD forms of the tailored strings, and their first code points
+    USet *tailored = ucol_getTailoredSet(collator, &err);
+    check(err, "ucol_getTailoredSet");
+    USet *strings = uset_openEmpty();
+    USet *starters = uset_openEmpty();
+    for (int32_t i = 0; i < uset_getItemCount(tailored); i++) {
+        UChar32 start, end;
+        UChar s[MAX_LENGTH];
+        int32_t length = uset_getItem(tailored, i, &start, &end, s, MAX_LENGTH, &err);
+        check(err, "uset_getItem");
+        if (length > 0) {
+            add_nfd(strings, s, length);
+            continue;
+        }
+        for (UChar32 c = start; c <= end; c++) {
+            length = 0;
+            U16_APPEND_UNSAFE(s, length, c);
+            add_nfd(strings, s, length);
+        }
+    }
+    for (int32_t i = 0; i < uset_getItemCount(strings); i++) {
+        UChar32 start, end;
+        UChar s[MAX_LENGTH];
+        int32_t length = uset_getItem(strings, i, &start, &end, s, MAX_LENGTH, &err);
+        check(err, "uset_getItem");
+        if (length == 0) {
+            uset_addRange(starters, start, end);
+        } else {
+            UChar32 first;
+            int32_t j = 0;
+            U16_NEXT(s, j, length, first);
+            uset_add(starters, first);
+        }
+    }
+
+    for (int32_t i = 0; i < uset_getItemCount(starters); i++) {
+        UChar32 start, end;
+        uset_getItem(starters, i, &start, &end, NULL, 0, &err);
+        for (UChar32 c = start; c <= end; c++) {
+            UChar s[2];
+            int32_t length = 0;
+            U16_APPEND_UNSAFE(s, length, c);
+            write_entry(f, "C", collator, s, length);
+        }
+    }
+    for (int32_t i = 0; i < uset_getItemCount(strings); i++) {
+        UChar32 start, end;
+        UChar s[MAX_LENGTH];
+        int32_t length = uset_getItem(strings, i, &start, &end, s, MAX_LENGTH, &err);
+        // The strings of more than one code point
+        if (length > 1 && (length > 2 || !U16_IS_LEAD(s[0]))) {
+            write_entry(f, "S", collator, s, length);
+        }
+    }
+    write_contractions(f, collator, root, starters);
+    write_contractions(f, collator, collator, starters);
+    check(err, "uset_getItem");
+
+    fclose(f);
+    uset_close(starters);
+    uset_close(strings);
+    uset_close(tailored);
+    ucol_close(root);
+    ucol_close(collator);
+}
+
+int main(int argc, char **argv) {
+    if (argc != 2) {
+        fprintf(stderr, "Usage: %s OUTPUT_DIRECTORY\n", argv[0]);
+        return 1;
+    }
+    out_dir = argv[1];
+    UErrorCode err = U_ZERO_ERROR;
+    nfd = unorm2_getNFDInstance(&err);
+    check(err, "unorm2_getNFDInstance");
+
+    dump_root();
+    static const char *const LOCALES[] = {"da", "es", "sv", "tr"};
+    for (size_t i = 0; i < sizeof LOCALES / sizeof LOCALES[0]; i++) {
+        dump_tailoring(LOCALES[i]);
+    }
+    return 0;
+}
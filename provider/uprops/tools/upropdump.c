@@ -0,0 +1,633 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Exports Unicode properties from ICU4C as the TOML files read by icu_provider_uprops, which are
+// the test data in provider/testdata/data/uprops, except for the sets of the binary properties,
+// which are kept as they are.
+//
+//     cc -o upropdump upropdump.c $(pkg-config --cflags --libs icu-i18n)
+//     ./upropdump ../../testdata/data/uprops confusables.txt
+//
+// The confusables are read from confusables.txt of UTS #39, which is at
+// https://www.unicode.org/Public/security/ for the Unicode version of ICU4C.
+//
+// Each file is overwritten; the output only depends on the ICU4C version, which is written in
+// the header of each file, and on confusables.txt. Arrays are written like usrc_writeArray() in
+// ICU4C, and code point tries like usrc_writeUCPTrie().
+
+#include <ctype.h>
+#include <stdbool.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <unicode/ucasemap.h>
+#include <unicode/uchar.h>
+#include <unicode/ucptrie.h>
+#include <unicode/umutablecptrie.h>
+#include <unicode/unorm2.h>
+#include <unicode/uscript.h>
+#include <unicode/uset.h>
+#include <unicode/uspoof.h>
+#include <unicode/ustring.h>
+#include <unicode/utf16.h>
+#include <unicode/uversion.h>
+
+static const char *out_dir;
+
+static void check(UErrorCode err, const char *what) {
+    if (U_FAILURE(err)) {
+        fprintf(stderr, "%s: %s\n", what, u_errorName(err));
+        exit(1);
+    }
+}
+
+// Creates the file `name`.toml in the output directory, and writes its header.
+static FILE *create(const char *name) {
+    char path[1024];
+    snprintf(path, sizeof path, "%s/%s.toml", out_dir, name);
+    FILE *f = fopen(path, "w");
+    if (f == NULL) {
+        perror(path);
+        exit(1);
+    }
+    UVersionInfo icu, unicode;
+    char icu_version[U_MAX_VERSION_STRING_LENGTH], unicode_version[U_MAX_VERSION_STRING_LENGTH];
+    u_getVersion(icu);
+    u_getUnicodeVersion(unicode);
+    u_versionToString(icu, icu_version);
+    u_versionToString(unicode, unicode_version);
+    fprintf(f,
+            "# Copyright (C) 2021 and later: Unicode, Inc. and others.\n"
+            "# License & terms of use: http://www.unicode.org/copyright.html\n"
+            "#\n"
+            "# file name: %s\n"
+            "#\n"
+            "# machine-generated by: provider/uprops/tools/upropdump.c with ICU4C %s "
+            "(Unicode %s)\n\n",
+            name, icu_version, unicode_version);
+    return f;
+}
+
+// Writes an array of `length` values, 16 per line, where `value(p, i)` is the value at `i`.
+static void write_array(FILE *f, const char *name, const void *p, int32_t length,
+                        uint32_t (*value)(const void *, int32_t)) {
+    fprintf(f, "%s = [\n", name);
+    for (int32_t i = 0; i < length; i++) {
+        uint32_t v = value(p, i);
+        fprintf(f, i % 16 == 0 ? "  " : ",");
+        fprintf(f, v <= 9 ? "%u" : "0x%x", v);
+        if (i % 16 == 15 && i + 1 < length) {
+            fprintf(f, ",\n");
+        }
+    }
+    fprintf(f, "\n]\n");
+}
+
+static uint32_t value8(const void *p, int32_t i) { return ((const uint8_t *)p)[i]; }
+static uint32_t value16(const void *p, int32_t i) { return ((const uint16_t *)p)[i]; }
+static uint32_t value32(const void *p, int32_t i) { return ((const uint32_t *)p)[i]; }
+
+// Writes the [unicode_set.data] of `set`.
+static void write_set(const char *name, const char *long_name, const USet *set) {
+    UErrorCode err = U_ZERO_ERROR;
+    int32_t length = uset_serialize(set, NULL, 0, &err);
+    uint16_t *serialized = malloc(length * sizeof(uint16_t));
+    err = U_ZERO_ERROR;
+    uset_serialize(set, serialized, length, &err);
+    check(err, name);
+
+    FILE *f = create(name);
+    fprintf(f, "[unicode_set.data]\nlong_name = \"%s\"\nname = \"%s\"\n", long_name, name);
+    write_array(f, "serialized", serialized, length, value16);
+    fprintf(f, "ranges = [\n");
+    for (int32_t i = 0; i < uset_getItemCount(set); i++) {
+        UChar32 start, end;
+        uset_getItem(set, i, &start, &end, NULL, 0, &err);
+        fprintf(f, "  [0x%x, 0x%x],\n", start, end);
+    }
+    fprintf(f, "]\n");
+    fclose(f);
+    free(serialized);
+}
+
+// Writes the set of the code points with the value `value` of the property `property`.
+static void write_property_set(UProperty property, int32_t value) {
+    UErrorCode err = U_ZERO_ERROR;
+    USet *set = uset_openEmpty();
+    uset_applyIntPropertyValue(set, property, value, &err);
+    check(err, "uset_applyIntPropertyValue");
+
+    char name[128], long_name[128];
+    snprintf(name, sizeof name, "%s=%s", u_getPropertyName(property, U_SHORT_PROPERTY_NAME),
+             u_getPropertyValueName(property, value, U_SHORT_PROPERTY_NAME));
+    snprintf(long_name, sizeof long_name, "%s=%s", u_getPropertyName(property, U_LONG_PROPERTY_NAME),
+             u_getPropertyValueName(property, value, U_LONG_PROPERTY_NAME));
+    write_set(name, long_name, set);
+    uset_close(set);
+}
+
+// Writes the [code_point_map.data] of the code points whose Bidi_Mirroring_Glyph, or whose
+// Bidi_Paired_Bracket if `brackets`, is another code point.
+static void write_bidi_mapping(const char *name, const char *long_name, bool brackets) {
+    FILE *f = create(name);
+    fprintf(f, "[code_point_map.data]\nlong_name = \"%s\"\nname = \"%s\"\nmappings = [\n",
+            long_name, name);
+    for (UChar32 c = 0; c <= 0x10ffff; c++) {
+        UChar32 mapping;
+        if (brackets) {
+            if (u_getIntPropertyValue(c, UCHAR_BIDI_PAIRED_BRACKET_TYPE) == U_BPT_NONE) {
+                continue;
+            }
+            mapping = u_getBidiPairedBracket(c);
+        } else {
+            if (!u_hasBinaryProperty(c, UCHAR_BIDI_MIRRORED)) {
+                continue;
+            }
+            mapping = u_charMirror(c);
+        }
+        if (mapping != c) {
+            fprintf(f, "  [0x%x, 0x%x],\n", c, mapping);
+        }
+    }
+    fprintf(f, "]\n");
+    fclose(f);
+}
+
+// Writes the [code_point_trie.struct] of `trie`.
+static void write_trie(FILE *f, const char *name, const UCPTrie *trie) {
+    fprintf(f, "[code_point_trie.struct]\nname = \"%s\"\n", name);
+    write_array(f, "index", trie->index, trie->indexLength, value16);
+    switch (trie->valueWidth) {
+    case UCPTRIE_VALUE_BITS_8:
+        write_array(f, "data_8", trie->data.ptr8, trie->dataLength, value8);
+        break;
+    case UCPTRIE_VALUE_BITS_16:
+        write_array(f, "data_16", trie->data.ptr16, trie->dataLength, value16);
+        break;
+    default:
+        write_array(f, "data_32", trie->data.ptr32, trie->dataLength, value32);
+        break;
+    }
+    fprintf(f,
+            "indexLength = %d\ndataLength = %d\nhighStart = 0x%x\nshifted12HighStart = 0x%x\n"
+            "type = %d\nvalueWidth = %d\nindex3NullOffset = 0x%x\ndataNullOffset = 0x%x\n"
+            "nullValue = 0x%x\n",
+            trie->indexLength, trie->dataLength, trie->highStart, trie->shifted12HighStart,
+            trie->type, trie->valueWidth, trie->index3NullOffset, trie->dataNullOffset,
+            trie->nullValue);
+}
+
+// Writes the [code_point_map.data] ranges and the small code point trie of the enumerated
+// property `property`, whose values fit in `width`.
+static void write_enumerated_property(UProperty property, UCPTrieValueWidth width) {
+    UErrorCode err = U_ZERO_ERROR;
+    const char *name = u_getPropertyName(property, U_SHORT_PROPERTY_NAME);
+    FILE *f = create(name);
+    fprintf(f, "[code_point_map.data]\nlong_name = \"%s\"\nname = \"%s\"\nranges = [\n",
+            u_getPropertyName(property, U_LONG_PROPERTY_NAME), name);
+
+    uint32_t null_value = u_getIntPropertyValue(0x10ffff, property);
+    UMutableCPTrie *builder = umutablecptrie_open(null_value, null_value, &err);
+    UChar32 start = 0;
+    int32_t value = u_getIntPropertyValue(0, property);
+    for (UChar32 c = 1; c <= 0x110000; c++) {
+        int32_t next = c <= 0x10ffff ? u_getIntPropertyValue(c, property) : -1;
+        if (next != value) {
+            fprintf(f, "  [0x%x, 0x%x, %d],\n", start, c - 1, value);
+            if ((uint32_t)value != null_value) {
+                umutablecptrie_setRange(builder, start, c - 1, value, &err);
+            }
+            start = c;
+            value = next;
+        }
+    }
+    fprintf(f, "]\n\n");
+    UCPTrie *trie = umutablecptrie_buildImmutable(builder, UCPTRIE_TYPE_SMALL, width, &err);
+    check(err, name);
+    write_trie(f, name, trie);
+    fclose(f);
+    ucptrie_close(trie);
+    umutablecptrie_close(builder);
+}
+
+// The Script_Extensions lists of the [script_extensions.data], each ending with an element with
+// bit 15 set.
+static uint16_t extensions[0x400];
+static int32_t extensions_length = 0;
+
+// Returns the offset of the `length` elements of `list` in `extensions`, appending them if they
+// aren't a list there yet.
+static int32_t find_extensions(const uint16_t *list, int32_t length) {
+    for (int32_t i = 0; i + length <= extensions_length; i++) {
+        if ((i == 0 || (extensions[i - 1] & 0x8000)) &&
+            memcmp(extensions + i, list, length * sizeof(uint16_t)) == 0) {
+            return i;
+        }
+    }
+    if (extensions_length + length > (int32_t)(sizeof extensions / sizeof extensions[0])) {
+        fprintf(stderr, "Too many Script_Extensions\n");
+        exit(1);
+    }
+    memcpy(extensions + extensions_length, list, length * sizeof(uint16_t));
+    extensions_length += length;
+    return extensions_length - length;
+}
+
+// Writes the [script_extensions.data] of the Script and Script_Extensions properties, and their
+// small code point trie, in the representation of ICU4C: the value of a code point is its
+// Script if that is its only extension, and otherwise the offset of its extensions, with the
+// Script in the bits above it.
+static void write_script_extensions(void) {
+    UErrorCode err = U_ZERO_ERROR;
+    static uint16_t values[0x110000];
+    for (UChar32 c = 0; c <= 0x10ffff; c++) {
+        UScriptCode codes[64];
+        int32_t script = u_getIntPropertyValue(c, UCHAR_SCRIPT);
+        int32_t length = uscript_getScriptExtensions(c, codes, 64, &err);
+        check(err, "uscript_getScriptExtensions");
+        if (length == 1 && codes[0] == script) {
+            values[c] = script;
+            continue;
+        }
+        uint16_t list[64];
+        for (int32_t i = 0; i < length; i++) {
+            list[i] = codes[i];
+        }
+        list[length - 1] |= 0x8000;
+        int32_t offset = find_extensions(list, length);
+        if (script == USCRIPT_COMMON) {
+            values[c] = 0x400 | offset;
+        } else if (script == USCRIPT_INHERITED) {
+            values[c] = 0x800 | offset;
+        } else {
+            // The Script, followed by the offset of the extensions
+            uint16_t pair[] = {script, offset | 0x8000};
+            values[c] = 0xc00 | find_extensions(pair, 2);
+        }
+    }
+
+    FILE *f = create("scx");
+    fprintf(f, "[script_extensions.data]\nlong_name = \"Script_Extensions\"\nname = \"scx\"\n");
+    write_array(f, "script_code_array", extensions, extensions_length, value16);
+    fprintf(f, "ranges = [\n");
+    uint32_t null_value = values[0x10ffff];
+    UMutableCPTrie *builder = umutablecptrie_open(null_value, null_value, &err);
+    UChar32 start = 0;
+    for (UChar32 c = 1; c <= 0x110000; c++) {
+        if (c > 0x10ffff || values[c] != values[start]) {
+            fprintf(f, "  [0x%x, 0x%x, 0x%x],\n", start, c - 1, values[start]);
+            if (values[start] != null_value) {
+                umutablecptrie_setRange(builder, start, c - 1, values[start], &err);
+            }
+            start = c;
+        }
+    }
+    fprintf(f, "]\n\n");
+    UCPTrie *trie =
+        umutablecptrie_buildImmutable(builder, UCPTRIE_TYPE_SMALL, UCPTRIE_VALUE_BITS_16, &err);
+    check(err, "scx");
+    write_trie(f, "scx", trie);
+    fclose(f);
+    ucptrie_close(trie);
+    umutablecptrie_close(builder);
+}
+
+// Returns whether `c` is a Hangul syllable, whose decompositions are algorithmic.
+static bool is_hangul_syllable(UChar32 c) { return 0xac00 <= c && c <= 0xd7a3; }
+
+// Writes the [decomposition_mapping.data] of the full decompositions of `normalizer`, except
+// for the Hangul syllables.
+static void write_decompositions(const UNormalizer2 *normalizer, const char *name,
+                                 const char *long_name) {
+    FILE *f = create(name);
+    fprintf(f, "[decomposition_mapping.data]\nlong_name = \"%s\"\nname = \"%s\"\nmappings = [\n",
+            long_name, name);
+    for (UChar32 c = 0; c <= 0x10ffff; c++) {
+        UErrorCode err = U_ZERO_ERROR;
+        UChar decomposition[64];
+        int32_t length = unorm2_getDecomposition(normalizer, c, decomposition, 64, &err);
+        check(err, name);
+        if (length < 0 || is_hangul_syllable(c)) {
+            continue;
+        }
+        fprintf(f, "  [0x%x", c);
+        for (int32_t i = 0; i < length;) {
+            UChar32 d;
+            U16_NEXT(decomposition, i, length, d);
+            fprintf(f, ", 0x%x", d);
+        }
+        fprintf(f, "],\n");
+    }
+    fprintf(f, "]\n");
+    fclose(f);
+}
+
+// A canonical composition of the first and second code points into the composite.
+typedef struct {
+    UChar32 first, second, composite;
+} Composition;
+
+static int compare_compositions(const void *a, const void *b) {
+    const Composition *x = a, *y = b;
+    if (x->first != y->first) {
+        return x->first < y->first ? -1 : 1;
+    }
+    return x->second < y->second ? -1 : x->second > y->second;
+}
+
+// Writes the [canonical_compositions.data] of the pairs that NFC composes, except for the Hangul
+// syllables, sorted by the first and the second code point.
+static void write_compositions(const UNormalizer2 *nfc) {
+    static Composition compositions[4096];
+    size_t length = 0;
+    for (UChar32 c = 0; c <= 0x10ffff; c++) {
+        UErrorCode err = U_ZERO_ERROR;
+        UChar raw[8];
+        int32_t raw_length = unorm2_getRawDecomposition(nfc, c, raw, 8, &err);
+        check(err, "comp");
+        if (raw_length < 0 || is_hangul_syllable(c)) {
+            continue;
+        }
+        UChar32 first, second;
+        int32_t i = 0;
+        U16_NEXT(raw, i, raw_length, first);
+        if (i == raw_length) {
+            continue;
+        }
+        U16_NEXT(raw, i, raw_length, second);
+        if (i == raw_length && unorm2_composePair(nfc, first, second) == c) {
+            compositions[length++] = (Composition){first, second, c};
+        }
+    }
+    qsort(compositions, length, sizeof compositions[0], compare_compositions);
+
+    FILE *f = create("comp");
+    fprintf(f, "[canonical_compositions.data]\nlong_name = \"Canonical_Compositions\"\n"
+               "name = \"comp\"\ncompositions = [\n");
+    for (size_t i = 0; i < length; i++) {
+        fprintf(f, "  [0x%x, 0x%x, 0x%x],\n", compositions[i].first, compositions[i].second,
+                compositions[i].composite);
+    }
+    fprintf(f, "]\n");
+    fclose(f);
+}
+
+// The case type of `c`: 0 if uncased, 1 if lowercase, 2 if uppercase and 3 if titlecase.
+static int case_type(UChar32 c) {
+    if (u_hasBinaryProperty(c, UCHAR_LOWERCASE)) {
+        return 1;
+    } else if (u_hasBinaryProperty(c, UCHAR_UPPERCASE)) {
+        return 2;
+    }
+    return u_charType(c) == U_TITLECASE_LETTER ? 3 : 0;
+}
+
+// The dot type of `c`: 1 if Soft_Dotted, and otherwise 0, 2 or 3 if its Canonical_Combining_Class
+// is 0, 230 (Above) or another value.
+static int dot_type(UChar32 c) {
+    if (u_hasBinaryProperty(c, UCHAR_SOFT_DOTTED)) {
+        return 1;
+    }
+    uint8_t ccc = u_getCombiningClass(c);
+    return ccc == 0 ? 0 : ccc == 230 ? 2 : 3;
+}
+
+// Whether titlecasing starts at `c`, like ustrcase_isLNS() in ICU4C: letters, numbers, symbols and
+// private use characters, except uncased modifier letters.
+static int is_lns(UChar32 c) {
+    if (u_charType(c) == U_MODIFIER_LETTER) {
+        return case_type(c) != 0;
+    }
+    return (U_GET_GC_MASK(c) & (U_GC_L_MASK | U_GC_N_MASK | U_GC_S_MASK | U_GC_CO_MASK)) != 0;
+}
+
+// Writes the code points of the `length` code units of `s` as a TOML array.
+static void write_code_points(FILE *f, const UChar *s, int32_t length) {
+    fprintf(f, "[");
+    for (int32_t i = 0; i < length;) {
+        UChar32 c;
+        U16_NEXT(s, i, length, c);
+        fprintf(f, i > U16_LENGTH(c) ? ", 0x%x" : "0x%x", c);
+    }
+    fprintf(f, "]");
+}
+
+// Writes the [case_mapping.data] of the case properties of the code points, and of their full
+// case mappings in the root locale that differ from the code point.
+static void write_case_mapping(void) {
+    FILE *f = create("casemap");
+    fprintf(f, "[case_mapping.data]\nlong_name = \"Case_Mapping\"\nname = \"casemap\"\n"
+               "# [start, end, case type (0 none, 1 lower, 2 upper, 3 title), Case_Ignorable,\n"
+               "#  dot type (0 ccc=0, 1 Soft_Dotted, 2 ccc=230, 3 other ccc), letter/number/symbol]\n"
+               "ranges = [\n");
+    UChar32 start = 0;
+    int values[4] = {case_type(0), u_hasBinaryProperty(0, UCHAR_CASE_IGNORABLE), dot_type(0),
+                     is_lns(0)};
+    for (UChar32 c = 1; c <= 0x110000; c++) {
+        int next[4] = {-1, -1, -1, -1};
+        if (c <= 0x10ffff) {
+            next[0] = case_type(c);
+            next[1] = u_hasBinaryProperty(c, UCHAR_CASE_IGNORABLE);
+            next[2] = dot_type(c);
+            next[3] = is_lns(c);
+        }
+        if (memcmp(next, values, sizeof values) != 0) {
+            fprintf(f, "  [0x%x, 0x%x, %d, %d, %d, %d],\n", start, c - 1, values[0], values[1],
+                    values[2], values[3]);
+            start = c;
+            memcpy(values, next, sizeof values);
+        }
+    }
+
+    fprintf(f, "]\n# The full case mappings of the root locale that differ from the code point.\n"
+               "mappings = [\n");
+    UErrorCode err = U_ZERO_ERROR;
+    UCaseMap *title = ucasemap_open("", U_TITLECASE_WHOLE_STRING, &err);
+    check(err, "ucasemap_open");
+    for (UChar32 c = 0; c <= 0x10ffff; c++) {
+        if (U_IS_SURROGATE(c)) {
+            continue;
+        }
+        UChar s[2];
+        int32_t length = 0;
+        U16_APPEND_UNSAFE(s, length, c);
+        UChar mappings[4][64];
+        int32_t lengths[4];
+        lengths[0] = u_strToLower(mappings[0], 64, s, length, "", &err);
+        lengths[1] = u_strToUpper(mappings[1], 64, s, length, "", &err);
+        lengths[2] = ucasemap_toTitle(title, mappings[2], 64, s, length, &err);
+        lengths[3] = u_strFoldCase(mappings[3], 64, s, length, U_FOLD_CASE_DEFAULT, &err);
+        check(err, "casemap");
+        static const char *const NAMES[] = {"lower", "upper", "title", "fold"};
+        bool any = false;
+        for (int i = 0; i < 4; i++) {
+            if (lengths[i] == length && memcmp(mappings[i], s, length * sizeof(UChar)) == 0) {
+                continue;
+            }
+            if (!any) {
+                fprintf(f, "  { code_point = 0x%x", c);
+            }
+            fprintf(f, ", %s = ", NAMES[i]);
+            write_code_points(f, mappings[i], lengths[i]);
+            any = true;
+        }
+        if (any) {
+            fprintf(f, " },\n");
+        }
+    }
+    fprintf(f, "]\n");
+    fclose(f);
+    ucasemap_close(title);
+}
+
+// A code point in NFD with its prototype from confusables.txt, in NFD.
+typedef struct {
+    UChar32 c;
+    UChar prototype[64];
+    int32_t length;
+} Confusable;
+
+static int compare_confusables(const void *a, const void *b) {
+    return ((const Confusable *)a)->c - ((const Confusable *)b)->c;
+}
+
+// Parses the code points in hexadecimal at the start of `field`, separated by spaces, into `s`,
+// and returns their number of code units.
+static int32_t parse_code_points(const char *field, UChar *s, int32_t capacity) {
+    int32_t length = 0;
+    for (;;) {
+        while (*field == ' ' || *field == '\t') {
+            field++;
+        }
+        if (!isxdigit((unsigned char)*field)) {
+            return length;
+        }
+        char *end;
+        UChar32 c = (UChar32)strtol(field, &end, 16);
+        if (length + U16_LENGTH(c) > capacity) {
+            fprintf(stderr, "Too many code points: %s\n", field);
+            exit(1);
+        }
+        U16_APPEND_UNSAFE(s, length, c);
+        field = end;
+    }
+}
+
+// Writes the [confusables.data] of the prototypes of the code points in NFD that have one in
+// confusables.txt, read from `path`, which are the skeletons of those code points in NFD.
+static void write_confusables(const UNormalizer2 *nfd, const char *path) {
+    FILE *in = fopen(path, "r");
+    if (in == NULL) {
+        perror(path);
+        exit(1);
+    }
+    static Confusable confusables[0x4000];
+    size_t count = 0;
+    char line[4096];
+    while (fgets(line, sizeof line, in) != NULL) {
+        // The file starts with a byte order mark.
+        char *start = strncmp(line, "\xef\xbb\xbf", 3) == 0 ? line + 3 : line;
+        char *comment = strchr(start, '#');
+        if (comment != NULL) {
+            *comment = '\0';
+        }
+        char *separator = strchr(start, ';');
+        if (separator == NULL) {
+            continue;
+        }
+        UErrorCode err = U_ZERO_ERROR;
+        UChar source[2], prototype[64];
+        int32_t source_length = parse_code_points(start, source, 2);
+        int32_t prototype_length = parse_code_points(separator + 1, prototype, 64);
+        if (!unorm2_isNormalized(nfd, source, source_length, &err)) {
+            continue;
+        }
+        if (count == sizeof confusables / sizeof confusables[0]) {
+            fprintf(stderr, "Too many confusables\n");
+            exit(1);
+        }
+        Confusable *confusable = &confusables[count++];
+        int32_t i = 0;
+        U16_NEXT(source, i, source_length, confusable->c);
+        confusable->length = unorm2_normalize(nfd, prototype, prototype_length,
+                                              confusable->prototype, 64, &err);
+        check(err, path);
+    }
+    fclose(in);
+    qsort(confusables, count, sizeof confusables[0], compare_confusables);
+
+    FILE *f = create("confusables");
+    fprintf(f,
+            "[confusables.data]\nlong_name = \"Confusables\"\nname = \"confusables\"\n"
+            "# [code point, [code points of its prototype]] for the code points in NFD with a\n"
+            "# prototype in confusables.txt, with the prototypes in NFD\n"
+            "mappings = [\n");
+    for (size_t i = 0; i < count; i++) {
+        fprintf(f, "  [0x%x, ", confusables[i].c);
+        write_code_points(f, confusables[i].prototype, confusables[i].length);
+        fprintf(f, "],\n");
+    }
+    fprintf(f, "]\n");
+    fclose(f);
+}
+
+// Writes the set of the characters with the Identifier_Status Allowed of UTS #39.
+static void write_allowed_identifiers(void) {
+    UErrorCode err = U_ZERO_ERROR;
+    USet *allowed = uset_openEmpty();
+    uset_addAll(allowed, uspoof_getRecommendedSet(&err));
+    uset_addAll(allowed, uspoof_getInclusionSet(&err));
+    check(err, "uspoof_get*Set");
+    write_set("IdStatus=Allowed", "Identifier_Status=Allowed", allowed);
+    uset_close(allowed);
+}
+
+int main(int argc, char **argv) {
+    if (argc != 3) {
+        fprintf(stderr, "Usage: %s OUTPUT_DIRECTORY CONFUSABLES_TXT\n", argv[0]);
+        return 1;
+    }
+    out_dir = argv[1];
+
+    // The data of the bidirectional algorithm
+    for (int32_t value = 0; value <= u_getIntPropertyMaxValue(UCHAR_BIDI_CLASS); value++) {
+        write_property_set(UCHAR_BIDI_CLASS, value);
+    }
+    for (int32_t value = 0; value <= u_getIntPropertyMaxValue(UCHAR_BIDI_PAIRED_BRACKET_TYPE); value++) {
+        write_property_set(UCHAR_BIDI_PAIRED_BRACKET_TYPE, value);
+    }
+    write_bidi_mapping("bmg", "Bidi_Mirroring_Glyph", false);
+    write_bidi_mapping("bpb", "Bidi_Paired_Bracket", true);
+
+    // Enumerated properties
+    write_enumerated_property(UCHAR_BIDI_CLASS, UCPTRIE_VALUE_BITS_8);
+    write_enumerated_property(UCHAR_EAST_ASIAN_WIDTH, UCPTRIE_VALUE_BITS_8);
+    write_enumerated_property(UCHAR_GENERAL_CATEGORY, UCPTRIE_VALUE_BITS_8);
+    write_enumerated_property(UCHAR_GRAPHEME_CLUSTER_BREAK, UCPTRIE_VALUE_BITS_8);
+    write_enumerated_property(UCHAR_LINE_BREAK, UCPTRIE_VALUE_BITS_8);
+    write_enumerated_property(UCHAR_SCRIPT, UCPTRIE_VALUE_BITS_16);
+    write_enumerated_property(UCHAR_SENTENCE_BREAK, UCPTRIE_VALUE_BITS_8);
+    write_enumerated_property(UCHAR_WORD_BREAK, UCPTRIE_VALUE_BITS_8);
+    write_script_extensions();
+
+    // The data of the normalizer
+    UErrorCode err = U_ZERO_ERROR;
+    const UNormalizer2 *nfc = unorm2_getNFCInstance(&err);
+    const UNormalizer2 *nfd = unorm2_getNFDInstance(&err);
+    const UNormalizer2 *nfkd = unorm2_getNFKDInstance(&err);
+    check(err, "unorm2_get*Instance");
+    write_enumerated_property(UCHAR_CANONICAL_COMBINING_CLASS, UCPTRIE_VALUE_BITS_8);
+    write_enumerated_property(UCHAR_NFC_QUICK_CHECK, UCPTRIE_VALUE_BITS_8);
+    write_enumerated_property(UCHAR_NFKC_QUICK_CHECK, UCPTRIE_VALUE_BITS_8);
+    write_decompositions(nfd, "nfd", "NFD");
+    write_decompositions(nfkd, "nfkd", "NFKD");
+    write_compositions(nfc);
+
+    write_case_mapping();
+
+    // The data of the security checks of UTS #39
+    write_confusables(nfd, argv[2]);
+    write_allowed_identifiers();
+    return 0;
+}
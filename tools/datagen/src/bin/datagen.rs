@@ -20,6 +20,8 @@ use icu_provider_fs::export::fs_exporter;
 use icu_provider_fs::export::serializers;
 use icu_provider_fs::export::FilesystemExporter;
 use icu_provider_fs::manifest;
+use icu_segmenter_lstm::provider::key as lstm_key;
+use icu_segmenter_lstm::LstmJsonDataProvider;
 use simple_logger::SimpleLogger;
 use std::collections::HashSet;
 use std::path::PathBuf;
@@ -117,6 +119,16 @@ fn main() -> anyhow::Result<()> {
                 .help("CLDR JSON locale subset; defaults to 'full'")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("LSTM_ROOT")
+                .long("lstm-root")
+                .value_name("PATH")
+                .help(
+                    "Path to a directory of LSTM models for word segmentation, containing one \
+                    directory with a weights.json file per model.",
+                )
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("KEYS")
                 .short("k")
@@ -261,7 +273,15 @@ fn main() -> anyhow::Result<()> {
 
     if matches.is_present("ALL_KEYS") || matches.is_present("KEYS") {
         let keys = matches.values_of("KEYS").map(|values| values.collect());
-        export_cldr(&matches, exporter, locales_vec.as_deref(), keys.as_ref())?;
+        let has_cldr_source = matches.is_present("CLDR_TAG")
+            || matches.is_present("CLDR_ROOT")
+            || matches.is_present("CLDR_TESTDATA");
+        if has_cldr_source || !matches.is_present("LSTM_ROOT") {
+            export_cldr(&matches, exporter, locales_vec.as_deref(), keys.as_ref())?;
+        }
+        if matches.is_present("LSTM_ROOT") {
+            export_lstm(&matches, exporter, locales_vec.as_deref(), keys.as_ref())?;
+        }
     }
 
     if matches.is_present("HELLO_WORLD") {
@@ -403,6 +423,42 @@ fn export_cldr<'data>(
     Ok(())
 }
 
+fn export_lstm<'data>(
+    matches: &ArgMatches,
+    exporter: &mut (impl DataExporter<'data, SerdeSeDataStructMarker> + ?Sized),
+    allowed_locales: Option<&[LanguageIdentifier]>,
+    allowed_keys: Option<&HashSet<&str>>,
+) -> anyhow::Result<()> {
+    let key = lstm_key::LSTM_V1;
+    if let Some(allowed_keys) = allowed_keys {
+        if !allowed_keys.contains(&*key.writeable_to_string()) {
+            return Ok(());
+        }
+    }
+
+    let root_dir = matches
+        .value_of_os("LSTM_ROOT")
+        .map(PathBuf::from)
+        .expect("Option is present");
+    let raw_provider = LstmJsonDataProvider::new(root_dir);
+    let filtered_provider;
+    let provider: &dyn IterableDataProvider<SerdeSeDataStructMarker>;
+
+    if let Some(allowlist) = allowed_locales {
+        filtered_provider = raw_provider
+            .filterable()
+            .filter_by_langid_allowlist_strict(allowlist);
+        provider = &filtered_provider;
+    } else {
+        provider = &raw_provider;
+    }
+
+    log::info!("Writing key: {}", key);
+    icu_provider::export::export_from_iterable(&key, provider, exporter)?;
+
+    Ok(())
+}
+
 fn export_hello_world<'data>(
     _: &ArgMatches,
     exporter: &mut (impl DataExporter<'data, SerdeSeDataStructMarker> + ?Sized),
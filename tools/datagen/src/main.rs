@@ -50,6 +50,18 @@
 //!    --out /tmp/icu4x_data/bincode \
 //!    -v
 //!```
+//!
+//! Generate the LSTM word segmentation models:
+//!
+//!```bash
+//!# Run from the icu4x project folder
+//!$ cargo run --bin icu4x-datagen -- \
+//!    --lstm-root experimental/segmenter_lstm/tests/testdata \
+//!    --keys segmenter/lstm@1 \
+//!    --all-locales \
+//!    --out /tmp/icu4x_data/json \
+//!    -v
+//!```
 
 fn main() {
     panic!("Please run a more specific binary")
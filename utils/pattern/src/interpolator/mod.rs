@@ -4,12 +4,12 @@
 
 mod error;
 use crate::{replacement::ReplacementProvider, token::PatternToken};
-pub use error::InterpolatorError;
-use std::{
-    borrow::Cow,
+use alloc::borrow::Cow;
+use core::{
     fmt::{Debug, Display, Formatter},
     str::FromStr,
 };
+pub use error::InterpolatorError;
 use writeable::Writeable;
 
 /// The type returned by the [`Interpolator`] iterator.
@@ -30,9 +30,9 @@ impl<'i, 's, E> Writeable for InterpolatedKind<'i, 's, E>
 where
     E: Writeable,
 {
-    fn write_to<W>(&self, sink: &mut W) -> std::result::Result<(), std::fmt::Error>
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
     where
-        W: std::fmt::Write + ?Sized,
+        W: core::fmt::Write + ?Sized,
     {
         match self {
             Self::Literal(lit) => sink.write_str(lit),
@@ -45,7 +45,7 @@ impl<'i, 's, E> Display for InterpolatedKind<'i, 's, E>
 where
     E: Display,
 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::result::Result<(), core::fmt::Error> {
         match self {
             Self::Literal(lit) => f.write_str(lit),
             Self::Element(elem) => elem.fmt(f),
@@ -53,7 +53,7 @@ where
     }
 }
 
-type Result<E, R> = std::result::Result<Option<E>, InterpolatorError<R>>;
+type Result<E, R> = core::result::Result<Option<E>, InterpolatorError<R>>;
 
 /// Placeholder pattern interpolator.
 ///
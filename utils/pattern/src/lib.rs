@@ -103,16 +103,45 @@
 //! only if the placeholder pattern encounters a placeholder key that requires given
 //! pattern to be used.
 //!
+//! # Typed patterns
+//!
+//! A [`TypedPattern`] is a pre-parsed pattern whose placeholders are declared by a
+//! [`PlaceholderSchema`], such as [`SinglePlaceholder`] for `"{0} Time"`. It is validated
+//! against its schema when it is created, checks the number of replacements at compile time,
+//! and can be stored in data structs: with the `serde` feature it serializes its placeholders
+//! as a [`ZeroVec`](zerovec::ZeroVec), and with the `yoke` feature it implements `Yokeable`.
+//!
+//! ```
+//! use icu_pattern::{SinglePlaceholder, TypedPattern};
+//! use std::convert::TryFrom;
+//! use writeable::Writeable;
+//!
+//! let pattern = TypedPattern::<SinglePlaceholder>::try_from("GMT{0}")
+//!     .expect("Failed to parse a pattern.");
+//!
+//! assert_eq!(pattern.interpolate(["+01:00"]).writeable_to_string(), "GMT+01:00");
+//! ```
+//!
 //! [`ICU4X`]: ../icu/index.html
 //! [`FromStr`]: std::str::FromStr
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
 mod interpolator;
 mod parser;
 mod pattern;
 mod replacement;
 mod token;
+mod typed;
 
 pub use interpolator::{InterpolatedKind, Interpolator, InterpolatorError};
 pub use parser::{Parser, ParserError, ParserOptions};
 pub use pattern::{InterpolatedPattern, Pattern, PatternError};
 pub use replacement::ReplacementProvider;
 pub use token::PatternToken;
+pub use typed::{
+    DoublePlaceholder, InterpolatedTypedPattern, PlaceholderCount, PlaceholderSchema,
+    SinglePlaceholder, TypedPattern, TypedPatternError,
+};
@@ -2,8 +2,8 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use core::fmt::Debug;
 use displaydoc::Display;
-use std::fmt::Debug;
 
 /// An error returned when parsing a pattern.
 ///
@@ -44,4 +44,5 @@ where
     UnclosedQuotedLiteral,
 }
 
+#[cfg(feature = "std")]
 impl<E: Debug> std::error::Error for ParserError<E> {}
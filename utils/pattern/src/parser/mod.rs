@@ -5,8 +5,9 @@
 pub mod error;
 
 use crate::token::PatternToken;
+use alloc::borrow::Cow;
+use core::{fmt::Debug, marker::PhantomData, str::FromStr};
 pub use error::ParserError;
-use std::{borrow::Cow, fmt::Debug, marker::PhantomData, str::FromStr};
 
 #[derive(PartialEq)]
 enum ParserState {
@@ -329,7 +330,7 @@ impl<'p, P> Parser<'p, P> {
         }
     }
 
-    fn advance_state(&mut self, idx: usize, next_state: ParserState) -> std::ops::Range<usize> {
+    fn advance_state(&mut self, idx: usize, next_state: ParserState) -> core::ops::Range<usize> {
         let range = self.start_idx..idx;
         self.idx = idx + 1;
         self.start_idx = self.idx;
@@ -3,8 +3,8 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::interpolator::InterpolatorError;
+use core::{fmt::Debug, str::FromStr};
 use displaydoc::Display;
-use std::{fmt::Debug, str::FromStr};
 
 /// An error returned from a pattern.
 ///
@@ -20,9 +20,10 @@ where
     #[displaydoc("Interpolator error: {0:?}")]
     Interpolator(InterpolatorError<K>),
     #[displaydoc("Format error: {0:?}")]
-    Format(std::fmt::Error),
+    Format(core::fmt::Error),
 }
 
+#[cfg(feature = "std")]
 impl<K> std::error::Error for PatternError<K>
 where
     K: Debug + FromStr + PartialEq,
@@ -40,12 +41,12 @@ where
     }
 }
 
-impl<K> From<std::fmt::Error> for PatternError<K>
+impl<K> From<core::fmt::Error> for PatternError<K>
 where
     K: Debug + FromStr + PartialEq,
     K::Err: Debug + PartialEq,
 {
-    fn from(err: std::fmt::Error) -> Self {
+    fn from(err: core::fmt::Error) -> Self {
         Self::Format(err)
     }
 }
@@ -10,13 +10,14 @@ use crate::{
     replacement::ReplacementProvider,
     token::PatternToken,
 };
-pub use error::PatternError;
-use std::{
+use alloc::{string::String, vec, vec::Vec};
+use core::{
     convert::{TryFrom, TryInto},
     fmt::{Debug, Display, Write},
     ops::Deref,
     str::FromStr,
 };
+pub use error::PatternError;
 use writeable::Writeable;
 
 /// `Pattern` stores the result of parsing operation as a vector
@@ -107,7 +108,7 @@ impl<'s, P> Pattern<'s, P> {
     /// struct Element(usize);
     ///
     /// impl Display for Element {
-    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     ///         self.0.fmt(f)
     ///     }
     /// }
@@ -161,7 +162,7 @@ impl<'s, P> Pattern<'s, P> {
     /// struct Element(usize);
     ///
     /// impl Display for Element {
-    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     ///         self.0.fmt(f)
     ///     }
     /// }
@@ -332,9 +333,9 @@ impl<'i, 's, E> Writeable for InterpolatedPattern<'i, 's, E>
 where
     E: Writeable,
 {
-    fn write_to<W>(&self, sink: &mut W) -> std::result::Result<(), std::fmt::Error>
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
     where
-        W: std::fmt::Write + ?Sized,
+        W: core::fmt::Write + ?Sized,
     {
         for elem in &self.0 {
             elem.write_to(sink)?;
@@ -347,7 +348,7 @@ impl<'i, 's, E> Display for InterpolatedPattern<'i, 's, E>
 where
     E: Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         for elem in &self.0 {
             write!(f, "{}", elem)?;
         }
@@ -2,6 +2,8 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use std::collections::HashMap;
 
 /// A trait which has to be implemented on any type that will be used to
@@ -117,7 +119,7 @@ pub trait ReplacementProvider<'r, E: 'r> {
 
 impl<'r, E: 'r> ReplacementProvider<'r, E> for Vec<Vec<E>> {
     type Key = usize;
-    type Iter = std::slice::Iter<'r, E>;
+    type Iter = core::slice::Iter<'r, E>;
 
     fn take_replacement(&'r self, input: &usize) -> Option<Self::Iter> {
         let replacement = self.get(*input)?;
@@ -127,17 +129,18 @@ impl<'r, E: 'r> ReplacementProvider<'r, E> for Vec<Vec<E>> {
 
 impl<'r, E: 'r> ReplacementProvider<'r, E> for Vec<E> {
     type Key = usize;
-    type Iter = std::iter::Once<&'r E>;
+    type Iter = core::iter::Once<&'r E>;
 
     fn take_replacement(&'r self, input: &usize) -> Option<Self::Iter> {
         let replacement = self.get(*input)?;
-        Some(std::iter::once(replacement))
+        Some(core::iter::once(replacement))
     }
 }
 
+#[cfg(feature = "std")]
 impl<'r, E: 'r> ReplacementProvider<'r, E> for HashMap<String, Vec<E>> {
     type Key = String;
-    type Iter = std::slice::Iter<'r, E>;
+    type Iter = core::slice::Iter<'r, E>;
 
     fn take_replacement(&'r self, input: &String) -> Option<Self::Iter> {
         let replacement = self.get(input)?;
@@ -145,12 +148,13 @@ impl<'r, E: 'r> ReplacementProvider<'r, E> for HashMap<String, Vec<E>> {
     }
 }
 
+#[cfg(feature = "std")]
 impl<'r, E: 'r> ReplacementProvider<'r, E> for HashMap<String, E> {
     type Key = String;
-    type Iter = std::iter::Once<&'r E>;
+    type Iter = core::iter::Once<&'r E>;
 
     fn take_replacement(&'r self, input: &String) -> Option<Self::Iter> {
         let replacement = self.get(input)?;
-        Some(std::iter::once(replacement))
+        Some(core::iter::once(replacement))
     }
 }
@@ -0,0 +1,36 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::parser::ParserError;
+use alloc::string::String;
+use core::convert::Infallible;
+use displaydoc::Display;
+
+/// An error returned when creating a [`TypedPattern`](crate::TypedPattern).
+#[derive(Display, Debug, PartialEq)]
+pub enum TypedPatternError {
+    #[displaydoc("Parser error: {0:?}")]
+    Parser(ParserError<Infallible>),
+    /// The pattern contains a placeholder which is not declared by its schema.
+    #[displaydoc("Unknown placeholder: {0}")]
+    UnknownPlaceholder(String),
+    /// The pattern does not contain a placeholder declared by its schema.
+    #[displaydoc("Missing placeholder: {0}")]
+    MissingPlaceholder(&'static str),
+    /// The literal text of the pattern is too long to be stored.
+    #[displaydoc("Pattern too long")]
+    TooLong,
+    /// The serialized form of the pattern is inconsistent.
+    #[displaydoc("Invalid pattern data")]
+    InvalidData,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TypedPatternError {}
+
+impl From<ParserError<Infallible>> for TypedPatternError {
+    fn from(err: ParserError<Infallible>) -> Self {
+        Self::Parser(err)
+    }
+}
@@ -0,0 +1,543 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+mod error;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "yoke")]
+mod yoke;
+
+use crate::{
+    parser::{Parser, ParserOptions},
+    token::PatternToken,
+};
+use alloc::{borrow::Cow, string::String, vec, vec::Vec};
+use core::{convert::TryFrom, fmt, marker::PhantomData};
+pub use error::TypedPatternError;
+use writeable::{LengthHint, Writeable};
+use zerovec::ZeroVec;
+
+/// A declaration of the placeholders of a [`TypedPattern`].
+///
+/// # Examples
+///
+/// ```
+/// use icu_pattern::{PlaceholderCount, PlaceholderSchema, TypedPattern};
+/// use std::convert::TryFrom;
+/// use writeable::Writeable;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct DateTime;
+///
+/// impl PlaceholderSchema for DateTime {
+///     const PLACEHOLDERS: &'static [&'static str] = &["date", "time"];
+/// }
+///
+/// impl PlaceholderCount<2> for DateTime {}
+///
+/// let pattern = TypedPattern::<DateTime>::try_from("{time} on {date}")
+///     .expect("Failed to parse a pattern");
+///
+/// assert_eq!(
+///     pattern.interpolate(["Monday", "noon"]).writeable_to_string(),
+///     "noon on Monday"
+/// );
+/// ```
+pub trait PlaceholderSchema {
+    /// The names of the placeholders, like `["0", "1"]` for `{0}` and `{1}`.
+    ///
+    /// The replacements of [`TypedPattern::interpolate`] are given in the same order.
+    const PLACEHOLDERS: &'static [&'static str];
+}
+
+/// Declares that a [`PlaceholderSchema`] has `N` placeholders, which lets
+/// [`TypedPattern::interpolate`] check the number of replacements at compile time.
+pub trait PlaceholderCount<const N: usize>: PlaceholderSchema {}
+
+/// The schema of patterns with a single placeholder `{0}`, like `"{0} Time"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SinglePlaceholder;
+
+impl PlaceholderSchema for SinglePlaceholder {
+    const PLACEHOLDERS: &'static [&'static str] = &["0"];
+}
+
+impl PlaceholderCount<1> for SinglePlaceholder {}
+
+/// The schema of patterns with two placeholders `{0}` and `{1}`, like `"{1} ({0})"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DoublePlaceholder;
+
+impl PlaceholderSchema for DoublePlaceholder {
+    const PLACEHOLDERS: &'static [&'static str] = &["0", "1"];
+}
+
+impl PlaceholderCount<2> for DoublePlaceholder {}
+
+/// The number of low bits of a stored placeholder which hold its index in the schema.
+const INDEX_BITS: u32 = 8;
+const INDEX_MASK: u32 = (1 << INDEX_BITS) - 1;
+
+/// `TypedPattern` stores a pre-parsed placeholder pattern whose placeholders have been
+/// validated against a [`PlaceholderSchema`].
+///
+/// Unlike [`Pattern`](crate::Pattern), it does not borrow the string it was parsed from,
+/// and it can be stored in data structs: its literal text is kept in a single string and its
+/// placeholders in a [`ZeroVec`], so that it can be deserialized without allocations.
+/// Human-readable formats, like JSON, serialize it as a pattern string.
+///
+/// # Type parameters
+///
+/// - `S`: The [`PlaceholderSchema`] of the pattern.
+///
+/// # Lifetimes
+///
+/// - `data`: The lifetime of the data the pattern was deserialized from.
+///
+/// # Examples
+///
+/// ```
+/// use icu_pattern::{SinglePlaceholder, TypedPattern, TypedPatternError};
+/// use std::convert::TryFrom;
+/// use writeable::Writeable;
+///
+/// let pattern = TypedPattern::<SinglePlaceholder>::try_from("{0} 'o''clock'")
+///     .expect("Failed to parse a pattern");
+///
+/// assert_eq!(pattern.interpolate(["5"]).writeable_to_string(), "5 o'clock");
+///
+/// assert_eq!(
+///     TypedPattern::<SinglePlaceholder>::try_from("{1} days"),
+///     Err(TypedPatternError::UnknownPlaceholder("1".into()))
+/// );
+/// assert_eq!(
+///     TypedPattern::<SinglePlaceholder>::try_from("days"),
+///     Err(TypedPatternError::MissingPlaceholder("0"))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedPattern<'data, S> {
+    /// The literal text of the pattern, without quotes.
+    literals: Cow<'data, str>,
+    /// The placeholders in the order of the pattern. The high bits of each hold the byte
+    /// offset in `literals` where it is inserted, and the low [`INDEX_BITS`] its index in
+    /// the schema.
+    placeholders: ZeroVec<'data, u32>,
+    schema: PhantomData<S>,
+}
+
+impl<'data, S> TypedPattern<'data, S>
+where
+    S: PlaceholderSchema,
+{
+    /// Creates a pattern from its literal text and encoded placeholders, as they are
+    /// serialized, checking that they are consistent with each other and with the schema.
+    #[cfg(feature = "serde")]
+    fn try_from_parts(
+        literals: Cow<'data, str>,
+        placeholders: ZeroVec<'data, u32>,
+    ) -> Result<Self, TypedPatternError> {
+        let mut start = 0;
+        for placeholder in placeholders.iter() {
+            let offset = (placeholder >> INDEX_BITS) as usize;
+            let index = (placeholder & INDEX_MASK) as usize;
+            if offset < start
+                || !literals.is_char_boundary(offset)
+                || index >= S::PLACEHOLDERS.len()
+            {
+                return Err(TypedPatternError::InvalidData);
+            }
+            start = offset;
+        }
+        let pattern = Self {
+            literals,
+            placeholders,
+            schema: PhantomData,
+        };
+        pattern.check_missing()?;
+        Ok(pattern)
+    }
+
+    fn check_missing(&self) -> Result<(), TypedPatternError> {
+        let mut found = vec![false; S::PLACEHOLDERS.len()];
+        for placeholder in self.placeholders.iter() {
+            found[(placeholder & INDEX_MASK) as usize] = true;
+        }
+        match found.iter().position(|found| !found) {
+            Some(index) => Err(TypedPatternError::MissingPlaceholder(
+                S::PLACEHOLDERS[index],
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Interpolates the `TypedPattern` with one replacement for each placeholder of the
+    /// schema, in the order of [`PlaceholderSchema::PLACEHOLDERS`], and returns a
+    /// [`Writeable`].
+    ///
+    /// The number of replacements is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_pattern::{DoublePlaceholder, TypedPattern};
+    /// use std::convert::TryFrom;
+    /// use writeable::Writeable;
+    ///
+    /// let pattern = TypedPattern::<DoublePlaceholder>::try_from("{1} ({0})")
+    ///     .expect("Failed to parse a pattern");
+    ///
+    /// assert_eq!(
+    ///     pattern.interpolate(["Pacific Time", "Los Angeles"]).writeable_to_string(),
+    ///     "Los Angeles (Pacific Time)"
+    /// );
+    /// ```
+    ///
+    /// A wrong number of replacements does not compile:
+    ///
+    /// ```compile_fail
+    /// use icu_pattern::{DoublePlaceholder, TypedPattern};
+    /// use std::convert::TryFrom;
+    ///
+    /// let pattern = TypedPattern::<DoublePlaceholder>::try_from("{1} ({0})")
+    ///     .expect("Failed to parse a pattern");
+    ///
+    /// pattern.interpolate(["Pacific Time"]);
+    /// ```
+    pub fn interpolate<'i, W, const N: usize>(
+        &'i self,
+        replacements: [&'i W; N],
+    ) -> InterpolatedTypedPattern<'i, W, N>
+    where
+        W: Writeable + ?Sized,
+        S: PlaceholderCount<N>,
+    {
+        debug_assert_eq!(S::PLACEHOLDERS.len(), N);
+        InterpolatedTypedPattern {
+            literals: &self.literals,
+            placeholders: &self.placeholders,
+            replacements,
+        }
+    }
+
+    /// Returns the literal text of the pattern, without its placeholders.
+    pub fn literals(&self) -> &str {
+        &self.literals
+    }
+
+    /// Returns the tokens of the pattern in their order: its literal text, without quotes, and
+    /// its placeholders, by their names in the schema.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_pattern::{PatternToken, SinglePlaceholder, TypedPattern};
+    /// use std::convert::TryFrom;
+    ///
+    /// let pattern = TypedPattern::<SinglePlaceholder>::try_from("{0} 'o''clock'")
+    ///     .expect("Failed to parse a pattern");
+    ///
+    /// assert_eq!(
+    ///     pattern.tokens().collect::<Vec<_>>(),
+    ///     vec![
+    ///         PatternToken::Placeholder("0"),
+    ///         PatternToken::Literal {
+    ///             content: " o'clock".into(),
+    ///             quoted: false,
+    ///         },
+    ///     ]
+    /// );
+    /// ```
+    pub fn tokens(&self) -> impl Iterator<Item = PatternToken<'_, &'static str>> + '_ {
+        let mut start = 0;
+        self.placeholders
+            .iter()
+            .map(Some)
+            .chain(core::iter::once(None))
+            .flat_map(move |placeholder| {
+                let end = placeholder.map_or(self.literals.len(), |placeholder| {
+                    (placeholder >> INDEX_BITS) as usize
+                });
+                let literal = &self.literals[start..end];
+                start = end;
+                let literal = if literal.is_empty() {
+                    None
+                } else {
+                    Some(PatternToken::Literal {
+                        content: Cow::Borrowed(literal),
+                        quoted: false,
+                    })
+                };
+                let placeholder = placeholder.map(|placeholder| {
+                    PatternToken::Placeholder(S::PLACEHOLDERS[(placeholder & INDEX_MASK) as usize])
+                });
+                literal.into_iter().chain(placeholder)
+            })
+    }
+
+    /// Converts the pattern into one that owns its data.
+    pub fn into_owned(self) -> TypedPattern<'static, S> {
+        TypedPattern {
+            literals: Cow::Owned(self.literals.into_owned()),
+            placeholders: self.placeholders.into_owned(),
+            schema: PhantomData,
+        }
+    }
+}
+
+impl<'data, S> TryFrom<&str> for TypedPattern<'data, S>
+where
+    S: PlaceholderSchema,
+{
+    type Error = TypedPatternError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut parser = Parser::<String>::new(
+            input,
+            ParserOptions {
+                allow_raw_letters: true,
+            },
+        );
+        let mut literals = String::new();
+        let mut placeholders = Vec::new();
+        while let Some(token) = parser.try_next()? {
+            match token {
+                PatternToken::Literal { content, .. } => literals.push_str(&content),
+                PatternToken::Placeholder(name) => {
+                    let index = S::PLACEHOLDERS
+                        .iter()
+                        .position(|placeholder| *placeholder == name)
+                        .ok_or(TypedPatternError::UnknownPlaceholder(name))?;
+                    let offset = u32::try_from(literals.len())
+                        .ok()
+                        .filter(|offset| offset.leading_zeros() >= INDEX_BITS)
+                        .ok_or(TypedPatternError::TooLong)?;
+                    if index as u32 > INDEX_MASK {
+                        return Err(TypedPatternError::TooLong);
+                    }
+                    placeholders.push(offset << INDEX_BITS | index as u32);
+                }
+            }
+        }
+        let pattern = Self {
+            literals: Cow::Owned(literals),
+            placeholders: ZeroVec::from_aligned(&placeholders),
+            schema: PhantomData,
+        };
+        pattern.check_missing()?;
+        Ok(pattern)
+    }
+}
+
+/// The default pattern consists of the placeholders of the schema in their order, like
+/// `"{0}{1}"`.
+impl<S> Default for TypedPattern<'_, S>
+where
+    S: PlaceholderSchema,
+{
+    fn default() -> Self {
+        let placeholders: Vec<u32> = (0..S::PLACEHOLDERS.len() as u32).collect();
+        Self {
+            literals: Cow::Borrowed(""),
+            placeholders: ZeroVec::from_aligned(&placeholders),
+            schema: PhantomData,
+        }
+    }
+}
+
+/// Writes the literal text and placeholders of a pattern, calling `write_placeholder` with the
+/// schema index of each placeholder.
+fn write_parts<W, F>(
+    literals: &str,
+    placeholders: &ZeroVec<u32>,
+    sink: &mut W,
+    mut write_literal: impl FnMut(&str, &mut W) -> fmt::Result,
+    mut write_placeholder: F,
+) -> fmt::Result
+where
+    W: fmt::Write + ?Sized,
+    F: FnMut(usize, &mut W) -> fmt::Result,
+{
+    let mut start = 0;
+    for placeholder in placeholders.iter() {
+        let offset = (placeholder >> INDEX_BITS) as usize;
+        write_literal(&literals[start..offset], sink)?;
+        write_placeholder((placeholder & INDEX_MASK) as usize, sink)?;
+        start = offset;
+    }
+    write_literal(&literals[start..], sink)
+}
+
+/// Writes the pattern in the placeholder pattern syntax, quoting literal text where needed.
+impl<S> fmt::Display for TypedPattern<'_, S>
+where
+    S: PlaceholderSchema,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_parts(
+            &self.literals,
+            &self.placeholders,
+            f,
+            |literal, f| {
+                for c in literal.chars() {
+                    match c {
+                        '\'' => f.write_str("''")?,
+                        '{' | '}' => write!(f, "'{}'", c)?,
+                        _ => fmt::Write::write_char(f, c)?,
+                    }
+                }
+                Ok(())
+            },
+            |index, f| write!(f, "{{{}}}", S::PLACEHOLDERS[index]),
+        )
+    }
+}
+
+/// The result of [`TypedPattern::interpolate`], which can be written with [`Writeable`].
+///
+/// # Type parameters
+///
+/// - `W`: The type of the replacements.
+/// - `N`: The number of replacements.
+///
+/// # Lifetimes
+///
+/// - `i`: The lifetime of the pattern and the replacements.
+#[derive(Debug)]
+pub struct InterpolatedTypedPattern<'i, W: ?Sized, const N: usize> {
+    literals: &'i str,
+    placeholders: &'i ZeroVec<'i, u32>,
+    replacements: [&'i W; N],
+}
+
+impl<W, const N: usize> Writeable for InterpolatedTypedPattern<'_, W, N>
+where
+    W: Writeable + ?Sized,
+{
+    fn write_to<S>(&self, sink: &mut S) -> fmt::Result
+    where
+        S: fmt::Write + ?Sized,
+    {
+        write_parts(
+            self.literals,
+            self.placeholders,
+            sink,
+            |literal, sink| sink.write_str(literal),
+            |index, sink| self.replacements[index].write_to(sink),
+        )
+    }
+
+    fn write_len(&self) -> LengthHint {
+        self.placeholders
+            .iter()
+            .map(|placeholder| self.replacements[(placeholder & INDEX_MASK) as usize].write_len())
+            .sum::<LengthHint>()
+            + self.literals.len()
+    }
+}
+
+impl<W, const N: usize> fmt::Display for InterpolatedTypedPattern<'_, W, N>
+where
+    W: Writeable + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ParserError;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn typed_pattern_display() {
+        let samples = [
+            ("{0}", "{0}"),
+            ("GMT{0}", "GMT{0}"),
+            ("{0} 'o''clock'", "{0} o''clock"),
+            ("'{'{0}'}'", "'{'{0}'}'"),
+            ("{0} {0}", "{0} {0}"),
+        ];
+        for (input, output) in samples.iter() {
+            let pattern = TypedPattern::<SinglePlaceholder>::try_from(*input)
+                .expect("Failed to parse a pattern");
+            assert_eq!(pattern.to_string(), *output, "{}", input);
+            assert_eq!(
+                TypedPattern::<SinglePlaceholder>::try_from(*output),
+                Ok(pattern),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            TypedPattern::<DoublePlaceholder>::default().to_string(),
+            "{0}{1}"
+        );
+    }
+
+    #[test]
+    fn typed_pattern_interpolate() {
+        let samples = [
+            ("{1} ({0})", "Los Angeles (Pacific Time)"),
+            ("{0}{1}", "Pacific TimeLos Angeles"),
+            ("'{0}' {1}: {0}.", "{0} Los Angeles: Pacific Time."),
+        ];
+        for (input, output) in samples.iter() {
+            let pattern = TypedPattern::<DoublePlaceholder>::try_from(*input)
+                .expect("Failed to parse a pattern");
+            assert_writeable_eq!(
+                *output,
+                pattern.interpolate(["Pacific Time", "Los Angeles"]),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn typed_pattern_tokens() {
+        let pattern = TypedPattern::<DoublePlaceholder>::try_from("{1}{0}, {1}!")
+            .expect("Failed to parse a pattern");
+        assert_eq!(
+            pattern.tokens().collect::<Vec<_>>(),
+            vec![
+                PatternToken::Placeholder("1"),
+                PatternToken::Placeholder("0"),
+                PatternToken::Literal {
+                    content: ", ".into(),
+                    quoted: false
+                },
+                PatternToken::Placeholder("1"),
+                PatternToken::Literal {
+                    content: "!".into(),
+                    quoted: false
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn typed_pattern_errors() {
+        assert_eq!(
+            TypedPattern::<DoublePlaceholder>::try_from("{0} {2}"),
+            Err(TypedPatternError::UnknownPlaceholder("2".into()))
+        );
+        assert_eq!(
+            TypedPattern::<DoublePlaceholder>::try_from("{1} days"),
+            Err(TypedPatternError::MissingPlaceholder("0"))
+        );
+        assert_eq!(
+            TypedPattern::<SinglePlaceholder>::try_from("{0"),
+            Err(TypedPatternError::Parser(ParserError::UnclosedPlaceholder))
+        );
+        assert_eq!(
+            TypedPattern::<SinglePlaceholder>::try_from("{0} 'days"),
+            Err(TypedPatternError::Parser(
+                ParserError::UnclosedQuotedLiteral
+            ))
+        );
+    }
+}
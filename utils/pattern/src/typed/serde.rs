@@ -0,0 +1,117 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::{PlaceholderSchema, TypedPattern, TypedPatternError};
+use ::serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use alloc::{borrow::Cow, string::String};
+use core::convert::TryFrom;
+use zerovec::ZeroVec;
+
+/// The serialized form of a [`TypedPattern`] in binary formats, whose placeholders are the
+/// bytes of its [`ZeroVec`].
+#[derive(Deserialize)]
+struct SerializedPattern<'data> {
+    #[serde(borrow)]
+    literals: Cow<'data, str>,
+    #[serde(borrow)]
+    placeholders: Cow<'data, [u8]>,
+}
+
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// This impl can be made available by enabling the optional `serde` feature of the `icu_pattern` crate
+impl<S> Serialize for TypedPattern<'_, S>
+where
+    S: PlaceholderSchema,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let mut state = serializer.serialize_struct("SerializedPattern", 2)?;
+            state.serialize_field("literals", &*self.literals)?;
+            state.serialize_field("placeholders", &Bytes(self.placeholders.as_bytes()))?;
+            state.end()
+        }
+    }
+}
+
+/// This impl can be made available by enabling the optional `serde` feature of the `icu_pattern` crate
+impl<'de, 'data, S> Deserialize<'de> for TypedPattern<'data, S>
+where
+    S: PlaceholderSchema,
+    'de: 'data,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let pattern = String::deserialize(deserializer)?;
+            TypedPattern::try_from(pattern.as_str()).map_err(de::Error::custom)
+        } else {
+            let SerializedPattern {
+                literals,
+                placeholders,
+            } = SerializedPattern::deserialize(deserializer)?;
+            let placeholders = match placeholders {
+                Cow::Borrowed(bytes) => ZeroVec::try_from_bytes(bytes),
+                Cow::Owned(bytes) => ZeroVec::try_from_bytes(&bytes).map(ZeroVec::into_owned),
+            }
+            .map_err(|_| de::Error::custom(TypedPatternError::InvalidData))?;
+            TypedPattern::try_from_parts(literals, placeholders).map_err(de::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DoublePlaceholder, SinglePlaceholder};
+    use writeable::Writeable;
+
+    #[test]
+    fn typed_pattern_json() {
+        let pattern = TypedPattern::<DoublePlaceholder>::try_from("{1} '{'{0}'}'")
+            .expect("Failed to parse a pattern");
+        let json = serde_json::to_string(&pattern).expect("Failed to serialize");
+        assert_eq!(json, r#""{1} '{'{0}'}'""#);
+        let deserialized: TypedPattern<DoublePlaceholder> =
+            serde_json::from_str(&json).expect("Failed to deserialize");
+        assert_eq!(deserialized, pattern);
+
+        assert!(serde_json::from_str::<TypedPattern<DoublePlaceholder>>(r#""{0} days""#).is_err());
+    }
+
+    #[test]
+    fn typed_pattern_postcard() {
+        let pattern = TypedPattern::<DoublePlaceholder>::try_from("{1} ({0})")
+            .expect("Failed to parse a pattern");
+        let bytes = postcard::to_stdvec(&pattern).expect("Failed to serialize");
+        let deserialized: TypedPattern<DoublePlaceholder> =
+            postcard::from_bytes(&bytes).expect("Failed to deserialize");
+        assert!(matches!(deserialized.literals, Cow::Borrowed(_)));
+        assert!(matches!(deserialized.placeholders, ZeroVec::Borrowed(_)));
+        assert_eq!(deserialized, pattern);
+        assert_eq!(
+            deserialized.interpolate(["a", "b"]).writeable_to_string(),
+            "b (a)"
+        );
+
+        // The placeholders of a pattern with another schema are not accepted.
+        assert!(postcard::from_bytes::<TypedPattern<SinglePlaceholder>>(&bytes).is_err());
+    }
+}
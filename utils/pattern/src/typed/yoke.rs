@@ -0,0 +1,45 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::TypedPattern;
+use ::yoke::{Yokeable, ZeroCopyFrom};
+use alloc::borrow::Cow;
+use core::{marker::PhantomData, mem, ptr};
+use zerovec::ZeroVec;
+
+// This impl is similar to the impl on Cow and is safe for the same reasons
+/// This impl can be made available by enabling the optional `yoke` feature of the `icu_pattern` crate
+unsafe impl<'a, S: 'static> Yokeable<'a> for TypedPattern<'static, S> {
+    type Output = TypedPattern<'a, S>;
+    fn transform(&'a self) -> &'a TypedPattern<'a, S> {
+        self
+    }
+    fn transform_owned(self) -> TypedPattern<'a, S> {
+        self
+    }
+    unsafe fn make(from: TypedPattern<'a, S>) -> Self {
+        debug_assert!(mem::size_of::<TypedPattern<'a, S>>() == mem::size_of::<Self>());
+        let ptr: *const Self = (&from as *const Self::Output).cast();
+        mem::forget(from);
+        ptr::read(ptr)
+    }
+
+    fn transform_mut<F>(&'a mut self, f: F)
+    where
+        F: 'static + for<'b> FnOnce(&'b mut Self::Output),
+    {
+        unsafe { f(mem::transmute::<&mut Self, &mut Self::Output>(self)) }
+    }
+}
+
+/// This impl can be made available by enabling the optional `yoke` feature of the `icu_pattern` crate
+impl<'a, S: 'static> ZeroCopyFrom<TypedPattern<'a, S>> for TypedPattern<'static, S> {
+    fn zero_copy_from<'b>(cart: &'b TypedPattern<'a, S>) -> TypedPattern<'b, S> {
+        TypedPattern {
+            literals: Cow::Borrowed(&cart.literals),
+            placeholders: ZeroVec::Borrowed(cart.placeholders.as_slice()),
+            schema: PhantomData,
+        }
+    }
+}
@@ -49,6 +49,13 @@
 //! assert_writeable_eq!("Hello, Alice!", &message);
 //! ```
 //!
+//! # Parts
+//!
+//! A Writeable can also annotate spans of its output with [`Part`]s, like the integer digits
+//! of a number or the month of a date, by implementing [`Writeable::write_to_parts`]. This
+//! allows clients to style parts of a formatted value, like ECMA-402's `formatToParts`. A sink
+//! which receives the parts implements [`PartsWrite`].
+//!
 //! [`ICU4X`]: ../icu/index.html
 
 extern crate alloc;
@@ -56,6 +63,7 @@ extern crate alloc;
 mod ops;
 
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt;
 
 /// A hint to help consumers of Writeable pre-allocate bytes before they call write_to.
@@ -100,11 +108,125 @@ impl LengthHint {
     }
 }
 
+/// An annotation of a span of the output of a [`Writeable`], like the integer digits of a
+/// number.
+///
+/// The `category` names the kind of value being written, like `"decimal"`, and the `value`
+/// names the part within it, like `"integer"`. Crates implementing [`Writeable`] export their
+/// parts as constants.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+pub struct Part {
+    pub category: &'static str,
+    pub value: &'static str,
+}
+
+/// A sink which receives the [`Part`]s of a [`Writeable`] in addition to its bytes.
+///
+/// # Examples
+///
+/// ```
+/// use writeable::{Part, PartsWrite};
+/// use std::fmt;
+///
+/// /// Wraps the output of each part in brackets.
+/// struct Brackets(String);
+///
+/// impl fmt::Write for Brackets {
+///     fn write_str(&mut self, s: &str) -> fmt::Result {
+///         self.0.push_str(s);
+///         Ok(())
+///     }
+/// }
+///
+/// impl PartsWrite for Brackets {
+///     fn with_part(
+///         &mut self,
+///         _part: Part,
+///         mut f: impl FnMut(&mut Self) -> fmt::Result,
+///     ) -> fmt::Result {
+///         self.0.push('[');
+///         f(self)?;
+///         self.0.push(']');
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait PartsWrite: fmt::Write {
+    /// Annotates everything `f` writes to the sink with a [`Part`]. Parts may be nested.
+    fn with_part(&mut self, part: Part, f: impl FnMut(&mut Self) -> fmt::Result) -> fmt::Result;
+}
+
+/// A [`PartsWrite`] which writes to a [`fmt::Write`], dropping the parts.
+///
+/// [`Writeable`]s implementing [`Writeable::write_to_parts`] can use it to implement
+/// [`Writeable::write_to`].
+///
+/// # Examples
+///
+/// ```
+/// use writeable::{CoreWriteAsPartsWrite, Part, PartsWrite, Writeable};
+/// use std::fmt;
+///
+/// const NAME: Part = Part {
+///     category: "greeting",
+///     value: "name",
+/// };
+///
+/// struct Greeting<'s>(&'s str);
+///
+/// impl Writeable for Greeting<'_> {
+///     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+///         self.write_to_parts(&mut CoreWriteAsPartsWrite(sink))
+///     }
+///
+///     fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+///         sink.write_str("Hello, ")?;
+///         sink.with_part(NAME, |sink| sink.write_str(self.0))
+///     }
+/// }
+///
+/// assert_eq!(Greeting("Alice").writeable_to_string(), "Hello, Alice");
+/// ```
+pub struct CoreWriteAsPartsWrite<W: fmt::Write + ?Sized>(pub W);
+
+impl<W: fmt::Write + ?Sized> fmt::Write for CoreWriteAsPartsWrite<W> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.0.write_char(c)
+    }
+}
+
+impl<W: fmt::Write + ?Sized> PartsWrite for CoreWriteAsPartsWrite<W> {
+    #[inline]
+    fn with_part(
+        &mut self,
+        _part: Part,
+        mut f: impl FnMut(&mut Self) -> fmt::Result,
+    ) -> fmt::Result {
+        f(self)
+    }
+}
+
 /// Writeable is an alternative to std::fmt::Display with the addition of a length function.
 pub trait Writeable {
     /// Writes bytes to the given sink. Errors from the sink are bubbled up.
     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result;
 
+    /// Writes bytes and [`Part`] annotations to the given sink. Errors from the sink are
+    /// bubbled up.
+    ///
+    /// The default implementation writes no parts. Override this method, and implement
+    /// [`write_to`](Writeable::write_to) with [`CoreWriteAsPartsWrite`], if the output has
+    /// parts.
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        self.write_to(sink)
+    }
+
     /// Returns a hint for the number of bytes that will be written to the sink.
     ///
     /// Override this method if it can be computed quickly.
@@ -124,6 +246,91 @@ pub trait Writeable {
     }
 }
 
+impl Writeable for str {
+    #[inline]
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_str(self)
+    }
+
+    #[inline]
+    fn write_len(&self) -> LengthHint {
+        LengthHint::Exact(self.len())
+    }
+}
+
+impl Writeable for String {
+    #[inline]
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_str(self)
+    }
+
+    #[inline]
+    fn write_len(&self) -> LengthHint {
+        LengthHint::Exact(self.len())
+    }
+}
+
+impl<T: Writeable + ?Sized> Writeable for &T {
+    #[inline]
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        (*self).write_to(sink)
+    }
+
+    #[inline]
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        (*self).write_to_parts(sink)
+    }
+
+    #[inline]
+    fn write_len(&self) -> LengthHint {
+        (*self).write_len()
+    }
+}
+
+/// A [`PartsWrite`] collecting the output and the parts with their byte ranges.
+struct TestWriter {
+    string: String,
+    parts: Vec<(usize, usize, Part)>,
+}
+
+impl fmt::Write for TestWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.string.push_str(s);
+        Ok(())
+    }
+}
+
+impl PartsWrite for TestWriter {
+    fn with_part(
+        &mut self,
+        part: Part,
+        mut f: impl FnMut(&mut Self) -> fmt::Result,
+    ) -> fmt::Result {
+        let start = self.string.len();
+        f(self)?;
+        self.parts.push((start, self.string.len(), part));
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+pub fn writeable_to_parts_for_test<W: Writeable + ?Sized>(
+    writeable: &W,
+) -> (String, Vec<(usize, usize, Part)>) {
+    let mut writer = TestWriter {
+        string: String::with_capacity(writeable.write_len().capacity()),
+        parts: Vec::new(),
+    };
+    writeable
+        .write_to_parts(&mut writer)
+        .expect("impl Write for String is infallible");
+    // Outer parts are pushed after their nested parts; sort them by position instead.
+    writer
+        .parts
+        .sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+    (writer.string, writer.parts)
+}
+
 /// Testing macro for types implementing Writeable. The first argument should be a string, and
 /// the second argument should be a `&dyn Writeable`.
 ///
@@ -175,3 +382,47 @@ macro_rules! assert_writeable_eq {
         }
     };
 }
+
+/// Testing macro for types implementing [`Writeable::write_to_parts`]. The first argument
+/// should be a string, the second argument should be a `&dyn Writeable`, and the third argument
+/// should be a slice of `(start, end, Part)` tuples, ordered by `start` and outer parts first.
+///
+/// The macro tests for equality of the string content, the string length, and the parts. The
+/// string written by [`Writeable::write_to`] must be the same as the one with parts.
+///
+/// # Examples
+///
+/// ```
+/// use writeable::{assert_writeable_parts_eq, CoreWriteAsPartsWrite, Part, PartsWrite, Writeable};
+/// use std::fmt;
+///
+/// const WORD: Part = Part {
+///     category: "demo",
+///     value: "word",
+/// };
+///
+/// struct Demo;
+/// impl Writeable for Demo {
+///     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+///         self.write_to_parts(&mut CoreWriteAsPartsWrite(sink))
+///     }
+///     fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+///         sink.with_part(WORD, |s| s.write_str("foo"))?;
+///         sink.write_char(' ')?;
+///         sink.with_part(WORD, |s| s.write_str("bar"))
+///     }
+/// }
+///
+/// assert_writeable_parts_eq!("foo bar", &Demo, [(0, 3, WORD), (4, 7, WORD)]);
+/// ```
+#[macro_export]
+macro_rules! assert_writeable_parts_eq {
+    ($expected_str:expr, $actual_writeable:expr, $expected_parts:expr $(,)?) => {{
+        let writeable = $actual_writeable;
+        $crate::assert_writeable_eq!($expected_str, writeable);
+        let (actual_str, actual_parts) = $crate::writeable_to_parts_for_test(writeable);
+        assert_eq!($expected_str, actual_str);
+        let expected_parts: &[(usize, usize, $crate::Part)] = &$expected_parts;
+        assert_eq!(expected_parts, &actual_parts[..]);
+    }};
+}
@@ -4,7 +4,11 @@
 
 use std::fmt;
 use writeable::assert_writeable_eq;
+use writeable::assert_writeable_parts_eq;
+use writeable::CoreWriteAsPartsWrite;
 use writeable::LengthHint;
+use writeable::Part;
+use writeable::PartsWrite;
 use writeable::Writeable;
 
 /// A sample type implementing Writeable
@@ -30,3 +34,60 @@ fn test_basic() {
     };
     assert_writeable_eq!(input_string, &message);
 }
+
+const SENTENCE: Part = Part {
+    category: "test",
+    value: "sentence",
+};
+const WORD: Part = Part {
+    category: "test",
+    value: "word",
+};
+
+/// A sample type implementing Writeable with nested parts
+struct WriteableWords<'s> {
+    words: &'s [&'s str],
+}
+
+impl Writeable for WriteableWords<'_> {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.write_to_parts(&mut CoreWriteAsPartsWrite(sink))
+    }
+
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        sink.with_part(SENTENCE, |sink| {
+            for (i, word) in self.words.iter().enumerate() {
+                if i != 0 {
+                    sink.write_char(' ')?;
+                }
+                sink.with_part(WORD, |sink| sink.write_str(word))?;
+            }
+            Ok(())
+        })?;
+        sink.write_char('.')
+    }
+
+    fn write_len(&self) -> LengthHint {
+        LengthHint::Exact(
+            self.words.iter().map(|w| w.len()).sum::<usize>() + self.words.len().max(1),
+        )
+    }
+}
+
+#[test]
+fn test_parts() {
+    let words = WriteableWords {
+        words: &["hello", "world"],
+    };
+    assert_writeable_parts_eq!(
+        "hello world.",
+        &words,
+        [(0, 11, SENTENCE), (0, 5, WORD), (6, 11, WORD)]
+    );
+
+    // Writeables without parts write no parts.
+    let message = WriteableMessage {
+        message: "hello world",
+    };
+    assert_writeable_parts_eq!("hello world", &message, []);
+}
@@ -77,6 +77,10 @@
 //! # } // feature = "serde"
 //! ```
 
+#![cfg_attr(not(test), no_std)]
+
+extern crate alloc;
+
 pub mod map;
 #[cfg(test)]
 pub mod samples;
@@ -6,7 +6,7 @@
 //! ULE implementation for the `char` type.
 
 use super::*;
-use std::convert::TryFrom;
+use core::convert::TryFrom;
 
 /// A u8 array of little-endian data corresponding to a Unicode code point.
 ///
@@ -40,7 +40,7 @@ use std::convert::TryFrom;
 pub struct CharULE([u8; 4]);
 
 impl ULE for CharULE {
-    type Error = std::char::CharTryFromError;
+    type Error = core::char::CharTryFromError;
 
     #[inline]
     fn parse_byte_slice(bytes: &[u8]) -> Result<&[Self], Self::Error> {
@@ -53,7 +53,7 @@ impl ULE for CharULE {
         let data = bytes.as_ptr();
         let len = bytes.len() / 4;
         // Safe because Self is transparent over [u8; 4]
-        Ok(unsafe { std::slice::from_raw_parts(data as *const Self, len) })
+        Ok(unsafe { core::slice::from_raw_parts(data as *const Self, len) })
     }
 
     #[inline]
@@ -61,7 +61,7 @@ impl ULE for CharULE {
         let data = slice.as_ptr();
         let len = slice.len() * 4;
         // Safe because Self is transparent over [u8; 4]
-        unsafe { std::slice::from_raw_parts(data as *const u8, len) }
+        unsafe { core::slice::from_raw_parts(data as *const u8, len) }
     }
 }
 
@@ -27,20 +27,20 @@ macro_rules! impl_byte_slice_size {
             }
         }
         impl ULE for PlainOldULE<$size> {
-            type Error = std::convert::Infallible;
+            type Error = core::convert::Infallible;
             #[inline]
             fn parse_byte_slice(bytes: &[u8]) -> Result<&[Self], Self::Error> {
                 let data = bytes.as_ptr();
                 let len = bytes.len() / $size;
                 // Safe because Self is transparent over [u8; $size]
-                Ok(unsafe { std::slice::from_raw_parts(data as *const Self, len) })
+                Ok(unsafe { core::slice::from_raw_parts(data as *const Self, len) })
             }
             #[inline]
             fn as_byte_slice(slice: &[Self]) -> &[u8] {
                 let data = slice.as_ptr();
                 let len = slice.len() * $size;
                 // Safe because Self is transparent over [u8; $size]
-                unsafe { std::slice::from_raw_parts(data as *const u8, len) }
+                unsafe { core::slice::from_raw_parts(data as *const u8, len) }
             }
         }
     };
@@ -85,3 +85,6 @@ impl_byte_slice_type!(i16, 2);
 impl_byte_slice_type!(i32, 4);
 impl_byte_slice_type!(i64, 8);
 impl_byte_slice_type!(i128, 16);
+
+impl_byte_slice_type!(f32, 4);
+impl_byte_slice_type!(f64, 8);
@@ -3,9 +3,10 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use super::*;
-use std::convert::TryInto;
-use std::marker::PhantomData;
-use std::{iter, mem};
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use core::marker::PhantomData;
+use core::{iter, mem};
 
 fn usizeify(x: PlainOldULE<4>) -> usize {
     u32::from_unaligned(&x) as usize
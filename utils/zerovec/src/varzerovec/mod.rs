@@ -3,10 +3,11 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::ule::*;
+use alloc::vec::Vec;
 use components::SliceComponents;
+use core::fmt::{self, Display};
+use core::ops::Index;
 use either::Either;
-use std::fmt::{self, Display};
-use std::ops::Index;
 
 mod components;
 #[cfg(feature = "serde")]